@@ -298,6 +298,71 @@ def adxr_inc(high, low, prev_high, prev_low, prev_close, prev_adx, prev_adx_peri
     """
     ...
 
+def alpha(returns, benchmark, period, risk_free=0.0, periods_per_year=252.0):
+    """
+    Computes the rolling Jensen's Alpha of asset returns against benchmark returns.
+
+    The average return beyond what the CAPM Beta to the benchmark predicts, annualised by
+    `periods_per_year`.
+
+    Args:
+      returns: Asset returns as a 1-D NumPy array of type `TAFloat`.
+      benchmark: Benchmark returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window. Must be >= 2.
+      risk_free: Annual risk-free rate. Defaults to 0.0.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      A tuple of six 1-D NumPy arrays containing:
+      - Jensen's Alpha values
+      - CAPM Beta values
+      - Running sum of asset returns
+      - Running sum of benchmark returns
+      - Running sum of squared benchmark returns
+      - Running sum of asset times benchmark returns
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.021, -0.009, 0.031, 0.011, -0.019])
+      >>> benchmark = np.array([0.01, -0.005, 0.015, 0.005, -0.01])
+      >>> alpha, beta, sum_ret, sum_bench, sum_bench_sq, sum_cross = kand.alpha(returns, benchmark, 3)
+      ```
+    """
+    ...
+
+def alpha_inc(ret, bench, old_ret, old_bench, prev_sum_ret, prev_sum_bench, prev_sum_bench_sq, prev_sum_cross, period, risk_free=0.0, periods_per_year=252.0):
+    """
+    Computes the latest rolling Jensen's Alpha value incrementally.
+
+    Args:
+      ret: Newest asset return to add.
+      bench: Newest benchmark return to add.
+      old_ret: Oldest asset return to remove.
+      old_bench: Oldest benchmark return to remove.
+      prev_sum_ret: Previous sum of asset returns.
+      prev_sum_bench: Previous sum of benchmark returns.
+      prev_sum_bench_sq: Previous sum of squared benchmark returns.
+      prev_sum_cross: Previous sum of asset times benchmark returns.
+      period: Number of returns in the rolling window. Must be >= 2.
+      risk_free: Annual risk-free rate. Defaults to 0.0.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      A tuple containing (alpha, beta, sum_ret, sum_bench, sum_bench_sq, sum_cross).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> alpha, beta, sum_ret, sum_bench, sum_bench_sq, sum_cross = kand.alpha_inc(
+      ...     0.011, 0.005, 0.021, 0.01, 0.043, 0.02, 0.00035, 0.00072, 3
+      ... )
+      ```
+    """
+    ...
+
 def aroon(high, low, period):
     """
     Calculate Aroon indicator for a NumPy array.
@@ -335,6 +400,9 @@ def aroon_inc(high, low, prev_high, prev_low, days_since_high, days_since_low, p
     """
     Calculate the next Aroon values incrementally.
 
+    Deprecated: the days since counters are capped once an extreme leaves the window; use `aroon` on
+    the full series.
+
     Args:
 
       high: Current period's high price.
@@ -406,6 +474,9 @@ def aroonosc_inc(high, low, prev_high, prev_low, days_since_high, days_since_low
     """
     Calculate the next Aroon Oscillator value incrementally.
 
+    Deprecated: the days since counters are capped once an extreme leaves the window; use `aroonosc`
+    on the full series.
+
     Args:
 
       high: Current period's high price.
@@ -538,6 +609,8 @@ def bbands_inc(price, prev_sma, prev_sum, prev_sum_sq, old_price, period, dev_up
     """
     Calculate the next Bollinger Bands values incrementally.
 
+    Deprecated: the running sums lose precision for large values; use `bbands_inc_welford`.
+
     Args:
 
       price: The current price value.
@@ -575,6 +648,148 @@ def bbands_inc(price, prev_sma, prev_sum, prev_sum_sq, old_price, period, dev_up
     """
     ...
 
+def bbands_inc_welford(price, prev_sma, prev_mean, prev_m2, old_price, period, dev_up, dev_down):
+    """
+    Calculate the next Bollinger Bands values incrementally from the Welford state.
+
+    Args:
+
+      price: The current price value.
+      prev_sma: The previous SMA value.
+      prev_mean: The previous rolling mean for variance calculation.
+      prev_m2: The previous sum of squared deviations for variance calculation.
+      old_price: The oldest price value to be removed from the period.
+      period: The time period for calculations (must be >= 2).
+      dev_up: Number of standard deviations for upper band.
+      dev_down: Number of standard deviations for lower band.
+
+    Returns:
+      A tuple containing:
+      - Upper Band value
+      - Middle Band value
+      - Lower Band value
+      - New SMA value
+      - New rolling mean value
+      - New sum of squared deviations value
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> upper, middle, lower, sma, mean, m2 = kand.bbands_inc_welford(
+      ...     10.0,   # price
+      ...     9.5,    # prev_sma
+      ...     9.5,    # prev_mean
+      ...     0.5,    # prev_m2
+      ...     9.0,    # old_price
+      ...     3,      # period
+      ...     2.0,    # dev_up
+      ...     2.0     # dev_down
+      ... )
+      ```
+    """
+    ...
+
+def bbands_welford(price, period, dev_up, dev_down):
+    """
+    Calculate Bollinger Bands for a NumPy array, returning the Welford state.
+
+    Bollinger Bands consist of:
+    - A middle band (N-period simple moving average)
+    - An upper band (K standard deviations above middle band)
+    - A lower band (K standard deviations below middle band)
+
+    Args:
+      price: Input price values as a 1-D NumPy array of type `TAFloat`.
+      period: The time period for calculations (must be >= 2).
+      dev_up: Number of standard deviations for upper band.
+      dev_down: Number of standard deviations for lower band.
+
+    Returns:
+      A tuple of 7 1-D NumPy arrays containing:
+      - Upper band values
+      - Middle band values
+      - Lower band values
+      - SMA values
+      - Variance values
+      - Rolling mean values
+      - Rolling sum of squared deviations
+      The first (period-1) elements of each array contain NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> price = np.array([10.0, 11.0, 12.0, 13.0, 14.0])
+      >>> upper, middle, lower, sma, var, mean, m2 = kand.bbands_welford(price, 3, 2.0, 2.0)
+      ```
+    """
+    ...
+
+def beta(input0, input1, period=5):
+    """
+    Computes the TA-Lib compatible Beta of two price series.
+
+    Both price series are turned into simple returns, then Beta is the least-squares slope of the
+    returns of `input1` on the returns of `input0`.
+
+    Args:
+      input0: First price series (e.g. the market) as a 1-D NumPy array of type `TAFloat`.
+      input1: Second price series (e.g. the asset) as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window. Must be >= 1. Defaults to 5.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - Beta values
+      - Running sum of series 0 returns
+      - Running sum of series 1 returns
+      - Running sum of squared series 0 returns
+      - Running sum of return products
+      Each array has the same length as the input, with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> market = np.array([100.0, 101.0, 100.5, 102.0, 103.0, 102.5])
+      >>> asset = np.array([50.0, 51.0, 50.2, 51.8, 52.5, 52.0])
+      >>> beta, sum_x, sum_y, sum_xx, sum_xy = kand.beta(market, asset, 3)
+      ```
+    """
+    ...
+
+def beta_inc(price0, price1, prev_price0, prev_price1, old_price0, old_price1, old_prev_price0, old_prev_price1, prev_sum_x, prev_sum_y, prev_sum_xx, prev_sum_xy, period):
+    """
+    Computes the latest TA-Lib compatible Beta value incrementally.
+
+    Args:
+      price0: Newest price of series 0.
+      price1: Newest price of series 1.
+      prev_price0: Price of series 0 one bar back.
+      prev_price1: Price of series 1 one bar back.
+      old_price0: Price of series 0 `period` bars back.
+      old_price1: Price of series 1 `period` bars back.
+      old_prev_price0: Price of series 0 `period + 1` bars back.
+      old_prev_price1: Price of series 1 `period + 1` bars back.
+      prev_sum_x: Previous sum of series 0 returns.
+      prev_sum_y: Previous sum of series 1 returns.
+      prev_sum_xx: Previous sum of squared series 0 returns.
+      prev_sum_xy: Previous sum of return products.
+      period: Number of returns in the rolling window. Must be >= 1.
+
+    Returns:
+      A tuple containing (beta, sum_x, sum_y, sum_xx, sum_xy).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> beta, sum_x, sum_y, sum_xx, sum_xy = kand.beta_inc(
+      ...     102.5, 52.0, 103.0, 52.5, 100.5, 50.2, 101.0, 51.0,
+      ...     0.0198, 0.0297, 0.000343, 0.000686, 3
+      ... )
+      ```
+    """
+    ...
+
 def bop(open, high, low, close):
     """
     Calculate Balance of Power (BOP) indicator for NumPy arrays.
@@ -626,6 +841,108 @@ def bop_inc(open, high, low, close):
     """
     ...
 
+def calmar(returns, period, periods_per_year=252.0):
+    """
+    Computes the rolling Calmar ratio over a NumPy array of periodic returns.
+
+    Compound annual growth rate over the maximum drawdown of the equity curve built from the returns.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window. Must be >= 2.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      A 1-D NumPy array of Calmar ratio values, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+      >>> calmar = kand.calmar(returns, 3)
+      ```
+    """
+    ...
+
+def calmar_ratio(returns, periods_per_year=252.0):
+    """
+    Computes the Calmar ratio of an entire return series.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      The Calmar ratio as a float, or NaN when it is undefined.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+      >>> ratio = kand.calmar_ratio(returns)
+      ```
+    """
+    ...
+
+def capm_beta(returns, benchmark, period):
+    """
+    Computes the rolling CAPM Beta of asset returns against benchmark returns.
+
+    Args:
+      returns: Asset returns as a 1-D NumPy array of type `TAFloat`.
+      benchmark: Benchmark returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window. Must be >= 2.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - Beta values
+      - Running sum of asset returns
+      - Running sum of benchmark returns
+      - Running sum of squared benchmark returns
+      - Running sum of asset times benchmark returns
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.02, -0.01, 0.03, 0.01, -0.02])
+      >>> benchmark = np.array([0.01, -0.005, 0.015, 0.005, -0.01])
+      >>> beta, sum_ret, sum_bench, sum_bench_sq, sum_cross = kand.capm_beta(returns, benchmark, 3)
+      ```
+    """
+    ...
+
+def capm_beta_inc(ret, bench, old_ret, old_bench, prev_sum_ret, prev_sum_bench, prev_sum_bench_sq, prev_sum_cross, period):
+    """
+    Computes the latest rolling CAPM Beta value incrementally.
+
+    Args:
+      ret: Newest asset return to add.
+      bench: Newest benchmark return to add.
+      old_ret: Oldest asset return to remove.
+      old_bench: Oldest benchmark return to remove.
+      prev_sum_ret: Previous sum of asset returns.
+      prev_sum_bench: Previous sum of benchmark returns.
+      prev_sum_bench_sq: Previous sum of squared benchmark returns.
+      prev_sum_cross: Previous sum of asset times benchmark returns.
+      period: Number of returns in the rolling window. Must be >= 2.
+
+    Returns:
+      A tuple containing (beta, sum_ret, sum_bench, sum_bench_sq, sum_cross).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> beta, sum_ret, sum_bench, sum_bench_sq, sum_cross = kand.capm_beta_inc(
+      ...     0.01, 0.005, 0.02, 0.01, 0.04, 0.02, 0.00035, 0.0007, 3
+      ... )
+      ```
+    """
+    ...
+
 def cci(high, low, close, period):
     """
     Computes the Commodity Channel Index (CCI) over NumPy arrays.
@@ -698,6 +1015,63 @@ def cci_inc(prev_sma_tp, new_high, new_low, new_close, old_high, old_low, old_cl
     """
     ...
 
+def cdl_dark_cloud_cover(open, high, low, close, body_long_percent=60.0, penetration=0.5):
+    """
+    Detects Dark Cloud Cover candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.5.
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+        - -100: Dark Cloud Cover
+        - 0: No pattern detected
+        - -1: Fewer than two bars available
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([10.0, 12.5, 10.8, 12.9])
+      >>> high = np.array([12.1, 12.6, 12.8, 13.0])
+      >>> low = np.array([9.9, 10.5, 10.7, 12.0])
+      >>> close = np.array([12.0, 10.8, 12.7, 12.2])
+      >>> kand.cdl_dark_cloud_cover(open, high, low, close)
+      array([-1, -100, 0, 0])
+      ```
+    """
+    ...
+
+def cdl_dark_cloud_cover_inc(open, high, low, close, body_long_percent=60.0, penetration=0.5):
+    """
+    Detects a Dark Cloud Cover pattern on the last two candlesticks.
+
+    Args:
+
+      open: Opening prices of the last two candles, oldest first.
+      high: High prices of the last two candles, oldest first.
+      low: Low prices of the last two candles, oldest first.
+      close: Close prices of the last two candles, oldest first.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.5.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_dark_cloud_cover_inc([10.0, 12.5], [12.1, 12.6], [9.9, 10.5], [12.0, 10.8])
+      -100
+      ```
+    """
+    ...
+
 def cdl_doji(open, high, low, close, body_percent, shadow_equal_percent):
     """
     Detects Doji candlestick patterns in price data.
@@ -804,88 +1178,262 @@ def cdl_dragonfly_doji_inc(open, high, low, close, body_percent):
     """
     ...
 
-def cdl_gravestone_doji(open, high, low, close, body_percent):
+def cdl_engulfing(open, high, low, close):
     """
-    Detects Gravestone Doji candlestick patterns in price data.
+    Detects Engulfing candlestick patterns in price data.
 
     Args:
       open: Opening prices as a 1-D NumPy array of type `TAFloat`.
       high: High prices as a 1-D NumPy array of type `TAFloat`.
       low: Low prices as a 1-D NumPy array of type `TAFloat`.
       close: Close prices as a 1-D NumPy array of type `TAFloat`.
-      body_percent: Maximum body size as percentage of total range (typically 5%).
 
     Returns:
       A 1-D NumPy array containing pattern signals:
-      - -100: Bearish Gravestone Doji pattern detected
-      - 0: No pattern detected
+        - 100: Bullish Engulfing
+        - -100: Bearish Engulfing
+        - 0: No pattern detected
+        - -1: Fewer than two bars available
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> open = np.array([100.0, 101.0, 102.0])
-      >>> high = np.array([102.0, 103.0, 104.0])
-      >>> low = np.array([98.0, 99.0, 100.0])
-      >>> close = np.array([101.0, 102.0, 103.0])
-      >>> signals = kand.cdl_gravestone_doji(open, high, low, close, 5.0)
+      >>> open = np.array([10.0, 8.9, 11.0, 11.8])
+      >>> high = np.array([10.5, 11.2, 11.8, 12.0])
+      >>> low = np.array([8.8, 8.7, 10.8, 10.0])
+      >>> close = np.array([9.0, 11.0, 11.5, 10.2])
+      >>> kand.cdl_engulfing(open, high, low, close)
+      array([-1, 100, 0, -100])
       ```
     """
     ...
 
-def cdl_gravestone_doji_inc(open, high, low, close, body_percent):
+def cdl_engulfing_inc(open, high, low, close):
     """
-    Detects a Gravestone Doji pattern in a single candlestick.
+    Detects a Engulfing pattern on the last two candlesticks.
 
     Args:
 
-      open: Opening price.
-      high: High price.
-      low: Low price.
-      close: Close price.
-      body_percent: Maximum body size as percentage of total range.
+      open: Opening prices of the last two candles, oldest first.
+      high: High prices of the last two candles, oldest first.
+      low: Low prices of the last two candles, oldest first.
+      close: Close prices of the last two candles, oldest first.
 
     Returns:
-      Signal value:
-      - -100: Bearish Gravestone Doji pattern detected
-      - 0: No pattern detected
+      Signal value for the newest candle.
 
     Examples:
       ```python
       >>> import kand
-      >>> signal = kand.cdl_gravestone_doji_inc(100.0, 102.0, 98.0, 100.1, 5.0)
+      >>> kand.cdl_engulfing_inc([10.0, 8.9], [10.5, 11.2], [8.8, 8.7], [9.0, 11.0])
+      100
       ```
     """
     ...
 
-def cdl_hammer(open, high, low, close, period, factor):
+def cdl_evening_doji_star(open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0, body_short_percent=30.0, penetration=0.3):
     """
-    Detects Hammer candlestick patterns in price data.
+    Detects Evening Doji Star candlestick patterns in price data.
 
     Args:
       open: Opening prices as a 1-D NumPy array of type `TAFloat`.
       high: High prices as a 1-D NumPy array of type `TAFloat`.
       low: Low prices as a 1-D NumPy array of type `TAFloat`.
       close: Close prices as a 1-D NumPy array of type `TAFloat`.
-      period: Period for EMA calculation of body sizes.
-      factor: Minimum ratio of lower shadow to body length.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
 
     Returns:
-      A tuple of two 1-D NumPy arrays containing:
-      - Pattern signals:
-        - 100: Bullish Hammer pattern detected
+      A 1-D NumPy array containing pattern signals:
+        - -100: Evening Doji Star
         - 0: No pattern detected
-      - EMA values of candle body sizes
+        - -1: Fewer than three bars available
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> open = np.array([100.0, 101.0, 102.0])
-      >>> high = np.array([102.0, 103.0, 104.0])
-      >>> low = np.array([98.0, 99.0, 100.0])
-      >>> close = np.array([101.0, 102.0, 103.0])
-      >>> signals, body_avg = kand.cdl_hammer(open, high, low, close, 14, 2.0)
+      >>> open = np.array([10.0, 12.3, 12.2, 10.6, 11.8])
+      >>> high = np.array([12.1, 12.6, 12.3, 12.0, 11.9])
+      >>> low = np.array([9.9, 12.0, 10.4, 10.5, 11.0])
+      >>> close = np.array([12.0, 12.31, 10.5, 11.9, 11.1])
+      >>> kand.cdl_evening_doji_star(open, high, low, close)
+      array([-1, -1, -100, 0, 0])
+      ```
+    """
+    ...
+
+def cdl_evening_doji_star_inc(open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0, body_short_percent=30.0, penetration=0.3):
+    """
+    Detects a Evening Doji Star pattern on the last three candlesticks.
+
+    Args:
+
+      open: Opening prices of the last three candles, oldest first.
+      high: High prices of the last three candles, oldest first.
+      low: Low prices of the last three candles, oldest first.
+      close: Close prices of the last three candles, oldest first.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_evening_doji_star_inc([10.0, 12.3, 12.2], [12.1, 12.6, 12.3], [9.9, 12.0, 10.4], [12.0, 12.31, 10.5])
+      -100
+      ```
+    """
+    ...
+
+def cdl_evening_star(open, high, low, close, body_long_percent=60.0, body_short_percent=30.0, penetration=0.3):
+    """
+    Detects Evening Star candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+        - -100: Evening Star
+        - 0: No pattern detected
+        - -1: Fewer than three bars available
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([10.0, 12.3, 12.2, 10.6, 11.8])
+      >>> high = np.array([12.1, 12.6, 12.3, 12.0, 11.9])
+      >>> low = np.array([9.9, 12.0, 10.4, 10.5, 11.0])
+      >>> close = np.array([12.0, 12.4, 10.5, 11.9, 11.1])
+      >>> kand.cdl_evening_star(open, high, low, close)
+      array([-1, -1, -100, 0, 0])
+      ```
+    """
+    ...
+
+def cdl_evening_star_inc(open, high, low, close, body_long_percent=60.0, body_short_percent=30.0, penetration=0.3):
+    """
+    Detects a Evening Star pattern on the last three candlesticks.
+
+    Args:
+
+      open: Opening prices of the last three candles, oldest first.
+      high: High prices of the last three candles, oldest first.
+      low: Low prices of the last three candles, oldest first.
+      close: Close prices of the last three candles, oldest first.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_evening_star_inc([10.0, 12.3, 12.2], [12.1, 12.6, 12.3], [9.9, 12.0, 10.4], [12.0, 12.4, 10.5])
+      -100
+      ```
+    """
+    ...
+
+def cdl_gravestone_doji(open, high, low, close, body_percent):
+    """
+    Detects Gravestone Doji candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      body_percent: Maximum body size as percentage of total range (typically 5%).
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+      - -100: Bearish Gravestone Doji pattern detected
+      - 0: No pattern detected
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([100.0, 101.0, 102.0])
+      >>> high = np.array([102.0, 103.0, 104.0])
+      >>> low = np.array([98.0, 99.0, 100.0])
+      >>> close = np.array([101.0, 102.0, 103.0])
+      >>> signals = kand.cdl_gravestone_doji(open, high, low, close, 5.0)
+      ```
+    """
+    ...
+
+def cdl_gravestone_doji_inc(open, high, low, close, body_percent):
+    """
+    Detects a Gravestone Doji pattern in a single candlestick.
+
+    Args:
+
+      open: Opening price.
+      high: High price.
+      low: Low price.
+      close: Close price.
+      body_percent: Maximum body size as percentage of total range.
+
+    Returns:
+      Signal value:
+      - -100: Bearish Gravestone Doji pattern detected
+      - 0: No pattern detected
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> signal = kand.cdl_gravestone_doji_inc(100.0, 102.0, 98.0, 100.1, 5.0)
+      ```
+    """
+    ...
+
+def cdl_hammer(open, high, low, close, period, factor):
+    """
+    Detects Hammer candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for EMA calculation of body sizes.
+      factor: Minimum ratio of lower shadow to body length.
+
+    Returns:
+      A tuple of two 1-D NumPy arrays containing:
+      - Pattern signals:
+        - 100: Bullish Hammer pattern detected
+        - 0: No pattern detected
+      - EMA values of candle body sizes
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([100.0, 101.0, 102.0])
+      >>> high = np.array([102.0, 103.0, 104.0])
+      >>> low = np.array([98.0, 99.0, 100.0])
+      >>> close = np.array([101.0, 102.0, 103.0])
+      >>> signals, body_avg = kand.cdl_hammer(open, high, low, close, 14, 2.0)
       ```
     """
     ...
@@ -919,6 +1467,122 @@ def cdl_hammer_inc(open, high, low, close, prev_body_avg, period, factor):
     """
     ...
 
+def cdl_harami(open, high, low, close, body_long_percent=60.0, body_short_percent=30.0):
+    """
+    Detects Harami candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+        - 100: Harami after a long black candle
+        - -100: Harami after a long white candle
+        - 0: No pattern detected
+        - -1: Fewer than two bars available
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([10.0, 8.5, 8.8, 10.2, 10.0])
+      >>> high = np.array([10.2, 9.3, 11.1, 10.9, 10.6])
+      >>> low = np.array([7.8, 8.1, 8.7, 9.9, 9.6])
+      >>> close = np.array([8.0, 8.8, 11.0, 10.0, 10.5])
+      >>> kand.cdl_harami(open, high, low, close)
+      array([-1, 100, 0, -100, 0])
+      ```
+    """
+    ...
+
+def cdl_harami_cross(open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0):
+    """
+    Detects Harami Cross candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+        - 100: Harami Cross after a long black candle
+        - -100: Harami Cross after a long white candle
+        - 0: No pattern detected
+        - -1: Fewer than two bars available
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([10.0, 9.0, 9.0, 10.0, 10.0])
+      >>> high = np.array([10.2, 9.4, 11.1, 10.5, 10.6])
+      >>> low = np.array([7.8, 8.6, 8.9, 9.5, 9.6])
+      >>> close = np.array([8.0, 9.02, 11.0, 10.01, 10.5])
+      >>> kand.cdl_harami_cross(open, high, low, close)
+      array([-1, 100, 0, -100, 0])
+      ```
+    """
+    ...
+
+def cdl_harami_cross_inc(open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0):
+    """
+    Detects a Harami Cross pattern on the last two candlesticks.
+
+    Args:
+
+      open: Opening prices of the last two candles, oldest first.
+      high: High prices of the last two candles, oldest first.
+      low: Low prices of the last two candles, oldest first.
+      close: Close prices of the last two candles, oldest first.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_harami_cross_inc([10.0, 9.0], [10.2, 9.4], [7.8, 8.6], [8.0, 9.02])
+      100
+      ```
+    """
+    ...
+
+def cdl_harami_inc(open, high, low, close, body_long_percent=60.0, body_short_percent=30.0):
+    """
+    Detects a Harami pattern on the last two candlesticks.
+
+    Args:
+
+      open: Opening prices of the last two candles, oldest first.
+      high: High prices of the last two candles, oldest first.
+      low: Low prices of the last two candles, oldest first.
+      close: Close prices of the last two candles, oldest first.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_harami_inc([10.0, 8.5], [10.2, 9.3], [7.8, 8.1], [8.0, 8.8])
+      100
+      ```
+    """
+    ...
+
 def cdl_inverted_hammer(open, high, low, close, period, factor):
     """
     Detects Inverted Hammer candlestick patterns in price data.
@@ -1106,99 +1770,837 @@ def cdl_marubozu_inc(open, high, low, close, prev_body_avg, period, shadow_perce
     """
     ...
 
-def dema(input_price, period):
+def cdl_morning_doji_star(open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0, body_short_percent=30.0, penetration=0.3):
     """
-    Calculates Double Exponential Moving Average (DEMA) over NumPy arrays.
+    Detects Morning Doji Star candlestick patterns in price data.
 
     Args:
-      input_price: Price values as a 1-D NumPy array of type `TAFloat`.
-      period: Smoothing period for EMA calculations. Must be >= 2.
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
 
     Returns:
-      A tuple of 1-D NumPy arrays containing:
-      - DEMA values
-      - First EMA values
-      - Second EMA values
-      Each array has the same length as the input, with the first `2*(period-1)` elements containing NaN values.
+      A 1-D NumPy array containing pattern signals:
+        - 100: Morning Doji Star
+        - 0: No pattern detected
+        - -1: Fewer than three bars available
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> prices = np.array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0])
-      >>> dema, ema1, ema2 = kand.dema(prices, 3)
+      >>> open = np.array([12.0, 9.6, 9.8, 11.4, 10.2])
+      >>> high = np.array([12.1, 9.9, 11.6, 11.5, 11.0])
+      >>> low = np.array([9.9, 9.3, 9.7, 10.0, 10.1])
+      >>> close = np.array([10.0, 9.61, 11.5, 10.1, 10.9])
+      >>> kand.cdl_morning_doji_star(open, high, low, close)
+      array([-1, -1, 100, 0, 0])
       ```
     """
     ...
 
-def dema_inc(price, prev_ema1, prev_ema2, period):
+def cdl_morning_doji_star_inc(open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0, body_short_percent=30.0, penetration=0.3):
     """
-    Calculates the next DEMA value incrementally.
+    Detects a Morning Doji Star pattern on the last three candlesticks.
 
     Args:
 
-      price: Current price value.
-      prev_ema1: Previous value of first EMA.
-      prev_ema2: Previous value of second EMA.
-      period: Smoothing period. Must be >= 2.
+      open: Opening prices of the last three candles, oldest first.
+      high: High prices of the last three candles, oldest first.
+      low: Low prices of the last three candles, oldest first.
+      close: Close prices of the last three candles, oldest first.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
 
     Returns:
-      A tuple containing (DEMA, new_ema1, new_ema2).
+      Signal value for the newest candle.
 
     Examples:
       ```python
       >>> import kand
-      >>> dema, ema1, ema2 = kand.dema_inc(10.0, 9.5, 9.0, 3)
+      >>> kand.cdl_morning_doji_star_inc([12.0, 9.6, 9.8], [12.1, 9.9, 11.6], [9.9, 9.3, 9.7], [10.0, 9.61, 11.5])
+      100
       ```
     """
     ...
 
-def dx(high, low, close, period):
+def cdl_morning_star(open, high, low, close, body_long_percent=60.0, body_short_percent=30.0, penetration=0.3):
     """
-    Computes the Directional Movement Index (DX) over NumPy arrays.
-
-    The DX indicator measures the strength of a trend by comparing positive and negative directional movements.
+    Detects Morning Star candlestick patterns in price data.
 
     Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
       high: High prices as a 1-D NumPy array of type `TAFloat`.
       low: Low prices as a 1-D NumPy array of type `TAFloat`.
       close: Close prices as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for DX calculation. Must be positive and less than input length.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
 
     Returns:
-      A tuple of four 1-D NumPy arrays containing:
-      - DX values
-      - Smoothed +DM values
-      - Smoothed -DM values
-      - Smoothed TR values
-      Each array has the same length as the input, with the first `period` elements containing NaN values.
+      A 1-D NumPy array containing pattern signals:
+        - 100: Morning Star
+        - 0: No pattern detected
+        - -1: Fewer than three bars available
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> high = np.array([24.20, 24.07, 24.04, 23.87, 23.67])
-      >>> low = np.array([23.85, 23.72, 23.64, 23.37, 23.46])
-      >>> close = np.array([23.89, 23.95, 23.67, 23.78, 23.50])
-      >>> dx, plus_dm, minus_dm, tr = kand.dx(high, low, close, 3)
+      >>> open = np.array([12.0, 9.6, 9.8, 11.4, 10.2])
+      >>> high = np.array([12.1, 9.9, 11.6, 11.5, 11.0])
+      >>> low = np.array([9.9, 9.3, 9.7, 10.0, 10.1])
+      >>> close = np.array([10.0, 9.7, 11.5, 10.1, 10.9])
+      >>> kand.cdl_morning_star(open, high, low, close)
+      array([-1, -1, 100, 0, 0])
       ```
     """
     ...
 
-def dx_inc(input_high, input_low, prev_high, prev_low, prev_close, prev_smoothed_plus_dm, prev_smoothed_minus_dm, prev_smoothed_tr, opt_period):
+def cdl_morning_star_inc(open, high, low, close, body_long_percent=60.0, body_short_percent=30.0, penetration=0.3):
     """
-    Calculates the latest DX value incrementally.
+    Detects a Morning Star pattern on the last three candlesticks.
 
-    Computes only the most recent DX value using previous smoothed values.
-    Optimized for real-time calculations where only the latest value is needed.
+    Args:
 
-    For the formula, refer to the [`dx`] function documentation.
+      open: Opening prices of the last three candles, oldest first.
+      high: High prices of the last three candles, oldest first.
+      low: Low prices of the last three candles, oldest first.
+      close: Close prices of the last three candles, oldest first.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+      penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
 
-    Args:
-        input_high (float): Current high price.
-        input_low (float): Current low price.
-        prev_high (float): Previous period's high price.
-        prev_low (float): Previous period's low price.
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_morning_star_inc([12.0, 9.6, 9.8], [12.1, 9.9, 11.6], [9.9, 9.3, 9.7], [10.0, 9.7, 11.5])
+      100
+      ```
+    """
+    ...
+
+def cdl_piercing(open, high, low, close, body_long_percent=60.0):
+    """
+    Detects Piercing candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+        - 100: Piercing pattern
+        - 0: No pattern detected
+        - -1: Fewer than two bars available
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([12.0, 9.5, 11.5, 9.0])
+      >>> high = np.array([12.1, 11.6, 12.0, 10.8])
+      >>> low = np.array([9.9, 9.4, 9.0, 8.9])
+      >>> close = np.array([10.0, 11.4, 9.2, 10.7])
+      >>> kand.cdl_piercing(open, high, low, close)
+      array([-1, 100, 0, 0])
+      ```
+    """
+    ...
+
+def cdl_piercing_inc(open, high, low, close, body_long_percent=60.0):
+    """
+    Detects a Piercing pattern on the last two candlesticks.
+
+    Args:
+
+      open: Opening prices of the last two candles, oldest first.
+      high: High prices of the last two candles, oldest first.
+      low: Low prices of the last two candles, oldest first.
+      close: Close prices of the last two candles, oldest first.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_piercing_inc([12.0, 9.5], [12.1, 11.6], [9.9, 9.4], [10.0, 11.4])
+      100
+      ```
+    """
+    ...
+
+def cdl_scan(open, high, low, close):
+    """
+    Scans price data for every supported candlestick pattern in one pass.
+
+    Patterns are judged with TA-Lib's default candle settings. Each output array holds one
+    bitmask per bar; bit `1 << id` is set when the pattern with that id matched:
+
+      0: Dark Cloud Cover, 1: Doji, 2: Dragonfly Doji, 3: Engulfing, 4: Evening Doji Star,
+      5: Evening Star, 6: Gravestone Doji, 7: Hammer, 8: Harami, 9: Harami Cross,
+      10: Inverted Hammer, 11: Long Shadow, 12: Marubozu, 13: Morning Doji Star,
+      14: Morning Star, 15: Piercing, 16: Three Black Crows, 17: Three Inside,
+      18: Three Outside, 19: Three White Soldiers
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays of type `uint32`:
+      - Patterns that signalled bullish (100)
+      - Patterns that signalled bearish (-100)
+      - Non-directional patterns (1), such as Doji
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([10.0] * 12 + [9.6])
+      >>> high = np.array([10.5] * 12 + [9.68])
+      >>> low = np.array([9.5] * 12 + [9.0])
+      >>> close = np.array([10.2] * 12 + [9.65])
+      >>> bullish, bearish, pattern = kand.cdl_scan(open, high, low, close)
+      >>> bool(bullish[12] & (1 << 7))  # Hammer
+      True
+      ```
+    """
+    ...
+
+def cdl_three_black_crows(open, high, low, close, shadow_very_short_percent=10.0, body_short_percent=30.0):
+    """
+    Detects Three Black Crows candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      shadow_very_short_percent: Maximum shadow length of a very short shadow as a percentage of the candle range. Defaults to 10.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+        - -100: Three Black Crows
+        - 0: No pattern detected
+        - -1: Fewer than three bars available
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([13.0, 12.2, 11.2, 10.0])
+      >>> high = np.array([13.1, 12.3, 11.3, 10.1])
+      >>> low = np.array([11.95, 10.95, 9.95, 9.0])
+      >>> close = np.array([12.0, 11.0, 10.0, 9.8])
+      >>> kand.cdl_three_black_crows(open, high, low, close)
+      array([-1, -1, -100, 0])
+      ```
+    """
+    ...
+
+def cdl_three_black_crows_inc(open, high, low, close, shadow_very_short_percent=10.0, body_short_percent=30.0):
+    """
+    Detects a Three Black Crows pattern on the last three candlesticks.
+
+    Args:
+
+      open: Opening prices of the last three candles, oldest first.
+      high: High prices of the last three candles, oldest first.
+      low: Low prices of the last three candles, oldest first.
+      close: Close prices of the last three candles, oldest first.
+      shadow_very_short_percent: Maximum shadow length of a very short shadow as a percentage of the candle range. Defaults to 10.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_three_black_crows_inc([13.0, 12.2, 11.2], [13.1, 12.3, 11.3], [11.95, 10.95, 9.95], [12.0, 11.0, 10.0])
+      -100
+      ```
+    """
+    ...
+
+def cdl_three_inside(open, high, low, close, body_long_percent=60.0, body_short_percent=30.0):
+    """
+    Detects Three Inside Up/Down candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+        - 100: Three Inside Up
+        - -100: Three Inside Down
+        - 0: No pattern detected
+        - -1: Fewer than three bars available
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([10.0, 8.5, 8.9, 10.4, 9.2])
+      >>> high = np.array([10.2, 9.3, 10.6, 10.5, 9.5])
+      >>> low = np.array([7.8, 8.1, 8.8, 9.0, 9.0])
+      >>> close = np.array([8.0, 8.8, 10.5, 9.1, 9.4])
+      >>> kand.cdl_three_inside(open, high, low, close)
+      array([-1, -1, 100, 0, 0])
+      ```
+    """
+    ...
+
+def cdl_three_inside_inc(open, high, low, close, body_long_percent=60.0, body_short_percent=30.0):
+    """
+    Detects a Three Inside Up/Down pattern on the last three candlesticks.
+
+    Args:
+
+      open: Opening prices of the last three candles, oldest first.
+      high: High prices of the last three candles, oldest first.
+      low: Low prices of the last three candles, oldest first.
+      close: Close prices of the last three candles, oldest first.
+      body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_three_inside_inc([10.0, 8.5, 8.9], [10.2, 9.3, 10.6], [7.8, 8.1, 8.8], [8.0, 8.8, 10.5])
+      100
+      ```
+    """
+    ...
+
+def cdl_three_outside(open, high, low, close):
+    """
+    Detects Three Outside Up/Down candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+        - 100: Three Outside Up
+        - -100: Three Outside Down
+        - 0: No pattern detected
+        - -1: Fewer than three bars available
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([10.0, 8.9, 11.0, 11.5, 10.1])
+      >>> high = np.array([10.5, 11.2, 11.8, 11.6, 10.2])
+      >>> low = np.array([8.8, 8.7, 10.8, 10.0, 9.5])
+      >>> close = np.array([9.0, 11.0, 11.5, 10.2, 9.6])
+      >>> kand.cdl_three_outside(open, high, low, close)
+      array([-1, -1, 100, 0, -100])
+      ```
+    """
+    ...
+
+def cdl_three_outside_inc(open, high, low, close):
+    """
+    Detects a Three Outside Up/Down pattern on the last three candlesticks.
+
+    Args:
+
+      open: Opening prices of the last three candles, oldest first.
+      high: High prices of the last three candles, oldest first.
+      low: Low prices of the last three candles, oldest first.
+      close: Close prices of the last three candles, oldest first.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_three_outside_inc([10.0, 8.9, 11.0], [10.5, 11.2, 11.8], [8.8, 8.7, 10.8], [9.0, 11.0, 11.5])
+      100
+      ```
+    """
+    ...
+
+def cdl_three_white_soldiers(open, high, low, close, shadow_very_short_percent=10.0, body_short_percent=30.0):
+    """
+    Detects Three White Soldiers candlestick patterns in price data.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      shadow_very_short_percent: Maximum shadow length of a very short shadow as a percentage of the candle range. Defaults to 10.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+
+    Returns:
+      A 1-D NumPy array containing pattern signals:
+        - 100: Three White Soldiers
+        - 0: No pattern detected
+        - -1: Fewer than three bars available
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open = np.array([10.0, 10.8, 11.8, 13.0])
+      >>> high = np.array([11.05, 12.05, 13.05, 14.0])
+      >>> low = np.array([9.9, 10.7, 11.7, 12.9])
+      >>> close = np.array([11.0, 12.0, 13.0, 13.2])
+      >>> kand.cdl_three_white_soldiers(open, high, low, close)
+      array([-1, -1, 100, 0])
+      ```
+    """
+    ...
+
+def cdl_three_white_soldiers_inc(open, high, low, close, shadow_very_short_percent=10.0, body_short_percent=30.0):
+    """
+    Detects a Three White Soldiers pattern on the last three candlesticks.
+
+    Args:
+
+      open: Opening prices of the last three candles, oldest first.
+      high: High prices of the last three candles, oldest first.
+      low: Low prices of the last three candles, oldest first.
+      close: Close prices of the last three candles, oldest first.
+      shadow_very_short_percent: Maximum shadow length of a very short shadow as a percentage of the candle range. Defaults to 10.0.
+      body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+
+    Returns:
+      Signal value for the newest candle.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.cdl_three_white_soldiers_inc([10.0, 10.8, 11.8], [11.05, 12.05, 13.05], [9.9, 10.7, 11.7], [11.0, 12.0, 13.0])
+      100
+      ```
+    """
+    ...
+
+def cornish_fisher_var(returns, period, confidence=0.95):
+    """
+    Computes the rolling Cornish-Fisher (modified) Value-at-Risk of a return series.
+
+    Corrects the Gaussian quantile for the skewness and excess kurtosis of the window, so fat left
+    tails raise the VaR above its Gaussian value.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window (must be >= 4).
+      confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - Value-at-Risk
+      - Rolling mean values
+      - Rolling sum of squared deviations from the mean
+      - Rolling sum of cubed deviations from the mean
+      - Rolling sum of fourth-power deviations from the mean
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.01, 0.01, -0.01])
+      >>> var, mean, m2, m3, m4 = kand.cornish_fisher_var(returns, 4)
+      ```
+    """
+    ...
+
+def cornish_fisher_var_inc(ret, prev_mean, prev_m2, prev_m3, prev_m4, old_ret, period, confidence=0.95):
+    """
+    Computes the latest rolling Cornish-Fisher Value-at-Risk incrementally.
+
+    Args:
+      ret: Newest return.
+      prev_mean: Previous rolling mean.
+      prev_m2: Previous rolling sum of squared deviations from the mean.
+      prev_m3: Previous rolling sum of cubed deviations from the mean.
+      prev_m4: Previous rolling sum of fourth-power deviations from the mean.
+      old_ret: Return leaving the window.
+      period: Number of returns in the rolling window (must be >= 4).
+      confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+
+    Returns:
+      A tuple containing (var, mean, m2, m3, m4).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> var, mean, m2, m3, m4 = kand.cornish_fisher_var_inc(
+      ...     0.01, 0.005, 0.0011, 0.000009, 0.0000004925, 0.03, 4
+      ... )
+      ```
+    """
+    ...
+
+def correl(input0, input1, period):
+    """
+    Calculate Pearson's Correlation Coefficient between two NumPy arrays
+
+    The Pearson Correlation Coefficient measures the linear correlation between two variables,
+    returning a value between -1 and +1, where:
+    - +1 indicates perfect positive correlation
+    - -1 indicates perfect negative correlation
+    - 0 indicates no linear correlation
+
+    Args:
+      input0: First input series as a 1-D NumPy array of type `TAFloat`.
+      input1: Second input series as a 1-D NumPy array of type `TAFloat`.
+      period: Period for calculation (must be >= 2).
+
+    Returns:
+      A tuple of six 1-D NumPy arrays containing:
+      - Correlation coefficient values
+      - Running sum of series 0
+      - Running sum of series 1
+      - Running sum of squares of series 0
+      - Running sum of squares of series 1
+      - Running sum of products
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> series1 = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+      >>> series2 = np.array([2.0, 4.0, 6.0, 8.0, 10.0])
+      >>> correl, sum0, sum1, sum0_sq, sum1_sq, sum01 = kand.correl(series1, series2, 3)
+      ```
+    """
+    ...
+
+def correl_inc(new0, new1, old0, old1, prev_sum0, prev_sum1, prev_sum0_sq, prev_sum1_sq, prev_sum01, period):
+    """
+    Calculate the latest Correlation value incrementally
+
+    Deprecated: the running sums lose precision for large values; use `correl_inc_welford`.
+
+    This function provides an optimized way to update the Correlation value when new data arrives,
+    avoiding full recalculation of the entire series.
+
+    Args:
+      new0: The newest value from series 0 to add
+      new1: The newest value from series 1 to add
+      old0: The oldest value from series 0 to remove
+      old1: The oldest value from series 1 to remove
+      prev_sum0: Previous sum of series 0
+      prev_sum1: Previous sum of series 1
+      prev_sum0_sq: Previous sum of squares of series 0
+      prev_sum1_sq: Previous sum of squares of series 1
+      prev_sum01: Previous sum of products
+      period: Period for calculation (must be >= 2)
+
+    Returns:
+      A tuple containing:
+      - New correlation value
+      - New sum of series 0
+      - New sum of series 1
+      - New sum of squares of series 0
+      - New sum of squares of series 1
+      - New sum of products
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> correl, sum0, sum1, sum0_sq, sum1_sq, sum01 = kand.correl_inc(
+      ...     4.0,    # new value for series 0
+      ...     8.0,    # new value for series 1
+      ...     1.0,    # old value for series 0
+      ...     2.0,    # old value for series 1
+      ...     6.0,    # previous sum of series 0
+      ...     12.0,   # previous sum of series 1
+      ...     14.0,   # previous sum of squares of series 0
+      ...     56.0,   # previous sum of squares of series 1
+      ...     28.0,   # previous sum of products
+      ...     3       # period
+      ... )
+      ```
+    """
+    ...
+
+def correl_inc_welford(new0, new1, old0, old1, prev_mean0, prev_mean1, prev_m2_0, prev_m2_1, prev_c01, period):
+    """
+    Calculate the latest Correlation value incrementally from the Welford state
+
+    This function provides an optimized way to update the Correlation value when new data arrives,
+    avoiding full recalculation of the entire series.
+
+    Args:
+      new0: The newest value from series 0 to add
+      new1: The newest value from series 1 to add
+      old0: The oldest value from series 0 to remove
+      old1: The oldest value from series 1 to remove
+      prev_mean0: Previous mean of series 0
+      prev_mean1: Previous mean of series 1
+      prev_m2_0: Previous sum of squared deviations of series 0
+      prev_m2_1: Previous sum of squared deviations of series 1
+      prev_c01: Previous sum of co-deviations
+      period: Period for calculation (must be >= 2)
+
+    Returns:
+      A tuple containing:
+      - New correlation value
+      - New mean of series 0
+      - New mean of series 1
+      - New sum of squared deviations of series 0
+      - New sum of squared deviations of series 1
+      - New sum of co-deviations
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> correl, mean0, mean1, m2_0, m2_1, c01 = kand.correl_inc_welford(
+      ...     4.0,    # new value for series 0
+      ...     8.0,    # new value for series 1
+      ...     1.0,    # old value for series 0
+      ...     2.0,    # old value for series 1
+      ...     2.0,    # previous mean of series 0
+      ...     4.0,    # previous mean of series 1
+      ...     2.0,    # previous sum of squared deviations of series 0
+      ...     8.0,    # previous sum of squared deviations of series 1
+      ...     4.0,    # previous sum of co-deviations
+      ...     3       # period
+      ... )
+      ```
+    """
+    ...
+
+def correl_welford(input0, input1, period):
+    """
+    Calculate Pearson's Correlation Coefficient, returning the Welford state
+
+    The Pearson Correlation Coefficient measures the linear correlation between two variables,
+    returning a value between -1 and +1, where:
+    - +1 indicates perfect positive correlation
+    - -1 indicates perfect negative correlation
+    - 0 indicates no linear correlation
+
+    Args:
+      input0: First input series as a 1-D NumPy array of type `TAFloat`.
+      input1: Second input series as a 1-D NumPy array of type `TAFloat`.
+      period: Period for calculation (must be >= 2).
+
+    Returns:
+      A tuple of six 1-D NumPy arrays containing:
+      - Correlation coefficient values
+      - Rolling mean of series 0
+      - Rolling mean of series 1
+      - Rolling sum of squared deviations of series 0
+      - Rolling sum of squared deviations of series 1
+      - Rolling sum of co-deviations
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> series1 = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+      >>> series2 = np.array([2.0, 4.0, 6.0, 8.0, 10.0])
+      >>> correl, mean0, mean1, m2_0, m2_1, c01 = kand.correl_welford(series1, series2, 3)
+      ```
+    """
+    ...
+
+def cumulative_returns(data):
+    """
+    Computes cumulative returns of a NumPy array of prices.
+
+    Each value is the relative change from the first price. The first value is 0.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array of cumulative returns, all NaN if the first price is zero.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([100.0, 110.0, 121.0])
+      >>> returns = kand.cumulative_returns(prices)
+      ```
+    """
+    ...
+
+def cumulative_returns_inc(price, base_price):
+    """
+    Calculates a single cumulative return incrementally.
+
+    Args:
+      price: The current price.
+      base_price: The first price of the series.
+
+    Returns:
+      The cumulative return, or NaN if the base price is zero.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> value = kand.cumulative_returns_inc(121.0, 100.0)
+      ```
+    """
+    ...
+
+def dema(input_price, period):
+    """
+    Calculates Double Exponential Moving Average (DEMA) over NumPy arrays.
+
+    Args:
+      input_price: Price values as a 1-D NumPy array of type `TAFloat`.
+      period: Smoothing period for EMA calculations. Must be >= 2.
+
+    Returns:
+      A tuple of 1-D NumPy arrays containing:
+      - DEMA values
+      - First EMA values
+      - Second EMA values
+      Each array has the same length as the input, with the first `2*(period-1)` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0])
+      >>> dema, ema1, ema2 = kand.dema(prices, 3)
+      ```
+    """
+    ...
+
+def dema_inc(price, prev_ema1, prev_ema2, period):
+    """
+    Calculates the next DEMA value incrementally.
+
+    Args:
+
+      price: Current price value.
+      prev_ema1: Previous value of first EMA.
+      prev_ema2: Previous value of second EMA.
+      period: Smoothing period. Must be >= 2.
+
+    Returns:
+      A tuple containing (DEMA, new_ema1, new_ema2).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> dema, ema1, ema2 = kand.dema_inc(10.0, 9.5, 9.0, 3)
+      ```
+    """
+    ...
+
+def donchian(high, low, period):
+    """
+    Calculates Donchian Channels for NumPy arrays.
+
+    Donchian Channels bracket price with the highest high and the lowest low of the last `period`
+    bars, with a middle channel halfway between them.
+
+    Args:
+      high: Input high price data as a 1-D NumPy array of type `TAFloat`.
+      low: Input low price data as a 1-D NumPy array of type `TAFloat`.
+      period: Time period for calculation (must be >= 2).
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Upper channel values (highest high)
+      - Middle channel values
+      - Lower channel values (lowest low)
+      Each array has the same length as the input, with the first `period-1` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+      >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+      >>> upper, middle, lower = kand.donchian(high, low, 3)
+      ```
+    """
+    ...
+
+def dx(high, low, close, period):
+    """
+    Computes the Directional Movement Index (DX) over NumPy arrays.
+
+    The DX indicator measures the strength of a trend by comparing positive and negative directional movements.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for DX calculation. Must be positive and less than input length.
+
+    Returns:
+      A tuple of four 1-D NumPy arrays containing:
+      - DX values
+      - Smoothed +DM values
+      - Smoothed -DM values
+      - Smoothed TR values
+      Each array has the same length as the input, with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([24.20, 24.07, 24.04, 23.87, 23.67])
+      >>> low = np.array([23.85, 23.72, 23.64, 23.37, 23.46])
+      >>> close = np.array([23.89, 23.95, 23.67, 23.78, 23.50])
+      >>> dx, plus_dm, minus_dm, tr = kand.dx(high, low, close, 3)
+      ```
+    """
+    ...
+
+def dx_inc(input_high, input_low, prev_high, prev_low, prev_close, prev_smoothed_plus_dm, prev_smoothed_minus_dm, prev_smoothed_tr, opt_period):
+    """
+    Calculates the latest DX value incrementally.
+
+    Computes only the most recent DX value using previous smoothed values.
+    Optimized for real-time calculations where only the latest value is needed.
+
+    For the formula, refer to the [`dx`] function documentation.
+
+    Args:
+        input_high (float): Current high price.
+        input_low (float): Current low price.
+        prev_high (float): Previous period's high price.
+        prev_low (float): Previous period's low price.
         prev_close (float): Previous period's close price.
         prev_smoothed_plus_dm (float): Previous smoothed +DM value.
         prev_smoothed_minus_dm (float): Previous smoothed -DM value.
@@ -1206,133 +2608,1229 @@ def dx_inc(input_high, input_low, prev_high, prev_low, prev_close, prev_smoothed
         opt_period (int): Period for DX calculation (typically 14).
 
     Returns:
-        tuple: A tuple containing:
-            - Latest DX value (float)
-            - New smoothed +DM (float)
-            - New smoothed -DM (float)
-            - New smoothed TR (float)
+        tuple: A tuple containing:
+            - Latest DX value (float)
+            - New smoothed +DM (float)
+            - New smoothed -DM (float)
+            - New smoothed TR (float)
+
+    Example:
+        >>> import kand
+        >>> high, low = 24.20, 23.85
+        >>> prev_high, prev_low, prev_close = 24.07, 23.72, 23.95
+        >>> prev_smoothed_plus_dm = 0.5
+        >>> prev_smoothed_minus_dm = 0.3
+        >>> prev_smoothed_tr = 1.2
+        >>> period = 14
+        >>> dx, plus_dm, minus_dm, tr = kand.dx_inc(
+        ...     high, low, prev_high, prev_low, prev_close,
+        ...     prev_smoothed_plus_dm, prev_smoothed_minus_dm,
+        ...     prev_smoothed_tr, period)
+    """
+    ...
+
+def drawdown(equity):
+    """
+    Computes drawdown analytics over a NumPy array of equity values.
+
+    Percentage drop from the running peak, deepest drop so far, bars under water, longest stretch
+    under water, and bars from trough to recovery on each bar that regains the peak.
+
+    Args:
+      equity: Equity values as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays:
+      - Drawdown percentage
+      - Maximum drawdown percentage so far
+      - Bars since the last peak
+      - Longest drawdown duration so far
+      - Bars from trough to recovery (non-zero only on the bar that regains the peak)
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> equity = np.array([100.0, 90.0, 80.0, 95.0, 105.0])
+      >>> dd, max_dd, duration, max_duration, recovery = kand.drawdown(equity)
+      ```
+    """
+    ...
+
+def ecl(high, low, close):
+    """
+    Computes the Expanded Camarilla Levels (ECL) over NumPy arrays.
+
+    The ECL indicator calculates multiple support and resistance levels based on the previous period's
+    high, low and close prices.
+
+    Args:
+      high: Input high prices as a 1-D NumPy array of type `TAFloat`.
+      low: Input low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Input close prices as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A tuple of ten 1-D NumPy arrays containing the ECL values (H5,H4,H3,H2,H1,L1,L2,L3,L4,L5).
+      Each array has the same length as the input, with the first element containing NaN value.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([24.20, 24.07, 24.04, 23.87, 23.67])
+      >>> low = np.array([23.85, 23.72, 23.64, 23.37, 23.46])
+      >>> close = np.array([23.89, 23.95, 23.67, 23.78, 23.50])
+      >>> h5,h4,h3,h2,h1,l1,l2,l3,l4,l5 = kand.ecl(high, low, close)
+      ```
+    """
+    ...
+
+def ecl_inc(prev_high, prev_low, prev_close):
+    """
+    Computes the latest Expanded Camarilla Levels (ECL) values incrementally.
+
+    This function provides an efficient way to calculate ECL values for new data without
+    reprocessing the entire dataset.
+
+    Args:
+
+      prev_high: Previous period's high price as `TAFloat`.
+      prev_low: Previous period's low price as `TAFloat`.
+      prev_close: Previous period's close price as `TAFloat`.
+
+    Returns:
+      A tuple of ten values (H5,H4,H3,H2,H1,L1,L2,L3,L4,L5) containing the latest ECL levels.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> h5,h4,h3,h2,h1,l1,l2,l3,l4,l5 = kand.ecl_inc(24.20, 23.85, 23.89)
+      ```
+    """
+    ...
+
+def ema(data, period, k=None):
+    """
+    Computes the Exponential Moving Average (EMA) over a NumPy array.
+
+    The Exponential Moving Average is calculated by applying more weight to recent prices
+    via a smoothing factor k. Each value is calculated as:
+    EMA = Price * k + EMA(previous) * (1 - k)
+    where k is typically 2/(period+1).
+
+    Args:
+      data: Input data as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for EMA calculation. Must be positive and less than input length.
+      k: Optional custom smoothing factor. If None, uses default k = 2/(period+1).
+
+    Returns:
+      A new 1-D NumPy array containing the EMA values. The array has the same length as the input,
+      with the first `period-1` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+      >>> result = kand.ema(data, 3)
+      >>> print(result)
+      [nan, nan, 2.0, 3.0, 4.2]
+      ```
+    """
+    ...
+
+def ema_inc(price, prev_ema, period, k=None):
+    """
+    Computes the latest EMA value incrementally.
+
+    This function provides an efficient way to calculate EMA values for new data without
+    reprocessing the entire dataset.
+
+    Args:
+
+      price: Current period's price value as `TAFloat`.
+      prev_ema: Previous period's EMA value as `TAFloat`.
+      period: Window size for EMA calculation. Must be >= 2.
+      k: Optional custom smoothing factor. If None, uses default k = 2/(period+1).
+
+    Returns:
+      The new EMA value as `TAFloat`.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> current_price = 15.0
+      >>> prev_ema = 14.5
+      >>> period = 14
+      >>> new_ema = kand.ema_inc(current_price, prev_ema, period)
+      ```
+    """
+    ...
+
+def excess_returns(returns, risk_free):
+    """
+    Computes excess returns over a risk-free series.
+
+    Both series must use the same periodicity. NaN inputs propagate to the output.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      risk_free: Risk-free returns for the same periods as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array of excess returns.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([np.nan, 0.012, -0.004])
+      >>> risk_free = np.array([0.0002, 0.0002, 0.0002])
+      >>> excess = kand.excess_returns(returns, risk_free)
+      ```
+    """
+    ...
+
+def excess_returns_inc(period_return, risk_free):
+    """
+    Calculates a single excess return incrementally.
+
+    Like `excess_returns`, a NaN input gives a NaN result instead of an error.
+
+    Args:
+      period_return: The asset return of the period.
+      risk_free: The risk-free return of the same period.
+
+    Returns:
+      The excess return.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> value = kand.excess_returns_inc(0.012, 0.0002)
+      ```
+    """
+    ...
+
+def fv(rate, nper, pmt, pv, begin=False):
+    """
+    Computes the future value of an investment, as the spreadsheet `FV` function does.
+
+    Cash paid out is negative and cash received is positive.
+
+    Args:
+      rate: Interest rate per period.
+      nper: Number of payment periods.
+      pmt: Payment made each period.
+      pv: Present value.
+      begin: Whether payments are due at the beginning of each period. Defaults to False.
+
+    Returns:
+      The future value as a float.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> value = kand.fv(0.06 / 12, 10, -200, -500, True)
+      ```
+    """
+    ...
+
+def garman_klass(open, high, low, close, period, periods_per_year=252.0):
+    """
+    Calculate rolling Garman-Klass volatility for NumPy arrays
+
+    Garman-Klass volatility combines the high-low range with the open-to-close move of each bar, annualized by `periods_per_year`.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Closing prices as a 1-D NumPy array of type `TAFloat`.
+      period: Number of bars in the rolling window (must be >= 2).
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple of two 1-D NumPy arrays containing:
+      - Garman-Klass volatility values
+      - Rolling sum of Garman-Klass terms
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open_ = np.array([100.0, 101.0, 101.5, 102.0, 103.0])
+      >>> high = np.array([101.0, 102.5, 102.0, 103.5, 104.0])
+      >>> low = np.array([99.0, 100.5, 100.0, 101.0, 102.0])
+      >>> close = np.array([100.5, 101.5, 101.0, 103.0, 103.5])
+      >>> garman_klass, total = kand.garman_klass(open_, high, low, close, 3)
+      ```
+    """
+    ...
+
+def garman_klass_inc(open, high, low, close, old_open, old_high, old_low, old_close, prev_sum, period, periods_per_year=252.0):
+    """
+    Calculate the latest Garman-Klass volatility value incrementally
+
+    Args:
+      py: Python interpreter token
+      open: Opening price of the newest bar
+      high: High price of the newest bar
+      low: Low price of the newest bar
+      close: Closing price of the newest bar
+      old_open: Opening price of the bar leaving the window
+      old_high: High price of the bar leaving the window
+      old_low: Low price of the bar leaving the window
+      old_close: Closing price of the bar leaving the window
+      prev_sum: Previous sum of Garman-Klass terms
+      period: Number of bars in the rolling window (must be >= 2)
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple containing:
+      - Latest Garman-Klass volatility
+      - New sum of Garman-Klass terms
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> garman_klass, total = kand.garman_klass_inc(
+      ...     103.0,   # opening price of the newest bar
+      ...     104.0,   # high price of the newest bar
+      ...     102.0,   # low price of the newest bar
+      ...     103.5,   # closing price of the newest bar
+      ...     101.0,   # opening price of the bar leaving the window
+      ...     102.5,   # high price of the bar leaving the window
+      ...     100.5,   # low price of the bar leaving the window
+      ...     101.5,   # closing price of the bar leaving the window
+      ...     0.0004,  # previous sum of Garman-Klass terms
+      ...     3        # period
+      ... )
+      ```
+    """
+    ...
+
+def gaussian_es(returns, period, confidence=0.95):
+    """
+    Computes the rolling Gaussian Expected Shortfall of a return series.
+
+    The mean loss beyond the Gaussian VaR when the returns of the window are normally distributed
+    with their rolling mean and population standard deviation.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window (must be >= 2).
+      confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Expected Shortfall
+      - Rolling mean values
+      - Rolling sum of squared deviations from the mean
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.01, 0.01, -0.01])
+      >>> es, mean, m2 = kand.gaussian_es(returns, 4)
+      ```
+    """
+    ...
+
+def gaussian_es_inc(ret, prev_mean, prev_m2, old_ret, period, confidence=0.95):
+    """
+    Computes the latest rolling Gaussian Expected Shortfall incrementally.
+
+    Args:
+      ret: Newest return.
+      prev_mean: Previous rolling mean.
+      prev_m2: Previous rolling sum of squared deviations from the mean.
+      old_ret: Return leaving the window.
+      period: Number of returns in the rolling window (must be >= 2).
+      confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+
+    Returns:
+      A tuple containing (es, mean, m2).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> es, mean, m2 = kand.gaussian_es_inc(0.01, 0.005, 0.0011, 0.03, 4)
+      ```
+    """
+    ...
+
+def gaussian_var(returns, period, confidence=0.95):
+    """
+    Computes the rolling Gaussian Value-at-Risk of a return series.
+
+    Assumes the returns of the window are normally distributed with their rolling mean and
+    population standard deviation.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window (must be >= 2).
+      confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Value-at-Risk
+      - Rolling mean values
+      - Rolling sum of squared deviations from the mean
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.01, 0.01, -0.01])
+      >>> var, mean, m2 = kand.gaussian_var(returns, 4)
+      ```
+    """
+    ...
+
+def gaussian_var_inc(ret, prev_mean, prev_m2, old_ret, period, confidence=0.95):
+    """
+    Computes the latest rolling Gaussian Value-at-Risk incrementally.
+
+    Args:
+      ret: Newest return.
+      prev_mean: Previous rolling mean.
+      prev_m2: Previous rolling sum of squared deviations from the mean.
+      old_ret: Return leaving the window.
+      period: Number of returns in the rolling window (must be >= 2).
+      confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+
+    Returns:
+      A tuple containing (var, mean, m2).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> var, mean, m2 = kand.gaussian_var_inc(0.01, 0.005, 0.0011, 0.03, 4)
+      ```
+    """
+    ...
+
+def historical_es(returns, period, confidence=0.95):
+    """
+    Computes the rolling historical Expected Shortfall (CVaR) of a return series.
+
+    Expected Shortfall is the average loss in the worst `1 - confidence` share of the window,
+    reported as a positive loss.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window (must be >= 2).
+      confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+
+    Returns:
+      A 1-D NumPy array containing the Expected Shortfall.
+      The array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.005, -0.04, 0.03])
+      >>> es = kand.historical_es(returns, 5, 0.6)
+      ```
+    """
+    ...
+
+def historical_var(returns, period, confidence=0.95):
+    """
+    Computes the rolling historical Value-at-Risk of a return series.
+
+    VaR is read from the empirical distribution of the window and reported as a positive loss:
+    a VaR of 0.02 means a 2% loss.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window (must be >= 2).
+      confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+
+    Returns:
+      A 1-D NumPy array containing the Value-at-Risk.
+      The array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.005, -0.04, 0.03])
+      >>> var = kand.historical_var(returns, 5, 0.75)
+      ```
+    """
+    ...
+
+def ht_dcperiod(data):
+    """
+    Computes the Hilbert Transform Dominant Cycle Period (HT_DCPERIOD).
+
+    Measures the length, in bars, of the dominant price cycle with the Hilbert transform
+    homodyne discriminator, matching TA-Lib.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array of dominant cycle periods, with the first 32 elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+      >>> dcperiod = kand.ht_dcperiod(data)
+      ```
+    """
+    ...
+
+def ht_dcphase(data):
+    """
+    Computes the Hilbert Transform Dominant Cycle Phase (HT_DCPHASE).
+
+    Locates each bar within the dominant cycle, in degrees, by correlating one cycle of
+    smoothed prices with a sine and a cosine, matching TA-Lib.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array of dominant cycle phases in degrees, with the first 63 elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+      >>> dcphase = kand.ht_dcphase(data)
+      ```
+    """
+    ...
+
+def ht_phasor(data):
+    """
+    Computes the Hilbert Transform Phasor Components (HT_PHASOR).
+
+    Splits the detrended price into its in-phase and quadrature components with the Hilbert
+    transform, matching TA-Lib.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A tuple of two 1-D NumPy arrays containing:
+      - In-phase component
+      - Quadrature component
+      Each array has the same length as the input, with the first 32 elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+      >>> in_phase, quadrature = kand.ht_phasor(data)
+      ```
+    """
+    ...
+
+def ht_sine(data):
+    """
+    Computes the Hilbert Transform SineWave (HT_SINE).
+
+    Returns the sine of the dominant cycle phase and a lead sine advanced by 45 degrees; the
+    lines cross ahead of cycle turning points and separate in trends. Matches TA-Lib.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A tuple of two 1-D NumPy arrays containing:
+      - Sine values
+      - Lead sine values
+      Each array has the same length as the input, with the first 63 elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+      >>> sine, lead_sine = kand.ht_sine(data)
+      ```
+    """
+    ...
+
+def ht_trendline(data):
+    """
+    Computes the Hilbert Transform Instantaneous Trendline (HT_TRENDLINE).
+
+    Averages the price over one measured dominant cycle and smooths the result with a 4-bar WMA,
+    matching TA-Lib.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array of trendline values, with the first 63 elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+      >>> trendline = kand.ht_trendline(data)
+      ```
+    """
+    ...
+
+def ht_trendmode(data):
+    """
+    Computes the Hilbert Transform Trend vs Cycle Mode (HT_TRENDMODE).
+
+    Classifies each bar as trending (1) or cycling (0) from the SineWave crossings, the phase
+    rate and the distance to the instantaneous trendline, matching TA-Lib.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array of modes (1 = trend, 0 = cycle), with the first 63 elements containing 0 values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+      >>> trendmode = kand.ht_trendmode(data)
+      ```
+    """
+    ...
+
+def hv(prices, period, periods_per_year=252.0):
+    """
+    Calculate rolling close-to-close historical volatility for NumPy arrays
+
+    Historical volatility is the sample standard deviation of log returns over a rolling window, annualized by `periods_per_year`.
+
+    Args:
+      prices: Prices as a 1-D NumPy array of type `TAFloat`.
+      period: Number of log returns in the rolling window (must be >= 2).
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Historical volatility values
+      - Rolling mean of log returns
+      - Rolling sum of squared deviations of log returns
+      Each array has the same length as the input, with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([100.0, 101.0, 100.5, 102.0, 101.5, 103.0])
+      >>> hv, mean, m2 = kand.hv(prices, 3)
+      ```
+    """
+    ...
+
+def hv_inc(price, prev_price, old_price, old_prev_price, prev_mean, prev_m2, period, periods_per_year=252.0):
+    """
+    Calculate the latest close-to-close historical volatility value incrementally
+
+    Args:
+      py: Python interpreter token
+      price: Newest price
+      prev_price: Price before the newest one
+      old_price: Price that ends the log return leaving the window
+      old_prev_price: Price that starts the log return leaving the window
+      prev_mean: Previous mean of log returns
+      prev_m2: Previous sum of squared deviations of log returns
+      period: Number of log returns in the rolling window (must be >= 2)
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple containing:
+      - Latest historical volatility
+      - New mean of log returns
+      - New sum of squared deviations
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> hv, mean, m2 = kand.hv_inc(
+      ...     101.5,   # newest price
+      ...     102.0,   # price before the newest one
+      ...     101.0,   # price that ends the log return leaving the window
+      ...     100.0,   # price that starts the log return leaving the window
+      ...     0.0066,  # previous mean of log returns
+      ...     0.0002,  # previous sum of squared deviations of log returns
+      ...     3        # period
+      ... )
+      ```
+    """
+    ...
+
+def ichimoku(high, low, close, tenkan_period=9, kijun_period=26, senkou_b_period=52, displacement=26):
+    """
+    Calculates Ichimoku Kinko Hyo for NumPy arrays.
+
+    Every output is aligned to the bar it is plotted on. The leading spans are `displacement`
+    values longer than the input, so their tail is the cloud projected beyond the latest bar. The
+    lagging span is the close shifted `displacement` bars back, so its last `displacement` values
+    are NaN.
+
+    Args:
+      high: Input high price data as a 1-D NumPy array of type `TAFloat`.
+      low: Input low price data as a 1-D NumPy array of type `TAFloat`.
+      close: Input close price data as a 1-D NumPy array of type `TAFloat`.
+      tenkan_period: Period of the conversion line (must be >= 2). Defaults to 9.
+      kijun_period: Period of the base line (must be >= 2). Defaults to 26.
+      senkou_b_period: Period of the leading span B (must be >= 2). Defaults to 52.
+      displacement: Bars the cloud is shifted forward and the lagging span backward (must be >= 1). Defaults to 26.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - Conversion line (Tenkan-sen) values, same length as the input
+      - Base line (Kijun-sen) values, same length as the input
+      - Leading span A (Senkou Span A) values, `displacement` longer than the input
+      - Leading span B (Senkou Span B) values, `displacement` longer than the input
+      - Lagging span (Chikou Span) values, same length as the input
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0, 12.0])
+      >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0, 8.0])
+      >>> close = np.array([9.0, 11.0, 14.0, 12.0, 11.0, 9.0])
+      >>> tenkan, kijun, senkou_a, senkou_b, chikou = kand.ichimoku(high, low, close, 2, 3, 4, 2)
+      ```
+    """
+    ...
+
+def irr(values, guess=0.1):
+    """
+    Computes the internal rate of return of a NumPy array of periodic cash flows, as the
+    spreadsheet `IRR` function does.
+
+    Args:
+      values: Cash flows as a 1-D NumPy array of type `TAFloat`, the first one made today.
+      guess: Starting rate for the solver. Must be > -1. Defaults to 0.1.
+
+    Returns:
+      The rate of return per period as a float.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> rate = kand.irr(np.array([-70000.0, 12000.0, 15000.0, 18000.0, 21000.0, 26000.0]))
+      ```
+    """
+    ...
+
+def kama(data, period, fast_period=2, slow_period=30):
+    """
+    Computes the Kaufman Adaptive Moving Average (KAMA) over a NumPy array.
+
+    KAMA adapts its smoothing to market noise. The efficiency ratio (net change over `period`
+    divided by the sum of bar-to-bar changes) blends a fast and a slow smoothing constant:
+    SC = (ER * (fast_sc - slow_sc) + slow_sc)^2
+    KAMA = KAMA(previous) + SC * (Price - KAMA(previous))
+    where fast_sc = 2/(fast_period+1) and slow_sc = 2/(slow_period+1).
+
+    Args:
+      data: Input data as a 1-D NumPy array of type `TAFloat`.
+      period: Efficiency ratio period. Must be >= 2 and less than input length.
+      fast_period: Period of the fast smoothing constant. Defaults to 2.
+      slow_period: Period of the slow smoothing constant. Defaults to 30.
+
+    Returns:
+      A new 1-D NumPy array containing the KAMA values. The array has the same length as the input,
+      with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([10.0, 11.0, 10.5, 11.5, 12.0, 12.5])
+      >>> result = kand.kama(data, 3)
+      ```
+    """
+    ...
+
+def kama_inc(price, prev_price, old_price, old_prev_price, prev_kama, prev_volatility, fast_period=2, slow_period=30):
+    """
+    Computes the latest KAMA value incrementally.
+
+    Rolls the volatility sum forward by one bar and applies the adaptive smoothing constant
+    to the previous KAMA value.
+
+    Args:
+
+      price: Current price as `TAFloat`.
+      prev_price: Previous price as `TAFloat`.
+      old_price: Price `period` bars before the current one.
+      old_prev_price: Price `period + 1` bars before the current one.
+      prev_kama: Previous KAMA value.
+      prev_volatility: Previous sum of absolute price changes over the period.
+      fast_period: Period of the fast smoothing constant. Defaults to 2.
+      slow_period: Period of the slow smoothing constant. Defaults to 30.
+
+    Returns:
+      A tuple containing (KAMA, volatility). Pass volatility back as `prev_volatility` on the next call.
 
-    Example:
-        >>> import kand
-        >>> high, low = 24.20, 23.85
-        >>> prev_high, prev_low, prev_close = 24.07, 23.72, 23.95
-        >>> prev_smoothed_plus_dm = 0.5
-        >>> prev_smoothed_minus_dm = 0.3
-        >>> prev_smoothed_tr = 1.2
-        >>> period = 14
-        >>> dx, plus_dm, minus_dm, tr = kand.dx_inc(
-        ...     high, low, prev_high, prev_low, prev_close,
-        ...     prev_smoothed_plus_dm, prev_smoothed_minus_dm,
-        ...     prev_smoothed_tr, period)
+    Examples:
+      ```python
+      >>> import kand
+      >>> kama, volatility = kand.kama_inc(11.0, 10.5, 10.0, 9.5, 10.2, 2.0)
+      ```
     """
     ...
 
-def ecl(high, low, close):
+def kelly(trades, period, fraction=1.0):
     """
-    Computes the Expanded Camarilla Levels (ECL) over NumPy arrays.
+    Computes the rolling Kelly fraction over a NumPy array of trade returns.
 
-    The ECL indicator calculates multiple support and resistance levels based on the previous period's
-    high, low and close prices.
+    The fraction of capital to risk per trade given the recent win rate and payoff ratio, scaled
+    by `fraction` (1.0 for full Kelly, 0.5 for half Kelly).
 
     Args:
-      high: Input high prices as a 1-D NumPy array of type `TAFloat`.
-      low: Input low prices as a 1-D NumPy array of type `TAFloat`.
-      close: Input close prices as a 1-D NumPy array of type `TAFloat`.
+      trades: Per-trade returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of trades in the rolling window. Must be >= 1.
+      fraction: Scale applied to the full Kelly fraction. Must be > 0. Defaults to 1.0.
 
     Returns:
-      A tuple of ten 1-D NumPy arrays containing the ECL values (H5,H4,H3,H2,H1,L1,L2,L3,L4,L5).
-      Each array has the same length as the input, with the first element containing NaN value.
+      A 1-D NumPy array of Kelly fractions, with the first (period-1) elements containing NaN values.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> high = np.array([24.20, 24.07, 24.04, 23.87, 23.67])
-      >>> low = np.array([23.85, 23.72, 23.64, 23.37, 23.46])
-      >>> close = np.array([23.89, 23.95, 23.67, 23.78, 23.50])
-      >>> h5,h4,h3,h2,h1,l1,l2,l3,l4,l5 = kand.ecl(high, low, close)
+      >>> trades = np.array([0.04, -0.02, 0.02, -0.01, 0.03])
+      >>> half_kelly = kand.kelly(trades, 4, 0.5)
       ```
     """
     ...
 
-def ecl_inc(prev_high, prev_low, prev_close):
+def kelly_criterion(trades, fraction=1.0):
     """
-    Computes the latest Expanded Camarilla Levels (ECL) values incrementally.
+    Computes the Kelly fraction of an entire NumPy array of trade returns.
 
-    This function provides an efficient way to calculate ECL values for new data without
-    reprocessing the entire dataset.
+    Args:
+      trades: Per-trade returns as a 1-D NumPy array of type `TAFloat`.
+      fraction: Scale applied to the full Kelly fraction. Must be > 0. Defaults to 1.0.
+
+    Returns:
+      The Kelly fraction as a float, or NaN when there are losing but no winning trades.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> trades = np.array([0.04, -0.02, 0.02, -0.01])
+      >>> full_kelly = kand.kelly_criterion(trades)
+      ```
+    """
+    ...
+
+def keltner(high, low, close, period=20, atr_period=10, multiplier=2.0):
+    """
+    Calculates Keltner Channels for NumPy arrays.
+
+    Keltner Channels place bands a multiple of the Average True Range above and below an EMA of
+    the typical price.
 
     Args:
+      high: Input high price data as a 1-D NumPy array of type `TAFloat`.
+      low: Input low price data as a 1-D NumPy array of type `TAFloat`.
+      close: Input close price data as a 1-D NumPy array of type `TAFloat`.
+      period: EMA period for the center line (must be >= 2). Defaults to 20.
+      atr_period: ATR period for the channel width (must be >= 2). Defaults to 10.
+      multiplier: ATR multiplier (typically 1-3). Defaults to 2.0.
 
-      prev_high: Previous period's high price as `TAFloat`.
-      prev_low: Previous period's low price as `TAFloat`.
-      prev_close: Previous period's close price as `TAFloat`.
+    Returns:
+      A tuple of four 1-D NumPy arrays containing:
+      - Upper channel values
+      - Middle channel (EMA) values
+      - Lower channel values
+      - ATR values
+      Each array has the same length as the input, with the first `max(period-1, atr_period)` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+      >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+      >>> close = np.array([9.0, 11.0, 13.0, 12.0, 11.0])
+      >>> upper, middle, lower, atr = kand.keltner(high, low, close, 3, 3, 2.0)
+      ```
+    """
+    ...
+
+def keltner_inc(high, low, close, prev_close, prev_middle, prev_atr, period=20, atr_period=10, multiplier=2.0):
+    """
+    Calculates the next Keltner Channels values incrementally.
+
+    Args:
+      high: Current high price value as `TAFloat`.
+      low: Current low price value as `TAFloat`.
+      close: Current close price value as `TAFloat`.
+      prev_close: Previous close price value as `TAFloat`.
+      prev_middle: Previous middle channel (EMA) value as `TAFloat`.
+      prev_atr: Previous ATR value as `TAFloat`.
+      period: EMA period for the center line (must be >= 2). Defaults to 20.
+      atr_period: ATR period for the channel width (must be >= 2). Defaults to 10.
+      multiplier: ATR multiplier. Defaults to 2.0.
 
     Returns:
-      A tuple of ten values (H5,H4,H3,H2,H1,L1,L2,L3,L4,L5) containing the latest ECL levels.
+      A tuple containing:
+      - Upper channel value
+      - Middle channel (EMA) value
+      - Lower channel value
+      - New ATR value
 
     Examples:
       ```python
       >>> import kand
-      >>> h5,h4,h3,h2,h1,l1,l2,l3,l4,l5 = kand.ecl_inc(24.20, 23.85, 23.89)
+      >>> upper, middle, lower, atr = kand.keltner_inc(
+      ...     13.0,  # current high
+      ...     9.0,   # current low
+      ...     11.0,  # current close
+      ...     12.0,  # previous close
+      ...     12.0,  # previous middle channel
+      ...     4.0,   # previous ATR
+      ...     3,     # EMA period
+      ...     3,     # ATR period
+      ...     2.0    # multiplier
+      ... )
       ```
     """
     ...
 
-def ema(data, period, k=None):
+def kurt(prices, period):
     """
-    Computes the Exponential Moving Average (EMA) over a NumPy array.
+    Calculate rolling excess Kurtosis for a NumPy array
 
-    The Exponential Moving Average is calculated by applying more weight to recent prices
-    via a smoothing factor k. Each value is calculated as:
-    EMA = Price * k + EMA(previous) * (1 - k)
-    where k is typically 2/(period+1).
+    Excess kurtosis measures how heavy the tails of the window are compared with a normal
+    distribution. This is the population excess kurtosis `n * M4 / M2^2 - 3`; a constant window
+    gives NaN.
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for Kurtosis calculation (must be >= 4).
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - Excess Kurtosis values
+      - Rolling mean values
+      - Rolling sum of squared deviations from the mean
+      - Rolling sum of cubed deviations from the mean
+      - Rolling sum of fourth-power deviations from the mean
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([1.0, 2.0, 3.0, 4.0, 20.0])
+      >>> kurt, mean, m2, m3, m4 = kand.kurt(prices, 4)
+      ```
+    """
+    ...
+
+def kurt_inc(price, prev_mean, prev_m2, prev_m3, prev_m4, old_price, period):
+    """
+    Calculate the latest rolling excess Kurtosis value incrementally
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_mean: Previous period's mean
+      prev_m2: Previous period's sum of squared deviations from the mean
+      prev_m3: Previous period's sum of cubed deviations from the mean
+      prev_m4: Previous period's sum of fourth-power deviations from the mean
+      old_price: Price being removed from the period
+      period: Period for Kurtosis calculation (must be >= 4)
+
+    Returns:
+      A tuple containing:
+      - Latest excess Kurtosis value
+      - New mean
+      - New sum of squared deviations
+      - New sum of cubed deviations
+      - New sum of fourth-power deviations
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> # Window [1.0, 2.0, 3.0, 4.0] slides to [2.0, 3.0, 4.0, 5.0]
+      >>> kurt, mean, m2, m3, m4 = kand.kurt_inc(
+      ...     5.0,    # current price
+      ...     2.5,    # previous mean
+      ...     5.0,    # previous sum of squared deviations
+      ...     0.0,    # previous sum of cubed deviations
+      ...     10.25,  # previous sum of fourth-power deviations
+      ...     1.0,    # price to remove
+      ...     4       # period
+      ... )
+      ```
+    """
+    ...
+
+def linearreg(data, period):
+    """
+    Computes the Linear Regression (LINEARREG) over a NumPy array.
+
+    Fits a least-squares line to the last `period` values and returns its value at the current bar.
+    Matches TA-Lib's `LINEARREG`.
+
+    Args:
+      data: Input data as a 1-D NumPy array of type `TAFloat`.
+      period: Regression window. Must be >= 2 and less than input length.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Regression line values
+      - Running sum of values
+      - Running sum of index-weighted values
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 3.0, 5.0, 4.0, 6.0])
+      >>> linearreg, sum_y, sum_xy = kand.linearreg(data, 3)
+      ```
+    """
+    ...
+
+def linearreg_angle(data, period):
+    """
+    Computes the Linear Regression Angle (LINEARREG_ANGLE) over a NumPy array.
+
+    Fits a least-squares line to the last `period` values and returns the angle of its slope in degrees.
+    Matches TA-Lib's `LINEARREG_ANGLE`.
+
+    Args:
+      data: Input data as a 1-D NumPy array of type `TAFloat`.
+      period: Regression window. Must be >= 2 and less than input length.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Regression angles in degrees
+      - Running sum of values
+      - Running sum of index-weighted values
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 3.0, 5.0, 4.0, 6.0])
+      >>> angle, sum_y, sum_xy = kand.linearreg_angle(data, 3)
+      ```
+    """
+    ...
+
+def linearreg_angle_inc(price, prev_sum_y, prev_sum_xy, old_price, period):
+    """
+    Computes the latest Linear Regression Angle value incrementally.
+
+    Slides the regression window by one bar using the running sums of the previous window.
+
+    Args:
+      price: Newest value to include.
+      prev_sum_y: Previous sum of values in the window.
+      prev_sum_xy: Previous sum of index-weighted values in the window.
+      old_price: Oldest value to remove from the window.
+      period: Regression window. Must be >= 2.
+
+    Returns:
+      A tuple containing (LINEARREG_ANGLE, sum_y, sum_xy).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> angle, sum_y, sum_xy = kand.linearreg_angle_inc(7.0, 9.0, 13.0, 1.0, 3)
+      ```
+    """
+    ...
+
+def linearreg_inc(price, prev_sum_y, prev_sum_xy, old_price, period):
+    """
+    Computes the latest Linear Regression value incrementally.
+
+    Slides the regression window by one bar using the running sums of the previous window.
+
+    Args:
+      price: Newest value to include.
+      prev_sum_y: Previous sum of values in the window.
+      prev_sum_xy: Previous sum of index-weighted values in the window.
+      old_price: Oldest value to remove from the window.
+      period: Regression window. Must be >= 2.
+
+    Returns:
+      A tuple containing (LINEARREG, sum_y, sum_xy).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> linearreg, sum_y, sum_xy = kand.linearreg_inc(7.0, 9.0, 13.0, 1.0, 3)
+      ```
+    """
+    ...
+
+def linearreg_intercept(data, period):
+    """
+    Computes the Linear Regression Intercept (LINEARREG_INTERCEPT) over a NumPy array.
+
+    Fits a least-squares line to the last `period` values and returns its value at the oldest bar of the window.
+    Matches TA-Lib's `LINEARREG_INTERCEPT`.
+
+    Args:
+      data: Input data as a 1-D NumPy array of type `TAFloat`.
+      period: Regression window. Must be >= 2 and less than input length.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Regression intercepts
+      - Running sum of values
+      - Running sum of index-weighted values
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 3.0, 5.0, 4.0, 6.0])
+      >>> intercept, sum_y, sum_xy = kand.linearreg_intercept(data, 3)
+      ```
+    """
+    ...
+
+def linearreg_intercept_inc(price, prev_sum_y, prev_sum_xy, old_price, period):
+    """
+    Computes the latest Linear Regression Intercept value incrementally.
+
+    Slides the regression window by one bar using the running sums of the previous window.
+
+    Args:
+      price: Newest value to include.
+      prev_sum_y: Previous sum of values in the window.
+      prev_sum_xy: Previous sum of index-weighted values in the window.
+      old_price: Oldest value to remove from the window.
+      period: Regression window. Must be >= 2.
+
+    Returns:
+      A tuple containing (LINEARREG_INTERCEPT, sum_y, sum_xy).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> intercept, sum_y, sum_xy = kand.linearreg_intercept_inc(7.0, 9.0, 13.0, 1.0, 3)
+      ```
+    """
+    ...
+
+def linearreg_slope(data, period):
+    """
+    Computes the Linear Regression Slope (LINEARREG_SLOPE) over a NumPy array.
+
+    Fits a least-squares line to the last `period` values and returns its slope per bar.
+    Matches TA-Lib's `LINEARREG_SLOPE`.
+
+    Args:
+      data: Input data as a 1-D NumPy array of type `TAFloat`.
+      period: Regression window. Must be >= 2 and less than input length.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Regression slopes
+      - Running sum of values
+      - Running sum of index-weighted values
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 3.0, 5.0, 4.0, 6.0])
+      >>> slope, sum_y, sum_xy = kand.linearreg_slope(data, 3)
+      ```
+    """
+    ...
+
+def linearreg_slope_inc(price, prev_sum_y, prev_sum_xy, old_price, period):
+    """
+    Computes the latest Linear Regression Slope value incrementally.
+
+    Slides the regression window by one bar using the running sums of the previous window.
+
+    Args:
+      price: Newest value to include.
+      prev_sum_y: Previous sum of values in the window.
+      prev_sum_xy: Previous sum of index-weighted values in the window.
+      old_price: Oldest value to remove from the window.
+      period: Regression window. Must be >= 2.
+
+    Returns:
+      A tuple containing (LINEARREG_SLOPE, sum_y, sum_xy).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> slope, sum_y, sum_xy = kand.linearreg_slope_inc(7.0, 9.0, 13.0, 1.0, 3)
+      ```
+    """
+    ...
+
+def log_returns(data):
+    """
+    Computes log returns of a NumPy array of prices.
+
+    Each value is the natural logarithm of the ratio to the previous price. The first value is
+    NaN, as is any value where either price is not positive.
 
     Args:
-      data: Input data as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for EMA calculation. Must be positive and less than input length.
-      k: Optional custom smoothing factor. If None, uses default k = 2/(period+1).
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
 
     Returns:
-      A new 1-D NumPy array containing the EMA values. The array has the same length as the input,
-      with the first `period-1` elements containing NaN values.
+      A 1-D NumPy array of log returns, with the first element NaN.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
-      >>> result = kand.ema(data, 3)
-      >>> print(result)
-      [nan, nan, 2.0, 3.0, 4.2]
+      >>> prices = np.array([100.0, 110.0, 100.0])
+      >>> returns = kand.log_returns(prices)
       ```
     """
     ...
 
-def ema_inc(price, prev_ema, period, k=None):
+def log_returns_inc(price, prev_price):
     """
-    Computes the latest EMA value incrementally.
-
-    This function provides an efficient way to calculate EMA values for new data without
-    reprocessing the entire dataset.
+    Calculates a single log return incrementally.
 
     Args:
-
-      price: Current period's price value as `TAFloat`.
-      prev_ema: Previous period's EMA value as `TAFloat`.
-      period: Window size for EMA calculation. Must be >= 2.
-      k: Optional custom smoothing factor. If None, uses default k = 2/(period+1).
+      price: The current price.
+      prev_price: The previous price.
 
     Returns:
-      The new EMA value as `TAFloat`.
+      The log return, or NaN if either price is not positive.
 
     Examples:
       ```python
       >>> import kand
-      >>> current_price = 15.0
-      >>> prev_ema = 14.5
-      >>> period = 14
-      >>> new_ema = kand.ema_inc(current_price, prev_ema, period)
+      >>> value = kand.log_returns_inc(110.0, 100.0)
       ```
     """
     ...
@@ -1411,6 +3909,35 @@ def macd_inc(price, prev_fast_ema, prev_slow_ema, prev_signal, fast_period, slow
     """
     ...
 
+def mama(data, fast_limit=0.5, slow_limit=0.05):
+    """
+    Computes the MESA Adaptive Moving Average (MAMA) and its Following Adaptive Moving Average (FAMA).
+
+    MAMA adapts its smoothing factor to the phase change measured by a Hilbert transform
+    homodyne discriminator: slow phase changes (trends) push alpha towards `slow_limit`,
+    fast ones towards `fast_limit`. FAMA follows MAMA with half the alpha.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+      fast_limit: Upper bound of the adaptive alpha, between 0.01 and 0.99. Defaults to 0.5.
+      slow_limit: Lower bound of the adaptive alpha, between 0.01 and 0.99. Defaults to 0.05.
+
+    Returns:
+      A tuple of two 1-D NumPy arrays containing:
+      - MAMA values
+      - FAMA values
+      Each array has the same length as the input, with the first 32 elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = 100.0 + np.sin(np.arange(40) * 0.3)
+      >>> mama, fama = kand.mama(data)
+      ```
+    """
+    ...
+
 def max(prices, period):
     """
     Calculate Maximum Value for a NumPy array
@@ -1432,10 +3959,33 @@ def max(prices, period):
     """
     ...
 
+def max_drawdown(equity):
+    """
+    Computes the maximum drawdown percentage of an entire equity curve.
+
+    Args:
+      equity: Equity values as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      The deepest drawdown percentage as a float.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> equity = np.array([100.0, 120.0, 90.0, 130.0])
+      >>> mdd = kand.max_drawdown(equity)
+      ```
+    """
+    ...
+
 def max_inc(price, prev_max, old_price, period):
     """
     Calculate the latest Maximum Value incrementally
 
+    Deprecated: returns the new price when the previous max leaves the window, which can
+    understate the MAX; use `max` on the full series.
+
     Args:
       py: Python interpreter token
       price: Current period's price
@@ -1454,6 +4004,31 @@ def max_inc(price, prev_max, old_price, period):
     """
     ...
 
+def median(prices, period):
+    """
+    Calculate a rolling Median for a NumPy array
+
+    The median is the middle value of the window, or the average of the two middle values when
+    the period is even.
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for Median calculation (must be >= 2).
+
+    Returns:
+      A 1-D NumPy array containing the Median values.
+      The array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([1.0, 100.0, 2.0, 3.0])
+      >>> median = kand.median(prices, 3)
+      ```
+    """
+    ...
+
 def medprice(high, low):
     """
     Calculates the Median Price (MEDPRICE) for a NumPy array.
@@ -1570,6 +4145,8 @@ def midpoint_inc(price, prev_highest, prev_lowest, period):
     """
     Calculates the next Midpoint value incrementally.
 
+    Deprecated: prices leaving the window are never dropped; use `midpoint` on the full series.
+
     Provides an optimized way to calculate the next Midpoint value when new data arrives,
     without recalculating the entire series.
 
@@ -1632,6 +4209,8 @@ def midprice_inc(high, low, prev_highest, prev_lowest, period):
     """
     Calculates the next Midpoint Price value incrementally.
 
+    Deprecated: bars leaving the window are never dropped; use `midprice` on the full series.
+
     Provides an optimized way to calculate the next Midpoint Price value when new data arrives,
     without recalculating the entire series.
 
@@ -1690,6 +4269,8 @@ def min_inc(price, prev_min, prev_price, period):
     """
     Calculate the latest MIN value incrementally
 
+    Deprecated: fails when the previous min leaves the window; use `min` on the full series.
+
     Args:
       py: Python interpreter token
       price: Current period's price
@@ -1883,6 +4464,53 @@ def natr_inc(high, low, close, prev_close, prev_atr, period):
     """
     ...
 
+def nper(rate, pmt, pv, fv=0.0, begin=False):
+    """
+    Computes the number of periods of an investment, as the spreadsheet `NPER` function does.
+
+    Cash paid out is negative and cash received is positive.
+
+    Args:
+      rate: Interest rate per period.
+      pmt: Payment made each period.
+      pv: Present value.
+      fv: Future value. Defaults to 0.0.
+      begin: Whether payments are due at the beginning of each period. Defaults to False.
+
+    Returns:
+      The number of periods as a float.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> periods = kand.nper(0.12 / 12, -100, -1000, 10000, True)
+      ```
+    """
+    ...
+
+def npv(rate, values):
+    """
+    Computes the net present value of a NumPy array of cash flows, as the spreadsheet `NPV`
+    function does.
+
+    The first cash flow is discounted by one period.
+
+    Args:
+      rate: Discount rate per period. Must be > -1.
+      values: Cash flows at the end of each period as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      The net present value as a float.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> value = kand.npv(0.1, np.array([-10000.0, 3000.0, 4200.0, 6800.0]))
+      ```
+    """
+    ...
+
 def obv(close, volume):
     """
     Computes the On Balance Volume (OBV) over NumPy arrays.
@@ -1941,6 +4569,94 @@ def obv_inc(curr_close, prev_close, volume, prev_obv):
     """
     ...
 
+def parkinson(high, low, period, periods_per_year=252.0):
+    """
+    Calculate rolling Parkinson volatility for NumPy arrays
+
+    Parkinson volatility estimates volatility from the high-low range of each bar, annualized by `periods_per_year`.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      period: Number of bars in the rolling window (must be >= 2).
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple of two 1-D NumPy arrays containing:
+      - Parkinson volatility values
+      - Rolling sum of squared log ranges
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([101.0, 102.5, 102.0, 103.5, 104.0])
+      >>> low = np.array([99.0, 100.5, 100.0, 101.0, 102.0])
+      >>> parkinson, total = kand.parkinson(high, low, 3)
+      ```
+    """
+    ...
+
+def parkinson_inc(high, low, old_high, old_low, prev_sum, period, periods_per_year=252.0):
+    """
+    Calculate the latest Parkinson volatility value incrementally
+
+    Args:
+      py: Python interpreter token
+      high: High price of the newest bar
+      low: Low price of the newest bar
+      old_high: High price of the bar leaving the window
+      old_low: Low price of the bar leaving the window
+      prev_sum: Previous sum of squared log ranges
+      period: Number of bars in the rolling window (must be >= 2)
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple containing:
+      - Latest Parkinson volatility
+      - New sum of squared log ranges
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> parkinson, total = kand.parkinson_inc(
+      ...     101.0,   # newest high
+      ...     100.0,   # newest low
+      ...     102.0,   # high leaving the window
+      ...     100.0,   # low leaving the window
+      ...     0.0012,  # previous sum
+      ...     3        # period
+      ... )
+      ```
+    """
+    ...
+
+def percentrank(prices, period):
+    """
+    Calculate a rolling Percent Rank for a NumPy array
+
+    Percent Rank places each price within its own window on a 0 to 100 scale, with ties sharing
+    the midpoint of their ranks.
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for Percent Rank calculation (must be >= 2).
+
+    Returns:
+      A 1-D NumPy array containing the Percent Rank values.
+      The array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([3.0, 1.0, 4.0, 1.0, 5.0])
+      >>> rank = kand.percentrank(prices, 4)
+      ```
+    """
+    ...
+
 def plus_di(high, low, close, period):
     """
     Computes the Plus Directional Indicator (+DI) over NumPy arrays.
@@ -1996,76 +4712,175 @@ def plus_di_inc(high, low, prev_high, prev_low, prev_close, prev_smoothed_plus_d
     Examples:
       ```python
       >>> import kand
-      >>> plus_di, smoothed_plus_dm, smoothed_tr = kand.plus_di_inc(
-      ...     10.5,  # high
-      ...     9.5,   # low
-      ...     10.0,  # prev_high
-      ...     9.0,   # prev_low
-      ...     9.5,   # prev_close
-      ...     15.0,  # prev_smoothed_plus_dm
-      ...     20.0,  # prev_smoothed_tr
-      ...     14     # period
-      ... )
+      >>> plus_di, smoothed_plus_dm, smoothed_tr = kand.plus_di_inc(
+      ...     10.5,  # high
+      ...     9.5,   # low
+      ...     10.0,  # prev_high
+      ...     9.0,   # prev_low
+      ...     9.5,   # prev_close
+      ...     15.0,  # prev_smoothed_plus_dm
+      ...     20.0,  # prev_smoothed_tr
+      ...     14     # period
+      ... )
+      ```
+    """
+    ...
+
+def plus_dm(high, low, period):
+    """
+    Computes the Plus Directional Movement (+DM) over NumPy arrays.
+
+    Plus Directional Movement (+DM) measures upward price movement and is used as part of the
+    Directional Movement System developed by J. Welles Wilder.
+
+    Args:
+      high: Input high prices as a 1-D NumPy array of type `TAFloat`.
+      low: Input low prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for +DM calculation. Must be positive and less than input length.
+
+    Returns:
+      A new 1-D NumPy array containing the +DM values. The array has the same length as the input,
+      with the first `period-1` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([35266.0, 35247.5, 35235.7, 35190.8, 35182.0])
+      >>> low = np.array([35216.1, 35206.5, 35180.0, 35130.7, 35153.6])
+      >>> result = kand.plus_dm(high, low, 3)
+      ```
+    """
+    ...
+
+def plus_dm_inc(high, prev_high, low, prev_low, prev_plus_dm, period):
+    """
+    Calculates the next Plus DM value incrementally using previous values.
+
+    This function enables real-time calculation of Plus DM by using the previous Plus DM value
+    and current price data, avoiding the need to recalculate the entire series.
+
+    Args:
+      high: Current high price as `TAFloat`.
+      prev_high: Previous high price as `TAFloat`.
+      low: Current low price as `TAFloat`.
+      prev_low: Previous low price as `TAFloat`.
+      prev_plus_dm: Previous Plus DM value as `TAFloat`.
+      period: Smoothing period (>= 2).
+
+    Returns:
+      The latest Plus DM value.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> new_plus_dm = kand.plus_dm_inc(
+      ...     10.5,  # high
+      ...     10.0,  # prev_high
+      ...     9.8,   # low
+      ...     9.5,   # prev_low
+      ...     0.45,  # prev_plus_dm
+      ...     14     # period
+      ... )
+      ```
+    """
+    ...
+
+def pmt(rate, nper, pv, fv=0.0, begin=False):
+    """
+    Computes the periodic payment of a loan or annuity, as the spreadsheet `PMT` function does.
+
+    Cash paid out is negative and cash received is positive.
+
+    Args:
+      rate: Interest rate per period.
+      nper: Number of payment periods. Must be non-zero.
+      pv: Present value.
+      fv: Future value. Defaults to 0.0.
+      begin: Whether payments are due at the beginning of each period. Defaults to False.
+
+    Returns:
+      The payment per period as a float.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> payment = kand.pmt(0.08 / 12, 10, 10000)
+      ```
+    """
+    ...
+
+def pv(rate, nper, pmt, fv=0.0, begin=False):
+    """
+    Computes the present value of an investment, as the spreadsheet `PV` function does.
+
+    Cash paid out is negative and cash received is positive.
+
+    Args:
+      rate: Interest rate per period.
+      nper: Number of payment periods.
+      pmt: Payment made each period.
+      fv: Future value. Defaults to 0.0.
+      begin: Whether payments are due at the beginning of each period. Defaults to False.
+
+    Returns:
+      The present value as a float.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> value = kand.pv(0.08 / 12, 240, 500)
       ```
     """
     ...
 
-def plus_dm(high, low, period):
+def quantile(prices, period, quantile):
     """
-    Computes the Plus Directional Movement (+DM) over NumPy arrays.
+    Calculate a rolling Quantile for a NumPy array
 
-    Plus Directional Movement (+DM) measures upward price movement and is used as part of the
-    Directional Movement System developed by J. Welles Wilder.
+    The quantile is the value below which a given fraction of the window lies, interpolated
+    linearly between the two nearest values like NumPy and pandas do.
 
     Args:
-      high: Input high prices as a 1-D NumPy array of type `TAFloat`.
-      low: Input low prices as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for +DM calculation. Must be positive and less than input length.
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for Quantile calculation (must be >= 2).
+      quantile: The quantile to calculate (must be between 0 and 1).
 
     Returns:
-      A new 1-D NumPy array containing the +DM values. The array has the same length as the input,
-      with the first `period-1` elements containing NaN values.
+      A 1-D NumPy array containing the Quantile values.
+      The array has the same length as the input, with the first (period-1) elements containing NaN values.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> high = np.array([35266.0, 35247.5, 35235.7, 35190.8, 35182.0])
-      >>> low = np.array([35216.1, 35206.5, 35180.0, 35130.7, 35153.6])
-      >>> result = kand.plus_dm(high, low, 3)
+      >>> prices = np.array([1.0, 5.0, 2.0, 8.0, 3.0])
+      >>> q75 = kand.quantile(prices, 4, 0.75)
       ```
     """
     ...
 
-def plus_dm_inc(high, prev_high, low, prev_low, prev_plus_dm, period):
+def rate(nper, pmt, pv, fv=0.0, begin=False, guess=0.1):
     """
-    Calculates the next Plus DM value incrementally using previous values.
+    Computes the interest rate per period of an annuity, as the spreadsheet `RATE` function does.
 
-    This function enables real-time calculation of Plus DM by using the previous Plus DM value
-    and current price data, avoiding the need to recalculate the entire series.
+    The rate is found with Newton's method starting from `guess`.
 
     Args:
-      high: Current high price as `TAFloat`.
-      prev_high: Previous high price as `TAFloat`.
-      low: Current low price as `TAFloat`.
-      prev_low: Previous low price as `TAFloat`.
-      prev_plus_dm: Previous Plus DM value as `TAFloat`.
-      period: Smoothing period (>= 2).
+      nper: Number of payment periods. Must be > 0.
+      pmt: Payment made each period.
+      pv: Present value.
+      fv: Future value. Defaults to 0.0.
+      begin: Whether payments are due at the beginning of each period. Defaults to False.
+      guess: Starting rate for the solver. Must be > -1. Defaults to 0.1.
 
     Returns:
-      The latest Plus DM value.
+      The rate per period as a float.
 
     Examples:
       ```python
       >>> import kand
-      >>> new_plus_dm = kand.plus_dm_inc(
-      ...     10.5,  # high
-      ...     10.0,  # prev_high
-      ...     9.8,   # low
-      ...     9.5,   # prev_low
-      ...     0.45,  # prev_plus_dm
-      ...     14     # period
-      ... )
+      >>> monthly = kand.rate(48, -200, 8000)
       ```
     """
     ...
@@ -2326,6 +5141,123 @@ def rocr_inc(current_price, prev_price):
     """
     ...
 
+def rogers_satchell(open, high, low, close, period, periods_per_year=252.0):
+    """
+    Calculate rolling Rogers-Satchell volatility for NumPy arrays
+
+    Rogers-Satchell volatility measures the high and low against both the open and the close, which keeps it unbiased when prices trend. It is annualized by `periods_per_year`.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Closing prices as a 1-D NumPy array of type `TAFloat`.
+      period: Number of bars in the rolling window (must be >= 2).
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple of two 1-D NumPy arrays containing:
+      - Rogers-Satchell volatility values
+      - Rolling sum of Rogers-Satchell terms
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open_ = np.array([100.0, 101.0, 101.5, 102.0, 103.0])
+      >>> high = np.array([101.0, 102.5, 102.0, 103.5, 104.0])
+      >>> low = np.array([99.0, 100.5, 100.0, 101.0, 102.0])
+      >>> close = np.array([100.5, 101.5, 101.0, 103.0, 103.5])
+      >>> rogers_satchell, total = kand.rogers_satchell(open_, high, low, close, 3)
+      ```
+    """
+    ...
+
+def rogers_satchell_inc(open, high, low, close, old_open, old_high, old_low, old_close, prev_sum, period, periods_per_year=252.0):
+    """
+    Calculate the latest Rogers-Satchell volatility value incrementally
+
+    Args:
+      py: Python interpreter token
+      open: Opening price of the newest bar
+      high: High price of the newest bar
+      low: Low price of the newest bar
+      close: Closing price of the newest bar
+      old_open: Opening price of the bar leaving the window
+      old_high: High price of the bar leaving the window
+      old_low: Low price of the bar leaving the window
+      old_close: Closing price of the bar leaving the window
+      prev_sum: Previous sum of Rogers-Satchell terms
+      period: Number of bars in the rolling window (must be >= 2)
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple containing:
+      - Latest Rogers-Satchell volatility
+      - New sum of Rogers-Satchell terms
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> rogers_satchell, total = kand.rogers_satchell_inc(
+      ...     103.0,   # opening price of the newest bar
+      ...     104.0,   # high price of the newest bar
+      ...     102.0,   # low price of the newest bar
+      ...     103.5,   # closing price of the newest bar
+      ...     101.0,   # opening price of the bar leaving the window
+      ...     102.5,   # high price of the bar leaving the window
+      ...     100.5,   # low price of the bar leaving the window
+      ...     101.5,   # closing price of the bar leaving the window
+      ...     0.0004,  # previous sum of Rogers-Satchell terms
+      ...     3        # period
+      ... )
+      ```
+    """
+    ...
+
+def rolling_returns(data, period):
+    """
+    Computes returns over a rolling window of a NumPy array of prices.
+
+    Each value is the relative change from the price `period` bars back.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+      period: Number of bars each return spans. Must be >= 1.
+
+    Returns:
+      A 1-D NumPy array of rolling returns, with the first `period` elements NaN.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([100.0, 101.0, 103.0, 105.0])
+      >>> returns = kand.rolling_returns(prices, 2)
+      ```
+    """
+    ...
+
+def rolling_returns_inc(price, old_price):
+    """
+    Calculates a single rolling-window return incrementally.
+
+    Args:
+      price: The current price.
+      old_price: The price `period` bars back.
+
+    Returns:
+      The rolling return, or NaN if the old price is zero.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> value = kand.rolling_returns_inc(103.0, 100.0)
+      ```
+    """
+    ...
+
 def rsi(prices, period):
     """
     Computes the Relative Strength Index (RSI) over NumPy arrays.
@@ -2437,6 +5369,188 @@ def sar_inc(high, low, prev_high, prev_low, prev_sar, is_long, af, ep, accelerat
     """
     ...
 
+def sharpe(returns, period, risk_free=0.0, periods_per_year=252.0):
+    """
+    Computes the rolling Sharpe ratio over a NumPy array of periodic returns.
+
+    Mean excess return over the sample standard deviation of returns, annualized by sqrt(periods_per_year).
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window. Must be >= 2.
+      risk_free: Annual risk-free rate. Defaults to 0.0.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Sharpe ratio values
+      - Rolling mean of returns
+      - Rolling sum of squared deviations from the mean (M2)
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+      >>> sharpe, mean, m2 = kand.sharpe(returns, 3)
+      ```
+    """
+    ...
+
+def sharpe_inc(ret, prev_mean, prev_m2, old_ret, period, risk_free=0.0, periods_per_year=252.0):
+    """
+    Computes the latest rolling Sharpe ratio incrementally.
+
+    Args:
+      ret: Newest return to include.
+      prev_mean: Previous mean of returns in the window.
+      prev_m2: Previous sum of squared deviations from the mean in the window.
+      old_ret: Oldest return to remove from the window.
+      period: Number of returns in the rolling window. Must be >= 2.
+      risk_free: Annual risk-free rate. Defaults to 0.0.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      A tuple containing (sharpe, mean, m2).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> sharpe, mean, m2 = kand.sharpe_inc(0.005, 0.005 / 3, 0.00215 / 3, 0.01, 3)
+      ```
+    """
+    ...
+
+def sharpe_ratio(returns, risk_free=0.0, periods_per_year=252.0):
+    """
+    Computes the Sharpe ratio of an entire return series.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      risk_free: Annual risk-free rate. Defaults to 0.0.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      The Sharpe ratio as a float, or NaN when it is undefined.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+      >>> ratio = kand.sharpe_ratio(returns)
+      ```
+    """
+    ...
+
+def simple_returns(data):
+    """
+    Computes simple returns of a NumPy array of prices.
+
+    Each value is the relative change from the previous price. The first value is NaN, as is
+    any value whose previous price is zero.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array of simple returns, with the first element NaN.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([100.0, 102.0, 99.96])
+      >>> returns = kand.simple_returns(prices)
+      ```
+    """
+    ...
+
+def simple_returns_inc(price, prev_price):
+    """
+    Calculates a single simple return incrementally.
+
+    Args:
+      price: The current price.
+      prev_price: The previous price.
+
+    Returns:
+      The simple return, or NaN if the previous price is zero.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> value = kand.simple_returns_inc(102.0, 100.0)
+      ```
+    """
+    ...
+
+def skew(prices, period):
+    """
+    Calculate rolling Skewness for a NumPy array
+
+    Skewness measures the asymmetry of values around their rolling mean. This is the population
+    skewness `sqrt(n) * M3 / M2^(3/2)`; a constant window gives NaN.
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for Skewness calculation (must be >= 3).
+
+    Returns:
+      A tuple of four 1-D NumPy arrays containing:
+      - Skewness values
+      - Rolling mean values
+      - Rolling sum of squared deviations from the mean
+      - Rolling sum of cubed deviations from the mean
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([1.0, 2.0, 3.0, 10.0, 4.0])
+      >>> skew, mean, m2, m3 = kand.skew(prices, 3)
+      ```
+    """
+    ...
+
+def skew_inc(price, prev_mean, prev_m2, prev_m3, old_price, period):
+    """
+    Calculate the latest rolling Skewness value incrementally
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_mean: Previous period's mean
+      prev_m2: Previous period's sum of squared deviations from the mean
+      prev_m3: Previous period's sum of cubed deviations from the mean
+      old_price: Price being removed from the period
+      period: Period for Skewness calculation (must be >= 3)
+
+    Returns:
+      A tuple containing:
+      - Latest Skewness value
+      - New mean
+      - New sum of squared deviations
+      - New sum of cubed deviations
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> # Window [1.0, 2.0, 3.0] slides to [2.0, 3.0, 10.0]
+      >>> skew, mean, m2, m3 = kand.skew_inc(
+      ...     10.0,  # current price
+      ...     2.0,   # previous mean
+      ...     2.0,   # previous sum of squared deviations
+      ...     0.0,   # previous sum of cubed deviations
+      ...     1.0,   # price to remove
+      ...     3      # period
+      ... )
+      ```
+    """
+    ...
+
 def sma(data, period):
     """
     Computes the Simple Moving Average (SMA) over a NumPy array.
@@ -2495,6 +5609,84 @@ def sma_inc(prev_sma, new_price, old_price, period):
     """
     ...
 
+def sortino(returns, period, mar=0.0, periods_per_year=252.0):
+    """
+    Computes the rolling Sortino ratio over a NumPy array of periodic returns.
+
+    Mean return in excess of the minimum acceptable return (MAR) over the downside deviation below it, annualized by sqrt(periods_per_year).
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window. Must be >= 2.
+      mar: Annual minimum acceptable return. Defaults to 0.0.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      A tuple of four 1-D NumPy arrays containing:
+      - Sortino ratio values
+      - Running sum of returns
+      - Running sum of squared shortfalls below the MAR
+      - Number of returns below the MAR in the window
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values
+      (0 for the count).
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+      >>> sortino, sum, sum_down_sq, down_count = kand.sortino(returns, 3)
+      ```
+    """
+    ...
+
+def sortino_inc(ret, prev_sum, prev_sum_down_sq, prev_down_count, old_ret, period, mar=0.0, periods_per_year=252.0):
+    """
+    Computes the latest rolling Sortino ratio incrementally.
+
+    Args:
+      ret: Newest return to include.
+      prev_sum: Previous sum of returns in the window.
+      prev_sum_down_sq: Previous sum of squared shortfalls below the MAR in the window.
+      prev_down_count: Previous number of returns below the MAR in the window.
+      old_ret: Oldest return to remove from the window.
+      period: Number of returns in the rolling window. Must be >= 2.
+      mar: Annual minimum acceptable return. Defaults to 0.0.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      A tuple containing (sortino, sum, sum_down_sq, down_count).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> sortino, sum, sum_down_sq, down_count = kand.sortino_inc(0.005, 0.005, 0.0004, 1, 0.01, 3)
+      ```
+    """
+    ...
+
+def sortino_ratio(returns, mar=0.0, periods_per_year=252.0):
+    """
+    Computes the Sortino ratio of an entire return series.
+
+    Args:
+      returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+      mar: Annual minimum acceptable return. Defaults to 0.0.
+      periods_per_year: Number of return periods per year. Defaults to 252.0.
+
+    Returns:
+      The Sortino ratio as a float, or NaN when it is undefined.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+      >>> ratio = kand.sortino_ratio(returns)
+      ```
+    """
+    ...
+
 def stddev(input, period):
     """
     Calculate Standard Deviation for a NumPy array
@@ -2527,6 +5719,8 @@ def stddev_inc(price, prev_sum, prev_sum_sq, old_price, period):
     """
     Calculate the latest Standard Deviation value incrementally
 
+    Deprecated: the running sums lose precision for large values; use `stddev_inc_welford`.
+
     Args:
       py: Python interpreter token
       price: Current period's price
@@ -2555,6 +5749,67 @@ def stddev_inc(price, prev_sum, prev_sum_sq, old_price, period):
     """
     ...
 
+def stddev_inc_welford(price, prev_mean, prev_m2, old_price, period):
+    """
+    Calculate the latest Standard Deviation value incrementally from the Welford state
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_mean: Previous period's mean
+      prev_m2: Previous period's sum of squared deviations from the mean
+      old_price: Price being removed from the period
+      period: Period for calculation (must be >= 2)
+
+    Returns:
+      A tuple containing:
+      - Latest Standard Deviation value
+      - New mean
+      - New sum of squared deviations
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> # Window [5.0, 8.0, 12.0] slides to [8.0, 12.0, 10.0]
+      >>> stddev, mean, m2 = kand.stddev_inc_welford(
+      ...     10.0,        # current price
+      ...     25.0 / 3.0,  # previous mean
+      ...     74.0 / 3.0,  # previous sum of squared deviations
+      ...     5.0,         # old price
+      ...     3            # period
+      ... )
+      ```
+    """
+    ...
+
+def stddev_welford(input, period):
+    """
+    Calculate Standard Deviation for a NumPy array, returning the Welford state
+
+    Standard Deviation measures the dispersion of values from their mean over a specified period.
+    It is calculated by taking the square root of the variance.
+
+    Args:
+      input: Input values as a 1-D NumPy array of type `TAFloat`.
+      period: Period for calculation (must be >= 2).
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Standard Deviation values
+      - Rolling mean values
+      - Rolling sum of squared deviations from the mean
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+      >>> stddev, mean, m2 = kand.stddev_welford(prices, 3)
+      ```
+    """
+    ...
+
 def stoch(high, low, close, k_period, k_slow_period, d_period):
     """
     Computes the Stochastic Oscillator indicator over NumPy arrays.
@@ -2860,6 +6115,52 @@ def tema_inc(new_price, prev_ema1, prev_ema2, prev_ema3, period):
     """
     ...
 
+def trade_returns(signal, price):
+    """
+    Extracts closed-trade returns from a position signal and a price series.
+
+    The sign of the signal gives the position held after each bar: long, short or flat. A trade
+    opens where the position becomes non-zero and closes where it changes again. A trade still
+    open at the end is not reported.
+
+    Args:
+      signal: Positions per bar as a 1-D NumPy array of type `TAInt`.
+      price: Prices as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array with one return per closed trade.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> signal = np.array([0, 1, 1, -1, -1, 0])
+      >>> price = np.array([100.0, 100.0, 104.0, 110.0, 105.0, 99.0])
+      >>> trades = kand.trade_returns(signal, price)
+      ```
+    """
+    ...
+
+def trade_stats(trades):
+    """
+    Computes the trade statistics of an entire NumPy array of trade returns.
+
+    Args:
+      trades: Per-trade returns as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A tuple containing (win_rate, avg_win, avg_loss, profit_factor, expectancy, payoff_ratio).
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> trades = np.array([0.04, -0.02, 0.02, -0.01])
+      >>> win_rate, avg_win, avg_loss, profit_factor, expectancy, payoff = kand.trade_stats(trades)
+      ```
+    """
+    ...
+
 def trange(high, low, close):
     """
     Computes the True Range (TR) over NumPy arrays.
@@ -2875,6 +6176,7 @@ def trange(high, low, close):
     Returns:
       A new 1-D NumPy array containing the TR values. The array has the same length as the input,
       with the first element containing NaN value.
+
     Examples:
       ```python
       >>> import numpy as np
@@ -3032,6 +6334,58 @@ def trix_inc(price, prev_ema1, prev_ema2, prev_ema3, period):
     """
     ...
 
+def tsf(data, period):
+    """
+    Computes the Time Series Forecast (TSF) over a NumPy array.
+
+    Fits a least-squares line to the last `period` values and extends it one bar into the future.
+    Matches TA-Lib's `TSF`.
+
+    Args:
+      data: Input data as a 1-D NumPy array of type `TAFloat`.
+      period: Regression window. Must be >= 2 and less than input length.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Forecast values
+      - Running sum of values
+      - Running sum of index-weighted values
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 3.0, 5.0, 4.0, 6.0])
+      >>> tsf, sum_y, sum_xy = kand.tsf(data, 3)
+      ```
+    """
+    ...
+
+def tsf_inc(price, prev_sum_y, prev_sum_xy, old_price, period):
+    """
+    Computes the latest Time Series Forecast value incrementally.
+
+    Slides the regression window by one bar using the running sums of the previous window.
+
+    Args:
+      price: Newest value to include.
+      prev_sum_y: Previous sum of values in the window.
+      prev_sum_xy: Previous sum of index-weighted values in the window.
+      old_price: Oldest value to remove from the window.
+      period: Regression window. Must be >= 2.
+
+    Returns:
+      A tuple containing (TSF, sum_y, sum_xy).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> tsf, sum_y, sum_xy = kand.tsf_inc(7.0, 9.0, 13.0, 1.0, 3)
+      ```
+    """
+    ...
+
 def typprice(high, low, close):
     """
     Computes the Typical Price over NumPy arrays.
@@ -3114,6 +6468,8 @@ def var_inc(price, prev_sum, prev_sum_sq, old_price, period):
     """
     Calculate the latest Variance value incrementally
 
+    Deprecated: the running sums lose precision for large values; use `var_inc_welford`.
+
     Args:
       py: Python interpreter token
       price: Current period's price
@@ -3142,6 +6498,66 @@ def var_inc(price, prev_sum, prev_sum_sq, old_price, period):
     """
     ...
 
+def var_inc_welford(price, prev_mean, prev_m2, old_price, period):
+    """
+    Calculate the latest Variance value incrementally from the Welford state
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_mean: Previous period's mean
+      prev_m2: Previous period's sum of squared deviations from the mean
+      old_price: Price being removed from the period
+      period: Period for Variance calculation (must be >= 2)
+
+    Returns:
+      A tuple containing:
+      - Latest Variance value
+      - New mean
+      - New sum of squared deviations
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> # Window [5.0, 8.0, 12.0] slides to [8.0, 12.0, 10.0]
+      >>> var, mean, m2 = kand.var_inc_welford(
+      ...     10.0,        # current price
+      ...     25.0 / 3.0,  # previous mean
+      ...     74.0 / 3.0,  # previous sum of squared deviations
+      ...     5.0,         # price to remove
+      ...     3            # period
+      ... )
+      ```
+    """
+    ...
+
+def var_welford(prices, period):
+    """
+    Calculate Variance (VAR) for a NumPy array, returning the Welford state
+
+    Variance measures the average squared deviation of data points from their mean over a specified period.
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for Variance calculation (must be >= 2).
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Variance values
+      - Rolling mean values
+      - Rolling sum of squared deviations from the mean
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([2.0, 4.0, 6.0, 8.0, 10.0])
+      >>> var, mean, m2 = kand.var_welford(prices, 3)
+      ```
+    """
+    ...
+
 def vegas(prices):
     """
     Computes the VEGAS (Volume and EMA Guided Adaptive Scaling) indicator over NumPy arrays.
@@ -3340,6 +6756,9 @@ def willr_inc(prev_highest_high, prev_lowest_low, prev_high, prev_low, close, hi
     """
     Incrementally calculates Williams %R for the latest data point.
 
+    Deprecated: the extremes are approximate once they leave the window; use `willr` on the full
+    series.
+
     This function provides an optimized way to calculate the latest Williams %R value
     by using previously calculated highest high and lowest low values.
 
@@ -3366,6 +6785,34 @@ def willr_inc(prev_highest_high, prev_lowest_low, prev_high, prev_low, close, hi
     """
     ...
 
+def winrate(trades, period):
+    """
+    Computes rolling trade statistics over a NumPy array of trade returns.
+
+    Args:
+      trades: Per-trade returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of trades in the rolling window. Must be >= 1.
+
+    Returns:
+      A tuple of six 1-D NumPy arrays containing:
+      - Win rate (fraction of winning trades)
+      - Average winning return
+      - Average losing return magnitude
+      - Profit factor (inf when the window has winners but no losers)
+      - Expectancy (average return per trade)
+      - Payoff ratio (average win over average loss)
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> trades = np.array([0.04, -0.02, 0.02, -0.01, 0.03])
+      >>> win_rate, avg_win, avg_loss, profit_factor, expectancy, payoff = kand.winrate(trades, 4)
+      ```
+    """
+    ...
+
 def wma(data, period):
     """
     Computes the Weighted Moving Average (WMA) over a NumPy array.
@@ -3417,3 +6864,184 @@ def wma_inc(input_window, period):
         ```
     """
     ...
+
+def xirr(values, dates, guess=0.1):
+    """
+    Computes the annual internal rate of return of dated cash flows, as the spreadsheet `XIRR`
+    function does.
+
+    Args:
+      values: Cash flows as a 1-D NumPy array of type `TAFloat`.
+      dates: Day numbers of the cash flows as a 1-D NumPy array of type `TAInt`, none earlier
+        than the first.
+      guess: Starting rate for the solver. Must be > -1. Defaults to 0.1.
+
+    Returns:
+      The annual rate of return as a float.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> values = np.array([-10000.0, 2750.0, 4250.0, 3250.0, 2750.0])
+      >>> dates = np.array([39448, 39508, 39751, 39859, 39904])
+      >>> rate = kand.xirr(values, dates)
+      ```
+    """
+    ...
+
+def yang_zhang(open, high, low, close, period, periods_per_year=252.0):
+    """
+    Calculate rolling Yang-Zhang volatility for NumPy arrays
+
+    Yang-Zhang volatility adds the overnight gap to a weighted mix of the open-to-close and Rogers-Satchell variances, annualized by `periods_per_year`.
+
+    Args:
+      open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Closing prices as a 1-D NumPy array of type `TAFloat`.
+      period: Number of bars in the rolling window (must be >= 2).
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple of six 1-D NumPy arrays containing:
+      - Yang-Zhang volatility values
+      - Rolling mean of overnight returns
+      - Rolling M2 of overnight returns
+      - Rolling mean of open-to-close returns
+      - Rolling M2 of open-to-close returns
+      - Rolling sum of Rogers-Satchell terms
+      Each array has the same length as the input, with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> open_ = np.array([100.0, 101.0, 101.5, 102.0, 103.0])
+      >>> high = np.array([101.0, 102.5, 102.0, 103.5, 104.0])
+      >>> low = np.array([99.0, 100.5, 100.0, 101.0, 102.0])
+      >>> close = np.array([100.5, 101.5, 101.0, 103.0, 103.5])
+      >>> yang_zhang, *state = kand.yang_zhang(open_, high, low, close, 3)
+      ```
+    """
+    ...
+
+def yang_zhang_inc(open, high, low, close, prev_close, old_open, old_high, old_low, old_close, old_prev_close, prev_overnight_mean, prev_overnight_m2, prev_intraday_mean, prev_intraday_m2, prev_rs_sum, period, periods_per_year=252.0):
+    """
+    Calculate the latest Yang-Zhang volatility value incrementally
+
+    Args:
+      py: Python interpreter token
+      open: Opening price of the newest bar
+      high: High price of the newest bar
+      low: Low price of the newest bar
+      close: Closing price of the newest bar
+      prev_close: Closing price of the bar before the newest one
+      old_open: Opening price of the bar leaving the window
+      old_high: High price of the bar leaving the window
+      old_low: Low price of the bar leaving the window
+      old_close: Closing price of the bar leaving the window
+      old_prev_close: Closing price of the bar before the one leaving the window
+      prev_overnight_mean: Previous mean of overnight returns
+      prev_overnight_m2: Previous M2 of overnight returns
+      prev_intraday_mean: Previous mean of open-to-close returns
+      prev_intraday_m2: Previous M2 of open-to-close returns
+      prev_rs_sum: Previous sum of Rogers-Satchell terms
+      period: Number of bars in the rolling window (must be >= 2)
+      periods_per_year: Number of bars per year. Defaults to 252.0.
+
+    Returns:
+      A tuple containing:
+      - Latest Yang-Zhang volatility
+      - New mean of overnight returns
+      - New M2 of overnight returns
+      - New mean of open-to-close returns
+      - New M2 of open-to-close returns
+      - New sum of Rogers-Satchell terms
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> yang_zhang, *state = kand.yang_zhang_inc(
+      ...     103.0,   # opening price of the newest bar
+      ...     104.0,   # high price of the newest bar
+      ...     102.0,   # low price of the newest bar
+      ...     103.5,   # closing price of the newest bar
+      ...     103.0,   # closing price of the bar before the newest one
+      ...     101.0,   # opening price of the bar leaving the window
+      ...     102.5,   # high price of the bar leaving the window
+      ...     100.5,   # low price of the bar leaving the window
+      ...     101.5,   # closing price of the bar leaving the window
+      ...     100.5,   # closing price of the bar before the one leaving the window
+      ...     0.001,   # previous mean of overnight returns
+      ...     0.0001,  # previous M2 of overnight returns
+      ...     0.001,   # previous mean of open-to-close returns
+      ...     0.0001,  # previous M2 of open-to-close returns
+      ...     0.0001,  # previous sum of Rogers-Satchell terms
+      ...     3        # period
+      ... )
+      ```
+    """
+    ...
+
+def zscore(prices, period):
+    """
+    Calculate the rolling Z-Score for a NumPy array
+
+    The Z-Score is the number of population standard deviations the latest price lies above or
+    below the mean of the last `period` prices; a constant window gives NaN.
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for Z-Score calculation (must be >= 2).
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Z-Score values
+      - Rolling mean values
+      - Rolling sum of squared deviations from the mean
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([2.0, 4.0, 6.0, 8.0, 10.0])
+      >>> zscore, mean, m2 = kand.zscore(prices, 3)
+      ```
+    """
+    ...
+
+def zscore_inc(price, prev_mean, prev_m2, old_price, period):
+    """
+    Calculate the latest rolling Z-Score value incrementally
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_mean: Previous period's mean
+      prev_m2: Previous period's sum of squared deviations from the mean
+      old_price: Price being removed from the period
+      period: Period for Z-Score calculation (must be >= 2)
+
+    Returns:
+      A tuple containing:
+      - Latest Z-Score value
+      - New mean
+      - New sum of squared deviations
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> # Window [5.0, 8.0, 12.0] slides to [8.0, 12.0, 10.0]
+      >>> zscore, mean, m2 = kand.zscore_inc(
+      ...     10.0,        # current price
+      ...     25.0 / 3.0,  # previous mean
+      ...     74.0 / 3.0,  # previous sum of squared deviations
+      ...     5.0,         # price to remove
+      ...     3            # period
+      ... )
+      ```
+    """
+    ...
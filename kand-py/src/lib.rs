@@ -24,35 +24,85 @@ fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ta::ohlcv::atr::atr_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::atr::atr_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::bbands::bbands_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::bbands::bbands_welford_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::bbands::bbands_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::bbands::bbands_inc_welford_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::bop::bop_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::bop::bop_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cci::cci_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cci::cci_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_dark_cloud_cover::cdl_dark_cloud_cover_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_dark_cloud_cover::cdl_dark_cloud_cover_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_doji::cdl_doji_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_doji::cdl_doji_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_dragonfly_doji::cdl_dragonfly_doji_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_dragonfly_doji::cdl_dragonfly_doji_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_engulfing::cdl_engulfing_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_engulfing::cdl_engulfing_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_evening_doji_star::cdl_evening_doji_star_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_evening_doji_star::cdl_evening_doji_star_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_evening_star::cdl_evening_star_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_evening_star::cdl_evening_star_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_gravestone_doji::cdl_gravestone_doji_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_gravestone_doji::cdl_gravestone_doji_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_hammer::cdl_hammer_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_hammer::cdl_hammer_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_harami::cdl_harami_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_harami::cdl_harami_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_harami_cross::cdl_harami_cross_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_harami_cross::cdl_harami_cross_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_inverted_hammer::cdl_inverted_hammer_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_inverted_hammer::cdl_inverted_hammer_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_long_shadow::cdl_long_shadow_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_long_shadow::cdl_long_shadow_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_marubozu::cdl_marubozu_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_marubozu::cdl_marubozu_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_morning_doji_star::cdl_morning_doji_star_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_morning_doji_star::cdl_morning_doji_star_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_morning_star::cdl_morning_star_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_morning_star::cdl_morning_star_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_piercing::cdl_piercing_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_piercing::cdl_piercing_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_scan::cdl_scan_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_three_black_crows::cdl_three_black_crows_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_three_black_crows::cdl_three_black_crows_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_three_inside::cdl_three_inside_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_three_inside::cdl_three_inside_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_three_outside::cdl_three_outside_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_three_outside::cdl_three_outside_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_three_white_soldiers::cdl_three_white_soldiers_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_three_white_soldiers::cdl_three_white_soldiers_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::dema::dema_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::dema::dema_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::donchian::donchian_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::dx::dx_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::dx::dx_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::ecl::ecl_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::ecl::ecl_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::ema::ema_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::ema::ema_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ht_dcperiod::ht_dcperiod_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ht_dcphase::ht_dcphase_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ht_phasor::ht_phasor_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ht_sine::ht_sine_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ht_trendline::ht_trendline_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ht_trendmode::ht_trendmode_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ichimoku::ichimoku_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::kama::kama_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::kama::kama_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::keltner::keltner_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::keltner::keltner_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::linearreg::linearreg_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::linearreg::linearreg_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::linearreg_angle::linearreg_angle_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::linearreg_angle::linearreg_angle_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::linearreg_intercept::linearreg_intercept_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::linearreg_intercept::linearreg_intercept_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::linearreg_slope::linearreg_slope_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::linearreg_slope::linearreg_slope_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::macd::macd_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::macd::macd_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::mama::mama_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::medprice::medprice_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::medprice::medprice_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::mfi::mfi_py, m)?)?;
@@ -101,6 +151,8 @@ fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ta::ohlcv::trima::trima_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::trix::trix_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::trix::trix_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::tsf::tsf_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::tsf::tsf_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::typprice::typprice_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::typprice::typprice_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::vegas::vegas_py, m)?)?;
@@ -115,18 +167,90 @@ fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ta::ohlcv::vwap::vwap_inc_py, m)?)?;
 
     // Add all stats functions
+    m.add_function(wrap_pyfunction!(ta::stats::alpha::alpha_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::alpha::alpha_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::beta::beta_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::beta::beta_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::beta::capm_beta_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::beta::capm_beta_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::calmar::calmar_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::calmar::calmar_ratio_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::correl::correl_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::correl::correl_welford_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::correl::correl_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::correl::correl_inc_welford_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::drawdown::drawdown_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::drawdown::max_drawdown_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::fv::fv_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::garman_klass::garman_klass_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::garman_klass::garman_klass_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::hv::hv_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::hv::hv_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::irr::irr_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::irr::xirr_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::kelly::kelly_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::kelly::kelly_criterion_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::kurt::kurt_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::kurt::kurt_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::max::max_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::max::max_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::min::min_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::min::min_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::nper::nper_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::npv::npv_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::parkinson::parkinson_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::parkinson::parkinson_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::percentrank::percentrank_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::pmt::pmt_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::pv::pv_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::quantile::quantile_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::quantile::median_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::rate::rate_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::simple_returns_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::simple_returns_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::log_returns_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::log_returns_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::cumulative_returns_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::cumulative_returns_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::rolling_returns_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::rolling_returns_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::excess_returns_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::excess_returns_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::risk::historical_var_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::risk::historical_es_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::risk::gaussian_var_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::risk::gaussian_var_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::risk::gaussian_es_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::risk::gaussian_es_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::risk::cornish_fisher_var_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::risk::cornish_fisher_var_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::rogers_satchell::rogers_satchell_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::rogers_satchell::rogers_satchell_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sharpe::sharpe_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sharpe::sharpe_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sharpe::sharpe_ratio_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::skew::skew_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::skew::skew_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sortino::sortino_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sortino::sortino_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sortino::sortino_ratio_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::stddev::stddev_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::stddev::stddev_welford_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::stddev::stddev_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::stddev::stddev_inc_welford_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::sum::sum_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::sum::sum_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::var::var_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::var::var_welford_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::var::var_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::var::var_inc_welford_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::winrate::winrate_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::winrate::trade_stats_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::winrate::trade_returns_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::yang_zhang::yang_zhang_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::yang_zhang::yang_zhang_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::zscore::zscore_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::zscore::zscore_inc_py, m)?)?;
 
     // Add all helper functions
 
@@ -83,6 +83,9 @@ pub fn aroon_py(
 
 /// Calculate the next Aroon values incrementally.
 ///
+/// Deprecated: the days since counters are capped once an extreme leaves the window; use `aroon` on
+/// the full series.
+///
 /// Args:
 ///
 ///   high: Current period's high price.
@@ -116,6 +119,7 @@ pub fn aroon_py(
 ///   ... )
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "aroon_inc", signature = (
     high,
     low,
@@ -78,6 +78,9 @@ pub fn aroonosc_py(
 
 /// Calculate the next Aroon Oscillator value incrementally.
 ///
+/// Deprecated: the days since counters are capped once an extreme leaves the window; use `aroonosc`
+/// on the full series.
+///
 /// Args:
 ///
 ///   high: Current period's high price.
@@ -110,6 +113,7 @@ pub fn aroonosc_py(
 ///   ... )
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "aroonosc_inc", signature = (
     high,
     low,
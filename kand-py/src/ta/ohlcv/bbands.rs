@@ -89,8 +89,98 @@ pub fn bbands_py(
     ))
 }
 
+/// Calculate Bollinger Bands for a NumPy array, returning the Welford state.
+///
+/// Bollinger Bands consist of:
+/// - A middle band (N-period simple moving average)
+/// - An upper band (K standard deviations above middle band)
+/// - A lower band (K standard deviations below middle band)
+///
+/// Args:
+///   price: Input price values as a 1-D NumPy array of type `TAFloat`.
+///   period: The time period for calculations (must be >= 2).
+///   dev_up: Number of standard deviations for upper band.
+///   dev_down: Number of standard deviations for lower band.
+///
+/// Returns:
+///   A tuple of 7 1-D NumPy arrays containing:
+///   - Upper band values
+///   - Middle band values
+///   - Lower band values
+///   - SMA values
+///   - Variance values
+///   - Rolling mean values
+///   - Rolling sum of squared deviations
+///   The first (period-1) elements of each array contain NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> price = np.array([10.0, 11.0, 12.0, 13.0, 14.0])
+///   >>> upper, middle, lower, sma, var, mean, m2 = kand.bbands_welford(price, 3, 2.0, 2.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "bbands_welford", signature = (price, period, dev_up, dev_down))]
+#[allow(clippy::type_complexity)]
+pub fn bbands_welford_py(
+    py: Python,
+    price: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    dev_up: TAFloat,
+    dev_down: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let price_slice = price.as_slice()?;
+    let len = price_slice.len();
+
+    let mut output_upper = vec![0.0; len];
+    let mut output_middle = vec![0.0; len];
+    let mut output_lower = vec![0.0; len];
+    let mut output_sma = vec![0.0; len];
+    let mut output_var = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        bbands::bbands_welford(
+            price_slice,
+            period,
+            dev_up,
+            dev_down,
+            output_upper.as_mut_slice(),
+            output_middle.as_mut_slice(),
+            output_lower.as_mut_slice(),
+            output_sma.as_mut_slice(),
+            output_var.as_mut_slice(),
+            output_mean.as_mut_slice(),
+            output_m2.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_upper.into_pyarray(py).into(),
+        output_middle.into_pyarray(py).into(),
+        output_lower.into_pyarray(py).into(),
+        output_sma.into_pyarray(py).into(),
+        output_var.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+    ))
+}
+
 /// Calculate the next Bollinger Bands values incrementally.
 ///
+/// Deprecated: the running sums lose precision for large values; use `bbands_inc_welford`.
+///
 /// Args:
 ///
 ///   price: The current price value.
@@ -126,6 +216,7 @@ pub fn bbands_py(
 ///   ... )
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "bbands_inc", signature = (
     price,
     prev_sma,
@@ -161,3 +252,69 @@ pub fn bbands_inc_py(
     })
     .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Calculate the next Bollinger Bands values incrementally from the Welford state.
+///
+/// Args:
+///
+///   price: The current price value.
+///   prev_sma: The previous SMA value.
+///   prev_mean: The previous rolling mean for variance calculation.
+///   prev_m2: The previous sum of squared deviations for variance calculation.
+///   old_price: The oldest price value to be removed from the period.
+///   period: The time period for calculations (must be >= 2).
+///   dev_up: Number of standard deviations for upper band.
+///   dev_down: Number of standard deviations for lower band.
+///
+/// Returns:
+///   A tuple containing:
+///   - Upper Band value
+///   - Middle Band value
+///   - Lower Band value
+///   - New SMA value
+///   - New rolling mean value
+///   - New sum of squared deviations value
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> upper, middle, lower, sma, mean, m2 = kand.bbands_inc_welford(
+///   ...     10.0,   # price
+///   ...     9.5,    # prev_sma
+///   ...     9.5,    # prev_mean
+///   ...     0.5,    # prev_m2
+///   ...     9.0,    # old_price
+///   ...     3,      # period
+///   ...     2.0,    # dev_up
+///   ...     2.0     # dev_down
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "bbands_inc_welford", signature = (
+    price,
+    prev_sma,
+    prev_mean,
+    prev_m2,
+    old_price,
+    period,
+    dev_up,
+    dev_down
+))]
+pub fn bbands_inc_welford_py(
+    py: Python,
+    price: TAFloat,
+    prev_sma: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    old_price: TAFloat,
+    period: usize,
+    dev_up: TAFloat,
+    dev_down: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        bbands::bbands_inc_welford(
+            price, prev_sma, prev_mean, prev_m2, old_price, period, dev_up, dev_down,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,105 @@
+use kand::{Bar, TAFloat, TAInt, ohlcv::cdl_dark_cloud_cover};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Detects Dark Cloud Cover candlestick patterns in price data.
+///
+/// Args:
+///   open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+///   penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.5.
+///
+/// Returns:
+///   A 1-D NumPy array containing pattern signals:
+///     - -100: Dark Cloud Cover
+///     - 0: No pattern detected
+///     - -1: Fewer than two bars available
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> open = np.array([10.0, 12.5, 10.8, 12.9])
+///   >>> high = np.array([12.1, 12.6, 12.8, 13.0])
+///   >>> low = np.array([9.9, 10.5, 10.7, 12.0])
+///   >>> close = np.array([12.0, 10.8, 12.7, 12.2])
+///   >>> kand.cdl_dark_cloud_cover(open, high, low, close)
+///   array([-1, -100, 0, 0])
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_dark_cloud_cover", signature = (open, high, low, close, body_long_percent=60.0, penetration=0.5))]
+pub fn cdl_dark_cloud_cover_py(
+    py: Python,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    body_long_percent: TAFloat,
+    penetration: TAFloat,
+) -> PyResult<Py<PyArray1<TAInt>>> {
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_open.len();
+
+    let mut output_signals = vec![0; len];
+
+    py.allow_threads(|| {
+        cdl_dark_cloud_cover::cdl_dark_cloud_cover(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            body_long_percent,
+            penetration,
+            &mut output_signals,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_signals.into_pyarray(py).into())
+}
+
+/// Detects a Dark Cloud Cover pattern on the last two candlesticks.
+///
+/// Args:
+///
+///   open: Opening prices of the last two candles, oldest first.
+///   high: High prices of the last two candles, oldest first.
+///   low: Low prices of the last two candles, oldest first.
+///   close: Close prices of the last two candles, oldest first.
+///   body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+///   penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.5.
+///
+/// Returns:
+///   Signal value for the newest candle.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.cdl_dark_cloud_cover_inc([10.0, 12.5], [12.1, 12.6], [9.9, 10.5], [12.0, 10.8])
+///   -100
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_dark_cloud_cover_inc", signature = (open, high, low, close, body_long_percent=60.0, penetration=0.5))]
+pub fn cdl_dark_cloud_cover_inc_py(
+    py: Python,
+    open: [TAFloat; 2],
+    high: [TAFloat; 2],
+    low: [TAFloat; 2],
+    close: [TAFloat; 2],
+    body_long_percent: TAFloat,
+    penetration: TAFloat,
+) -> PyResult<TAInt> {
+    let input_bars: [Bar; 2] =
+        core::array::from_fn(|i| Bar::new(open[i], high[i], low[i], close[i], 0.0));
+
+    py.allow_threads(|| {
+        cdl_dark_cloud_cover::cdl_dark_cloud_cover_inc(&input_bars, body_long_percent, penetration)
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
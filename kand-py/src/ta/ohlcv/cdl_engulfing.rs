@@ -0,0 +1,94 @@
+use kand::{Bar, TAFloat, TAInt, ohlcv::cdl_engulfing};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Detects Engulfing candlestick patterns in price data.
+///
+/// Args:
+///   open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array containing pattern signals:
+///     - 100: Bullish Engulfing
+///     - -100: Bearish Engulfing
+///     - 0: No pattern detected
+///     - -1: Fewer than two bars available
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> open = np.array([10.0, 8.9, 11.0, 11.8])
+///   >>> high = np.array([10.5, 11.2, 11.8, 12.0])
+///   >>> low = np.array([8.8, 8.7, 10.8, 10.0])
+///   >>> close = np.array([9.0, 11.0, 11.5, 10.2])
+///   >>> kand.cdl_engulfing(open, high, low, close)
+///   array([-1, 100, 0, -100])
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_engulfing", signature = (open, high, low, close))]
+pub fn cdl_engulfing_py(
+    py: Python,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAInt>>> {
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_open.len();
+
+    let mut output_signals = vec![0; len];
+
+    py.allow_threads(|| {
+        cdl_engulfing::cdl_engulfing(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            &mut output_signals,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_signals.into_pyarray(py).into())
+}
+
+/// Detects a Engulfing pattern on the last two candlesticks.
+///
+/// Args:
+///
+///   open: Opening prices of the last two candles, oldest first.
+///   high: High prices of the last two candles, oldest first.
+///   low: Low prices of the last two candles, oldest first.
+///   close: Close prices of the last two candles, oldest first.
+///
+/// Returns:
+///   Signal value for the newest candle.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.cdl_engulfing_inc([10.0, 8.9], [10.5, 11.2], [8.8, 8.7], [9.0, 11.0])
+///   100
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_engulfing_inc", signature = (open, high, low, close))]
+pub fn cdl_engulfing_inc_py(
+    py: Python,
+    open: [TAFloat; 2],
+    high: [TAFloat; 2],
+    low: [TAFloat; 2],
+    close: [TAFloat; 2],
+) -> PyResult<TAInt> {
+    let input_bars: [Bar; 2] =
+        core::array::from_fn(|i| Bar::new(open[i], high[i], low[i], close[i], 0.0));
+
+    py.allow_threads(|| cdl_engulfing::cdl_engulfing_inc(&input_bars))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,106 @@
+use kand::{Bar, TAFloat, TAInt, ohlcv::cdl_harami_cross};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Detects Harami Cross candlestick patterns in price data.
+///
+/// Args:
+///   open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+///   body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+///
+/// Returns:
+///   A 1-D NumPy array containing pattern signals:
+///     - 100: Harami Cross after a long black candle
+///     - -100: Harami Cross after a long white candle
+///     - 0: No pattern detected
+///     - -1: Fewer than two bars available
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> open = np.array([10.0, 9.0, 9.0, 10.0, 10.0])
+///   >>> high = np.array([10.2, 9.4, 11.1, 10.5, 10.6])
+///   >>> low = np.array([7.8, 8.6, 8.9, 9.5, 9.6])
+///   >>> close = np.array([8.0, 9.02, 11.0, 10.01, 10.5])
+///   >>> kand.cdl_harami_cross(open, high, low, close)
+///   array([-1, 100, 0, -100, 0])
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_harami_cross", signature = (open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0))]
+pub fn cdl_harami_cross_py(
+    py: Python,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    body_long_percent: TAFloat,
+    body_doji_percent: TAFloat,
+) -> PyResult<Py<PyArray1<TAInt>>> {
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_open.len();
+
+    let mut output_signals = vec![0; len];
+
+    py.allow_threads(|| {
+        cdl_harami_cross::cdl_harami_cross(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            body_long_percent,
+            body_doji_percent,
+            &mut output_signals,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_signals.into_pyarray(py).into())
+}
+
+/// Detects a Harami Cross pattern on the last two candlesticks.
+///
+/// Args:
+///
+///   open: Opening prices of the last two candles, oldest first.
+///   high: High prices of the last two candles, oldest first.
+///   low: Low prices of the last two candles, oldest first.
+///   close: Close prices of the last two candles, oldest first.
+///   body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+///   body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+///
+/// Returns:
+///   Signal value for the newest candle.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.cdl_harami_cross_inc([10.0, 9.0], [10.2, 9.4], [7.8, 8.6], [8.0, 9.02])
+///   100
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_harami_cross_inc", signature = (open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0))]
+pub fn cdl_harami_cross_inc_py(
+    py: Python,
+    open: [TAFloat; 2],
+    high: [TAFloat; 2],
+    low: [TAFloat; 2],
+    close: [TAFloat; 2],
+    body_long_percent: TAFloat,
+    body_doji_percent: TAFloat,
+) -> PyResult<TAInt> {
+    let input_bars: [Bar; 2] =
+        core::array::from_fn(|i| Bar::new(open[i], high[i], low[i], close[i], 0.0));
+
+    py.allow_threads(|| {
+        cdl_harami_cross::cdl_harami_cross_inc(&input_bars, body_long_percent, body_doji_percent)
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
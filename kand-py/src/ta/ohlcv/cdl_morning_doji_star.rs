@@ -0,0 +1,121 @@
+use kand::{Bar, TAFloat, TAInt, ohlcv::cdl_morning_doji_star};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Detects Morning Doji Star candlestick patterns in price data.
+///
+/// Args:
+///   open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+///   body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+///   body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+///   penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
+///
+/// Returns:
+///   A 1-D NumPy array containing pattern signals:
+///     - 100: Morning Doji Star
+///     - 0: No pattern detected
+///     - -1: Fewer than three bars available
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> open = np.array([12.0, 9.6, 9.8, 11.4, 10.2])
+///   >>> high = np.array([12.1, 9.9, 11.6, 11.5, 11.0])
+///   >>> low = np.array([9.9, 9.3, 9.7, 10.0, 10.1])
+///   >>> close = np.array([10.0, 9.61, 11.5, 10.1, 10.9])
+///   >>> kand.cdl_morning_doji_star(open, high, low, close)
+///   array([-1, -1, 100, 0, 0])
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_morning_doji_star", signature = (open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0, body_short_percent=30.0, penetration=0.3))]
+pub fn cdl_morning_doji_star_py(
+    py: Python,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    body_long_percent: TAFloat,
+    body_doji_percent: TAFloat,
+    body_short_percent: TAFloat,
+    penetration: TAFloat,
+) -> PyResult<Py<PyArray1<TAInt>>> {
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_open.len();
+
+    let mut output_signals = vec![0; len];
+
+    py.allow_threads(|| {
+        cdl_morning_doji_star::cdl_morning_doji_star(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            body_long_percent,
+            body_doji_percent,
+            body_short_percent,
+            penetration,
+            &mut output_signals,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_signals.into_pyarray(py).into())
+}
+
+/// Detects a Morning Doji Star pattern on the last three candlesticks.
+///
+/// Args:
+///
+///   open: Opening prices of the last three candles, oldest first.
+///   high: High prices of the last three candles, oldest first.
+///   low: Low prices of the last three candles, oldest first.
+///   close: Close prices of the last three candles, oldest first.
+///   body_long_percent: Minimum body size of a long candle as a percentage of its range. Defaults to 60.0.
+///   body_doji_percent: Maximum body size of a doji as a percentage of its range. Defaults to 5.0.
+///   body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+///   penetration: Fraction of the first candle's body the last close must penetrate. Defaults to 0.3.
+///
+/// Returns:
+///   Signal value for the newest candle.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.cdl_morning_doji_star_inc([12.0, 9.6, 9.8], [12.1, 9.9, 11.6], [9.9, 9.3, 9.7], [10.0, 9.61, 11.5])
+///   100
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_morning_doji_star_inc", signature = (open, high, low, close, body_long_percent=60.0, body_doji_percent=5.0, body_short_percent=30.0, penetration=0.3))]
+pub fn cdl_morning_doji_star_inc_py(
+    py: Python,
+    open: [TAFloat; 3],
+    high: [TAFloat; 3],
+    low: [TAFloat; 3],
+    close: [TAFloat; 3],
+    body_long_percent: TAFloat,
+    body_doji_percent: TAFloat,
+    body_short_percent: TAFloat,
+    penetration: TAFloat,
+) -> PyResult<TAInt> {
+    let input_bars: [Bar; 3] =
+        core::array::from_fn(|i| Bar::new(open[i], high[i], low[i], close[i], 0.0));
+
+    py.allow_threads(|| {
+        cdl_morning_doji_star::cdl_morning_doji_star_inc(
+            &input_bars,
+            body_long_percent,
+            body_doji_percent,
+            body_short_percent,
+            penetration,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,82 @@
+use kand::{
+    TAFloat,
+    helper::CandleSettings,
+    ohlcv::cdl_scan::{self, CdlMatches},
+};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Scans price data for every supported candlestick pattern in one pass.
+///
+/// Patterns are judged with TA-Lib's default candle settings. Each output array holds one
+/// bitmask per bar; bit `1 << id` is set when the pattern with that id matched:
+///
+///   0: Dark Cloud Cover, 1: Doji, 2: Dragonfly Doji, 3: Engulfing, 4: Evening Doji Star,
+///   5: Evening Star, 6: Gravestone Doji, 7: Hammer, 8: Harami, 9: Harami Cross,
+///   10: Inverted Hammer, 11: Long Shadow, 12: Marubozu, 13: Morning Doji Star,
+///   14: Morning Star, 15: Piercing, 16: Three Black Crows, 17: Three Inside,
+///   18: Three Outside, 19: Three White Soldiers
+///
+/// Args:
+///   open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays of type `uint32`:
+///   - Patterns that signalled bullish (100)
+///   - Patterns that signalled bearish (-100)
+///   - Non-directional patterns (1), such as Doji
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> open = np.array([10.0] * 12 + [9.6])
+///   >>> high = np.array([10.5] * 12 + [9.68])
+///   >>> low = np.array([9.5] * 12 + [9.0])
+///   >>> close = np.array([10.2] * 12 + [9.65])
+///   >>> bullish, bearish, pattern = kand.cdl_scan(open, high, low, close)
+///   >>> bool(bullish[12] & (1 << 7))  # Hammer
+///   True
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_scan", signature = (open, high, low, close))]
+pub fn cdl_scan_py(
+    py: Python,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+) -> PyResult<(Py<PyArray1<u32>>, Py<PyArray1<u32>>, Py<PyArray1<u32>>)> {
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_open.len();
+
+    let mut output_matches = vec![CdlMatches::default(); len];
+
+    py.allow_threads(|| {
+        cdl_scan::cdl_scan(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            &CandleSettings::default(),
+            &mut output_matches,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    let output_bullish: Vec<u32> = output_matches.iter().map(|m| m.bullish).collect();
+    let output_bearish: Vec<u32> = output_matches.iter().map(|m| m.bearish).collect();
+    let output_pattern: Vec<u32> = output_matches.iter().map(|m| m.pattern).collect();
+
+    Ok((
+        output_bullish.into_pyarray(py).into(),
+        output_bearish.into_pyarray(py).into(),
+        output_pattern.into_pyarray(py).into(),
+    ))
+}
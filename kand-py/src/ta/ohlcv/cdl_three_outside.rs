@@ -0,0 +1,94 @@
+use kand::{Bar, TAFloat, TAInt, ohlcv::cdl_three_outside};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Detects Three Outside Up/Down candlestick patterns in price data.
+///
+/// Args:
+///   open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array containing pattern signals:
+///     - 100: Three Outside Up
+///     - -100: Three Outside Down
+///     - 0: No pattern detected
+///     - -1: Fewer than three bars available
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> open = np.array([10.0, 8.9, 11.0, 11.5, 10.1])
+///   >>> high = np.array([10.5, 11.2, 11.8, 11.6, 10.2])
+///   >>> low = np.array([8.8, 8.7, 10.8, 10.0, 9.5])
+///   >>> close = np.array([9.0, 11.0, 11.5, 10.2, 9.6])
+///   >>> kand.cdl_three_outside(open, high, low, close)
+///   array([-1, -1, 100, 0, -100])
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_three_outside", signature = (open, high, low, close))]
+pub fn cdl_three_outside_py(
+    py: Python,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAInt>>> {
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_open.len();
+
+    let mut output_signals = vec![0; len];
+
+    py.allow_threads(|| {
+        cdl_three_outside::cdl_three_outside(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            &mut output_signals,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_signals.into_pyarray(py).into())
+}
+
+/// Detects a Three Outside Up/Down pattern on the last three candlesticks.
+///
+/// Args:
+///
+///   open: Opening prices of the last three candles, oldest first.
+///   high: High prices of the last three candles, oldest first.
+///   low: Low prices of the last three candles, oldest first.
+///   close: Close prices of the last three candles, oldest first.
+///
+/// Returns:
+///   Signal value for the newest candle.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.cdl_three_outside_inc([10.0, 8.9, 11.0], [10.5, 11.2, 11.8], [8.8, 8.7, 10.8], [9.0, 11.0, 11.5])
+///   100
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_three_outside_inc", signature = (open, high, low, close))]
+pub fn cdl_three_outside_inc_py(
+    py: Python,
+    open: [TAFloat; 3],
+    high: [TAFloat; 3],
+    low: [TAFloat; 3],
+    close: [TAFloat; 3],
+) -> PyResult<TAInt> {
+    let input_bars: [Bar; 3] =
+        core::array::from_fn(|i| Bar::new(open[i], high[i], low[i], close[i], 0.0));
+
+    py.allow_threads(|| cdl_three_outside::cdl_three_outside_inc(&input_bars))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
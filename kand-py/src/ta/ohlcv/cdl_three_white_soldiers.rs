@@ -0,0 +1,109 @@
+use kand::{Bar, TAFloat, TAInt, ohlcv::cdl_three_white_soldiers};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Detects Three White Soldiers candlestick patterns in price data.
+///
+/// Args:
+///   open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   shadow_very_short_percent: Maximum shadow length of a very short shadow as a percentage of the candle range. Defaults to 10.0.
+///   body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+///
+/// Returns:
+///   A 1-D NumPy array containing pattern signals:
+///     - 100: Three White Soldiers
+///     - 0: No pattern detected
+///     - -1: Fewer than three bars available
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> open = np.array([10.0, 10.8, 11.8, 13.0])
+///   >>> high = np.array([11.05, 12.05, 13.05, 14.0])
+///   >>> low = np.array([9.9, 10.7, 11.7, 12.9])
+///   >>> close = np.array([11.0, 12.0, 13.0, 13.2])
+///   >>> kand.cdl_three_white_soldiers(open, high, low, close)
+///   array([-1, -1, 100, 0])
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_three_white_soldiers", signature = (open, high, low, close, shadow_very_short_percent=10.0, body_short_percent=30.0))]
+pub fn cdl_three_white_soldiers_py(
+    py: Python,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    shadow_very_short_percent: TAFloat,
+    body_short_percent: TAFloat,
+) -> PyResult<Py<PyArray1<TAInt>>> {
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_open.len();
+
+    let mut output_signals = vec![0; len];
+
+    py.allow_threads(|| {
+        cdl_three_white_soldiers::cdl_three_white_soldiers(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            shadow_very_short_percent,
+            body_short_percent,
+            &mut output_signals,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_signals.into_pyarray(py).into())
+}
+
+/// Detects a Three White Soldiers pattern on the last three candlesticks.
+///
+/// Args:
+///
+///   open: Opening prices of the last three candles, oldest first.
+///   high: High prices of the last three candles, oldest first.
+///   low: Low prices of the last three candles, oldest first.
+///   close: Close prices of the last three candles, oldest first.
+///   shadow_very_short_percent: Maximum shadow length of a very short shadow as a percentage of the candle range. Defaults to 10.0.
+///   body_short_percent: Maximum body size of a short candle as a percentage of its range. Defaults to 30.0.
+///
+/// Returns:
+///   Signal value for the newest candle.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.cdl_three_white_soldiers_inc([10.0, 10.8, 11.8], [11.05, 12.05, 13.05], [9.9, 10.7, 11.7], [11.0, 12.0, 13.0])
+///   100
+///   ```
+#[pyfunction]
+#[pyo3(name = "cdl_three_white_soldiers_inc", signature = (open, high, low, close, shadow_very_short_percent=10.0, body_short_percent=30.0))]
+pub fn cdl_three_white_soldiers_inc_py(
+    py: Python,
+    open: [TAFloat; 3],
+    high: [TAFloat; 3],
+    low: [TAFloat; 3],
+    close: [TAFloat; 3],
+    shadow_very_short_percent: TAFloat,
+    body_short_percent: TAFloat,
+) -> PyResult<TAInt> {
+    let input_bars: [Bar; 3] =
+        core::array::from_fn(|i| Bar::new(open[i], high[i], low[i], close[i], 0.0));
+
+    py.allow_threads(|| {
+        cdl_three_white_soldiers::cdl_three_white_soldiers_inc(
+            &input_bars,
+            shadow_very_short_percent,
+            body_short_percent,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
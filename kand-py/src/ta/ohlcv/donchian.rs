@@ -0,0 +1,67 @@
+use kand::{TAFloat, ohlcv::donchian};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculates Donchian Channels for NumPy arrays.
+///
+/// Donchian Channels bracket price with the highest high and the lowest low of the last `period`
+/// bars, with a middle channel halfway between them.
+///
+/// Args:
+///   high: Input high price data as a 1-D NumPy array of type `TAFloat`.
+///   low: Input low price data as a 1-D NumPy array of type `TAFloat`.
+///   period: Time period for calculation (must be >= 2).
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Upper channel values (highest high)
+///   - Middle channel values
+///   - Lower channel values (lowest low)
+///   Each array has the same length as the input, with the first `period-1` elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+///   >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+///   >>> upper, middle, lower = kand.donchian(high, low, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "donchian", signature = (high, low, period))]
+pub fn donchian_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let len = input_high.len();
+
+    let mut output_upper = vec![0.0; len];
+    let mut output_middle = vec![0.0; len];
+    let mut output_lower = vec![0.0; len];
+
+    py.allow_threads(|| {
+        donchian::donchian(
+            input_high,
+            input_low,
+            period,
+            output_upper.as_mut_slice(),
+            output_middle.as_mut_slice(),
+            output_lower.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_upper.into_pyarray(py).into(),
+        output_middle.into_pyarray(py).into(),
+        output_lower.into_pyarray(py).into(),
+    ))
+}
@@ -0,0 +1,42 @@
+use kand::{TAFloat, ohlcv::ht_dcperiod};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Hilbert Transform Dominant Cycle Period (HT_DCPERIOD).
+///
+/// Measures the length, in bars, of the dominant price cycle with the Hilbert transform
+/// homodyne discriminator, matching TA-Lib.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array of dominant cycle periods, with the first 32 elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+///   >>> dcperiod = kand.ht_dcperiod(data)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ht_dcperiod", signature = (data))]
+pub fn ht_dcperiod_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    // Convert the input NumPy array to a Rust slice
+    let input = data.as_slice()?;
+    let len = input.len();
+
+    // Create output array using vec
+    let mut output_dcperiod = vec![0.0; len];
+
+    // Perform the calculation while releasing the GIL
+    py.allow_threads(|| ht_dcperiod::ht_dcperiod(input, output_dcperiod.as_mut_slice()))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    // Convert output array to Python object
+    Ok(output_dcperiod.into_pyarray(py).into())
+}
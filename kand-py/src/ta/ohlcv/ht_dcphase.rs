@@ -0,0 +1,42 @@
+use kand::{TAFloat, ohlcv::ht_dcphase};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Hilbert Transform Dominant Cycle Phase (HT_DCPHASE).
+///
+/// Locates each bar within the dominant cycle, in degrees, by correlating one cycle of
+/// smoothed prices with a sine and a cosine, matching TA-Lib.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array of dominant cycle phases in degrees, with the first 63 elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+///   >>> dcphase = kand.ht_dcphase(data)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ht_dcphase", signature = (data))]
+pub fn ht_dcphase_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    // Convert the input NumPy array to a Rust slice
+    let input = data.as_slice()?;
+    let len = input.len();
+
+    // Create output array using vec
+    let mut output_dcphase = vec![0.0; len];
+
+    // Perform the calculation while releasing the GIL
+    py.allow_threads(|| ht_dcphase::ht_dcphase(input, output_dcphase.as_mut_slice()))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    // Convert output array to Python object
+    Ok(output_dcphase.into_pyarray(py).into())
+}
@@ -0,0 +1,55 @@
+use kand::{TAFloat, ohlcv::ht_phasor};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Hilbert Transform Phasor Components (HT_PHASOR).
+///
+/// Splits the detrended price into its in-phase and quadrature components with the Hilbert
+/// transform, matching TA-Lib.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A tuple of two 1-D NumPy arrays containing:
+///   - In-phase component
+///   - Quadrature component
+///   Each array has the same length as the input, with the first 32 elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+///   >>> in_phase, quadrature = kand.ht_phasor(data)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ht_phasor", signature = (data))]
+pub fn ht_phasor_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    // Convert the input NumPy array to a Rust slice
+    let input = data.as_slice()?;
+    let len = input.len();
+
+    // Create output arrays using vec
+    let mut output_in_phase = vec![0.0; len];
+    let mut output_quadrature = vec![0.0; len];
+
+    // Perform the calculation while releasing the GIL
+    py.allow_threads(|| {
+        ht_phasor::ht_phasor(
+            input,
+            output_in_phase.as_mut_slice(),
+            output_quadrature.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    // Convert output arrays to Python objects
+    Ok((
+        output_in_phase.into_pyarray(py).into(),
+        output_quadrature.into_pyarray(py).into(),
+    ))
+}
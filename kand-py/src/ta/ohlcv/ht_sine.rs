@@ -0,0 +1,55 @@
+use kand::{TAFloat, ohlcv::ht_sine};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Hilbert Transform SineWave (HT_SINE).
+///
+/// Returns the sine of the dominant cycle phase and a lead sine advanced by 45 degrees; the
+/// lines cross ahead of cycle turning points and separate in trends. Matches TA-Lib.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A tuple of two 1-D NumPy arrays containing:
+///   - Sine values
+///   - Lead sine values
+///   Each array has the same length as the input, with the first 63 elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+///   >>> sine, lead_sine = kand.ht_sine(data)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ht_sine", signature = (data))]
+pub fn ht_sine_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    // Convert the input NumPy array to a Rust slice
+    let input = data.as_slice()?;
+    let len = input.len();
+
+    // Create output arrays using vec
+    let mut output_sine = vec![0.0; len];
+    let mut output_lead_sine = vec![0.0; len];
+
+    // Perform the calculation while releasing the GIL
+    py.allow_threads(|| {
+        ht_sine::ht_sine(
+            input,
+            output_sine.as_mut_slice(),
+            output_lead_sine.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    // Convert output arrays to Python objects
+    Ok((
+        output_sine.into_pyarray(py).into(),
+        output_lead_sine.into_pyarray(py).into(),
+    ))
+}
@@ -0,0 +1,42 @@
+use kand::{TAFloat, ohlcv::ht_trendline};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Hilbert Transform Instantaneous Trendline (HT_TRENDLINE).
+///
+/// Averages the price over one measured dominant cycle and smooths the result with a 4-bar WMA,
+/// matching TA-Lib.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array of trendline values, with the first 63 elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+///   >>> trendline = kand.ht_trendline(data)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ht_trendline", signature = (data))]
+pub fn ht_trendline_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    // Convert the input NumPy array to a Rust slice
+    let input = data.as_slice()?;
+    let len = input.len();
+
+    // Create output array using vec
+    let mut output_trendline = vec![0.0; len];
+
+    // Perform the calculation while releasing the GIL
+    py.allow_threads(|| ht_trendline::ht_trendline(input, output_trendline.as_mut_slice()))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    // Convert output array to Python object
+    Ok(output_trendline.into_pyarray(py).into())
+}
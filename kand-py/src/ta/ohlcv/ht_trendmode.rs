@@ -0,0 +1,42 @@
+use kand::{TAFloat, TAInt, ohlcv::ht_trendmode};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Hilbert Transform Trend vs Cycle Mode (HT_TRENDMODE).
+///
+/// Classifies each bar as trending (1) or cycling (0) from the SineWave crossings, the phase
+/// rate and the distance to the instantaneous trendline, matching TA-Lib.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array of modes (1 = trend, 0 = cycle), with the first 63 elements containing 0 values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = 100.0 + np.sin(np.arange(80) * 0.3)
+///   >>> trendmode = kand.ht_trendmode(data)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ht_trendmode", signature = (data))]
+pub fn ht_trendmode_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAInt>>> {
+    // Convert the input NumPy array to a Rust slice
+    let input = data.as_slice()?;
+    let len = input.len();
+
+    // Create output array using vec
+    let mut output_trendmode = vec![0; len];
+
+    // Perform the calculation while releasing the GIL
+    py.allow_threads(|| ht_trendmode::ht_trendmode(input, output_trendmode.as_mut_slice()))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    // Convert output array to Python object
+    Ok(output_trendmode.into_pyarray(py).into())
+}
@@ -0,0 +1,93 @@
+use kand::{TAFloat, ohlcv::ichimoku};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculates Ichimoku Kinko Hyo for NumPy arrays.
+///
+/// Every output is aligned to the bar it is plotted on. The leading spans are `displacement`
+/// values longer than the input, so their tail is the cloud projected beyond the latest bar. The
+/// lagging span is the close shifted `displacement` bars back, so its last `displacement` values
+/// are NaN.
+///
+/// Args:
+///   high: Input high price data as a 1-D NumPy array of type `TAFloat`.
+///   low: Input low price data as a 1-D NumPy array of type `TAFloat`.
+///   close: Input close price data as a 1-D NumPy array of type `TAFloat`.
+///   tenkan_period: Period of the conversion line (must be >= 2). Defaults to 9.
+///   kijun_period: Period of the base line (must be >= 2). Defaults to 26.
+///   senkou_b_period: Period of the leading span B (must be >= 2). Defaults to 52.
+///   displacement: Bars the cloud is shifted forward and the lagging span backward (must be >= 1). Defaults to 26.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Conversion line (Tenkan-sen) values, same length as the input
+///   - Base line (Kijun-sen) values, same length as the input
+///   - Leading span A (Senkou Span A) values, `displacement` longer than the input
+///   - Leading span B (Senkou Span B) values, `displacement` longer than the input
+///   - Lagging span (Chikou Span) values, same length as the input
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0, 12.0])
+///   >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0, 8.0])
+///   >>> close = np.array([9.0, 11.0, 14.0, 12.0, 11.0, 9.0])
+///   >>> tenkan, kijun, senkou_a, senkou_b, chikou = kand.ichimoku(high, low, close, 2, 3, 4, 2)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ichimoku", signature = (high, low, close, tenkan_period=9, kijun_period=26, senkou_b_period=52, displacement=26))]
+#[allow(clippy::type_complexity)]
+pub fn ichimoku_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    tenkan_period: usize,
+    kijun_period: usize,
+    senkou_b_period: usize,
+    displacement: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_high.len();
+
+    let mut output_tenkan = vec![0.0; len];
+    let mut output_kijun = vec![0.0; len];
+    let mut output_senkou_a = vec![0.0; len + displacement];
+    let mut output_senkou_b = vec![0.0; len + displacement];
+    let mut output_chikou = vec![0.0; len];
+
+    py.allow_threads(|| {
+        ichimoku::ichimoku(
+            input_high,
+            input_low,
+            input_close,
+            tenkan_period,
+            kijun_period,
+            senkou_b_period,
+            displacement,
+            output_tenkan.as_mut_slice(),
+            output_kijun.as_mut_slice(),
+            output_senkou_a.as_mut_slice(),
+            output_senkou_b.as_mut_slice(),
+            output_chikou.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_tenkan.into_pyarray(py).into(),
+        output_kijun.into_pyarray(py).into(),
+        output_senkou_a.into_pyarray(py).into(),
+        output_senkou_b.into_pyarray(py).into(),
+        output_chikou.into_pyarray(py).into(),
+    ))
+}
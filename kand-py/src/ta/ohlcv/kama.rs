@@ -0,0 +1,113 @@
+use kand::{TAFloat, ohlcv::kama};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Kaufman Adaptive Moving Average (KAMA) over a NumPy array.
+///
+/// KAMA adapts its smoothing to market noise. The efficiency ratio (net change over `period`
+/// divided by the sum of bar-to-bar changes) blends a fast and a slow smoothing constant:
+/// SC = (ER * (fast_sc - slow_sc) + slow_sc)^2
+/// KAMA = KAMA(previous) + SC * (Price - KAMA(previous))
+/// where fast_sc = 2/(fast_period+1) and slow_sc = 2/(slow_period+1).
+///
+/// Args:
+///   data: Input data as a 1-D NumPy array of type `TAFloat`.
+///   period: Efficiency ratio period. Must be >= 2 and less than input length.
+///   fast_period: Period of the fast smoothing constant. Defaults to 2.
+///   slow_period: Period of the slow smoothing constant. Defaults to 30.
+///
+/// Returns:
+///   A new 1-D NumPy array containing the KAMA values. The array has the same length as the input,
+///   with the first `period` elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = np.array([10.0, 11.0, 10.5, 11.5, 12.0, 12.5])
+///   >>> result = kand.kama(data, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "kama", signature = (data, period, fast_period=2, slow_period=30))]
+pub fn kama_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    fast_period: usize,
+    slow_period: usize,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    // Convert the input NumPy array to a Rust slice.
+    let input = data.as_slice()?;
+    let len = input.len();
+
+    // Create a new output array using vec
+    let mut output = vec![0.0; len];
+
+    // Perform the KAMA calculation while releasing the GIL to allow other Python threads to run.
+    py.allow_threads(|| {
+        kama::kama(
+            input,
+            period,
+            fast_period,
+            slow_period,
+            output.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    // Convert the output array to a Python object
+    Ok(output.into_pyarray(py).into())
+}
+
+/// Computes the latest KAMA value incrementally.
+///
+/// Rolls the volatility sum forward by one bar and applies the adaptive smoothing constant
+/// to the previous KAMA value.
+///
+/// Args:
+///
+///   price: Current price as `TAFloat`.
+///   prev_price: Previous price as `TAFloat`.
+///   old_price: Price `period` bars before the current one.
+///   old_prev_price: Price `period + 1` bars before the current one.
+///   prev_kama: Previous KAMA value.
+///   prev_volatility: Previous sum of absolute price changes over the period.
+///   fast_period: Period of the fast smoothing constant. Defaults to 2.
+///   slow_period: Period of the slow smoothing constant. Defaults to 30.
+///
+/// Returns:
+///   A tuple containing (KAMA, volatility). Pass volatility back as `prev_volatility` on the next call.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kama, volatility = kand.kama_inc(11.0, 10.5, 10.0, 9.5, 10.2, 2.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "kama_inc", signature = (price, prev_price, old_price, old_prev_price, prev_kama, prev_volatility, fast_period=2, slow_period=30))]
+#[allow(clippy::too_many_arguments)]
+pub fn kama_inc_py(
+    py: Python,
+    price: TAFloat,
+    prev_price: TAFloat,
+    old_price: TAFloat,
+    old_prev_price: TAFloat,
+    prev_kama: TAFloat,
+    prev_volatility: TAFloat,
+    fast_period: usize,
+    slow_period: usize,
+) -> PyResult<(TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        kama::kama_inc(
+            price,
+            prev_price,
+            old_price,
+            old_prev_price,
+            prev_kama,
+            prev_volatility,
+            fast_period,
+            slow_period,
+        )
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    })
+}
@@ -0,0 +1,149 @@
+use kand::{TAFloat, ohlcv::keltner};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculates Keltner Channels for NumPy arrays.
+///
+/// Keltner Channels place bands a multiple of the Average True Range above and below an EMA of
+/// the typical price.
+///
+/// Args:
+///   high: Input high price data as a 1-D NumPy array of type `TAFloat`.
+///   low: Input low price data as a 1-D NumPy array of type `TAFloat`.
+///   close: Input close price data as a 1-D NumPy array of type `TAFloat`.
+///   period: EMA period for the center line (must be >= 2). Defaults to 20.
+///   atr_period: ATR period for the channel width (must be >= 2). Defaults to 10.
+///   multiplier: ATR multiplier (typically 1-3). Defaults to 2.0.
+///
+/// Returns:
+///   A tuple of four 1-D NumPy arrays containing:
+///   - Upper channel values
+///   - Middle channel (EMA) values
+///   - Lower channel values
+///   - ATR values
+///   Each array has the same length as the input, with the first `max(period-1, atr_period)` elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+///   >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+///   >>> close = np.array([9.0, 11.0, 13.0, 12.0, 11.0])
+///   >>> upper, middle, lower, atr = kand.keltner(high, low, close, 3, 3, 2.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "keltner", signature = (high, low, close, period=20, atr_period=10, multiplier=2.0))]
+#[allow(clippy::type_complexity)]
+pub fn keltner_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    atr_period: usize,
+    multiplier: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_high.len();
+
+    let mut output_upper = vec![0.0; len];
+    let mut output_middle = vec![0.0; len];
+    let mut output_lower = vec![0.0; len];
+    let mut output_atr = vec![0.0; len];
+
+    py.allow_threads(|| {
+        keltner::keltner(
+            input_high,
+            input_low,
+            input_close,
+            period,
+            atr_period,
+            multiplier,
+            output_upper.as_mut_slice(),
+            output_middle.as_mut_slice(),
+            output_lower.as_mut_slice(),
+            output_atr.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_upper.into_pyarray(py).into(),
+        output_middle.into_pyarray(py).into(),
+        output_lower.into_pyarray(py).into(),
+        output_atr.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculates the next Keltner Channels values incrementally.
+///
+/// Args:
+///   high: Current high price value as `TAFloat`.
+///   low: Current low price value as `TAFloat`.
+///   close: Current close price value as `TAFloat`.
+///   prev_close: Previous close price value as `TAFloat`.
+///   prev_middle: Previous middle channel (EMA) value as `TAFloat`.
+///   prev_atr: Previous ATR value as `TAFloat`.
+///   period: EMA period for the center line (must be >= 2). Defaults to 20.
+///   atr_period: ATR period for the channel width (must be >= 2). Defaults to 10.
+///   multiplier: ATR multiplier. Defaults to 2.0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Upper channel value
+///   - Middle channel (EMA) value
+///   - Lower channel value
+///   - New ATR value
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> upper, middle, lower, atr = kand.keltner_inc(
+///   ...     13.0,  # current high
+///   ...     9.0,   # current low
+///   ...     11.0,  # current close
+///   ...     12.0,  # previous close
+///   ...     12.0,  # previous middle channel
+///   ...     4.0,   # previous ATR
+///   ...     3,     # EMA period
+///   ...     3,     # ATR period
+///   ...     2.0    # multiplier
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "keltner_inc", signature = (high, low, close, prev_close, prev_middle, prev_atr, period=20, atr_period=10, multiplier=2.0))]
+pub fn keltner_inc_py(
+    py: Python,
+    high: TAFloat,
+    low: TAFloat,
+    close: TAFloat,
+    prev_close: TAFloat,
+    prev_middle: TAFloat,
+    prev_atr: TAFloat,
+    period: usize,
+    atr_period: usize,
+    multiplier: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        keltner::keltner_inc(
+            high,
+            low,
+            close,
+            prev_close,
+            prev_middle,
+            prev_atr,
+            period,
+            atr_period,
+            multiplier,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,97 @@
+use kand::{TAFloat, ohlcv::linearreg_angle};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Linear Regression Angle (LINEARREG_ANGLE) over a NumPy array.
+///
+/// Fits a least-squares line to the last `period` values and returns the angle of its slope in degrees.
+/// Matches TA-Lib's `LINEARREG_ANGLE`.
+///
+/// Args:
+///   data: Input data as a 1-D NumPy array of type `TAFloat`.
+///   period: Regression window. Must be >= 2 and less than input length.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Regression angles in degrees
+///   - Running sum of values
+///   - Running sum of index-weighted values
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = np.array([1.0, 3.0, 5.0, 4.0, 6.0])
+///   >>> angle, sum_y, sum_xy = kand.linearreg_angle(data, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "linearreg_angle", signature = (data, period))]
+pub fn linearreg_angle_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input = data.as_slice()?;
+    let len = input.len();
+
+    let mut output_angle = vec![0.0; len];
+    let mut output_sum_y = vec![0.0; len];
+    let mut output_sum_xy = vec![0.0; len];
+
+    py.allow_threads(|| {
+        linearreg_angle::linearreg_angle(
+            input,
+            period,
+            &mut output_angle,
+            &mut output_sum_y,
+            &mut output_sum_xy,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_angle.into_pyarray(py).into(),
+        output_sum_y.into_pyarray(py).into(),
+        output_sum_xy.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the latest Linear Regression Angle value incrementally.
+///
+/// Slides the regression window by one bar using the running sums of the previous window.
+///
+/// Args:
+///   price: Newest value to include.
+///   prev_sum_y: Previous sum of values in the window.
+///   prev_sum_xy: Previous sum of index-weighted values in the window.
+///   old_price: Oldest value to remove from the window.
+///   period: Regression window. Must be >= 2.
+///
+/// Returns:
+///   A tuple containing (LINEARREG_ANGLE, sum_y, sum_xy).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> angle, sum_y, sum_xy = kand.linearreg_angle_inc(7.0, 9.0, 13.0, 1.0, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "linearreg_angle_inc", signature = (price, prev_sum_y, prev_sum_xy, old_price, period))]
+pub fn linearreg_angle_inc_py(
+    py: Python,
+    price: TAFloat,
+    prev_sum_y: TAFloat,
+    prev_sum_xy: TAFloat,
+    old_price: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        linearreg_angle::linearreg_angle_inc(price, prev_sum_y, prev_sum_xy, old_price, period)
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,62 @@
+use kand::{TAFloat, ohlcv::mama};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the MESA Adaptive Moving Average (MAMA) and its Following Adaptive Moving Average (FAMA).
+///
+/// MAMA adapts its smoothing factor to the phase change measured by a Hilbert transform
+/// homodyne discriminator: slow phase changes (trends) push alpha towards `slow_limit`,
+/// fast ones towards `fast_limit`. FAMA follows MAMA with half the alpha.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///   fast_limit: Upper bound of the adaptive alpha, between 0.01 and 0.99. Defaults to 0.5.
+///   slow_limit: Lower bound of the adaptive alpha, between 0.01 and 0.99. Defaults to 0.05.
+///
+/// Returns:
+///   A tuple of two 1-D NumPy arrays containing:
+///   - MAMA values
+///   - FAMA values
+///   Each array has the same length as the input, with the first 32 elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = 100.0 + np.sin(np.arange(40) * 0.3)
+///   >>> mama, fama = kand.mama(data)
+///   ```
+#[pyfunction]
+#[pyo3(name = "mama", signature = (data, fast_limit=0.5, slow_limit=0.05))]
+pub fn mama_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+    fast_limit: TAFloat,
+    slow_limit: TAFloat,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    // Convert the input NumPy array to a Rust slice
+    let input = data.as_slice()?;
+    let len = input.len();
+
+    // Create output arrays using vec
+    let mut output_mama = vec![0.0; len];
+    let mut output_fama = vec![0.0; len];
+
+    // Perform MAMA calculation while releasing the GIL
+    py.allow_threads(|| {
+        mama::mama(
+            input,
+            fast_limit,
+            slow_limit,
+            output_mama.as_mut_slice(),
+            output_fama.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    // Convert output arrays to Python objects
+    Ok((
+        output_mama.into_pyarray(py).into(),
+        output_fama.into_pyarray(py).into(),
+    ))
+}
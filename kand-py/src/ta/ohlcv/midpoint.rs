@@ -63,6 +63,8 @@ pub fn midpoint_py(
 
 /// Calculates the next Midpoint value incrementally.
 ///
+/// Deprecated: prices leaving the window are never dropped; use `midpoint` on the full series.
+///
 /// Provides an optimized way to calculate the next Midpoint value when new data arrives,
 /// without recalculating the entire series.
 ///
@@ -89,6 +91,7 @@ pub fn midpoint_py(
 ///   ... )
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "midpoint_inc", signature = (price, prev_highest, prev_lowest, period))]
 pub fn midpoint_inc_py(
     py: Python,
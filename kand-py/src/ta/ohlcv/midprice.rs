@@ -68,6 +68,8 @@ pub fn midprice_py(
 
 /// Calculates the next Midpoint Price value incrementally.
 ///
+/// Deprecated: bars leaving the window are never dropped; use `midprice` on the full series.
+///
 /// Provides an optimized way to calculate the next Midpoint Price value when new data arrives,
 /// without recalculating the entire series.
 ///
@@ -97,6 +99,7 @@ pub fn midprice_py(
 ///   ... )
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "midprice_inc", signature = (high, low, prev_highest, prev_lowest, period))]
 pub fn midprice_inc_py(
     py: Python,
@@ -74,6 +74,9 @@ pub fn willr_py(
 
 /// Incrementally calculates Williams %R for the latest data point.
 ///
+/// Deprecated: the extremes are approximate once they leave the window; use `willr` on the full
+/// series.
+///
 /// This function provides an optimized way to calculate the latest Williams %R value
 /// by using previously calculated highest high and lowest low values.
 ///
@@ -98,6 +101,7 @@ pub fn willr_py(
 ///     >>> willr, high, low = kand.willr_inc(15.0, 10.0, 14.0, 11.0, 12.0, 13.0, 11.0)
 ///     ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(
     name = "willr_inc",
     signature = (prev_highest_high, prev_lowest_low, prev_high, prev_low, close, high, low)
@@ -0,0 +1,149 @@
+use kand::{TAFloat, stats::alpha};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the rolling Jensen's Alpha of asset returns against benchmark returns.
+///
+/// The average return beyond what the CAPM Beta to the benchmark predicts, annualised by
+/// `periods_per_year`.
+///
+/// Args:
+///   returns: Asset returns as a 1-D NumPy array of type `TAFloat`.
+///   benchmark: Benchmark returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window. Must be >= 2.
+///   risk_free: Annual risk-free rate. Defaults to 0.0.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple of six 1-D NumPy arrays containing:
+///   - Jensen's Alpha values
+///   - CAPM Beta values
+///   - Running sum of asset returns
+///   - Running sum of benchmark returns
+///   - Running sum of squared benchmark returns
+///   - Running sum of asset times benchmark returns
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.021, -0.009, 0.031, 0.011, -0.019])
+///   >>> benchmark = np.array([0.01, -0.005, 0.015, 0.005, -0.01])
+///   >>> alpha, beta, sum_ret, sum_bench, sum_bench_sq, sum_cross = kand.alpha(returns, benchmark, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "alpha", signature = (returns, benchmark, period, risk_free=0.0, periods_per_year=252.0))]
+#[allow(clippy::type_complexity)]
+pub fn alpha_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    benchmark: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    risk_free: TAFloat,
+    periods_per_year: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_returns = returns.as_slice()?;
+    let input_benchmark = benchmark.as_slice()?;
+    let len = input_returns.len();
+
+    let mut output_alpha = vec![0.0; len];
+    let mut output_beta = vec![0.0; len];
+    let mut output_sum_returns = vec![0.0; len];
+    let mut output_sum_benchmark = vec![0.0; len];
+    let mut output_sum_benchmark_sq = vec![0.0; len];
+    let mut output_sum_cross = vec![0.0; len];
+
+    py.allow_threads(|| {
+        alpha::alpha(
+            input_returns,
+            input_benchmark,
+            period,
+            risk_free,
+            periods_per_year,
+            &mut output_alpha,
+            &mut output_beta,
+            &mut output_sum_returns,
+            &mut output_sum_benchmark,
+            &mut output_sum_benchmark_sq,
+            &mut output_sum_cross,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_alpha.into_pyarray(py).into(),
+        output_beta.into_pyarray(py).into(),
+        output_sum_returns.into_pyarray(py).into(),
+        output_sum_benchmark.into_pyarray(py).into(),
+        output_sum_benchmark_sq.into_pyarray(py).into(),
+        output_sum_cross.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the latest rolling Jensen's Alpha value incrementally.
+///
+/// Args:
+///   ret: Newest asset return to add.
+///   bench: Newest benchmark return to add.
+///   old_ret: Oldest asset return to remove.
+///   old_bench: Oldest benchmark return to remove.
+///   prev_sum_ret: Previous sum of asset returns.
+///   prev_sum_bench: Previous sum of benchmark returns.
+///   prev_sum_bench_sq: Previous sum of squared benchmark returns.
+///   prev_sum_cross: Previous sum of asset times benchmark returns.
+///   period: Number of returns in the rolling window. Must be >= 2.
+///   risk_free: Annual risk-free rate. Defaults to 0.0.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple containing (alpha, beta, sum_ret, sum_bench, sum_bench_sq, sum_cross).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> alpha, beta, sum_ret, sum_bench, sum_bench_sq, sum_cross = kand.alpha_inc(
+///   ...     0.011, 0.005, 0.021, 0.01, 0.043, 0.02, 0.00035, 0.00072, 3
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "alpha_inc", signature = (ret, bench, old_ret, old_bench, prev_sum_ret, prev_sum_bench, prev_sum_bench_sq, prev_sum_cross, period, risk_free=0.0, periods_per_year=252.0))]
+#[allow(clippy::too_many_arguments)]
+pub fn alpha_inc_py(
+    py: Python,
+    ret: TAFloat,
+    bench: TAFloat,
+    old_ret: TAFloat,
+    old_bench: TAFloat,
+    prev_sum_ret: TAFloat,
+    prev_sum_bench: TAFloat,
+    prev_sum_bench_sq: TAFloat,
+    prev_sum_cross: TAFloat,
+    period: usize,
+    risk_free: TAFloat,
+    periods_per_year: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        alpha::alpha_inc(
+            ret,
+            bench,
+            old_ret,
+            old_bench,
+            prev_sum_ret,
+            prev_sum_bench,
+            prev_sum_bench_sq,
+            prev_sum_cross,
+            period,
+            risk_free,
+            periods_per_year,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
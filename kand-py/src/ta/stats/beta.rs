@@ -0,0 +1,271 @@
+use kand::{TAFloat, stats::beta};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the TA-Lib compatible Beta of two price series.
+///
+/// Both price series are turned into simple returns, then Beta is the least-squares slope of the
+/// returns of `input1` on the returns of `input0`.
+///
+/// Args:
+///   input0: First price series (e.g. the market) as a 1-D NumPy array of type `TAFloat`.
+///   input1: Second price series (e.g. the asset) as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window. Must be >= 1. Defaults to 5.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Beta values
+///   - Running sum of series 0 returns
+///   - Running sum of series 1 returns
+///   - Running sum of squared series 0 returns
+///   - Running sum of return products
+///   Each array has the same length as the input, with the first `period` elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> market = np.array([100.0, 101.0, 100.5, 102.0, 103.0, 102.5])
+///   >>> asset = np.array([50.0, 51.0, 50.2, 51.8, 52.5, 52.0])
+///   >>> beta, sum_x, sum_y, sum_xx, sum_xy = kand.beta(market, asset, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "beta", signature = (input0, input1, period=5))]
+#[allow(clippy::type_complexity)]
+pub fn beta_py(
+    py: Python,
+    input0: PyReadonlyArray1<TAFloat>,
+    input1: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input0_array = input0.as_slice()?;
+    let input1_array = input1.as_slice()?;
+    let len = input0_array.len();
+
+    let mut output_beta = vec![0.0; len];
+    let mut output_sum_x = vec![0.0; len];
+    let mut output_sum_y = vec![0.0; len];
+    let mut output_sum_xx = vec![0.0; len];
+    let mut output_sum_xy = vec![0.0; len];
+
+    py.allow_threads(|| {
+        beta::beta(
+            input0_array,
+            input1_array,
+            period,
+            &mut output_beta,
+            &mut output_sum_x,
+            &mut output_sum_y,
+            &mut output_sum_xx,
+            &mut output_sum_xy,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_beta.into_pyarray(py).into(),
+        output_sum_x.into_pyarray(py).into(),
+        output_sum_y.into_pyarray(py).into(),
+        output_sum_xx.into_pyarray(py).into(),
+        output_sum_xy.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the latest TA-Lib compatible Beta value incrementally.
+///
+/// Args:
+///   price0: Newest price of series 0.
+///   price1: Newest price of series 1.
+///   prev_price0: Price of series 0 one bar back.
+///   prev_price1: Price of series 1 one bar back.
+///   old_price0: Price of series 0 `period` bars back.
+///   old_price1: Price of series 1 `period` bars back.
+///   old_prev_price0: Price of series 0 `period + 1` bars back.
+///   old_prev_price1: Price of series 1 `period + 1` bars back.
+///   prev_sum_x: Previous sum of series 0 returns.
+///   prev_sum_y: Previous sum of series 1 returns.
+///   prev_sum_xx: Previous sum of squared series 0 returns.
+///   prev_sum_xy: Previous sum of return products.
+///   period: Number of returns in the rolling window. Must be >= 1.
+///
+/// Returns:
+///   A tuple containing (beta, sum_x, sum_y, sum_xx, sum_xy).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> beta, sum_x, sum_y, sum_xx, sum_xy = kand.beta_inc(
+///   ...     102.5, 52.0, 103.0, 52.5, 100.5, 50.2, 101.0, 51.0,
+///   ...     0.0198, 0.0297, 0.000343, 0.000686, 3
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "beta_inc")]
+#[allow(clippy::too_many_arguments)]
+pub fn beta_inc_py(
+    py: Python,
+    price0: TAFloat,
+    price1: TAFloat,
+    prev_price0: TAFloat,
+    prev_price1: TAFloat,
+    old_price0: TAFloat,
+    old_price1: TAFloat,
+    old_prev_price0: TAFloat,
+    old_prev_price1: TAFloat,
+    prev_sum_x: TAFloat,
+    prev_sum_y: TAFloat,
+    prev_sum_xx: TAFloat,
+    prev_sum_xy: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        beta::beta_inc(
+            price0,
+            price1,
+            prev_price0,
+            prev_price1,
+            old_price0,
+            old_price1,
+            old_prev_price0,
+            old_prev_price1,
+            prev_sum_x,
+            prev_sum_y,
+            prev_sum_xx,
+            prev_sum_xy,
+            period,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes the rolling CAPM Beta of asset returns against benchmark returns.
+///
+/// Args:
+///   returns: Asset returns as a 1-D NumPy array of type `TAFloat`.
+///   benchmark: Benchmark returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window. Must be >= 2.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Beta values
+///   - Running sum of asset returns
+///   - Running sum of benchmark returns
+///   - Running sum of squared benchmark returns
+///   - Running sum of asset times benchmark returns
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.02, -0.01, 0.03, 0.01, -0.02])
+///   >>> benchmark = np.array([0.01, -0.005, 0.015, 0.005, -0.01])
+///   >>> beta, sum_ret, sum_bench, sum_bench_sq, sum_cross = kand.capm_beta(returns, benchmark, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "capm_beta", signature = (returns, benchmark, period))]
+#[allow(clippy::type_complexity)]
+pub fn capm_beta_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    benchmark: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_returns = returns.as_slice()?;
+    let input_benchmark = benchmark.as_slice()?;
+    let len = input_returns.len();
+
+    let mut output_beta = vec![0.0; len];
+    let mut output_sum_returns = vec![0.0; len];
+    let mut output_sum_benchmark = vec![0.0; len];
+    let mut output_sum_benchmark_sq = vec![0.0; len];
+    let mut output_sum_cross = vec![0.0; len];
+
+    py.allow_threads(|| {
+        beta::capm_beta(
+            input_returns,
+            input_benchmark,
+            period,
+            &mut output_beta,
+            &mut output_sum_returns,
+            &mut output_sum_benchmark,
+            &mut output_sum_benchmark_sq,
+            &mut output_sum_cross,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_beta.into_pyarray(py).into(),
+        output_sum_returns.into_pyarray(py).into(),
+        output_sum_benchmark.into_pyarray(py).into(),
+        output_sum_benchmark_sq.into_pyarray(py).into(),
+        output_sum_cross.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the latest rolling CAPM Beta value incrementally.
+///
+/// Args:
+///   ret: Newest asset return to add.
+///   bench: Newest benchmark return to add.
+///   old_ret: Oldest asset return to remove.
+///   old_bench: Oldest benchmark return to remove.
+///   prev_sum_ret: Previous sum of asset returns.
+///   prev_sum_bench: Previous sum of benchmark returns.
+///   prev_sum_bench_sq: Previous sum of squared benchmark returns.
+///   prev_sum_cross: Previous sum of asset times benchmark returns.
+///   period: Number of returns in the rolling window. Must be >= 2.
+///
+/// Returns:
+///   A tuple containing (beta, sum_ret, sum_bench, sum_bench_sq, sum_cross).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> beta, sum_ret, sum_bench, sum_bench_sq, sum_cross = kand.capm_beta_inc(
+///   ...     0.01, 0.005, 0.02, 0.01, 0.04, 0.02, 0.00035, 0.0007, 3
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "capm_beta_inc")]
+#[allow(clippy::too_many_arguments)]
+pub fn capm_beta_inc_py(
+    py: Python,
+    ret: TAFloat,
+    bench: TAFloat,
+    old_ret: TAFloat,
+    old_bench: TAFloat,
+    prev_sum_ret: TAFloat,
+    prev_sum_bench: TAFloat,
+    prev_sum_bench_sq: TAFloat,
+    prev_sum_cross: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        beta::capm_beta_inc(
+            ret,
+            bench,
+            old_ret,
+            old_bench,
+            prev_sum_ret,
+            prev_sum_bench,
+            prev_sum_bench_sq,
+            prev_sum_cross,
+            period,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
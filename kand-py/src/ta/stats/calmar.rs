@@ -0,0 +1,67 @@
+use kand::{TAFloat, stats::calmar};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the rolling Calmar ratio over a NumPy array of periodic returns.
+///
+/// Compound annual growth rate over the maximum drawdown of the equity curve built from the returns.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window. Must be >= 2.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   A 1-D NumPy array of Calmar ratio values, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+///   >>> calmar = kand.calmar(returns, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "calmar", signature = (returns, period, periods_per_year=252.0))]
+pub fn calmar_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    periods_per_year: TAFloat,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input = returns.as_slice()?;
+    let mut output_calmar = vec![0.0; input.len()];
+
+    py.allow_threads(|| calmar::calmar(input, period, periods_per_year, &mut output_calmar))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_calmar.into_pyarray(py).into())
+}
+
+/// Computes the Calmar ratio of an entire return series.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   The Calmar ratio as a float, or NaN when it is undefined.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+///   >>> ratio = kand.calmar_ratio(returns)
+///   ```
+#[pyfunction]
+#[pyo3(name = "calmar_ratio", signature = (returns, periods_per_year=252.0))]
+pub fn calmar_ratio_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    periods_per_year: TAFloat,
+) -> PyResult<TAFloat> {
+    let input = returns.as_slice()?;
+    py.allow_threads(|| calmar::calmar_ratio(input, periods_per_year))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -35,6 +35,7 @@
 ///   ```
 #[pyfunction]
 #[pyo3(name = "correl", signature = (input0, input1, period))]
+#[allow(clippy::type_complexity)]
 pub fn correl_py(
     py: Python,
     input0: PyReadonlyArray1<TAFloat>,
@@ -90,8 +91,99 @@ pub fn correl_py(
     ))
 }
 
+/// Calculate Pearson's Correlation Coefficient, returning the Welford state
+///
+/// The Pearson Correlation Coefficient measures the linear correlation between two variables,
+/// returning a value between -1 and +1, where:
+/// - +1 indicates perfect positive correlation
+/// - -1 indicates perfect negative correlation
+/// - 0 indicates no linear correlation
+///
+/// Args:
+///   input0: First input series as a 1-D NumPy array of type `TAFloat`.
+///   input1: Second input series as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for calculation (must be >= 2).
+///
+/// Returns:
+///   A tuple of six 1-D NumPy arrays containing:
+///   - Correlation coefficient values
+///   - Rolling mean of series 0
+///   - Rolling mean of series 1
+///   - Rolling sum of squared deviations of series 0
+///   - Rolling sum of squared deviations of series 1
+///   - Rolling sum of co-deviations
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> series1 = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+///   >>> series2 = np.array([2.0, 4.0, 6.0, 8.0, 10.0])
+///   >>> correl, mean0, mean1, m2_0, m2_1, c01 = kand.correl_welford(series1, series2, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "correl_welford", signature = (input0, input1, period))]
+#[allow(clippy::type_complexity)]
+pub fn correl_welford_py(
+    py: Python,
+    input0: PyReadonlyArray1<TAFloat>,
+    input1: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input0_array = input0.as_slice()?;
+    let input1_array = input1.as_slice()?;
+    let len = input0_array.len();
+
+    if len != input1_array.len() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Input arrays must have the same length",
+        ));
+    }
+
+    let mut output_correl = vec![0.0; len];
+    let mut output_mean_0 = vec![0.0; len];
+    let mut output_mean_1 = vec![0.0; len];
+    let mut output_m2_0 = vec![0.0; len];
+    let mut output_m2_1 = vec![0.0; len];
+    let mut output_c_01 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        correl::correl_welford(
+            input0_array,
+            input1_array,
+            period,
+            &mut output_correl,
+            &mut output_mean_0,
+            &mut output_mean_1,
+            &mut output_m2_0,
+            &mut output_m2_1,
+            &mut output_c_01,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_correl.into_pyarray(py).into(),
+        output_mean_0.into_pyarray(py).into(),
+        output_mean_1.into_pyarray(py).into(),
+        output_m2_0.into_pyarray(py).into(),
+        output_m2_1.into_pyarray(py).into(),
+        output_c_01.into_pyarray(py).into(),
+    ))
+}
+
 /// Calculate the latest Correlation value incrementally
 ///
+/// Deprecated: the running sums lose precision for large values; use `correl_inc_welford`.
+///
 /// This function provides an optimized way to update the Correlation value when new data arrives,
 /// avoiding full recalculation of the entire series.
 ///
@@ -133,6 +225,7 @@ pub fn correl_py(
 ///   ... )
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "correl_inc")]
 pub fn correl_inc_py(
     py: Python,
@@ -163,3 +256,68 @@ pub fn correl_inc_py(
     })
     .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Calculate the latest Correlation value incrementally from the Welford state
+///
+/// This function provides an optimized way to update the Correlation value when new data arrives,
+/// avoiding full recalculation of the entire series.
+///
+/// Args:
+///   new0: The newest value from series 0 to add
+///   new1: The newest value from series 1 to add
+///   old0: The oldest value from series 0 to remove
+///   old1: The oldest value from series 1 to remove
+///   prev_mean0: Previous mean of series 0
+///   prev_mean1: Previous mean of series 1
+///   prev_m2_0: Previous sum of squared deviations of series 0
+///   prev_m2_1: Previous sum of squared deviations of series 1
+///   prev_c01: Previous sum of co-deviations
+///   period: Period for calculation (must be >= 2)
+///
+/// Returns:
+///   A tuple containing:
+///   - New correlation value
+///   - New mean of series 0
+///   - New mean of series 1
+///   - New sum of squared deviations of series 0
+///   - New sum of squared deviations of series 1
+///   - New sum of co-deviations
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> correl, mean0, mean1, m2_0, m2_1, c01 = kand.correl_inc_welford(
+///   ...     4.0,    # new value for series 0
+///   ...     8.0,    # new value for series 1
+///   ...     1.0,    # old value for series 0
+///   ...     2.0,    # old value for series 1
+///   ...     2.0,    # previous mean of series 0
+///   ...     4.0,    # previous mean of series 1
+///   ...     2.0,    # previous sum of squared deviations of series 0
+///   ...     8.0,    # previous sum of squared deviations of series 1
+///   ...     4.0,    # previous sum of co-deviations
+///   ...     3       # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "correl_inc_welford")]
+pub fn correl_inc_welford_py(
+    py: Python,
+    new0: TAFloat,
+    new1: TAFloat,
+    old0: TAFloat,
+    old1: TAFloat,
+    prev_mean0: TAFloat,
+    prev_mean1: TAFloat,
+    prev_m2_0: TAFloat,
+    prev_m2_1: TAFloat,
+    prev_c01: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        correl::correl_inc_welford(
+            new0, new1, old0, old1, prev_mean0, prev_mean1, prev_m2_0, prev_m2_1, prev_c01, period,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
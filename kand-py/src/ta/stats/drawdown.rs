@@ -0,0 +1,91 @@
+use kand::{TAFloat, TAInt, stats::drawdown};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes drawdown analytics over a NumPy array of equity values.
+///
+/// Percentage drop from the running peak, deepest drop so far, bars under water, longest stretch
+/// under water, and bars from trough to recovery on each bar that regains the peak.
+///
+/// Args:
+///   equity: Equity values as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays:
+///   - Drawdown percentage
+///   - Maximum drawdown percentage so far
+///   - Bars since the last peak
+///   - Longest drawdown duration so far
+///   - Bars from trough to recovery (non-zero only on the bar that regains the peak)
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> equity = np.array([100.0, 90.0, 80.0, 95.0, 105.0])
+///   >>> dd, max_dd, duration, max_duration, recovery = kand.drawdown(equity)
+///   ```
+#[pyfunction]
+#[pyo3(name = "drawdown", signature = (equity))]
+#[allow(clippy::type_complexity)]
+pub fn drawdown_py(
+    py: Python,
+    equity: PyReadonlyArray1<TAFloat>,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAInt>>,
+    Py<PyArray1<TAInt>>,
+    Py<PyArray1<TAInt>>,
+)> {
+    let input = equity.as_slice()?;
+    let len = input.len();
+    let mut output_drawdown = vec![0.0; len];
+    let mut output_max_drawdown = vec![0.0; len];
+    let mut output_duration = vec![0; len];
+    let mut output_max_duration = vec![0; len];
+    let mut output_recovery = vec![0; len];
+
+    py.allow_threads(|| {
+        drawdown::drawdown(
+            input,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+            &mut output_duration,
+            &mut output_max_duration,
+            &mut output_recovery,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_drawdown.into_pyarray(py).into(),
+        output_max_drawdown.into_pyarray(py).into(),
+        output_duration.into_pyarray(py).into(),
+        output_max_duration.into_pyarray(py).into(),
+        output_recovery.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the maximum drawdown percentage of an entire equity curve.
+///
+/// Args:
+///   equity: Equity values as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   The deepest drawdown percentage as a float.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> equity = np.array([100.0, 120.0, 90.0, 130.0])
+///   >>> mdd = kand.max_drawdown(equity)
+///   ```
+#[pyfunction]
+#[pyo3(name = "max_drawdown", signature = (equity))]
+pub fn max_drawdown_py(py: Python, equity: PyReadonlyArray1<TAFloat>) -> PyResult<TAFloat> {
+    let input = equity.as_slice()?;
+    py.allow_threads(|| drawdown::max_drawdown(input))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
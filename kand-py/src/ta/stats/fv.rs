@@ -0,0 +1,34 @@
+use kand::{TAFloat, stats::fv};
+use pyo3::prelude::*;
+
+/// Computes the future value of an investment, as the spreadsheet `FV` function does.
+///
+/// Cash paid out is negative and cash received is positive.
+///
+/// Args:
+///   rate: Interest rate per period.
+///   nper: Number of payment periods.
+///   pmt: Payment made each period.
+///   pv: Present value.
+///   begin: Whether payments are due at the beginning of each period. Defaults to False.
+///
+/// Returns:
+///   The future value as a float.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> value = kand.fv(0.06 / 12, 10, -200, -500, True)
+///   ```
+#[pyfunction]
+#[pyo3(name = "fv", signature = (rate, nper, pmt, pv, begin=false))]
+pub fn fv_py(
+    rate: TAFloat,
+    nper: TAFloat,
+    pmt: TAFloat,
+    pv: TAFloat,
+    begin: bool,
+) -> PyResult<TAFloat> {
+    fv::fv(rate, nper, pmt, pv, begin)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
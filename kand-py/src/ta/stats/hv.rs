@@ -0,0 +1,125 @@
+use kand::{TAFloat, stats::hv};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate rolling close-to-close historical volatility for NumPy arrays
+///
+/// Historical volatility is the sample standard deviation of log returns over a rolling window, annualized by `periods_per_year`.
+///
+/// Args:
+///   prices: Prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of log returns in the rolling window (must be >= 2).
+///   periods_per_year: Number of bars per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Historical volatility values
+///   - Rolling mean of log returns
+///   - Rolling sum of squared deviations of log returns
+///   Each array has the same length as the input, with the first `period` elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 101.0, 100.5, 102.0, 101.5, 103.0])
+///   >>> hv, mean, m2 = kand.hv(prices, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "hv", signature = (prices, period, periods_per_year=252.0))]
+pub fn hv_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    periods_per_year: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_hv = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        hv::hv(
+            input_prices,
+            period,
+            periods_per_year,
+            &mut output_hv,
+            &mut output_mean,
+            &mut output_m2,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_hv.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest close-to-close historical volatility value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Newest price
+///   prev_price: Price before the newest one
+///   old_price: Price that ends the log return leaving the window
+///   old_prev_price: Price that starts the log return leaving the window
+///   prev_mean: Previous mean of log returns
+///   prev_m2: Previous sum of squared deviations of log returns
+///   period: Number of log returns in the rolling window (must be >= 2)
+///   periods_per_year: Number of bars per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest historical volatility
+///   - New mean of log returns
+///   - New sum of squared deviations
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> hv, mean, m2 = kand.hv_inc(
+///   ...     101.5,   # newest price
+///   ...     102.0,   # price before the newest one
+///   ...     101.0,   # price that ends the log return leaving the window
+///   ...     100.0,   # price that starts the log return leaving the window
+///   ...     0.0066,  # previous mean of log returns
+///   ...     0.0002,  # previous sum of squared deviations of log returns
+///   ...     3        # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "hv_inc", signature = (price, prev_price, old_price, old_prev_price, prev_mean, prev_m2, period, periods_per_year=252.0))]
+#[allow(clippy::too_many_arguments)]
+pub fn hv_inc_py(
+    py: Python,
+    price: TAFloat,
+    prev_price: TAFloat,
+    old_price: TAFloat,
+    old_prev_price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    period: usize,
+    periods_per_year: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        hv::hv_inc(
+            price,
+            prev_price,
+            old_price,
+            old_prev_price,
+            prev_mean,
+            prev_m2,
+            period,
+            periods_per_year,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,61 @@
+use kand::{TAFloat, TAInt, stats::irr};
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+
+/// Computes the internal rate of return of a NumPy array of periodic cash flows, as the
+/// spreadsheet `IRR` function does.
+///
+/// Args:
+///   values: Cash flows as a 1-D NumPy array of type `TAFloat`, the first one made today.
+///   guess: Starting rate for the solver. Must be > -1. Defaults to 0.1.
+///
+/// Returns:
+///   The rate of return per period as a float.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> rate = kand.irr(np.array([-70000.0, 12000.0, 15000.0, 18000.0, 21000.0, 26000.0]))
+///   ```
+#[pyfunction]
+#[pyo3(name = "irr", signature = (values, guess=0.1))]
+pub fn irr_py(py: Python, values: PyReadonlyArray1<TAFloat>, guess: TAFloat) -> PyResult<TAFloat> {
+    let input = values.as_slice()?;
+    py.allow_threads(|| irr::irr(input, guess))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes the annual internal rate of return of dated cash flows, as the spreadsheet `XIRR`
+/// function does.
+///
+/// Args:
+///   values: Cash flows as a 1-D NumPy array of type `TAFloat`.
+///   dates: Day numbers of the cash flows as a 1-D NumPy array of type `TAInt`, none earlier
+///     than the first.
+///   guess: Starting rate for the solver. Must be > -1. Defaults to 0.1.
+///
+/// Returns:
+///   The annual rate of return as a float.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> values = np.array([-10000.0, 2750.0, 4250.0, 3250.0, 2750.0])
+///   >>> dates = np.array([39448, 39508, 39751, 39859, 39904])
+///   >>> rate = kand.xirr(values, dates)
+///   ```
+#[pyfunction]
+#[pyo3(name = "xirr", signature = (values, dates, guess=0.1))]
+pub fn xirr_py(
+    py: Python,
+    values: PyReadonlyArray1<TAFloat>,
+    dates: PyReadonlyArray1<TAInt>,
+    guess: TAFloat,
+) -> PyResult<TAFloat> {
+    let input_values = values.as_slice()?;
+    let input_dates = dates.as_slice()?;
+    py.allow_threads(|| irr::xirr(input_values, input_dates, guess))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,68 @@
+use kand::{TAFloat, stats::kelly};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the rolling Kelly fraction over a NumPy array of trade returns.
+///
+/// The fraction of capital to risk per trade given the recent win rate and payoff ratio, scaled
+/// by `fraction` (1.0 for full Kelly, 0.5 for half Kelly).
+///
+/// Args:
+///   trades: Per-trade returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of trades in the rolling window. Must be >= 1.
+///   fraction: Scale applied to the full Kelly fraction. Must be > 0. Defaults to 1.0.
+///
+/// Returns:
+///   A 1-D NumPy array of Kelly fractions, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> trades = np.array([0.04, -0.02, 0.02, -0.01, 0.03])
+///   >>> half_kelly = kand.kelly(trades, 4, 0.5)
+///   ```
+#[pyfunction]
+#[pyo3(name = "kelly", signature = (trades, period, fraction=1.0))]
+pub fn kelly_py(
+    py: Python,
+    trades: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    fraction: TAFloat,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input = trades.as_slice()?;
+    let mut output_kelly = vec![0.0; input.len()];
+
+    py.allow_threads(|| kelly::kelly(input, period, fraction, &mut output_kelly))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_kelly.into_pyarray(py).into())
+}
+
+/// Computes the Kelly fraction of an entire NumPy array of trade returns.
+///
+/// Args:
+///   trades: Per-trade returns as a 1-D NumPy array of type `TAFloat`.
+///   fraction: Scale applied to the full Kelly fraction. Must be > 0. Defaults to 1.0.
+///
+/// Returns:
+///   The Kelly fraction as a float, or NaN when there are losing but no winning trades.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> trades = np.array([0.04, -0.02, 0.02, -0.01])
+///   >>> full_kelly = kand.kelly_criterion(trades)
+///   ```
+#[pyfunction]
+#[pyo3(name = "kelly_criterion", signature = (trades, fraction=1.0))]
+pub fn kelly_criterion_py(
+    py: Python,
+    trades: PyReadonlyArray1<TAFloat>,
+    fraction: TAFloat,
+) -> PyResult<TAFloat> {
+    let input = trades.as_slice()?;
+    py.allow_threads(|| kelly::kelly_criterion(input, fraction))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,128 @@
+use kand::{TAFloat, stats::kurt};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate rolling excess Kurtosis for a NumPy array
+///
+/// Excess kurtosis measures how heavy the tails of the window are compared with a normal
+/// distribution. This is the population excess kurtosis `n * M4 / M2^2 - 3`; a constant window
+/// gives NaN.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for Kurtosis calculation (must be >= 4).
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Excess Kurtosis values
+///   - Rolling mean values
+///   - Rolling sum of squared deviations from the mean
+///   - Rolling sum of cubed deviations from the mean
+///   - Rolling sum of fourth-power deviations from the mean
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([1.0, 2.0, 3.0, 4.0, 20.0])
+///   >>> kurt, mean, m2, m3, m4 = kand.kurt(prices, 4)
+///   ```
+#[pyfunction]
+#[pyo3(name = "kurt", signature = (prices, period))]
+#[allow(clippy::type_complexity)]
+pub fn kurt_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_kurt = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+    let mut output_m3 = vec![0.0; len];
+    let mut output_m4 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        kurt::kurt(
+            input_prices,
+            period,
+            &mut output_kurt,
+            &mut output_mean,
+            &mut output_m2,
+            &mut output_m3,
+            &mut output_m4,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_kurt.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+        output_m3.into_pyarray(py).into(),
+        output_m4.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest rolling excess Kurtosis value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   prev_mean: Previous period's mean
+///   prev_m2: Previous period's sum of squared deviations from the mean
+///   prev_m3: Previous period's sum of cubed deviations from the mean
+///   prev_m4: Previous period's sum of fourth-power deviations from the mean
+///   old_price: Price being removed from the period
+///   period: Period for Kurtosis calculation (must be >= 4)
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest excess Kurtosis value
+///   - New mean
+///   - New sum of squared deviations
+///   - New sum of cubed deviations
+///   - New sum of fourth-power deviations
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> # Window [1.0, 2.0, 3.0, 4.0] slides to [2.0, 3.0, 4.0, 5.0]
+///   >>> kurt, mean, m2, m3, m4 = kand.kurt_inc(
+///   ...     5.0,    # current price
+///   ...     2.5,    # previous mean
+///   ...     5.0,    # previous sum of squared deviations
+///   ...     0.0,    # previous sum of cubed deviations
+///   ...     10.25,  # previous sum of fourth-power deviations
+///   ...     1.0,    # price to remove
+///   ...     4       # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "kurt_inc")]
+pub fn kurt_inc_py(
+    py: Python,
+    price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    prev_m3: TAFloat,
+    prev_m4: TAFloat,
+    old_price: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        kurt::kurt_inc(
+            price, prev_mean, prev_m2, prev_m3, prev_m4, old_price, period,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -42,6 +42,9 @@ pub fn max_py(
 
 /// Calculate the latest Maximum Value incrementally
 ///
+/// Deprecated: returns the new price when the previous max leaves the window, which can
+/// understate the MAX; use `max` on the full series.
+///
 /// Args:
 ///   py: Python interpreter token
 ///   price: Current period's price
@@ -58,6 +61,7 @@ pub fn max_py(
 ///   >>> new_max = kand.max_inc(10.5, 11.0, 9.0, 14)
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "max_inc")]
 pub fn max_inc_py(
     py: Python,
@@ -44,6 +44,8 @@ pub fn min_py(
 
 /// Calculate the latest MIN value incrementally
 ///
+/// Deprecated: fails when the previous min leaves the window; use `min` on the full series.
+///
 /// Args:
 ///   py: Python interpreter token
 ///   price: Current period's price
@@ -60,6 +62,7 @@ pub fn min_py(
 ///   >>> new_min = kand.min_inc(15.0, 12.0, 14.0, 14)
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "min_inc")]
 pub fn min_inc_py(
     py: Python,
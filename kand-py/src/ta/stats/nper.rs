@@ -0,0 +1,34 @@
+use kand::{TAFloat, stats::nper};
+use pyo3::prelude::*;
+
+/// Computes the number of periods of an investment, as the spreadsheet `NPER` function does.
+///
+/// Cash paid out is negative and cash received is positive.
+///
+/// Args:
+///   rate: Interest rate per period.
+///   pmt: Payment made each period.
+///   pv: Present value.
+///   fv: Future value. Defaults to 0.0.
+///   begin: Whether payments are due at the beginning of each period. Defaults to False.
+///
+/// Returns:
+///   The number of periods as a float.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> periods = kand.nper(0.12 / 12, -100, -1000, 10000, True)
+///   ```
+#[pyfunction]
+#[pyo3(name = "nper", signature = (rate, pmt, pv, fv=0.0, begin=false))]
+pub fn nper_py(
+    rate: TAFloat,
+    pmt: TAFloat,
+    pv: TAFloat,
+    fv: TAFloat,
+    begin: bool,
+) -> PyResult<TAFloat> {
+    nper::nper(rate, pmt, pv, fv, begin)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,29 @@
+use kand::{TAFloat, stats::npv};
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+
+/// Computes the net present value of a NumPy array of cash flows, as the spreadsheet `NPV`
+/// function does.
+///
+/// The first cash flow is discounted by one period.
+///
+/// Args:
+///   rate: Discount rate per period. Must be > -1.
+///   values: Cash flows at the end of each period as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   The net present value as a float.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> value = kand.npv(0.1, np.array([-10000.0, 3000.0, 4200.0, 6800.0]))
+///   ```
+#[pyfunction]
+#[pyo3(name = "npv", signature = (rate, values))]
+pub fn npv_py(py: Python, rate: TAFloat, values: PyReadonlyArray1<TAFloat>) -> PyResult<TAFloat> {
+    let input = values.as_slice()?;
+    py.allow_threads(|| npv::npv(rate, input))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,117 @@
+use kand::{TAFloat, stats::parkinson};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate rolling Parkinson volatility for NumPy arrays
+///
+/// Parkinson volatility estimates volatility from the high-low range of each bar, annualized by `periods_per_year`.
+///
+/// Args:
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of bars in the rolling window (must be >= 2).
+///   periods_per_year: Number of bars per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple of two 1-D NumPy arrays containing:
+///   - Parkinson volatility values
+///   - Rolling sum of squared log ranges
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([101.0, 102.5, 102.0, 103.5, 104.0])
+///   >>> low = np.array([99.0, 100.5, 100.0, 101.0, 102.0])
+///   >>> parkinson, total = kand.parkinson(high, low, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "parkinson", signature = (high, low, period, periods_per_year=252.0))]
+pub fn parkinson_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    periods_per_year: TAFloat,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let len = input_high.len();
+
+    let mut output_parkinson = vec![0.0; len];
+    let mut output_sum = vec![0.0; len];
+
+    py.allow_threads(|| {
+        parkinson::parkinson(
+            input_high,
+            input_low,
+            period,
+            periods_per_year,
+            &mut output_parkinson,
+            &mut output_sum,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_parkinson.into_pyarray(py).into(),
+        output_sum.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest Parkinson volatility value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   high: High price of the newest bar
+///   low: Low price of the newest bar
+///   old_high: High price of the bar leaving the window
+///   old_low: Low price of the bar leaving the window
+///   prev_sum: Previous sum of squared log ranges
+///   period: Number of bars in the rolling window (must be >= 2)
+///   periods_per_year: Number of bars per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Parkinson volatility
+///   - New sum of squared log ranges
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> parkinson, total = kand.parkinson_inc(
+///   ...     101.0,   # newest high
+///   ...     100.0,   # newest low
+///   ...     102.0,   # high leaving the window
+///   ...     100.0,   # low leaving the window
+///   ...     0.0012,  # previous sum
+///   ...     3        # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "parkinson_inc", signature = (high, low, old_high, old_low, prev_sum, period, periods_per_year=252.0))]
+#[allow(clippy::too_many_arguments)]
+pub fn parkinson_inc_py(
+    py: Python,
+    high: TAFloat,
+    low: TAFloat,
+    old_high: TAFloat,
+    old_low: TAFloat,
+    prev_sum: TAFloat,
+    period: usize,
+    periods_per_year: TAFloat,
+) -> PyResult<(TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        parkinson::parkinson_inc(
+            high,
+            low,
+            old_high,
+            old_low,
+            prev_sum,
+            period,
+            periods_per_year,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,41 @@
+use kand::{TAFloat, stats::percentrank};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate a rolling Percent Rank for a NumPy array
+///
+/// Percent Rank places each price within its own window on a 0 to 100 scale, with ties sharing
+/// the midpoint of their ranks.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for Percent Rank calculation (must be >= 2).
+///
+/// Returns:
+///   A 1-D NumPy array containing the Percent Rank values.
+///   The array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([3.0, 1.0, 4.0, 1.0, 5.0])
+///   >>> rank = kand.percentrank(prices, 4)
+///   ```
+#[pyfunction]
+#[pyo3(name = "percentrank", signature = (prices, period))]
+pub fn percentrank_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_percentrank = vec![0.0; len];
+
+    py.allow_threads(|| percentrank::percentrank(input_prices, period, &mut output_percentrank))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_percentrank.into_pyarray(py).into())
+}
@@ -0,0 +1,34 @@
+use kand::{TAFloat, stats::pmt};
+use pyo3::prelude::*;
+
+/// Computes the periodic payment of a loan or annuity, as the spreadsheet `PMT` function does.
+///
+/// Cash paid out is negative and cash received is positive.
+///
+/// Args:
+///   rate: Interest rate per period.
+///   nper: Number of payment periods. Must be non-zero.
+///   pv: Present value.
+///   fv: Future value. Defaults to 0.0.
+///   begin: Whether payments are due at the beginning of each period. Defaults to False.
+///
+/// Returns:
+///   The payment per period as a float.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> payment = kand.pmt(0.08 / 12, 10, 10000)
+///   ```
+#[pyfunction]
+#[pyo3(name = "pmt", signature = (rate, nper, pv, fv=0.0, begin=false))]
+pub fn pmt_py(
+    rate: TAFloat,
+    nper: TAFloat,
+    pv: TAFloat,
+    fv: TAFloat,
+    begin: bool,
+) -> PyResult<TAFloat> {
+    pmt::pmt(rate, nper, pv, fv, begin)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,34 @@
+use kand::{TAFloat, stats::pv};
+use pyo3::prelude::*;
+
+/// Computes the present value of an investment, as the spreadsheet `PV` function does.
+///
+/// Cash paid out is negative and cash received is positive.
+///
+/// Args:
+///   rate: Interest rate per period.
+///   nper: Number of payment periods.
+///   pmt: Payment made each period.
+///   fv: Future value. Defaults to 0.0.
+///   begin: Whether payments are due at the beginning of each period. Defaults to False.
+///
+/// Returns:
+///   The present value as a float.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> value = kand.pv(0.08 / 12, 240, 500)
+///   ```
+#[pyfunction]
+#[pyo3(name = "pv", signature = (rate, nper, pmt, fv=0.0, begin=false))]
+pub fn pv_py(
+    rate: TAFloat,
+    nper: TAFloat,
+    pmt: TAFloat,
+    fv: TAFloat,
+    begin: bool,
+) -> PyResult<TAFloat> {
+    pv::pv(rate, nper, pmt, fv, begin)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,81 @@
+use kand::{TAFloat, stats::quantile};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate a rolling Quantile for a NumPy array
+///
+/// The quantile is the value below which a given fraction of the window lies, interpolated
+/// linearly between the two nearest values like NumPy and pandas do.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for Quantile calculation (must be >= 2).
+///   quantile: The quantile to calculate (must be between 0 and 1).
+///
+/// Returns:
+///   A 1-D NumPy array containing the Quantile values.
+///   The array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([1.0, 5.0, 2.0, 8.0, 3.0])
+///   >>> q75 = kand.quantile(prices, 4, 0.75)
+///   ```
+#[pyfunction]
+#[pyo3(name = "quantile", signature = (prices, period, quantile))]
+pub fn quantile_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    quantile: TAFloat,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_quantile = vec![0.0; len];
+
+    py.allow_threads(|| quantile::quantile(input_prices, period, quantile, &mut output_quantile))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_quantile.into_pyarray(py).into())
+}
+
+/// Calculate a rolling Median for a NumPy array
+///
+/// The median is the middle value of the window, or the average of the two middle values when
+/// the period is even.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for Median calculation (must be >= 2).
+///
+/// Returns:
+///   A 1-D NumPy array containing the Median values.
+///   The array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([1.0, 100.0, 2.0, 3.0])
+///   >>> median = kand.median(prices, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "median", signature = (prices, period))]
+pub fn median_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_median = vec![0.0; len];
+
+    py.allow_threads(|| quantile::median(input_prices, period, &mut output_median))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_median.into_pyarray(py).into())
+}
@@ -0,0 +1,36 @@
+use kand::{TAFloat, stats::rate};
+use pyo3::prelude::*;
+
+/// Computes the interest rate per period of an annuity, as the spreadsheet `RATE` function does.
+///
+/// The rate is found with Newton's method starting from `guess`.
+///
+/// Args:
+///   nper: Number of payment periods. Must be > 0.
+///   pmt: Payment made each period.
+///   pv: Present value.
+///   fv: Future value. Defaults to 0.0.
+///   begin: Whether payments are due at the beginning of each period. Defaults to False.
+///   guess: Starting rate for the solver. Must be > -1. Defaults to 0.1.
+///
+/// Returns:
+///   The rate per period as a float.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> monthly = kand.rate(48, -200, 8000)
+///   ```
+#[pyfunction]
+#[pyo3(name = "rate", signature = (nper, pmt, pv, fv=0.0, begin=false, guess=0.1))]
+pub fn rate_py(
+    nper: TAFloat,
+    pmt: TAFloat,
+    pv: TAFloat,
+    fv: TAFloat,
+    begin: bool,
+    guess: TAFloat,
+) -> PyResult<TAFloat> {
+    rate::rate(nper, pmt, pv, fv, begin, guess)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,278 @@
+use kand::{TAFloat, stats::ret};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes simple returns of a NumPy array of prices.
+///
+/// Each value is the relative change from the previous price. The first value is NaN, as is
+/// any value whose previous price is zero.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array of simple returns, with the first element NaN.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 102.0, 99.96])
+///   >>> returns = kand.simple_returns(prices)
+///   ```
+#[pyfunction]
+#[pyo3(name = "simple_returns", signature = (data))]
+pub fn simple_returns_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input = data.as_slice()?;
+    let mut output_returns = vec![0.0; input.len()];
+
+    py.allow_threads(|| ret::simple_returns(input, &mut output_returns))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_returns.into_pyarray(py).into())
+}
+
+/// Calculates a single simple return incrementally.
+///
+/// Args:
+///   price: The current price.
+///   prev_price: The previous price.
+///
+/// Returns:
+///   The simple return, or NaN if the previous price is zero.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> value = kand.simple_returns_inc(102.0, 100.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "simple_returns_inc", signature = (price, prev_price))]
+pub fn simple_returns_inc_py(price: TAFloat, prev_price: TAFloat) -> PyResult<TAFloat> {
+    ret::simple_returns_inc(price, prev_price)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes log returns of a NumPy array of prices.
+///
+/// Each value is the natural logarithm of the ratio to the previous price. The first value is
+/// NaN, as is any value where either price is not positive.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array of log returns, with the first element NaN.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 110.0, 100.0])
+///   >>> returns = kand.log_returns(prices)
+///   ```
+#[pyfunction]
+#[pyo3(name = "log_returns", signature = (data))]
+pub fn log_returns_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input = data.as_slice()?;
+    let mut output_returns = vec![0.0; input.len()];
+
+    py.allow_threads(|| ret::log_returns(input, &mut output_returns))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_returns.into_pyarray(py).into())
+}
+
+/// Calculates a single log return incrementally.
+///
+/// Args:
+///   price: The current price.
+///   prev_price: The previous price.
+///
+/// Returns:
+///   The log return, or NaN if either price is not positive.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> value = kand.log_returns_inc(110.0, 100.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "log_returns_inc", signature = (price, prev_price))]
+pub fn log_returns_inc_py(price: TAFloat, prev_price: TAFloat) -> PyResult<TAFloat> {
+    ret::log_returns_inc(price, prev_price)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes cumulative returns of a NumPy array of prices.
+///
+/// Each value is the relative change from the first price. The first value is 0.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array of cumulative returns, all NaN if the first price is zero.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 110.0, 121.0])
+///   >>> returns = kand.cumulative_returns(prices)
+///   ```
+#[pyfunction]
+#[pyo3(name = "cumulative_returns", signature = (data))]
+pub fn cumulative_returns_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input = data.as_slice()?;
+    let mut output_returns = vec![0.0; input.len()];
+
+    py.allow_threads(|| ret::cumulative_returns(input, &mut output_returns))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_returns.into_pyarray(py).into())
+}
+
+/// Calculates a single cumulative return incrementally.
+///
+/// Args:
+///   price: The current price.
+///   base_price: The first price of the series.
+///
+/// Returns:
+///   The cumulative return, or NaN if the base price is zero.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> value = kand.cumulative_returns_inc(121.0, 100.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "cumulative_returns_inc", signature = (price, base_price))]
+pub fn cumulative_returns_inc_py(price: TAFloat, base_price: TAFloat) -> PyResult<TAFloat> {
+    ret::cumulative_returns_inc(price, base_price)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes returns over a rolling window of a NumPy array of prices.
+///
+/// Each value is the relative change from the price `period` bars back.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of bars each return spans. Must be >= 1.
+///
+/// Returns:
+///   A 1-D NumPy array of rolling returns, with the first `period` elements NaN.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 101.0, 103.0, 105.0])
+///   >>> returns = kand.rolling_returns(prices, 2)
+///   ```
+#[pyfunction]
+#[pyo3(name = "rolling_returns", signature = (data, period))]
+pub fn rolling_returns_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input = data.as_slice()?;
+    let mut output_returns = vec![0.0; input.len()];
+
+    py.allow_threads(|| ret::rolling_returns(input, period, &mut output_returns))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_returns.into_pyarray(py).into())
+}
+
+/// Calculates a single rolling-window return incrementally.
+///
+/// Args:
+///   price: The current price.
+///   old_price: The price `period` bars back.
+///
+/// Returns:
+///   The rolling return, or NaN if the old price is zero.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> value = kand.rolling_returns_inc(103.0, 100.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "rolling_returns_inc", signature = (price, old_price))]
+pub fn rolling_returns_inc_py(price: TAFloat, old_price: TAFloat) -> PyResult<TAFloat> {
+    ret::rolling_returns_inc(price, old_price)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes excess returns over a risk-free series.
+///
+/// Both series must use the same periodicity. NaN inputs propagate to the output.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   risk_free: Risk-free returns for the same periods as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array of excess returns.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([np.nan, 0.012, -0.004])
+///   >>> risk_free = np.array([0.0002, 0.0002, 0.0002])
+///   >>> excess = kand.excess_returns(returns, risk_free)
+///   ```
+#[pyfunction]
+#[pyo3(name = "excess_returns", signature = (returns, risk_free))]
+pub fn excess_returns_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    risk_free: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_returns = returns.as_slice()?;
+    let input_risk_free = risk_free.as_slice()?;
+    let mut output_excess = vec![0.0; input_returns.len()];
+
+    py.allow_threads(|| ret::excess_returns(input_returns, input_risk_free, &mut output_excess))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_excess.into_pyarray(py).into())
+}
+
+/// Calculates a single excess return incrementally.
+///
+/// Like `excess_returns`, a NaN input gives a NaN result instead of an error.
+///
+/// Args:
+///   period_return: The asset return of the period.
+///   risk_free: The risk-free return of the same period.
+///
+/// Returns:
+///   The excess return.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> value = kand.excess_returns_inc(0.012, 0.0002)
+///   ```
+#[pyfunction]
+#[pyo3(name = "excess_returns_inc", signature = (period_return, risk_free))]
+pub fn excess_returns_inc_py(period_return: TAFloat, risk_free: TAFloat) -> PyResult<TAFloat> {
+    ret::excess_returns_inc(period_return, risk_free)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,392 @@
+use kand::{TAFloat, stats::risk};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the rolling historical Value-at-Risk of a return series.
+///
+/// VaR is read from the empirical distribution of the window and reported as a positive loss:
+/// a VaR of 0.02 means a 2% loss.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window (must be >= 2).
+///   confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+///
+/// Returns:
+///   A 1-D NumPy array containing the Value-at-Risk.
+///   The array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.005, -0.04, 0.03])
+///   >>> var = kand.historical_var(returns, 5, 0.75)
+///   ```
+#[pyfunction]
+#[pyo3(name = "historical_var", signature = (returns, period, confidence=0.95))]
+pub fn historical_var_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    confidence: TAFloat,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_returns = returns.as_slice()?;
+    let len = input_returns.len();
+
+    let mut output_var = vec![0.0; len];
+
+    py.allow_threads(|| risk::historical_var(input_returns, period, confidence, &mut output_var))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_var.into_pyarray(py).into())
+}
+
+/// Computes the rolling historical Expected Shortfall (CVaR) of a return series.
+///
+/// Expected Shortfall is the average loss in the worst `1 - confidence` share of the window,
+/// reported as a positive loss.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window (must be >= 2).
+///   confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+///
+/// Returns:
+///   A 1-D NumPy array containing the Expected Shortfall.
+///   The array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.005, -0.04, 0.03])
+///   >>> es = kand.historical_es(returns, 5, 0.6)
+///   ```
+#[pyfunction]
+#[pyo3(name = "historical_es", signature = (returns, period, confidence=0.95))]
+pub fn historical_es_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    confidence: TAFloat,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_returns = returns.as_slice()?;
+    let len = input_returns.len();
+
+    let mut output_es = vec![0.0; len];
+
+    py.allow_threads(|| risk::historical_es(input_returns, period, confidence, &mut output_es))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_es.into_pyarray(py).into())
+}
+
+/// Computes the rolling Gaussian Value-at-Risk of a return series.
+///
+/// Assumes the returns of the window are normally distributed with their rolling mean and
+/// population standard deviation.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window (must be >= 2).
+///   confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Value-at-Risk
+///   - Rolling mean values
+///   - Rolling sum of squared deviations from the mean
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.01, 0.01, -0.01])
+///   >>> var, mean, m2 = kand.gaussian_var(returns, 4)
+///   ```
+#[pyfunction]
+#[pyo3(name = "gaussian_var", signature = (returns, period, confidence=0.95))]
+pub fn gaussian_var_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    confidence: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_returns = returns.as_slice()?;
+    let len = input_returns.len();
+
+    let mut output_var = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        risk::gaussian_var(
+            input_returns,
+            period,
+            confidence,
+            &mut output_var,
+            &mut output_mean,
+            &mut output_m2,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_var.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the latest rolling Gaussian Value-at-Risk incrementally.
+///
+/// Args:
+///   ret: Newest return.
+///   prev_mean: Previous rolling mean.
+///   prev_m2: Previous rolling sum of squared deviations from the mean.
+///   old_ret: Return leaving the window.
+///   period: Number of returns in the rolling window (must be >= 2).
+///   confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+///
+/// Returns:
+///   A tuple containing (var, mean, m2).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> var, mean, m2 = kand.gaussian_var_inc(0.01, 0.005, 0.0011, 0.03, 4)
+///   ```
+#[pyfunction]
+#[pyo3(name = "gaussian_var_inc", signature = (ret, prev_mean, prev_m2, old_ret, period, confidence=0.95))]
+pub fn gaussian_var_inc_py(
+    py: Python,
+    ret: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    old_ret: TAFloat,
+    period: usize,
+    confidence: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        risk::gaussian_var_inc(ret, prev_mean, prev_m2, old_ret, period, confidence)
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes the rolling Gaussian Expected Shortfall of a return series.
+///
+/// The mean loss beyond the Gaussian VaR when the returns of the window are normally distributed
+/// with their rolling mean and population standard deviation.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window (must be >= 2).
+///   confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Expected Shortfall
+///   - Rolling mean values
+///   - Rolling sum of squared deviations from the mean
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.01, 0.01, -0.01])
+///   >>> es, mean, m2 = kand.gaussian_es(returns, 4)
+///   ```
+#[pyfunction]
+#[pyo3(name = "gaussian_es", signature = (returns, period, confidence=0.95))]
+pub fn gaussian_es_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    confidence: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_returns = returns.as_slice()?;
+    let len = input_returns.len();
+
+    let mut output_es = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        risk::gaussian_es(
+            input_returns,
+            period,
+            confidence,
+            &mut output_es,
+            &mut output_mean,
+            &mut output_m2,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_es.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the latest rolling Gaussian Expected Shortfall incrementally.
+///
+/// Args:
+///   ret: Newest return.
+///   prev_mean: Previous rolling mean.
+///   prev_m2: Previous rolling sum of squared deviations from the mean.
+///   old_ret: Return leaving the window.
+///   period: Number of returns in the rolling window (must be >= 2).
+///   confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+///
+/// Returns:
+///   A tuple containing (es, mean, m2).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> es, mean, m2 = kand.gaussian_es_inc(0.01, 0.005, 0.0011, 0.03, 4)
+///   ```
+#[pyfunction]
+#[pyo3(name = "gaussian_es_inc", signature = (ret, prev_mean, prev_m2, old_ret, period, confidence=0.95))]
+pub fn gaussian_es_inc_py(
+    py: Python,
+    ret: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    old_ret: TAFloat,
+    period: usize,
+    confidence: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| risk::gaussian_es_inc(ret, prev_mean, prev_m2, old_ret, period, confidence))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes the rolling Cornish-Fisher (modified) Value-at-Risk of a return series.
+///
+/// Corrects the Gaussian quantile for the skewness and excess kurtosis of the window, so fat left
+/// tails raise the VaR above its Gaussian value.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window (must be >= 4).
+///   confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Value-at-Risk
+///   - Rolling mean values
+///   - Rolling sum of squared deviations from the mean
+///   - Rolling sum of cubed deviations from the mean
+///   - Rolling sum of fourth-power deviations from the mean
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.01, 0.01, -0.01])
+///   >>> var, mean, m2, m3, m4 = kand.cornish_fisher_var(returns, 4)
+///   ```
+#[pyfunction]
+#[pyo3(name = "cornish_fisher_var", signature = (returns, period, confidence=0.95))]
+#[allow(clippy::type_complexity)]
+pub fn cornish_fisher_var_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    confidence: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_returns = returns.as_slice()?;
+    let len = input_returns.len();
+
+    let mut output_var = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+    let mut output_m3 = vec![0.0; len];
+    let mut output_m4 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        risk::cornish_fisher_var(
+            input_returns,
+            period,
+            confidence,
+            &mut output_var,
+            &mut output_mean,
+            &mut output_m2,
+            &mut output_m3,
+            &mut output_m4,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_var.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+        output_m3.into_pyarray(py).into(),
+        output_m4.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the latest rolling Cornish-Fisher Value-at-Risk incrementally.
+///
+/// Args:
+///   ret: Newest return.
+///   prev_mean: Previous rolling mean.
+///   prev_m2: Previous rolling sum of squared deviations from the mean.
+///   prev_m3: Previous rolling sum of cubed deviations from the mean.
+///   prev_m4: Previous rolling sum of fourth-power deviations from the mean.
+///   old_ret: Return leaving the window.
+///   period: Number of returns in the rolling window (must be >= 4).
+///   confidence: Confidence level, strictly between 0 and 1. Defaults to 0.95.
+///
+/// Returns:
+///   A tuple containing (var, mean, m2, m3, m4).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> var, mean, m2, m3, m4 = kand.cornish_fisher_var_inc(
+///   ...     0.01, 0.005, 0.0011, 0.000009, 0.0000004925, 0.03, 4
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "cornish_fisher_var_inc", signature = (ret, prev_mean, prev_m2, prev_m3, prev_m4, old_ret, period, confidence=0.95))]
+#[allow(clippy::too_many_arguments)]
+pub fn cornish_fisher_var_inc_py(
+    py: Python,
+    ret: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    prev_m3: TAFloat,
+    prev_m4: TAFloat,
+    old_ret: TAFloat,
+    period: usize,
+    confidence: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        risk::cornish_fisher_var_inc(
+            ret, prev_mean, prev_m2, prev_m3, prev_m4, old_ret, period, confidence,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,143 @@
+use kand::{TAFloat, stats::rogers_satchell};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate rolling Rogers-Satchell volatility for NumPy arrays
+///
+/// Rogers-Satchell volatility measures the high and low against both the open and the close, which keeps it unbiased when prices trend. It is annualized by `periods_per_year`.
+///
+/// Args:
+///   open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Closing prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of bars in the rolling window (must be >= 2).
+///   periods_per_year: Number of bars per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple of two 1-D NumPy arrays containing:
+///   - Rogers-Satchell volatility values
+///   - Rolling sum of Rogers-Satchell terms
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> open_ = np.array([100.0, 101.0, 101.5, 102.0, 103.0])
+///   >>> high = np.array([101.0, 102.5, 102.0, 103.5, 104.0])
+///   >>> low = np.array([99.0, 100.5, 100.0, 101.0, 102.0])
+///   >>> close = np.array([100.5, 101.5, 101.0, 103.0, 103.5])
+///   >>> rogers_satchell, total = kand.rogers_satchell(open_, high, low, close, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "rogers_satchell", signature = (open, high, low, close, period, periods_per_year=252.0))]
+pub fn rogers_satchell_py(
+    py: Python,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    periods_per_year: TAFloat,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_open.len();
+
+    let mut output_rogers_satchell = vec![0.0; len];
+    let mut output_sum = vec![0.0; len];
+
+    py.allow_threads(|| {
+        rogers_satchell::rogers_satchell(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            period,
+            periods_per_year,
+            &mut output_rogers_satchell,
+            &mut output_sum,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_rogers_satchell.into_pyarray(py).into(),
+        output_sum.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest Rogers-Satchell volatility value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   open: Opening price of the newest bar
+///   high: High price of the newest bar
+///   low: Low price of the newest bar
+///   close: Closing price of the newest bar
+///   old_open: Opening price of the bar leaving the window
+///   old_high: High price of the bar leaving the window
+///   old_low: Low price of the bar leaving the window
+///   old_close: Closing price of the bar leaving the window
+///   prev_sum: Previous sum of Rogers-Satchell terms
+///   period: Number of bars in the rolling window (must be >= 2)
+///   periods_per_year: Number of bars per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Rogers-Satchell volatility
+///   - New sum of Rogers-Satchell terms
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> rogers_satchell, total = kand.rogers_satchell_inc(
+///   ...     103.0,   # opening price of the newest bar
+///   ...     104.0,   # high price of the newest bar
+///   ...     102.0,   # low price of the newest bar
+///   ...     103.5,   # closing price of the newest bar
+///   ...     101.0,   # opening price of the bar leaving the window
+///   ...     102.5,   # high price of the bar leaving the window
+///   ...     100.5,   # low price of the bar leaving the window
+///   ...     101.5,   # closing price of the bar leaving the window
+///   ...     0.0004,  # previous sum of Rogers-Satchell terms
+///   ...     3        # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "rogers_satchell_inc", signature = (open, high, low, close, old_open, old_high, old_low, old_close, prev_sum, period, periods_per_year=252.0))]
+#[allow(clippy::too_many_arguments)]
+pub fn rogers_satchell_inc_py(
+    py: Python,
+    open: TAFloat,
+    high: TAFloat,
+    low: TAFloat,
+    close: TAFloat,
+    old_open: TAFloat,
+    old_high: TAFloat,
+    old_low: TAFloat,
+    old_close: TAFloat,
+    prev_sum: TAFloat,
+    period: usize,
+    periods_per_year: TAFloat,
+) -> PyResult<(TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        rogers_satchell::rogers_satchell_inc(
+            open,
+            high,
+            low,
+            close,
+            old_open,
+            old_high,
+            old_low,
+            old_close,
+            prev_sum,
+            period,
+            periods_per_year,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
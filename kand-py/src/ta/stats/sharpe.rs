@@ -0,0 +1,143 @@
+use kand::{TAFloat, stats::sharpe};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the rolling Sharpe ratio over a NumPy array of periodic returns.
+///
+/// Mean excess return over the sample standard deviation of returns, annualized by sqrt(periods_per_year).
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window. Must be >= 2.
+///   risk_free: Annual risk-free rate. Defaults to 0.0.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Sharpe ratio values
+///   - Rolling mean of returns
+///   - Rolling sum of squared deviations from the mean (M2)
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+///   >>> sharpe, mean, m2 = kand.sharpe(returns, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "sharpe", signature = (returns, period, risk_free=0.0, periods_per_year=252.0))]
+pub fn sharpe_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    risk_free: TAFloat,
+    periods_per_year: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input = returns.as_slice()?;
+    let len = input.len();
+
+    let mut output_sharpe = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        sharpe::sharpe(
+            input,
+            period,
+            risk_free,
+            periods_per_year,
+            &mut output_sharpe,
+            &mut output_mean,
+            &mut output_m2,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_sharpe.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the latest rolling Sharpe ratio incrementally.
+///
+/// Args:
+///   ret: Newest return to include.
+///   prev_mean: Previous mean of returns in the window.
+///   prev_m2: Previous sum of squared deviations from the mean in the window.
+///   old_ret: Oldest return to remove from the window.
+///   period: Number of returns in the rolling window. Must be >= 2.
+///   risk_free: Annual risk-free rate. Defaults to 0.0.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple containing (sharpe, mean, m2).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> sharpe, mean, m2 = kand.sharpe_inc(0.005, 0.005 / 3, 0.00215 / 3, 0.01, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "sharpe_inc", signature = (ret, prev_mean, prev_m2, old_ret, period, risk_free=0.0, periods_per_year=252.0))]
+#[allow(clippy::too_many_arguments)]
+pub fn sharpe_inc_py(
+    py: Python,
+    ret: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    old_ret: TAFloat,
+    period: usize,
+    risk_free: TAFloat,
+    periods_per_year: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        sharpe::sharpe_inc(
+            ret,
+            prev_mean,
+            prev_m2,
+            old_ret,
+            period,
+            risk_free,
+            periods_per_year,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes the Sharpe ratio of an entire return series.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   risk_free: Annual risk-free rate. Defaults to 0.0.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   The Sharpe ratio as a float, or NaN when it is undefined.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+///   >>> ratio = kand.sharpe_ratio(returns)
+///   ```
+#[pyfunction]
+#[pyo3(name = "sharpe_ratio", signature = (returns, risk_free=0.0, periods_per_year=252.0))]
+pub fn sharpe_ratio_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    risk_free: TAFloat,
+    periods_per_year: TAFloat,
+) -> PyResult<TAFloat> {
+    let input = returns.as_slice()?;
+    py.allow_threads(|| sharpe::sharpe_ratio(input, risk_free, periods_per_year))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
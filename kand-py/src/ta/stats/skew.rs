@@ -0,0 +1,114 @@
+use kand::{TAFloat, stats::skew};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate rolling Skewness for a NumPy array
+///
+/// Skewness measures the asymmetry of values around their rolling mean. This is the population
+/// skewness `sqrt(n) * M3 / M2^(3/2)`; a constant window gives NaN.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for Skewness calculation (must be >= 3).
+///
+/// Returns:
+///   A tuple of four 1-D NumPy arrays containing:
+///   - Skewness values
+///   - Rolling mean values
+///   - Rolling sum of squared deviations from the mean
+///   - Rolling sum of cubed deviations from the mean
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([1.0, 2.0, 3.0, 10.0, 4.0])
+///   >>> skew, mean, m2, m3 = kand.skew(prices, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "skew", signature = (prices, period))]
+#[allow(clippy::type_complexity)]
+pub fn skew_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_skew = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+    let mut output_m3 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        skew::skew(
+            input_prices,
+            period,
+            &mut output_skew,
+            &mut output_mean,
+            &mut output_m2,
+            &mut output_m3,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_skew.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+        output_m3.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest rolling Skewness value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   prev_mean: Previous period's mean
+///   prev_m2: Previous period's sum of squared deviations from the mean
+///   prev_m3: Previous period's sum of cubed deviations from the mean
+///   old_price: Price being removed from the period
+///   period: Period for Skewness calculation (must be >= 3)
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Skewness value
+///   - New mean
+///   - New sum of squared deviations
+///   - New sum of cubed deviations
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> # Window [1.0, 2.0, 3.0] slides to [2.0, 3.0, 10.0]
+///   >>> skew, mean, m2, m3 = kand.skew_inc(
+///   ...     10.0,  # current price
+///   ...     2.0,   # previous mean
+///   ...     2.0,   # previous sum of squared deviations
+///   ...     0.0,   # previous sum of cubed deviations
+///   ...     1.0,   # price to remove
+///   ...     3      # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "skew_inc")]
+pub fn skew_inc_py(
+    py: Python,
+    price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    prev_m3: TAFloat,
+    old_price: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| skew::skew_inc(price, prev_mean, prev_m2, prev_m3, old_price, period))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
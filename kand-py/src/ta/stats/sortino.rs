@@ -0,0 +1,153 @@
+use kand::{TAFloat, stats::sortino};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the rolling Sortino ratio over a NumPy array of periodic returns.
+///
+/// Mean return in excess of the minimum acceptable return (MAR) over the downside deviation below it, annualized by sqrt(periods_per_year).
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window. Must be >= 2.
+///   mar: Annual minimum acceptable return. Defaults to 0.0.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple of four 1-D NumPy arrays containing:
+///   - Sortino ratio values
+///   - Running sum of returns
+///   - Running sum of squared shortfalls below the MAR
+///   - Number of returns below the MAR in the window
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values
+///   (0 for the count).
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+///   >>> sortino, sum, sum_down_sq, down_count = kand.sortino(returns, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "sortino", signature = (returns, period, mar=0.0, periods_per_year=252.0))]
+#[allow(clippy::type_complexity)]
+pub fn sortino_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    mar: TAFloat,
+    periods_per_year: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<usize>>,
+)> {
+    let input = returns.as_slice()?;
+    let len = input.len();
+
+    let mut output_sortino = vec![0.0; len];
+    let mut output_sum = vec![0.0; len];
+    let mut output_sum_down_sq = vec![0.0; len];
+    let mut output_down_count = vec![0_usize; len];
+
+    py.allow_threads(|| {
+        sortino::sortino(
+            input,
+            period,
+            mar,
+            periods_per_year,
+            &mut output_sortino,
+            &mut output_sum,
+            &mut output_sum_down_sq,
+            &mut output_down_count,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_sortino.into_pyarray(py).into(),
+        output_sum.into_pyarray(py).into(),
+        output_sum_down_sq.into_pyarray(py).into(),
+        output_down_count.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the latest rolling Sortino ratio incrementally.
+///
+/// Args:
+///   ret: Newest return to include.
+///   prev_sum: Previous sum of returns in the window.
+///   prev_sum_down_sq: Previous sum of squared shortfalls below the MAR in the window.
+///   prev_down_count: Previous number of returns below the MAR in the window.
+///   old_ret: Oldest return to remove from the window.
+///   period: Number of returns in the rolling window. Must be >= 2.
+///   mar: Annual minimum acceptable return. Defaults to 0.0.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple containing (sortino, sum, sum_down_sq, down_count).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> sortino, sum, sum_down_sq, down_count = kand.sortino_inc(0.005, 0.005, 0.0004, 1, 0.01, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "sortino_inc", signature = (ret, prev_sum, prev_sum_down_sq, prev_down_count, old_ret, period, mar=0.0, periods_per_year=252.0))]
+#[allow(clippy::too_many_arguments)]
+pub fn sortino_inc_py(
+    py: Python,
+    ret: TAFloat,
+    prev_sum: TAFloat,
+    prev_sum_down_sq: TAFloat,
+    prev_down_count: usize,
+    old_ret: TAFloat,
+    period: usize,
+    mar: TAFloat,
+    periods_per_year: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, usize)> {
+    py.allow_threads(|| {
+        sortino::sortino_inc(
+            ret,
+            prev_sum,
+            prev_sum_down_sq,
+            prev_down_count,
+            old_ret,
+            period,
+            mar,
+            periods_per_year,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes the Sortino ratio of an entire return series.
+///
+/// Args:
+///   returns: Periodic returns as a 1-D NumPy array of type `TAFloat`.
+///   mar: Annual minimum acceptable return. Defaults to 0.0.
+///   periods_per_year: Number of return periods per year. Defaults to 252.0.
+///
+/// Returns:
+///   The Sortino ratio as a float, or NaN when it is undefined.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+///   >>> ratio = kand.sortino_ratio(returns)
+///   ```
+#[pyfunction]
+#[pyo3(name = "sortino_ratio", signature = (returns, mar=0.0, periods_per_year=252.0))]
+pub fn sortino_ratio_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    mar: TAFloat,
+    periods_per_year: TAFloat,
+) -> PyResult<TAFloat> {
+    let input = returns.as_slice()?;
+    py.allow_threads(|| sortino::sortino_ratio(input, mar, periods_per_year))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -61,8 +61,69 @@ pub fn stddev_py(
     ))
 }
 
+/// Calculate Standard Deviation for a NumPy array, returning the Welford state
+///
+/// Standard Deviation measures the dispersion of values from their mean over a specified period.
+/// It is calculated by taking the square root of the variance.
+///
+/// Args:
+///   input: Input values as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for calculation (must be >= 2).
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Standard Deviation values
+///   - Rolling mean values
+///   - Rolling sum of squared deviations from the mean
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+///   >>> stddev, mean, m2 = kand.stddev_welford(prices, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "stddev_welford", signature = (input, period))]
+pub fn stddev_welford_py(
+    py: Python,
+    input: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_array = input.as_slice()?;
+    let len = input_array.len();
+
+    let mut output_stddev = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        stddev::stddev_welford(
+            input_array,
+            period,
+            &mut output_stddev,
+            &mut output_mean,
+            &mut output_m2,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_stddev.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+    ))
+}
+
 /// Calculate the latest Standard Deviation value incrementally
 ///
+/// Deprecated: the running sums lose precision for large values; use `stddev_inc_welford`.
+///
 /// Args:
 ///   py: Python interpreter token
 ///   price: Current period's price
@@ -89,6 +150,7 @@ pub fn stddev_py(
 ///   ... )
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "stddev_inc")]
 pub fn stddev_inc_py(
     py: Python,
@@ -101,3 +163,45 @@ pub fn stddev_inc_py(
     py.allow_threads(|| stddev::stddev_inc(price, prev_sum, prev_sum_sq, old_price, period))
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Calculate the latest Standard Deviation value incrementally from the Welford state
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   prev_mean: Previous period's mean
+///   prev_m2: Previous period's sum of squared deviations from the mean
+///   old_price: Price being removed from the period
+///   period: Period for calculation (must be >= 2)
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Standard Deviation value
+///   - New mean
+///   - New sum of squared deviations
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> # Window [5.0, 8.0, 12.0] slides to [8.0, 12.0, 10.0]
+///   >>> stddev, mean, m2 = kand.stddev_inc_welford(
+///   ...     10.0,        # current price
+///   ...     25.0 / 3.0,  # previous mean
+///   ...     74.0 / 3.0,  # previous sum of squared deviations
+///   ...     5.0,         # old price
+///   ...     3            # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "stddev_inc_welford")]
+pub fn stddev_inc_welford_py(
+    py: Python,
+    price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    old_price: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| stddev::stddev_inc_welford(price, prev_mean, prev_m2, old_price, period))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -60,8 +60,68 @@ pub fn var_py(
     ))
 }
 
+/// Calculate Variance (VAR) for a NumPy array, returning the Welford state
+///
+/// Variance measures the average squared deviation of data points from their mean over a specified period.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for Variance calculation (must be >= 2).
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Variance values
+///   - Rolling mean values
+///   - Rolling sum of squared deviations from the mean
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([2.0, 4.0, 6.0, 8.0, 10.0])
+///   >>> var, mean, m2 = kand.var_welford(prices, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "var_welford", signature = (prices, period))]
+pub fn var_welford_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_var = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        var::var_welford(
+            input_prices,
+            period,
+            &mut output_var,
+            &mut output_mean,
+            &mut output_m2,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_var.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+    ))
+}
+
 /// Calculate the latest Variance value incrementally
 ///
+/// Deprecated: the running sums lose precision for large values; use `var_inc_welford`.
+///
 /// Args:
 ///   py: Python interpreter token
 ///   price: Current period's price
@@ -88,6 +148,7 @@ pub fn var_py(
 ///   ... )
 ///   ```
 #[pyfunction]
+#[allow(deprecated)]
 #[pyo3(name = "var_inc")]
 pub fn var_inc_py(
     py: Python,
@@ -100,3 +161,45 @@ pub fn var_inc_py(
     py.allow_threads(|| var::var_inc(price, prev_sum, prev_sum_sq, old_price, period))
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Calculate the latest Variance value incrementally from the Welford state
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   prev_mean: Previous period's mean
+///   prev_m2: Previous period's sum of squared deviations from the mean
+///   old_price: Price being removed from the period
+///   period: Period for Variance calculation (must be >= 2)
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Variance value
+///   - New mean
+///   - New sum of squared deviations
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> # Window [5.0, 8.0, 12.0] slides to [8.0, 12.0, 10.0]
+///   >>> var, mean, m2 = kand.var_inc_welford(
+///   ...     10.0,        # current price
+///   ...     25.0 / 3.0,  # previous mean
+///   ...     74.0 / 3.0,  # previous sum of squared deviations
+///   ...     5.0,         # price to remove
+///   ...     3            # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "var_inc_welford")]
+pub fn var_inc_welford_py(
+    py: Python,
+    price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    old_price: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| var::var_inc_welford(price, prev_mean, prev_m2, old_price, period))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,152 @@
+use kand::{TAFloat, TAInt, stats::winrate};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Extracts closed-trade returns from a position signal and a price series.
+///
+/// The sign of the signal gives the position held after each bar: long, short or flat. A trade
+/// opens where the position becomes non-zero and closes where it changes again. A trade still
+/// open at the end is not reported.
+///
+/// Args:
+///   signal: Positions per bar as a 1-D NumPy array of type `TAInt`.
+///   price: Prices as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array with one return per closed trade.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> signal = np.array([0, 1, 1, -1, -1, 0])
+///   >>> price = np.array([100.0, 100.0, 104.0, 110.0, 105.0, 99.0])
+///   >>> trades = kand.trade_returns(signal, price)
+///   ```
+#[pyfunction]
+#[pyo3(name = "trade_returns", signature = (signal, price))]
+pub fn trade_returns_py(
+    py: Python,
+    signal: PyReadonlyArray1<TAInt>,
+    price: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_signal = signal.as_slice()?;
+    let input_price = price.as_slice()?;
+    let mut output_trade_return = vec![0.0; input_signal.len()];
+
+    let count = py
+        .allow_threads(|| {
+            winrate::trade_returns(input_signal, input_price, &mut output_trade_return)
+        })
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+    output_trade_return.truncate(count);
+
+    Ok(output_trade_return.into_pyarray(py).into())
+}
+
+/// Computes rolling trade statistics over a NumPy array of trade returns.
+///
+/// Args:
+///   trades: Per-trade returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of trades in the rolling window. Must be >= 1.
+///
+/// Returns:
+///   A tuple of six 1-D NumPy arrays containing:
+///   - Win rate (fraction of winning trades)
+///   - Average winning return
+///   - Average losing return magnitude
+///   - Profit factor (inf when the window has winners but no losers)
+///   - Expectancy (average return per trade)
+///   - Payoff ratio (average win over average loss)
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> trades = np.array([0.04, -0.02, 0.02, -0.01, 0.03])
+///   >>> win_rate, avg_win, avg_loss, profit_factor, expectancy, payoff = kand.winrate(trades, 4)
+///   ```
+#[pyfunction]
+#[pyo3(name = "winrate", signature = (trades, period))]
+#[allow(clippy::type_complexity)]
+pub fn winrate_py(
+    py: Python,
+    trades: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input = trades.as_slice()?;
+    let len = input.len();
+    let mut output_win_rate = vec![0.0; len];
+    let mut output_avg_win = vec![0.0; len];
+    let mut output_avg_loss = vec![0.0; len];
+    let mut output_profit_factor = vec![0.0; len];
+    let mut output_expectancy = vec![0.0; len];
+    let mut output_payoff_ratio = vec![0.0; len];
+
+    py.allow_threads(|| {
+        winrate::winrate(
+            input,
+            period,
+            &mut output_win_rate,
+            &mut output_avg_win,
+            &mut output_avg_loss,
+            &mut output_profit_factor,
+            &mut output_expectancy,
+            &mut output_payoff_ratio,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_win_rate.into_pyarray(py).into(),
+        output_avg_win.into_pyarray(py).into(),
+        output_avg_loss.into_pyarray(py).into(),
+        output_profit_factor.into_pyarray(py).into(),
+        output_expectancy.into_pyarray(py).into(),
+        output_payoff_ratio.into_pyarray(py).into(),
+    ))
+}
+
+/// Computes the trade statistics of an entire NumPy array of trade returns.
+///
+/// Args:
+///   trades: Per-trade returns as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A tuple containing (win_rate, avg_win, avg_loss, profit_factor, expectancy, payoff_ratio).
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> trades = np.array([0.04, -0.02, 0.02, -0.01])
+///   >>> win_rate, avg_win, avg_loss, profit_factor, expectancy, payoff = kand.trade_stats(trades)
+///   ```
+#[pyfunction]
+#[pyo3(name = "trade_stats", signature = (trades))]
+pub fn trade_stats_py(
+    py: Python,
+    trades: PyReadonlyArray1<TAFloat>,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    let input = trades.as_slice()?;
+    let stats = py
+        .allow_threads(|| winrate::trade_stats(input))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        stats.win_rate(),
+        stats.avg_win(),
+        stats.avg_loss(),
+        stats.profit_factor(),
+        stats.expectancy(),
+        stats.payoff_ratio(),
+    ))
+}
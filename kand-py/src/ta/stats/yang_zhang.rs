@@ -0,0 +1,195 @@
+use kand::{TAFloat, stats::yang_zhang};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate rolling Yang-Zhang volatility for NumPy arrays
+///
+/// Yang-Zhang volatility adds the overnight gap to a weighted mix of the open-to-close and Rogers-Satchell variances, annualized by `periods_per_year`.
+///
+/// Args:
+///   open: Opening prices as a 1-D NumPy array of type `TAFloat`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Closing prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of bars in the rolling window (must be >= 2).
+///   periods_per_year: Number of bars per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple of six 1-D NumPy arrays containing:
+///   - Yang-Zhang volatility values
+///   - Rolling mean of overnight returns
+///   - Rolling M2 of overnight returns
+///   - Rolling mean of open-to-close returns
+///   - Rolling M2 of open-to-close returns
+///   - Rolling sum of Rogers-Satchell terms
+///   Each array has the same length as the input, with the first `period` elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> open_ = np.array([100.0, 101.0, 101.5, 102.0, 103.0])
+///   >>> high = np.array([101.0, 102.5, 102.0, 103.5, 104.0])
+///   >>> low = np.array([99.0, 100.5, 100.0, 101.0, 102.0])
+///   >>> close = np.array([100.5, 101.5, 101.0, 103.0, 103.5])
+///   >>> yang_zhang, *state = kand.yang_zhang(open_, high, low, close, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "yang_zhang", signature = (open, high, low, close, period, periods_per_year=252.0))]
+#[allow(clippy::type_complexity)]
+pub fn yang_zhang_py(
+    py: Python,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    periods_per_year: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_open.len();
+
+    let mut output_yang_zhang = vec![0.0; len];
+    let mut output_overnight_mean = vec![0.0; len];
+    let mut output_overnight_m2 = vec![0.0; len];
+    let mut output_intraday_mean = vec![0.0; len];
+    let mut output_intraday_m2 = vec![0.0; len];
+    let mut output_rs_sum = vec![0.0; len];
+
+    py.allow_threads(|| {
+        yang_zhang::yang_zhang(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            period,
+            periods_per_year,
+            &mut output_yang_zhang,
+            &mut output_overnight_mean,
+            &mut output_overnight_m2,
+            &mut output_intraday_mean,
+            &mut output_intraday_m2,
+            &mut output_rs_sum,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_yang_zhang.into_pyarray(py).into(),
+        output_overnight_mean.into_pyarray(py).into(),
+        output_overnight_m2.into_pyarray(py).into(),
+        output_intraday_mean.into_pyarray(py).into(),
+        output_intraday_m2.into_pyarray(py).into(),
+        output_rs_sum.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest Yang-Zhang volatility value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   open: Opening price of the newest bar
+///   high: High price of the newest bar
+///   low: Low price of the newest bar
+///   close: Closing price of the newest bar
+///   prev_close: Closing price of the bar before the newest one
+///   old_open: Opening price of the bar leaving the window
+///   old_high: High price of the bar leaving the window
+///   old_low: Low price of the bar leaving the window
+///   old_close: Closing price of the bar leaving the window
+///   old_prev_close: Closing price of the bar before the one leaving the window
+///   prev_overnight_mean: Previous mean of overnight returns
+///   prev_overnight_m2: Previous M2 of overnight returns
+///   prev_intraday_mean: Previous mean of open-to-close returns
+///   prev_intraday_m2: Previous M2 of open-to-close returns
+///   prev_rs_sum: Previous sum of Rogers-Satchell terms
+///   period: Number of bars in the rolling window (must be >= 2)
+///   periods_per_year: Number of bars per year. Defaults to 252.0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Yang-Zhang volatility
+///   - New mean of overnight returns
+///   - New M2 of overnight returns
+///   - New mean of open-to-close returns
+///   - New M2 of open-to-close returns
+///   - New sum of Rogers-Satchell terms
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> yang_zhang, *state = kand.yang_zhang_inc(
+///   ...     103.0,   # opening price of the newest bar
+///   ...     104.0,   # high price of the newest bar
+///   ...     102.0,   # low price of the newest bar
+///   ...     103.5,   # closing price of the newest bar
+///   ...     103.0,   # closing price of the bar before the newest one
+///   ...     101.0,   # opening price of the bar leaving the window
+///   ...     102.5,   # high price of the bar leaving the window
+///   ...     100.5,   # low price of the bar leaving the window
+///   ...     101.5,   # closing price of the bar leaving the window
+///   ...     100.5,   # closing price of the bar before the one leaving the window
+///   ...     0.001,   # previous mean of overnight returns
+///   ...     0.0001,  # previous M2 of overnight returns
+///   ...     0.001,   # previous mean of open-to-close returns
+///   ...     0.0001,  # previous M2 of open-to-close returns
+///   ...     0.0001,  # previous sum of Rogers-Satchell terms
+///   ...     3        # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "yang_zhang_inc", signature = (open, high, low, close, prev_close, old_open, old_high, old_low, old_close, old_prev_close, prev_overnight_mean, prev_overnight_m2, prev_intraday_mean, prev_intraday_m2, prev_rs_sum, period, periods_per_year=252.0))]
+#[allow(clippy::too_many_arguments)]
+pub fn yang_zhang_inc_py(
+    py: Python,
+    open: TAFloat,
+    high: TAFloat,
+    low: TAFloat,
+    close: TAFloat,
+    prev_close: TAFloat,
+    old_open: TAFloat,
+    old_high: TAFloat,
+    old_low: TAFloat,
+    old_close: TAFloat,
+    old_prev_close: TAFloat,
+    prev_overnight_mean: TAFloat,
+    prev_overnight_m2: TAFloat,
+    prev_intraday_mean: TAFloat,
+    prev_intraday_m2: TAFloat,
+    prev_rs_sum: TAFloat,
+    period: usize,
+    periods_per_year: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        yang_zhang::yang_zhang_inc(
+            open,
+            high,
+            low,
+            close,
+            prev_close,
+            old_open,
+            old_high,
+            old_low,
+            old_close,
+            old_prev_close,
+            prev_overnight_mean,
+            prev_overnight_m2,
+            prev_intraday_mean,
+            prev_intraday_m2,
+            prev_rs_sum,
+            period,
+            periods_per_year,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,104 @@
+use kand::{TAFloat, stats::zscore};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate the rolling Z-Score for a NumPy array
+///
+/// The Z-Score is the number of population standard deviations the latest price lies above or
+/// below the mean of the last `period` prices; a constant window gives NaN.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for Z-Score calculation (must be >= 2).
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Z-Score values
+///   - Rolling mean values
+///   - Rolling sum of squared deviations from the mean
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([2.0, 4.0, 6.0, 8.0, 10.0])
+///   >>> zscore, mean, m2 = kand.zscore(prices, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "zscore", signature = (prices, period))]
+pub fn zscore_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_zscore = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+
+    py.allow_threads(|| {
+        zscore::zscore(
+            input_prices,
+            period,
+            &mut output_zscore,
+            &mut output_mean,
+            &mut output_m2,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_zscore.into_pyarray(py).into(),
+        output_mean.into_pyarray(py).into(),
+        output_m2.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest rolling Z-Score value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   prev_mean: Previous period's mean
+///   prev_m2: Previous period's sum of squared deviations from the mean
+///   old_price: Price being removed from the period
+///   period: Period for Z-Score calculation (must be >= 2)
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Z-Score value
+///   - New mean
+///   - New sum of squared deviations
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> # Window [5.0, 8.0, 12.0] slides to [8.0, 12.0, 10.0]
+///   >>> zscore, mean, m2 = kand.zscore_inc(
+///   ...     10.0,        # current price
+///   ...     25.0 / 3.0,  # previous mean
+///   ...     74.0 / 3.0,  # previous sum of squared deviations
+///   ...     5.0,         # price to remove
+///   ...     3            # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "zscore_inc")]
+pub fn zscore_inc_py(
+    py: Python,
+    price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    old_price: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| zscore::zscore_inc(price, prev_mean, prev_m2, old_price, period))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,27 @@
+use kand::ta::ohlcv::ht_dcperiod;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for Hilbert Transform Dominant Cycle Period (HT_DCPERIOD) calculation.
+ * @returns {number} The lookback period.
+ */
+#[wasm_bindgen(js_name = htDcperiodLookback)]
+pub fn ht_dcperiod_lookback_wasm() -> Result<usize, JsValue> {
+    ht_dcperiod::lookback().map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the Hilbert Transform Dominant Cycle Period (HT_DCPERIOD) for a price series.
+ * @param {Float64Array} input_prices - Array of price values.
+ * @returns {Float64Array} An array of dominant cycle periods in bars with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = htDcperiod)]
+pub fn ht_dcperiod_wasm(input_prices: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let mut output_dcperiod = vec![0.0; input_prices.len()];
+
+    ht_dcperiod::ht_dcperiod(&input_prices, &mut output_dcperiod)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_dcperiod)
+}
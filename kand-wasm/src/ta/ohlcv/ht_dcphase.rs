@@ -0,0 +1,27 @@
+use kand::ta::ohlcv::ht_dcphase;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for Hilbert Transform Dominant Cycle Phase (HT_DCPHASE) calculation.
+ * @returns {number} The lookback period.
+ */
+#[wasm_bindgen(js_name = htDcphaseLookback)]
+pub fn ht_dcphase_lookback_wasm() -> Result<usize, JsValue> {
+    ht_dcphase::lookback().map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the Hilbert Transform Dominant Cycle Phase (HT_DCPHASE) for a price series.
+ * @param {Float64Array} input_prices - Array of price values.
+ * @returns {Float64Array} An array of dominant cycle phases in degrees with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = htDcphase)]
+pub fn ht_dcphase_wasm(input_prices: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let mut output_dcphase = vec![0.0; input_prices.len()];
+
+    ht_dcphase::ht_dcphase(&input_prices, &mut output_dcphase)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_dcphase)
+}
@@ -0,0 +1,45 @@
+use kand::ta::ohlcv::ht_phasor;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for Hilbert Transform Phasor Components (HT_PHASOR) calculation.
+ * @returns {number} The lookback period.
+ */
+#[wasm_bindgen(js_name = htPhasorLookback)]
+pub fn ht_phasor_lookback_wasm() -> Result<usize, JsValue> {
+    ht_phasor::lookback().map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the in-phase component of the Hilbert Transform Phasor Components (HT_PHASOR).
+ * @param {Float64Array} input_prices - Array of price values.
+ * @returns {Float64Array} An array of in-phase component values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = htPhasorInPhase)]
+pub fn ht_phasor_in_phase_wasm(input_prices: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let (output_in_phase, _) = phasor(&input_prices)?;
+    Ok(output_in_phase)
+}
+
+/**
+ * Calculates the quadrature component of the Hilbert Transform Phasor Components (HT_PHASOR).
+ * @param {Float64Array} input_prices - Array of price values.
+ * @returns {Float64Array} An array of quadrature component values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = htPhasorQuadrature)]
+pub fn ht_phasor_quadrature_wasm(input_prices: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let (_, output_quadrature) = phasor(&input_prices)?;
+    Ok(output_quadrature)
+}
+
+fn phasor(input_prices: &[f64]) -> Result<(Vec<f64>, Vec<f64>), JsValue> {
+    let mut output_in_phase = vec![0.0; input_prices.len()];
+    let mut output_quadrature = vec![0.0; input_prices.len()];
+
+    ht_phasor::ht_phasor(input_prices, &mut output_in_phase, &mut output_quadrature)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok((output_in_phase, output_quadrature))
+}
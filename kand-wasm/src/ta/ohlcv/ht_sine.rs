@@ -0,0 +1,45 @@
+use kand::ta::ohlcv::ht_sine;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for Hilbert Transform SineWave (HT_SINE) calculation.
+ * @returns {number} The lookback period.
+ */
+#[wasm_bindgen(js_name = htSineLookback)]
+pub fn ht_sine_lookback_wasm() -> Result<usize, JsValue> {
+    ht_sine::lookback().map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the sine line of the Hilbert Transform SineWave (HT_SINE).
+ * @param {Float64Array} input_prices - Array of price values.
+ * @returns {Float64Array} An array of sine values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = htSine)]
+pub fn ht_sine_wasm(input_prices: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let (output_sine, _) = sine_lead_sine(&input_prices)?;
+    Ok(output_sine)
+}
+
+/**
+ * Calculates the lead sine line of the Hilbert Transform SineWave (HT_SINE).
+ * @param {Float64Array} input_prices - Array of price values.
+ * @returns {Float64Array} An array of lead sine values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = htSineLeadSine)]
+pub fn ht_sine_lead_sine_wasm(input_prices: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let (_, output_lead_sine) = sine_lead_sine(&input_prices)?;
+    Ok(output_lead_sine)
+}
+
+fn sine_lead_sine(input_prices: &[f64]) -> Result<(Vec<f64>, Vec<f64>), JsValue> {
+    let mut output_sine = vec![0.0; input_prices.len()];
+    let mut output_lead_sine = vec![0.0; input_prices.len()];
+
+    ht_sine::ht_sine(input_prices, &mut output_sine, &mut output_lead_sine)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok((output_sine, output_lead_sine))
+}
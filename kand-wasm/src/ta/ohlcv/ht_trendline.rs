@@ -0,0 +1,27 @@
+use kand::ta::ohlcv::ht_trendline;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for Hilbert Transform Instantaneous Trendline (HT_TRENDLINE) calculation.
+ * @returns {number} The lookback period.
+ */
+#[wasm_bindgen(js_name = htTrendlineLookback)]
+pub fn ht_trendline_lookback_wasm() -> Result<usize, JsValue> {
+    ht_trendline::lookback().map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the Hilbert Transform Instantaneous Trendline (HT_TRENDLINE) for a price series.
+ * @param {Float64Array} input_prices - Array of price values.
+ * @returns {Float64Array} An array of trendline values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = htTrendline)]
+pub fn ht_trendline_wasm(input_prices: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let mut output_trendline = vec![0.0; input_prices.len()];
+
+    ht_trendline::ht_trendline(&input_prices, &mut output_trendline)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_trendline)
+}
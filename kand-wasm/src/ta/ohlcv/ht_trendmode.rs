@@ -0,0 +1,27 @@
+use kand::{TAInt, ta::ohlcv::ht_trendmode};
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for Hilbert Transform Trend vs Cycle Mode (HT_TRENDMODE) calculation.
+ * @returns {number} The lookback period.
+ */
+#[wasm_bindgen(js_name = htTrendmodeLookback)]
+pub fn ht_trendmode_lookback_wasm() -> Result<usize, JsValue> {
+    ht_trendmode::lookback().map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the Hilbert Transform Trend vs Cycle Mode (HT_TRENDMODE) for a price series.
+ * @param {Float64Array} input_prices - Array of price values.
+ * @returns {BigInt64Array} An array of modes (1 = trend, 0 = cycle) with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = htTrendmode)]
+pub fn ht_trendmode_wasm(input_prices: Vec<f64>) -> Result<Vec<TAInt>, JsValue> {
+    let mut output_trendmode = vec![0; input_prices.len()];
+
+    ht_trendmode::ht_trendmode(&input_prices, &mut output_trendmode)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_trendmode)
+}
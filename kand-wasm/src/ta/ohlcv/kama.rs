@@ -0,0 +1,90 @@
+use kand::ta::ohlcv::kama;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for KAMA calculation.
+ * @param {number} opt_period - The efficiency ratio period (must be >= 2).
+ * @param {number} opt_fast_period - Period of the fast smoothing constant (typically 2).
+ * @param {number} opt_slow_period - Period of the slow smoothing constant (typically 30).
+ * @returns {number} The lookback period.
+ * @throws {Error} If any period is invalid.
+ */
+#[wasm_bindgen(js_name = kamaLookback)]
+pub fn kama_lookback_wasm(
+    opt_period: usize,
+    opt_fast_period: usize,
+    opt_slow_period: usize,
+) -> Result<usize, JsValue> {
+    kama::lookback(opt_period, opt_fast_period, opt_slow_period)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates Kaufman Adaptive Moving Average (KAMA) for a price series.
+ * @param {Float64Array} input_prices - Array of price values to calculate KAMA from.
+ * @param {number} opt_period - The efficiency ratio period (must be >= 2).
+ * @param {number} opt_fast_period - Period of the fast smoothing constant (typically 2).
+ * @param {number} opt_slow_period - Period of the slow smoothing constant (typically 30).
+ * @returns {Float64Array} An array of KAMA values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = kama)]
+pub fn kama_wasm(
+    input_prices: Vec<f64>,
+    opt_period: usize,
+    opt_fast_period: usize,
+    opt_slow_period: usize,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_kama = vec![0.0; input_prices.len()];
+
+    kama::kama(
+        &input_prices,
+        opt_period,
+        opt_fast_period,
+        opt_slow_period,
+        &mut output_kama,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_kama)
+}
+
+/**
+ * Calculates a single KAMA value incrementally.
+ * @param {number} input_price - The current price.
+ * @param {number} input_prev_price - The previous price.
+ * @param {number} input_old_price - The price `opt_period` bars before the current one.
+ * @param {number} input_old_prev_price - The price `opt_period + 1` bars before the current one.
+ * @param {number} prev_kama - The previous KAMA value.
+ * @param {number} prev_volatility - The previous sum of absolute price changes over the period.
+ * @param {number} opt_fast_period - Period of the fast smoothing constant (typically 2).
+ * @param {number} opt_slow_period - Period of the slow smoothing constant (typically 30).
+ * @returns {Float64Array} A two-element array `[kama, volatility]`; pass `volatility` back as `prev_volatility` on the next call.
+ * @throws {Error} If the parameters are invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = kamaInc)]
+#[allow(clippy::too_many_arguments)]
+pub fn kama_inc_wasm(
+    input_price: f64,
+    input_prev_price: f64,
+    input_old_price: f64,
+    input_old_prev_price: f64,
+    prev_kama: f64,
+    prev_volatility: f64,
+    opt_fast_period: usize,
+    opt_slow_period: usize,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_kama, output_volatility) = kama::kama_inc(
+        input_price,
+        input_prev_price,
+        input_old_price,
+        input_old_prev_price,
+        prev_kama,
+        prev_volatility,
+        opt_fast_period,
+        opt_slow_period,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_kama, output_volatility])
+}
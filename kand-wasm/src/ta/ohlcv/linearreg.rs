@@ -0,0 +1,68 @@
+use kand::ta::ohlcv::linearreg;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for LINEARREG calculation.
+ * @param {number} opt_period - The regression window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = linearregLookback)]
+pub fn linearreg_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    linearreg::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the Linear Regression (LINEARREG) for a price series.
+ * @param {Float64Array} input_prices - Array of price values.
+ * @param {number} opt_period - The regression window (must be >= 2).
+ * @returns {Float64Array} An array of regression line values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = linearreg)]
+pub fn linearreg_wasm(input_prices: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let mut output_linearreg = vec![0.0; input_prices.len()];
+    let mut output_sum_y = vec![0.0; input_prices.len()];
+    let mut output_sum_xy = vec![0.0; input_prices.len()];
+
+    linearreg::linearreg(
+        &input_prices,
+        opt_period,
+        &mut output_linearreg,
+        &mut output_sum_y,
+        &mut output_sum_xy,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_linearreg)
+}
+
+/**
+ * Calculates a single LINEARREG value incrementally.
+ * @param {number} input_price - The newest price to include.
+ * @param {number} prev_sum_y - The previous sum of prices in the window.
+ * @param {number} prev_sum_xy - The previous sum of index-weighted prices in the window.
+ * @param {number} input_old_price - The oldest price to remove from the window.
+ * @param {number} opt_period - The regression window (must be >= 2).
+ * @returns {Float64Array} A three-element array `[linearreg, sum_y, sum_xy]`; pass the sums back on the next call.
+ * @throws {Error} If the parameters are invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = linearregInc)]
+pub fn linearreg_inc_wasm(
+    input_price: f64,
+    prev_sum_y: f64,
+    prev_sum_xy: f64,
+    input_old_price: f64,
+    opt_period: usize,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_linearreg, output_sum_y, output_sum_xy) = linearreg::linearreg_inc(
+        input_price,
+        prev_sum_y,
+        prev_sum_xy,
+        input_old_price,
+        opt_period,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_linearreg, output_sum_y, output_sum_xy])
+}
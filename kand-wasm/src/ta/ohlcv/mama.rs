@@ -0,0 +1,70 @@
+use kand::ta::ohlcv::mama;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for MAMA calculation.
+ * @param {number} opt_fast_limit - Upper bound of the adaptive alpha (0.01 to 0.99).
+ * @param {number} opt_slow_limit - Lower bound of the adaptive alpha (0.01 to 0.99).
+ * @returns {number} The lookback period.
+ * @throws {Error} If either limit is invalid.
+ */
+#[wasm_bindgen(js_name = mamaLookback)]
+pub fn mama_lookback_wasm(opt_fast_limit: f64, opt_slow_limit: f64) -> Result<usize, JsValue> {
+    mama::lookback(opt_fast_limit, opt_slow_limit).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the MESA Adaptive Moving Average (MAMA) line.
+ * @param {Float64Array} input_prices - Array of price values.
+ * @param {number} opt_fast_limit - Upper bound of the adaptive alpha (typically 0.5).
+ * @param {number} opt_slow_limit - Lower bound of the adaptive alpha (typically 0.05).
+ * @returns {Float64Array} An array of MAMA values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = mama)]
+pub fn mama_wasm(
+    input_prices: Vec<f64>,
+    opt_fast_limit: f64,
+    opt_slow_limit: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_mama, _) = mama_fama(&input_prices, opt_fast_limit, opt_slow_limit)?;
+    Ok(output_mama)
+}
+
+/**
+ * Calculates the Following Adaptive Moving Average (FAMA) line that accompanies MAMA.
+ * @param {Float64Array} input_prices - Array of price values.
+ * @param {number} opt_fast_limit - Upper bound of the adaptive alpha (typically 0.5).
+ * @param {number} opt_slow_limit - Lower bound of the adaptive alpha (typically 0.05).
+ * @returns {Float64Array} An array of FAMA values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = fama)]
+pub fn fama_wasm(
+    input_prices: Vec<f64>,
+    opt_fast_limit: f64,
+    opt_slow_limit: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (_, output_fama) = mama_fama(&input_prices, opt_fast_limit, opt_slow_limit)?;
+    Ok(output_fama)
+}
+
+fn mama_fama(
+    input_prices: &[f64],
+    opt_fast_limit: f64,
+    opt_slow_limit: f64,
+) -> Result<(Vec<f64>, Vec<f64>), JsValue> {
+    let mut output_mama = vec![0.0; input_prices.len()];
+    let mut output_fama = vec![0.0; input_prices.len()];
+
+    mama::mama(
+        input_prices,
+        opt_fast_limit,
+        opt_slow_limit,
+        &mut output_mama,
+        &mut output_fama,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok((output_mama, output_fama))
+}
@@ -24,20 +24,20 @@
 pub mod ecl;
 pub mod ema;
 pub mod ha;
-// pub mod ht_dcperiod;
-// pub mod ht_dcphase;
-// pub mod ht_phasor;
-// pub mod ht_sine;
-// pub mod ht_trendline;
-// pub mod ht_trendmode;
-// pub mod kama;
-// pub mod linearreg;
-// pub mod linearreg_angle;
-// pub mod linearreg_intercept;
-// pub mod linearreg_slope;
+pub mod ht_dcperiod;
+pub mod ht_dcphase;
+pub mod ht_phasor;
+pub mod ht_sine;
+pub mod ht_trendline;
+pub mod ht_trendmode;
+pub mod kama;
+pub mod linearreg;
+pub mod linearreg_angle;
+pub mod linearreg_intercept;
+pub mod linearreg_slope;
 pub mod macd;
 // pub mod macdext;
-// pub mod mama;
+pub mod mama;
 pub mod medprice;
 pub mod mfi;
 pub mod midpoint;
@@ -68,7 +68,7 @@
 pub mod trange;
 pub mod trima;
 pub mod trix;
-// pub mod tsf;
+pub mod tsf;
 pub mod typprice;
 // pub mod ultosc;
 pub mod vegas;
@@ -0,0 +1,112 @@
+use kand::ta::stats::alpha;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for the rolling Jensen's Alpha.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = alphaLookback)]
+pub fn alpha_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    alpha::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling annualised Jensen's Alpha of asset returns against benchmark returns.
+ * @param {Float64Array} input_returns - Array of asset returns.
+ * @param {Float64Array} input_benchmark - Array of benchmark returns.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_risk_free - Annual risk-free rate (e.g. 0.02 for 2%).
+ * @param {number} opt_periods_per_year - Number of return periods per year (e.g. 252 for daily bars).
+ * @returns {Float64Array} An array of Alpha values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = alpha)]
+pub fn alpha_wasm(
+    input_returns: Vec<f64>,
+    input_benchmark: Vec<f64>,
+    opt_period: usize,
+    opt_risk_free: f64,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let len = input_returns.len();
+    let mut output_alpha = vec![0.0; len];
+    let mut output_beta = vec![0.0; len];
+    let mut output_sum_returns = vec![0.0; len];
+    let mut output_sum_benchmark = vec![0.0; len];
+    let mut output_sum_benchmark_sq = vec![0.0; len];
+    let mut output_sum_cross = vec![0.0; len];
+
+    alpha::alpha(
+        &input_returns,
+        &input_benchmark,
+        opt_period,
+        opt_risk_free,
+        opt_periods_per_year,
+        &mut output_alpha,
+        &mut output_beta,
+        &mut output_sum_returns,
+        &mut output_sum_benchmark,
+        &mut output_sum_benchmark_sq,
+        &mut output_sum_cross,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_alpha)
+}
+
+/**
+ * Calculates the latest rolling Jensen's Alpha value incrementally.
+ * @param {number} input_return - The newest asset return to add.
+ * @param {number} input_benchmark - The newest benchmark return to add.
+ * @param {number} input_old_return - The oldest asset return to remove.
+ * @param {number} input_old_benchmark - The oldest benchmark return to remove.
+ * @param {number} prev_sum_returns - Previous sum of asset returns.
+ * @param {number} prev_sum_benchmark - Previous sum of benchmark returns.
+ * @param {number} prev_sum_benchmark_sq - Previous sum of squared benchmark returns.
+ * @param {number} prev_sum_cross - Previous sum of asset times benchmark returns.
+ * @param {number} opt_period - The number of returns in the rolling window.
+ * @param {number} opt_risk_free - Annual risk-free rate.
+ * @param {number} opt_periods_per_year - Number of return periods per year.
+ * @returns {Array} A flat array [alpha, beta, sumReturns, sumBenchmark, sumBenchmarkSq, sumCross].
+ * @throws {Error} If the calculation fails.
+ */
+#[wasm_bindgen(js_name = alphaInc)]
+pub fn alpha_inc_wasm(
+    input_return: f64,
+    input_benchmark: f64,
+    input_old_return: f64,
+    input_old_benchmark: f64,
+    prev_sum_returns: f64,
+    prev_sum_benchmark: f64,
+    prev_sum_benchmark_sq: f64,
+    prev_sum_cross: f64,
+    opt_period: usize,
+    opt_risk_free: f64,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (alpha, beta, sum_returns, sum_benchmark, sum_benchmark_sq, sum_cross) = alpha::alpha_inc(
+        input_return,
+        input_benchmark,
+        input_old_return,
+        input_old_benchmark,
+        prev_sum_returns,
+        prev_sum_benchmark,
+        prev_sum_benchmark_sq,
+        prev_sum_cross,
+        opt_period,
+        opt_risk_free,
+        opt_periods_per_year,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![
+        alpha,
+        beta,
+        sum_returns,
+        sum_benchmark,
+        sum_benchmark_sq,
+        sum_cross,
+    ])
+}
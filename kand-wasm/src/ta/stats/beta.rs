@@ -0,0 +1,204 @@
+use kand::ta::stats::beta;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for TA-Lib compatible Beta.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 1).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = betaLookback)]
+pub fn beta_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    beta::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the TA-Lib compatible Beta of two price series.
+ * @param {Float64Array} input_0 - First price series (e.g. the market).
+ * @param {Float64Array} input_1 - Second price series (e.g. the asset).
+ * @param {number} opt_period - The number of returns in the rolling window (TA-Lib uses 5).
+ * @returns {Float64Array} An array of Beta values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = beta)]
+pub fn beta_wasm(
+    input_0: Vec<f64>,
+    input_1: Vec<f64>,
+    opt_period: usize,
+) -> Result<Vec<f64>, JsValue> {
+    let len = input_0.len();
+    let mut output_beta = vec![0.0; len];
+    let mut output_sum_x = vec![0.0; len];
+    let mut output_sum_y = vec![0.0; len];
+    let mut output_sum_xx = vec![0.0; len];
+    let mut output_sum_xy = vec![0.0; len];
+
+    beta::beta(
+        &input_0,
+        &input_1,
+        opt_period,
+        &mut output_beta,
+        &mut output_sum_x,
+        &mut output_sum_y,
+        &mut output_sum_xx,
+        &mut output_sum_xy,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_beta)
+}
+
+/**
+ * Calculates the latest TA-Lib compatible Beta value incrementally.
+ * @param {number} input_0 - The newest price of series 0.
+ * @param {number} input_1 - The newest price of series 1.
+ * @param {number} input_prev_0 - The price of series 0 one bar back.
+ * @param {number} input_prev_1 - The price of series 1 one bar back.
+ * @param {number} input_old_0 - The price of series 0 `opt_period` bars back.
+ * @param {number} input_old_1 - The price of series 1 `opt_period` bars back.
+ * @param {number} input_old_prev_0 - The price of series 0 `opt_period + 1` bars back.
+ * @param {number} input_old_prev_1 - The price of series 1 `opt_period + 1` bars back.
+ * @param {number} prev_sum_x - Previous sum of series 0 returns.
+ * @param {number} prev_sum_y - Previous sum of series 1 returns.
+ * @param {number} prev_sum_xx - Previous sum of squared series 0 returns.
+ * @param {number} prev_sum_xy - Previous sum of return products.
+ * @param {number} opt_period - The number of returns in the rolling window.
+ * @returns {Array} A flat array [beta, sumX, sumY, sumXX, sumXY].
+ * @throws {Error} If the calculation fails.
+ */
+#[wasm_bindgen(js_name = betaInc)]
+pub fn beta_inc_wasm(
+    input_0: f64,
+    input_1: f64,
+    input_prev_0: f64,
+    input_prev_1: f64,
+    input_old_0: f64,
+    input_old_1: f64,
+    input_old_prev_0: f64,
+    input_old_prev_1: f64,
+    prev_sum_x: f64,
+    prev_sum_y: f64,
+    prev_sum_xx: f64,
+    prev_sum_xy: f64,
+    opt_period: usize,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_beta, output_sum_x, output_sum_y, output_sum_xx, output_sum_xy) = beta::beta_inc(
+        input_0,
+        input_1,
+        input_prev_0,
+        input_prev_1,
+        input_old_0,
+        input_old_1,
+        input_old_prev_0,
+        input_old_prev_1,
+        prev_sum_x,
+        prev_sum_y,
+        prev_sum_xx,
+        prev_sum_xy,
+        opt_period,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![
+        output_beta,
+        output_sum_x,
+        output_sum_y,
+        output_sum_xx,
+        output_sum_xy,
+    ])
+}
+
+/**
+ * Returns the lookback period for the rolling CAPM Beta.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = capmBetaLookback)]
+pub fn capm_beta_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    beta::capm_lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling CAPM Beta of asset returns against benchmark returns.
+ * @param {Float64Array} input_returns - Array of asset returns.
+ * @param {Float64Array} input_benchmark - Array of benchmark returns.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @returns {Float64Array} An array of Beta values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = capmBeta)]
+pub fn capm_beta_wasm(
+    input_returns: Vec<f64>,
+    input_benchmark: Vec<f64>,
+    opt_period: usize,
+) -> Result<Vec<f64>, JsValue> {
+    let len = input_returns.len();
+    let mut output_beta = vec![0.0; len];
+    let mut output_sum_returns = vec![0.0; len];
+    let mut output_sum_benchmark = vec![0.0; len];
+    let mut output_sum_benchmark_sq = vec![0.0; len];
+    let mut output_sum_cross = vec![0.0; len];
+
+    beta::capm_beta(
+        &input_returns,
+        &input_benchmark,
+        opt_period,
+        &mut output_beta,
+        &mut output_sum_returns,
+        &mut output_sum_benchmark,
+        &mut output_sum_benchmark_sq,
+        &mut output_sum_cross,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_beta)
+}
+
+/**
+ * Calculates the latest rolling CAPM Beta value incrementally.
+ * @param {number} input_return - The newest asset return to add.
+ * @param {number} input_benchmark - The newest benchmark return to add.
+ * @param {number} input_old_return - The oldest asset return to remove.
+ * @param {number} input_old_benchmark - The oldest benchmark return to remove.
+ * @param {number} prev_sum_returns - Previous sum of asset returns.
+ * @param {number} prev_sum_benchmark - Previous sum of benchmark returns.
+ * @param {number} prev_sum_benchmark_sq - Previous sum of squared benchmark returns.
+ * @param {number} prev_sum_cross - Previous sum of asset times benchmark returns.
+ * @param {number} opt_period - The number of returns in the rolling window.
+ * @returns {Array} A flat array [beta, sumReturns, sumBenchmark, sumBenchmarkSq, sumCross].
+ * @throws {Error} If the calculation fails.
+ */
+#[wasm_bindgen(js_name = capmBetaInc)]
+pub fn capm_beta_inc_wasm(
+    input_return: f64,
+    input_benchmark: f64,
+    input_old_return: f64,
+    input_old_benchmark: f64,
+    prev_sum_returns: f64,
+    prev_sum_benchmark: f64,
+    prev_sum_benchmark_sq: f64,
+    prev_sum_cross: f64,
+    opt_period: usize,
+) -> Result<Vec<f64>, JsValue> {
+    let (beta, sum_returns, sum_benchmark, sum_benchmark_sq, sum_cross) = beta::capm_beta_inc(
+        input_return,
+        input_benchmark,
+        input_old_return,
+        input_old_benchmark,
+        prev_sum_returns,
+        prev_sum_benchmark,
+        prev_sum_benchmark_sq,
+        prev_sum_cross,
+        opt_period,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![
+        beta,
+        sum_returns,
+        sum_benchmark,
+        sum_benchmark_sq,
+        sum_cross,
+    ])
+}
@@ -0,0 +1,56 @@
+use kand::ta::stats::calmar;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for the rolling Calmar ratio.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = calmarLookback)]
+pub fn calmar_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    calmar::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling Calmar ratio for a series of periodic returns.
+ * @param {Float64Array} input_returns - Array of periodic returns (e.g. 0.01 for +1%).
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_periods_per_year - Number of return periods per year (e.g. 252 for daily bars).
+ * @returns {Float64Array} An array of Calmar ratio values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = calmar)]
+pub fn calmar_wasm(
+    input_returns: Vec<f64>,
+    opt_period: usize,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_calmar = vec![0.0; input_returns.len()];
+
+    calmar::calmar(
+        &input_returns,
+        opt_period,
+        opt_periods_per_year,
+        &mut output_calmar,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_calmar)
+}
+
+/**
+ * Calculates the Calmar ratio of an entire return series.
+ * @param {Float64Array} input_returns - Array of periodic returns.
+ * @param {number} opt_periods_per_year - Number of return periods per year.
+ * @returns {number} The Calmar ratio, or NaN when it is undefined.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = calmarRatio)]
+pub fn calmar_ratio_wasm(
+    input_returns: Vec<f64>,
+    opt_periods_per_year: f64,
+) -> Result<f64, JsValue> {
+    calmar::calmar_ratio(&input_returns, opt_periods_per_year)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
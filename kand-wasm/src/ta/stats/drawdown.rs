@@ -0,0 +1,79 @@
+use kand::{TAInt, ta::stats::drawdown};
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for drawdown analytics.
+ * @returns {number} The lookback period (always 0).
+ */
+#[wasm_bindgen(js_name = drawdownLookback)]
+pub fn drawdown_lookback_wasm() -> Result<usize, JsValue> {
+    drawdown::lookback().map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the drawdown percentage of an equity curve from its running peak.
+ * @param {Float64Array} input_equity - Array of equity values.
+ * @returns {Float64Array} An array of drawdown percentages with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = drawdown)]
+pub fn drawdown_wasm(input_equity: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let (output_drawdown, _, _) = drawdown_all(&input_equity)?;
+    Ok(output_drawdown)
+}
+
+/**
+ * Calculates the number of bars an equity curve has spent below its last peak.
+ * @param {Float64Array} input_equity - Array of equity values.
+ * @returns {BigInt64Array} An array of drawdown durations with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = drawdownDuration)]
+pub fn drawdown_duration_wasm(input_equity: Vec<f64>) -> Result<Vec<TAInt>, JsValue> {
+    let (_, output_duration, _) = drawdown_all(&input_equity)?;
+    Ok(output_duration)
+}
+
+/**
+ * Calculates the bars from trough to recovery, reported on each bar that regains the peak.
+ * @param {Float64Array} input_equity - Array of equity values.
+ * @returns {BigInt64Array} An array of recovery times (0 on other bars) with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = drawdownRecovery)]
+pub fn drawdown_recovery_wasm(input_equity: Vec<f64>) -> Result<Vec<TAInt>, JsValue> {
+    let (_, _, output_recovery) = drawdown_all(&input_equity)?;
+    Ok(output_recovery)
+}
+
+/**
+ * Calculates the maximum drawdown percentage of an entire equity curve.
+ * @param {Float64Array} input_equity - Array of equity values.
+ * @returns {number} The deepest drawdown percentage.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = maxDrawdown)]
+pub fn max_drawdown_wasm(input_equity: Vec<f64>) -> Result<f64, JsValue> {
+    drawdown::max_drawdown(&input_equity).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+fn drawdown_all(input_equity: &[f64]) -> Result<(Vec<f64>, Vec<TAInt>, Vec<TAInt>), JsValue> {
+    let len = input_equity.len();
+    let mut output_drawdown = vec![0.0; len];
+    let mut output_max_drawdown = vec![0.0; len];
+    let mut output_duration = vec![0; len];
+    let mut output_max_duration = vec![0; len];
+    let mut output_recovery = vec![0; len];
+
+    drawdown::drawdown(
+        input_equity,
+        &mut output_drawdown,
+        &mut output_max_drawdown,
+        &mut output_duration,
+        &mut output_max_duration,
+        &mut output_recovery,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok((output_drawdown, output_duration, output_recovery))
+}
@@ -0,0 +1,25 @@
+use kand::ta::stats::fv;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Calculates the future value of an investment, as the spreadsheet FV function does.
+ * Cash paid out is negative and cash received is positive.
+ * @param {number} input_rate - Interest rate per period.
+ * @param {number} input_nper - Number of payment periods.
+ * @param {number} input_pmt - Payment made each period.
+ * @param {number} input_pv - Present value.
+ * @param {boolean} opt_begin - Whether payments are due at the beginning of each period.
+ * @returns {number} The future value.
+ * @throws {Error} If the parameters are invalid.
+ */
+#[wasm_bindgen(js_name = fv)]
+pub fn fv_wasm(
+    input_rate: f64,
+    input_nper: f64,
+    input_pmt: f64,
+    input_pv: f64,
+    opt_begin: bool,
+) -> Result<f64, JsValue> {
+    fv::fv(input_rate, input_nper, input_pmt, input_pv, opt_begin)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
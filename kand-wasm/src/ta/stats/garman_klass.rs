@@ -0,0 +1,100 @@
+use kand::ta::stats::garman_klass;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for rolling Garman-Klass volatility.
+ * @param {number} opt_period - Number of bars in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = garmanKlassLookback)]
+pub fn garman_klass_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    garman_klass::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates rolling Garman-Klass volatility.
+ * @param {Float64Array} input_open - Array of opening prices.
+ * @param {Float64Array} input_high - Array of high prices.
+ * @param {Float64Array} input_low - Array of low prices.
+ * @param {Float64Array} input_close - Array of closing prices.
+ * @param {number} opt_period - Number of bars in the rolling window (must be >= 2).
+ * @param {number} opt_periods_per_year - Number of bars per year (e.g. 252 for daily bars).
+ * @returns {Float64Array} An array of Garman-Klass volatility values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = garmanKlass)]
+pub fn garman_klass_wasm(
+    input_open: Vec<f64>,
+    input_high: Vec<f64>,
+    input_low: Vec<f64>,
+    input_close: Vec<f64>,
+    opt_period: usize,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_garman_klass = vec![0.0; input_open.len()];
+    let mut output_sum = vec![0.0; input_open.len()];
+
+    garman_klass::garman_klass(
+        &input_open,
+        &input_high,
+        &input_low,
+        &input_close,
+        opt_period,
+        opt_periods_per_year,
+        &mut output_garman_klass,
+        &mut output_sum,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_garman_klass)
+}
+
+/**
+ * Calculates a single rolling Garman-Klass volatility value incrementally.
+ * @param {number} input_open - Opening price of the newest bar.
+ * @param {number} input_high - High price of the newest bar.
+ * @param {number} input_low - Low price of the newest bar.
+ * @param {number} input_close - Closing price of the newest bar.
+ * @param {number} input_old_open - Opening price of the bar leaving the window.
+ * @param {number} input_old_high - High price of the bar leaving the window.
+ * @param {number} input_old_low - Low price of the bar leaving the window.
+ * @param {number} input_old_close - Closing price of the bar leaving the window.
+ * @param {number} prev_sum - Previous sum of Garman-Klass terms.
+ * @param {number} opt_period - Number of bars in the rolling window (must be >= 2).
+ * @param {number} opt_periods_per_year - Number of bars per year.
+ * @returns {Float64Array} A two-element array `[garman_klass, sum]`; pass the sum back on the next call.
+ * @throws {Error} If the parameters are invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = garmanKlassInc)]
+#[allow(clippy::too_many_arguments)]
+pub fn garman_klass_inc_wasm(
+    input_open: f64,
+    input_high: f64,
+    input_low: f64,
+    input_close: f64,
+    input_old_open: f64,
+    input_old_high: f64,
+    input_old_low: f64,
+    input_old_close: f64,
+    prev_sum: f64,
+    opt_period: usize,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_garman_klass, output_sum) = garman_klass::garman_klass_inc(
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        input_old_open,
+        input_old_high,
+        input_old_low,
+        input_old_close,
+        prev_sum,
+        opt_period,
+        opt_periods_per_year,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_garman_klass, output_sum])
+}
@@ -0,0 +1,84 @@
+use kand::ta::stats::hv;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for rolling close-to-close historical volatility.
+ * @param {number} opt_period - Number of log returns in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = hvLookback)]
+pub fn hv_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    hv::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates rolling close-to-close historical volatility.
+ * @param {Float64Array} input_prices - Array of prices.
+ * @param {number} opt_period - Number of log returns in the rolling window (must be >= 2).
+ * @param {number} opt_periods_per_year - Number of bars per year (e.g. 252 for daily bars).
+ * @returns {Float64Array} An array of close-to-close historical volatility values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = hv)]
+pub fn hv_wasm(
+    input_prices: Vec<f64>,
+    opt_period: usize,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_hv = vec![0.0; input_prices.len()];
+    let mut output_mean = vec![0.0; input_prices.len()];
+    let mut output_m2 = vec![0.0; input_prices.len()];
+
+    hv::hv(
+        &input_prices,
+        opt_period,
+        opt_periods_per_year,
+        &mut output_hv,
+        &mut output_mean,
+        &mut output_m2,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_hv)
+}
+
+/**
+ * Calculates a single rolling close-to-close historical volatility value incrementally.
+ * @param {number} input_price - Newest price.
+ * @param {number} input_prev_price - Price before the newest one.
+ * @param {number} input_old_price - Price that ends the log return leaving the window.
+ * @param {number} input_old_prev_price - Price that starts the log return leaving the window.
+ * @param {number} prev_mean - Previous mean of log returns.
+ * @param {number} prev_m2 - Previous sum of squared deviations of log returns.
+ * @param {number} opt_period - Number of log returns in the rolling window (must be >= 2).
+ * @param {number} opt_periods_per_year - Number of bars per year.
+ * @returns {Float64Array} A three-element array `[hv, mean, m2]`; pass the state back on the next call.
+ * @throws {Error} If the parameters are invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = hvInc)]
+#[allow(clippy::too_many_arguments)]
+pub fn hv_inc_wasm(
+    input_price: f64,
+    input_prev_price: f64,
+    input_old_price: f64,
+    input_old_prev_price: f64,
+    prev_mean: f64,
+    prev_m2: f64,
+    opt_period: usize,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_hv, output_mean, output_m2) = hv::hv_inc(
+        input_price,
+        input_prev_price,
+        input_old_price,
+        input_old_prev_price,
+        prev_mean,
+        prev_m2,
+        opt_period,
+        opt_periods_per_year,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_hv, output_mean, output_m2])
+}
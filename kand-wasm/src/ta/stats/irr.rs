@@ -0,0 +1,33 @@
+use kand::{TAInt, ta::stats::irr};
+use wasm_bindgen::prelude::*;
+
+/**
+ * Calculates the internal rate of return of periodic cash flows, as the spreadsheet IRR
+ * function does.
+ * @param {Float64Array} input_values - Cash flows, the first one made today.
+ * @param {number} opt_guess - Starting rate for the solver (0.1 in spreadsheets).
+ * @returns {number} The rate of return per period.
+ * @throws {Error} If the input is invalid or the solver does not converge.
+ */
+#[wasm_bindgen(js_name = irr)]
+pub fn irr_wasm(input_values: Vec<f64>, opt_guess: f64) -> Result<f64, JsValue> {
+    irr::irr(&input_values, opt_guess).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the annual internal rate of return of dated cash flows, as the spreadsheet XIRR
+ * function does.
+ * @param {Float64Array} input_values - Cash flows.
+ * @param {BigInt64Array} input_dates - Day numbers of the cash flows, none earlier than the first.
+ * @param {number} opt_guess - Starting rate for the solver (0.1 in spreadsheets).
+ * @returns {number} The annual rate of return.
+ * @throws {Error} If the input is invalid or the solver does not converge.
+ */
+#[wasm_bindgen(js_name = xirr)]
+pub fn xirr_wasm(
+    input_values: Vec<f64>,
+    input_dates: Vec<TAInt>,
+    opt_guess: f64,
+) -> Result<f64, JsValue> {
+    irr::xirr(&input_values, &input_dates, opt_guess).map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,48 @@
+use kand::ta::stats::kelly;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for the rolling Kelly fraction.
+ * @param {number} opt_period - The number of trades in the rolling window (must be >= 1).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = kellyLookback)]
+pub fn kelly_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    kelly::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling Kelly fraction of a series of trade returns.
+ * @param {Float64Array} input_trades - Array of per-trade returns.
+ * @param {number} opt_period - The number of trades in the rolling window (must be >= 1).
+ * @param {number} opt_fraction - Scale applied to the full Kelly fraction (1 for full, 0.5 for half Kelly).
+ * @returns {Float64Array} An array of Kelly fractions with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = kelly)]
+pub fn kelly_wasm(
+    input_trades: Vec<f64>,
+    opt_period: usize,
+    opt_fraction: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_kelly = vec![0.0; input_trades.len()];
+
+    kelly::kelly(&input_trades, opt_period, opt_fraction, &mut output_kelly)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_kelly)
+}
+
+/**
+ * Calculates the Kelly fraction of an entire list of trade returns.
+ * @param {Float64Array} input_trades - Array of per-trade returns.
+ * @param {number} opt_fraction - Scale applied to the full Kelly fraction.
+ * @returns {number} The Kelly fraction, or NaN when there are losing but no winning trades.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = kellyCriterion)]
+pub fn kelly_criterion_wasm(input_trades: Vec<f64>, opt_fraction: f64) -> Result<f64, JsValue> {
+    kelly::kelly_criterion(&input_trades, opt_fraction)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,27 @@
+pub mod alpha;
+pub mod beta;
+pub mod calmar;
+pub mod drawdown;
+pub mod fv;
+pub mod garman_klass;
+pub mod hv;
+pub mod irr;
+pub mod kelly;
+pub mod kurt;
+pub mod nper;
+pub mod npv;
+pub mod parkinson;
+pub mod percentrank;
+pub mod pmt;
+pub mod pv;
+pub mod quantile;
+pub mod rate;
+pub mod ret;
+pub mod risk;
+pub mod rogers_satchell;
+pub mod sharpe;
+pub mod skew;
+pub mod sortino;
+pub mod winrate;
+pub mod yang_zhang;
+pub mod zscore;
@@ -0,0 +1,25 @@
+use kand::ta::stats::nper;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Calculates the number of periods of an investment, as the spreadsheet NPER function does.
+ * Cash paid out is negative and cash received is positive.
+ * @param {number} input_rate - Interest rate per period.
+ * @param {number} input_pmt - Payment made each period.
+ * @param {number} input_pv - Present value.
+ * @param {number} input_fv - Future value.
+ * @param {boolean} opt_begin - Whether payments are due at the beginning of each period.
+ * @returns {number} The number of periods.
+ * @throws {Error} If the parameters are invalid or no number of periods settles the cash flows.
+ */
+#[wasm_bindgen(js_name = nper)]
+pub fn nper_wasm(
+    input_rate: f64,
+    input_pmt: f64,
+    input_pv: f64,
+    input_fv: f64,
+    opt_begin: bool,
+) -> Result<f64, JsValue> {
+    nper::nper(input_rate, input_pmt, input_pv, input_fv, opt_begin)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
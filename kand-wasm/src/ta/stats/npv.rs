@@ -0,0 +1,15 @@
+use kand::ta::stats::npv;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Calculates the net present value of a list of cash flows, as the spreadsheet NPV function
+ * does. The first cash flow is discounted by one period.
+ * @param {number} input_rate - Discount rate per period.
+ * @param {Float64Array} input_values - Cash flows at the end of each period.
+ * @returns {number} The net present value.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = npv)]
+pub fn npv_wasm(input_rate: f64, input_values: Vec<f64>) -> Result<f64, JsValue> {
+    npv::npv(input_rate, &input_values).map_err(|e| JsValue::from_str(&e.to_string()))
+}
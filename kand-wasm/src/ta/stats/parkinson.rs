@@ -0,0 +1,81 @@
+use kand::ta::stats::parkinson;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for rolling Parkinson volatility.
+ * @param {number} opt_period - Number of bars in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = parkinsonLookback)]
+pub fn parkinson_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    parkinson::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates rolling Parkinson volatility.
+ * @param {Float64Array} input_high - Array of high prices.
+ * @param {Float64Array} input_low - Array of low prices.
+ * @param {number} opt_period - Number of bars in the rolling window (must be >= 2).
+ * @param {number} opt_periods_per_year - Number of bars per year (e.g. 252 for daily bars).
+ * @returns {Float64Array} An array of Parkinson volatility values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = parkinson)]
+pub fn parkinson_wasm(
+    input_high: Vec<f64>,
+    input_low: Vec<f64>,
+    opt_period: usize,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_parkinson = vec![0.0; input_high.len()];
+    let mut output_sum = vec![0.0; input_high.len()];
+
+    parkinson::parkinson(
+        &input_high,
+        &input_low,
+        opt_period,
+        opt_periods_per_year,
+        &mut output_parkinson,
+        &mut output_sum,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_parkinson)
+}
+
+/**
+ * Calculates a single rolling Parkinson volatility value incrementally.
+ * @param {number} input_high - High price of the newest bar.
+ * @param {number} input_low - Low price of the newest bar.
+ * @param {number} input_old_high - High price of the bar leaving the window.
+ * @param {number} input_old_low - Low price of the bar leaving the window.
+ * @param {number} prev_sum - Previous sum of squared log ranges.
+ * @param {number} opt_period - Number of bars in the rolling window (must be >= 2).
+ * @param {number} opt_periods_per_year - Number of bars per year.
+ * @returns {Float64Array} A two-element array `[parkinson, sum]`; pass the sum back on the next call.
+ * @throws {Error} If the parameters are invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = parkinsonInc)]
+pub fn parkinson_inc_wasm(
+    input_high: f64,
+    input_low: f64,
+    input_old_high: f64,
+    input_old_low: f64,
+    prev_sum: f64,
+    opt_period: usize,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_parkinson, output_sum) = parkinson::parkinson_inc(
+        input_high,
+        input_low,
+        input_old_high,
+        input_old_low,
+        prev_sum,
+        opt_period,
+        opt_periods_per_year,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_parkinson, output_sum])
+}
@@ -0,0 +1,30 @@
+use kand::ta::stats::percentrank;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for the rolling percent rank.
+ * @param {number} opt_period - The rolling window size (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = percentrankLookback)]
+pub fn percentrank_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    percentrank::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling percent rank (0 to 100) of each price within its window.
+ * @param {Float64Array} input_prices - Array of prices.
+ * @param {number} opt_period - The rolling window size (must be >= 2).
+ * @returns {Float64Array} An array of percent rank values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = percentrank)]
+pub fn percentrank_wasm(input_prices: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let mut output_percentrank = vec![0.0; input_prices.len()];
+
+    percentrank::percentrank(&input_prices, opt_period, &mut output_percentrank)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_percentrank)
+}
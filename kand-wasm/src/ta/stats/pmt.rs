@@ -0,0 +1,25 @@
+use kand::ta::stats::pmt;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Calculates the periodic payment of a loan or annuity, as the spreadsheet PMT function does.
+ * Cash paid out is negative and cash received is positive.
+ * @param {number} input_rate - Interest rate per period.
+ * @param {number} input_nper - Number of payment periods.
+ * @param {number} input_pv - Present value.
+ * @param {number} input_fv - Future value.
+ * @param {boolean} opt_begin - Whether payments are due at the beginning of each period.
+ * @returns {number} The payment per period.
+ * @throws {Error} If the parameters are invalid.
+ */
+#[wasm_bindgen(js_name = pmt)]
+pub fn pmt_wasm(
+    input_rate: f64,
+    input_nper: f64,
+    input_pv: f64,
+    input_fv: f64,
+    opt_begin: bool,
+) -> Result<f64, JsValue> {
+    pmt::pmt(input_rate, input_nper, input_pv, input_fv, opt_begin)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,25 @@
+use kand::ta::stats::pv;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Calculates the present value of an investment, as the spreadsheet PV function does.
+ * Cash paid out is negative and cash received is positive.
+ * @param {number} input_rate - Interest rate per period.
+ * @param {number} input_nper - Number of payment periods.
+ * @param {number} input_pmt - Payment made each period.
+ * @param {number} input_fv - Future value.
+ * @param {boolean} opt_begin - Whether payments are due at the beginning of each period.
+ * @returns {number} The present value.
+ * @throws {Error} If the parameters are invalid.
+ */
+#[wasm_bindgen(js_name = pv)]
+pub fn pv_wasm(
+    input_rate: f64,
+    input_nper: f64,
+    input_pmt: f64,
+    input_fv: f64,
+    opt_begin: bool,
+) -> Result<f64, JsValue> {
+    pv::pv(input_rate, input_nper, input_pmt, input_fv, opt_begin)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
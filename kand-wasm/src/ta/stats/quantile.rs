@@ -0,0 +1,57 @@
+use kand::ta::stats::quantile;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for the rolling quantile and median.
+ * @param {number} opt_period - The rolling window size (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = quantileLookback)]
+pub fn quantile_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    quantile::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling quantile of each window, interpolated linearly like NumPy and pandas.
+ * @param {Float64Array} input_prices - Array of prices.
+ * @param {number} opt_period - The rolling window size (must be >= 2).
+ * @param {number} opt_quantile - The quantile to calculate (must be between 0 and 1).
+ * @returns {Float64Array} An array of quantile values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = quantile)]
+pub fn quantile_wasm(
+    input_prices: Vec<f64>,
+    opt_period: usize,
+    opt_quantile: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_quantile = vec![0.0; input_prices.len()];
+
+    quantile::quantile(
+        &input_prices,
+        opt_period,
+        opt_quantile,
+        &mut output_quantile,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_quantile)
+}
+
+/**
+ * Calculates the rolling median of each window.
+ * @param {Float64Array} input_prices - Array of prices.
+ * @param {number} opt_period - The rolling window size (must be >= 2).
+ * @returns {Float64Array} An array of median values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = median)]
+pub fn median_wasm(input_prices: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let mut output_median = vec![0.0; input_prices.len()];
+
+    quantile::median(&input_prices, opt_period, &mut output_median)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_median)
+}
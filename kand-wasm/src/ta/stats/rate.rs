@@ -0,0 +1,28 @@
+use kand::ta::stats::rate;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Calculates the interest rate per period of an annuity, as the spreadsheet RATE function does.
+ * @param {number} input_nper - Number of payment periods.
+ * @param {number} input_pmt - Payment made each period.
+ * @param {number} input_pv - Present value.
+ * @param {number} input_fv - Future value.
+ * @param {boolean} opt_begin - Whether payments are due at the beginning of each period.
+ * @param {number} opt_guess - Starting rate for the solver (0.1 in spreadsheets).
+ * @returns {number} The rate per period.
+ * @throws {Error} If the parameters are invalid or the solver does not converge.
+ */
+#[wasm_bindgen(js_name = rate)]
+pub fn rate_wasm(
+    input_nper: f64,
+    input_pmt: f64,
+    input_pv: f64,
+    input_fv: f64,
+    opt_begin: bool,
+    opt_guess: f64,
+) -> Result<f64, JsValue> {
+    rate::rate(
+        input_nper, input_pmt, input_pv, input_fv, opt_begin, opt_guess,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,98 @@
+use kand::ta::stats::ret;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for rolling-window returns.
+ * @param {number} opt_period - The number of bars each return spans (must be >= 1).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = rollingReturnsLookback)]
+pub fn rolling_returns_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    ret::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates simple returns of a price series.
+ * @param {Float64Array} input_price - Array of prices.
+ * @returns {Float64Array} An array of simple returns with the first element NaN.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = simpleReturns)]
+pub fn simple_returns_wasm(input_price: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let mut output_returns = vec![0.0; input_price.len()];
+
+    ret::simple_returns(&input_price, &mut output_returns)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_returns)
+}
+
+/**
+ * Calculates log returns of a price series.
+ * @param {Float64Array} input_price - Array of prices.
+ * @returns {Float64Array} An array of log returns with the first element NaN.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = logReturns)]
+pub fn log_returns_wasm(input_price: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let mut output_returns = vec![0.0; input_price.len()];
+
+    ret::log_returns(&input_price, &mut output_returns)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_returns)
+}
+
+/**
+ * Calculates cumulative returns of a price series relative to its first price.
+ * @param {Float64Array} input_price - Array of prices.
+ * @returns {Float64Array} An array of cumulative returns with the first element 0.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = cumulativeReturns)]
+pub fn cumulative_returns_wasm(input_price: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let mut output_returns = vec![0.0; input_price.len()];
+
+    ret::cumulative_returns(&input_price, &mut output_returns)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_returns)
+}
+
+/**
+ * Calculates returns over a rolling window of bars.
+ * @param {Float64Array} input_price - Array of prices.
+ * @param {number} opt_period - The number of bars each return spans (must be >= 1).
+ * @returns {Float64Array} An array of rolling returns with the first `opt_period` elements NaN.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = rollingReturns)]
+pub fn rolling_returns_wasm(input_price: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let mut output_returns = vec![0.0; input_price.len()];
+
+    ret::rolling_returns(&input_price, opt_period, &mut output_returns)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_returns)
+}
+
+/**
+ * Calculates excess returns over a risk-free series of the same periodicity.
+ * @param {Float64Array} input_returns - Array of periodic returns.
+ * @param {Float64Array} input_risk_free - Array of risk-free returns for the same periods.
+ * @returns {Float64Array} An array of excess returns.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = excessReturns)]
+pub fn excess_returns_wasm(
+    input_returns: Vec<f64>,
+    input_risk_free: Vec<f64>,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_excess = vec![0.0; input_returns.len()];
+
+    ret::excess_returns(&input_returns, &input_risk_free, &mut output_excess)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_excess)
+}
@@ -0,0 +1,266 @@
+use kand::ta::stats::risk;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for the rolling Value-at-Risk and Expected Shortfall.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = riskLookback)]
+pub fn risk_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    risk::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling historical Value-at-Risk, reported as a positive loss.
+ * @param {Float64Array} input_returns - Array of periodic returns.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_confidence - The confidence level, strictly between 0 and 1.
+ * @returns {Float64Array} An array of VaR values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = historicalVar)]
+pub fn historical_var_wasm(
+    input_returns: Vec<f64>,
+    opt_period: usize,
+    opt_confidence: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_var = vec![0.0; input_returns.len()];
+
+    risk::historical_var(&input_returns, opt_period, opt_confidence, &mut output_var)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_var)
+}
+
+/**
+ * Calculates the rolling historical Expected Shortfall (CVaR), reported as a positive loss.
+ * @param {Float64Array} input_returns - Array of periodic returns.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_confidence - The confidence level, strictly between 0 and 1.
+ * @returns {Float64Array} An array of Expected Shortfall values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = historicalEs)]
+pub fn historical_es_wasm(
+    input_returns: Vec<f64>,
+    opt_period: usize,
+    opt_confidence: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_es = vec![0.0; input_returns.len()];
+
+    risk::historical_es(&input_returns, opt_period, opt_confidence, &mut output_es)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_es)
+}
+
+/**
+ * Calculates the rolling Gaussian Value-at-Risk, reported as a positive loss.
+ * @param {Float64Array} input_returns - Array of periodic returns.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_confidence - The confidence level, strictly between 0 and 1.
+ * @returns {Float64Array} An array of VaR values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = gaussianVar)]
+pub fn gaussian_var_wasm(
+    input_returns: Vec<f64>,
+    opt_period: usize,
+    opt_confidence: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_var = vec![0.0; input_returns.len()];
+    let mut output_mean = vec![0.0; input_returns.len()];
+    let mut output_m2 = vec![0.0; input_returns.len()];
+
+    risk::gaussian_var(
+        &input_returns,
+        opt_period,
+        opt_confidence,
+        &mut output_var,
+        &mut output_mean,
+        &mut output_m2,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_var)
+}
+
+/**
+ * Calculates a single rolling Gaussian Value-at-Risk value incrementally.
+ * @param {number} input_return - The newest return to include.
+ * @param {number} prev_mean - The previous mean of the window.
+ * @param {number} prev_m2 - The previous sum of squared deviations from the mean.
+ * @param {number} input_old_return - The oldest return to remove from the window.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_confidence - The confidence level, strictly between 0 and 1.
+ * @returns {Float64Array} A three-element array `[var, mean, m2]`; pass the moments back on the next call.
+ * @throws {Error} If the parameters are invalid.
+ */
+#[wasm_bindgen(js_name = gaussianVarInc)]
+pub fn gaussian_var_inc_wasm(
+    input_return: f64,
+    prev_mean: f64,
+    prev_m2: f64,
+    input_old_return: f64,
+    opt_period: usize,
+    opt_confidence: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_var, output_mean, output_m2) = risk::gaussian_var_inc(
+        input_return,
+        prev_mean,
+        prev_m2,
+        input_old_return,
+        opt_period,
+        opt_confidence,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_var, output_mean, output_m2])
+}
+
+/**
+ * Calculates the rolling Gaussian Expected Shortfall, reported as a positive loss.
+ * @param {Float64Array} input_returns - Array of periodic returns.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_confidence - The confidence level, strictly between 0 and 1.
+ * @returns {Float64Array} An array of Expected Shortfall values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = gaussianEs)]
+pub fn gaussian_es_wasm(
+    input_returns: Vec<f64>,
+    opt_period: usize,
+    opt_confidence: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_es = vec![0.0; input_returns.len()];
+    let mut output_mean = vec![0.0; input_returns.len()];
+    let mut output_m2 = vec![0.0; input_returns.len()];
+
+    risk::gaussian_es(
+        &input_returns,
+        opt_period,
+        opt_confidence,
+        &mut output_es,
+        &mut output_mean,
+        &mut output_m2,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_es)
+}
+
+/**
+ * Calculates a single rolling Gaussian Expected Shortfall value incrementally.
+ * @param {number} input_return - The newest return to include.
+ * @param {number} prev_mean - The previous mean of the window.
+ * @param {number} prev_m2 - The previous sum of squared deviations from the mean.
+ * @param {number} input_old_return - The oldest return to remove from the window.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_confidence - The confidence level, strictly between 0 and 1.
+ * @returns {Float64Array} A three-element array `[es, mean, m2]`; pass the moments back on the next call.
+ * @throws {Error} If the parameters are invalid.
+ */
+#[wasm_bindgen(js_name = gaussianEsInc)]
+pub fn gaussian_es_inc_wasm(
+    input_return: f64,
+    prev_mean: f64,
+    prev_m2: f64,
+    input_old_return: f64,
+    opt_period: usize,
+    opt_confidence: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_es, output_mean, output_m2) = risk::gaussian_es_inc(
+        input_return,
+        prev_mean,
+        prev_m2,
+        input_old_return,
+        opt_period,
+        opt_confidence,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_es, output_mean, output_m2])
+}
+
+/**
+ * Calculates the rolling Cornish-Fisher (modified) Value-at-Risk, reported as a positive loss.
+ * @param {Float64Array} input_returns - Array of periodic returns.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 4).
+ * @param {number} opt_confidence - The confidence level, strictly between 0 and 1.
+ * @returns {Float64Array} An array of VaR values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = cornishFisherVar)]
+pub fn cornish_fisher_var_wasm(
+    input_returns: Vec<f64>,
+    opt_period: usize,
+    opt_confidence: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_var = vec![0.0; input_returns.len()];
+    let mut output_mean = vec![0.0; input_returns.len()];
+    let mut output_m2 = vec![0.0; input_returns.len()];
+    let mut output_m3 = vec![0.0; input_returns.len()];
+    let mut output_m4 = vec![0.0; input_returns.len()];
+
+    risk::cornish_fisher_var(
+        &input_returns,
+        opt_period,
+        opt_confidence,
+        &mut output_var,
+        &mut output_mean,
+        &mut output_m2,
+        &mut output_m3,
+        &mut output_m4,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_var)
+}
+
+/**
+ * Calculates a single rolling Cornish-Fisher Value-at-Risk value incrementally.
+ * @param {number} input_return - The newest return to include.
+ * @param {number} prev_mean - The previous mean of the window.
+ * @param {number} prev_m2 - The previous sum of squared deviations from the mean.
+ * @param {number} prev_m3 - The previous sum of cubed deviations from the mean.
+ * @param {number} prev_m4 - The previous sum of fourth-power deviations from the mean.
+ * @param {number} input_old_return - The oldest return to remove from the window.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 4).
+ * @param {number} opt_confidence - The confidence level, strictly between 0 and 1.
+ * @returns {Float64Array} A five-element array `[var, mean, m2, m3, m4]`; pass the moments back on the next call.
+ * @throws {Error} If the parameters are invalid.
+ */
+#[wasm_bindgen(js_name = cornishFisherVarInc)]
+#[allow(clippy::too_many_arguments)]
+pub fn cornish_fisher_var_inc_wasm(
+    input_return: f64,
+    prev_mean: f64,
+    prev_m2: f64,
+    prev_m3: f64,
+    prev_m4: f64,
+    input_old_return: f64,
+    opt_period: usize,
+    opt_confidence: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_var, output_mean, output_m2, output_m3, output_m4) = risk::cornish_fisher_var_inc(
+        input_return,
+        prev_mean,
+        prev_m2,
+        prev_m3,
+        prev_m4,
+        input_old_return,
+        opt_period,
+        opt_confidence,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![
+        output_var,
+        output_mean,
+        output_m2,
+        output_m3,
+        output_m4,
+    ])
+}
@@ -0,0 +1,101 @@
+use kand::ta::stats::sharpe;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for the rolling Sharpe ratio.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = sharpeLookback)]
+pub fn sharpe_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    sharpe::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling Sharpe ratio for a series of periodic returns.
+ * @param {Float64Array} input_returns - Array of periodic returns (e.g. 0.01 for +1%).
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_risk_free - Annual risk-free rate (e.g. 0.02 for 2%).
+ * @param {number} opt_periods_per_year - Number of return periods per year (e.g. 252 for daily bars).
+ * @returns {Float64Array} An array of Sharpe ratio values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = sharpe)]
+pub fn sharpe_wasm(
+    input_returns: Vec<f64>,
+    opt_period: usize,
+    opt_risk_free: f64,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_sharpe = vec![0.0; input_returns.len()];
+    let mut output_mean = vec![0.0; input_returns.len()];
+    let mut output_m2 = vec![0.0; input_returns.len()];
+
+    sharpe::sharpe(
+        &input_returns,
+        opt_period,
+        opt_risk_free,
+        opt_periods_per_year,
+        &mut output_sharpe,
+        &mut output_mean,
+        &mut output_m2,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_sharpe)
+}
+
+/**
+ * Calculates a single rolling Sharpe ratio value incrementally.
+ * @param {number} input_return - The newest return to include.
+ * @param {number} prev_mean - The previous mean of returns in the window.
+ * @param {number} prev_m2 - The previous sum of squared deviations from the mean in the window.
+ * @param {number} input_old_return - The oldest return to remove from the window.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_risk_free - Annual risk-free rate (e.g. 0.02 for 2%).
+ * @param {number} opt_periods_per_year - Number of return periods per year.
+ * @returns {Float64Array} A three-element array `[sharpe, mean, m2]`; pass the mean and M2 back on the next call.
+ * @throws {Error} If the parameters are invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = sharpeInc)]
+pub fn sharpe_inc_wasm(
+    input_return: f64,
+    prev_mean: f64,
+    prev_m2: f64,
+    input_old_return: f64,
+    opt_period: usize,
+    opt_risk_free: f64,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_sharpe, output_mean, output_m2) = sharpe::sharpe_inc(
+        input_return,
+        prev_mean,
+        prev_m2,
+        input_old_return,
+        opt_period,
+        opt_risk_free,
+        opt_periods_per_year,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_sharpe, output_mean, output_m2])
+}
+
+/**
+ * Calculates the Sharpe ratio of an entire return series.
+ * @param {Float64Array} input_returns - Array of periodic returns.
+ * @param {number} opt_risk_free - Annual risk-free rate (e.g. 0.02 for 2%).
+ * @param {number} opt_periods_per_year - Number of return periods per year.
+ * @returns {number} The Sharpe ratio, or NaN when it is undefined.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = sharpeRatio)]
+pub fn sharpe_ratio_wasm(
+    input_returns: Vec<f64>,
+    opt_risk_free: f64,
+    opt_periods_per_year: f64,
+) -> Result<f64, JsValue> {
+    sharpe::sharpe_ratio(&input_returns, opt_risk_free, opt_periods_per_year)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,73 @@
+use kand::ta::stats::skew;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for rolling skewness.
+ * @param {number} opt_period - The rolling window size (must be >= 3).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = skewLookback)]
+pub fn skew_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    skew::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling population skewness of a price series.
+ * @param {Float64Array} input_prices - Array of prices.
+ * @param {number} opt_period - The rolling window size (must be >= 3).
+ * @returns {Float64Array} An array of skewness values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = skew)]
+pub fn skew_wasm(input_prices: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let mut output_skew = vec![0.0; input_prices.len()];
+    let mut output_mean = vec![0.0; input_prices.len()];
+    let mut output_m2 = vec![0.0; input_prices.len()];
+    let mut output_m3 = vec![0.0; input_prices.len()];
+
+    skew::skew(
+        &input_prices,
+        opt_period,
+        &mut output_skew,
+        &mut output_mean,
+        &mut output_m2,
+        &mut output_m3,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_skew)
+}
+
+/**
+ * Calculates a single rolling skewness value incrementally.
+ * @param {number} input_price - The newest price to include.
+ * @param {number} prev_mean - The previous mean of the window.
+ * @param {number} prev_m2 - The previous sum of squared deviations from the mean.
+ * @param {number} prev_m3 - The previous sum of cubed deviations from the mean.
+ * @param {number} input_old_price - The oldest price to remove from the window.
+ * @param {number} opt_period - The rolling window size (must be >= 3).
+ * @returns {Float64Array} A four-element array `[skew, mean, m2, m3]`; pass the moments back on the next call.
+ * @throws {Error} If the parameters are invalid.
+ */
+#[wasm_bindgen(js_name = skewInc)]
+pub fn skew_inc_wasm(
+    input_price: f64,
+    prev_mean: f64,
+    prev_m2: f64,
+    prev_m3: f64,
+    input_old_price: f64,
+    opt_period: usize,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_skew, output_mean, output_m2, output_m3) = skew::skew_inc(
+        input_price,
+        prev_mean,
+        prev_m2,
+        prev_m3,
+        input_old_price,
+        opt_period,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_skew, output_mean, output_m2, output_m3])
+}
@@ -0,0 +1,111 @@
+use kand::ta::stats::sortino;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for the rolling Sortino ratio.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = sortinoLookback)]
+pub fn sortino_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    sortino::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling Sortino ratio for a series of periodic returns.
+ * @param {Float64Array} input_returns - Array of periodic returns (e.g. 0.01 for +1%).
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_mar - Annual minimum acceptable return (e.g. 0.0).
+ * @param {number} opt_periods_per_year - Number of return periods per year (e.g. 252 for daily bars).
+ * @returns {Float64Array} An array of Sortino ratio values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = sortino)]
+pub fn sortino_wasm(
+    input_returns: Vec<f64>,
+    opt_period: usize,
+    opt_mar: f64,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_sortino = vec![0.0; input_returns.len()];
+    let mut output_sum = vec![0.0; input_returns.len()];
+    let mut output_sum_down_sq = vec![0.0; input_returns.len()];
+    let mut output_down_count = vec![0; input_returns.len()];
+
+    sortino::sortino(
+        &input_returns,
+        opt_period,
+        opt_mar,
+        opt_periods_per_year,
+        &mut output_sortino,
+        &mut output_sum,
+        &mut output_sum_down_sq,
+        &mut output_down_count,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_sortino)
+}
+
+/**
+ * Calculates a single rolling Sortino ratio value incrementally.
+ * @param {number} input_return - The newest return to include.
+ * @param {number} prev_sum - The previous sum of returns in the window.
+ * @param {number} prev_sum_down_sq - The previous sum of squared shortfalls below the MAR in the window.
+ * @param {number} prev_down_count - The previous number of returns below the MAR in the window.
+ * @param {number} input_old_return - The oldest return to remove from the window.
+ * @param {number} opt_period - The number of returns in the rolling window (must be >= 2).
+ * @param {number} opt_mar - Annual minimum acceptable return (e.g. 0.0).
+ * @param {number} opt_periods_per_year - Number of return periods per year.
+ * @returns {Float64Array} A four-element array `[sortino, sum, sum_down_sq, down_count]`; pass the sums and the count back on the next call.
+ * @throws {Error} If the parameters are invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = sortinoInc)]
+pub fn sortino_inc_wasm(
+    input_return: f64,
+    prev_sum: f64,
+    prev_sum_down_sq: f64,
+    prev_down_count: usize,
+    input_old_return: f64,
+    opt_period: usize,
+    opt_mar: f64,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_sortino, output_sum, output_sum_down_sq, output_down_count) = sortino::sortino_inc(
+        input_return,
+        prev_sum,
+        prev_sum_down_sq,
+        prev_down_count,
+        input_old_return,
+        opt_period,
+        opt_mar,
+        opt_periods_per_year,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![
+        output_sortino,
+        output_sum,
+        output_sum_down_sq,
+        output_down_count as f64,
+    ])
+}
+
+/**
+ * Calculates the Sortino ratio of an entire return series.
+ * @param {Float64Array} input_returns - Array of periodic returns.
+ * @param {number} opt_mar - Annual minimum acceptable return (e.g. 0.0).
+ * @param {number} opt_periods_per_year - Number of return periods per year.
+ * @returns {number} The Sortino ratio, or NaN when it is undefined.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = sortinoRatio)]
+pub fn sortino_ratio_wasm(
+    input_returns: Vec<f64>,
+    opt_mar: f64,
+    opt_periods_per_year: f64,
+) -> Result<f64, JsValue> {
+    sortino::sortino_ratio(&input_returns, opt_mar, opt_periods_per_year)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,138 @@
+use kand::{TAInt, ta::stats::winrate};
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for rolling trade statistics.
+ * @param {number} opt_period - The number of trades in the rolling window (must be >= 1).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = winrateLookback)]
+pub fn winrate_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    winrate::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Extracts closed-trade returns from a position signal and a price series.
+ * @param {BigInt64Array} input_signal - Positions per bar (sign gives long, short or flat).
+ * @param {Float64Array} input_price - Array of prices.
+ * @returns {Float64Array} An array with one return per closed trade.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = tradeReturns)]
+pub fn trade_returns_wasm(
+    input_signal: Vec<TAInt>,
+    input_price: Vec<f64>,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_trade_return = vec![0.0; input_signal.len()];
+
+    let count = winrate::trade_returns(&input_signal, &input_price, &mut output_trade_return)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    output_trade_return.truncate(count);
+
+    Ok(output_trade_return)
+}
+
+/**
+ * Calculates the rolling win rate of a series of trade returns.
+ * @param {Float64Array} input_trades - Array of per-trade returns.
+ * @param {number} opt_period - The number of trades in the rolling window (must be >= 1).
+ * @returns {Float64Array} An array of win rates (0 to 1) with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = winrate)]
+pub fn winrate_wasm(input_trades: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let [output_win_rate, ..] = winrate_all(&input_trades, opt_period)?;
+    Ok(output_win_rate)
+}
+
+/**
+ * Calculates the rolling profit factor (gross profit over gross loss) of a series of trade returns.
+ * @param {Float64Array} input_trades - Array of per-trade returns.
+ * @param {number} opt_period - The number of trades in the rolling window (must be >= 1).
+ * @returns {Float64Array} An array of profit factors with the same length as the input; `Infinity` where the window has winners but no losers.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = profitFactor)]
+pub fn profit_factor_wasm(input_trades: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let [_, _, _, output_profit_factor, _, _] = winrate_all(&input_trades, opt_period)?;
+    Ok(output_profit_factor)
+}
+
+/**
+ * Calculates the rolling expectancy (average return per trade) of a series of trade returns.
+ * @param {Float64Array} input_trades - Array of per-trade returns.
+ * @param {number} opt_period - The number of trades in the rolling window (must be >= 1).
+ * @returns {Float64Array} An array of expectancies with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = expectancy)]
+pub fn expectancy_wasm(input_trades: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let [_, _, _, _, output_expectancy, _] = winrate_all(&input_trades, opt_period)?;
+    Ok(output_expectancy)
+}
+
+/**
+ * Calculates the rolling payoff ratio (average win over average loss) of a series of trade returns.
+ * @param {Float64Array} input_trades - Array of per-trade returns.
+ * @param {number} opt_period - The number of trades in the rolling window (must be >= 1).
+ * @returns {Float64Array} An array of payoff ratios with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = payoffRatio)]
+pub fn payoff_ratio_wasm(input_trades: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let [.., output_payoff_ratio] = winrate_all(&input_trades, opt_period)?;
+    Ok(output_payoff_ratio)
+}
+
+/**
+ * Calculates the trade statistics of an entire list of trade returns.
+ * @param {Float64Array} input_trades - Array of per-trade returns.
+ * @returns {Array} A flat array [winRate, avgWin, avgLoss, profitFactor, expectancy, payoffRatio].
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = tradeStats)]
+pub fn trade_stats_wasm(input_trades: Vec<f64>) -> Result<Vec<f64>, JsValue> {
+    let stats =
+        winrate::trade_stats(&input_trades).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![
+        stats.win_rate(),
+        stats.avg_win(),
+        stats.avg_loss(),
+        stats.profit_factor(),
+        stats.expectancy(),
+        stats.payoff_ratio(),
+    ])
+}
+
+fn winrate_all(input_trades: &[f64], opt_period: usize) -> Result<[Vec<f64>; 6], JsValue> {
+    let len = input_trades.len();
+    let mut output_win_rate = vec![0.0; len];
+    let mut output_avg_win = vec![0.0; len];
+    let mut output_avg_loss = vec![0.0; len];
+    let mut output_profit_factor = vec![0.0; len];
+    let mut output_expectancy = vec![0.0; len];
+    let mut output_payoff_ratio = vec![0.0; len];
+
+    winrate::winrate(
+        input_trades,
+        opt_period,
+        &mut output_win_rate,
+        &mut output_avg_win,
+        &mut output_avg_loss,
+        &mut output_profit_factor,
+        &mut output_expectancy,
+        &mut output_payoff_ratio,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok([
+        output_win_rate,
+        output_avg_win,
+        output_avg_loss,
+        output_profit_factor,
+        output_expectancy,
+        output_payoff_ratio,
+    ])
+}
@@ -0,0 +1,140 @@
+use kand::ta::stats::yang_zhang;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for rolling Yang-Zhang volatility.
+ * @param {number} opt_period - Number of bars in the rolling window (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = yangZhangLookback)]
+pub fn yang_zhang_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    yang_zhang::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates rolling Yang-Zhang volatility.
+ * @param {Float64Array} input_open - Array of opening prices.
+ * @param {Float64Array} input_high - Array of high prices.
+ * @param {Float64Array} input_low - Array of low prices.
+ * @param {Float64Array} input_close - Array of closing prices.
+ * @param {number} opt_period - Number of bars in the rolling window (must be >= 2).
+ * @param {number} opt_periods_per_year - Number of bars per year (e.g. 252 for daily bars).
+ * @returns {Float64Array} An array of Yang-Zhang volatility values with the same length as the input.
+ * @throws {Error} If the input is invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = yangZhang)]
+pub fn yang_zhang_wasm(
+    input_open: Vec<f64>,
+    input_high: Vec<f64>,
+    input_low: Vec<f64>,
+    input_close: Vec<f64>,
+    opt_period: usize,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let mut output_yang_zhang = vec![0.0; input_open.len()];
+    let mut output_overnight_mean = vec![0.0; input_open.len()];
+    let mut output_overnight_m2 = vec![0.0; input_open.len()];
+    let mut output_intraday_mean = vec![0.0; input_open.len()];
+    let mut output_intraday_m2 = vec![0.0; input_open.len()];
+    let mut output_rs_sum = vec![0.0; input_open.len()];
+
+    yang_zhang::yang_zhang(
+        &input_open,
+        &input_high,
+        &input_low,
+        &input_close,
+        opt_period,
+        opt_periods_per_year,
+        &mut output_yang_zhang,
+        &mut output_overnight_mean,
+        &mut output_overnight_m2,
+        &mut output_intraday_mean,
+        &mut output_intraday_m2,
+        &mut output_rs_sum,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_yang_zhang)
+}
+
+/**
+ * Calculates a single rolling Yang-Zhang volatility value incrementally.
+ * @param {number} input_open - Opening price of the newest bar.
+ * @param {number} input_high - High price of the newest bar.
+ * @param {number} input_low - Low price of the newest bar.
+ * @param {number} input_close - Closing price of the newest bar.
+ * @param {number} input_prev_close - Closing price of the bar before the newest one.
+ * @param {number} input_old_open - Opening price of the bar leaving the window.
+ * @param {number} input_old_high - High price of the bar leaving the window.
+ * @param {number} input_old_low - Low price of the bar leaving the window.
+ * @param {number} input_old_close - Closing price of the bar leaving the window.
+ * @param {number} input_old_prev_close - Closing price of the bar before the one leaving the window.
+ * @param {number} prev_overnight_mean - Previous mean of overnight returns.
+ * @param {number} prev_overnight_m2 - Previous M2 of overnight returns.
+ * @param {number} prev_intraday_mean - Previous mean of open-to-close returns.
+ * @param {number} prev_intraday_m2 - Previous M2 of open-to-close returns.
+ * @param {number} prev_rs_sum - Previous sum of Rogers-Satchell terms.
+ * @param {number} opt_period - Number of bars in the rolling window (must be >= 2).
+ * @param {number} opt_periods_per_year - Number of bars per year.
+ * @returns {Float64Array} A six-element array `[yang_zhang, overnight_mean, overnight_m2, intraday_mean, intraday_m2, rs_sum]`; pass the state back on the next call.
+ * @throws {Error} If the parameters are invalid or the calculation fails.
+ */
+#[wasm_bindgen(js_name = yangZhangInc)]
+#[allow(clippy::too_many_arguments)]
+pub fn yang_zhang_inc_wasm(
+    input_open: f64,
+    input_high: f64,
+    input_low: f64,
+    input_close: f64,
+    input_prev_close: f64,
+    input_old_open: f64,
+    input_old_high: f64,
+    input_old_low: f64,
+    input_old_close: f64,
+    input_old_prev_close: f64,
+    prev_overnight_mean: f64,
+    prev_overnight_m2: f64,
+    prev_intraday_mean: f64,
+    prev_intraday_m2: f64,
+    prev_rs_sum: f64,
+    opt_period: usize,
+    opt_periods_per_year: f64,
+) -> Result<Vec<f64>, JsValue> {
+    let (
+        output_yang_zhang,
+        output_overnight_mean,
+        output_overnight_m2,
+        output_intraday_mean,
+        output_intraday_m2,
+        output_rs_sum,
+    ) = yang_zhang::yang_zhang_inc(
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        input_prev_close,
+        input_old_open,
+        input_old_high,
+        input_old_low,
+        input_old_close,
+        input_old_prev_close,
+        prev_overnight_mean,
+        prev_overnight_m2,
+        prev_intraday_mean,
+        prev_intraday_m2,
+        prev_rs_sum,
+        opt_period,
+        opt_periods_per_year,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![
+        output_yang_zhang,
+        output_overnight_mean,
+        output_overnight_m2,
+        output_intraday_mean,
+        output_intraday_m2,
+        output_rs_sum,
+    ])
+}
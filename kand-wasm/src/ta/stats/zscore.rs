@@ -0,0 +1,63 @@
+use kand::ta::stats::zscore;
+use wasm_bindgen::prelude::*;
+
+/**
+ * Returns the lookback period for the rolling z-score.
+ * @param {number} opt_period - The rolling window size (must be >= 2).
+ * @returns {number} The lookback period.
+ * @throws {Error} If the period is invalid.
+ */
+#[wasm_bindgen(js_name = zscoreLookback)]
+pub fn zscore_lookback_wasm(opt_period: usize) -> Result<usize, JsValue> {
+    zscore::lookback(opt_period).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/**
+ * Calculates the rolling z-score of each price against the mean of its window.
+ * @param {Float64Array} input_prices - Array of prices.
+ * @param {number} opt_period - The rolling window size (must be >= 2).
+ * @returns {Float64Array} An array of z-score values with the same length as the input.
+ * @throws {Error} If the input is invalid.
+ */
+#[wasm_bindgen(js_name = zscore)]
+pub fn zscore_wasm(input_prices: Vec<f64>, opt_period: usize) -> Result<Vec<f64>, JsValue> {
+    let mut output_zscore = vec![0.0; input_prices.len()];
+    let mut output_mean = vec![0.0; input_prices.len()];
+    let mut output_m2 = vec![0.0; input_prices.len()];
+
+    zscore::zscore(
+        &input_prices,
+        opt_period,
+        &mut output_zscore,
+        &mut output_mean,
+        &mut output_m2,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(output_zscore)
+}
+
+/**
+ * Calculates a single rolling z-score value incrementally.
+ * @param {number} input_price - The newest price to include.
+ * @param {number} prev_mean - The previous mean of the window.
+ * @param {number} prev_m2 - The previous sum of squared deviations from the mean.
+ * @param {number} input_old_price - The oldest price to remove from the window.
+ * @param {number} opt_period - The rolling window size (must be >= 2).
+ * @returns {Float64Array} A three-element array `[zscore, mean, m2]`; pass the moments back on the next call.
+ * @throws {Error} If the parameters are invalid.
+ */
+#[wasm_bindgen(js_name = zscoreInc)]
+pub fn zscore_inc_wasm(
+    input_price: f64,
+    prev_mean: f64,
+    prev_m2: f64,
+    input_old_price: f64,
+    opt_period: usize,
+) -> Result<Vec<f64>, JsValue> {
+    let (output_zscore, output_mean, output_m2) =
+        zscore::zscore_inc(input_price, prev_mean, prev_m2, input_old_price, opt_period)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(vec![output_zscore, output_mean, output_m2])
+}
@@ -16,18 +16,47 @@
     benchmarks::ohlcv::bbands_bench::ohlcv,
     benchmarks::ohlcv::bop_bench::ohlcv,
     benchmarks::ohlcv::cci_bench::ohlcv,
+    benchmarks::ohlcv::cdl_dark_cloud_cover_bench::ohlcv,
     benchmarks::ohlcv::cdl_doji_bench::ohlcv,
     benchmarks::ohlcv::cdl_dragonfly_doji_bench::ohlcv,
+    benchmarks::ohlcv::cdl_engulfing_bench::ohlcv,
+    benchmarks::ohlcv::cdl_evening_doji_star_bench::ohlcv,
+    benchmarks::ohlcv::cdl_evening_star_bench::ohlcv,
     benchmarks::ohlcv::cdl_gravestone_doji_bench::ohlcv,
     benchmarks::ohlcv::cdl_hammer_bench::ohlcv,
+    benchmarks::ohlcv::cdl_harami_bench::ohlcv,
+    benchmarks::ohlcv::cdl_harami_cross_bench::ohlcv,
     benchmarks::ohlcv::cdl_inverted_hammer_bench::ohlcv,
     benchmarks::ohlcv::cdl_long_shadow_bench::ohlcv,
     benchmarks::ohlcv::cdl_marubozu_bench::ohlcv,
+    benchmarks::ohlcv::cdl_morning_doji_star_bench::ohlcv,
+    benchmarks::ohlcv::cdl_morning_star_bench::ohlcv,
+    benchmarks::ohlcv::cdl_piercing_bench::ohlcv,
+    benchmarks::ohlcv::cdl_scan_bench::ohlcv,
+    benchmarks::ohlcv::cdl_three_black_crows_bench::ohlcv,
+    benchmarks::ohlcv::cdl_three_inside_bench::ohlcv,
+    benchmarks::ohlcv::cdl_three_outside_bench::ohlcv,
+    benchmarks::ohlcv::cdl_three_white_soldiers_bench::ohlcv,
     benchmarks::ohlcv::dema_bench::ohlcv,
+    benchmarks::ohlcv::donchian_bench::ohlcv,
     benchmarks::ohlcv::dx_bench::ohlcv,
     benchmarks::ohlcv::ecl_bench::ohlcv,
     benchmarks::ohlcv::ema_bench::ohlcv,
+    benchmarks::ohlcv::ht_dcperiod_bench::ohlcv,
+    benchmarks::ohlcv::ht_dcphase_bench::ohlcv,
+    benchmarks::ohlcv::ht_phasor_bench::ohlcv,
+    benchmarks::ohlcv::ht_sine_bench::ohlcv,
+    benchmarks::ohlcv::ht_trendline_bench::ohlcv,
+    benchmarks::ohlcv::ht_trendmode_bench::ohlcv,
+    benchmarks::ohlcv::ichimoku_bench::ohlcv,
+    benchmarks::ohlcv::kama_bench::ohlcv,
+    benchmarks::ohlcv::keltner_bench::ohlcv,
+    benchmarks::ohlcv::linearreg_bench::ohlcv,
+    benchmarks::ohlcv::linearreg_angle_bench::ohlcv,
+    benchmarks::ohlcv::linearreg_intercept_bench::ohlcv,
+    benchmarks::ohlcv::linearreg_slope_bench::ohlcv,
     benchmarks::ohlcv::macd_bench::ohlcv,
+    benchmarks::ohlcv::mama_bench::ohlcv,
     benchmarks::ohlcv::medprice_bench::ohlcv,
     benchmarks::ohlcv::mfi_bench::ohlcv,
     benchmarks::ohlcv::midpoint_bench::ohlcv,
@@ -55,6 +84,7 @@
     benchmarks::ohlcv::trange_bench::ohlcv,
     benchmarks::ohlcv::trima_bench::ohlcv,
     benchmarks::ohlcv::trix_bench::ohlcv,
+    benchmarks::ohlcv::tsf_bench::ohlcv,
     benchmarks::ohlcv::typprice_bench::ohlcv,
     benchmarks::ohlcv::vegas_bench::ohlcv,
     benchmarks::ohlcv::wclprice_bench::ohlcv,
@@ -63,11 +93,32 @@
     benchmarks::ohlcv::vwap_bench::ohlcv,
 
     // Stats benchmarks
+    benchmarks::stats::alpha_bench::stats,
+    benchmarks::stats::beta_bench::stats,
+    benchmarks::stats::calmar_bench::stats,
+    benchmarks::stats::drawdown_bench::stats,
+    benchmarks::stats::garman_klass_bench::stats,
+    benchmarks::stats::hv_bench::stats,
+    benchmarks::stats::irr_bench::stats,
+    benchmarks::stats::kelly_bench::stats,
+    benchmarks::stats::kurt_bench::stats,
     benchmarks::stats::max_bench::stats,
     benchmarks::stats::min_bench::stats,
+    benchmarks::stats::parkinson_bench::stats,
+    benchmarks::stats::percentrank_bench::stats,
+    benchmarks::stats::quantile_bench::stats,
+    benchmarks::stats::ret_bench::stats,
+    benchmarks::stats::risk_bench::stats,
+    benchmarks::stats::rogers_satchell_bench::stats,
+    benchmarks::stats::sharpe_bench::stats,
+    benchmarks::stats::skew_bench::stats,
+    benchmarks::stats::sortino_bench::stats,
     benchmarks::stats::stddev_bench::stats,
     benchmarks::stats::sum_bench::stats,
     benchmarks::stats::var_bench::stats,
+    benchmarks::stats::winrate_bench::stats,
+    benchmarks::stats::yang_zhang_bench::stats,
+    benchmarks::stats::zscore_bench::stats,
 
     // Helper benchmarks
     // benchmarks::helper::helper,
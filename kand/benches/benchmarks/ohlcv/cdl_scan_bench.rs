@@ -0,0 +1,41 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::{
+    helper::CandleSettings,
+    ohlcv::cdl_scan::{CdlMatches, cdl_scan},
+};
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_cdl_scan(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cdl_scan");
+
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let settings = CandleSettings::default();
+
+    for size in sizes {
+        let input_open = generate_test_data(size);
+        let input_high = generate_test_data(size);
+        let input_low = generate_test_data(size);
+        let input_close = generate_test_data(size);
+        let mut output_matches = vec![CdlMatches::default(); size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = cdl_scan(
+                    black_box(&input_open),
+                    black_box(&input_high),
+                    black_box(&input_low),
+                    black_box(&input_close),
+                    black_box(&settings),
+                    black_box(&mut output_matches),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_cdl_scan);
@@ -0,0 +1,28 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::ohlcv::ht_dcperiod::ht_dcperiod;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_ht_dcperiod(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ht_dcperiod");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = ht_dcperiod(black_box(&input), black_box(&mut output));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_ht_dcperiod);
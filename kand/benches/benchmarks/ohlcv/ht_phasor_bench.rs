@@ -0,0 +1,33 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::ohlcv::ht_phasor::ht_phasor;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_ht_phasor(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ht_phasor");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output_in_phase = vec![0.0; size];
+        let mut output_quadrature = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = ht_phasor(
+                    black_box(&input),
+                    black_box(&mut output_in_phase),
+                    black_box(&mut output_quadrature),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_ht_phasor);
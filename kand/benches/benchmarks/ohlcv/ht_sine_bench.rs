@@ -0,0 +1,33 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::ohlcv::ht_sine::ht_sine;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_ht_sine(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ht_sine");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output_sine = vec![0.0; size];
+        let mut output_lead_sine = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = ht_sine(
+                    black_box(&input),
+                    black_box(&mut output_sine),
+                    black_box(&mut output_lead_sine),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_ht_sine);
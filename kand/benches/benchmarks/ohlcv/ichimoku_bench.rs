@@ -0,0 +1,81 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::{
+    Bar, Indicator,
+    ohlcv::ichimoku::{Ichimoku, ichimoku},
+};
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_ichimoku(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ichimoku");
+
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let displacement = 26;
+
+    for size in sizes {
+        let input_high = generate_test_data(size);
+        let input_low = generate_test_data(size);
+        let input_close = generate_test_data(size);
+        let mut output_tenkan = vec![0.0; size];
+        let mut output_kijun = vec![0.0; size];
+        let mut output_senkou_a = vec![0.0; size + displacement];
+        let mut output_senkou_b = vec![0.0; size + displacement];
+        let mut output_chikou = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = ichimoku(
+                    black_box(&input_high),
+                    black_box(&input_low),
+                    black_box(&input_close),
+                    black_box(9),
+                    black_box(26),
+                    black_box(52),
+                    black_box(displacement),
+                    black_box(&mut output_tenkan),
+                    black_box(&mut output_kijun),
+                    black_box(&mut output_senkou_a),
+                    black_box(&mut output_senkou_b),
+                    black_box(&mut output_chikou),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Per-tick cost of committing a bar versus revising a forming one, which clones the windows.
+#[allow(dead_code)]
+fn bench_ichimoku_revise(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ichimoku_revise");
+
+    let input = generate_test_data(10_000);
+    let bars: Vec<Bar> = input
+        .iter()
+        .map(|&price| Bar::new(price, price + 1.0, price - 1.0, price, 0.0))
+        .collect();
+    let mut indicator = Ichimoku::new(9, 26, 52, 26).unwrap();
+    for bar in &bars {
+        let _ = indicator.update(bar);
+    }
+    let tick = bars[bars.len() - 1];
+
+    let mut live = indicator.clone();
+    group.bench_function("push", |b| {
+        b.iter(|| {
+            let _ = black_box(live.push(black_box(&tick)));
+        });
+    });
+    group.bench_function("revise", |b| {
+        b.iter(|| {
+            let _ = black_box(indicator.revise(black_box(&tick)));
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_ichimoku, bench_ichimoku_revise);
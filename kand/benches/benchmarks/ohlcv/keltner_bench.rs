@@ -0,0 +1,51 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::ohlcv::keltner::keltner;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_keltner(c: &mut Criterion) {
+    let mut group = c.benchmark_group("keltner");
+
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![5, 50, 200];
+    let multiplier = 2.0;
+
+    for size in sizes {
+        let input_high = generate_test_data(size);
+        let input_low = generate_test_data(size);
+        let input_close = generate_test_data(size);
+        let mut output_upper = vec![0.0; size];
+        let mut output_middle = vec![0.0; size];
+        let mut output_lower = vec![0.0; size];
+        let mut output_atr = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = keltner(
+                            black_box(&input_high),
+                            black_box(&input_low),
+                            black_box(&input_close),
+                            black_box(period),
+                            black_box(period),
+                            black_box(multiplier),
+                            black_box(&mut output_upper),
+                            black_box(&mut output_middle),
+                            black_box(&mut output_lower),
+                            black_box(&mut output_atr),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_keltner);
@@ -0,0 +1,43 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::ohlcv::linearreg_intercept::linearreg_intercept;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_linearreg_intercept(c: &mut Criterion) {
+    let mut group = c.benchmark_group("linearreg_intercept");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![5, 50, 200];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output = vec![0.0; size];
+        let mut output_sum_y = vec![0.0; size];
+        let mut output_sum_xy = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = linearreg_intercept(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(&mut output),
+                            black_box(&mut output_sum_y),
+                            black_box(&mut output_sum_xy),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_linearreg_intercept);
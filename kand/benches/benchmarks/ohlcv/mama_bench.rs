@@ -0,0 +1,42 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::ohlcv::mama::mama;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_mama(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mama");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let limits = vec![(0.5, 0.05), (0.9, 0.1)];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output_mama = vec![0.0; size];
+        let mut output_fama = vec![0.0; size];
+
+        for &(fast_limit, slow_limit) in &limits {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), format!("{fast_limit}_{slow_limit}")),
+                &(fast_limit, slow_limit),
+                |b, &(fast_limit, slow_limit)| {
+                    b.iter(|| {
+                        let _ = mama(
+                            black_box(&input),
+                            black_box(fast_limit),
+                            black_box(slow_limit),
+                            black_box(&mut output_mama),
+                            black_box(&mut output_fama),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_mama);
@@ -9,18 +9,47 @@
 pub mod bbands_bench;
 pub mod bop_bench;
 pub mod cci_bench;
+pub mod cdl_dark_cloud_cover_bench;
 pub mod cdl_doji_bench;
 pub mod cdl_dragonfly_doji_bench;
+pub mod cdl_engulfing_bench;
+pub mod cdl_evening_doji_star_bench;
+pub mod cdl_evening_star_bench;
 pub mod cdl_gravestone_doji_bench;
 pub mod cdl_hammer_bench;
+pub mod cdl_harami_bench;
+pub mod cdl_harami_cross_bench;
 pub mod cdl_inverted_hammer_bench;
 pub mod cdl_long_shadow_bench;
 pub mod cdl_marubozu_bench;
+pub mod cdl_morning_doji_star_bench;
+pub mod cdl_morning_star_bench;
+pub mod cdl_piercing_bench;
+pub mod cdl_scan_bench;
+pub mod cdl_three_black_crows_bench;
+pub mod cdl_three_inside_bench;
+pub mod cdl_three_outside_bench;
+pub mod cdl_three_white_soldiers_bench;
 pub mod dema_bench;
+pub mod donchian_bench;
 pub mod dx_bench;
 pub mod ecl_bench;
 pub mod ema_bench;
+pub mod ht_dcperiod_bench;
+pub mod ht_dcphase_bench;
+pub mod ht_phasor_bench;
+pub mod ht_sine_bench;
+pub mod ht_trendline_bench;
+pub mod ht_trendmode_bench;
+pub mod ichimoku_bench;
+pub mod kama_bench;
+pub mod keltner_bench;
+pub mod linearreg_angle_bench;
+pub mod linearreg_bench;
+pub mod linearreg_intercept_bench;
+pub mod linearreg_slope_bench;
 pub mod macd_bench;
+pub mod mama_bench;
 pub mod medprice_bench;
 pub mod mfi_bench;
 pub mod midpoint_bench;
@@ -47,6 +76,7 @@
 pub mod trange_bench;
 pub mod trima_bench;
 pub mod trix_bench;
+pub mod tsf_bench;
 pub mod typprice_bench;
 pub mod vegas_bench;
 pub mod vwap_bench;
@@ -0,0 +1,53 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::stats::alpha::alpha;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_alpha(c: &mut Criterion) {
+    let mut group = c.benchmark_group("alpha");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![5, 50, 200];
+
+    for size in sizes {
+        let input_returns = generate_test_data(size);
+        let input_benchmark: Vec<_> = input_returns.iter().rev().copied().collect();
+        let mut output_alpha = vec![0.0; size];
+        let mut output_beta = vec![0.0; size];
+        let mut output_sum_returns = vec![0.0; size];
+        let mut output_sum_benchmark = vec![0.0; size];
+        let mut output_sum_benchmark_sq = vec![0.0; size];
+        let mut output_sum_cross = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = alpha(
+                            black_box(&input_returns),
+                            black_box(&input_benchmark),
+                            black_box(period),
+                            black_box(0.0),
+                            black_box(252.0),
+                            black_box(&mut output_alpha),
+                            black_box(&mut output_beta),
+                            black_box(&mut output_sum_returns),
+                            black_box(&mut output_sum_benchmark),
+                            black_box(&mut output_sum_benchmark_sq),
+                            black_box(&mut output_sum_cross),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_alpha);
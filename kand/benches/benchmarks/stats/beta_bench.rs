@@ -1,7 +1,8 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
-use kand::ohlcv::beta::beta;
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::stats::beta::beta;
+use std::hint::black_box;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_beta(c: &mut Criterion) {
@@ -12,16 +13,30 @@ fn bench_beta(c: &mut Criterion) {
     let periods = vec![5, 50, 200];
 
     for size in sizes {
-        let input = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let input_0 = generate_test_data(size);
+        let input_1: Vec<_> = input_0.iter().rev().copied().collect();
+        let mut output_beta = vec![0.0; size];
+        let mut output_sum_x = vec![0.0; size];
+        let mut output_sum_y = vec![0.0; size];
+        let mut output_sum_xx = vec![0.0; size];
+        let mut output_sum_xy = vec![0.0; size];
 
         for period in &periods {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
+                BenchmarkId::new(format!("size_{size}"), period),
                 period,
                 |b, &period| {
                     b.iter(|| {
-                        let _ = beta(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = beta(
+                            black_box(&input_0),
+                            black_box(&input_1),
+                            black_box(period),
+                            black_box(&mut output_beta),
+                            black_box(&mut output_sum_x),
+                            black_box(&mut output_sum_y),
+                            black_box(&mut output_sum_xx),
+                            black_box(&mut output_sum_xy),
+                        );
                     });
                 },
             );
@@ -31,4 +46,4 @@ fn bench_beta(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(ohlcv, bench_beta);
+criterion_group!(stats, bench_beta);
@@ -0,0 +1,39 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::stats::drawdown::drawdown;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_drawdown(c: &mut Criterion) {
+    let mut group = c.benchmark_group("drawdown");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output_drawdown = vec![0.0; size];
+        let mut output_max_drawdown = vec![0.0; size];
+        let mut output_duration = vec![0; size];
+        let mut output_max_duration = vec![0; size];
+        let mut output_recovery = vec![0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = drawdown(
+                    black_box(&input),
+                    black_box(&mut output_drawdown),
+                    black_box(&mut output_max_drawdown),
+                    black_box(&mut output_duration),
+                    black_box(&mut output_max_duration),
+                    black_box(&mut output_recovery),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_drawdown);
@@ -0,0 +1,48 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::stats::garman_klass::garman_klass;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_garman_klass(c: &mut Criterion) {
+    let mut group = c.benchmark_group("garman_klass");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![5, 50, 200];
+
+    for size in sizes {
+        let open = generate_test_data(size);
+        let high = generate_test_data(size);
+        let low = generate_test_data(size);
+        let close = generate_test_data(size);
+        let mut output = vec![0.0; size];
+        let mut output_sum = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = garman_klass(
+                            black_box(&open),
+                            black_box(&high),
+                            black_box(&low),
+                            black_box(&close),
+                            black_box(period),
+                            black_box(252.0),
+                            black_box(&mut output),
+                            black_box(&mut output_sum),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_garman_klass);
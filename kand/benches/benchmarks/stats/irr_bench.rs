@@ -0,0 +1,29 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::stats::irr::irr;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_irr(c: &mut Criterion) {
+    let mut group = c.benchmark_group("irr");
+
+    // Test different data sizes
+    let sizes = vec![1_000, 100_000, 1_000_000];
+
+    for size in sizes {
+        // An initial outlay recovered by the rest of the series
+        let mut input = generate_test_data(size);
+        input[0] = -0.8 * input[1..].iter().sum::<f64>();
+
+        group.bench_with_input(BenchmarkId::new("size", size), &input, |b, input| {
+            b.iter(|| {
+                let _ = irr(black_box(input), black_box(0.1));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_irr);
@@ -1,5 +1,26 @@
+pub mod alpha_bench;
+pub mod beta_bench;
+pub mod calmar_bench;
+pub mod drawdown_bench;
+pub mod garman_klass_bench;
+pub mod hv_bench;
+pub mod irr_bench;
+pub mod kelly_bench;
+pub mod kurt_bench;
 pub mod max_bench;
 pub mod min_bench;
+pub mod parkinson_bench;
+pub mod percentrank_bench;
+pub mod quantile_bench;
+pub mod ret_bench;
+pub mod risk_bench;
+pub mod rogers_satchell_bench;
+pub mod sharpe_bench;
+pub mod skew_bench;
+pub mod sortino_bench;
 pub mod stddev_bench;
 pub mod sum_bench;
 pub mod var_bench;
+pub mod winrate_bench;
+pub mod yang_zhang_bench;
+pub mod zscore_bench;
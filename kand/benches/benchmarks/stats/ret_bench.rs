@@ -0,0 +1,33 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::stats::ret::{log_returns, simple_returns};
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_ret(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ret");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("simple", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = simple_returns(black_box(&input), black_box(&mut output));
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("log", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = log_returns(black_box(&input), black_box(&mut output));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_ret);
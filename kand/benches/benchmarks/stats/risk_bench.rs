@@ -0,0 +1,56 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::stats::risk::{cornish_fisher_var, historical_var};
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_risk(c: &mut Criterion) {
+    let mut group = c.benchmark_group("risk");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let period = 250;
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output = vec![0.0; size];
+        let mut output_mean = vec![0.0; size];
+        let mut output_m2 = vec![0.0; size];
+        let mut output_m3 = vec![0.0; size];
+        let mut output_m4 = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("historical", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = historical_var(
+                    black_box(&input),
+                    black_box(period),
+                    black_box(0.95),
+                    black_box(&mut output),
+                );
+            });
+        });
+        group.bench_with_input(
+            BenchmarkId::new("cornish_fisher", size),
+            &size,
+            |b, &_size| {
+                b.iter(|| {
+                    let _ = cornish_fisher_var(
+                        black_box(&input),
+                        black_box(period),
+                        black_box(0.95),
+                        black_box(&mut output),
+                        black_box(&mut output_mean),
+                        black_box(&mut output_m2),
+                        black_box(&mut output_m3),
+                        black_box(&mut output_m4),
+                    );
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_risk);
@@ -0,0 +1,49 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::stats::winrate::winrate;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_winrate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("winrate");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![5, 50, 200];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output_win_rate = vec![0.0; size];
+        let mut output_avg_win = vec![0.0; size];
+        let mut output_avg_loss = vec![0.0; size];
+        let mut output_profit_factor = vec![0.0; size];
+        let mut output_expectancy = vec![0.0; size];
+        let mut output_payoff_ratio = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = winrate(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(&mut output_win_rate),
+                            black_box(&mut output_avg_win),
+                            black_box(&mut output_avg_loss),
+                            black_box(&mut output_profit_factor),
+                            black_box(&mut output_expectancy),
+                            black_box(&mut output_payoff_ratio),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_winrate);
@@ -0,0 +1,56 @@
+use criterion::{BenchmarkId, Criterion, criterion_group};
+use kand::stats::yang_zhang::yang_zhang;
+use std::hint::black_box;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_yang_zhang(c: &mut Criterion) {
+    let mut group = c.benchmark_group("yang_zhang");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![5, 50, 200];
+
+    for size in sizes {
+        let open = generate_test_data(size);
+        let high = generate_test_data(size);
+        let low = generate_test_data(size);
+        let close = generate_test_data(size);
+        let mut output = vec![0.0; size];
+        let mut output_overnight_mean = vec![0.0; size];
+        let mut output_overnight_m2 = vec![0.0; size];
+        let mut output_intraday_mean = vec![0.0; size];
+        let mut output_intraday_m2 = vec![0.0; size];
+        let mut output_rs_sum = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = yang_zhang(
+                            black_box(&open),
+                            black_box(&high),
+                            black_box(&low),
+                            black_box(&close),
+                            black_box(period),
+                            black_box(252.0),
+                            black_box(&mut output),
+                            black_box(&mut output_overnight_mean),
+                            black_box(&mut output_overnight_m2),
+                            black_box(&mut output_intraday_mean),
+                            black_box(&mut output_intraday_m2),
+                            black_box(&mut output_rs_sum),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_yang_zhang);
@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, types::Bar};
 
 /// Find the number of bars back to the lowest value in a lookback period
 ///
@@ -134,6 +136,26 @@ pub fn real_body_length(open: TAFloat, close: TAFloat) -> TAFloat {
     (close - open).abs()
 }
 
+/// Calculate candlestick real body length as a percentage of its high-low range
+///
+/// # Arguments
+/// * `open` - Opening price
+/// * `high` - High price
+/// * `low` - Low price
+/// * `close` - Closing price
+///
+/// # Returns
+/// * `TAFloat` - Real body length divided by the range, times 100; 0.0 for a zero-range candle
+#[must_use]
+pub fn real_body_percent(open: TAFloat, high: TAFloat, low: TAFloat, close: TAFloat) -> TAFloat {
+    let range = high - low;
+    if range > 0.0 {
+        real_body_length(open, close) / range * 100.0
+    } else {
+        0.0
+    }
+}
+
 /// Calculate candlestick upper shadow length
 ///
 /// # Arguments
@@ -205,3 +227,661 @@ pub fn has_real_body_gap_down(
 ) -> bool {
     open2.max(close2) < open1.min(close1)
 }
+
+/// Collect the `N` candles ending at `end` into a window ordered from oldest to newest
+///
+/// Multi-candle pattern detectors take their input as a fixed-size window of bars; this
+/// builds that window from parallel OHLC arrays. Volume is left at zero.
+///
+/// # Arguments
+/// * `open` - Array of opening prices
+/// * `high` - Array of high prices
+/// * `low` - Array of low prices
+/// * `close` - Array of closing prices
+/// * `end` - Index of the newest candle in the window; must be at least `N - 1`
+///
+/// # Returns
+/// * `[Bar; N]` - Bars `end + 1 - N ..= end`
+#[must_use]
+pub fn bar_window<const N: usize>(
+    open: &[TAFloat],
+    high: &[TAFloat],
+    low: &[TAFloat],
+    close: &[TAFloat],
+    end: usize,
+) -> [Bar; N] {
+    let start = end + 1 - N;
+    core::array::from_fn(|i| {
+        let idx = start + i;
+        Bar::new(open[idx], high[idx], low[idx], close[idx], 0.0)
+    })
+}
+
+/// Part of a candle measured by a [`CandleSetting`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CandleRangeType {
+    /// Length of the real body, `|close - open|`
+    RealBody,
+    /// Length of the whole candle, `high - low`
+    HighLow,
+    /// Combined length of the upper and lower shadows
+    Shadows,
+}
+
+/// Threshold rule used by candlestick patterns to judge a body or shadow size
+///
+/// A candle feature is compared against `factor` times the average [`CandleRangeType`] length of
+/// the `avg_period` candles before it, as in TA-Lib's candle settings. With `avg_period == 0`
+/// the candle's own range is used instead of an average. Shadow averages are halved so they
+/// refer to a single shadow.
+///
+/// # Fields
+/// * `range_type` - Part of the candle that is averaged
+/// * `avg_period` - Number of preceding candles in the average
+/// * `factor` - Multiplier applied to the average
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandleSetting {
+    pub range_type: CandleRangeType,
+    pub avg_period: usize,
+    pub factor: TAFloat,
+}
+
+impl CandleSetting {
+    /// Creates a candle setting.
+    #[must_use]
+    pub const fn new(range_type: CandleRangeType, avg_period: usize, factor: TAFloat) -> Self {
+        Self {
+            range_type,
+            avg_period,
+            factor,
+        }
+    }
+
+    /// Length of the part of `input` measured by this setting.
+    #[must_use]
+    pub fn range(&self, input: &Bar) -> TAFloat {
+        match self.range_type {
+            CandleRangeType::RealBody => real_body_length(input.open, input.close),
+            CandleRangeType::HighLow => input.high - input.low,
+            CandleRangeType::Shadows => {
+                upper_shadow_length(input.high, input.open, input.close)
+                    + lower_shadow_length(input.low, input.open, input.close)
+            }
+        }
+    }
+
+    /// Threshold for `input` given the sum of ranges over the `avg_period` candles before it.
+    ///
+    /// # Arguments
+    /// * `sum` - Sum of [`CandleSetting::range`] over the preceding `avg_period` candles
+    /// * `input` - Candle being judged; only used when `avg_period` is 0
+    #[must_use]
+    pub fn average(&self, sum: TAFloat, input: &Bar) -> TAFloat {
+        let base = if self.avg_period == 0 {
+            self.range(input)
+        } else {
+            sum / self.avg_period as TAFloat
+        };
+        let divisor = if self.range_type == CandleRangeType::Shadows {
+            2.0
+        } else {
+            1.0
+        };
+        self.factor * base / divisor
+    }
+}
+
+/// Full set of candle settings used by the TA-Lib compatible pattern detectors
+///
+/// [`CandleSettings::default`] returns TA-Lib's defaults. Individual settings can be overridden
+/// with struct update syntax.
+///
+/// # Example
+/// ```
+/// use kand::helper::{CandleRangeType, CandleSetting, CandleSettings};
+///
+/// let settings = CandleSettings {
+///     body_doji: CandleSetting::new(CandleRangeType::HighLow, 10, 0.05),
+///     ..CandleSettings::default()
+/// };
+/// assert_eq!(settings.body_long.avg_period, 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandleSettings {
+    /// Real body is long when longer than the average real body
+    pub body_long: CandleSetting,
+    /// Real body is very long when longer than three times the average real body
+    pub body_very_long: CandleSetting,
+    /// Real body is short when shorter than the average real body
+    pub body_short: CandleSetting,
+    /// Real body is like a doji when shorter than 10% of the average high-low range
+    pub body_doji: CandleSetting,
+    /// Shadow is long when longer than the candle's own real body
+    pub shadow_long: CandleSetting,
+    /// Shadow is very long when longer than twice the candle's own real body
+    pub shadow_very_long: CandleSetting,
+    /// Shadow is short when shorter than half the average sum of shadows
+    pub shadow_short: CandleSetting,
+    /// Shadow is very short when shorter than 10% of the average high-low range
+    pub shadow_very_short: CandleSetting,
+    /// Distance is near when within 20% of the average high-low range
+    pub near: CandleSetting,
+    /// Distance is far when beyond 60% of the average high-low range
+    pub far: CandleSetting,
+    /// Distance is equal when within 5% of the average high-low range
+    pub equal: CandleSetting,
+}
+
+impl CandleSettings {
+    /// TA-Lib's default candle settings.
+    pub const TALIB: Self = Self {
+        body_long: CandleSetting::new(CandleRangeType::RealBody, 10, 1.0),
+        body_very_long: CandleSetting::new(CandleRangeType::RealBody, 10, 3.0),
+        body_short: CandleSetting::new(CandleRangeType::RealBody, 10, 1.0),
+        body_doji: CandleSetting::new(CandleRangeType::HighLow, 10, 0.1),
+        shadow_long: CandleSetting::new(CandleRangeType::RealBody, 0, 1.0),
+        shadow_very_long: CandleSetting::new(CandleRangeType::RealBody, 0, 2.0),
+        shadow_short: CandleSetting::new(CandleRangeType::Shadows, 10, 1.0),
+        shadow_very_short: CandleSetting::new(CandleRangeType::HighLow, 10, 0.1),
+        near: CandleSetting::new(CandleRangeType::HighLow, 5, 0.2),
+        far: CandleSetting::new(CandleRangeType::HighLow, 5, 0.6),
+        equal: CandleSetting::new(CandleRangeType::HighLow, 5, 0.05),
+    };
+}
+
+impl Default for CandleSettings {
+    /// Returns [`CandleSettings::TALIB`].
+    fn default() -> Self {
+        Self::TALIB
+    }
+}
+
+/// Calculate the rolling candle average threshold of a [`CandleSetting`] for every candle
+///
+/// # Arguments
+/// * `setting` - Candle setting to evaluate
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `output_average` - Output array; entry `i` is the threshold for candle `i`, computed from
+///   candles `i - avg_period .. i`. The first `avg_period` entries are NaN
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::LengthMismatch` - If input and output arrays have different lengths
+///
+/// # Example
+/// ```
+/// use kand::helper::{CandleRangeType, CandleSetting, candle_average};
+///
+/// let setting = CandleSetting::new(CandleRangeType::RealBody, 2, 1.0);
+/// let input_open = vec![10.0, 11.0, 12.0, 12.0];
+/// let input_high = vec![11.5, 12.5, 13.5, 13.0];
+/// let input_low = vec![9.5, 10.5, 11.5, 11.0];
+/// let input_close = vec![11.0, 12.0, 14.0, 12.5];
+/// let mut output_average = vec![0.0; 4];
+///
+/// candle_average(
+///     &setting,
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &mut output_average,
+/// )
+/// .unwrap();
+///
+/// assert!(output_average[1].is_nan());
+/// assert_eq!(output_average[2], 1.0);
+/// assert_eq!(output_average[3], 1.5);
+/// ```
+pub fn candle_average(
+    setting: &CandleSetting,
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    output_average: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    if len != input_high.len()
+        || len != input_low.len()
+        || len != input_close.len()
+        || len != output_average.len()
+    {
+        return Err(KandError::LengthMismatch);
+    }
+
+    let bar = |i: usize| {
+        Bar::new(
+            input_open[i],
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            0.0,
+        )
+    };
+    let period = setting.avg_period;
+    let mut sum = 0.0;
+    for (i, average) in output_average.iter_mut().enumerate() {
+        *average = if i < period {
+            TAFloat::NAN
+        } else {
+            setting.average(sum, &bar(i))
+        };
+        if period > 0 {
+            let old = (i >= period).then(|| bar(i - period));
+            sum = candle_range_sum_inc(setting, sum, &bar(i), old.as_ref());
+        }
+    }
+
+    Ok(())
+}
+
+/// Roll the range sum of a [`CandleSetting`] forward by one candle
+///
+/// # Arguments
+/// * `setting` - Candle setting whose range is summed
+/// * `prev_sum` - Sum of ranges over the previous window
+/// * `input_new` - Candle entering the window
+/// * `input_old` - Candle leaving the window, or `None` while the window is still filling
+///
+/// # Returns
+/// * `TAFloat` - Updated range sum
+#[must_use]
+pub fn candle_range_sum_inc(
+    setting: &CandleSetting,
+    prev_sum: TAFloat,
+    input_new: &Bar,
+    input_old: Option<&Bar>,
+) -> TAFloat {
+    let old = input_old.map_or(0.0, |bar| setting.range(bar));
+    prev_sum + setting.range(input_new) - old
+}
+
+/// Streaming rolling candle average of a [`CandleSetting`]
+///
+/// Query [`CandleAverage::value`] for the threshold of a new candle, then
+/// [`CandleAverage::push`] the candle so it counts towards later averages.
+///
+/// # Example
+/// ```
+/// use kand::{
+///     Bar,
+///     helper::{CandleAverage, CandleRangeType, CandleSetting},
+/// };
+///
+/// let mut body = CandleAverage::new(CandleSetting::new(CandleRangeType::RealBody, 2, 1.0));
+/// body.push(&Bar::new(10.0, 11.5, 9.5, 11.0, 0.0));
+/// body.push(&Bar::new(11.0, 12.5, 10.5, 12.0, 0.0));
+///
+/// let next = Bar::new(12.0, 13.5, 11.5, 14.0, 0.0);
+/// assert_eq!(body.value(&next), 1.0);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandleAverage {
+    setting: CandleSetting,
+    window: VecDeque<Bar>,
+    sum: TAFloat,
+}
+
+impl CandleAverage {
+    /// Creates an empty rolling average for `setting`.
+    #[must_use]
+    pub fn new(setting: CandleSetting) -> Self {
+        Self {
+            setting,
+            window: VecDeque::with_capacity(setting.avg_period),
+            sum: 0.0,
+        }
+    }
+
+    /// Threshold for `input` from the candles pushed so far; NaN until `avg_period` candles have
+    /// been pushed.
+    #[must_use]
+    pub fn value(&self, input: &Bar) -> TAFloat {
+        if self.is_ready() {
+            self.setting.average(self.sum, input)
+        } else {
+            TAFloat::NAN
+        }
+    }
+
+    /// Adds `input` to the window, dropping the oldest candle once the window is full.
+    pub fn push(&mut self, input: &Bar) {
+        if self.setting.avg_period == 0 {
+            return;
+        }
+        let old = if self.window.len() == self.setting.avg_period {
+            self.window.pop_front()
+        } else {
+            None
+        };
+        self.sum = candle_range_sum_inc(&self.setting, self.sum, input, old.as_ref());
+        self.window.push_back(*input);
+    }
+
+    /// Whether `avg_period` candles have been pushed.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.window.len() >= self.setting.avg_period
+    }
+
+    /// Removes all candles from the window.
+    pub fn clear(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+    }
+}
+
+/// Candle average thresholds of every [`CandleSettings`] entry for a single candle
+///
+/// Each field holds the value [`CandleSetting::average`] yields for the candle from the candles
+/// before it, or NaN while that setting's `avg_period` has not yet elapsed.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandleThresholds {
+    /// Threshold of [`CandleSettings::body_long`]
+    pub body_long: TAFloat,
+    /// Threshold of [`CandleSettings::body_very_long`]
+    pub body_very_long: TAFloat,
+    /// Threshold of [`CandleSettings::body_short`]
+    pub body_short: TAFloat,
+    /// Threshold of [`CandleSettings::body_doji`]
+    pub body_doji: TAFloat,
+    /// Threshold of [`CandleSettings::shadow_long`]
+    pub shadow_long: TAFloat,
+    /// Threshold of [`CandleSettings::shadow_very_long`]
+    pub shadow_very_long: TAFloat,
+    /// Threshold of [`CandleSettings::shadow_short`]
+    pub shadow_short: TAFloat,
+    /// Threshold of [`CandleSettings::shadow_very_short`]
+    pub shadow_very_short: TAFloat,
+    /// Threshold of [`CandleSettings::near`]
+    pub near: TAFloat,
+    /// Threshold of [`CandleSettings::far`]
+    pub far: TAFloat,
+    /// Threshold of [`CandleSettings::equal`]
+    pub equal: TAFloat,
+}
+
+/// Streaming rolling candle averages for a full [`CandleSettings`]
+///
+/// Works like [`CandleAverage`] for all settings at once: query [`CandleAverages::value`] for the
+/// thresholds of a new candle, then [`CandleAverages::push`] the candle.
+///
+/// # Example
+/// ```
+/// use kand::{
+///     Bar,
+///     helper::{CandleAverages, CandleSettings},
+/// };
+///
+/// let mut averages = CandleAverages::new(&CandleSettings::default());
+/// for _ in 0..10 {
+///     averages.push(&Bar::new(10.0, 10.5, 9.5, 10.2, 0.0));
+/// }
+///
+/// let thresholds = averages.value(&Bar::new(10.0, 10.6, 9.4, 10.05, 0.0));
+/// assert!((thresholds.body_doji - 0.1).abs() < 1e-12);
+/// assert!(thresholds.near.is_finite());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandleAverages {
+    body_long: CandleAverage,
+    body_very_long: CandleAverage,
+    body_short: CandleAverage,
+    body_doji: CandleAverage,
+    shadow_long: CandleAverage,
+    shadow_very_long: CandleAverage,
+    shadow_short: CandleAverage,
+    shadow_very_short: CandleAverage,
+    near: CandleAverage,
+    far: CandleAverage,
+    equal: CandleAverage,
+}
+
+impl CandleAverages {
+    /// Creates empty rolling averages for every entry of `settings`.
+    #[must_use]
+    pub fn new(settings: &CandleSettings) -> Self {
+        Self {
+            body_long: CandleAverage::new(settings.body_long),
+            body_very_long: CandleAverage::new(settings.body_very_long),
+            body_short: CandleAverage::new(settings.body_short),
+            body_doji: CandleAverage::new(settings.body_doji),
+            shadow_long: CandleAverage::new(settings.shadow_long),
+            shadow_very_long: CandleAverage::new(settings.shadow_very_long),
+            shadow_short: CandleAverage::new(settings.shadow_short),
+            shadow_very_short: CandleAverage::new(settings.shadow_very_short),
+            near: CandleAverage::new(settings.near),
+            far: CandleAverage::new(settings.far),
+            equal: CandleAverage::new(settings.equal),
+        }
+    }
+
+    /// Thresholds for `input` from the candles pushed so far.
+    #[must_use]
+    pub fn value(&self, input: &Bar) -> CandleThresholds {
+        CandleThresholds {
+            body_long: self.body_long.value(input),
+            body_very_long: self.body_very_long.value(input),
+            body_short: self.body_short.value(input),
+            body_doji: self.body_doji.value(input),
+            shadow_long: self.shadow_long.value(input),
+            shadow_very_long: self.shadow_very_long.value(input),
+            shadow_short: self.shadow_short.value(input),
+            shadow_very_short: self.shadow_very_short.value(input),
+            near: self.near.value(input),
+            far: self.far.value(input),
+            equal: self.equal.value(input),
+        }
+    }
+
+    /// Adds `input` to every rolling window.
+    pub fn push(&mut self, input: &Bar) {
+        self.body_long.push(input);
+        self.body_very_long.push(input);
+        self.body_short.push(input);
+        self.body_doji.push(input);
+        self.shadow_long.push(input);
+        self.shadow_very_long.push(input);
+        self.shadow_short.push(input);
+        self.shadow_very_short.push(input);
+        self.near.push(input);
+        self.far.push(input);
+        self.equal.push(input);
+    }
+
+    /// Removes all candles from every rolling window.
+    pub fn clear(&mut self) {
+        self.body_long.clear();
+        self.body_very_long.clear();
+        self.body_short.clear();
+        self.body_doji.clear();
+        self.shadow_long.clear();
+        self.shadow_very_long.clear();
+        self.shadow_short.clear();
+        self.shadow_very_short.clear();
+        self.near.clear();
+        self.far.clear();
+        self.equal.clear();
+    }
+}
+
+/// Calculate the [`CandleThresholds`] of every candle in one pass
+///
+/// # Arguments
+/// * `settings` - Candle settings to evaluate
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `output_thresholds` - Output array; entry `i` holds the thresholds for candle `i`, matching
+///   [`candle_average`] for each setting
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::LengthMismatch` - If input and output arrays have different lengths
+pub fn candle_thresholds(
+    settings: &CandleSettings,
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    output_thresholds: &mut [CandleThresholds],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    if len != input_high.len()
+        || len != input_low.len()
+        || len != input_close.len()
+        || len != output_thresholds.len()
+    {
+        return Err(KandError::LengthMismatch);
+    }
+
+    let mut averages = CandleAverages::new(settings);
+    for (i, thresholds) in output_thresholds.iter_mut().enumerate() {
+        let bar = Bar::new(
+            input_open[i],
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            0.0,
+        );
+        *thresholds = averages.value(&bar);
+        averages.push(&bar);
+    }
+
+    Ok(())
+}
+
+/// Collect the `N` candle thresholds ending at `end`, ordered from oldest to newest
+///
+/// Counterpart of [`bar_window`] for the output of [`candle_thresholds`].
+///
+/// # Arguments
+/// * `thresholds` - Array of per-candle thresholds
+/// * `end` - Index of the newest candle in the window; must be at least `N - 1`
+///
+/// # Returns
+/// * `[CandleThresholds; N]` - Thresholds `end + 1 - N ..= end`
+#[must_use]
+pub fn threshold_window<const N: usize>(
+    thresholds: &[CandleThresholds],
+    end: usize,
+) -> [CandleThresholds; N] {
+    core::array::from_fn(|k| thresholds[end + 1 + k - N])
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_candle_average_streaming_matches_batch() {
+        let input_open = vec![
+            10.0, 10.2, 10.1, 10.6, 10.4, 10.9, 11.3, 11.0, 10.8, 11.2, 11.6, 11.4, 11.9, 12.3,
+        ];
+        let input_high = vec![
+            10.5, 10.4, 10.8, 10.9, 11.0, 11.5, 11.4, 11.2, 11.3, 11.8, 11.9, 12.0, 12.5, 12.4,
+        ];
+        let input_low = vec![
+            9.8, 9.9, 10.0, 10.2, 10.3, 10.7, 10.9, 10.6, 10.5, 11.0, 11.2, 11.1, 11.7, 11.8,
+        ];
+        let input_close = vec![
+            10.2, 10.1, 10.6, 10.4, 10.9, 11.3, 11.0, 10.8, 11.2, 11.6, 11.4, 11.9, 12.3, 12.0,
+        ];
+        let len = input_open.len();
+        let settings = CandleSettings {
+            body_long: CandleSetting::new(CandleRangeType::RealBody, 4, 1.0),
+            ..CandleSettings::default()
+        };
+
+        for setting in [
+            settings.body_long,
+            settings.body_doji,
+            settings.shadow_long,
+            settings.shadow_short,
+            settings.near,
+        ] {
+            let mut output_average = vec![0.0; len];
+            candle_average(
+                &setting,
+                &input_open,
+                &input_high,
+                &input_low,
+                &input_close,
+                &mut output_average,
+            )
+            .unwrap();
+
+            let mut average = CandleAverage::new(setting);
+            for i in 0..len {
+                let bar = Bar::new(
+                    input_open[i],
+                    input_high[i],
+                    input_low[i],
+                    input_close[i],
+                    0.0,
+                );
+                let value = average.value(&bar);
+                if i < setting.avg_period {
+                    assert!(value.is_nan());
+                    assert!(output_average[i].is_nan());
+                } else {
+                    assert_relative_eq!(value, output_average[i], epsilon = 1e-12);
+                }
+                average.push(&bar);
+            }
+
+            average.clear();
+            assert_eq!(average.is_ready(), setting.avg_period == 0);
+        }
+
+        let mut output_thresholds = vec![CandleThresholds::default(); len];
+        candle_thresholds(
+            &settings,
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &mut output_thresholds,
+        )
+        .unwrap();
+        let mut output_near = vec![0.0; len];
+        candle_average(
+            &settings.near,
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &mut output_near,
+        )
+        .unwrap();
+        for i in 0..len {
+            if i < settings.near.avg_period {
+                assert!(output_thresholds[i].near.is_nan());
+            } else {
+                assert_relative_eq!(output_thresholds[i].near, output_near[i], epsilon = 1e-12);
+            }
+            assert_relative_eq!(
+                output_thresholds[i].shadow_long,
+                (input_close[i] - input_open[i]).abs(),
+                epsilon = 1e-12
+            );
+        }
+    }
+}
@@ -38,7 +38,7 @@
 //! let new_price = 12.0; // New price to include
 //! let old_price = 6.0; // Oldest price to remove
 //!
-//! let next_sma = sma::sma_inc(prev_sma, new_price, old_price, period).unwrap();
+//! let next_sma = sma::sma_inc(new_price, old_price, prev_sma, period).unwrap();
 //! // next_sma = 10.0 ((8.0 + 10.0 + 12.0) / 3)
 //! ```
 //!
@@ -61,6 +61,10 @@
 //! - `check`: Enable basic validation checks
 //! - `check-nan = ["check"]`: Enable extended validation (includes basic checks)
 //!
+//! ### Serialization
+//! - `serde`: Derive `Serialize`/`Deserialize` for every streaming indicator, so its state can be
+//!   snapshotted and restored (see [`Indicator`])
+//!
 //! ## Safety and Error Handling
 //!
 //! All functions in Kand return a `Result` type, properly handling edge cases and
@@ -84,6 +88,7 @@
 
 pub mod ta;
 pub use ta::*;
+pub use ta::{indicator::Indicator, types::Bar};
 
 pub mod helper;
 
@@ -0,0 +1,428 @@
+use std::collections::VecDeque;
+
+use crate::TAFloat;
+
+/// Hilbert transform FIR coefficients used by Ehlers (and TA-Lib).
+const A: TAFloat = 0.0962;
+const B: TAFloat = 0.5769;
+
+/// One Hilbert transform FIR stage.
+///
+/// TA-Lib keeps separate three-slot histories for odd and even bars and only advances the slot
+/// index on even bars; reproducing that bookkeeping is what makes the outputs match.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct HilbertStage {
+    odd: [TAFloat; 3],
+    even: [TAFloat; 3],
+    prev_odd: TAFloat,
+    prev_even: TAFloat,
+    prev_input_odd: TAFloat,
+    prev_input_even: TAFloat,
+}
+
+impl HilbertStage {
+    fn step(
+        &mut self,
+        input: TAFloat,
+        idx: usize,
+        is_even: bool,
+        adjusted_prev_period: TAFloat,
+    ) -> TAFloat {
+        let (history, prev, prev_input) = if is_even {
+            (
+                &mut self.even,
+                &mut self.prev_even,
+                &mut self.prev_input_even,
+            )
+        } else {
+            (&mut self.odd, &mut self.prev_odd, &mut self.prev_input_odd)
+        };
+
+        let weighted = A * input;
+        let mut value = weighted - history[idx];
+        history[idx] = weighted;
+        value -= *prev;
+        *prev = B * *prev_input;
+        value += *prev;
+        *prev_input = input;
+        value * adjusted_prev_period
+    }
+}
+
+/// Values produced by [`HilbertTransform::update`] for one bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HilbertStep {
+    /// 4-bar weighted moving average of the price.
+    pub smoothed: TAFloat,
+    /// In-phase component: the detrended price delayed by three bars.
+    pub in_phase: TAFloat,
+    /// Quadrature component.
+    pub quadrature: TAFloat,
+    /// Dominant cycle period measured by the homodyne discriminator, after this bar.
+    pub period: TAFloat,
+}
+
+/// Ehlers' Hilbert transform with homodyne discriminator, as used by TA-Lib's MAMA and `HT_*`.
+///
+/// Prices are smoothed with a 4-bar WMA, detrended and split into in-phase and quadrature
+/// components; the discriminator then measures the dominant cycle period. The first three inputs
+/// and the following `warmup` inputs only prime the smoother, so [`Self::update`] returns `None`
+/// for the first `warmup + 3` inputs. TA-Lib uses a `warmup` of 9 for MAMA, `HT_DCPERIOD` and
+/// `HT_PHASOR`, and 34 for the other `HT_*` functions.
+///
+/// Odd and even bars are processed with separate filter histories, counted from the first input,
+/// so a stream reproduces the batch output only when both start on the same bar.
+///
+/// # Example
+/// ```
+/// use kand::hilbert::HilbertTransform;
+///
+/// let mut hilbert = HilbertTransform::new(9);
+/// let steps: Vec<_> = (0..40)
+///     .map(|i| hilbert.update(100.0 + (i as f64 * 0.4).sin()))
+///     .collect();
+/// assert!(steps[11].is_none());
+/// assert!(steps[12].is_some());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HilbertTransform {
+    warmup: usize,
+    count: usize,
+    prices: VecDeque<TAFloat>,
+    wma_sub: TAFloat,
+    wma_sum: TAFloat,
+    trailing_wma_value: TAFloat,
+    hilbert_idx: usize,
+    detrender: HilbertStage,
+    q1: HilbertStage,
+    ji: HilbertStage,
+    jq: HilbertStage,
+    i1_odd_prev2: TAFloat,
+    i1_odd_prev3: TAFloat,
+    i1_even_prev2: TAFloat,
+    i1_even_prev3: TAFloat,
+    prev_i2: TAFloat,
+    prev_q2: TAFloat,
+    re: TAFloat,
+    im: TAFloat,
+    period: TAFloat,
+}
+
+impl HilbertTransform {
+    /// Creates a transform that primes its price smoother for `warmup` bars after the first three.
+    #[must_use]
+    pub fn new(warmup: usize) -> Self {
+        Self {
+            warmup,
+            count: 0,
+            prices: VecDeque::with_capacity(4),
+            wma_sub: 0.0,
+            wma_sum: 0.0,
+            trailing_wma_value: 0.0,
+            hilbert_idx: 0,
+            detrender: HilbertStage::default(),
+            q1: HilbertStage::default(),
+            ji: HilbertStage::default(),
+            jq: HilbertStage::default(),
+            i1_odd_prev2: 0.0,
+            i1_odd_prev3: 0.0,
+            i1_even_prev2: 0.0,
+            i1_even_prev3: 0.0,
+            prev_i2: 0.0,
+            prev_q2: 0.0,
+            re: 0.0,
+            im: 0.0,
+            period: 0.0,
+        }
+    }
+
+    /// Feeds the next price. Returns `None` while the price smoother is still being primed.
+    pub fn update(&mut self, input_price: TAFloat) -> Option<HilbertStep> {
+        let today = self.count;
+        self.count += 1;
+        self.prices.push_back(input_price);
+
+        if today < 3 {
+            // Seed the running sums of the 4-bar WMA with weights 1, 2, 3
+            self.wma_sub += input_price;
+            self.wma_sum += input_price * (today + 1) as TAFloat;
+            return None;
+        }
+
+        let adjusted_prev_period = self.period.mul_add(0.075, 0.54);
+        let smoothed = self.smooth(input_price);
+        if today < self.warmup + 3 {
+            return None;
+        }
+
+        let idx = self.hilbert_idx;
+        let is_even = today.is_multiple_of(2);
+        let i1 = if is_even {
+            self.i1_even_prev3
+        } else {
+            self.i1_odd_prev3
+        };
+
+        let detrender = self
+            .detrender
+            .step(smoothed, idx, is_even, adjusted_prev_period);
+        let q1 = self.q1.step(detrender, idx, is_even, adjusted_prev_period);
+        let ji = self.ji.step(i1, idx, is_even, adjusted_prev_period);
+        let jq = self.jq.step(q1, idx, is_even, adjusted_prev_period);
+
+        if is_even {
+            self.hilbert_idx = (self.hilbert_idx + 1) % 3;
+            self.i1_odd_prev3 = self.i1_odd_prev2;
+            self.i1_odd_prev2 = detrender;
+        } else {
+            self.i1_even_prev3 = self.i1_even_prev2;
+            self.i1_even_prev2 = detrender;
+        }
+
+        let q2 = (q1 + ji).mul_add(0.2, 0.8 * self.prev_q2);
+        let i2 = (i1 - jq).mul_add(0.2, 0.8 * self.prev_i2);
+
+        // Homodyne discriminator
+        self.re = i2
+            .mul_add(self.prev_i2, q2 * self.prev_q2)
+            .mul_add(0.2, 0.8 * self.re);
+        self.im = i2
+            .mul_add(self.prev_q2, -(q2 * self.prev_i2))
+            .mul_add(0.2, 0.8 * self.im);
+        self.prev_q2 = q2;
+        self.prev_i2 = i2;
+
+        let prev_period = self.period;
+        let period = if self.im != 0.0 && self.re != 0.0 {
+            360.0 / (self.im / self.re).atan().to_degrees()
+        } else {
+            prev_period
+        };
+        let period = period
+            .min(1.5 * prev_period)
+            .max(0.67 * prev_period)
+            .clamp(6.0, 50.0);
+        self.period = period.mul_add(0.2, 0.8 * prev_period);
+
+        Some(HilbertStep {
+            smoothed,
+            in_phase: i1,
+            quadrature: q1,
+            period: self.period,
+        })
+    }
+
+    /// Dominant cycle period after the last input (0 until the transform has run).
+    #[must_use]
+    pub const fn period(&self) -> TAFloat {
+        self.period
+    }
+
+    /// Number of inputs consumed so far.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Clears all state, keeping the configured `warmup`.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.warmup);
+    }
+
+    /// Advances the 4-bar WMA (weights 4, 3, 2, 1) with the same running sums as TA-Lib.
+    fn smooth(&mut self, input_price: TAFloat) -> TAFloat {
+        self.wma_sub += input_price - self.trailing_wma_value;
+        self.wma_sum += input_price * 4.0;
+        self.trailing_wma_value = self.prices.pop_front().unwrap_or_default();
+        let smoothed = self.wma_sum * 0.1;
+        self.wma_sum -= self.wma_sub;
+        smoothed
+    }
+}
+
+/// Number of bars kept for the dominant cycle phase and trendline, as in TA-Lib.
+const CYCLE_BUFFER_SIZE: usize = 50;
+
+/// Bars that prime [`DominantCycle`]'s price smoother, as in TA-Lib's `HT_DCPHASE`, `HT_SINE`,
+/// `HT_TRENDLINE` and `HT_TRENDMODE`.
+const CYCLE_SMOOTHER_WARMUP: usize = 34;
+
+/// Values produced by [`DominantCycle::update`] for one bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CycleStep {
+    /// 4-bar weighted moving average of the price.
+    pub smoothed: TAFloat,
+    /// Smoothed dominant cycle period, as output by TA-Lib's `HT_DCPERIOD`.
+    pub period: TAFloat,
+    /// Dominant cycle phase in degrees, within `(-45, 315]`.
+    pub phase: TAFloat,
+    /// Dominant cycle phase of the previous bar (0 before the first one).
+    pub prev_phase: TAFloat,
+    /// Sine of the phase.
+    pub sine: TAFloat,
+    /// Sine of the phase advanced by 45 degrees.
+    pub lead_sine: TAFloat,
+    /// Sine of the previous bar (0 before the first one).
+    pub prev_sine: TAFloat,
+    /// Lead sine of the previous bar (0 before the first one).
+    pub prev_lead_sine: TAFloat,
+    /// Instantaneous trendline: a 4-bar WMA of the price averaged over one dominant cycle.
+    pub trendline: TAFloat,
+}
+
+/// Dominant cycle measurements shared by TA-Lib's `HT_DCPHASE`, `HT_SINE`, `HT_TRENDLINE` and
+/// `HT_TRENDMODE`.
+///
+/// Wraps a [`HilbertTransform`] with a warmup of 34 and, on each bar it produces, smooths its
+/// period (`0.33 * period + 0.67 * previous`), correlates the last smoothed prices of one cycle
+/// with a sine and cosine to find the phase, and averages the raw prices of one cycle into the
+/// instantaneous trendline. [`Self::update`] returns `None` for the first 37 inputs; TA-Lib only
+/// reports these functions from index 63 onwards, once the measurements have settled.
+///
+/// # Example
+/// ```
+/// use kand::hilbert::DominantCycle;
+///
+/// let mut cycle = DominantCycle::new();
+/// let steps: Vec<_> = (0..80)
+///     .map(|i| cycle.update(100.0 + (i as f64 * 0.4).sin()))
+///     .collect();
+/// assert!(steps[36].is_none());
+/// assert!(steps[63].unwrap().period > 0.0);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DominantCycle {
+    hilbert: HilbertTransform,
+    prices: VecDeque<TAFloat>,
+    smoothed_prices: VecDeque<TAFloat>,
+    smooth_period: TAFloat,
+    phase: TAFloat,
+    sine: TAFloat,
+    lead_sine: TAFloat,
+    trend: [TAFloat; 3],
+}
+
+impl DominantCycle {
+    /// Creates an empty dominant cycle measurement.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            hilbert: HilbertTransform::new(CYCLE_SMOOTHER_WARMUP),
+            prices: VecDeque::with_capacity(CYCLE_BUFFER_SIZE + 1),
+            smoothed_prices: VecDeque::with_capacity(CYCLE_BUFFER_SIZE + 1),
+            smooth_period: 0.0,
+            phase: 0.0,
+            sine: 0.0,
+            lead_sine: 0.0,
+            trend: [0.0; 3],
+        }
+    }
+
+    /// Feeds the next price. Returns `None` while the Hilbert transform is still being primed.
+    pub fn update(&mut self, input_price: TAFloat) -> Option<CycleStep> {
+        push_bounded(&mut self.prices, input_price);
+        let step = self.hilbert.update(input_price)?;
+        push_bounded(&mut self.smoothed_prices, step.smoothed);
+
+        self.smooth_period = step.period.mul_add(0.33, 0.67 * self.smooth_period);
+        let cycle_len = cycle_length(self.smooth_period);
+
+        let prev_phase = self.phase;
+        self.phase = self.next_phase(cycle_len);
+        let (prev_sine, prev_lead_sine) = (self.sine, self.lead_sine);
+        self.sine = self.phase.to_radians().sin();
+        self.lead_sine = (self.phase + 45.0).to_radians().sin();
+
+        // Simple average of the raw prices over one cycle, then a 4-bar WMA of those averages
+        let mut average = self.prices.iter().rev().take(cycle_len).sum::<TAFloat>();
+        if cycle_len > 0 {
+            average /= cycle_len as TAFloat;
+        }
+        let trendline =
+            (4.0 * average + 3.0 * self.trend[0] + 2.0 * self.trend[1] + self.trend[2]) / 10.0;
+        self.trend = [average, self.trend[0], self.trend[1]];
+
+        Some(CycleStep {
+            smoothed: step.smoothed,
+            period: self.smooth_period,
+            phase: self.phase,
+            prev_phase,
+            sine: self.sine,
+            lead_sine: self.lead_sine,
+            prev_sine,
+            prev_lead_sine,
+            trendline,
+        })
+    }
+
+    /// Number of inputs consumed so far.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.hilbert.count()
+    }
+
+    /// Clears all state.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Dominant cycle phase from a one-cycle DFT of the smoothed prices, newest first.
+    ///
+    /// When the cosine part vanishes, TA-Lib nudges the previous phase by 90 degrees instead.
+    fn next_phase(&self, cycle_len: usize) -> TAFloat {
+        let (mut real_part, mut imag_part) = (0.0, 0.0);
+        for (i, &smoothed) in self
+            .smoothed_prices
+            .iter()
+            .rev()
+            .take(cycle_len)
+            .enumerate()
+        {
+            let angle = (i as TAFloat * 360.0 / cycle_len as TAFloat).to_radians();
+            real_part += angle.sin() * smoothed;
+            imag_part += angle.cos() * smoothed;
+        }
+
+        let mut phase = self.phase;
+        if imag_part != 0.0 {
+            phase = (real_part / imag_part).atan().to_degrees();
+        } else if real_part < 0.0 {
+            phase -= 90.0;
+        } else if real_part > 0.0 {
+            phase += 90.0;
+        }
+        phase += 90.0;
+
+        // Compensate for the one-bar lag of the price smoother
+        phase += 360.0 / self.smooth_period;
+        if imag_part < 0.0 {
+            phase += 180.0;
+        }
+        if phase > 315.0 {
+            phase -= 360.0;
+        }
+        phase
+    }
+}
+
+impl Default for DominantCycle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whole bars in one dominant cycle, rounded like TA-Lib's `(int)(period + 0.5)`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn cycle_length(smooth_period: TAFloat) -> usize {
+    (smooth_period + 0.5) as usize
+}
+
+fn push_bounded(buffer: &mut VecDeque<TAFloat>, value: TAFloat) {
+    if buffer.len() == CYCLE_BUFFER_SIZE {
+        buffer.pop_front();
+    }
+    buffer.push_back(value);
+}
@@ -0,0 +1,258 @@
+use crate::{KandError, types::Bar};
+
+/// Common interface for streaming indicators that own their state.
+///
+/// Every module with an `*_inc` function also provides a struct implementing this trait
+/// (for example [`crate::ohlcv::rsi::Rsi`]). The struct keeps whatever the `*_inc` function
+/// would otherwise require the caller to carry between calls, so a live feed only needs to
+/// hand it one input at a time.
+///
+/// Outputs follow the batch functions exactly: values produced before the lookback period
+/// is complete are `NaN` (or the same placeholder signal the batch function writes), and
+/// folding [`Indicator::update`] over a series reproduces the batch output bar for bar.
+///
+/// For live charts, call [`Indicator::revise`] on every tick of the forming bar and
+/// [`Indicator::push`] once it closes; revisions never touch the committed state.
+///
+/// With the `serde` feature, every streaming struct implements `Serialize` and `Deserialize`.
+/// A restored snapshot continues with bit-identical output, so a restarted process does not
+/// need to re-warm long lookbacks from history. Warm-up state may hold `NaN`, which JSON writes
+/// as `null`; snapshot after [`Indicator::is_ready`] or use a format that keeps non-finite floats.
+///
+/// Most indicators consume a [`Bar`]; those that need two aligned series (such as
+/// [`crate::stats::correl::Correl`]) take a `(TAFloat, TAFloat)` pair instead.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::sma::Sma};
+///
+/// let mut sma = Sma::new(3).unwrap();
+/// let outputs: Vec<_> = [2.0, 4.0, 6.0, 8.0]
+///     .iter()
+///     .map(|&price| sma.update(&Bar::from(price)).unwrap())
+///     .collect();
+///
+/// assert!(outputs[1].is_nan());
+/// assert_eq!(outputs[2], 4.0);
+/// assert_eq!(outputs[3], 6.0);
+/// assert!(sma.is_ready());
+///
+/// // A forming bar can be revised without affecting the committed window
+/// assert_eq!(sma.revise(&Bar::from(16.0)).unwrap(), 10.0);
+/// assert_eq!(sma.push(&Bar::from(10.0)).unwrap(), 8.0);
+/// ```
+pub trait Indicator<I = Bar> {
+    /// Value produced for each input.
+    type Output;
+
+    /// Feeds the next input and returns the indicator value for it.
+    ///
+    /// # Errors
+    /// Propagates any error from the underlying `*_inc` calculation, such as
+    /// `KandError::NaNDetected` when the "`check-nan`" feature is enabled.
+    fn update(&mut self, input: &I) -> Result<Self::Output, KandError>;
+
+    /// Number of inputs consumed before the first valid output.
+    fn lookback(&self) -> usize;
+
+    /// Returns `true` once the indicator has produced a valid output.
+    fn is_ready(&self) -> bool;
+
+    /// Clears all accumulated state, as if the indicator had just been constructed.
+    fn reset(&mut self);
+
+    /// Commits a closed input. Equivalent to [`Indicator::update`]; the name pairs with
+    /// [`Indicator::revise`] in live feeds.
+    ///
+    /// # Errors
+    /// Same as [`Indicator::update`].
+    fn push(&mut self, input: &I) -> Result<Self::Output, KandError> {
+        self.update(input)
+    }
+
+    /// Computes the value for a still-forming input without committing it.
+    ///
+    /// The value is always derived from the last committed state, so a forming bar can be
+    /// revised any number of times and the final [`Indicator::push`] still matches the batch
+    /// output. This matters for path-dependent indicators such as EMA, ATR, ADX or SAR, where
+    /// calling [`Indicator::update`] on every tick would fold intermediate prices into the state.
+    ///
+    /// # Cost
+    /// The default implementation clones `self` and calls [`Indicator::update`] on the copy. For
+    /// recursive indicators such as EMA or ATR the copy is a handful of floats, but structs that
+    /// keep a window ([`crate::ohlcv::ichimoku::Ichimoku`], [`crate::ohlcv::vegas::Vegas`],
+    /// [`crate::ohlcv::cdl_scan::CdlScanner`] and the `VecDeque` or order-statistic backed
+    /// windows) allocate and copy all of it, O(period) per revision. On high-frequency feeds,
+    /// revise those on a throttled cadence rather than on every tick.
+    ///
+    /// # Errors
+    /// Same as [`Indicator::update`].
+    fn revise(&self, input: &I) -> Result<Self::Output, KandError>
+    where
+        Self: Clone,
+    {
+        self.clone().update(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TAFloat,
+        ohlcv::{adx::Adx, atr::Atr, ema::Ema, rma::Rma, sar::Sar, supertrend::Supertrend},
+    };
+
+    fn bars() -> Vec<Bar> {
+        let input_open = [
+            35230.0, 35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9,
+            35197.6, 35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3,
+            35013.4, 35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8,
+        ];
+        let input_high = [
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = [
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = [
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        (0..input_open.len())
+            .map(|i| {
+                Bar::new(
+                    input_open[i],
+                    input_high[i],
+                    input_low[i],
+                    input_close[i],
+                    0.0,
+                )
+            })
+            .collect()
+    }
+
+    /// Ticks a forming bar would go through before closing as `bar`.
+    fn ticks(bar: &Bar) -> [Bar; 3] {
+        [
+            Bar::new(bar.open, bar.open, bar.open, bar.open, 0.0),
+            Bar::new(bar.open, bar.high + 25.0, bar.low, bar.high + 20.0, 0.0),
+            Bar::new(bar.open, bar.high, bar.low - 25.0, bar.low - 20.0, 0.0),
+        ]
+    }
+
+    fn assert_revise_then_push<T>(indicator: T)
+    where
+        T: Indicator<Output = TAFloat> + Clone,
+    {
+        let mut committed = indicator.clone();
+        let mut live = indicator;
+        for bar in &bars() {
+            let expected = committed.update(bar).unwrap();
+            for tick in &ticks(bar) {
+                live.revise(tick).unwrap();
+            }
+            let revised = live.revise(bar).unwrap();
+            let pushed = live.push(bar).unwrap();
+            assert!((expected.is_nan() && pushed.is_nan()) || expected == pushed);
+            assert!((revised.is_nan() && pushed.is_nan()) || revised == pushed);
+            assert_eq!(live.is_ready(), committed.is_ready());
+        }
+    }
+
+    #[test]
+    fn test_revise_does_not_commit() {
+        assert_revise_then_push(Ema::new(5, None).unwrap());
+        assert_revise_then_push(Rma::new(5).unwrap());
+        assert_revise_then_push(Atr::new(5).unwrap());
+        assert_revise_then_push(Adx::new(5).unwrap());
+        assert_revise_then_push(Sar::new(0.02, 0.2).unwrap());
+    }
+
+    #[test]
+    fn test_revise_supertrend() {
+        let mut committed = Supertrend::new(5, 3.0).unwrap();
+        let mut live = committed.clone();
+        for bar in &bars() {
+            let (expected_trend, expected_line) = committed.update(bar).unwrap();
+            for tick in &ticks(bar) {
+                live.revise(tick).unwrap();
+            }
+            let (trend, line) = live.push(bar).unwrap();
+            assert_eq!(trend, expected_trend);
+            assert!((expected_line.is_nan() && line.is_nan()) || expected_line == line);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn assert_restore_matches<T>(indicator: T, split: usize)
+    where
+        T: Indicator + serde::Serialize + serde::de::DeserializeOwned,
+        T::Output: std::fmt::Debug,
+    {
+        let bars: Vec<Bar> = (0..800)
+            .map(|i| {
+                let t = i as TAFloat;
+                let close = (t * 0.31)
+                    .sin()
+                    .mul_add(3.0, (t * 0.05).sin().mul_add(10.0, 100.0));
+                let open = close - (t * 0.17).cos();
+                let high = open.max(close) + 1.0 + (t * 0.7).sin().abs();
+                let low = open.min(close) - 1.0 - (t * 0.4).cos().abs();
+                Bar::new(open, high, low, close, 1000.0)
+            })
+            .collect();
+
+        let mut running = indicator;
+        for bar in &bars[..split] {
+            running.update(bar).unwrap();
+        }
+        let snapshot = serde_json::to_string(&running).unwrap();
+        let mut restored: T = serde_json::from_str(&snapshot).unwrap();
+        assert_eq!(restored.is_ready(), running.is_ready());
+
+        // `Debug` prints the shortest representation that round-trips, so equal strings mean
+        // bit-identical floats.
+        for bar in &bars[split..] {
+            let expected = running.update(bar).unwrap();
+            let value = restored.update(bar).unwrap();
+            assert_eq!(format!("{value:?}"), format!("{expected:?}"));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_restore() {
+        use crate::{
+            ohlcv::{
+                cci::Cci, ht_sine::HtSine, ht_trendmode::HtTrendmode, macd::Macd, macdext::Macdext,
+                mama::Mama, midprice::Midprice, t3::T3, tsf::Tsf, vegas::Vegas,
+            },
+            stats::stddev::Stddev,
+            types::MAType,
+        };
+
+        assert_restore_matches(Vegas::new().unwrap(), 700);
+        assert_restore_matches(T3::new(5, 0.7).unwrap(), 100);
+        assert_restore_matches(Macd::new(12, 26, 9).unwrap(), 100);
+        assert_restore_matches(Adx::new(14).unwrap(), 100);
+        assert_restore_matches(Sar::new(0.02, 0.2).unwrap(), 100);
+        assert_restore_matches(Supertrend::new(10, 3.0).unwrap(), 100);
+        assert_restore_matches(Cci::new(20).unwrap(), 100);
+        assert_restore_matches(Midprice::new(14).unwrap(), 100);
+        assert_restore_matches(Stddev::new(20).unwrap(), 100);
+        assert_restore_matches(Mama::new(0.5, 0.05).unwrap(), 101);
+        assert_restore_matches(HtSine::new(), 101);
+        assert_restore_matches(HtTrendmode::new(), 150);
+        assert_restore_matches(Tsf::new(14).unwrap(), 100);
+        assert_restore_matches(
+            Macdext::new(12, MAType::KAMA, 26, MAType::T3, 9, MAType::WMA).unwrap(),
+            200,
+        );
+    }
+}
@@ -1,4 +1,7 @@
+pub mod hilbert;
+pub mod indicator;
 pub mod ohlcv;
 pub mod stats;
 
 pub mod types;
+pub mod window;
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat, TAPeriod};
+use crate::{KandError, TAFloat, TAPeriod, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for A/D calculation.
 ///
@@ -15,7 +15,6 @@
 /// let lookback = ad::lookback().unwrap();
 /// assert_eq!(lookback, 0);
 /// ```
-#[must_use]
 pub const fn lookback() -> Result<TAPeriod, KandError> {
     Ok(0)
 }
@@ -234,7 +233,6 @@ pub fn ad_inc_raw(
 ///
 /// let output_ad = ad::ad_inc(input_high, input_low, input_close, input_volume, prev_ad).unwrap();
 /// ```
-#[must_use]
 pub fn ad_inc(
     input_high: TAFloat,
     input_low: TAFloat,
@@ -263,6 +261,56 @@ pub fn ad_inc(
     ))
 }
 
+/// Streaming A/D line that owns the running accumulation.
+///
+/// Reads high, low, close and volume from each [`Bar`]. Folding [`Indicator::update`] over a
+/// series reproduces [`ad`].
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ad {
+    prev_ad: TAFloat,
+    count: usize,
+}
+
+impl Ad {
+    /// Creates a streaming A/D line starting from zero.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            prev_ad: 0.0,
+            count: 0,
+        }
+    }
+}
+
+impl Indicator for Ad {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.prev_ad = ad_inc(
+            input.high,
+            input.low,
+            input.close,
+            input.volume,
+            self.prev_ad,
+        )?;
+        self.count += 1;
+        Ok(self.prev_ad)
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > 0
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -395,4 +443,26 @@ fn test_ad_without_nan() {
             prev_ad = result;
         }
     }
+
+    #[test]
+    fn test_ad_indicator() {
+        let mut indicator = Ad::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        assert!(!indicator.is_ready());
+        for (i, &expected) in EXPECTED_VALUES.iter().enumerate() {
+            let bar = Bar::new(
+                INPUT_CLOSE[i],
+                INPUT_HIGH[i],
+                INPUT_LOW[i],
+                INPUT_CLOSE[i],
+                INPUT_VOLUME[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            assert_relative_eq!(value, expected, epsilon = EPSILON);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
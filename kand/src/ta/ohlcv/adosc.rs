@@ -1,5 +1,13 @@
-use super::{ad, ema};
-use crate::{KandError, TAFloat};
+use super::{
+    ad::{self, Ad},
+    ema,
+    ma::{self, Ma},
+};
+use crate::{
+    KandError, TAFloat,
+    indicator::Indicator,
+    types::{Bar, MAType},
+};
 
 /// Returns the lookback period required for A/D Oscillator calculation.
 ///
@@ -16,7 +24,6 @@
 /// let lookback = adosc::lookback(3, 10).unwrap();
 /// assert_eq!(lookback, 9);
 /// ```
-#[must_use]
 pub const fn lookback(opt_fast_period: usize, opt_slow_period: usize) -> Result<usize, KandError> {
     #[cfg(feature = "check")]
     {
@@ -28,6 +35,33 @@ pub const fn lookback(opt_fast_period: usize, opt_slow_period: usize) -> Result<
     ema::lookback(opt_slow_period)
 }
 
+/// Returns the lookback period required for [`adosc_ext`].
+///
+/// The oscillator is valid once both moving averages of the A/D line are, so the lookback is the
+/// larger of their lookbacks.
+///
+/// # Errors
+///
+/// - [`KandError::InvalidParameter`] if fast or slow period is less than 2, or if fast period is not less than slow period (enabled by "check" feature).
+///
+/// # Examples
+///
+/// ```
+/// use kand::{ohlcv::adosc, types::MAType};
+/// assert_eq!(adosc::lookback_ext(3, 10, MAType::EMA).unwrap(), 9);
+/// assert_eq!(adosc::lookback_ext(3, 10, MAType::TEMA).unwrap(), 27);
+/// ```
+pub fn lookback_ext(
+    opt_fast_period: usize,
+    opt_slow_period: usize,
+    opt_ma_type: MAType,
+) -> Result<usize, KandError> {
+    lookback(opt_fast_period, opt_slow_period)?;
+    let fast_lookback = ma::lookback(opt_fast_period, opt_ma_type)?;
+    let slow_lookback = ma::lookback(opt_slow_period, opt_ma_type)?;
+    Ok(fast_lookback.max(slow_lookback))
+}
+
 /// Calculates the Accumulation/Distribution Oscillator (A/D Oscillator or ADOSC) for the entire price series.
 ///
 /// The A/D Oscillator is the difference between fast and slow EMAs of the Accumulation/Distribution (A/D) line.
@@ -149,6 +183,128 @@ pub fn adosc(
     Ok(())
 }
 
+/// Calculates the A/D Oscillator with the selected moving average type for the A/D line.
+///
+/// Same as [`adosc`], but the fast and slow lines are the moving average selected by
+/// `opt_ma_type` instead of EMAs. Values before the lookback are `NaN`.
+///
+/// # Formula
+///
+/// ```text
+/// ADOSC = MA(AD, fast_period, ma_type) - MA(AD, slow_period, ma_type)
+/// ```
+///
+/// # Errors
+///
+/// - [`KandError::InvalidData`] if input arrays are empty (enabled by "check" feature).
+/// - [`KandError::LengthMismatch`] if input/output arrays have different lengths (enabled by "check" feature).
+/// - [`KandError::InvalidParameter`] if periods are invalid (enabled by "check" feature).
+/// - [`KandError::InsufficientData`] if input length <= lookback (enabled by "check" feature).
+/// - [`KandError::NaNDetected`] if any input contains NaN (enabled by "check-nan" feature).
+///
+/// # Examples
+///
+/// ```
+/// use kand::{ohlcv::adosc, types::MAType};
+/// let high = vec![10.0, 11.0, 12.0, 11.5, 10.5, 11.0];
+/// let low = vec![8.0, 9.0, 10.0, 9.5, 8.5, 9.0];
+/// let close = vec![9.0, 10.0, 11.0, 10.0, 9.0, 10.5];
+/// let volume = vec![100.0, 150.0, 200.0, 150.0, 100.0, 120.0];
+/// let mut output_adosc = vec![0.0; 6];
+///
+/// adosc::adosc_ext(
+///     &high,
+///     &low,
+///     &close,
+///     &volume,
+///     2,
+///     3,
+///     MAType::SMA,
+///     &mut output_adosc,
+/// )
+/// .unwrap();
+/// assert!(output_adosc[1].is_nan());
+/// assert!(!output_adosc[2].is_nan());
+/// ```
+pub fn adosc_ext(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    opt_fast_period: usize,
+    opt_slow_period: usize,
+    opt_ma_type: MAType,
+    output_adosc: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback_ext(opt_fast_period, opt_slow_period, opt_ma_type)?;
+
+    #[cfg(feature = "check")]
+    {
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        if len != input_low.len()
+            || len != input_close.len()
+            || len != input_volume.len()
+            || len != output_adosc.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+                || input_volume[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut output_ad = vec![0.0; len];
+    let mut output_ad_fast = vec![0.0; len];
+    let mut output_ad_slow = vec![0.0; len];
+    ad::ad(
+        input_high,
+        input_low,
+        input_close,
+        input_volume,
+        &mut output_ad,
+    )?;
+    ma::ma(
+        &output_ad,
+        opt_fast_period,
+        opt_ma_type,
+        &mut output_ad_fast,
+    )?;
+    ma::ma(
+        &output_ad,
+        opt_slow_period,
+        opt_ma_type,
+        &mut output_ad_slow,
+    )?;
+
+    for i in lookback..len {
+        output_adosc[i] = output_ad_fast[i] - output_ad_slow[i];
+    }
+
+    for value in output_adosc.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
 /// Calculates the latest A/D Oscillator value incrementally using previous values.
 ///
 /// This is an optimized version that computes only the latest ADOSC value, avoiding recalculation of the entire series.
@@ -188,7 +344,6 @@ pub fn adosc(
 /// )
 /// .unwrap();
 /// ```
-#[must_use]
 pub fn adosc_inc(
     input_high: TAFloat,
     input_low: TAFloat,
@@ -234,6 +389,83 @@ pub fn adosc_inc(
     ))
 }
 
+/// Streaming A/D oscillator that owns the A/D line and both of its moving averages.
+///
+/// Reads high, low, close and volume from each [`Bar`]. Folding [`Indicator::update`] over a
+/// series reproduces [`adosc`] (or [`adosc_ext`] when created with [`Adosc::with_ma_type`]) from
+/// its lookback onward; earlier values are `NaN`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adosc {
+    ad: Ad,
+    fast_ma: Ma,
+    slow_ma: Ma,
+}
+
+impl Adosc {
+    /// Creates a streaming A/D oscillator.
+    ///
+    /// # Arguments
+    /// * `opt_fast_period` - Fast EMA period (must be >= 2)
+    /// * `opt_slow_period` - Slow EMA period (must be >= 2 and > fast period)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If either period is invalid
+    pub fn new(opt_fast_period: usize, opt_slow_period: usize) -> Result<Self, KandError> {
+        Self::with_ma_type(opt_fast_period, opt_slow_period, MAType::EMA)
+    }
+
+    /// Creates a streaming A/D oscillator whose lines use the given moving average type.
+    ///
+    /// # Arguments
+    /// * `opt_fast_period` - Fast MA period (must be >= 2)
+    /// * `opt_slow_period` - Slow MA period (must be >= 2 and > fast period)
+    /// * `opt_ma_type` - Moving average type for both lines
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If either period is invalid
+    pub fn with_ma_type(
+        opt_fast_period: usize,
+        opt_slow_period: usize,
+        opt_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        lookback_ext(opt_fast_period, opt_slow_period, opt_ma_type)?;
+        Ok(Self {
+            ad: Ad::new(),
+            fast_ma: Ma::new(opt_fast_period, opt_ma_type)?,
+            slow_ma: Ma::new(opt_slow_period, opt_ma_type)?,
+        })
+    }
+}
+
+impl Indicator for Adosc {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let ad = self.ad.update(input)?;
+        let fast = self.fast_ma.update_value(ad)?;
+        let slow = self.slow_ma.update_value(ad)?;
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(fast - slow)
+    }
+
+    fn lookback(&self) -> usize {
+        self.fast_ma.lookback().max(self.slow_ma.lookback())
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fast_ma.is_ready() && self.slow_ma.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.ad.reset();
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -244,6 +476,7 @@ mod tests {
 
     /// Tests the calculation of A/D Oscillator for a full series and verifies incremental calculations match.
     #[test]
+    #[allow(clippy::inconsistent_digit_grouping)]
     fn test_adosc_calculation() {
         let input_high = vec![
             35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
@@ -463,4 +696,170 @@ fn test_adosc_calculation() {
             prev_ad_slow_ema = output_ad_slow_ema_inc;
         }
     }
+
+    #[test]
+    fn test_adosc_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_fast_period = 3;
+        let opt_slow_period = 10;
+        let len = input_high.len();
+        let mut output_adosc = vec![0.0; len];
+        let mut output_ad = vec![0.0; len];
+        let mut output_ad_fast_ema = vec![0.0; len];
+        let mut output_ad_slow_ema = vec![0.0; len];
+        adosc(
+            &input_high,
+            &input_low,
+            &input_close,
+            &input_volume,
+            opt_fast_period,
+            opt_slow_period,
+            &mut output_adosc,
+            &mut output_ad,
+            &mut output_ad_fast_ema,
+            &mut output_ad_slow_ema,
+        )
+        .unwrap();
+
+        let mut indicator = Adosc::new(opt_fast_period, opt_slow_period).unwrap();
+        let lookback = lookback(opt_fast_period, opt_slow_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_adosc[i], epsilon = EPSILON);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_adosc_ext() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let (opt_fast_period, opt_slow_period) = (3, 10);
+        let len = input_high.len();
+        let mut output_adosc = vec![0.0; len];
+
+        // EMA lines match `adosc`
+        let mut expected_adosc = vec![0.0; len];
+        let mut output_ad = vec![0.0; len];
+        let mut output_ad_fast_ema = vec![0.0; len];
+        let mut output_ad_slow_ema = vec![0.0; len];
+        adosc(
+            &input_high,
+            &input_low,
+            &input_close,
+            &input_volume,
+            opt_fast_period,
+            opt_slow_period,
+            &mut expected_adosc,
+            &mut output_ad,
+            &mut output_ad_fast_ema,
+            &mut output_ad_slow_ema,
+        )
+        .unwrap();
+        adosc_ext(
+            &input_high,
+            &input_low,
+            &input_close,
+            &input_volume,
+            opt_fast_period,
+            opt_slow_period,
+            MAType::EMA,
+            &mut output_adosc,
+        )
+        .unwrap();
+        let lookback = lookback(opt_fast_period, opt_slow_period).unwrap();
+        for i in lookback..len {
+            assert_relative_eq!(output_adosc[i], expected_adosc[i], epsilon = EPSILON);
+        }
+
+        // SMA lines over the same A/D line
+        adosc_ext(
+            &input_high,
+            &input_low,
+            &input_close,
+            &input_volume,
+            opt_fast_period,
+            opt_slow_period,
+            MAType::SMA,
+            &mut output_adosc,
+        )
+        .unwrap();
+        let lookback = lookback_ext(opt_fast_period, opt_slow_period, MAType::SMA).unwrap();
+        let mut indicator =
+            Adosc::with_ma_type(opt_fast_period, opt_slow_period, MAType::SMA).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(output_adosc[i].is_nan() && value.is_nan());
+                continue;
+            }
+            let mean = |period: usize| {
+                output_ad[i + 1 - period..=i].iter().sum::<TAFloat>() / period as TAFloat
+            };
+            let expected = mean(opt_fast_period) - mean(opt_slow_period);
+            assert_relative_eq!(output_adosc[i], expected, epsilon = 1e-6);
+            assert_relative_eq!(value, output_adosc[i], epsilon = 1e-6);
+        }
+    }
 }
@@ -1,5 +1,5 @@
-use super::sma;
-use crate::{KandError, TAFloat};
+use super::sma::{self, Sma};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for Average Daily Range (ADR) calculation.
 ///
@@ -16,7 +16,6 @@
 /// let lookback = adr::lookback(14).unwrap();
 /// assert_eq!(lookback, 13);
 /// ```
-#[must_use]
 pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
     sma::lookback(opt_period)
 }
@@ -98,7 +97,14 @@ pub fn adr(
             .map(|(&h, &l)| h - l),
     );
 
-    sma::sma(&ranges, opt_period, output_adr)
+    sma::sma(&ranges, opt_period, output_adr)?;
+
+    // Fill initial values with NAN
+    for value in output_adr.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
 }
 
 /// Calculates the latest Average Daily Range (ADR) value incrementally using the previous ADR value.
@@ -131,7 +137,6 @@ pub fn adr(
 ///
 /// let next_adr = adr::adr_inc(prev_adr, new_high, new_low, old_high, old_low, period).unwrap();
 /// ```
-#[must_use]
 pub fn adr_inc(
     prev_adr: TAFloat,
     input_new_high: TAFloat,
@@ -162,7 +167,58 @@ pub fn adr_inc(
     let new_range = input_new_high - input_new_low;
     let old_range = input_old_high - input_old_low;
 
-    sma::sma_inc(prev_adr, new_range, old_range, opt_period)
+    sma::sma_inc(new_range, old_range, prev_adr, opt_period)
+}
+
+/// Streaming ADR built on an [`Sma`] of each bar's high-low range.
+///
+/// Folding [`Indicator::update`] over a series reproduces [`adr`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adr {
+    sma: Sma,
+}
+
+impl Adr {
+    /// Creates a streaming ADR.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for ADR calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            sma: Sma::new(opt_period)?,
+        })
+    }
+}
+
+impl Indicator for Adr {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.high.is_nan() || input.low.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.sma.update_value(input.high - input.low)
+    }
+
+    fn lookback(&self) -> usize {
+        self.sma.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.sma.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
 }
 
 #[cfg(test)]
@@ -173,6 +229,7 @@ mod tests {
 
     /// Tests the ADR calculation for a full series and verifies incremental calculations match.
     #[test]
+    #[allow(clippy::excessive_precision, clippy::legacy_numeric_constants)]
     fn test_adr_calculation() {
         const EPSILON: f64 = 1e-9; // Local epsilon for this test
 
@@ -339,4 +396,93 @@ fn test_adr_calculation() {
             prev_adr = next_adr;
         }
     }
+
+    #[test]
+    fn test_adr_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 3;
+        let len = input_high.len();
+        let mut output_adr = vec![0.0; len];
+        adr(&input_high, &input_low, opt_period, &mut output_adr).unwrap();
+
+        let mut indicator = Adr::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_adr[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    /// Tests that the lookback region is NaN even when the output buffer is not.
+    #[test]
+    fn test_adr_lookback_is_nan() {
+        let input_high = vec![10.0, 12.0, 15.0, 14.0, 13.0];
+        let input_low = vec![8.0, 9.0, 11.0, 10.0, 9.0];
+        let period = 3;
+        let mut output_adr = vec![0.0; input_high.len()];
+
+        adr(&input_high, &input_low, period, &mut output_adr).unwrap();
+
+        let lookback = lookback(period).unwrap();
+        for value in &output_adr[..lookback] {
+            assert!(value.is_nan());
+        }
+        assert_relative_eq!(output_adr[lookback], 3.0, epsilon = 1e-10);
+    }
+
+    /// Tests that `adr_inc` rolls the oldest range out of the window.
+    #[test]
+    fn test_adr_inc_rolls_window() {
+        let input_high = [10.0, 12.0, 15.0, 14.0, 13.0];
+        let input_low = [8.0, 9.0, 11.0, 10.0, 9.0];
+        let period = 3;
+
+        let next_adr = adr_inc(
+            3.0,
+            input_high[3],
+            input_low[3],
+            input_high[0],
+            input_low[0],
+            period,
+        )
+        .unwrap();
+
+        assert_relative_eq!(next_adr, 11.0 / 3.0, epsilon = 1e-10);
+    }
 }
@@ -1,5 +1,5 @@
-use super::dx;
-use crate::{KandError, TAFloat};
+use super::dx::{self, Dx};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculate the lookback period required for ADX calculation
 ///
@@ -299,6 +299,79 @@ pub fn adx_inc(
     ))
 }
 
+/// Streaming ADX built on [`Dx`].
+///
+/// The first `opt_period` DX values are averaged to seed Wilder's smoothing, exactly as
+/// [`adx`] does.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adx {
+    opt_period: usize,
+    dx: Dx,
+    count: usize,
+    sum: TAFloat,
+    prev_adx: TAFloat,
+}
+
+impl Adx {
+    /// Creates a streaming ADX.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            dx: Dx::new(opt_period)?,
+            count: 0,
+            sum: 0.0,
+            prev_adx: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Adx {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let dx = self.dx.update(input)?;
+        if !self.dx.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        let period_t = self.opt_period as TAFloat;
+        if self.count >= self.opt_period {
+            self.prev_adx = self.prev_adx.mul_add(period_t - 1.0, dx) / period_t;
+            return Ok(self.prev_adx);
+        }
+
+        self.sum += dx;
+        self.count += 1;
+        if self.count < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        self.prev_adx = self.sum / period_t;
+        Ok(self.prev_adx)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period * 2 - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.dx.reset();
+        self.count = 0;
+        self.sum = 0.0;
+        self.prev_adx = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -422,4 +495,69 @@ fn test_adx_calculation() {
             assert_relative_eq!(new_smoothed_tr, output_smoothed_tr[i], epsilon = 0.00001);
         }
     }
+
+    #[test]
+    fn test_adx_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 5;
+        let mut output_smoothed_plus_dm = vec![0.0; input_high.len()];
+        let mut output_smoothed_minus_dm = vec![0.0; input_high.len()];
+        let mut output_smoothed_tr = vec![0.0; input_high.len()];
+        let len = input_high.len();
+        let mut output_adx = vec![0.0; len];
+        adx(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            &mut output_adx,
+            &mut output_smoothed_plus_dm,
+            &mut output_smoothed_minus_dm,
+            &mut output_smoothed_tr,
+        )
+        .unwrap();
+
+        let mut indicator = Adx::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_adx[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
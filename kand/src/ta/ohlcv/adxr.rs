@@ -1,5 +1,7 @@
-use super::adx;
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use super::adx::{self, Adx};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculates the lookback period required for ADXR calculation
 ///
@@ -286,6 +288,67 @@ pub fn adxr_inc(
     ))
 }
 
+/// Streaming ADXR built on [`Adx`] and the last `opt_period` ADX values.
+///
+/// Folding [`Indicator::update`] over a series reproduces [`adxr`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adxr {
+    opt_period: usize,
+    adx: Adx,
+    adx_window: VecDeque<TAFloat>,
+}
+
+impl Adxr {
+    /// Creates a streaming ADXR.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            adx: Adx::new(opt_period)?,
+            adx_window: VecDeque::with_capacity(opt_period + 1),
+        })
+    }
+}
+
+impl Indicator for Adxr {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let adx = self.adx.update(input)?;
+        if !self.adx.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        self.adx_window.push_back(adx);
+        if self.adx_window.len() > self.opt_period {
+            self.adx_window.pop_front();
+        }
+        match self.adx_window.front() {
+            Some(&adx_period_ago) if self.is_ready() => Ok(TAFloat::midpoint(adx, adx_period_ago)),
+            _ => Ok(TAFloat::NAN),
+        }
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period * 3 - 2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.adx_window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.adx.reset();
+        self.adx_window.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -410,4 +473,71 @@ fn test_adxr_calculation() {
             // TR
         }
     }
+
+    #[test]
+    fn test_adxr_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 5;
+        let mut output_adx = vec![0.0; input_high.len()];
+        let mut output_smoothed_plus_dm = vec![0.0; input_high.len()];
+        let mut output_smoothed_minus_dm = vec![0.0; input_high.len()];
+        let mut output_smoothed_tr = vec![0.0; input_high.len()];
+        let len = input_high.len();
+        let mut output_adxr = vec![0.0; len];
+        adxr(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            &mut output_adxr,
+            &mut output_adx,
+            &mut output_smoothed_plus_dm,
+            &mut output_smoothed_minus_dm,
+            &mut output_smoothed_tr,
+        )
+        .unwrap();
+
+        let mut indicator = Adxr::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_adxr[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,8 +1,4 @@
-use crate::{
-    TAFloat,
-    error::KandError,
-    helper::{highest_bars, lowest_bars},
-};
+use crate::{TAFloat, error::KandError, indicator::Indicator, types::Bar, window::RollingExtremum};
 
 /// Returns the lookback period required for Aroon indicator calculation.
 ///
@@ -154,7 +150,7 @@ pub fn aroon(
     let hundred_t = 100.0;
 
     // Calculate Aroon Up and Down values for each index starting from lookback
-    // Note: We use opt_period + 1 in highest_bars/lowest_bars because:
+    // Note: The rolling windows cover opt_period + 1 bars because:
     //
     // Visual example with opt_period = 3:
     // Array:     [1, 4, 2, 5, 3]
@@ -170,9 +166,14 @@ pub fn aroon(
     // the current value, allowing days_since_high/low to range from 0 to opt_period:
     // - If current value is highest/lowest: days_since = 0
     // - If earliest value is highest/lowest: days_since = opt_period
-    for i in lookback..len {
-        let days_since_high = highest_bars(input_high, i, opt_period + 1)?;
-        let days_since_low = lowest_bars(input_low, i, opt_period + 1)?;
+    let mut highs = RollingExtremum::highest(opt_period + 1)?;
+    let mut lows = RollingExtremum::lowest(opt_period + 1)?;
+    for i in 0..len {
+        let (_, days_since_high) = highs.push(input_high[i]);
+        let (_, days_since_low) = lows.push(input_low[i]);
+        if i < lookback {
+            continue;
+        }
 
         // Store intermediate values
         output_days_since_high[i] = days_since_high;
@@ -208,6 +209,8 @@ pub fn aroon(
 /// # Description
 /// This function provides an optimized way to calculate the next Aroon values
 /// when processing streaming data, without recalculating the entire series.
+/// Once the previous extreme leaves the window the next one is unknown, so the days since
+/// counter is capped at `opt_period` instead; use [`Aroon`] for exact streaming results.
 ///
 /// # Calculation Principle
 /// 1. Update days since last high/low by incrementing counters
@@ -236,8 +239,13 @@ pub fn aroon(
 /// * Returns `KandError::InvalidParameter` if period < 2
 /// * Returns `KandError::NaNDetected` if any input is NaN (with "`check-nan`" feature)
 ///
+/// # Migration
+/// Drop the extreme and days since state and keep an [`Aroon`] per series instead; its
+/// [`Indicator::update`] yields `(aroon_up, aroon_down)`.
+///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::ohlcv::aroon;
 ///
 /// let (aroon_up, aroon_down, new_high, new_low, days_high, days_low) = aroon::aroon_inc(
@@ -251,6 +259,9 @@ pub fn aroon(
 /// )
 /// .unwrap();
 /// ```
+#[deprecated(
+    note = "the days since counters are capped once an extreme leaves the period; use `Aroon`"
+)]
 pub fn aroon_inc(
     input_high: TAFloat,
     input_low: TAFloat,
@@ -315,6 +326,73 @@ pub fn aroon_inc(
     ))
 }
 
+/// Streaming Aroon over the last `opt_period + 1` highs and lows, tracked with
+/// [`RollingExtremum`].
+///
+/// Folding [`Indicator::update`] over a series reproduces [`aroon`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aroon {
+    opt_period: usize,
+    highs: RollingExtremum,
+    lows: RollingExtremum,
+}
+
+impl Aroon {
+    /// Creates a streaming Aroon.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The lookback window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            highs: RollingExtremum::highest(opt_period + 1)?,
+            lows: RollingExtremum::lowest(opt_period + 1)?,
+        })
+    }
+}
+
+impl Indicator for Aroon {
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.high.is_nan() || input.low.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let (_, days_since_high) = self.highs.push(input.high);
+        let (_, days_since_low) = self.lows.push(input.low);
+        if !self.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN));
+        }
+        let opt_period_t = self.opt_period as TAFloat;
+        let hundred_t = 100.0;
+        let aroon_up = hundred_t - (hundred_t * days_since_high as TAFloat / opt_period_t);
+        let aroon_down = hundred_t - (hundred_t * days_since_low as TAFloat / opt_period_t);
+        Ok((aroon_up, aroon_down))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.highs.is_full()
+    }
+
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -322,6 +400,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_aroon_calculation() {
         let input_high = vec![
             35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
@@ -465,4 +544,74 @@ fn test_aroon_calculation() {
             days_since_low = result.5;
         }
     }
+
+    #[test]
+    fn test_aroon_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 14;
+        let mut output_prev_high = vec![0.0; input_high.len()];
+        let mut output_prev_low = vec![0.0; input_high.len()];
+        let mut output_days_since_high = vec![0; input_high.len()];
+        let mut output_days_since_low = vec![0; input_high.len()];
+        let len = input_high.len();
+        let mut output_aroon_up = vec![0.0; len];
+        let mut output_aroon_down = vec![0.0; len];
+        aroon(
+            &input_high,
+            &input_low,
+            opt_period,
+            &mut output_aroon_up,
+            &mut output_aroon_down,
+            &mut output_prev_high,
+            &mut output_prev_low,
+            &mut output_days_since_high,
+            &mut output_days_since_low,
+        )
+        .unwrap();
+
+        let mut indicator = Aroon::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let (aroon_up, aroon_down) = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(aroon_up.is_nan());
+                assert!(aroon_down.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(aroon_up, output_aroon_up[i], epsilon = 0.00001);
+                assert_relative_eq!(aroon_down, output_aroon_down[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,8 +1,4 @@
-use crate::{
-    TAFloat,
-    error::KandError,
-    helper::{highest_bars, lowest_bars},
-};
+use crate::{TAFloat, error::KandError, indicator::Indicator, types::Bar, window::RollingExtremum};
 
 /// Returns the lookback period required for Aroon Oscillator calculation.
 ///
@@ -149,9 +145,14 @@ pub fn aroonosc(
     let opt_period_t = opt_period as TAFloat;
     let hundred_t = 100.0;
 
-    for i in lookback..len {
-        let days_since_high = highest_bars(input_high, i, opt_period + 1)?;
-        let days_since_low = lowest_bars(input_low, i, opt_period + 1)?;
+    let mut highs = RollingExtremum::highest(opt_period + 1)?;
+    let mut lows = RollingExtremum::lowest(opt_period + 1)?;
+    for i in 0..len {
+        let (_, days_since_high) = highs.push(input_high[i]);
+        let (_, days_since_low) = lows.push(input_low[i]);
+        if i < lookback {
+            continue;
+        }
 
         output_days_since_high[i] = days_since_high;
         output_days_since_low[i] = days_since_low;
@@ -185,6 +186,8 @@ pub fn aroonosc(
 /// # Description
 /// This function provides an efficient way to calculate the next Aroon Oscillator value
 /// when new price data becomes available, without recalculating the entire series.
+/// Once the previous extreme leaves the window the next one is unknown, so the days since
+/// counter is capped at `opt_period` instead; use [`Aroonosc`] for exact streaming results.
 ///
 /// # Mathematical Formula
 /// ```text
@@ -220,8 +223,13 @@ pub fn aroonosc(
 /// * `KandError::InvalidParameter` - If `opt_period` < 2 (when "check" enabled)
 /// * `KandError::NaNDetected` - If any input is NaN (when "`check-nan`" enabled)
 ///
+/// # Migration
+/// Keep an [`Aroonosc`] per series in place of the extreme and days since state, and read the
+/// oscillator from [`Indicator::update`].
+///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::ohlcv::aroonosc::aroonosc_inc;
 ///
 /// let (aroonosc, high, low, days_high, days_low) = aroonosc_inc(
@@ -235,6 +243,9 @@ pub fn aroonosc(
 /// )
 /// .unwrap();
 /// ```
+#[deprecated(
+    note = "the days since counters are capped once an extreme leaves the period; use `Aroonosc`"
+)]
 pub fn aroonosc_inc(
     input_high: TAFloat,
     input_low: TAFloat,
@@ -297,6 +308,73 @@ pub fn aroonosc_inc(
     ))
 }
 
+/// Streaming Aroon Oscillator over the last `opt_period + 1` highs and lows, tracked with
+/// [`RollingExtremum`].
+///
+/// Folding [`Indicator::update`] over a series reproduces [`aroonosc`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aroonosc {
+    opt_period: usize,
+    highs: RollingExtremum,
+    lows: RollingExtremum,
+}
+
+impl Aroonosc {
+    /// Creates a streaming Aroon Oscillator.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The lookback window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            highs: RollingExtremum::highest(opt_period + 1)?,
+            lows: RollingExtremum::lowest(opt_period + 1)?,
+        })
+    }
+}
+
+impl Indicator for Aroonosc {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.high.is_nan() || input.low.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let (_, days_since_high) = self.highs.push(input.high);
+        let (_, days_since_low) = self.lows.push(input.low);
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        let opt_period_t = self.opt_period as TAFloat;
+        let hundred_t = 100.0;
+        let aroon_up = hundred_t - (hundred_t * days_since_high as TAFloat / opt_period_t);
+        let aroon_down = hundred_t - (hundred_t * days_since_low as TAFloat / opt_period_t);
+        Ok(aroon_up - aroon_down)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.highs.is_full()
+    }
+
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -304,6 +382,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_aroonosc_calculation() {
         let input_high = vec![
             35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
@@ -384,4 +463,70 @@ fn test_aroonosc_calculation() {
             days_since_low = new_days_low;
         }
     }
+
+    #[test]
+    fn test_aroonosc_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 14;
+        let mut output_prev_high = vec![0.0; input_high.len()];
+        let mut output_prev_low = vec![0.0; input_high.len()];
+        let mut output_days_since_high = vec![0; input_high.len()];
+        let mut output_days_since_low = vec![0; input_high.len()];
+        let len = input_high.len();
+        let mut output_aroonosc = vec![0.0; len];
+        aroonosc(
+            &input_high,
+            &input_low,
+            opt_period,
+            &mut output_aroonosc,
+            &mut output_prev_high,
+            &mut output_prev_low,
+            &mut output_days_since_high,
+            &mut output_days_since_low,
+        )
+        .unwrap();
+
+        let mut indicator = Aroonosc::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_aroonosc[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
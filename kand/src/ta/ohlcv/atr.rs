@@ -1,5 +1,5 @@
 use super::trange;
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for ATR calculation.
 ///
@@ -218,6 +218,85 @@ pub fn atr_inc(
     Ok(prev_atr.mul_add((opt_period - 1) as TAFloat, tr) / (opt_period as TAFloat))
 }
 
+/// Streaming ATR that owns the True Range seed and the previous ATR.
+///
+/// The first `opt_period` True Range values (starting from the second bar) are averaged to
+/// seed Wilder's smoothing, exactly as [`atr`] does.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Atr {
+    opt_period: usize,
+    count: usize,
+    tr_sum: TAFloat,
+    prev_close: TAFloat,
+    prev_atr: TAFloat,
+}
+
+impl Atr {
+    /// Creates a streaming ATR.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for ATR calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            count: 0,
+            tr_sum: 0.0,
+            prev_close: TAFloat::NAN,
+            prev_atr: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Atr {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let prev_close = self.prev_close;
+        self.prev_close = input.close;
+        self.count += 1;
+        if self.count == 1 {
+            return Ok(TAFloat::NAN);
+        }
+        if self.count > self.opt_period + 1 {
+            self.prev_atr = atr_inc(
+                input.high,
+                input.low,
+                prev_close,
+                self.prev_atr,
+                self.opt_period,
+            )?;
+            return Ok(self.prev_atr);
+        }
+
+        self.tr_sum += trange::trange_inc(input.high, input.low, prev_close)?;
+        if self.count <= self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        self.prev_atr = self.tr_sum / (self.opt_period as TAFloat);
+        Ok(self.prev_atr)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.tr_sum = 0.0;
+        self.prev_close = TAFloat::NAN;
+        self.prev_atr = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -295,4 +374,63 @@ fn test_atr_calculation() {
             prev_atr = result;
         }
     }
+
+    #[test]
+    fn test_atr_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 14;
+        let len = input_high.len();
+        let mut output_atr = vec![0.0; len];
+        atr(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            &mut output_atr,
+        )
+        .unwrap();
+
+        let mut indicator = Atr::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_atr[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
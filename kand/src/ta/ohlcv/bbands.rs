@@ -1,6 +1,14 @@
 use crate::{
     KandError, TAFloat,
-    ta::{ohlcv::sma, stats::var},
+    indicator::Indicator,
+    ta::{
+        ohlcv::{
+            ma::{self, Ma},
+            sma,
+        },
+        stats::var::{self, Var},
+    },
+    types::{Bar, MAType},
 };
 
 /// Returns the lookback period required for Bollinger Bands calculation.
@@ -30,6 +38,33 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
     sma::lookback(opt_period)
 }
 
+/// Returns the lookback period required for [`bbands_ext`].
+///
+/// # Description
+/// The bands are valid once both the middle band moving average and the standard deviation
+/// are, so the lookback is the larger of the two.
+///
+/// # Arguments
+/// * `opt_period` - The time period used for calculations (must be >= 2)
+/// * `opt_ma_type` - Moving average type for the middle band
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::{ta::ohlcv::bbands, types::MAType};
+/// assert_eq!(bbands::lookback_ext(20, MAType::EMA).unwrap(), 19);
+/// assert_eq!(bbands::lookback_ext(20, MAType::DEMA).unwrap(), 38);
+/// ```
+pub fn lookback_ext(opt_period: usize, opt_ma_type: MAType) -> Result<usize, KandError> {
+    let var_lookback = var::lookback(opt_period)?;
+    Ok(ma::lookback(opt_period, opt_ma_type)?.max(var_lookback))
+}
+
 /// Calculates Bollinger Bands for a price series.
 ///
 /// # Description
@@ -191,6 +226,274 @@ pub fn bbands(
     Ok(())
 }
 
+/// Calculates Bollinger Bands for a price series, returning the Welford state.
+///
+/// # Description
+/// Same bands as [`bbands`], but the variance state outputs are the rolling mean and sum of
+/// squared deviations (M2) that seed [`bbands_inc_welford`], as returned by [`var::var_welford`].
+///
+/// # Mathematical Formula
+/// ```text
+/// Middle Band = SMA(price, N)
+/// Standard Deviation = sqrt(sum((price - SMA)^2) / N)
+/// Upper Band = Middle Band + (K × Standard Deviation)
+/// Lower Band = Middle Band - (K × Standard Deviation)
+/// ```
+/// where:
+/// - N is the period
+/// - K is the number of standard deviations
+///
+/// # Calculation Steps
+/// 1. Calculate N-period SMA as middle band
+/// 2. Calculate N-period standard deviation
+/// 3. Add/subtract K standard deviations to get upper/lower bands
+///
+/// # Arguments
+/// * `input_price` - Slice of input price values
+/// * `opt_period` - The time period for calculations (must be >= 2)
+/// * `opt_dev_up` - Number of standard deviations for upper band
+/// * `opt_dev_down` - Number of standard deviations for lower band
+/// * `output_upper` - Buffer to store upper band values
+/// * `output_middle` - Buffer to store middle band values
+/// * `output_lower` - Buffer to store lower band values
+/// * `output_sma` - Buffer to store SMA values
+/// * `output_var` - Buffer to store variance values
+/// * `output_mean` - Buffer to store rolling mean values used by the variance
+/// * `output_m2` - Buffer to store rolling sum of squared deviations from the mean
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input slice is empty
+/// * `KandError::LengthMismatch` - If input and output slices have different lengths
+/// * `KandError::InvalidParameter` - If period is less than 2
+/// * `KandError::InsufficientData` - If input length is less than required period
+/// * `KandError::NaNDetected` - If any input contains NaN values
+///
+/// # Example
+/// ```
+/// use kand::ta::ohlcv::bbands;
+/// let prices = vec![10.0, 11.0, 12.0, 13.0, 14.0];
+/// let period = 3;
+/// let mut upper = vec![0.0; 5];
+/// let mut middle = vec![0.0; 5];
+/// let mut lower = vec![0.0; 5];
+/// let mut sma = vec![0.0; 5];
+/// let mut var = vec![0.0; 5];
+/// let mut mean = vec![0.0; 5];
+/// let mut m2 = vec![0.0; 5];
+///
+/// bbands::bbands_welford(
+///     &prices,
+///     period,
+///     2.0,
+///     2.0,
+///     &mut upper,
+///     &mut middle,
+///     &mut lower,
+///     &mut sma,
+///     &mut var,
+///     &mut mean,
+///     &mut m2,
+/// )
+/// .unwrap();
+/// ```
+pub fn bbands_welford(
+    input_price: &[TAFloat],
+    opt_period: usize,
+    opt_dev_up: TAFloat,
+    opt_dev_down: TAFloat,
+    output_upper: &mut [TAFloat],
+    output_middle: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_sma: &mut [TAFloat],
+    output_var: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_price.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Data sufficiency check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length check
+        if len != output_upper.len()
+            || len != output_middle.len()
+            || len != output_lower.len()
+            || len != output_sma.len()
+            || len != output_var.len()
+            || len != output_mean.len()
+            || len != output_m2.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_price {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate SMA first
+    sma::sma(input_price, opt_period, output_sma)?;
+
+    // Calculate variance
+    var::var_welford(input_price, opt_period, output_var, output_mean, output_m2)?;
+
+    for i in lookback..len {
+        output_middle[i] = output_sma[i];
+        let std_dev = output_var[i].sqrt();
+
+        // Calculate upper and lower bands using standard deviations
+        output_upper[i] = opt_dev_up.mul_add(std_dev, output_sma[i]);
+        output_lower[i] = opt_dev_down.mul_add(-std_dev, output_sma[i]);
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_upper[i] = TAFloat::NAN;
+        output_middle[i] = TAFloat::NAN;
+        output_lower[i] = TAFloat::NAN;
+        output_sma[i] = TAFloat::NAN;
+        output_var[i] = TAFloat::NAN;
+        output_mean[i] = TAFloat::NAN;
+        output_m2[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates Bollinger Bands around a middle band of the selected moving average type.
+///
+/// # Description
+/// Same as [`bbands`], but the middle band is the moving average selected by `opt_ma_type`
+/// instead of an SMA, as in TA-Lib's `BBANDS`. The band width is still the population standard
+/// deviation of the price over `opt_period`.
+///
+/// # Arguments
+/// * `input_price` - Slice of input price values
+/// * `opt_period` - The time period for calculations (must be >= 2)
+/// * `opt_dev_up` - Number of standard deviations for upper band
+/// * `opt_dev_down` - Number of standard deviations for lower band
+/// * `opt_ma_type` - Moving average type for the middle band
+/// * `output_upper` - Buffer to store upper band values
+/// * `output_middle` - Buffer to store middle band values
+/// * `output_lower` - Buffer to store lower band values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input slice is empty
+/// * `KandError::LengthMismatch` - If input and output slices have different lengths
+/// * `KandError::InvalidParameter` - If period is less than 2
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input contains NaN values
+///
+/// # Example
+/// ```
+/// use kand::{ta::ohlcv::bbands, types::MAType};
+/// let prices = vec![10.0, 11.0, 12.0, 13.0, 14.0];
+/// let mut upper = vec![0.0; 5];
+/// let mut middle = vec![0.0; 5];
+/// let mut lower = vec![0.0; 5];
+///
+/// bbands::bbands_ext(
+///     &prices,
+///     3,
+///     2.0,
+///     2.0,
+///     MAType::EMA,
+///     &mut upper,
+///     &mut middle,
+///     &mut lower,
+/// )
+/// .unwrap();
+/// ```
+pub fn bbands_ext(
+    input_price: &[TAFloat],
+    opt_period: usize,
+    opt_dev_up: TAFloat,
+    opt_dev_down: TAFloat,
+    opt_ma_type: MAType,
+    output_upper: &mut [TAFloat],
+    output_middle: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_price.len();
+    let lookback = lookback_ext(opt_period, opt_ma_type)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Data sufficiency check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length check
+        if len != output_upper.len() || len != output_middle.len() || len != output_lower.len() {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_price {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    ma::ma(input_price, opt_period, opt_ma_type, output_middle)?;
+
+    let mut output_var = vec![0.0; len];
+    let mut output_mean = vec![0.0; len];
+    let mut output_m2 = vec![0.0; len];
+    var::var_welford(
+        input_price,
+        opt_period,
+        &mut output_var,
+        &mut output_mean,
+        &mut output_m2,
+    )?;
+
+    for i in lookback..len {
+        let std_dev = output_var[i].sqrt();
+        output_upper[i] = opt_dev_up.mul_add(std_dev, output_middle[i]);
+        output_lower[i] = opt_dev_down.mul_add(-std_dev, output_middle[i]);
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_upper[i] = TAFloat::NAN;
+        output_middle[i] = TAFloat::NAN;
+        output_lower[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
 /// Calculates the next Bollinger Bands values using an incremental approach.
 ///
 /// # Description
@@ -226,8 +529,14 @@ pub fn bbands(
 /// * `KandError::InvalidParameter` - If period is less than 2
 /// * `KandError::NaNDetected` - If any input contains NaN values
 ///
+/// # Migration
+/// The running sums lose precision when prices are large relative to their spread. Switch to
+/// [`bbands_welford`] and [`bbands_inc_welford`], which carry the mean and M2 instead, as
+/// described for [`var::var_inc`].
+///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::ta::ohlcv::bbands;
 /// let (upper, middle, lower, sma, sum, sum_sq) = bbands::bbands_inc(
 ///     10.0,   // new price
@@ -241,6 +550,7 @@ pub fn bbands(
 /// )
 /// .unwrap();
 /// ```
+#[deprecated(note = "sum-based state is numerically unstable; use `bbands_inc_welford`")]
 pub fn bbands_inc(
     input_price: TAFloat,
     prev_sma: TAFloat,
@@ -274,9 +584,10 @@ pub fn bbands_inc(
     }
 
     // Calculate new SMA using incremental SMA
-    let new_sma = sma::sma_inc(prev_sma, input_price, input_old_price, opt_period)?;
+    let new_sma = sma::sma_inc(input_price, input_old_price, prev_sma, opt_period)?;
 
     // Calculate new variance using incremental variance
+    #[allow(deprecated)]
     let (new_variance, new_sum, new_sum_sq) = var::var_inc(
         input_price,
         prev_sum,
@@ -292,6 +603,191 @@ pub fn bbands_inc(
     Ok((upper, new_sma, lower, new_sma, new_sum, new_sum_sq))
 }
 
+/// Calculates the next Bollinger Bands values incrementally from the Welford state.
+///
+/// # Description
+/// This function provides an optimized way to calculate the next set of Bollinger Bands values
+/// when new data arrives, without recalculating the entire series. It uses the previous values
+/// to compute the new bands efficiently.
+///
+/// # Calculation Steps
+/// 1. Calculate new SMA using incremental approach
+/// 2. Calculate new variance using incremental approach
+/// 3. Compute standard deviation and bands
+///
+/// # Arguments
+/// * `input_price` - The current price value
+/// * `prev_sma` - The previous SMA value
+/// * `prev_mean` - The previous rolling mean for variance calculation
+/// * `prev_m2` - The previous sum of squared deviations for variance calculation
+/// * `input_old_price` - The oldest price value to be removed from the period
+/// * `opt_period` - The time period for calculations (must be >= 2)
+/// * `opt_dev_up` - Number of standard deviations for upper band
+/// * `opt_dev_down` - Number of standard deviations for lower band
+///
+/// Seed `prev_mean` and `prev_m2` from the `output_mean` and `output_m2` of [`bbands_welford`].
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - A tuple containing:
+///   - Upper Band value
+///   - Middle Band value
+///   - Lower Band value
+///   - New SMA value
+///   - New rolling mean value
+///   - New sum of squared deviations value
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If period is less than 2
+/// * `KandError::NaNDetected` - If any input contains NaN values
+///
+/// # Example
+/// ```
+/// use kand::ta::ohlcv::bbands;
+/// // Window [9.0, 9.5, 10.0] slides to [9.5, 10.0, 10.0]
+/// let (upper, middle, lower, sma, mean, m2) = bbands::bbands_inc_welford(
+///     10.0, // new price
+///     9.5,  // previous SMA
+///     9.5,  // previous mean
+///     0.5,  // previous sum of squared deviations
+///     9.0,  // oldest price
+///     3,    // period
+///     2.0,  // upper deviation
+///     2.0,  // lower deviation
+/// )
+/// .unwrap();
+/// ```
+pub fn bbands_inc_welford(
+    input_price: TAFloat,
+    prev_sma: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    input_old_price: TAFloat,
+    opt_period: usize,
+    opt_dev_up: TAFloat,
+    opt_dev_down: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_price.is_nan()
+            || prev_sma.is_nan()
+            || prev_mean.is_nan()
+            || prev_m2.is_nan()
+            || input_old_price.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+        if opt_dev_up.is_nan() || opt_dev_down.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    // Calculate new SMA using incremental SMA
+    let new_sma = sma::sma_inc(input_price, input_old_price, prev_sma, opt_period)?;
+
+    // Calculate new variance using incremental variance
+    let (new_variance, new_mean, new_m2) =
+        var::var_inc_welford(input_price, prev_mean, prev_m2, input_old_price, opt_period)?;
+
+    let std_dev = new_variance.sqrt();
+    let upper = opt_dev_up.mul_add(std_dev, new_sma);
+    let lower = opt_dev_down.mul_add(-std_dev, new_sma);
+
+    Ok((upper, new_sma, lower, new_sma, new_mean, new_m2))
+}
+
+/// Streaming Bollinger Bands built on an [`Ma`] middle band and a rolling [`Var`] of closes.
+///
+/// Yields `(upper, middle, lower)`. Folding [`Indicator::update`] over a series reproduces
+/// [`bbands`], or [`bbands_ext`] when created with [`Bbands::with_ma_type`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bbands {
+    opt_dev_up: TAFloat,
+    opt_dev_down: TAFloat,
+    ma: Ma,
+    var: Var,
+}
+
+impl Bbands {
+    /// Creates streaming Bollinger Bands.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for calculations (must be >= 2)
+    /// * `opt_dev_up` - Number of standard deviations for upper band
+    /// * `opt_dev_down` - Number of standard deviations for lower band
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(
+        opt_period: usize,
+        opt_dev_up: TAFloat,
+        opt_dev_down: TAFloat,
+    ) -> Result<Self, KandError> {
+        Self::with_ma_type(opt_period, opt_dev_up, opt_dev_down, MAType::SMA)
+    }
+
+    /// Creates streaming Bollinger Bands whose middle band uses the given moving average type.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for calculations (must be >= 2)
+    /// * `opt_dev_up` - Number of standard deviations for upper band
+    /// * `opt_dev_down` - Number of standard deviations for lower band
+    /// * `opt_ma_type` - Moving average type for the middle band
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn with_ma_type(
+        opt_period: usize,
+        opt_dev_up: TAFloat,
+        opt_dev_down: TAFloat,
+        opt_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        lookback_ext(opt_period, opt_ma_type)?;
+        Ok(Self {
+            opt_dev_up,
+            opt_dev_down,
+            ma: Ma::new(opt_period, opt_ma_type)?,
+            var: Var::new(opt_period)?,
+        })
+    }
+}
+
+impl Indicator for Bbands {
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        let middle = self.ma.update_value(input.close)?;
+        let var = self.var.update_value(input.close)?;
+        if !self.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+        }
+        let std_dev = var.sqrt();
+        let upper = self.opt_dev_up.mul_add(std_dev, middle);
+        let lower = self.opt_dev_down.mul_add(-std_dev, middle);
+        Ok((upper, middle, lower))
+    }
+
+    fn lookback(&self) -> usize {
+        self.ma.lookback().max(self.var.lookback())
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ma.is_ready() && self.var.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.var.reset();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -299,6 +795,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_bbands_calculation() {
         let input_price = vec![
             35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
@@ -464,4 +961,207 @@ fn test_bbands_calculation() {
             prev_sum_sq = new_sum_sq;
         }
     }
+
+    #[test]
+    fn test_bbands_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let (opt_period, opt_dev_up, opt_dev_down) = (10, 2.0, 2.0);
+        let mut output_sma = vec![0.0; input_high.len()];
+        let mut output_var = vec![0.0; input_high.len()];
+        let mut output_sum = vec![0.0; input_high.len()];
+        let mut output_sum_sq = vec![0.0; input_high.len()];
+        let len = input_high.len();
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        bbands(
+            &input_close,
+            opt_period,
+            opt_dev_up,
+            opt_dev_down,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+            &mut output_sma,
+            &mut output_var,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        let mut indicator = Bbands::new(opt_period, opt_dev_up, opt_dev_down).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let (upper, middle, lower) = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(upper.is_nan());
+                assert!(middle.is_nan());
+                assert!(lower.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(upper, output_upper[i], epsilon = 0.00001);
+                assert_relative_eq!(middle, output_middle[i], epsilon = 0.00001);
+                assert_relative_eq!(lower, output_lower[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    /// Tests that `bbands_inc` rolls the oldest price out of the middle band.
+    #[test]
+    #[allow(deprecated)]
+    fn test_bbands_inc_middle_band() {
+        let (_, middle, _, sma, sum, sum_sq) =
+            bbands_inc(4.0, 2.0, 6.0, 14.0, 1.0, 3, 2.0, 2.0).unwrap();
+
+        assert_relative_eq!(middle, 3.0, epsilon = 1e-10);
+        assert_relative_eq!(sma, 3.0, epsilon = 1e-10);
+        assert_relative_eq!(sum, 9.0, epsilon = 1e-10);
+        assert_relative_eq!(sum_sq, 29.0, epsilon = 1e-10);
+    }
+
+    /// Tests that `bbands_inc_welford` slides the mean and M2 with the middle band.
+    #[test]
+    fn test_bbands_inc_welford_middle_band() {
+        let (_, middle, _, sma, mean, m2) =
+            bbands_inc_welford(4.0, 2.0, 2.0, 2.0, 1.0, 3, 2.0, 2.0).unwrap();
+
+        assert_relative_eq!(middle, 3.0, epsilon = 1e-10);
+        assert_relative_eq!(sma, 3.0, epsilon = 1e-10);
+        assert_relative_eq!(mean, 3.0, epsilon = 1e-10);
+        assert_relative_eq!(m2, 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_bbands_ext() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+        ];
+        let (opt_period, opt_dev_up, opt_dev_down) = (10, 2.0, 1.5);
+        let len = input_price.len();
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+
+        // SMA middle band matches `bbands`
+        let mut expected_upper = vec![0.0; len];
+        let mut expected_middle = vec![0.0; len];
+        let mut expected_lower = vec![0.0; len];
+        let mut output_sma = vec![0.0; len];
+        let mut output_var = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+        bbands(
+            &input_price,
+            opt_period,
+            opt_dev_up,
+            opt_dev_down,
+            &mut expected_upper,
+            &mut expected_middle,
+            &mut expected_lower,
+            &mut output_sma,
+            &mut output_var,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+        bbands_ext(
+            &input_price,
+            opt_period,
+            opt_dev_up,
+            opt_dev_down,
+            MAType::SMA,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+        )
+        .unwrap();
+        for i in lookback(opt_period).unwrap()..len {
+            assert_relative_eq!(output_upper[i], expected_upper[i], epsilon = 1e-9);
+            assert_relative_eq!(output_middle[i], expected_middle[i], epsilon = 1e-9);
+            assert_relative_eq!(output_lower[i], expected_lower[i], epsilon = 1e-9);
+        }
+
+        // DEMA middle band, same width
+        bbands_ext(
+            &input_price,
+            opt_period,
+            opt_dev_up,
+            opt_dev_down,
+            MAType::DEMA,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+        )
+        .unwrap();
+        let lookback = lookback_ext(opt_period, MAType::DEMA).unwrap();
+        assert_eq!(lookback, 18);
+        let mut expected_dema = vec![0.0; len];
+        ma::ma(&input_price, opt_period, MAType::DEMA, &mut expected_dema).unwrap();
+        for i in 0..len {
+            if i < lookback {
+                assert!(output_upper[i].is_nan() && output_middle[i].is_nan());
+                continue;
+            }
+            let std_dev = output_var[i].sqrt();
+            assert_relative_eq!(output_middle[i], expected_dema[i], epsilon = 1e-9);
+            assert_relative_eq!(
+                output_upper[i],
+                expected_dema[i] + opt_dev_up * std_dev,
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                output_lower[i],
+                expected_dema[i] - opt_dev_down * std_dev,
+                epsilon = 1e-9
+            );
+        }
+
+        let mut indicator =
+            Bbands::with_ma_type(opt_period, opt_dev_up, opt_dev_down, MAType::DEMA).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let (upper, middle, lower) = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(upper.is_nan() && middle.is_nan() && lower.is_nan());
+            } else {
+                assert_relative_eq!(upper, output_upper[i], epsilon = 1e-6);
+                assert_relative_eq!(middle, output_middle[i], epsilon = 1e-6);
+                assert_relative_eq!(lower, output_lower[i], epsilon = 1e-6);
+            }
+        }
+    }
 }
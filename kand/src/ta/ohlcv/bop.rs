@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for Balance of Power (BOP) calculation.
 ///
@@ -186,6 +186,46 @@ pub fn bop_inc(
     }
 }
 
+/// Streaming Balance of Power.
+///
+/// Balance of Power depends on the current bar only; the struct exists so it can be driven through
+/// [`Indicator`] alongside stateful indicators.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bop {
+    ready: bool,
+}
+
+impl Bop {
+    /// Creates a streaming Balance of Power.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { ready: false }
+    }
+}
+
+impl Indicator for Bop {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let value = bop_inc(input.open, input.high, input.low, input.close)?;
+        self.ready = true;
+        Ok(value)
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn reset(&mut self) {
+        self.ready = false;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -259,4 +299,67 @@ fn test_bop_calculation() {
             assert_relative_eq!(result, output_bop[i], epsilon = 0.0001);
         }
     }
+
+    #[test]
+    fn test_bop_indicator() {
+        let input_open = vec![
+            35230.0, 35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9,
+            35197.6, 35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3,
+            35013.4, 35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8,
+        ];
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let len = input_high.len();
+        let mut output_bop = vec![0.0; len];
+        bop(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &mut output_bop,
+        )
+        .unwrap();
+
+        let mut indicator = Bop::new();
+        let lookback = lookback().unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_bop[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,5 +1,7 @@
+use std::collections::VecDeque;
+
 use super::{sma, typprice};
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, stats::moments::RollingMoments, types::Bar};
 
 /// Returns the lookback period required for CCI calculation.
 ///
@@ -152,8 +154,16 @@ pub fn cci(
     // Calculate typical prices
     typprice::typprice(input_high, input_low, input_close, output_tp)?;
 
-    // Calculate SMA of typical prices
-    sma::sma(output_tp, opt_period, output_tp_sma)?;
+    // Calculate SMA of typical prices with a compensated rolling mean
+    let mut tp_moments = RollingMoments::new();
+    for &tp in &output_tp[..opt_period] {
+        tp_moments.push(tp);
+    }
+    output_tp_sma[lookback] = tp_moments.mean();
+    for i in opt_period..len {
+        tp_moments.replace(output_tp[i], output_tp[i - opt_period]);
+        output_tp_sma[i] = tp_moments.mean();
+    }
 
     // Calculate mean deviation
     let factor = 0.015;
@@ -290,7 +300,7 @@ pub fn cci_inc(
     let old_tp = (input_old_high + input_old_low + input_old_close) / 3.0;
 
     // Calculate new SMA of typical prices
-    let sma_tp = sma::sma_inc(prev_sma_tp, new_tp, old_tp, opt_period)?;
+    let sma_tp = sma::sma_inc(new_tp, old_tp, prev_sma_tp, opt_period)?;
 
     // Update circular buffer - remove oldest and add newest TP
     if tp_buffer.len() == opt_period {
@@ -314,6 +324,81 @@ pub fn cci_inc(
     })
 }
 
+/// Streaming CCI that keeps the last `opt_period` typical prices and their [`RollingMoments`].
+///
+/// Unlike [`cci_inc`], the caller does not need to manage a typical price buffer. Folding
+/// [`Indicator::update`] over a series reproduces [`cci`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cci {
+    opt_period: usize,
+    tp_moments: RollingMoments,
+    tp_window: VecDeque<TAFloat>,
+}
+
+impl Cci {
+    /// Creates a streaming CCI.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for calculations (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            tp_moments: RollingMoments::new(),
+            tp_window: VecDeque::with_capacity(opt_period + 1),
+        })
+    }
+}
+
+impl Indicator for Cci {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let tp = typprice::typprice_inc(input.high, input.low, input.close)?;
+        self.tp_window.push_back(tp);
+        if self.tp_window.len() > self.opt_period {
+            let old_tp = self.tp_window.pop_front().unwrap_or(tp);
+            self.tp_moments.replace(tp, old_tp);
+        } else {
+            self.tp_moments.push(tp);
+        }
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+
+        let tp_sma = self.tp_moments.mean();
+
+        let mut mean_dev = 0.0;
+        for &value in self.tp_window.iter().rev() {
+            mean_dev += (value - tp_sma).abs();
+        }
+        mean_dev /= self.opt_period as TAFloat;
+        let factor = 0.015;
+        Ok(if mean_dev == 0.0 {
+            0.0
+        } else {
+            (tp - tp_sma) / (factor * mean_dev)
+        })
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.tp_window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.tp_moments = RollingMoments::new();
+        self.tp_window.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -411,4 +496,80 @@ fn test_cci_calculation() {
             assert_relative_eq!(result, output_cci[i], epsilon = 0.00001);
         }
     }
+
+    #[test]
+    fn test_cci_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 14;
+        let mut output_tp = vec![0.0; input_high.len()];
+        let mut output_tp_sma = vec![0.0; input_high.len()];
+        let mut output_mean_dev = vec![0.0; input_high.len()];
+        let len = input_high.len();
+        let mut output_cci = vec![0.0; len];
+        cci(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            &mut output_cci,
+            &mut output_tp,
+            &mut output_tp_sma,
+            &mut output_mean_dev,
+        )
+        .unwrap();
+
+        let mut indicator = Cci::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_cci[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    /// Tests that `cci_inc` rolls the oldest typical price out of the SMA.
+    #[test]
+    fn test_cci_inc_rolls_window() {
+        let mut tp_buffer = vec![1.0, 2.0, 3.0];
+
+        let cci = cci_inc(2.0, 4.0, 4.0, 4.0, 1.0, 1.0, 1.0, 3, &mut tp_buffer).unwrap();
+
+        assert_eq!(tp_buffer, vec![2.0, 3.0, 4.0]);
+        assert_relative_eq!(cci, 100.0, epsilon = 1e-10);
+    }
 }
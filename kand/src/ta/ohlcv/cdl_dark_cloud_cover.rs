@@ -0,0 +1,668 @@
+use crate::{
+    KandError, TAFloat, TAInt,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, real_body_length,
+        real_body_percent, threshold_window,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
+};
+
+/// Returns the lookback period for Dark Cloud Cover pattern detection.
+///
+/// # Description
+/// The Dark Cloud Cover pattern spans two candles, so the first signal is available once
+/// two bars have been seen.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 1
+///
+/// # Errors
+/// This function does not return any errors. It always returns `Ok(1)`.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cdl_dark_cloud_cover;
+/// let lookback = cdl_dark_cloud_cover::lookback().unwrap();
+/// assert_eq!(lookback, 1);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(1)
+}
+
+/// Returns the lookback period for [`cdl_dark_cloud_cover_ext`].
+///
+/// # Description
+/// Every candle of the pattern is judged against averages of the candles before it, so the first
+/// signal needs the longest average period used by the pattern plus the candles it spans.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_dark_cloud_cover_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_dark_cloud_cover};
+/// let lookback = cdl_dark_cloud_cover::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 11);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings.body_long.avg_period + 1)
+}
+
+/// Detects Dark Cloud Cover candlestick patterns in price data.
+///
+/// # Description
+/// Dark Cloud Cover is a two-candle bearish reversal. After a long white candle, a black candle
+/// opens above the prior high and closes deep into the white real body, but still above its
+/// open.
+///
+/// # Mathematical Formula
+/// ```text
+/// Long1 = Close1 > Open1 AND |Close1 - Open1| / (High1 - Low1) * 100 >= BodyLongPercent
+/// Cover = Close2 < Open2 AND Open2 > High1 AND Close2 > Open1
+///         AND Close2 < Close1 - |Close1 - Open1| * Penetration
+/// Signal = Long1 AND Cover ? Bearish : Neutral
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_body_long_percent` - Minimum body size of a long candle as a percentage of its range (e.g. 60.0)
+/// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate (e.g. 0.5)
+/// * `output_signals` - Output array for pattern signals:
+///   - Bearish (-100): Dark Cloud Cover
+///   - Neutral (0): No pattern detected
+///   - Invalid (-1): Fewer than two bars available
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than 2
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If a body percentage is <= 0 or the penetration is negative
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::cdl_dark_cloud_cover, types::Signal};
+///
+/// let input_open = vec![10.0, 12.5, 10.8, 12.9];
+/// let input_high = vec![12.1, 12.6, 12.8, 13.0];
+/// let input_low = vec![9.9, 10.5, 10.7, 12.0];
+/// let input_close = vec![12.0, 10.8, 12.7, 12.2];
+/// let mut output_signals = vec![0i64; 4];
+///
+/// cdl_dark_cloud_cover::cdl_dark_cloud_cover(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     60.0, // opt_body_long_percent
+///     0.5, // opt_penetration
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// let expected: Vec<i64> = vec![Signal::Invalid.into(), Signal::Bearish.into(), Signal::Neutral.into(), Signal::Neutral.into()];
+/// assert_eq!(output_signals, expected);
+/// ```
+pub fn cdl_dark_cloud_cover(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_body_long_percent: TAFloat,
+    opt_penetration: TAFloat,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_body_long_percent <= 0.0 || opt_penetration < 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_dark_cloud_cover_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            opt_body_long_percent,
+            opt_penetration,
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Detects Dark Cloud Cover patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_dark_cloud_cover`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, following TA-Lib's `CDLDARKCLOUDCOVER`. With
+/// [`CandleSettings::default`] the pattern fires on the same candles as TA-Lib.
+///
+/// # Mathematical Formula
+/// ```text
+/// BodyLong(k) = settings.body_long average for candle k
+///
+/// DarkCloud = Close(i-1) >= Open(i-1) AND |Close(i-1) - Open(i-1)| > BodyLong(i-1)
+///             AND Close < Open AND Open > High(i-1) AND Close > Open(i-1)
+///             AND Close < Close(i-1) - |Close(i-1) - Open(i-1)| * Penetration
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate (e.g. 0.5)
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_dark_cloud_cover`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If the penetration is negative
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_dark_cloud_cover, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([10.0, 10.9]);
+/// input_high.extend([10.85, 10.95]);
+/// input_low.extend([9.95, 10.25]);
+/// input_close.extend([10.8, 10.3]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_dark_cloud_cover::cdl_dark_cloud_cover_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     0.5, // opt_penetration
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[13], Signal::Bearish.into());
+/// ```
+pub fn cdl_dark_cloud_cover_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_penetration: TAFloat,
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_penetration < 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_dark_cloud_cover_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+            opt_penetration,
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Evaluates the Dark Cloud Cover pattern on a window of two consecutive candles.
+///
+/// # Description
+/// Checks whether the two most recent candles, ordered from oldest to newest, form the
+/// pattern. Because the pattern only looks at these bars, no state has to be carried between
+/// calls; slide the window forward by one bar to process the next candle.
+///
+/// # Arguments
+/// * `input_bars` - The last two candles, oldest first
+/// * `opt_body_long_percent` - Minimum body size of a long candle as a percentage of its range
+/// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If a body percentage is <= 0 or the penetration is negative
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_dark_cloud_cover_inc(
+    input_bars: &[Bar; 2],
+    opt_body_long_percent: TAFloat,
+    opt_penetration: TAFloat,
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_body_long_percent <= 0.0 || opt_penetration < 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [first, second] = input_bars;
+
+    let long_white = first.close > first.open
+        && real_body_percent(first.open, first.high, first.low, first.close)
+            >= opt_body_long_percent;
+    let covers = second.close < second.open
+        && second.open > first.high
+        && second.close > first.open
+        && second.close
+            < real_body_length(first.open, first.close).mul_add(-opt_penetration, first.close);
+
+    Ok(if long_white && covers {
+        Signal::Bearish.into()
+    } else {
+        Signal::Neutral.into()
+    })
+}
+
+/// Evaluates the TA-Lib style Dark Cloud Cover pattern on a window of two consecutive candles.
+///
+/// # Description
+/// Incremental form of [`cdl_dark_cloud_cover_ext`]. `input_thresholds[k]` holds the candle
+/// averages of `input_bars[k]`, as returned by
+/// [`CandleAverages::value`](crate::helper::CandleAverages::value) before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The last two candles, oldest first
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+/// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate (e.g. 0.5)
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If the penetration is negative
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_dark_cloud_cover_ext_inc(
+    input_bars: &[Bar; 2],
+    input_thresholds: &[CandleThresholds; 2],
+    opt_penetration: TAFloat,
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_penetration < 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [prev_avg, _] = input_thresholds;
+    let [prev, curr] = input_bars;
+    let prev_body = real_body_length(prev.open, prev.close);
+    Ok(
+        if prev.close >= prev.open
+            && prev_body > prev_avg.body_long
+            && curr.close < curr.open
+            && curr.open > prev.high
+            && curr.close > prev.open
+            && curr.close < prev_body.mul_add(-opt_penetration, prev.close)
+        {
+            Signal::Bearish.into()
+        } else {
+            Signal::Neutral.into()
+        },
+    )
+}
+
+/// Streaming Dark Cloud Cover pattern detector.
+///
+/// Keeps the last two bars and emits [`Signal::Invalid`] until the window is full.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DarkCloudCover {
+    opt_body_long_percent: TAFloat,
+    opt_penetration: TAFloat,
+    window: [Bar; 2],
+    count: usize,
+}
+
+impl DarkCloudCover {
+    /// Creates a streaming Dark Cloud Cover pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_body_long_percent` - Minimum body size of a long candle as a percentage of its range
+    /// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate
+    #[must_use]
+    pub const fn new(opt_body_long_percent: TAFloat, opt_penetration: TAFloat) -> Self {
+        Self {
+            opt_body_long_percent,
+            opt_penetration,
+            window: [Bar::new(0.0, 0.0, 0.0, 0.0, 0.0); 2],
+            count: 0,
+        }
+    }
+}
+
+impl Indicator for DarkCloudCover {
+    type Output = TAInt;
+
+    fn update(&mut self, input: &Bar) -> Result<TAInt, KandError> {
+        self.window.rotate_left(1);
+        self.window[1] = *input;
+        if self.count < 2 {
+            self.count += 1;
+            if self.count < 2 {
+                return Ok(Signal::Invalid.into());
+            }
+        }
+        cdl_dark_cloud_cover_inc(
+            &self.window,
+            self.opt_body_long_percent,
+            self.opt_penetration,
+        )
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= 2
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_dark_cloud_cover() {
+        let input_open = vec![10.0, 12.5, 10.8, 12.9];
+        let input_high = vec![12.1, 12.6, 12.8, 13.0];
+        let input_low = vec![9.9, 10.5, 10.7, 12.0];
+        let input_close = vec![12.0, 10.8, 12.7, 12.2];
+        let opt_body_long_percent = 60.0;
+        let opt_penetration = 0.5;
+        let mut output_signals = vec![0i64; input_open.len()];
+
+        cdl_dark_cloud_cover(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_body_long_percent,
+            opt_penetration,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let expected: Vec<TAInt> = vec![
+            Signal::Invalid.into(),
+            Signal::Bearish.into(),
+            Signal::Neutral.into(),
+            Signal::Neutral.into(),
+        ];
+        assert_eq!(output_signals, expected);
+
+        // Test incremental calculation matches regular calculation
+        for (i, &expected) in output_signals.iter().enumerate().skip(lookback().unwrap()) {
+            let signal = cdl_dark_cloud_cover_inc(
+                &bar_window(&input_open, &input_high, &input_low, &input_close, i),
+                opt_body_long_percent,
+                opt_penetration,
+            )
+            .unwrap();
+            assert_eq!(signal, expected, "Mismatch at index {i}");
+        }
+
+        // Fewer bars than the pattern spans
+        assert!(
+            cdl_dark_cloud_cover(
+                &input_open[..1],
+                &input_high[..1],
+                &input_low[..1],
+                &input_close[..1],
+                opt_body_long_percent,
+                opt_penetration,
+                &mut output_signals[..1],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cdl_dark_cloud_cover_indicator() {
+        let input_open = vec![10.0, 12.5, 10.8, 12.9];
+        let input_high = vec![12.1, 12.6, 12.8, 13.0];
+        let input_low = vec![9.9, 10.5, 10.7, 12.0];
+        let input_close = vec![12.0, 10.8, 12.7, 12.2];
+        let opt_body_long_percent = 60.0;
+        let opt_penetration = 0.5;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        cdl_dark_cloud_cover(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_body_long_percent,
+            opt_penetration,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut indicator = DarkCloudCover::new(opt_body_long_percent, opt_penetration);
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            assert_eq!(indicator.update(&bar).unwrap(), output_signals[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        let bar = Bar::new(
+            input_open[0],
+            input_high[0],
+            input_low[0],
+            input_close[0],
+            0.0,
+        );
+        assert_eq!(
+            indicator.update(&bar).unwrap(),
+            TAInt::from(Signal::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_cdl_dark_cloud_cover_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.9,
+            10.0, 10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.85, 10.95,
+            10.5, 10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.95, 10.25, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.8, 10.3,
+            10.2, 10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 11);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_dark_cloud_cover_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            0.5,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[13] = Signal::Bearish.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_dark_cloud_cover_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                0.5,
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
+}
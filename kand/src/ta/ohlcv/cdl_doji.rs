@@ -1,7 +1,11 @@
 use crate::{
     KandError, TAFloat, TAInt,
-    helper::{lower_shadow_length, real_body_length, upper_shadow_length},
-    types::Signal,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, lower_shadow_length,
+        real_body_length, threshold_window, upper_shadow_length,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
 };
 
 /// Returns the lookback period for Doji pattern detection.
@@ -27,6 +31,31 @@ pub const fn lookback() -> Result<usize, KandError> {
     Ok(0)
 }
 
+/// Returns the lookback period for [`cdl_doji_ext`].
+///
+/// # Description
+/// The candle is judged against averages of the candles before it, so the first signal needs
+/// the longest average period used by the pattern.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_doji_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_doji};
+/// let lookback = cdl_doji::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 10);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings.body_doji.avg_period)
+}
+
 /// Detects Doji candlestick patterns in price data.
 ///
 /// # Description
@@ -146,6 +175,137 @@ pub fn cdl_doji(
     Ok(())
 }
 
+/// Detects Doji patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_doji`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, following TA-Lib's `CDLDOJI`. With
+/// [`CandleSettings::default`] the pattern fires on the same candles as TA-Lib.
+///
+/// # Mathematical Formula
+/// ```text
+/// BodyDoji(k) = settings.body_doji average for candle k
+///
+/// Doji = |Close - Open| <= BodyDoji(i)
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_doji`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_doji, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([10.0]);
+/// input_high.extend([10.6]);
+/// input_low.extend([9.4]);
+/// input_close.extend([10.05]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_doji::cdl_doji_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[12], Signal::Pattern.into());
+/// ```
+pub fn cdl_doji_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_doji_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
 /// Processes a single candlestick to detect a Doji pattern.
 ///
 /// # Description
@@ -242,6 +402,102 @@ pub fn cdl_doji_inc(
     })
 }
 
+/// Evaluates the TA-Lib style Doji pattern on a single candle.
+///
+/// # Description
+/// Incremental form of [`cdl_doji_ext`]. `input_thresholds[k]` holds the candle averages of
+/// `input_bars[k]`, as returned by [`CandleAverages::value`](crate::helper::CandleAverages::value)
+/// before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The current candle
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_doji_ext_inc(
+    input_bars: &[Bar; 1],
+    input_thresholds: &[CandleThresholds; 1],
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [candle] = input_bars;
+    let [candle_avg] = input_thresholds;
+    let body = real_body_length(candle.open, candle.close);
+    Ok(if body <= candle_avg.body_doji {
+        Signal::Pattern.into()
+    } else {
+        Signal::Neutral.into()
+    })
+}
+
+/// Streaming Doji pattern detector.
+///
+/// The pattern depends on the current bar only; the struct keeps the thresholds so it can be
+/// driven through [`Indicator`] alongside stateful indicators.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Doji {
+    opt_body_percent: TAFloat,
+    opt_shadow_equal_percent: TAFloat,
+    ready: bool,
+}
+
+impl Doji {
+    /// Creates a streaming Doji pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_body_percent` - Maximum body size as a percentage of the candle range
+    /// * `opt_shadow_equal_percent` - Maximum allowed difference between the shadows, in percent
+    #[must_use]
+    pub const fn new(opt_body_percent: TAFloat, opt_shadow_equal_percent: TAFloat) -> Self {
+        Self {
+            opt_body_percent,
+            opt_shadow_equal_percent,
+            ready: false,
+        }
+    }
+}
+
+impl Indicator for Doji {
+    type Output = TAInt;
+
+    fn update(&mut self, input: &Bar) -> Result<TAInt, KandError> {
+        let signal = cdl_doji_inc(
+            input.open,
+            input.high,
+            input.low,
+            input.close,
+            self.opt_body_percent,
+            self.opt_shadow_equal_percent,
+        )?;
+        self.ready = true;
+        Ok(signal)
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn reset(&mut self) {
+        self.ready = false;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,7 +563,7 @@ fn test_cdl_doji() {
         for &idx in &doji_indices {
             assert_eq!(
                 output_signals[idx],
-                Signal::Pattern.into(),
+                TAInt::from(Signal::Pattern),
                 "Expected doji signal at index {idx}"
             );
         }
@@ -326,4 +582,126 @@ fn test_cdl_doji() {
             assert_eq!(signal, output_signals[i], "Mismatch at index {i}");
         }
     }
+
+    #[test]
+    fn test_cdl_doji_indicator() {
+        let input_open = vec![
+            98826.3, 98554.6, 98610.4, 98508.0, 98314.1, 98245.0, 98214.2, 98486.1, 98563.5,
+            98419.9, 98249.6, 98074.7, 97797.1, 97925.6, 97544.2, 97140.3, 97285.7, 97486.5,
+            97009.3, 96554.9, 96542.5, 96450.1, 96772.8, 96797.0, 96662.7, 96252.2, 96131.1,
+            96364.3, 96274.1, 96448.0, 96408.3, 95960.1, 95946.0, 96238.8, 96358.5, 96770.6,
+            96884.2, 96613.9, 96489.0, 96710.1, 96779.9, 96149.6, 96548.1, 96560.0, 96923.0,
+            96567.1, 96571.7, 96341.5, 96515.0, 96720.2, 96746.1, 96461.1, 96460.9, 96735.0,
+            96679.9, 96759.9, 97350.8, 97216.6, 97346.4, 97419.9, 97534.2, 97521.6,
+        ];
+        let input_high = vec![
+            99019.9, 98680.0, 98618.7, 98590.0, 98443.9, 98366.8, 98745.5, 98745.6, 98711.4,
+            98454.3, 98922.1, 98356.2, 98025.4, 97952.6, 97554.0, 97285.7, 97500.0, 97500.0,
+            97076.1, 96754.1, 96826.5, 96795.0, 97154.2, 96936.7, 96797.1, 96415.7, 96430.0,
+            96539.7, 96530.5, 96883.1, 96412.7, 96161.9, 96327.2, 96408.3, 96781.0, 97041.4,
+            96913.2, 96696.8, 96730.7, 96827.7, 96794.7, 96577.5, 96560.0, 96923.0, 96923.0,
+            96638.4, 96634.5, 96576.4, 96896.7, 96896.5, 96788.3, 96563.4, 96815.0, 96822.3,
+            96835.0, 97805.8, 97561.9, 97473.4, 97480.0, 97586.0, 97727.7, 97639.8,
+        ];
+        let input_low = vec![
+            98550.0, 98465.0, 98300.0, 98252.3, 98135.6, 98122.0, 98214.2, 98350.0, 98223.3,
+            98067.6, 97712.7, 97743.3, 97593.6, 97468.1, 96963.3, 96866.9, 97147.7, 96845.8,
+            96536.0, 96337.2, 96330.0, 96440.0, 96592.4, 96662.7, 96220.0, 96111.0, 95811.1,
+            96161.5, 95880.1, 96390.5, 95860.0, 95613.5, 95736.0, 96093.4, 96337.3, 96650.8,
+            96609.1, 96313.0, 96050.4, 96522.0, 96036.0, 96130.0, 96313.1, 96410.4, 96548.1,
+            96439.6, 96161.1, 96311.8, 96488.5, 96611.9, 96446.1, 96358.3, 96456.2, 96600.0,
+            96508.0, 96700.0, 97150.0, 97021.3, 97290.0, 97333.5, 97411.4, 97355.0,
+        ];
+        let input_close = vec![
+            98554.6, 98610.4, 98507.9, 98314.1, 98245.0, 98214.1, 98485.8, 98563.5, 98419.9,
+            98249.5, 98074.7, 97797.1, 97925.6, 97546.1, 97140.3, 97285.6, 97486.5, 97009.3,
+            96555.0, 96542.5, 96450.1, 96772.8, 96796.9, 96662.7, 96252.3, 96131.1, 96364.4,
+            96274.1, 96447.8, 96408.3, 95960.1, 95946.1, 96238.8, 96359.0, 96770.6, 96884.2,
+            96613.9, 96489.1, 96710.0, 96780.0, 96149.6, 96548.1, 96560.0, 96923.0, 96567.2,
+            96571.7, 96341.5, 96515.1, 96720.2, 96746.1, 96461.0, 96460.9, 96735.0, 96679.9,
+            96759.9, 97350.9, 97216.7, 97346.3, 97419.9, 97534.2, 97521.5, 97384.1,
+        ];
+        let opt_body_percent = 5.0;
+        let opt_shadow_equal_percent = 100.0;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        cdl_doji(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_body_percent,
+            opt_shadow_equal_percent,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut indicator = Doji::new(opt_body_percent, opt_shadow_equal_percent);
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            assert_eq!(indicator.update(&bar).unwrap(), output_signals[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_cdl_doji_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0,
+            10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.6, 10.5,
+            10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.4, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.05, 10.2,
+            10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 10);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_doji_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[12] = Signal::Pattern.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_doji_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
 }
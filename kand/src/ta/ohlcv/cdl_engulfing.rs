@@ -0,0 +1,348 @@
+use crate::{
+    KandError, TAFloat, TAInt,
+    helper::bar_window,
+    indicator::Indicator,
+    types::{Bar, Signal},
+};
+
+/// Returns the lookback period for Engulfing pattern detection.
+///
+/// # Description
+/// The Engulfing pattern spans two candles, so the first signal is available once
+/// two bars have been seen.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 1
+///
+/// # Errors
+/// This function does not return any errors. It always returns `Ok(1)`.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cdl_engulfing;
+/// let lookback = cdl_engulfing::lookback().unwrap();
+/// assert_eq!(lookback, 1);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(1)
+}
+
+/// Detects Engulfing candlestick patterns in price data.
+///
+/// # Description
+/// An Engulfing pattern is a two-candle reversal where the second real body, of the opposite
+/// color, covers the first real body. A white candle engulfing a black one is bullish; a black
+/// candle engulfing a white one is bearish. One matching body edge is tolerated as long as the
+/// other edge extends beyond the first body.
+///
+/// Engulfing compares the two real bodies directly and uses no size thresholds, so unlike
+/// the other candlestick patterns it has no candle-settings variant.
+///
+/// # Mathematical Formula
+/// ```text
+/// Bullish = Close1 < Open1 AND Close2 > Open2 AND
+///           ((Close2 >= Open1 AND Open2 < Close1) OR (Close2 > Open1 AND Open2 <= Close1))
+/// Bearish = Close1 > Open1 AND Close2 < Open2 AND
+///           ((Open2 >= Close1 AND Close2 < Open1) OR (Open2 > Close1 AND Close2 <= Open1))
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `output_signals` - Output array for pattern signals:
+///   - Bullish (100): Bullish Engulfing
+///   - Bearish (-100): Bearish Engulfing
+///   - Neutral (0): No pattern detected
+///   - Invalid (-1): Fewer than two bars available
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than 2
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::cdl_engulfing, types::Signal};
+///
+/// let input_open = vec![10.0, 8.9, 11.0, 11.8];
+/// let input_high = vec![10.5, 11.2, 11.8, 12.0];
+/// let input_low = vec![8.8, 8.7, 10.8, 10.0];
+/// let input_close = vec![9.0, 11.0, 11.5, 10.2];
+/// let mut output_signals = vec![0i64; 4];
+///
+/// cdl_engulfing::cdl_engulfing(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// let expected: Vec<i64> = vec![Signal::Invalid.into(), Signal::Bullish.into(), Signal::Neutral.into(), Signal::Bearish.into()];
+/// assert_eq!(output_signals, expected);
+/// ```
+pub fn cdl_engulfing(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_engulfing_inc(&bar_window(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            i,
+        ))?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Evaluates the Engulfing pattern on a window of two consecutive candles.
+///
+/// # Description
+/// Checks whether the two most recent candles, ordered from oldest to newest, form the
+/// pattern. Because the pattern only looks at these bars, no state has to be carried between
+/// calls; slide the window forward by one bar to process the next candle.
+///
+/// # Arguments
+/// * `input_bars` - The last two candles, oldest first
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_engulfing_inc(input_bars: &[Bar; 2]) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [first, second] = input_bars;
+
+    let bullish = first.close < first.open
+        && second.close > second.open
+        && ((second.close >= first.open && second.open < first.close)
+            || (second.close > first.open && second.open <= first.close));
+    let bearish = first.close > first.open
+        && second.close < second.open
+        && ((second.open >= first.close && second.close < first.open)
+            || (second.open > first.close && second.close <= first.open));
+
+    Ok(if bullish {
+        Signal::Bullish.into()
+    } else if bearish {
+        Signal::Bearish.into()
+    } else {
+        Signal::Neutral.into()
+    })
+}
+
+/// Streaming Engulfing pattern detector.
+///
+/// Keeps the last two bars and emits [`Signal::Invalid`] until the window is full.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Engulfing {
+    window: [Bar; 2],
+    count: usize,
+}
+
+impl Engulfing {
+    /// Creates a streaming Engulfing pattern detector.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            window: [Bar::new(0.0, 0.0, 0.0, 0.0, 0.0); 2],
+            count: 0,
+        }
+    }
+}
+
+impl Indicator for Engulfing {
+    type Output = TAInt;
+
+    fn update(&mut self, input: &Bar) -> Result<TAInt, KandError> {
+        self.window.rotate_left(1);
+        self.window[1] = *input;
+        if self.count < 2 {
+            self.count += 1;
+            if self.count < 2 {
+                return Ok(Signal::Invalid.into());
+            }
+        }
+        cdl_engulfing_inc(&self.window)
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= 2
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_engulfing() {
+        let input_open = vec![10.0, 8.9, 11.0, 11.8];
+        let input_high = vec![10.5, 11.2, 11.8, 12.0];
+        let input_low = vec![8.8, 8.7, 10.8, 10.0];
+        let input_close = vec![9.0, 11.0, 11.5, 10.2];
+        let mut output_signals = vec![0i64; input_open.len()];
+
+        cdl_engulfing(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let expected: Vec<TAInt> = vec![
+            Signal::Invalid.into(),
+            Signal::Bullish.into(),
+            Signal::Neutral.into(),
+            Signal::Bearish.into(),
+        ];
+        assert_eq!(output_signals, expected);
+
+        // Test incremental calculation matches regular calculation
+        for (i, &expected) in output_signals.iter().enumerate().skip(lookback().unwrap()) {
+            let signal = cdl_engulfing_inc(&bar_window(
+                &input_open,
+                &input_high,
+                &input_low,
+                &input_close,
+                i,
+            ))
+            .unwrap();
+            assert_eq!(signal, expected, "Mismatch at index {i}");
+        }
+
+        // Fewer bars than the pattern spans
+        assert!(
+            cdl_engulfing(
+                &input_open[..1],
+                &input_high[..1],
+                &input_low[..1],
+                &input_close[..1],
+                &mut output_signals[..1],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cdl_engulfing_indicator() {
+        let input_open = vec![10.0, 8.9, 11.0, 11.8];
+        let input_high = vec![10.5, 11.2, 11.8, 12.0];
+        let input_low = vec![8.8, 8.7, 10.8, 10.0];
+        let input_close = vec![9.0, 11.0, 11.5, 10.2];
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        cdl_engulfing(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut indicator = Engulfing::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            assert_eq!(indicator.update(&bar).unwrap(), output_signals[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        let bar = Bar::new(
+            input_open[0],
+            input_high[0],
+            input_low[0],
+            input_close[0],
+            0.0,
+        );
+        assert_eq!(
+            indicator.update(&bar).unwrap(),
+            TAInt::from(Signal::Invalid)
+        );
+    }
+}
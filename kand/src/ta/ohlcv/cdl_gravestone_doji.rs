@@ -1,7 +1,11 @@
 use crate::{
     KandError, TAFloat, TAInt,
-    helper::{lower_shadow_length, real_body_length},
-    types::Signal,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, lower_shadow_length,
+        real_body_length, threshold_window, upper_shadow_length,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
 };
 
 /// Returns the lookback period required for Gravestone Doji pattern detection.
@@ -27,6 +31,34 @@ pub const fn lookback() -> Result<usize, KandError> {
     Ok(0)
 }
 
+/// Returns the lookback period for [`cdl_gravestone_doji_ext`].
+///
+/// # Description
+/// The candle is judged against averages of the candles before it, so the first signal needs
+/// the longest average period used by the pattern.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_gravestone_doji_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_gravestone_doji};
+/// let lookback = cdl_gravestone_doji::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 10);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings
+        .body_doji
+        .avg_period
+        .max(settings.shadow_very_short.avg_period))
+}
+
 /// Detects Gravestone Doji candlestick patterns in price data.
 ///
 /// # Description
@@ -126,6 +158,142 @@ pub fn cdl_gravestone_doji(
     Ok(())
 }
 
+/// Detects Gravestone Doji patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_gravestone_doji`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, following TA-Lib's `CDLGRAVESTONEDOJI`. With
+/// [`CandleSettings::default`] the pattern fires on the same candles as TA-Lib.
+///
+/// TA-Lib reports the pattern as +100; this module keeps its bearish reading.
+///
+/// # Mathematical Formula
+/// ```text
+/// BodyDoji(k) = settings.body_doji average for candle k
+/// ShadowVeryShort(k) = settings.shadow_very_short average for candle k
+///
+/// Gravestone = |Close - Open| <= BodyDoji(i)
+///              AND LowerShadow < ShadowVeryShort(i)
+///              AND UpperShadow > ShadowVeryShort(i)
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_gravestone_doji`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_gravestone_doji, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([10.2]);
+/// input_high.extend([11.2]);
+/// input_low.extend([10.14]);
+/// input_close.extend([10.15]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_gravestone_doji::cdl_gravestone_doji_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[12], Signal::Bearish.into());
+/// ```
+pub fn cdl_gravestone_doji_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_gravestone_doji_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
 /// Processes a single candlestick to detect a Gravestone Doji pattern.
 ///
 /// # Description
@@ -203,6 +371,105 @@ pub fn cdl_gravestone_doji_inc(
     Ok(output_signal)
 }
 
+/// Evaluates the TA-Lib style Gravestone Doji pattern on a single candle.
+///
+/// # Description
+/// Incremental form of [`cdl_gravestone_doji_ext`]. `input_thresholds[k]` holds the candle averages
+/// of `input_bars[k]`, as returned by
+/// [`CandleAverages::value`](crate::helper::CandleAverages::value) before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The current candle
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_gravestone_doji_ext_inc(
+    input_bars: &[Bar; 1],
+    input_thresholds: &[CandleThresholds; 1],
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [candle] = input_bars;
+    let [candle_avg] = input_thresholds;
+    let body = real_body_length(candle.open, candle.close);
+    let up_shadow = upper_shadow_length(candle.high, candle.open, candle.close);
+    let dn_shadow = lower_shadow_length(candle.low, candle.open, candle.close);
+    Ok(
+        if body <= candle_avg.body_doji
+            && dn_shadow < candle_avg.shadow_very_short
+            && up_shadow > candle_avg.shadow_very_short
+        {
+            Signal::Bearish.into()
+        } else {
+            Signal::Neutral.into()
+        },
+    )
+}
+
+/// Streaming Gravestone Doji pattern detector.
+///
+/// The pattern depends on the current bar only; the struct keeps the thresholds so it can be
+/// driven through [`Indicator`] alongside stateful indicators.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GravestoneDoji {
+    opt_body_percent: TAFloat,
+    ready: bool,
+}
+
+impl GravestoneDoji {
+    /// Creates a streaming Gravestone Doji pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_body_percent` - Maximum body size as a percentage of the candle range
+    #[must_use]
+    pub const fn new(opt_body_percent: TAFloat) -> Self {
+        Self {
+            opt_body_percent,
+            ready: false,
+        }
+    }
+}
+
+impl Indicator for GravestoneDoji {
+    type Output = TAInt;
+
+    fn update(&mut self, input: &Bar) -> Result<TAInt, KandError> {
+        let signal = cdl_gravestone_doji_inc(
+            input.open,
+            input.high,
+            input.low,
+            input.close,
+            self.opt_body_percent,
+        )?;
+        self.ready = true;
+        Ok(signal)
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn reset(&mut self) {
+        self.ready = false;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,7 +513,7 @@ fn test_cdl_gravestone_doji() {
         println!("output_signals: {output_signals:?}");
 
         // Test specific signals
-        assert_eq!(output_signals[15], Signal::Bearish.into()); // TV BTCUSDT.P 5m 2025-01-29 03:45
+        assert_eq!(output_signals[15], TAInt::from(Signal::Bearish)); // TV BTCUSDT.P 5m 2025-01-29 03:45
 
         // Test incremental calculation matches regular calculation
         for i in 0..18 {
@@ -261,4 +528,108 @@ fn test_cdl_gravestone_doji() {
             assert_eq!(output_signal, output_signals[i]);
         }
     }
+
+    #[test]
+    fn test_cdl_gravestone_doji_indicator() {
+        let input_open = vec![
+            102_730.6, 102_233.5, 102_003.4, 102_330.6, 102_211.8, 102_994.9, 102_817.5, 102_407.9,
+            102_525.3, 103_002.3, 102_826.2, 102_499.1, 102_161.0, 102_033.9, 102_191.6, 102_358.0,
+            102_368.7, 102_354.8, 101_928.3, 101_923.5, 101_226.3,
+        ];
+        let input_high = vec![
+            102_870.3, 102_421.8, 102_374.7, 102_543.5, 103_065.9, 103_059.4, 102_899.3, 102_713.6,
+            103_089.9, 103_083.5, 102_914.6, 102_510.8, 102_204.8, 102_366.8, 102_358.1, 102_624.0,
+            102_495.0, 102_354.9, 102_115.4, 101_933.7, 101_449.1,
+        ];
+        let input_low = vec![
+            102_205.0, 101_850.0, 101_984.1, 101_921.2, 102_170.3, 102_700.0, 102_301.0, 102_308.3,
+            102_336.7, 102_733.2, 102_435.0, 102_123.9, 101_778.0, 101_929.2, 101_994.0, 102_357.9,
+            102_241.4, 101_921.8, 101_852.7, 101_195.2, 101_056.1,
+        ];
+        let input_close = vec![
+            102_233.4, 102_003.3, 102_330.6, 102_211.8, 102_994.8, 102_817.5, 102_407.8, 102_525.2,
+            103_002.3, 102_826.3, 102_499.2, 102_161.1, 102_033.8, 102_191.6, 102_358.0, 102_368.7,
+            102_354.8, 101_928.4, 101_923.6, 101_226.3, 101_260.0,
+        ];
+        let opt_body_percent = 5.0;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        cdl_gravestone_doji(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_body_percent,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut indicator = GravestoneDoji::new(opt_body_percent);
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            assert_eq!(indicator.update(&bar).unwrap(), output_signals[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_cdl_gravestone_doji_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.2, 10.0,
+            10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 11.2, 10.5,
+            10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 10.14, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.15, 10.2,
+            10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 10);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_gravestone_doji_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[12] = Signal::Bearish.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_gravestone_doji_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
 }
@@ -1,7 +1,11 @@
 use crate::{
     KandError, TAFloat, TAInt,
-    helper::{lower_shadow_length, period_to_k, real_body_length, upper_shadow_length},
-    types::Signal,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, lower_shadow_length,
+        period_to_k, real_body_length, threshold_window, upper_shadow_length,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
 };
 
 /// Returns the lookback period for Hammer pattern detection.
@@ -35,6 +39,37 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
     Ok(opt_period - 1)
 }
 
+/// Returns the lookback period for [`cdl_hammer_ext`].
+///
+/// # Description
+/// Every candle of the pattern is judged against averages of the candles before it, so the first
+/// signal needs the longest average period used by the pattern plus the candles it spans.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_hammer_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_hammer};
+/// let lookback = cdl_hammer::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 11);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings
+        .body_short
+        .avg_period
+        .max(settings.shadow_long.avg_period)
+        .max(settings.shadow_very_short.avg_period)
+        .max(settings.near.avg_period)
+        + 1)
+}
+
 /// Detects Hammer candlestick patterns in price data.
 ///
 /// # Description
@@ -188,6 +223,143 @@ pub fn cdl_hammer(
     Ok(())
 }
 
+/// Detects Hammer patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_hammer`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, following TA-Lib's `CDLHAMMER`. With
+/// [`CandleSettings::default`] the pattern fires on the same candles as TA-Lib.
+///
+/// # Mathematical Formula
+/// ```text
+/// BodyShort(k) = settings.body_short average for candle k
+/// ShadowLong(k) = settings.shadow_long average for candle k
+/// ShadowVeryShort(k) = settings.shadow_very_short average for candle k
+/// Near(k) = settings.near average for candle k
+///
+/// Hammer = |Close - Open| < BodyShort(i)
+///          AND LowerShadow > ShadowLong(i)
+///          AND UpperShadow < ShadowVeryShort(i)
+///          AND min(Open, Close) <= Low(i-1) + Near(i-1)
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_hammer`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_hammer, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([9.6]);
+/// input_high.extend([9.68]);
+/// input_low.extend([9.0]);
+/// input_close.extend([9.65]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_hammer::cdl_hammer_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[12], Signal::Bullish.into());
+/// ```
+pub fn cdl_hammer_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_hammer_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
 /// Incrementally processes a single candlestick for Hammer pattern detection.
 ///
 /// # Description
@@ -284,6 +456,129 @@ pub fn cdl_hammer_inc(
     Ok((signal, body_avg))
 }
 
+/// Evaluates the TA-Lib style Hammer pattern on a window of two consecutive candles.
+///
+/// # Description
+/// Incremental form of [`cdl_hammer_ext`]. `input_thresholds[k]` holds the candle averages of
+/// `input_bars[k]`, as returned by [`CandleAverages::value`](crate::helper::CandleAverages::value)
+/// before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The last two candles, oldest first
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_hammer_ext_inc(
+    input_bars: &[Bar; 2],
+    input_thresholds: &[CandleThresholds; 2],
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [prev_avg, curr_avg] = input_thresholds;
+    let [prev, curr] = input_bars;
+    let body = real_body_length(curr.open, curr.close);
+    let up_shadow = upper_shadow_length(curr.high, curr.open, curr.close);
+    let dn_shadow = lower_shadow_length(curr.low, curr.open, curr.close);
+    Ok(
+        if body < curr_avg.body_short
+            && dn_shadow > curr_avg.shadow_long
+            && up_shadow < curr_avg.shadow_very_short
+            && curr.open.min(curr.close) <= prev.low + prev_avg.near
+        {
+            Signal::Bullish.into()
+        } else {
+            Signal::Neutral.into()
+        },
+    )
+}
+
+/// Streaming Hammer pattern detector.
+///
+/// Seeds the body average with the mean body length of the first `opt_period` bars, then updates
+/// it with [`cdl_hammer_inc`]. Each update returns `(signal, body_avg)`.
+/// Bars before the body average is seeded yield [`Signal::Invalid`], matching [`cdl_hammer`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hammer {
+    opt_period: usize,
+    opt_factor: TAFloat,
+    count: usize,
+    body_sum: TAFloat,
+    body_avg: TAFloat,
+}
+
+impl Hammer {
+    /// Creates a streaming Hammer pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_period` - Period for the body average EMA
+    /// * `opt_factor` - Minimum lower shadow to body ratio
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` < 2
+    pub fn new(opt_period: usize, opt_factor: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            opt_factor,
+            count: 0,
+            body_sum: 0.0,
+            body_avg: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Hammer {
+    type Output = (TAInt, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<(TAInt, TAFloat), KandError> {
+        if self.count < self.opt_period {
+            self.count += 1;
+            self.body_sum += real_body_length(input.open, input.close);
+            if self.count < self.opt_period {
+                return Ok((Signal::Invalid.into(), TAFloat::NAN));
+            }
+            self.body_avg = self.body_sum / self.opt_period as TAFloat;
+        }
+        let (signal, body_avg) = cdl_hammer_inc(
+            input.open,
+            input.high,
+            input.low,
+            input.close,
+            self.body_avg,
+            self.opt_period,
+            self.opt_factor,
+        )?;
+        self.body_avg = body_avg;
+        Ok((signal, body_avg))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.body_sum = 0.0;
+        self.body_avg = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -348,14 +643,14 @@ fn test_cdl_hammer() {
 
         // First 13 values should be -1
         for i in 0..13 {
-            assert_eq!(output_signals[i], Signal::Invalid.into());
+            assert_eq!(output_signals[i], TAInt::from(Signal::Invalid));
             assert!(output_body_avg[i].is_nan());
         }
 
         // Test specific signals
-        assert_eq!(output_signals[16], Signal::Bullish.into()); // TV BTCUSDT.P 5m 2025-02-03 06:30
-        assert_eq!(output_signals[54], Signal::Bullish.into()); // TV BTCUSDT.P 5m 2025-02-03 16:00
-        assert_eq!(output_signals[59], Signal::Bullish.into()); // TV BTCUSDT.P 5m 2025-02-03 17:15
+        assert_eq!(output_signals[16], TAInt::from(Signal::Bullish)); // TV BTCUSDT.P 5m 2025-02-03 06:30
+        assert_eq!(output_signals[54], TAInt::from(Signal::Bullish)); // TV BTCUSDT.P 5m 2025-02-03 16:00
+        assert_eq!(output_signals[59], TAInt::from(Signal::Bullish)); // TV BTCUSDT.P 5m 2025-02-03 17:15
 
         // Test incremental calculation matches regular calculation
         let mut prev_body_avg = output_body_avg[13]; // First valid body average
@@ -377,4 +672,135 @@ fn test_cdl_hammer() {
             prev_body_avg = new_body_avg;
         }
     }
+
+    #[test]
+    fn test_cdl_hammer_indicator() {
+        let input_open = vec![
+            97798.1, 96982.9, 97050.5, 97281.3, 97480.7, 98310.4, 98232.0, 98473.2, 98136.9,
+            97912.7, 97759.0, 97516.4, 96913.4, 96738.1, 96999.0, 97472.5, 97368.3, 97140.0,
+            97971.6, 97684.9, 96985.2, 97298.6, 97664.5, 97286.7, 97041.2, 95591.8, 96464.5,
+            95750.1, 95132.6, 94132.8, 93408.4, 94009.0, 93876.7, 93847.5, 93539.2, 94308.4,
+            94403.9, 93820.9, 94001.6, 93880.0, 93317.1, 92969.9, 92765.2, 92874.4, 93562.1,
+            93583.3, 94171.9, 93910.0, 94387.7, 93965.3, 93872.7, 93974.7, 94162.4, 94518.5,
+            95271.1, 95354.0, 95340.9, 94978.8, 95281.6, 95742.5, 95829.2, 95680.3, 95227.2,
+        ];
+        let input_high = vec![
+            97833.9, 97420.1, 97562.1, 97550.0, 98371.8, 98667.2, 98594.9, 98523.7, 98216.5,
+            97912.7, 97947.4, 97582.8, 97294.2, 97051.5, 97683.0, 97700.0, 97368.3, 97999.0,
+            97985.8, 97897.9, 97608.8, 97755.5, 97748.0, 97570.0, 97167.0, 96869.7, 96875.7,
+            95825.8, 95430.9, 94150.6, 94282.2, 94983.2, 94159.7, 94052.2, 94577.2, 94645.4,
+            94473.2, 94217.7, 94461.0, 94102.9, 93525.7, 93480.0, 93168.0, 93650.0, 93931.3,
+            94249.5, 94204.0, 94421.0, 94578.5, 94237.2, 94162.8, 94303.4, 94662.1, 95373.1,
+            95354.1, 95525.7, 95582.9, 95380.6, 95830.0, 95891.9, 95877.8, 95713.6, 95380.0,
+        ];
+        let input_low = vec![
+            96750.1, 96760.0, 96759.1, 96985.1, 97469.9, 97982.8, 98161.2, 98043.2, 97780.9,
+            97618.2, 97481.4, 96880.4, 96520.0, 96576.3, 96948.1, 97131.8, 96029.6, 97023.7,
+            97130.0, 96500.0, 96716.2, 97273.0, 97226.8, 96006.0, 95325.6, 95539.0, 95740.3,
+            95006.0, 93750.0, 91800.1, 91130.3, 93547.0, 93170.9, 93290.4, 93400.0, 94239.3,
+            93732.0, 93748.6, 93725.6, 93118.8, 92777.9, 92595.7, 92739.1, 92843.5, 93430.5,
+            93520.1, 93700.9, 93781.1, 93924.6, 93605.1, 93805.7, 93974.7, 93964.4, 94404.9,
+            94852.4, 95011.0, 94793.8, 94914.3, 95211.9, 95225.3, 95476.5, 95180.0, 95081.1,
+        ];
+        let input_close = vec![
+            96977.5, 97050.5, 97281.2, 97480.7, 98310.3, 98232.0, 98473.2, 98136.8, 97912.7,
+            97759.1, 97516.4, 96913.4, 96738.0, 96998.8, 97472.6, 97368.3, 97140.0, 97971.6,
+            97684.9, 96985.3, 97298.6, 97664.5, 97287.8, 97041.2, 95591.8, 96464.6, 95750.2,
+            95132.6, 94132.8, 93408.4, 94009.0, 93876.7, 93847.5, 93539.1, 94308.4, 94403.8,
+            93820.9, 94001.6, 93879.9, 93317.1, 92969.8, 92765.2, 92874.4, 93562.1, 93583.4,
+            94171.9, 93909.9, 94387.6, 93965.3, 93872.7, 93974.6, 94162.3, 94518.6, 95271.1,
+            95354.1, 95340.9, 94978.9, 95281.5, 95742.6, 95829.2, 95680.2, 95227.2, 95223.8,
+        ];
+        let opt_period = 14;
+        let opt_factor = 2.0;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        let mut output_body_avg = vec![0.0; len];
+        cdl_hammer(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_factor,
+            &mut output_signals,
+            &mut output_body_avg,
+        )
+        .unwrap();
+
+        let mut indicator = Hammer::new(opt_period, opt_factor).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            let (signal, body_avg) = indicator.update(&bar).unwrap();
+            assert_eq!(signal, output_signals[i]);
+            if i < lookback {
+                assert!(body_avg.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(body_avg, output_body_avg[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_cdl_hammer_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 9.6, 10.0, 10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 9.68, 10.5,
+            10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.0, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 9.65, 10.2,
+            10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 11);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_hammer_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[12] = Signal::Bullish.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_hammer_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
 }
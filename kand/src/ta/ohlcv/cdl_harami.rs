@@ -0,0 +1,658 @@
+use crate::{
+    KandError, TAFloat, TAInt,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, real_body_length,
+        real_body_percent, threshold_window,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
+};
+
+/// Returns the lookback period for Harami pattern detection.
+///
+/// # Description
+/// The Harami pattern spans two candles, so the first signal is available once
+/// two bars have been seen.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 1
+///
+/// # Errors
+/// This function does not return any errors. It always returns `Ok(1)`.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cdl_harami;
+/// let lookback = cdl_harami::lookback().unwrap();
+/// assert_eq!(lookback, 1);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(1)
+}
+
+/// Returns the lookback period for [`cdl_harami_ext`].
+///
+/// # Description
+/// Every candle of the pattern is judged against averages of the candles before it, so the first
+/// signal needs the longest average period used by the pattern plus the candles it spans.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_harami_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_harami};
+/// let lookback = cdl_harami::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 11);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings
+        .body_long
+        .avg_period
+        .max(settings.body_short.avg_period)
+        + 1)
+}
+
+/// Detects Harami candlestick patterns in price data.
+///
+/// # Description
+/// A Harami is a two-candle reversal where a short real body sits strictly inside the long real
+/// body before it. The signal points against the first candle: bullish after a long black
+/// candle, bearish after a long white one.
+///
+/// # Mathematical Formula
+/// ```text
+/// Long1 = |Close1 - Open1| / (High1 - Low1) * 100 >= BodyLongPercent
+/// Short2 = |Close2 - Open2| / (High2 - Low2) * 100 <= BodyShortPercent
+/// Inside = max(Open2, Close2) < max(Open1, Close1) AND min(Open2, Close2) > min(Open1, Close1)
+/// Signal = Long1 AND Short2 AND Inside ? -sign(Close1 - Open1) : Neutral
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_body_long_percent` - Minimum body size of a long candle as a percentage of its range (e.g. 60.0)
+/// * `opt_body_short_percent` - Maximum body size of a short candle as a percentage of its range (e.g. 30.0)
+/// * `output_signals` - Output array for pattern signals:
+///   - Bullish (100): Harami after a long black candle
+///   - Bearish (-100): Harami after a long white candle
+///   - Neutral (0): No pattern detected
+///   - Invalid (-1): Fewer than two bars available
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than 2
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If a body percentage is <= 0
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::cdl_harami, types::Signal};
+///
+/// let input_open = vec![10.0, 8.5, 8.8, 10.2, 10.0];
+/// let input_high = vec![10.2, 9.3, 11.1, 10.9, 10.6];
+/// let input_low = vec![7.8, 8.1, 8.7, 9.9, 9.6];
+/// let input_close = vec![8.0, 8.8, 11.0, 10.0, 10.5];
+/// let mut output_signals = vec![0i64; 5];
+///
+/// cdl_harami::cdl_harami(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     60.0, // opt_body_long_percent
+///     30.0, // opt_body_short_percent
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// let expected: Vec<i64> = vec![Signal::Invalid.into(), Signal::Bullish.into(), Signal::Neutral.into(), Signal::Bearish.into(), Signal::Neutral.into()];
+/// assert_eq!(output_signals, expected);
+/// ```
+pub fn cdl_harami(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_body_long_percent: TAFloat,
+    opt_body_short_percent: TAFloat,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_body_long_percent <= 0.0 || opt_body_short_percent <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_harami_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            opt_body_long_percent,
+            opt_body_short_percent,
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Detects Harami patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_harami`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, following TA-Lib's `CDLHARAMI`. With
+/// [`CandleSettings::default`] the pattern fires on the same candles as TA-Lib.
+///
+/// # Mathematical Formula
+/// ```text
+/// BodyLong(k) = settings.body_long average for candle k
+/// BodyShort(k) = settings.body_short average for candle k
+///
+/// Harami = |Close(i-1) - Open(i-1)| > BodyLong(i-1)
+///          AND |Close - Open| <= BodyShort(i)
+///          AND max(Open, Close) < max(Open(i-1), Close(i-1))
+///          AND min(Open, Close) > min(Open(i-1), Close(i-1))
+/// Signal = Harami ? (Close(i-1) >= Open(i-1) ? Bearish : Bullish) : Neutral
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_harami`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_harami, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([10.8, 10.3]);
+/// input_high.extend([10.85, 10.5]);
+/// input_low.extend([9.9, 10.2]);
+/// input_close.extend([10.0, 10.4]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_harami::cdl_harami_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[13], Signal::Bullish.into());
+/// ```
+pub fn cdl_harami_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_harami_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Evaluates the Harami pattern on a window of two consecutive candles.
+///
+/// # Description
+/// Checks whether the two most recent candles, ordered from oldest to newest, form the
+/// pattern. Because the pattern only looks at these bars, no state has to be carried between
+/// calls; slide the window forward by one bar to process the next candle.
+///
+/// # Arguments
+/// * `input_bars` - The last two candles, oldest first
+/// * `opt_body_long_percent` - Minimum body size of a long candle as a percentage of its range
+/// * `opt_body_short_percent` - Maximum body size of a short candle as a percentage of its range
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If a body percentage is <= 0
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_harami_inc(
+    input_bars: &[Bar; 2],
+    opt_body_long_percent: TAFloat,
+    opt_body_short_percent: TAFloat,
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_body_long_percent <= 0.0 || opt_body_short_percent <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [first, second] = input_bars;
+
+    let long_first =
+        real_body_percent(first.open, first.high, first.low, first.close) >= opt_body_long_percent;
+    let short_second = real_body_percent(second.open, second.high, second.low, second.close)
+        <= opt_body_short_percent;
+    let inside = second.open.max(second.close) < first.open.max(first.close)
+        && second.open.min(second.close) > first.open.min(first.close);
+
+    Ok(if long_first && short_second && inside {
+        if first.close > first.open {
+            Signal::Bearish.into()
+        } else {
+            Signal::Bullish.into()
+        }
+    } else {
+        Signal::Neutral.into()
+    })
+}
+
+/// Evaluates the TA-Lib style Harami pattern on a window of two consecutive candles.
+///
+/// # Description
+/// Incremental form of [`cdl_harami_ext`]. `input_thresholds[k]` holds the candle averages of
+/// `input_bars[k]`, as returned by [`CandleAverages::value`](crate::helper::CandleAverages::value)
+/// before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The last two candles, oldest first
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_harami_ext_inc(
+    input_bars: &[Bar; 2],
+    input_thresholds: &[CandleThresholds; 2],
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [prev_avg, curr_avg] = input_thresholds;
+    let [prev, curr] = input_bars;
+    let inside = curr.open.max(curr.close) < prev.open.max(prev.close)
+        && curr.open.min(curr.close) > prev.open.min(prev.close);
+    Ok(
+        if real_body_length(prev.open, prev.close) > prev_avg.body_long
+            && real_body_length(curr.open, curr.close) <= curr_avg.body_short
+            && inside
+        {
+            if prev.close >= prev.open {
+                Signal::Bearish.into()
+            } else {
+                Signal::Bullish.into()
+            }
+        } else {
+            Signal::Neutral.into()
+        },
+    )
+}
+
+/// Streaming Harami pattern detector.
+///
+/// Keeps the last two bars and emits [`Signal::Invalid`] until the window is full.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Harami {
+    opt_body_long_percent: TAFloat,
+    opt_body_short_percent: TAFloat,
+    window: [Bar; 2],
+    count: usize,
+}
+
+impl Harami {
+    /// Creates a streaming Harami pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_body_long_percent` - Minimum body size of a long candle as a percentage of its range
+    /// * `opt_body_short_percent` - Maximum body size of a short candle as a percentage of its range
+    #[must_use]
+    pub const fn new(opt_body_long_percent: TAFloat, opt_body_short_percent: TAFloat) -> Self {
+        Self {
+            opt_body_long_percent,
+            opt_body_short_percent,
+            window: [Bar::new(0.0, 0.0, 0.0, 0.0, 0.0); 2],
+            count: 0,
+        }
+    }
+}
+
+impl Indicator for Harami {
+    type Output = TAInt;
+
+    fn update(&mut self, input: &Bar) -> Result<TAInt, KandError> {
+        self.window.rotate_left(1);
+        self.window[1] = *input;
+        if self.count < 2 {
+            self.count += 1;
+            if self.count < 2 {
+                return Ok(Signal::Invalid.into());
+            }
+        }
+        cdl_harami_inc(
+            &self.window,
+            self.opt_body_long_percent,
+            self.opt_body_short_percent,
+        )
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= 2
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_harami() {
+        let input_open = vec![10.0, 8.5, 8.8, 10.2, 10.0];
+        let input_high = vec![10.2, 9.3, 11.1, 10.9, 10.6];
+        let input_low = vec![7.8, 8.1, 8.7, 9.9, 9.6];
+        let input_close = vec![8.0, 8.8, 11.0, 10.0, 10.5];
+        let opt_body_long_percent = 60.0;
+        let opt_body_short_percent = 30.0;
+        let mut output_signals = vec![0i64; input_open.len()];
+
+        cdl_harami(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_body_long_percent,
+            opt_body_short_percent,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let expected: Vec<TAInt> = vec![
+            Signal::Invalid.into(),
+            Signal::Bullish.into(),
+            Signal::Neutral.into(),
+            Signal::Bearish.into(),
+            Signal::Neutral.into(),
+        ];
+        assert_eq!(output_signals, expected);
+
+        // Test incremental calculation matches regular calculation
+        for (i, &expected) in output_signals.iter().enumerate().skip(lookback().unwrap()) {
+            let signal = cdl_harami_inc(
+                &bar_window(&input_open, &input_high, &input_low, &input_close, i),
+                opt_body_long_percent,
+                opt_body_short_percent,
+            )
+            .unwrap();
+            assert_eq!(signal, expected, "Mismatch at index {i}");
+        }
+
+        // Fewer bars than the pattern spans
+        assert!(
+            cdl_harami(
+                &input_open[..1],
+                &input_high[..1],
+                &input_low[..1],
+                &input_close[..1],
+                opt_body_long_percent,
+                opt_body_short_percent,
+                &mut output_signals[..1],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cdl_harami_indicator() {
+        let input_open = vec![10.0, 8.5, 8.8, 10.2, 10.0];
+        let input_high = vec![10.2, 9.3, 11.1, 10.9, 10.6];
+        let input_low = vec![7.8, 8.1, 8.7, 9.9, 9.6];
+        let input_close = vec![8.0, 8.8, 11.0, 10.0, 10.5];
+        let opt_body_long_percent = 60.0;
+        let opt_body_short_percent = 30.0;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        cdl_harami(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_body_long_percent,
+            opt_body_short_percent,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut indicator = Harami::new(opt_body_long_percent, opt_body_short_percent);
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            assert_eq!(indicator.update(&bar).unwrap(), output_signals[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        let bar = Bar::new(
+            input_open[0],
+            input_high[0],
+            input_low[0],
+            input_close[0],
+            0.0,
+        );
+        assert_eq!(
+            indicator.update(&bar).unwrap(),
+            TAInt::from(Signal::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_cdl_harami_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.8, 10.3,
+            10.0, 10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.85, 10.5,
+            10.5, 10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.9, 10.2, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.0, 10.4,
+            10.2, 10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 11);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_harami_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[13] = Signal::Bullish.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_harami_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
+}
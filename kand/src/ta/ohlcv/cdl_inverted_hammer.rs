@@ -1,7 +1,11 @@
 use crate::{
     KandError, TAFloat, TAInt,
-    helper::{lower_shadow_length, period_to_k, real_body_length, upper_shadow_length},
-    types::Signal,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, has_real_body_gap_down,
+        lower_shadow_length, period_to_k, real_body_length, threshold_window, upper_shadow_length,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
 };
 
 /// Returns the required lookback period for Inverted Hammer pattern detection.
@@ -39,6 +43,36 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
     Ok(opt_period - 1)
 }
 
+/// Returns the lookback period for [`cdl_inverted_hammer_ext`].
+///
+/// # Description
+/// Every candle of the pattern is judged against averages of the candles before it, so the first
+/// signal needs the longest average period used by the pattern plus the candles it spans.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_inverted_hammer_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_inverted_hammer};
+/// let lookback = cdl_inverted_hammer::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 11);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings
+        .body_short
+        .avg_period
+        .max(settings.shadow_long.avg_period)
+        .max(settings.shadow_very_short.avg_period)
+        + 1)
+}
+
 /// Detects Inverted Hammer candlestick patterns in price data.
 ///
 /// # Description
@@ -177,6 +211,142 @@ pub fn cdl_inverted_hammer(
     Ok(())
 }
 
+/// Detects Inverted Hammer patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_inverted_hammer`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, following TA-Lib's `CDLINVERTEDHAMMER`. With
+/// [`CandleSettings::default`] the pattern fires on the same candles as TA-Lib.
+///
+/// # Mathematical Formula
+/// ```text
+/// BodyShort(k) = settings.body_short average for candle k
+/// ShadowLong(k) = settings.shadow_long average for candle k
+/// ShadowVeryShort(k) = settings.shadow_very_short average for candle k
+///
+/// InvertedHammer = |Close - Open| < BodyShort(i)
+///                  AND UpperShadow > ShadowLong(i)
+///                  AND LowerShadow < ShadowVeryShort(i)
+///                  AND max(Open, Close) < min(Open(i-1), Close(i-1))
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_inverted_hammer`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_inverted_hammer, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([9.8]);
+/// input_high.extend([10.4]);
+/// input_low.extend([9.78]);
+/// input_close.extend([9.85]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_inverted_hammer::cdl_inverted_hammer_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[12], Signal::Bullish.into());
+/// ```
+pub fn cdl_inverted_hammer_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_inverted_hammer_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
 /// Processes a single candlestick to detect an Inverted Hammer pattern.
 ///
 /// # Description
@@ -273,6 +443,129 @@ pub fn cdl_inverted_hammer_inc(
     Ok((signal, body_avg))
 }
 
+/// Evaluates the TA-Lib style Inverted Hammer pattern on a window of two consecutive candles.
+///
+/// # Description
+/// Incremental form of [`cdl_inverted_hammer_ext`]. `input_thresholds[k]` holds the candle averages
+/// of `input_bars[k]`, as returned by
+/// [`CandleAverages::value`](crate::helper::CandleAverages::value) before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The last two candles, oldest first
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_inverted_hammer_ext_inc(
+    input_bars: &[Bar; 2],
+    input_thresholds: &[CandleThresholds; 2],
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [_, curr_avg] = input_thresholds;
+    let [prev, curr] = input_bars;
+    let body = real_body_length(curr.open, curr.close);
+    let up_shadow = upper_shadow_length(curr.high, curr.open, curr.close);
+    let dn_shadow = lower_shadow_length(curr.low, curr.open, curr.close);
+    Ok(
+        if body < curr_avg.body_short
+            && up_shadow > curr_avg.shadow_long
+            && dn_shadow < curr_avg.shadow_very_short
+            && has_real_body_gap_down(curr.open, curr.close, prev.open, prev.close)
+        {
+            Signal::Bullish.into()
+        } else {
+            Signal::Neutral.into()
+        },
+    )
+}
+
+/// Streaming Inverted Hammer pattern detector.
+///
+/// Seeds the body average with the mean body length of the first `opt_period` bars, then updates
+/// it with [`cdl_inverted_hammer_inc`]. Each update returns `(signal, body_avg)`.
+/// Bars before the body average is seeded yield [`Signal::Invalid`], matching [`cdl_inverted_hammer`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InvertedHammer {
+    opt_period: usize,
+    opt_factor: TAFloat,
+    count: usize,
+    body_sum: TAFloat,
+    body_avg: TAFloat,
+}
+
+impl InvertedHammer {
+    /// Creates a streaming Inverted Hammer pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_period` - Period for the body average EMA
+    /// * `opt_factor` - Minimum upper shadow to body ratio
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` < 2
+    pub fn new(opt_period: usize, opt_factor: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            opt_factor,
+            count: 0,
+            body_sum: 0.0,
+            body_avg: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for InvertedHammer {
+    type Output = (TAInt, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<(TAInt, TAFloat), KandError> {
+        if self.count < self.opt_period {
+            self.count += 1;
+            self.body_sum += real_body_length(input.open, input.close);
+            if self.count < self.opt_period {
+                return Ok((Signal::Invalid.into(), TAFloat::NAN));
+            }
+            self.body_avg = self.body_sum / self.opt_period as TAFloat;
+        }
+        let (signal, body_avg) = cdl_inverted_hammer_inc(
+            input.open,
+            input.high,
+            input.low,
+            input.close,
+            self.body_avg,
+            self.opt_period,
+            self.opt_factor,
+        )?;
+        self.body_avg = body_avg;
+        Ok((signal, body_avg))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.body_sum = 0.0;
+        self.body_avg = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -333,10 +626,10 @@ fn test_cdl_inverted_hammer() {
         println!("output_body_avg: {output_body_avg:?}");
 
         // Test specific signals
-        assert_eq!(output_signals[19], Signal::Bullish.into()); // TV BTCUSDT.P 5m 2025-02-08 14:05
-        assert_eq!(output_signals[23], Signal::Bullish.into()); // TV BTCUSDT.P 5m 2025-02-08 14:25
-        assert_eq!(output_signals[25], Signal::Bullish.into()); // TV BTCUSDT.P 5m 2025-02-08 14:35
-        assert_eq!(output_signals[28], Signal::Bullish.into()); // TV BTCUSDT.P 5m 2025-02-08 14:50
+        assert_eq!(output_signals[19], TAInt::from(Signal::Bullish)); // TV BTCUSDT.P 5m 2025-02-08 14:05
+        assert_eq!(output_signals[23], TAInt::from(Signal::Bullish)); // TV BTCUSDT.P 5m 2025-02-08 14:25
+        assert_eq!(output_signals[25], TAInt::from(Signal::Bullish)); // TV BTCUSDT.P 5m 2025-02-08 14:35
+        assert_eq!(output_signals[28], TAInt::from(Signal::Bullish)); // TV BTCUSDT.P 5m 2025-02-08 14:50
 
         // Test incremental calculation matches regular calculation
         let mut prev_body_avg = output_body_avg[13]; // First valid body average
@@ -358,4 +651,123 @@ fn test_cdl_inverted_hammer() {
             prev_body_avg = new_body_avg;
         }
     }
+
+    #[test]
+    fn test_cdl_inverted_hammer_indicator() {
+        let input_open = vec![
+            96470.4, 96415.2, 96386.1, 96259.3, 96290.1, 96307.4, 96266.7, 96200.0, 96078.4,
+            96175.2, 96123.1, 96242.9, 96149.3, 96104.0, 96191.9, 96236.3, 96278.1, 96200.6,
+            96164.9, 96113.6, 96095.8, 96051.1, 96085.9, 96074.0, 96092.5, 96052.5, 96067.5,
+            96100.0, 96067.1, 96054.1, 95951.3,
+        ];
+        let input_high = vec![
+            96470.5, 96450.0, 96386.1, 96344.7, 96374.1, 96312.5, 96300.0, 96244.8, 96183.1,
+            96198.5, 96242.9, 96275.6, 96156.9, 96211.7, 96240.5, 96323.0, 96300.0, 96200.7,
+            96165.0, 96162.2, 96118.1, 96107.6, 96086.0, 96183.8, 96095.7, 96102.0, 96125.7,
+            96120.5, 96110.5, 96054.1, 96043.7,
+        ];
+        let input_low = vec![
+            96388.0, 96370.4, 96168.8, 96217.2, 96286.2, 96261.5, 96111.0, 96061.2, 96078.4,
+            96070.4, 96079.1, 96138.3, 96008.7, 96052.1, 96169.4, 96236.2, 96200.6, 96137.0,
+            96050.0, 96095.7, 96050.0, 96045.9, 96006.1, 96074.0, 96032.1, 96047.0, 96050.0,
+            96060.3, 96054.0, 95835.8, 95900.0,
+        ];
+        let input_close = vec![
+            96415.2, 96386.1, 96259.3, 96290.2, 96307.3, 96266.8, 96200.0, 96078.3, 96175.3,
+            96123.2, 96242.9, 96149.3, 96104.1, 96192.0, 96236.3, 96278.1, 96200.6, 96165.0,
+            96113.6, 96095.8, 96051.2, 96086.0, 96074.0, 96092.6, 96052.5, 96067.6, 96100.0,
+            96067.0, 96054.0, 95951.4, 95951.5,
+        ];
+        let opt_period = 14;
+        let opt_factor = 2.0;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        let mut output_body_avg = vec![0.0; len];
+        cdl_inverted_hammer(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_factor,
+            &mut output_signals,
+            &mut output_body_avg,
+        )
+        .unwrap();
+
+        let mut indicator = InvertedHammer::new(opt_period, opt_factor).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            let (signal, body_avg) = indicator.update(&bar).unwrap();
+            assert_eq!(signal, output_signals[i]);
+            if i < lookback {
+                assert!(body_avg.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(body_avg, output_body_avg[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_cdl_inverted_hammer_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 9.8, 10.0, 10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.4, 10.5,
+            10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.78, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 9.85, 10.2,
+            10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 11);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_inverted_hammer_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[12] = Signal::Bullish.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_inverted_hammer_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
 }
@@ -1,7 +1,11 @@
 use crate::{
     KandError, TAFloat, TAInt,
-    helper::{lower_shadow_length, period_to_k, real_body_length, upper_shadow_length},
-    types::Signal,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, lower_shadow_length,
+        period_to_k, real_body_length, threshold_window, upper_shadow_length,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
 };
 
 /// Returns the required lookback period for Long Shadow pattern detection.
@@ -38,6 +42,35 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
     Ok(opt_period - 1)
 }
 
+/// Returns the lookback period for [`cdl_long_shadow_ext`].
+///
+/// # Description
+/// The candle is judged against averages of the candles before it, so the first signal needs
+/// the longest average period used by the pattern.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_long_shadow_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_long_shadow};
+/// let lookback = cdl_long_shadow::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 10);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings
+        .body_short
+        .avg_period
+        .max(settings.shadow_very_long.avg_period)
+        .max(settings.shadow_short.avg_period))
+}
+
 /// Detects Long Shadow candlestick patterns in price data.
 ///
 /// # Description
@@ -194,6 +227,142 @@ pub fn cdl_long_shadow(
     Ok(())
 }
 
+/// Detects Long Shadow patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_long_shadow`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, in the style of TA-Lib's candle settings.
+/// TA-Lib has no Long Shadow function; the thresholds reuse its `BodyShort`, `ShadowVeryLong`
+/// and `ShadowShort` settings.
+///
+/// # Mathematical Formula
+/// ```text
+/// BodyShort(k) = settings.body_short average for candle k
+/// ShadowVeryLong(k) = settings.shadow_very_long average for candle k
+/// ShadowShort(k) = settings.shadow_short average for candle k
+///
+/// SmallBody = |Close - Open| < BodyShort(i)
+/// Bullish = SmallBody AND LowerShadow > ShadowVeryLong(i) AND UpperShadow < ShadowShort(i)
+/// Bearish = SmallBody AND UpperShadow > ShadowVeryLong(i) AND LowerShadow < ShadowShort(i)
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_long_shadow`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_long_shadow, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([10.0, 10.1]);
+/// input_high.extend([10.15, 11.1]);
+/// input_low.extend([9.0, 9.95]);
+/// input_close.extend([10.1, 10.0]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_long_shadow::cdl_long_shadow_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[13], Signal::Bearish.into());
+/// ```
+pub fn cdl_long_shadow_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_long_shadow_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
 /// Processes a single candlestick to detect a Long Shadow pattern.
 ///
 /// # Description
@@ -309,6 +478,127 @@ pub fn cdl_long_shadow_inc(
     Ok((signal, body_avg))
 }
 
+/// Evaluates the TA-Lib style Long Shadow pattern on a single candle.
+///
+/// # Description
+/// Incremental form of [`cdl_long_shadow_ext`]. `input_thresholds[k]` holds the candle averages of
+/// `input_bars[k]`, as returned by [`CandleAverages::value`](crate::helper::CandleAverages::value)
+/// before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The current candle
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_long_shadow_ext_inc(
+    input_bars: &[Bar; 1],
+    input_thresholds: &[CandleThresholds; 1],
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [candle] = input_bars;
+    let [candle_avg] = input_thresholds;
+    let body = real_body_length(candle.open, candle.close);
+    let up_shadow = upper_shadow_length(candle.high, candle.open, candle.close);
+    let dn_shadow = lower_shadow_length(candle.low, candle.open, candle.close);
+    Ok(if body >= candle_avg.body_short {
+        Signal::Neutral.into()
+    } else if dn_shadow > candle_avg.shadow_very_long && up_shadow < candle_avg.shadow_short {
+        Signal::Bullish.into()
+    } else if up_shadow > candle_avg.shadow_very_long && dn_shadow < candle_avg.shadow_short {
+        Signal::Bearish.into()
+    } else {
+        Signal::Neutral.into()
+    })
+}
+
+/// Streaming Long Shadow pattern detector.
+///
+/// Seeds the body average with the mean body length of the first `opt_period` bars, then updates
+/// it with [`cdl_long_shadow_inc`]. Each update returns `(signal, body_avg)`.
+/// Bars before the body average is seeded yield [`Signal::Invalid`], matching [`cdl_long_shadow`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LongShadow {
+    opt_period: usize,
+    opt_shadow_factor: TAFloat,
+    count: usize,
+    body_sum: TAFloat,
+    body_avg: TAFloat,
+}
+
+impl LongShadow {
+    /// Creates a streaming Long Shadow pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_period` - Period for the body average EMA
+    /// * `opt_shadow_factor` - Minimum shadow length as a percentage of the candle range
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` < 2
+    pub fn new(opt_period: usize, opt_shadow_factor: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            opt_shadow_factor,
+            count: 0,
+            body_sum: 0.0,
+            body_avg: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for LongShadow {
+    type Output = (TAInt, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<(TAInt, TAFloat), KandError> {
+        if self.count < self.opt_period {
+            self.count += 1;
+            self.body_sum += real_body_length(input.open, input.close);
+            if self.count < self.opt_period {
+                return Ok((Signal::Invalid.into(), TAFloat::NAN));
+            }
+            self.body_avg = self.body_sum / self.opt_period as TAFloat;
+        }
+        let (signal, body_avg) = cdl_long_shadow_inc(
+            input.open,
+            input.high,
+            input.low,
+            input.close,
+            self.body_avg,
+            self.opt_period,
+            self.opt_shadow_factor,
+        )?;
+        self.body_avg = body_avg;
+        Ok((signal, body_avg))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.body_sum = 0.0;
+        self.body_avg = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -357,17 +647,17 @@ fn test_cdl_long_shadow() {
 
         // First 13 values should be i64::MIN
         for i in 0..13 {
-            assert_eq!(output_signals[i], Signal::Invalid.into());
+            assert_eq!(output_signals[i], TAInt::from(Signal::Invalid));
             assert!(output_body_avg[i].is_nan());
         }
 
         println!("output_signals: {output_signals:?}");
 
         // Test specific signals
-        assert_eq!(output_signals[15], Signal::Bullish.into()); // Example bullish signal
-        assert_eq!(output_signals[16], Signal::Bearish.into()); // Example bearish signal
-        assert_eq!(output_signals[17], Signal::Bullish.into()); // Example bullish signal
-        assert_eq!(output_signals[22], Signal::Bullish.into()); // Example bullish signal
+        assert_eq!(output_signals[15], TAInt::from(Signal::Bullish)); // Example bullish signal
+        assert_eq!(output_signals[16], TAInt::from(Signal::Bearish)); // Example bearish signal
+        assert_eq!(output_signals[17], TAInt::from(Signal::Bullish)); // Example bullish signal
+        assert_eq!(output_signals[22], TAInt::from(Signal::Bullish)); // Example bullish signal
 
         // Test incremental calculation matches regular calculation
         let mut prev_body_avg = output_body_avg[13]; // First valid body average
@@ -389,4 +679,121 @@ fn test_cdl_long_shadow() {
             prev_body_avg = new_body_avg;
         }
     }
+
+    #[test]
+    fn test_cdl_long_shadow_indicator() {
+        let input_open = vec![
+            96674.3, 96814.9, 96667.3, 96747.9, 96743.4, 96712.4, 96677.7, 96556.3, 96500.0,
+            96442.8, 96229.7, 96152.2, 96145.7, 96233.1, 96140.1, 95505.0, 95575.1, 95585.2,
+            95544.0, 95450.0, 95592.4, 95456.0, 95664.2, 95674.1, 95546.9,
+        ];
+        let input_high = vec![
+            96911.0, 96831.2, 96754.9, 96875.7, 96793.9, 96725.7, 96802.4, 96646.6, 96526.0,
+            96470.9, 96229.8, 96245.3, 96341.5, 96233.1, 96140.1, 95582.6, 95856.1, 95585.2,
+            95702.4, 95729.2, 95633.3, 95720.6, 95698.2, 95682.9, 95794.6,
+        ];
+        let input_low = vec![
+            96567.2, 96646.7, 96648.0, 96730.0, 96694.7, 96660.4, 96556.2, 96500.1, 96400.0,
+            96200.0, 96100.0, 96073.6, 96130.4, 96045.6, 95400.0, 95200.1, 95544.8, 95400.7,
+            95427.6, 95359.5, 95442.3, 95427.0, 95545.4, 95473.1, 95475.8,
+        ];
+        let input_close = vec![
+            96814.9, 96667.4, 96747.9, 96743.4, 96712.5, 96677.7, 96556.2, 96500.1, 96442.7,
+            96229.7, 96152.2, 96145.7, 96233.1, 96140.0, 95505.2, 95575.1, 95585.3, 95544.0,
+            95450.1, 95592.5, 95456.0, 95664.2, 95674.1, 95547.0, 95679.4,
+        ];
+        let opt_period = 14;
+        let opt_shadow_factor = 75.0;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        let mut output_body_avg = vec![0.0; len];
+        cdl_long_shadow(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_shadow_factor,
+            &mut output_signals,
+            &mut output_body_avg,
+        )
+        .unwrap();
+
+        let mut indicator = LongShadow::new(opt_period, opt_shadow_factor).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            let (signal, body_avg) = indicator.update(&bar).unwrap();
+            assert_eq!(signal, output_signals[i]);
+            if i < lookback {
+                assert!(body_avg.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(body_avg, output_body_avg[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_cdl_long_shadow_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.1,
+            10.0, 10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.15, 11.1,
+            10.5, 10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.0, 9.95, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.1, 10.0,
+            10.2, 10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 10);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_long_shadow_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[12] = Signal::Bullish.into();
+        expected[13] = Signal::Bearish.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_long_shadow_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
 }
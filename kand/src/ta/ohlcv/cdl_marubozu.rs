@@ -1,7 +1,11 @@
 use crate::{
     KandError, TAFloat, TAInt,
-    helper::{lower_shadow_length, period_to_k, real_body_length, upper_shadow_length},
-    types::Signal,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, lower_shadow_length,
+        period_to_k, real_body_length, threshold_window, upper_shadow_length,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
 };
 
 /// Calculates the lookback period for Marubozu pattern detection.
@@ -40,6 +44,34 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
     Ok(opt_period - 1)
 }
 
+/// Returns the lookback period for [`cdl_marubozu_ext`].
+///
+/// # Description
+/// The candle is judged against averages of the candles before it, so the first signal needs
+/// the longest average period used by the pattern.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_marubozu_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_marubozu};
+/// let lookback = cdl_marubozu::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 10);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings
+        .body_long
+        .avg_period
+        .max(settings.shadow_very_short.avg_period))
+}
+
 /// Identifies Marubozu candlestick patterns in price data.
 ///
 /// # Description
@@ -194,6 +226,141 @@ pub fn cdl_marubozu(
     Ok(())
 }
 
+/// Detects Marubozu patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_marubozu`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, following TA-Lib's `CDLMARUBOZU`. With
+/// [`CandleSettings::default`] the pattern fires on the same candles as TA-Lib.
+///
+/// # Mathematical Formula
+/// ```text
+/// BodyLong(k) = settings.body_long average for candle k
+/// ShadowVeryShort(k) = settings.shadow_very_short average for candle k
+///
+/// Marubozu = |Close - Open| > BodyLong(i)
+///            AND UpperShadow < ShadowVeryShort(i)
+///            AND LowerShadow < ShadowVeryShort(i)
+/// Signal = Marubozu ? (Close >= Open ? Bullish : Bearish) : Neutral
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_marubozu`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_marubozu, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([10.0]);
+/// input_high.extend([10.82]);
+/// input_low.extend([9.98]);
+/// input_close.extend([10.8]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_marubozu::cdl_marubozu_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[12], Signal::Bullish.into());
+/// ```
+pub fn cdl_marubozu_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_marubozu_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
 /// Incrementally processes a single candlestick for Marubozu pattern detection.
 ///
 /// # Description
@@ -305,6 +472,134 @@ pub fn cdl_marubozu_inc(
     Ok((signal, new_body_avg))
 }
 
+/// Evaluates the TA-Lib style Marubozu pattern on a single candle.
+///
+/// # Description
+/// Incremental form of [`cdl_marubozu_ext`]. `input_thresholds[k]` holds the candle averages of
+/// `input_bars[k]`, as returned by [`CandleAverages::value`](crate::helper::CandleAverages::value)
+/// before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The current candle
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_marubozu_ext_inc(
+    input_bars: &[Bar; 1],
+    input_thresholds: &[CandleThresholds; 1],
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [candle] = input_bars;
+    let [candle_avg] = input_thresholds;
+    let body = real_body_length(candle.open, candle.close);
+    let up_shadow = upper_shadow_length(candle.high, candle.open, candle.close);
+    let dn_shadow = lower_shadow_length(candle.low, candle.open, candle.close);
+    Ok(
+        if body > candle_avg.body_long
+            && up_shadow < candle_avg.shadow_very_short
+            && dn_shadow < candle_avg.shadow_very_short
+        {
+            if candle.close >= candle.open {
+                Signal::Bullish.into()
+            } else {
+                Signal::Bearish.into()
+            }
+        } else {
+            Signal::Neutral.into()
+        },
+    )
+}
+
+/// Streaming Marubozu pattern detector.
+///
+/// Seeds the body average with the mean body length of the first `opt_period` bars, then updates
+/// it with [`cdl_marubozu_inc`]. Each update returns `(signal, body_avg)`.
+/// Bars before the body average is seeded yield [`Signal::Neutral`], and the seeding bar itself is
+/// not evaluated, matching [`cdl_marubozu`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Marubozu {
+    opt_period: usize,
+    opt_shadow_percent: TAFloat,
+    count: usize,
+    body_sum: TAFloat,
+    body_avg: TAFloat,
+}
+
+impl Marubozu {
+    /// Creates a streaming Marubozu pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_period` - Period for the body average EMA
+    /// * `opt_shadow_percent` - Maximum shadow length as a percentage of the body
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` < 2
+    pub fn new(opt_period: usize, opt_shadow_percent: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            opt_shadow_percent,
+            count: 0,
+            body_sum: 0.0,
+            body_avg: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Marubozu {
+    type Output = (TAInt, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<(TAInt, TAFloat), KandError> {
+        if self.count < self.opt_period {
+            self.count += 1;
+            self.body_sum += real_body_length(input.open, input.close);
+            if self.count < self.opt_period {
+                return Ok((Signal::Neutral.into(), TAFloat::NAN));
+            }
+            self.body_avg = self.body_sum / self.opt_period as TAFloat;
+            return Ok((Signal::Neutral.into(), self.body_avg));
+        }
+        let (signal, body_avg) = cdl_marubozu_inc(
+            input.open,
+            input.high,
+            input.low,
+            input.close,
+            self.body_avg,
+            self.opt_period,
+            self.opt_shadow_percent,
+        )?;
+        self.body_avg = body_avg;
+        Ok((signal, body_avg))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.body_sum = 0.0;
+        self.body_avg = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -374,10 +669,10 @@ fn test_cdl_marubozu() {
         }
 
         // Test specific signals
-        assert_eq!(output_signals[14], Signal::Bullish.into()); // TV BTCUSDT.P 5m 2025-02-10 14:05
-        assert_eq!(output_signals[27], Signal::Bearish.into()); // TV BTCUSDT.P 5m 2025-02-10 05:10
-        assert_eq!(output_signals[46], Signal::Bearish.into()); // TV BTCUSDT.P 5m 2025-02-10 06:45
-        assert_eq!(output_signals[61], Signal::Bullish.into()); // TV BTCUSDT.P 5m 2025-02-10 08:00
+        assert_eq!(output_signals[14], TAInt::from(Signal::Bullish)); // TV BTCUSDT.P 5m 2025-02-10 14:05
+        assert_eq!(output_signals[27], TAInt::from(Signal::Bearish)); // TV BTCUSDT.P 5m 2025-02-10 05:10
+        assert_eq!(output_signals[46], TAInt::from(Signal::Bearish)); // TV BTCUSDT.P 5m 2025-02-10 06:45
+        assert_eq!(output_signals[61], TAInt::from(Signal::Bullish)); // TV BTCUSDT.P 5m 2025-02-10 08:00
 
         // Now test incremental calculation matches regular calculation
         let mut prev_body_avg = output_body_avg[13]; // First valid body average
@@ -399,4 +694,136 @@ fn test_cdl_marubozu() {
             prev_body_avg = new_body_avg;
         }
     }
+
+    #[test]
+    fn test_cdl_marubozu_indicator() {
+        let input_open = vec![
+            96105.4, 96156.3, 96166.5, 96171.2, 96225.4, 96183.7, 96069.0, 95991.2, 96005.3,
+            95930.5, 95902.0, 95931.7, 95979.0, 95950.1, 96045.6, 96139.0, 96146.5, 96139.2,
+            96216.2, 96460.1, 96519.4, 96511.7, 96405.0, 96316.9, 96232.7, 96277.0, 96196.6,
+            96300.0, 96200.0, 96007.3, 95958.1, 95909.6, 95800.1, 95437.2, 94963.6, 94820.8,
+            94789.1, 95121.0, 94925.0, 95136.7, 95376.3, 95399.9, 95575.1, 95679.0, 95723.1,
+            95779.9, 96350.1, 95906.8, 95747.8, 95779.8, 95869.8, 95984.8, 96040.7, 96040.1,
+            96062.5, 96009.7, 96087.9, 96230.0, 96275.6, 96350.2, 96477.6, 96423.6,
+        ];
+        let input_high = vec![
+            96205.1, 96180.8, 96182.7, 96240.2, 96230.6, 96183.8, 96069.0, 96020.0, 96084.6,
+            95975.0, 95945.7, 95991.2, 95993.3, 96016.3, 96142.3, 96177.5, 96210.0, 96247.7,
+            96640.7, 96591.7, 96750.0, 96530.7, 96413.9, 96319.9, 96305.2, 96291.8, 96300.0,
+            96300.0, 96225.5, 96043.9, 95973.0, 95952.3, 95822.4, 95484.7, 95155.8, 94930.3,
+            95237.6, 95121.0, 95136.7, 95463.1, 95471.3, 95671.7, 95679.0, 95742.6, 95788.0,
+            96417.5, 96366.4, 96000.0, 95940.7, 95940.6, 96034.7, 96100.1, 96120.0, 96062.5,
+            96062.5, 96115.1, 96236.6, 96333.2, 96481.8, 96547.0, 96522.1, 96763.3,
+        ];
+        let input_low = vec![
+            96105.4, 96123.9, 96081.6, 96157.3, 96162.4, 96050.2, 95980.1, 95974.0, 95893.0,
+            95828.0, 95829.0, 95849.9, 95932.8, 95950.0, 96041.0, 96097.7, 96082.0, 96123.0,
+            96204.1, 96346.9, 96442.5, 96405.2, 96302.2, 96182.6, 96206.5, 96145.0, 96190.3,
+            96200.0, 95972.1, 95921.7, 95888.2, 95777.3, 95180.0, 94848.0, 94727.4, 94650.1,
+            94744.4, 94718.4, 94828.2, 95095.9, 95217.6, 95399.9, 95428.4, 95420.0, 95654.7,
+            95747.1, 95902.0, 95462.4, 95615.5, 95674.7, 95780.0, 95984.8, 96000.0, 95948.2,
+            95935.0, 96008.2, 96062.5, 96229.9, 96225.5, 96350.2, 96407.5, 96414.0,
+        ];
+        let input_close = vec![
+            96156.3, 96166.4, 96171.1, 96225.4, 96183.7, 96069.0, 95991.3, 96005.3, 95930.5,
+            95902.0, 95931.7, 95979.1, 95950.1, 96011.4, 96139.0, 96146.5, 96139.1, 96216.3,
+            96460.2, 96519.3, 96511.8, 96405.2, 96316.9, 96232.7, 96277.0, 96196.6, 96300.0,
+            96200.0, 96007.4, 95958.1, 95909.6, 95800.0, 95437.3, 94963.6, 94820.3, 94789.2,
+            95120.9, 94925.0, 95136.7, 95376.3, 95399.9, 95575.0, 95678.9, 95723.1, 95779.9,
+            96350.0, 95906.8, 95747.8, 95779.9, 95869.9, 95984.8, 96040.7, 96040.1, 96062.4,
+            96009.7, 96087.9, 96230.0, 96275.6, 96350.2, 96477.6, 96423.6, 96749.1,
+        ];
+        let opt_period = 14;
+        let opt_shadow_percent = 5.0;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        let mut output_body_avg = vec![0.0; len];
+        cdl_marubozu(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_shadow_percent,
+            &mut output_signals,
+            &mut output_body_avg,
+        )
+        .unwrap();
+
+        let mut indicator = Marubozu::new(opt_period, opt_shadow_percent).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            let (signal, body_avg) = indicator.update(&bar).unwrap();
+            assert_eq!(signal, output_signals[i]);
+            if i < lookback {
+                assert!(body_avg.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(body_avg, output_body_avg[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_cdl_marubozu_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0,
+            10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.82, 10.5,
+            10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.98, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.8, 10.2,
+            10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 10);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_marubozu_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[12] = Signal::Bullish.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_marubozu_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
 }
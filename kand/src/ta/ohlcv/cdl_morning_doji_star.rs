@@ -0,0 +1,734 @@
+use crate::{
+    KandError, TAFloat, TAInt,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, has_real_body_gap_down,
+        real_body_length, real_body_percent, threshold_window,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
+};
+
+/// Returns the lookback period for Morning Doji Star pattern detection.
+///
+/// # Description
+/// The Morning Doji Star pattern spans three candles, so the first signal is available once
+/// three bars have been seen.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 2
+///
+/// # Errors
+/// This function does not return any errors. It always returns `Ok(2)`.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cdl_morning_doji_star;
+/// let lookback = cdl_morning_doji_star::lookback().unwrap();
+/// assert_eq!(lookback, 2);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(2)
+}
+
+/// Returns the lookback period for [`cdl_morning_doji_star_ext`].
+///
+/// # Description
+/// Every candle of the pattern is judged against averages of the candles before it, so the first
+/// signal needs the longest average period used by the pattern plus the candles it spans.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_morning_doji_star_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_morning_doji_star};
+/// let lookback = cdl_morning_doji_star::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 12);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings
+        .body_long
+        .avg_period
+        .max(settings.body_doji.avg_period)
+        .max(settings.body_short.avg_period)
+        + 2)
+}
+
+/// Detects Morning Doji Star candlestick patterns in price data.
+///
+/// # Description
+/// A Morning Doji Star is a Morning Star whose middle candle is a doji: a long black candle, a
+/// doji whose real body gaps below it, and a white candle that closes well into the first real
+/// body.
+///
+/// # Mathematical Formula
+/// ```text
+/// Long1 = Close1 < Open1 AND |Close1 - Open1| / (High1 - Low1) * 100 >= BodyLongPercent
+/// Doji2 = |Close2 - Open2| / (High2 - Low2) * 100 <= BodyDojiPercent
+///         AND max(Open2, Close2) < min(Open1, Close1)
+/// White3 = Close3 > Open3 AND |Close3 - Open3| / (High3 - Low3) * 100 > BodyShortPercent
+///          AND Close3 > Close1 + |Close1 - Open1| * Penetration
+/// Signal = Long1 AND Doji2 AND White3 ? Bullish : Neutral
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_body_long_percent` - Minimum body size of a long candle as a percentage of its range (e.g. 60.0)
+/// * `opt_body_doji_percent` - Maximum body size of a doji as a percentage of its range (e.g. 5.0)
+/// * `opt_body_short_percent` - Maximum body size of a short candle as a percentage of its range (e.g. 30.0)
+/// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate (e.g. 0.3)
+/// * `output_signals` - Output array for pattern signals:
+///   - Bullish (100): Morning Doji Star
+///   - Neutral (0): No pattern detected
+///   - Invalid (-1): Fewer than three bars available
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than 3
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If a body percentage is <= 0 or the penetration is negative
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::cdl_morning_doji_star, types::Signal};
+///
+/// let input_open = vec![12.0, 9.6, 9.8, 11.4, 10.2];
+/// let input_high = vec![12.1, 9.9, 11.6, 11.5, 11.0];
+/// let input_low = vec![9.9, 9.3, 9.7, 10.0, 10.1];
+/// let input_close = vec![10.0, 9.61, 11.5, 10.1, 10.9];
+/// let mut output_signals = vec![0i64; 5];
+///
+/// cdl_morning_doji_star::cdl_morning_doji_star(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     60.0, // opt_body_long_percent
+///     5.0, // opt_body_doji_percent
+///     30.0, // opt_body_short_percent
+///     0.3, // opt_penetration
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// let expected: Vec<i64> = vec![Signal::Invalid.into(), Signal::Invalid.into(), Signal::Bullish.into(), Signal::Neutral.into(), Signal::Neutral.into()];
+/// assert_eq!(output_signals, expected);
+/// ```
+pub fn cdl_morning_doji_star(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_body_long_percent: TAFloat,
+    opt_body_doji_percent: TAFloat,
+    opt_body_short_percent: TAFloat,
+    opt_penetration: TAFloat,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_body_long_percent <= 0.0
+            || opt_body_doji_percent <= 0.0
+            || opt_body_short_percent <= 0.0
+            || opt_penetration < 0.0
+        {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_morning_doji_star_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            opt_body_long_percent,
+            opt_body_doji_percent,
+            opt_body_short_percent,
+            opt_penetration,
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Detects Morning Doji Star patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_morning_doji_star`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, following TA-Lib's `CDLMORNINGDOJISTAR`. With
+/// [`CandleSettings::default`] the pattern fires on the same candles as TA-Lib.
+///
+/// # Mathematical Formula
+/// ```text
+/// BodyLong(k) = settings.body_long average for candle k
+/// BodyDoji(k) = settings.body_doji average for candle k
+/// BodyShort(k) = settings.body_short average for candle k
+///
+/// Star = Close(i-2) < Open(i-2) AND |Close(i-2) - Open(i-2)| > BodyLong(i-2)
+///        AND |Close(i-1) - Open(i-1)| <= BodyDoji(i-1)
+///        AND max(Open(i-1), Close(i-1)) < min(Open(i-2), Close(i-2))
+///        AND Close >= Open AND |Close - Open| > BodyShort(i)
+///        AND Close > Close(i-2) + |Close(i-2) - Open(i-2)| * Penetration
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate (e.g. 0.3)
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_morning_doji_star`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If the penetration is negative
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_morning_doji_star, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([10.8, 9.8, 9.9]);
+/// input_high.extend([10.85, 9.9, 10.55]);
+/// input_low.extend([9.95, 9.7, 9.85]);
+/// input_close.extend([10.0, 9.81, 10.5]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_morning_doji_star::cdl_morning_doji_star_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     0.3, // opt_penetration
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[14], Signal::Bullish.into());
+/// ```
+pub fn cdl_morning_doji_star_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_penetration: TAFloat,
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_penetration < 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_morning_doji_star_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+            opt_penetration,
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Evaluates the Morning Doji Star pattern on a window of three consecutive candles.
+///
+/// # Description
+/// Checks whether the three most recent candles, ordered from oldest to newest, form the
+/// pattern. Because the pattern only looks at these bars, no state has to be carried between
+/// calls; slide the window forward by one bar to process the next candle.
+///
+/// # Arguments
+/// * `input_bars` - The last three candles, oldest first
+/// * `opt_body_long_percent` - Minimum body size of a long candle as a percentage of its range
+/// * `opt_body_doji_percent` - Maximum body size of a doji as a percentage of its range
+/// * `opt_body_short_percent` - Maximum body size of a short candle as a percentage of its range
+/// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If a body percentage is <= 0 or the penetration is negative
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_morning_doji_star_inc(
+    input_bars: &[Bar; 3],
+    opt_body_long_percent: TAFloat,
+    opt_body_doji_percent: TAFloat,
+    opt_body_short_percent: TAFloat,
+    opt_penetration: TAFloat,
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_body_long_percent <= 0.0
+            || opt_body_doji_percent <= 0.0
+            || opt_body_short_percent <= 0.0
+            || opt_penetration < 0.0
+        {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [first, second, third] = input_bars;
+
+    let long_black = first.close < first.open
+        && real_body_percent(first.open, first.high, first.low, first.close)
+            >= opt_body_long_percent;
+    let doji = real_body_percent(second.open, second.high, second.low, second.close)
+        <= opt_body_doji_percent
+        && has_real_body_gap_down(second.open, second.close, first.open, first.close);
+    let white = third.close > third.open
+        && real_body_percent(third.open, third.high, third.low, third.close)
+            > opt_body_short_percent
+        && third.close
+            > real_body_length(first.open, first.close).mul_add(opt_penetration, first.close);
+
+    Ok(if long_black && doji && white {
+        Signal::Bullish.into()
+    } else {
+        Signal::Neutral.into()
+    })
+}
+
+/// Evaluates the TA-Lib style Morning Doji Star pattern on a window of three consecutive candles.
+///
+/// # Description
+/// Incremental form of [`cdl_morning_doji_star_ext`]. `input_thresholds[k]` holds the candle
+/// averages of `input_bars[k]`, as returned by
+/// [`CandleAverages::value`](crate::helper::CandleAverages::value) before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The last three candles, oldest first
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+/// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate (e.g. 0.3)
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If the penetration is negative
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_morning_doji_star_ext_inc(
+    input_bars: &[Bar; 3],
+    input_thresholds: &[CandleThresholds; 3],
+    opt_penetration: TAFloat,
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_penetration < 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [first_avg, second_avg, third_avg] = input_thresholds;
+    let [first, second, third] = input_bars;
+    let first_body = real_body_length(first.open, first.close);
+    Ok(
+        if first.close < first.open
+            && first_body > first_avg.body_long
+            && real_body_length(second.open, second.close) <= second_avg.body_doji
+            && has_real_body_gap_down(second.open, second.close, first.open, first.close)
+            && third.close >= third.open
+            && real_body_length(third.open, third.close) > third_avg.body_short
+            && third.close > first_body.mul_add(opt_penetration, first.close)
+        {
+            Signal::Bullish.into()
+        } else {
+            Signal::Neutral.into()
+        },
+    )
+}
+
+/// Streaming Morning Doji Star pattern detector.
+///
+/// Keeps the last three bars and emits [`Signal::Invalid`] until the window is full.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MorningDojiStar {
+    opt_body_long_percent: TAFloat,
+    opt_body_doji_percent: TAFloat,
+    opt_body_short_percent: TAFloat,
+    opt_penetration: TAFloat,
+    window: [Bar; 3],
+    count: usize,
+}
+
+impl MorningDojiStar {
+    /// Creates a streaming Morning Doji Star pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_body_long_percent` - Minimum body size of a long candle as a percentage of its range
+    /// * `opt_body_doji_percent` - Maximum body size of a doji as a percentage of its range
+    /// * `opt_body_short_percent` - Maximum body size of a short candle as a percentage of its range
+    /// * `opt_penetration` - Fraction of the first candle's body the last close must penetrate
+    #[must_use]
+    pub const fn new(
+        opt_body_long_percent: TAFloat,
+        opt_body_doji_percent: TAFloat,
+        opt_body_short_percent: TAFloat,
+        opt_penetration: TAFloat,
+    ) -> Self {
+        Self {
+            opt_body_long_percent,
+            opt_body_doji_percent,
+            opt_body_short_percent,
+            opt_penetration,
+            window: [Bar::new(0.0, 0.0, 0.0, 0.0, 0.0); 3],
+            count: 0,
+        }
+    }
+}
+
+impl Indicator for MorningDojiStar {
+    type Output = TAInt;
+
+    fn update(&mut self, input: &Bar) -> Result<TAInt, KandError> {
+        self.window.rotate_left(1);
+        self.window[2] = *input;
+        if self.count < 3 {
+            self.count += 1;
+            if self.count < 3 {
+                return Ok(Signal::Invalid.into());
+            }
+        }
+        cdl_morning_doji_star_inc(
+            &self.window,
+            self.opt_body_long_percent,
+            self.opt_body_doji_percent,
+            self.opt_body_short_percent,
+            self.opt_penetration,
+        )
+    }
+
+    fn lookback(&self) -> usize {
+        2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= 3
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_morning_doji_star() {
+        let input_open = vec![12.0, 9.6, 9.8, 11.4, 10.2];
+        let input_high = vec![12.1, 9.9, 11.6, 11.5, 11.0];
+        let input_low = vec![9.9, 9.3, 9.7, 10.0, 10.1];
+        let input_close = vec![10.0, 9.61, 11.5, 10.1, 10.9];
+        let opt_body_long_percent = 60.0;
+        let opt_body_doji_percent = 5.0;
+        let opt_body_short_percent = 30.0;
+        let opt_penetration = 0.3;
+        let mut output_signals = vec![0i64; input_open.len()];
+
+        cdl_morning_doji_star(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_body_long_percent,
+            opt_body_doji_percent,
+            opt_body_short_percent,
+            opt_penetration,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let expected: Vec<TAInt> = vec![
+            Signal::Invalid.into(),
+            Signal::Invalid.into(),
+            Signal::Bullish.into(),
+            Signal::Neutral.into(),
+            Signal::Neutral.into(),
+        ];
+        assert_eq!(output_signals, expected);
+
+        // Test incremental calculation matches regular calculation
+        for (i, &expected) in output_signals.iter().enumerate().skip(lookback().unwrap()) {
+            let signal = cdl_morning_doji_star_inc(
+                &bar_window(&input_open, &input_high, &input_low, &input_close, i),
+                opt_body_long_percent,
+                opt_body_doji_percent,
+                opt_body_short_percent,
+                opt_penetration,
+            )
+            .unwrap();
+            assert_eq!(signal, expected, "Mismatch at index {i}");
+        }
+
+        // Fewer bars than the pattern spans
+        assert!(
+            cdl_morning_doji_star(
+                &input_open[..2],
+                &input_high[..2],
+                &input_low[..2],
+                &input_close[..2],
+                opt_body_long_percent,
+                opt_body_doji_percent,
+                opt_body_short_percent,
+                opt_penetration,
+                &mut output_signals[..2],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cdl_morning_doji_star_indicator() {
+        let input_open = vec![12.0, 9.6, 9.8, 11.4, 10.2];
+        let input_high = vec![12.1, 9.9, 11.6, 11.5, 11.0];
+        let input_low = vec![9.9, 9.3, 9.7, 10.0, 10.1];
+        let input_close = vec![10.0, 9.61, 11.5, 10.1, 10.9];
+        let opt_body_long_percent = 60.0;
+        let opt_body_doji_percent = 5.0;
+        let opt_body_short_percent = 30.0;
+        let opt_penetration = 0.3;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        cdl_morning_doji_star(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_body_long_percent,
+            opt_body_doji_percent,
+            opt_body_short_percent,
+            opt_penetration,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut indicator = MorningDojiStar::new(
+            opt_body_long_percent,
+            opt_body_doji_percent,
+            opt_body_short_percent,
+            opt_penetration,
+        );
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            assert_eq!(indicator.update(&bar).unwrap(), output_signals[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        let bar = Bar::new(
+            input_open[0],
+            input_high[0],
+            input_low[0],
+            input_close[0],
+            0.0,
+        );
+        assert_eq!(
+            indicator.update(&bar).unwrap(),
+            TAInt::from(Signal::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_cdl_morning_doji_star_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.8, 9.8, 9.9,
+            10.0, 10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.85, 9.9,
+            10.55, 10.5, 10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.95, 9.7, 9.85, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.0, 9.81,
+            10.5, 10.2, 10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 12);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_morning_doji_star_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            0.3,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[14] = Signal::Bullish.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_morning_doji_star_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                0.3,
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
+}
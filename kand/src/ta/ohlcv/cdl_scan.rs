@@ -0,0 +1,657 @@
+use super::{
+    cdl_dark_cloud_cover, cdl_doji, cdl_dragonfly_doji, cdl_engulfing, cdl_evening_doji_star,
+    cdl_evening_star, cdl_gravestone_doji, cdl_hammer, cdl_harami, cdl_harami_cross,
+    cdl_inverted_hammer, cdl_long_shadow, cdl_marubozu, cdl_morning_doji_star, cdl_morning_star,
+    cdl_piercing, cdl_three_black_crows, cdl_three_inside, cdl_three_outside,
+    cdl_three_white_soldiers,
+};
+use crate::{
+    KandError, TAFloat, TAInt,
+    helper::{CandleAverages, CandleSettings, CandleThresholds},
+    indicator::Indicator,
+    types::{Bar, Signal},
+};
+
+/// Penetration used for Dark Cloud Cover, TA-Lib's default.
+const DARK_CLOUD_PENETRATION: TAFloat = 0.5;
+
+/// Penetration used for the morning and evening star patterns, TA-Lib's default.
+const STAR_PENETRATION: TAFloat = 0.3;
+
+/// Number of bars kept by the scanner: the widest pattern spans four candles.
+const WINDOW: usize = 4;
+
+/// Candlestick patterns recognised by [`cdl_scan`].
+///
+/// The discriminant is the pattern's bit position in the [`CdlMatches`] masks, so
+/// `1 << pattern as u32` (or [`CdlPattern::mask`]) selects it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum CdlPattern {
+    DarkCloudCover = 0,
+    Doji = 1,
+    DragonflyDoji = 2,
+    Engulfing = 3,
+    EveningDojiStar = 4,
+    EveningStar = 5,
+    GravestoneDoji = 6,
+    Hammer = 7,
+    Harami = 8,
+    HaramiCross = 9,
+    InvertedHammer = 10,
+    LongShadow = 11,
+    Marubozu = 12,
+    MorningDojiStar = 13,
+    MorningStar = 14,
+    Piercing = 15,
+    ThreeBlackCrows = 16,
+    ThreeInside = 17,
+    ThreeOutside = 18,
+    ThreeWhiteSoldiers = 19,
+}
+
+impl CdlPattern {
+    /// Every pattern, ordered by id.
+    pub const ALL: [Self; 20] = [
+        Self::DarkCloudCover,
+        Self::Doji,
+        Self::DragonflyDoji,
+        Self::Engulfing,
+        Self::EveningDojiStar,
+        Self::EveningStar,
+        Self::GravestoneDoji,
+        Self::Hammer,
+        Self::Harami,
+        Self::HaramiCross,
+        Self::InvertedHammer,
+        Self::LongShadow,
+        Self::Marubozu,
+        Self::MorningDojiStar,
+        Self::MorningStar,
+        Self::Piercing,
+        Self::ThreeBlackCrows,
+        Self::ThreeInside,
+        Self::ThreeOutside,
+        Self::ThreeWhiteSoldiers,
+    ];
+
+    /// Bit of this pattern in the [`CdlMatches`] masks.
+    #[must_use]
+    pub const fn mask(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Patterns matched on a single bar, as bitmasks over [`CdlPattern`] ids.
+///
+/// A pattern sets its bit in exactly one mask, chosen by the signal its detector returned:
+/// `bullish` for [`Signal::Bullish`], `bearish` for [`Signal::Bearish`] and `pattern` for
+/// non-directional matches ([`Signal::Pattern`], e.g. Doji). Patterns that did not fire, or whose
+/// lookback has not yet elapsed, are absent from all three.
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::cdl_scan::{CdlMatches, CdlPattern},
+///     types::Signal,
+/// };
+///
+/// let matches = CdlMatches {
+///     bullish: CdlPattern::Hammer.mask(),
+///     bearish: 0,
+///     pattern: CdlPattern::Doji.mask(),
+/// };
+/// assert!(matches.contains(CdlPattern::Doji));
+/// assert_eq!(matches.signal(CdlPattern::Hammer), Signal::Bullish);
+/// assert_eq!(matches.signal(CdlPattern::Engulfing), Signal::Neutral);
+/// assert_eq!(matches.iter().count(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CdlMatches {
+    /// Patterns that signalled [`Signal::Bullish`]
+    pub bullish: u32,
+    /// Patterns that signalled [`Signal::Bearish`]
+    pub bearish: u32,
+    /// Patterns that signalled [`Signal::Pattern`]
+    pub pattern: u32,
+}
+
+impl CdlMatches {
+    /// Whether no pattern matched.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bullish | self.bearish | self.pattern == 0
+    }
+
+    /// Whether `pattern` matched in any direction.
+    #[must_use]
+    pub const fn contains(&self, pattern: CdlPattern) -> bool {
+        (self.bullish | self.bearish | self.pattern) & pattern.mask() != 0
+    }
+
+    /// Signal of `pattern`, or [`Signal::Neutral`] if it did not match.
+    #[must_use]
+    pub const fn signal(&self, pattern: CdlPattern) -> Signal {
+        let mask = pattern.mask();
+        if self.bullish & mask != 0 {
+            Signal::Bullish
+        } else if self.bearish & mask != 0 {
+            Signal::Bearish
+        } else if self.pattern & mask != 0 {
+            Signal::Pattern
+        } else {
+            Signal::Neutral
+        }
+    }
+
+    /// Matched patterns with their signals, ordered by id.
+    pub fn iter(&self) -> impl Iterator<Item = (CdlPattern, Signal)> + '_ {
+        CdlPattern::ALL
+            .into_iter()
+            .filter(|pattern| self.contains(*pattern))
+            .map(|pattern| (pattern, self.signal(pattern)))
+    }
+
+    /// Records the detector output `signal` for `pattern`.
+    fn insert(&mut self, pattern: CdlPattern, signal: TAInt) {
+        let mask = pattern.mask();
+        match Signal::try_from(signal) {
+            Ok(Signal::Bullish) => self.bullish |= mask,
+            Ok(Signal::Bearish) => self.bearish |= mask,
+            Ok(Signal::Pattern) => self.pattern |= mask,
+            _ => {}
+        }
+    }
+}
+
+/// Returns the lookback of every pattern in [`CdlPattern::ALL`] order.
+fn pattern_lookbacks(settings: &CandleSettings) -> Result<[usize; 20], KandError> {
+    Ok([
+        cdl_dark_cloud_cover::lookback_ext(settings)?,
+        cdl_doji::lookback_ext(settings)?,
+        cdl_dragonfly_doji::lookback_ext(settings)?,
+        cdl_engulfing::lookback()?,
+        cdl_evening_doji_star::lookback_ext(settings)?,
+        cdl_evening_star::lookback_ext(settings)?,
+        cdl_gravestone_doji::lookback_ext(settings)?,
+        cdl_hammer::lookback_ext(settings)?,
+        cdl_harami::lookback_ext(settings)?,
+        cdl_harami_cross::lookback_ext(settings)?,
+        cdl_inverted_hammer::lookback_ext(settings)?,
+        cdl_long_shadow::lookback_ext(settings)?,
+        cdl_marubozu::lookback_ext(settings)?,
+        cdl_morning_doji_star::lookback_ext(settings)?,
+        cdl_morning_star::lookback_ext(settings)?,
+        cdl_piercing::lookback_ext(settings)?,
+        cdl_three_black_crows::lookback_ext(settings)?,
+        cdl_three_inside::lookback_ext(settings)?,
+        cdl_three_outside::lookback()?,
+        cdl_three_white_soldiers::lookback_ext(settings)?,
+    ])
+}
+
+/// Returns the newest `N` entries of a scanner window.
+fn tail<T: Copy, const N: usize>(window: &[T; WINDOW]) -> [T; N] {
+    core::array::from_fn(|k| window[WINDOW - N + k])
+}
+
+/// Returns the lookback period of the candlestick pattern scanner.
+///
+/// # Description
+/// The scanner reports each pattern as soon as that pattern's own lookback has elapsed; this is
+/// the longest of them, after which every pattern is evaluated.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_scan`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Number of bars before every pattern is evaluated
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_scan};
+///
+/// let lookback = cdl_scan::lookback(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 13);
+/// ```
+pub fn lookback(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(pattern_lookbacks(settings)?.into_iter().max().unwrap_or(0))
+}
+
+/// Scans price data for every supported candlestick pattern in one pass.
+///
+/// # Description
+/// Runs all [`CdlPattern`] detectors over the data and collects, for each bar, the patterns
+/// that fired together with their direction. Pattern sizes follow the TA-Lib compatible `_ext`
+/// detectors (for example [`cdl_doji::cdl_doji_ext`]) with `settings`; Dark Cloud Cover uses a
+/// penetration of 0.5 and the star patterns 0.3, as in TA-Lib. Engulfing and Three Outside have
+/// no size thresholds and use [`cdl_engulfing::cdl_engulfing`] and
+/// [`cdl_three_outside::cdl_three_outside`].
+///
+/// Each pattern is reported from its own lookback on, so short patterns already match on bars
+/// before [`lookback`].
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_matches` - Output array of matched patterns per bar
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     helper::CandleSettings,
+///     ohlcv::cdl_scan::{self, CdlMatches, CdlPattern},
+///     types::Signal,
+/// };
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Hammer after twelve ordinary candles
+/// input_open.push(9.6);
+/// input_high.push(9.68);
+/// input_low.push(9.0);
+/// input_close.push(9.65);
+/// let mut output_matches = vec![CdlMatches::default(); input_open.len()];
+///
+/// cdl_scan::cdl_scan(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &CandleSettings::default(),
+///     &mut output_matches,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_matches[12].signal(CdlPattern::Hammer), Signal::Bullish);
+/// ```
+pub fn cdl_scan(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    settings: &CandleSettings,
+    output_matches: &mut [CdlMatches],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_matches.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut scanner = CdlScanner::new(settings)?;
+    for (i, matches) in output_matches.iter_mut().enumerate() {
+        *matches = scanner.update(&Bar::new(
+            input_open[i],
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            0.0,
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Streaming candlestick pattern scanner.
+///
+/// Feed one closed bar at a time; each update returns the patterns matched on that bar, exactly
+/// as [`cdl_scan`] reports them. The scanner keeps the last four bars and the rolling candle
+/// averages of `settings`.
+///
+/// # Example
+/// ```
+/// use kand::{
+///     Bar, Indicator,
+///     helper::CandleSettings,
+///     ohlcv::cdl_scan::{CdlPattern, CdlScanner},
+///     types::Signal,
+/// };
+///
+/// let mut scanner = CdlScanner::new(&CandleSettings::default()).unwrap();
+/// for _ in 0..12 {
+///     scanner.update(&Bar::new(10.0, 10.5, 9.5, 10.2, 0.0)).unwrap();
+/// }
+///
+/// let matches = scanner.update(&Bar::new(9.6, 9.68, 9.0, 9.65, 0.0)).unwrap();
+/// assert_eq!(matches.signal(CdlPattern::Hammer), Signal::Bullish);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CdlScanner {
+    lookbacks: [usize; 20],
+    averages: CandleAverages,
+    bars: [Bar; WINDOW],
+    thresholds: [CandleThresholds; WINDOW],
+    count: usize,
+}
+
+impl CdlScanner {
+    /// Creates a streaming candlestick pattern scanner.
+    ///
+    /// # Arguments
+    /// * `settings` - Candle settings for the body and shadow thresholds
+    ///
+    /// # Errors
+    /// Returns an error if a pattern lookback cannot be computed from `settings`.
+    pub fn new(settings: &CandleSettings) -> Result<Self, KandError> {
+        Ok(Self {
+            lookbacks: pattern_lookbacks(settings)?,
+            averages: CandleAverages::new(settings),
+            bars: [Bar::default(); WINDOW],
+            thresholds: [CandleThresholds::default(); WINDOW],
+            count: 0,
+        })
+    }
+}
+
+impl Indicator for CdlScanner {
+    type Output = CdlMatches;
+
+    fn update(&mut self, input: &Bar) -> Result<CdlMatches, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.open.is_nan()
+                || input.high.is_nan()
+                || input.low.is_nan()
+                || input.close.is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.bars.rotate_left(1);
+        self.bars[WINDOW - 1] = *input;
+        self.thresholds.rotate_left(1);
+        self.thresholds[WINDOW - 1] = self.averages.value(input);
+        self.averages.push(input);
+        let index = self.count;
+        self.count = self.count.saturating_add(1);
+
+        let bars = &self.bars;
+        let thresholds = &self.thresholds;
+        let signals = [
+            cdl_dark_cloud_cover::cdl_dark_cloud_cover_ext_inc(
+                &tail(bars),
+                &tail(thresholds),
+                DARK_CLOUD_PENETRATION,
+            )?,
+            cdl_doji::cdl_doji_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_dragonfly_doji::cdl_dragonfly_doji_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_engulfing::cdl_engulfing_inc(&tail(bars))?,
+            cdl_evening_doji_star::cdl_evening_doji_star_ext_inc(
+                &tail(bars),
+                &tail(thresholds),
+                STAR_PENETRATION,
+            )?,
+            cdl_evening_star::cdl_evening_star_ext_inc(
+                &tail(bars),
+                &tail(thresholds),
+                STAR_PENETRATION,
+            )?,
+            cdl_gravestone_doji::cdl_gravestone_doji_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_hammer::cdl_hammer_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_harami::cdl_harami_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_harami_cross::cdl_harami_cross_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_inverted_hammer::cdl_inverted_hammer_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_long_shadow::cdl_long_shadow_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_marubozu::cdl_marubozu_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_morning_doji_star::cdl_morning_doji_star_ext_inc(
+                &tail(bars),
+                &tail(thresholds),
+                STAR_PENETRATION,
+            )?,
+            cdl_morning_star::cdl_morning_star_ext_inc(
+                &tail(bars),
+                &tail(thresholds),
+                STAR_PENETRATION,
+            )?,
+            cdl_piercing::cdl_piercing_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_three_black_crows::cdl_three_black_crows_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_three_inside::cdl_three_inside_ext_inc(&tail(bars), &tail(thresholds))?,
+            cdl_three_outside::cdl_three_outside_inc(&tail(bars))?,
+            cdl_three_white_soldiers::cdl_three_white_soldiers_ext_inc(
+                &tail(bars),
+                &tail(thresholds),
+            )?,
+        ];
+
+        let mut matches = CdlMatches::default();
+        for ((pattern, signal), lookback) in
+            CdlPattern::ALL.into_iter().zip(signals).zip(self.lookbacks)
+        {
+            if index >= lookback {
+                matches.insert(pattern, signal);
+            }
+        }
+        Ok(matches)
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookbacks.into_iter().max().unwrap_or(0)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback()
+    }
+
+    fn reset(&mut self) {
+        self.averages.clear();
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pattern candles from the `_ext` tests, each preceded by ordinary filler candles.
+    fn input() -> (Vec<TAFloat>, Vec<TAFloat>, Vec<TAFloat>, Vec<TAFloat>) {
+        let patterns: [&[(TAFloat, TAFloat, TAFloat, TAFloat)]; 8] = [
+            &[(10.0, 10.6, 9.4, 10.05)],
+            &[(9.6, 9.68, 9.0, 9.65)],
+            &[(10.0, 10.82, 9.98, 10.8)],
+            &[(10.8, 10.85, 9.9, 10.0), (10.3, 10.5, 10.2, 10.4)],
+            &[(10.0, 10.85, 9.95, 10.8), (10.9, 10.95, 10.25, 10.3)],
+            &[
+                (10.8, 10.85, 9.95, 10.0),
+                (9.8, 9.9, 9.75, 9.85),
+                (9.9, 10.55, 9.85, 10.5),
+            ],
+            &[
+                (10.0, 10.65, 9.95, 10.6),
+                (10.4, 11.05, 10.35, 11.0),
+                (10.8, 11.45, 10.75, 11.4),
+            ],
+            &[
+                (10.3, 10.32, 9.78, 9.8),
+                (9.9, 9.92, 9.38, 9.4),
+                (9.5, 9.52, 8.98, 9.0),
+            ],
+        ];
+        let mut bars = vec![(10.0, 10.5, 9.5, 10.2); 12];
+        for pattern in patterns {
+            bars.extend_from_slice(pattern);
+            bars.extend([(10.0, 10.5, 9.5, 10.2); 12]);
+        }
+        (
+            bars.iter().map(|bar| bar.0).collect(),
+            bars.iter().map(|bar| bar.1).collect(),
+            bars.iter().map(|bar| bar.2).collect(),
+            bars.iter().map(|bar| bar.3).collect(),
+        )
+    }
+
+    #[test]
+    fn test_cdl_scan() {
+        let (input_open, input_high, input_low, input_close) = input();
+        let len = input_open.len();
+        let settings = CandleSettings::default();
+        let mut output_matches = vec![CdlMatches::default(); len];
+
+        cdl_scan(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_matches,
+        )
+        .unwrap();
+
+        // Each scanned pattern agrees with its own detector
+        let mut expected = vec![vec![0; len]; CdlPattern::ALL.len()];
+        let (o, h, l, c) = (&input_open, &input_high, &input_low, &input_close);
+        let s = &settings;
+        cdl_dark_cloud_cover::cdl_dark_cloud_cover_ext(o, h, l, c, 0.5, s, &mut expected[0])
+            .unwrap();
+        cdl_doji::cdl_doji_ext(o, h, l, c, s, &mut expected[1]).unwrap();
+        cdl_dragonfly_doji::cdl_dragonfly_doji_ext(o, h, l, c, s, &mut expected[2]).unwrap();
+        cdl_engulfing::cdl_engulfing(o, h, l, c, &mut expected[3]).unwrap();
+        cdl_evening_doji_star::cdl_evening_doji_star_ext(o, h, l, c, 0.3, s, &mut expected[4])
+            .unwrap();
+        cdl_evening_star::cdl_evening_star_ext(o, h, l, c, 0.3, s, &mut expected[5]).unwrap();
+        cdl_gravestone_doji::cdl_gravestone_doji_ext(o, h, l, c, s, &mut expected[6]).unwrap();
+        cdl_hammer::cdl_hammer_ext(o, h, l, c, s, &mut expected[7]).unwrap();
+        cdl_harami::cdl_harami_ext(o, h, l, c, s, &mut expected[8]).unwrap();
+        cdl_harami_cross::cdl_harami_cross_ext(o, h, l, c, s, &mut expected[9]).unwrap();
+        cdl_inverted_hammer::cdl_inverted_hammer_ext(o, h, l, c, s, &mut expected[10]).unwrap();
+        cdl_long_shadow::cdl_long_shadow_ext(o, h, l, c, s, &mut expected[11]).unwrap();
+        cdl_marubozu::cdl_marubozu_ext(o, h, l, c, s, &mut expected[12]).unwrap();
+        cdl_morning_doji_star::cdl_morning_doji_star_ext(o, h, l, c, 0.3, s, &mut expected[13])
+            .unwrap();
+        cdl_morning_star::cdl_morning_star_ext(o, h, l, c, 0.3, s, &mut expected[14]).unwrap();
+        cdl_piercing::cdl_piercing_ext(o, h, l, c, s, &mut expected[15]).unwrap();
+        cdl_three_black_crows::cdl_three_black_crows_ext(o, h, l, c, s, &mut expected[16]).unwrap();
+        cdl_three_inside::cdl_three_inside_ext(o, h, l, c, s, &mut expected[17]).unwrap();
+        cdl_three_outside::cdl_three_outside(o, h, l, c, &mut expected[18]).unwrap();
+        cdl_three_white_soldiers::cdl_three_white_soldiers_ext(o, h, l, c, s, &mut expected[19])
+            .unwrap();
+
+        for (pattern, signals) in CdlPattern::ALL.into_iter().zip(&expected) {
+            for (i, &signal) in signals.iter().enumerate() {
+                let signal = if signal == TAInt::from(Signal::Invalid) {
+                    Signal::Neutral
+                } else {
+                    Signal::try_from(signal).unwrap()
+                };
+                assert_eq!(
+                    output_matches[i].signal(pattern),
+                    signal,
+                    "{pattern:?} at {i}"
+                );
+            }
+        }
+
+        // Patterns from the fixture are all found
+        assert_eq!(output_matches[12].signal(CdlPattern::Doji), Signal::Pattern);
+        assert_eq!(
+            output_matches[25].signal(CdlPattern::Hammer),
+            Signal::Bullish
+        );
+        assert_eq!(
+            output_matches[38].signal(CdlPattern::Marubozu),
+            Signal::Bullish
+        );
+        assert_eq!(
+            output_matches[52].signal(CdlPattern::Harami),
+            Signal::Bullish
+        );
+        assert_eq!(
+            output_matches[66].signal(CdlPattern::DarkCloudCover),
+            Signal::Bearish
+        );
+        assert_eq!(
+            output_matches[81].signal(CdlPattern::MorningStar),
+            Signal::Bullish
+        );
+        assert_eq!(
+            output_matches[96].signal(CdlPattern::ThreeWhiteSoldiers),
+            Signal::Bullish
+        );
+        assert_eq!(
+            output_matches[111].signal(CdlPattern::ThreeBlackCrows),
+            Signal::Bearish
+        );
+        assert!(output_matches[11].is_empty());
+    }
+
+    #[test]
+    fn test_cdl_scanner_indicator() {
+        let (input_open, input_high, input_low, input_close) = input();
+        let settings = CandleSettings::default();
+        let mut output_matches = vec![CdlMatches::default(); input_open.len()];
+        cdl_scan(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_matches,
+        )
+        .unwrap();
+
+        let mut scanner = CdlScanner::new(&settings).unwrap();
+        assert_eq!(scanner.lookback(), lookback(&settings).unwrap());
+        for _ in 0..2 {
+            for (i, &expected) in output_matches.iter().enumerate() {
+                let bar = Bar::new(
+                    input_open[i],
+                    input_high[i],
+                    input_low[i],
+                    input_close[i],
+                    0.0,
+                );
+                assert_eq!(scanner.update(&bar).unwrap(), expected);
+                assert_eq!(scanner.is_ready(), i >= scanner.lookback());
+            }
+            scanner.reset();
+            assert!(!scanner.is_ready());
+        }
+    }
+}
@@ -0,0 +1,667 @@
+use crate::{
+    KandError, TAFloat, TAInt,
+    helper::{
+        CandleSettings, CandleThresholds, bar_window, candle_thresholds, lower_shadow_length,
+        real_body_percent, threshold_window,
+    },
+    indicator::Indicator,
+    types::{Bar, Signal},
+};
+
+/// Returns the lookback period for Three Black Crows pattern detection.
+///
+/// # Description
+/// The Three Black Crows pattern spans three candles, so the first signal is available once
+/// three bars have been seen.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 2
+///
+/// # Errors
+/// This function does not return any errors. It always returns `Ok(2)`.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cdl_three_black_crows;
+/// let lookback = cdl_three_black_crows::lookback().unwrap();
+/// assert_eq!(lookback, 2);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(2)
+}
+
+/// Returns the lookback period for [`cdl_three_black_crows_ext`].
+///
+/// # Description
+/// Every candle of the pattern is judged against averages of the candles before it, so the first
+/// signal needs the longest average period used by the pattern plus the candles it spans.
+///
+/// # Arguments
+/// * `settings` - Candle settings passed to [`cdl_three_black_crows_ext`]
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_three_black_crows};
+/// let lookback = cdl_three_black_crows::lookback_ext(&CandleSettings::default()).unwrap();
+/// assert_eq!(lookback, 13);
+/// ```
+pub fn lookback_ext(settings: &CandleSettings) -> Result<usize, KandError> {
+    Ok(settings.shadow_very_short.avg_period + 3)
+}
+
+/// Detects Three Black Crows candlestick patterns in price data.
+///
+/// # Description
+/// Three Black Crows is a three-candle bearish pattern: three black candles with falling closes
+/// and very short lower shadows, each opening within the previous real body.
+///
+/// # Mathematical Formula
+/// ```text
+/// Black_i = Close_i < Open_i AND |Close_i - Open_i| / (High_i - Low_i) * 100 > BodyShortPercent
+///           AND (Close_i - Low_i) <= (High_i - Low_i) * ShadowVeryShortPercent / 100
+/// Decline = Close3 < Close2 < Close1 AND Open1 > Open2 >= Close1 AND Open2 > Open3 >= Close2
+/// Signal = Black1 AND Black2 AND Black3 AND Decline ? Bearish : Neutral
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_shadow_very_short_percent` - Maximum shadow length of a very short shadow as a percentage of the candle range (e.g. 10.0)
+/// * `opt_body_short_percent` - Maximum body size of a short candle as a percentage of its range (e.g. 30.0)
+/// * `output_signals` - Output array for pattern signals:
+///   - Bearish (-100): Three Black Crows
+///   - Neutral (0): No pattern detected
+///   - Invalid (-1): Fewer than three bars available
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than 3
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If a body or shadow percentage is <= 0
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::cdl_three_black_crows, types::Signal};
+///
+/// let input_open = vec![13.0, 12.2, 11.2, 10.0];
+/// let input_high = vec![13.1, 12.3, 11.3, 10.1];
+/// let input_low = vec![11.95, 10.95, 9.95, 9.0];
+/// let input_close = vec![12.0, 11.0, 10.0, 9.8];
+/// let mut output_signals = vec![0i64; 4];
+///
+/// cdl_three_black_crows::cdl_three_black_crows(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     10.0, // opt_shadow_very_short_percent
+///     30.0, // opt_body_short_percent
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// let expected: Vec<i64> = vec![Signal::Invalid.into(), Signal::Invalid.into(), Signal::Bearish.into(), Signal::Neutral.into()];
+/// assert_eq!(output_signals, expected);
+/// ```
+pub fn cdl_three_black_crows(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_shadow_very_short_percent: TAFloat,
+    opt_body_short_percent: TAFloat,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_shadow_very_short_percent <= 0.0 || opt_body_short_percent <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_three_black_crows_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            opt_shadow_very_short_percent,
+            opt_body_short_percent,
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Detects Three Black Crows patterns using TA-Lib style candle average thresholds.
+///
+/// # Description
+/// Same pattern as [`cdl_three_black_crows`], but body and shadow sizes are judged against rolling averages of
+/// the preceding candles configured by `settings`, following TA-Lib's `CDL3BLACKCROWS`. With
+/// [`CandleSettings::default`] the pattern fires on the same candles as TA-Lib.
+///
+/// Like TA-Lib, the extended form also requires a white candle before the three crows, so it needs one more bar than [`cdl_three_black_crows`].
+///
+/// # Mathematical Formula
+/// ```text
+/// ShadowVeryShort(k) = settings.shadow_very_short average for candle k
+///
+/// Black(k) = Close(k) < Open(k) AND LowerShadow(k) < ShadowVeryShort(k)
+/// Crows = Close(i-3) >= Open(i-3)
+///         AND Black(i-2) AND Black(i-1) AND Black(i)
+///         AND Close(i-2) < Open(i-1) < Open(i-2)
+///         AND Close(i-1) < Open < Open(i-1)
+///         AND High(i-3) > Close(i-2)
+///         AND Close(i-2) > Close(i-1) > Close
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `settings` - Candle settings for the body and shadow thresholds
+/// * `output_signals` - Output array for pattern signals, with the same meaning as in
+///   [`cdl_three_black_crows`]; the first [`lookback_ext`] entries are `Signal::Invalid`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{helper::CandleSettings, ohlcv::cdl_three_black_crows, types::Signal};
+///
+/// let mut input_open = vec![10.0; 12];
+/// let mut input_high = vec![10.5; 12];
+/// let mut input_low = vec![9.5; 12];
+/// let mut input_close = vec![10.2; 12];
+///
+/// // Pattern candles after twelve ordinary candles
+/// input_open.extend([10.3, 9.9, 9.5]);
+/// input_high.extend([10.32, 9.92, 9.52]);
+/// input_low.extend([9.78, 9.38, 8.98]);
+/// input_close.extend([9.8, 9.4, 9.0]);
+/// let mut output_signals = vec![0i64; input_open.len()];
+///
+/// cdl_three_black_crows::cdl_three_black_crows_ext(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &CandleSettings::default(),
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_signals[14], Signal::Bearish.into());
+/// ```
+pub fn cdl_three_black_crows_ext(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    settings: &CandleSettings,
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback_ext(settings)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut thresholds = vec![CandleThresholds::default(); len];
+    candle_thresholds(
+        settings,
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        &mut thresholds,
+    )?;
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_three_black_crows_ext_inc(
+            &bar_window(input_open, input_high, input_low, input_close, i),
+            &threshold_window(&thresholds, i),
+        )?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Evaluates the Three Black Crows pattern on a window of three consecutive candles.
+///
+/// # Description
+/// Checks whether the three most recent candles, ordered from oldest to newest, form the
+/// pattern. Because the pattern only looks at these bars, no state has to be carried between
+/// calls; slide the window forward by one bar to process the next candle.
+///
+/// # Arguments
+/// * `input_bars` - The last three candles, oldest first
+/// * `opt_shadow_very_short_percent` - Maximum shadow length of a very short shadow as a percentage of the candle range
+/// * `opt_body_short_percent` - Maximum body size of a short candle as a percentage of its range
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If a body or shadow percentage is <= 0
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_three_black_crows_inc(
+    input_bars: &[Bar; 3],
+    opt_shadow_very_short_percent: TAFloat,
+    opt_body_short_percent: TAFloat,
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_shadow_very_short_percent <= 0.0 || opt_body_short_percent <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [first, second, third] = input_bars;
+
+    let crow = |bar: &Bar| {
+        bar.close < bar.open
+            && real_body_percent(bar.open, bar.high, bar.low, bar.close) > opt_body_short_percent
+            && lower_shadow_length(bar.low, bar.open, bar.close)
+                <= (bar.high - bar.low) * opt_shadow_very_short_percent / 100.0
+    };
+    let declining = third.close < second.close
+        && second.close < first.close
+        && second.open < first.open
+        && second.open >= first.close
+        && third.open < second.open
+        && third.open >= second.close;
+
+    Ok(if crow(first) && crow(second) && crow(third) && declining {
+        Signal::Bearish.into()
+    } else {
+        Signal::Neutral.into()
+    })
+}
+
+/// Evaluates the TA-Lib style Three Black Crows pattern on a window of four consecutive candles.
+///
+/// # Description
+/// Incremental form of [`cdl_three_black_crows_ext`]. `input_thresholds[k]` holds the candle
+/// averages of `input_bars[k]`, as returned by
+/// [`CandleAverages::value`](crate::helper::CandleAverages::value) before that candle is pushed.
+///
+/// # Arguments
+/// * `input_bars` - The last four candles, oldest first
+/// * `input_thresholds` - Candle average thresholds of `input_bars`
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_three_black_crows_ext_inc(
+    input_bars: &[Bar; 4],
+    input_thresholds: &[CandleThresholds; 4],
+) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let crow = |k: usize| {
+        let bar = &input_bars[k];
+        bar.close < bar.open
+            && lower_shadow_length(bar.low, bar.open, bar.close)
+                < input_thresholds[k].shadow_very_short
+    };
+    let [lead, first, second, third] = input_bars;
+    Ok(
+        if lead.close >= lead.open
+            && crow(1)
+            && crow(2)
+            && crow(3)
+            && second.open < first.open
+            && second.open > first.close
+            && third.open < second.open
+            && third.open > second.close
+            && lead.high > first.close
+            && first.close > second.close
+            && second.close > third.close
+        {
+            Signal::Bearish.into()
+        } else {
+            Signal::Neutral.into()
+        },
+    )
+}
+
+/// Streaming Three Black Crows pattern detector.
+///
+/// Keeps the last three bars and emits [`Signal::Invalid`] until the window is full.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThreeBlackCrows {
+    opt_shadow_very_short_percent: TAFloat,
+    opt_body_short_percent: TAFloat,
+    window: [Bar; 3],
+    count: usize,
+}
+
+impl ThreeBlackCrows {
+    /// Creates a streaming Three Black Crows pattern detector.
+    ///
+    /// # Arguments
+    /// * `opt_shadow_very_short_percent` - Maximum shadow length of a very short shadow as a percentage of the candle range
+    /// * `opt_body_short_percent` - Maximum body size of a short candle as a percentage of its range
+    #[must_use]
+    pub const fn new(
+        opt_shadow_very_short_percent: TAFloat,
+        opt_body_short_percent: TAFloat,
+    ) -> Self {
+        Self {
+            opt_shadow_very_short_percent,
+            opt_body_short_percent,
+            window: [Bar::new(0.0, 0.0, 0.0, 0.0, 0.0); 3],
+            count: 0,
+        }
+    }
+}
+
+impl Indicator for ThreeBlackCrows {
+    type Output = TAInt;
+
+    fn update(&mut self, input: &Bar) -> Result<TAInt, KandError> {
+        self.window.rotate_left(1);
+        self.window[2] = *input;
+        if self.count < 3 {
+            self.count += 1;
+            if self.count < 3 {
+                return Ok(Signal::Invalid.into());
+            }
+        }
+        cdl_three_black_crows_inc(
+            &self.window,
+            self.opt_shadow_very_short_percent,
+            self.opt_body_short_percent,
+        )
+    }
+
+    fn lookback(&self) -> usize {
+        2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= 3
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_three_black_crows() {
+        let input_open = vec![13.0, 12.2, 11.2, 10.0];
+        let input_high = vec![13.1, 12.3, 11.3, 10.1];
+        let input_low = vec![11.95, 10.95, 9.95, 9.0];
+        let input_close = vec![12.0, 11.0, 10.0, 9.8];
+        let opt_shadow_very_short_percent = 10.0;
+        let opt_body_short_percent = 30.0;
+        let mut output_signals = vec![0i64; input_open.len()];
+
+        cdl_three_black_crows(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_shadow_very_short_percent,
+            opt_body_short_percent,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let expected: Vec<TAInt> = vec![
+            Signal::Invalid.into(),
+            Signal::Invalid.into(),
+            Signal::Bearish.into(),
+            Signal::Neutral.into(),
+        ];
+        assert_eq!(output_signals, expected);
+
+        // Test incremental calculation matches regular calculation
+        for (i, &expected) in output_signals.iter().enumerate().skip(lookback().unwrap()) {
+            let signal = cdl_three_black_crows_inc(
+                &bar_window(&input_open, &input_high, &input_low, &input_close, i),
+                opt_shadow_very_short_percent,
+                opt_body_short_percent,
+            )
+            .unwrap();
+            assert_eq!(signal, expected, "Mismatch at index {i}");
+        }
+
+        // Fewer bars than the pattern spans
+        assert!(
+            cdl_three_black_crows(
+                &input_open[..2],
+                &input_high[..2],
+                &input_low[..2],
+                &input_close[..2],
+                opt_shadow_very_short_percent,
+                opt_body_short_percent,
+                &mut output_signals[..2],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cdl_three_black_crows_indicator() {
+        let input_open = vec![13.0, 12.2, 11.2, 10.0];
+        let input_high = vec![13.1, 12.3, 11.3, 10.1];
+        let input_low = vec![11.95, 10.95, 9.95, 9.0];
+        let input_close = vec![12.0, 11.0, 10.0, 9.8];
+        let opt_shadow_very_short_percent = 10.0;
+        let opt_body_short_percent = 30.0;
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        cdl_three_black_crows(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_shadow_very_short_percent,
+            opt_body_short_percent,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut indicator =
+            ThreeBlackCrows::new(opt_shadow_very_short_percent, opt_body_short_percent);
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            assert_eq!(indicator.update(&bar).unwrap(), output_signals[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        let bar = Bar::new(
+            input_open[0],
+            input_high[0],
+            input_low[0],
+            input_close[0],
+            0.0,
+        );
+        assert_eq!(
+            indicator.update(&bar).unwrap(),
+            TAInt::from(Signal::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_cdl_three_black_crows_ext() {
+        let input_open = vec![
+            10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.3, 9.9, 9.5,
+            10.0, 10.0,
+        ];
+        let input_high = vec![
+            10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.5, 10.32, 9.92,
+            9.52, 10.5, 10.5,
+        ];
+        let input_low = vec![
+            9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.5, 9.78, 9.38, 8.98, 9.5, 9.5,
+        ];
+        let input_close = vec![
+            10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 10.2, 9.8, 9.4, 9.0,
+            10.2, 10.2,
+        ];
+        let settings = CandleSettings::default();
+        let lookback = lookback_ext(&settings).unwrap();
+        assert_eq!(lookback, 13);
+
+        let mut output_signals = vec![0i64; input_open.len()];
+        cdl_three_black_crows_ext(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &settings,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut expected = vec![TAInt::from(Signal::Neutral); input_open.len()];
+        expected[..lookback].fill(Signal::Invalid.into());
+        expected[14] = Signal::Bearish.into();
+        assert_eq!(output_signals, expected);
+
+        // Not enough candles to fill the averages
+        assert!(
+            cdl_three_black_crows_ext(
+                &input_open[..lookback],
+                &input_high[..lookback],
+                &input_low[..lookback],
+                &input_close[..lookback],
+                &settings,
+                &mut output_signals[..lookback],
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use super::cdl_engulfing::cdl_engulfing_inc;
+use crate::{
+    KandError, TAFloat, TAInt,
+    helper::bar_window,
+    indicator::Indicator,
+    types::{Bar, Signal},
+};
+
+/// Returns the lookback period for Three Outside Up/Down pattern detection.
+///
+/// # Description
+/// The Three Outside Up/Down pattern spans three candles, so the first signal is available once
+/// three bars have been seen.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 2
+///
+/// # Errors
+/// This function does not return any errors. It always returns `Ok(2)`.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cdl_three_outside;
+/// let lookback = cdl_three_outside::lookback().unwrap();
+/// assert_eq!(lookback, 2);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(2)
+}
+
+/// Detects Three Outside Up/Down candlestick patterns in price data.
+///
+/// # Description
+/// Three Outside Up/Down confirms an Engulfing pattern: the first two candles form an Engulfing
+/// pattern and the third candle closes beyond the second in the same direction. Three Outside
+/// Up is bullish, Three Outside Down is bearish.
+///
+/// The pattern compares real bodies and closes directly and uses no size thresholds, so unlike
+/// the other candlestick patterns it has no candle-settings variant.
+///
+/// # Mathematical Formula
+/// ```text
+/// Engulfing = cdl_engulfing(Candle1, Candle2)
+/// Up = Engulfing == Bullish AND Close3 > Close2
+/// Down = Engulfing == Bearish AND Close3 < Close2
+/// Signal = Up ? Bullish : Down ? Bearish : Neutral
+/// ```
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `output_signals` - Output array for pattern signals:
+///   - Bullish (100): Three Outside Up
+///   - Bearish (-100): Three Outside Down
+///   - Neutral (0): No pattern detected
+///   - Invalid (-1): Fewer than three bars available
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::InsufficientData` - If input length is less than 3
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN values (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::cdl_three_outside, types::Signal};
+///
+/// let input_open = vec![10.0, 8.9, 11.0, 11.5, 10.1];
+/// let input_high = vec![10.5, 11.2, 11.8, 11.6, 10.2];
+/// let input_low = vec![8.8, 8.7, 10.8, 10.0, 9.5];
+/// let input_close = vec![9.0, 11.0, 11.5, 10.2, 9.6];
+/// let mut output_signals = vec![0i64; 5];
+///
+/// cdl_three_outside::cdl_three_outside(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &mut output_signals,
+/// )
+/// .unwrap();
+///
+/// let expected: Vec<i64> = vec![Signal::Invalid.into(), Signal::Invalid.into(), Signal::Bullish.into(), Signal::Neutral.into(), Signal::Bearish.into()];
+/// assert_eq!(output_signals, expected);
+/// ```
+pub fn cdl_three_outside(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    output_signals: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_high.len()
+            || len != input_low.len()
+            || len != input_close.len()
+            || len != output_signals.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for (i, signal) in output_signals.iter_mut().enumerate().skip(lookback) {
+        *signal = cdl_three_outside_inc(&bar_window(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            i,
+        ))?;
+    }
+
+    // Fill initial values with -1
+    for signal in output_signals.iter_mut().take(lookback) {
+        *signal = Signal::Invalid.into();
+    }
+
+    Ok(())
+}
+
+/// Evaluates the Three Outside Up/Down pattern on a window of three consecutive candles.
+///
+/// # Description
+/// Checks whether the three most recent candles, ordered from oldest to newest, form the
+/// pattern. Because the pattern only looks at these bars, no state has to be carried between
+/// calls; slide the window forward by one bar to process the next candle.
+///
+/// # Arguments
+/// * `input_bars` - The last three candles, oldest first
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - Signal value for the newest candle
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
+pub fn cdl_three_outside_inc(input_bars: &[Bar; 3]) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_bars.iter().any(|bar| {
+            bar.open.is_nan() || bar.high.is_nan() || bar.low.is_nan() || bar.close.is_nan()
+        }) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let [first, second, third] = input_bars;
+
+    let engulfing = cdl_engulfing_inc(&[*first, *second])?;
+
+    Ok(
+        if engulfing == TAInt::from(Signal::Bullish) && third.close > second.close {
+            Signal::Bullish.into()
+        } else if engulfing == TAInt::from(Signal::Bearish) && third.close < second.close {
+            Signal::Bearish.into()
+        } else {
+            Signal::Neutral.into()
+        },
+    )
+}
+
+/// Streaming Three Outside Up/Down pattern detector.
+///
+/// Keeps the last three bars and emits [`Signal::Invalid`] until the window is full.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThreeOutside {
+    window: [Bar; 3],
+    count: usize,
+}
+
+impl ThreeOutside {
+    /// Creates a streaming Three Outside Up/Down pattern detector.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            window: [Bar::new(0.0, 0.0, 0.0, 0.0, 0.0); 3],
+            count: 0,
+        }
+    }
+}
+
+impl Indicator for ThreeOutside {
+    type Output = TAInt;
+
+    fn update(&mut self, input: &Bar) -> Result<TAInt, KandError> {
+        self.window.rotate_left(1);
+        self.window[2] = *input;
+        if self.count < 3 {
+            self.count += 1;
+            if self.count < 3 {
+                return Ok(Signal::Invalid.into());
+            }
+        }
+        cdl_three_outside_inc(&self.window)
+    }
+
+    fn lookback(&self) -> usize {
+        2
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= 3
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdl_three_outside() {
+        let input_open = vec![10.0, 8.9, 11.0, 11.5, 10.1];
+        let input_high = vec![10.5, 11.2, 11.8, 11.6, 10.2];
+        let input_low = vec![8.8, 8.7, 10.8, 10.0, 9.5];
+        let input_close = vec![9.0, 11.0, 11.5, 10.2, 9.6];
+        let mut output_signals = vec![0i64; input_open.len()];
+
+        cdl_three_outside(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let expected: Vec<TAInt> = vec![
+            Signal::Invalid.into(),
+            Signal::Invalid.into(),
+            Signal::Bullish.into(),
+            Signal::Neutral.into(),
+            Signal::Bearish.into(),
+        ];
+        assert_eq!(output_signals, expected);
+
+        // Test incremental calculation matches regular calculation
+        for (i, &expected) in output_signals.iter().enumerate().skip(lookback().unwrap()) {
+            let signal = cdl_three_outside_inc(&bar_window(
+                &input_open,
+                &input_high,
+                &input_low,
+                &input_close,
+                i,
+            ))
+            .unwrap();
+            assert_eq!(signal, expected, "Mismatch at index {i}");
+        }
+
+        // Fewer bars than the pattern spans
+        assert!(
+            cdl_three_outside(
+                &input_open[..2],
+                &input_high[..2],
+                &input_low[..2],
+                &input_close[..2],
+                &mut output_signals[..2],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cdl_three_outside_indicator() {
+        let input_open = vec![10.0, 8.9, 11.0, 11.5, 10.1];
+        let input_high = vec![10.5, 11.2, 11.8, 11.6, 10.2];
+        let input_low = vec![8.8, 8.7, 10.8, 10.0, 9.5];
+        let input_close = vec![9.0, 11.0, 11.5, 10.2, 9.6];
+        let len = input_open.len();
+        let mut output_signals = vec![0; len];
+        cdl_three_outside(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &mut output_signals,
+        )
+        .unwrap();
+
+        let mut indicator = ThreeOutside::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..len {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            assert_eq!(indicator.update(&bar).unwrap(), output_signals[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        let bar = Bar::new(
+            input_open[0],
+            input_high[0],
+            input_low[0],
+            input_close[0],
+            0.0,
+        );
+        assert_eq!(
+            indicator.update(&bar).unwrap(),
+            TAInt::from(Signal::Invalid)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{TAFloat, error::KandError, helper::period_to_k};
+use crate::{TAFloat, error::KandError, helper::period_to_k, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for DEMA calculation.
 ///
@@ -233,6 +233,120 @@ pub fn dema_inc(
     Ok((dema, new_ema1, new_ema2))
 }
 
+/// Streaming DEMA that owns both EMA stages and their SMA seeds.
+///
+/// Reads [`Bar::close`]. The first EMA is seeded with the SMA of the first `opt_period` prices
+/// and the second with the SMA of the first `opt_period` EMA1 values, as in [`dema`].
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::dema::Dema};
+///
+/// let mut dema = Dema::new(3).unwrap();
+/// for price in [10.0, 11.0, 12.0, 13.0] {
+///     assert!(dema.update(&Bar::from(price)).unwrap().is_nan());
+/// }
+/// assert!(!dema.update(&Bar::from(14.0)).unwrap().is_nan());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dema {
+    opt_period: usize,
+    alpha: TAFloat,
+    count: usize,
+    sum: TAFloat,
+    ema1: TAFloat,
+    ema2: TAFloat,
+}
+
+impl Dema {
+    /// Creates a streaming DEMA.
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` is less than 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            alpha: period_to_k(opt_period)?,
+            count: 0,
+            sum: 0.0,
+            ema1: TAFloat::NAN,
+            ema2: TAFloat::NAN,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the DEMA for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        let ready_at = 2 * self.opt_period - 1;
+
+        if self.count >= ready_at {
+            let (dema, ema1, ema2) = dema_inc(input_price, self.ema1, self.ema2, self.opt_period)?;
+            self.ema1 = ema1;
+            self.ema2 = ema2;
+            return Ok(dema);
+        }
+
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        if self.count < self.opt_period {
+            // Seed EMA1 with the SMA of the first period
+            self.sum = if self.count == 0 {
+                input_price
+            } else {
+                self.sum + input_price
+            };
+            self.count += 1;
+            if self.count == self.opt_period {
+                self.ema1 = self.sum / self.opt_period as TAFloat;
+                self.sum = self.ema1;
+            }
+        } else {
+            // Seed EMA2 with the SMA of the first period of EMA1 values
+            self.ema1 = input_price.mul_add(self.alpha, self.ema1 * (1.0 - self.alpha));
+            self.sum += self.ema1;
+            self.count += 1;
+        }
+
+        if self.count < ready_at {
+            return Ok(TAFloat::NAN);
+        }
+        self.ema2 = self.sum / self.opt_period as TAFloat;
+        Ok(self.ema1.mul_add(2.0, -self.ema2))
+    }
+}
+
+impl Indicator for Dema {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        2 * (self.opt_period - 1)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= 2 * self.opt_period - 1
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.sum = 0.0;
+        self.ema1 = TAFloat::NAN;
+        self.ema2 = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -302,4 +416,38 @@ fn test_dema_calculation() {
             prev_ema2 = new_ema2;
         }
     }
+
+    #[test]
+    fn test_dema_indicator() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6,
+        ];
+        let opt_period = 5;
+        let mut output_dema = vec![0.0; input.len()];
+        let mut output_ema1 = vec![0.0; input.len()];
+        let mut output_ema2 = vec![0.0; input.len()];
+        dema(
+            &input,
+            opt_period,
+            &mut output_dema,
+            &mut output_ema1,
+            &mut output_ema2,
+        )
+        .unwrap();
+
+        let mut indicator = Dema::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        for (i, &price) in input.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_dema[i], epsilon = 0.00001);
+                assert!(indicator.is_ready());
+            }
+        }
+    }
 }
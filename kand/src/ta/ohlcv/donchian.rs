@@ -0,0 +1,342 @@
+use crate::{
+    KandError, TAFloat,
+    indicator::Indicator,
+    ta::stats::{max, min},
+    types::Bar,
+    window::RollingExtremum,
+};
+
+/// Returns the lookback period required for Donchian Channels calculation.
+///
+/// # Description
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For Donchian Channels, this equals the specified period minus one.
+///
+/// # Arguments
+/// * `opt_period` - The time period used for calculation (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns `opt_period - 1` on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `opt_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::donchian;
+/// let lookback = donchian::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    max::lookback(opt_period)
+}
+
+/// Calculates Donchian Channels for a price series.
+///
+/// # Description
+/// Donchian Channels bracket price with the highest high and the lowest low of the last `n` bars.
+/// A close above the upper channel marks a new `n`-bar high, which is the classic breakout
+/// signal, and the middle channel halfway between them is often used as a trailing exit.
+///
+/// # Mathematical Formula
+/// ```text
+/// Upper[i]  = max(High[i-n+1...i])
+/// Lower[i]  = min(Low[i-n+1...i])
+/// Middle[i] = (Upper[i] + Lower[i]) / 2
+/// ```
+/// Where:
+/// - n is the period
+/// - i is the current index
+///
+/// # Calculation Steps
+/// 1. Calculate the rolling maximum of the highs as the upper channel
+/// 2. Calculate the rolling minimum of the lows as the lower channel
+/// 3. Average the two channels to get the middle channel
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `opt_period` - Calculation period (must be >= 2)
+/// * `output_upper` - Buffer to store upper channel values
+/// * `output_middle` - Buffer to store middle channel values
+/// * `output_lower` - Buffer to store lower channel values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output array lengths differ
+/// * `KandError::InvalidParameter` - If period is less than 2
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input contains NaN (with `check-nan`)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::donchian;
+///
+/// let high = vec![10.0, 12.0, 15.0, 14.0, 13.0];
+/// let low = vec![8.0, 9.0, 11.0, 10.0, 9.0];
+/// let mut upper = vec![0.0; 5];
+/// let mut middle = vec![0.0; 5];
+/// let mut lower = vec![0.0; 5];
+///
+/// donchian::donchian(&high, &low, 3, &mut upper, &mut middle, &mut lower).unwrap();
+/// assert!(upper[1].is_nan());
+/// assert_eq!((upper[4], middle[4], lower[4]), (15.0, 12.0, 9.0));
+/// ```
+pub fn donchian(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    opt_period: usize,
+    output_upper: &mut [TAFloat],
+    output_middle: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_low.len()
+            || len != output_upper.len()
+            || len != output_middle.len()
+            || len != output_lower.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    max::max(input_high, opt_period, output_upper)?;
+    min::min(input_low, opt_period, output_lower)?;
+
+    for i in lookback..len {
+        output_middle[i] = TAFloat::midpoint(output_upper[i], output_lower[i]);
+    }
+
+    // Fill initial values with NAN
+    for value in output_middle.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Incrementally calculates the next Donchian Channels values.
+///
+/// # Description
+/// `highs` and `lows` hold the highs and lows of the current window, as created by
+/// [`RollingExtremum::highest`] and [`RollingExtremum::lowest`] with the channel period. This
+/// function pushes the new bar into both and returns the channels of the updated window in O(1)
+/// amortized, so the result is exact even when the bar holding an extreme leaves the window.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `highs` - Rolling highest high of the current window, updated in place
+/// * `lows` - Rolling lowest low of the current window, updated in place
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Returns (upper, middle, lower), all NaN
+///   until `highs` holds a full period
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input contains NaN (with `check-nan`)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::donchian, window::RollingExtremum};
+///
+/// let mut highs = RollingExtremum::highest(3).unwrap();
+/// let mut lows = RollingExtremum::lowest(3).unwrap();
+/// for (high, low) in [(15.0, 10.0), (12.0, 9.0), (11.0, 10.0)] {
+///     donchian::donchian_inc(high, low, &mut highs, &mut lows).unwrap();
+/// }
+/// // The bar holding the 15.0 high leaves the window
+/// let (upper, middle, lower) = donchian::donchian_inc(13.0, 10.0, &mut highs, &mut lows).unwrap();
+/// assert_eq!((upper, middle, lower), (13.0, 11.0, 9.0));
+/// ```
+pub fn donchian_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    highs: &mut RollingExtremum,
+    lows: &mut RollingExtremum,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_high.is_nan() || input_low.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (upper, _) = highs.push(input_high);
+    let (lower, _) = lows.push(input_low);
+    if !highs.is_full() {
+        return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+    }
+    Ok((upper, TAFloat::midpoint(upper, lower), lower))
+}
+
+/// Streaming Donchian Channels over the last `opt_period` highs and lows, tracked with
+/// [`RollingExtremum`].
+///
+/// Yields `(upper, middle, lower)` by owning the windows that [`donchian_inc`] takes. Folding
+/// [`Indicator::update`] over a series reproduces [`donchian`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Donchian {
+    opt_period: usize,
+    highs: RollingExtremum,
+    lows: RollingExtremum,
+}
+
+impl Donchian {
+    /// Creates streaming Donchian Channels.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The lookback window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            highs: RollingExtremum::highest(opt_period)?,
+            lows: RollingExtremum::lowest(opt_period)?,
+        })
+    }
+}
+
+impl Indicator for Donchian {
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        donchian_inc(input.high, input.low, &mut self.highs, &mut self.lows)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.highs.is_full()
+    }
+
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_donchian_calculation() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let opt_period = 10;
+        let len = input_high.len();
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+
+        donchian(
+            &input_high,
+            &input_low,
+            opt_period,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+        )
+        .unwrap();
+
+        // First 9 values should be NaN
+        for i in 0..9 {
+            assert!(output_upper[i].is_nan());
+            assert!(output_middle[i].is_nan());
+            assert!(output_lower[i].is_nan());
+        }
+
+        let expected = [
+            (9, 35281.5, 35206.1, 35130.7),
+            (13, 35281.5, 35212.7, 35143.9),
+            (16, 35281.5, 35115.8, 34950.1),
+            (17, 35256.0, 35103.05, 34950.1),
+            (20, 35241.0, 35086.3, 34931.6),
+            (24, 35128.9, 35019.95, 34911.0),
+        ];
+        for (i, upper, middle, lower) in expected {
+            assert_relative_eq!(output_upper[i], upper, epsilon = 0.0001);
+            assert_relative_eq!(output_middle[i], middle, epsilon = 0.0001);
+            assert_relative_eq!(output_lower[i], lower, epsilon = 0.0001);
+        }
+
+        // Incremental calculation matches the batch outputs, including when an extreme leaves
+        let mut highs = RollingExtremum::highest(opt_period).unwrap();
+        let mut lows = RollingExtremum::lowest(opt_period).unwrap();
+        for i in 0..len {
+            let (upper, middle, lower) =
+                donchian_inc(input_high[i], input_low[i], &mut highs, &mut lows).unwrap();
+            if i < opt_period - 1 {
+                assert!(upper.is_nan() && middle.is_nan() && lower.is_nan());
+            } else {
+                assert_relative_eq!(upper, output_upper[i], epsilon = 0.00001);
+                assert_relative_eq!(middle, output_middle[i], epsilon = 0.00001);
+                assert_relative_eq!(lower, output_lower[i], epsilon = 0.00001);
+            }
+        }
+
+        // Streaming indicator matches the batch outputs
+        let mut indicator = Donchian::new(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback(opt_period).unwrap());
+        for i in 0..len {
+            let bar = Bar::new(input_low[i], input_high[i], input_low[i], input_low[i], 0.0);
+            let (upper, middle, lower) = indicator.update(&bar).unwrap();
+            if i < opt_period - 1 {
+                assert!(upper.is_nan() && middle.is_nan() && lower.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(upper, output_upper[i], epsilon = 0.00001);
+                assert_relative_eq!(middle, output_middle[i], epsilon = 0.00001);
+                assert_relative_eq!(lower, output_lower[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
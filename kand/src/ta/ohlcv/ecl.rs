@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for Expanded Camarilla Levels (ECL) calculation.
 ///
@@ -275,6 +275,67 @@ pub fn ecl_inc(
     Ok((h5_val, h4, h3, h2, h1, l1, l2, l3, l4, l5))
 }
 
+/// Streaming ECL that remembers the previous bar.
+///
+/// Yields `(h5, h4, h3, h2, h1, l1, l2, l3, l4, l5)` computed from the previous bar, so the
+/// first bar produces `NaN` levels exactly like [`ecl`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ecl {
+    prev_bar: Option<Bar>,
+}
+
+impl Ecl {
+    /// Creates a streaming ECL.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { prev_bar: None }
+    }
+}
+
+impl Default for Ecl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for Ecl {
+    type Output = (
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+    );
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        match self.prev_bar.replace(*input) {
+            Some(prev) => ecl_inc(prev.high, prev.low, prev.close),
+            None => {
+                let nan = TAFloat::NAN;
+                Ok((nan, nan, nan, nan, nan, nan, nan, nan, nan, nan))
+            }
+        }
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_bar.is_some()
+    }
+
+    fn reset(&mut self) {
+        self.prev_bar = None;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -351,4 +412,56 @@ fn test_ecl_calculation() {
         assert_relative_eq!(l4_inc, output_l4[i], epsilon = TAFloat::EPSILON);
         assert_relative_eq!(l5_inc, output_l5[i], epsilon = TAFloat::EPSILON);
     }
+
+    #[test]
+    fn test_ecl_indicator() {
+        let input_high = vec![24.20, 24.07, 24.04, 23.87, 23.67];
+        let input_low = vec![23.85, 23.72, 23.64, 23.37, 23.46];
+        let input_close = vec![23.89, 23.95, 23.67, 23.78, 23.50];
+        let mut outputs = vec![vec![0.0; 5]; 10];
+        let [h5, h4, h3, h2, h1, l1, l2, l3, l4, l5] = &mut outputs[..] else {
+            unreachable!()
+        };
+        ecl(
+            &input_high,
+            &input_low,
+            &input_close,
+            h5,
+            h4,
+            h3,
+            h2,
+            h1,
+            l1,
+            l2,
+            l3,
+            l4,
+            l5,
+        )
+        .unwrap();
+
+        let mut indicator = Ecl::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..input_high.len() {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            let (h5, h4, h3, h2, h1, l1, l2, l3, l4, l5) = indicator.update(&bar).unwrap();
+            let levels = [h5, h4, h3, h2, h1, l1, l2, l3, l4, l5];
+            for (value, output) in levels.iter().zip(&outputs) {
+                if i == 0 {
+                    assert!(value.is_nan());
+                } else {
+                    assert_relative_eq!(*value, output[i], epsilon = 0.00001);
+                }
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
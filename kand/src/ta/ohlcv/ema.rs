@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat, helper::period_to_k};
+use crate::{KandError, TAFloat, helper::period_to_k, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for EMA calculation.
 ///
@@ -208,6 +208,111 @@ pub fn ema_inc(
     Ok((input_price - prev_ema).mul_add(multiplier, prev_ema))
 }
 
+/// Streaming EMA that owns its SMA seed and previous EMA value.
+///
+/// Reads [`Bar::close`]. The first `opt_period` prices are averaged to seed the EMA exactly as
+/// [`ema`] does, so folding [`Indicator::update`] over a series reproduces the batch output.
+/// Composite indicators feed derived series through [`Ema::update_value`].
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::ema::Ema};
+///
+/// let mut ema = Ema::new(3, None).unwrap();
+/// for price in [10.0, 11.0, 12.0] {
+///     ema.update(&Bar::from(price)).unwrap();
+/// }
+/// assert!(ema.is_ready());
+/// let next = ema.update(&Bar::from(13.0)).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ema {
+    opt_period: usize,
+    k: TAFloat,
+    count: usize,
+    sum: TAFloat,
+    prev_ema: TAFloat,
+}
+
+impl Ema {
+    /// Creates a streaming EMA.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for EMA calculation (must be >= 2)
+    /// * `opt_k` - Optional custom smoothing factor. If None, uses 2/(period+1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize, opt_k: Option<TAFloat>) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        let k = match opt_k {
+            Some(k) => k,
+            None => period_to_k(opt_period)?,
+        };
+        Ok(Self {
+            opt_period,
+            k,
+            count: 0,
+            sum: 0.0,
+            prev_ema: TAFloat::NAN,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the EMA for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        if self.count >= self.opt_period {
+            self.prev_ema = ema_inc(input_price, self.prev_ema, self.opt_period, Some(self.k))?;
+            return Ok(self.prev_ema);
+        }
+
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.sum = if self.count == 0 {
+            input_price
+        } else {
+            self.sum + input_price
+        };
+        self.count += 1;
+
+        if self.count < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        self.prev_ema = self.sum / (self.opt_period as TAFloat);
+        Ok(self.prev_ema)
+    }
+}
+
+impl Indicator for Ema {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.sum = 0.0;
+        self.prev_ema = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -269,4 +374,32 @@ fn test_ema_calculation() {
             prev_ema = result;
         }
     }
+
+    #[test]
+    fn test_ema_indicator() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+        ];
+        let opt_period = 14;
+        let mut output_ema = vec![0.0; input_prices.len()];
+        ema(&input_prices, opt_period, None, &mut output_ema).unwrap();
+
+        let mut indicator = Ema::new(opt_period, None).unwrap();
+        assert_eq!(indicator.lookback(), lookback(opt_period).unwrap());
+        for (i, &price) in input_prices.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < opt_period - 1 {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_ema[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
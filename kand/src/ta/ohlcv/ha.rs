@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for Heikin-Ashi calculation
 ///
@@ -219,6 +219,67 @@ pub fn ha_inc(
     Ok((ha_open, ha_high, ha_low, ha_close))
 }
 
+/// Streaming Heikin-Ashi that remembers the previous HA open and close.
+///
+/// Yields `(ha_open, ha_high, ha_low, ha_close)`. The first bar is seeded the same way as in
+/// [`ha`], so every bar produces a value.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ha {
+    prev_ha: Option<(TAFloat, TAFloat)>,
+}
+
+impl Ha {
+    /// Creates a streaming Heikin-Ashi.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { prev_ha: None }
+    }
+}
+
+impl Default for Ha {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for Ha {
+    type Output = (TAFloat, TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        let output = match self.prev_ha {
+            Some((prev_ha_open, prev_ha_close)) => ha_inc(
+                input.open,
+                input.high,
+                input.low,
+                input.close,
+                prev_ha_open,
+                prev_ha_close,
+            )?,
+            None => (
+                TAFloat::midpoint(input.open, input.close),
+                input.high,
+                input.low,
+                (input.open + input.high + input.low + input.close) / 4.0,
+            ),
+        };
+        self.prev_ha = Some((output.0, output.3));
+        Ok(output)
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_ha.is_some()
+    }
+
+    fn reset(&mut self) {
+        self.prev_ha = None;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -279,4 +340,48 @@ fn test_ha_calculation() {
             assert_relative_eq!(ha_close, output_close[i], epsilon = 0.0001);
         }
     }
+
+    #[test]
+    fn test_ha_indicator() {
+        let input_open = vec![10.0, 10.5, 11.2, 10.8, 11.5];
+        let input_high = vec![11.0, 11.5, 11.8, 11.3, 12.0];
+        let input_low = vec![9.5, 10.2, 10.8, 10.5, 11.3];
+        let input_close = vec![10.8, 11.3, 11.5, 11.0, 11.8];
+        let mut output_open = vec![0.0; 5];
+        let mut output_high = vec![0.0; 5];
+        let mut output_low = vec![0.0; 5];
+        let mut output_close = vec![0.0; 5];
+        ha(
+            &input_open,
+            &input_high,
+            &input_low,
+            &input_close,
+            &mut output_open,
+            &mut output_high,
+            &mut output_low,
+            &mut output_close,
+        )
+        .unwrap();
+
+        let mut indicator = Ha::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for i in 0..input_open.len() {
+            let bar = Bar::new(
+                input_open[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            let (open, high, low, close) = indicator.update(&bar).unwrap();
+            assert_relative_eq!(open, output_open[i], epsilon = 0.00001);
+            assert_relative_eq!(high, output_high[i], epsilon = 0.00001);
+            assert_relative_eq!(low, output_low[i], epsilon = 0.00001);
+            assert_relative_eq!(close, output_close[i], epsilon = 0.00001);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
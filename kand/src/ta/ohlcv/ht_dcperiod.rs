@@ -0,0 +1,345 @@
+use crate::{KandError, TAFloat, hilbert::HilbertTransform, indicator::Indicator, types::Bar};
+
+/// Bars that only prime the Hilbert transform's price smoother, as in TA-Lib.
+const SMOOTHER_WARMUP: usize = 9;
+
+/// Returns the lookback period required for Hilbert Transform Dominant Cycle Period calculation.
+///
+/// # Description
+/// `HT_DCPERIOD` needs 32 bars to prime the price smoother and the Hilbert transform, matching
+/// TA-Lib. It takes no parameters.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (always 32)
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_dcperiod;
+/// let lookback = ht_dcperiod::lookback().unwrap();
+/// assert_eq!(lookback, 32);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(32)
+}
+
+/// Calculates the Hilbert Transform Dominant Cycle Period (`HT_DCPERIOD`).
+///
+/// # Description
+/// John Ehlers' dominant cycle period measures the length, in bars, of the strongest cycle
+/// currently present in the price. The period comes from the Hilbert transform homodyne
+/// discriminator and is smoothed once more before output. The calculation matches TA-Lib's
+/// `HT_DCPERIOD`, including its zero-seeded start.
+///
+/// # Mathematical Formula
+/// ```text
+/// Period = 360 / atan(Im / Re) in degrees, limited to [0.67, 1.5] * Period(previous) and [6, 50]
+/// Period = 0.2 * Period + 0.8 * Period(previous)
+/// SmoothPeriod = 0.33 * Period + 0.67 * SmoothPeriod(previous)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Smooth prices with a 4-bar WMA and run the Hilbert transform ([`HilbertTransform`])
+/// 2. Smooth the measured period
+/// 3. Write NaN before the lookback period
+///
+/// # Arguments
+/// * `input_prices` - Array of price values
+/// * `output_dcperiod` - Array to store the dominant cycle period. Must match input length
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output length doesn't match input
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input price is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_dcperiod;
+/// let prices: Vec<f64> = (0..40).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_dcperiod = vec![0.0; prices.len()];
+///
+/// ht_dcperiod::ht_dcperiod(&prices, &mut output_dcperiod).unwrap();
+/// assert!(output_dcperiod[31].is_nan());
+/// assert!(!output_dcperiod[32].is_nan());
+/// ```
+pub fn ht_dcperiod(
+    input_prices: &[TAFloat],
+    output_dcperiod: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_dcperiod.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut hilbert = HilbertTransform::new(SMOOTHER_WARMUP);
+    let mut smooth_period = 0.0;
+    for i in 0..len {
+        smooth_period = dcperiod_step(input_prices[i], smooth_period, &mut hilbert);
+        output_dcperiod[i] = if i >= lookback {
+            smooth_period
+        } else {
+            TAFloat::NAN
+        };
+    }
+
+    Ok(())
+}
+
+/// Calculates the next dominant cycle period incrementally.
+///
+/// # Description
+/// The discriminator is carried in a [`HilbertTransform`] that this function advances in place,
+/// while the smoothed period is passed in and returned like other `*_inc` functions. Start a
+/// series with `HilbertTransform::new(9)` and `0.0` for `prev_dcperiod`; folding this function
+/// over the prices then reproduces [`ht_dcperiod`], whose values are valid from index
+/// [`lookback`] onwards. While the transform is still priming, `prev_dcperiod` is returned
+/// unchanged.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `prev_dcperiod` - The previous smoothed dominant cycle period
+/// * `hilbert` - Hilbert transform state, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The new dominant cycle period on success
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::{hilbert::HilbertTransform, ohlcv::ht_dcperiod};
+///
+/// let mut hilbert = HilbertTransform::new(9);
+/// let mut prev_dcperiod = 0.0;
+/// for i in 0..40 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     prev_dcperiod = ht_dcperiod::ht_dcperiod_inc(price, prev_dcperiod, &mut hilbert).unwrap();
+/// }
+/// ```
+pub fn ht_dcperiod_inc(
+    input_price: TAFloat,
+    prev_dcperiod: TAFloat,
+    hilbert: &mut HilbertTransform,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan() || prev_dcperiod.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(dcperiod_step(input_price, prev_dcperiod, hilbert))
+}
+
+/// Advances the transform and smooths its period once more.
+fn dcperiod_step(
+    price: TAFloat,
+    prev_dcperiod: TAFloat,
+    hilbert: &mut HilbertTransform,
+) -> TAFloat {
+    hilbert.update(price).map_or(prev_dcperiod, |step| {
+        step.period.mul_add(0.33, 0.67 * prev_dcperiod)
+    })
+}
+
+/// Streaming dominant cycle period that owns the Hilbert transform.
+///
+/// Reads [`Bar::close`]. Outputs are `NaN` for the first [`lookback`] prices, after which every
+/// value matches [`ht_dcperiod`] for the same series.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::ht_dcperiod::HtDcperiod};
+///
+/// let mut dcperiod = HtDcperiod::new();
+/// for i in 0..40 {
+///     let value = dcperiod
+///         .update(&Bar::from(100.0 + (i as f64 * 0.3).sin()))
+///         .unwrap();
+/// }
+/// assert!(dcperiod.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtDcperiod {
+    hilbert: HilbertTransform,
+    prev_dcperiod: TAFloat,
+}
+
+impl HtDcperiod {
+    /// Creates a streaming dominant cycle period.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            hilbert: HilbertTransform::new(SMOOTHER_WARMUP),
+            prev_dcperiod: 0.0,
+        }
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the dominant cycle period for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        self.prev_dcperiod = ht_dcperiod_inc(input_price, self.prev_dcperiod, &mut self.hilbert)?;
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(self.prev_dcperiod)
+    }
+}
+
+impl Default for HtDcperiod {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtDcperiod {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        32
+    }
+
+    fn is_ready(&self) -> bool {
+        self.hilbert.count() > 32
+    }
+
+    fn reset(&mut self) {
+        self.hilbert.reset();
+        self.prev_dcperiod = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 60] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3, 35154.0, 35216.3, 35211.8, 35158.4,
+        35172.0, 35176.7, 35113.3, 35114.7, 35129.3, 35094.6, 35114.4, 35094.5, 35116.0, 35105.4,
+        35050.7, 35031.3, 35008.1, 35021.4, 35048.4, 35080.1, 35043.6, 34962.7, 34970.1, 34980.1,
+    ];
+
+    #[test]
+    fn test_ht_dcperiod_calculation() {
+        let mut output_dcperiod = vec![0.0; INPUT_PRICES.len()];
+        ht_dcperiod(&INPUT_PRICES, &mut output_dcperiod).unwrap();
+
+        // First 32 values should be NaN
+        for value in output_dcperiod.iter().take(32) {
+            assert!(value.is_nan());
+        }
+
+        // TA-Lib HT_DCPERIOD
+        let expected_values = [
+            15.646_696_015_449_795,
+            17.211_764_825_124_15,
+            18.895_466_084_543_493,
+            20.545_573_120_872_994,
+            22.077_019_863_512_398,
+            23.452_888_630_491_213,
+            24.661_410_988_631_182,
+            25.705_414_642_103_27,
+            26.596_203_369_121_334,
+            27.345_367_664_632_96,
+            27.973_245_022_386_8,
+            28.500_417_374_467_2,
+            28.929_586_030_216_818,
+            29.294_561_457_462_603,
+            29.635_993_266_823_32,
+            29.883_843_983_491_225,
+            30.007_476_748_797_245,
+            30.076_379_335_554_5,
+            30.050_157_423_753_554,
+            29.884_047_444_904_04,
+            29.663_820_492_252_533,
+            29.458_321_472_009_46,
+            29.332_278_861_060_78,
+            29.373_664_175_309_667,
+            29.586_520_250_888_17,
+            30.005_152_734_721_49,
+            30.428_482_608_345_583,
+            30.572_653_513_171_126,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_dcperiod[i + 32], expected, epsilon = 0.00001);
+        }
+
+        // Incremental calculation should match the batch output
+        let mut hilbert = HilbertTransform::new(9);
+        let mut prev_dcperiod = 0.0;
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            prev_dcperiod = ht_dcperiod_inc(price, prev_dcperiod, &mut hilbert).unwrap();
+            if i >= 32 {
+                assert_relative_eq!(prev_dcperiod, output_dcperiod[i], epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ht_dcperiod_indicator() {
+        let mut output_dcperiod = vec![0.0; INPUT_PRICES.len()];
+        ht_dcperiod(&INPUT_PRICES, &mut output_dcperiod).unwrap();
+
+        let mut indicator = HtDcperiod::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < 32 {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_dcperiod[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
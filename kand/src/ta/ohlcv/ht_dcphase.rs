@@ -0,0 +1,336 @@
+use crate::{KandError, TAFloat, hilbert::DominantCycle, indicator::Indicator, types::Bar};
+
+/// Returns the lookback period required for Hilbert Transform Dominant Cycle Phase calculation.
+///
+/// # Description
+/// `HT_DCPHASE` needs 63 bars to prime the price smoother and let the dominant cycle settle,
+/// matching TA-Lib. It takes no parameters.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (always 63)
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_dcphase;
+/// let lookback = ht_dcphase::lookback().unwrap();
+/// assert_eq!(lookback, 63);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(63)
+}
+
+/// Calculates the Hilbert Transform Dominant Cycle Phase (`HT_DCPHASE`).
+///
+/// # Description
+/// John Ehlers' dominant cycle phase locates the current bar within the dominant cycle, in
+/// degrees. It correlates the smoothed prices of the last dominant cycle period with a sine and
+/// a cosine, then corrects for the lag of the price smoother. The calculation matches TA-Lib's
+/// `HT_DCPHASE`, including its zero-seeded start.
+///
+/// # Mathematical Formula
+/// ```text
+/// N = int(SmoothPeriod + 0.5)
+/// Real = Sum(sin(360 * i / N) * Smooth[i]) for i in 0..N
+/// Imag = Sum(cos(360 * i / N) * Smooth[i]) for i in 0..N
+/// Phase = atan(Real / Imag) + 90 + 360 / SmoothPeriod (+ 180 if Imag < 0), wrapped to (-45, 315]
+/// ```
+///
+/// # Calculation Steps
+/// 1. Smooth prices with a 4-bar WMA and measure the dominant cycle ([`DominantCycle`])
+/// 2. Output its phase
+/// 3. Write NaN before the lookback period
+///
+/// # Arguments
+/// * `input_prices` - Array of price values
+/// * `output_dcphase` - Array to store the dominant cycle phase in degrees. Must match input length
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output length doesn't match input
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input price is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_dcphase;
+/// let prices: Vec<f64> = (0..80).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_dcphase = vec![0.0; prices.len()];
+///
+/// ht_dcphase::ht_dcphase(&prices, &mut output_dcphase).unwrap();
+/// assert!(output_dcphase[62].is_nan());
+/// assert!(!output_dcphase[63].is_nan());
+/// ```
+pub fn ht_dcphase(
+    input_prices: &[TAFloat],
+    output_dcphase: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_dcphase.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut cycle = DominantCycle::new();
+    for i in 0..len {
+        let step = cycle.update(input_prices[i]);
+        output_dcphase[i] = match step {
+            Some(step) if i >= lookback => step.phase,
+            _ => TAFloat::NAN,
+        };
+    }
+
+    Ok(())
+}
+
+/// Calculates the next dominant cycle phase incrementally.
+///
+/// # Description
+/// All state lives in the [`DominantCycle`], which this function advances in place. Start a
+/// series with `DominantCycle::new()`; folding this function over the prices then reproduces
+/// [`ht_dcphase`], whose values are valid from index [`lookback`] onwards. While the transform
+/// is still priming, the phase is NaN.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `cycle` - Dominant cycle state, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The dominant cycle phase in degrees on success
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::{hilbert::DominantCycle, ohlcv::ht_dcphase};
+///
+/// let mut cycle = DominantCycle::new();
+/// for i in 0..80 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     let dcphase = ht_dcphase::ht_dcphase_inc(price, &mut cycle).unwrap();
+/// }
+/// ```
+pub fn ht_dcphase_inc(
+    input_price: TAFloat,
+    cycle: &mut DominantCycle,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(cycle
+        .update(input_price)
+        .map_or(TAFloat::NAN, |step| step.phase))
+}
+
+/// Streaming dominant cycle phase that owns the dominant cycle measurement.
+///
+/// Reads [`Bar::close`]. Outputs are `NaN` for the first [`lookback`] prices, after which every
+/// value matches [`ht_dcphase`] for the same series.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::ht_dcphase::HtDcphase};
+///
+/// let mut dcphase = HtDcphase::new();
+/// for i in 0..80 {
+///     let value = dcphase
+///         .update(&Bar::from(100.0 + (i as f64 * 0.3).sin()))
+///         .unwrap();
+/// }
+/// assert!(dcphase.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtDcphase {
+    cycle: DominantCycle,
+}
+
+impl HtDcphase {
+    /// Creates a streaming dominant cycle phase.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cycle: DominantCycle::new(),
+        }
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the dominant cycle phase for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        let dcphase = ht_dcphase_inc(input_price, &mut self.cycle)?;
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(dcphase)
+    }
+}
+
+impl Default for HtDcphase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtDcphase {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        63
+    }
+
+    fn is_ready(&self) -> bool {
+        self.cycle.count() > 63
+    }
+
+    fn reset(&mut self) {
+        self.cycle.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 100] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3, 35154.0, 35216.3, 35211.8, 35158.4,
+        35172.0, 35176.7, 35113.3, 35114.7, 35129.3, 35094.6, 35114.4, 35094.5, 35116.0, 35105.4,
+        35050.7, 35031.3, 35008.1, 35021.4, 35048.4, 35080.1, 35043.6, 34962.7, 34970.1, 34980.1,
+        34930.6, 35000.0, 34998.0, 35024.7, 34982.1, 34972.3, 34971.6, 34953.0, 34937.0, 34964.3,
+        34975.1, 34995.1, 34989.0, 34942.9, 34895.2, 34830.4, 34925.1, 34888.6, 34910.3, 34917.6,
+        34940.0, 35005.4, 34980.1, 34966.8, 34976.1, 34948.6, 34969.3, 34996.5, 35004.0, 35011.0,
+        35059.2, 35036.1, 35062.3, 35067.7, 35087.9, 35076.7, 35041.6, 34993.3, 34974.5, 34990.2,
+    ];
+
+    #[test]
+    fn test_ht_dcphase_calculation() {
+        let mut output_dcphase = vec![0.0; INPUT_PRICES.len()];
+        ht_dcphase(&INPUT_PRICES, &mut output_dcphase).unwrap();
+
+        // First 63 values should be NaN
+        for value in output_dcphase.iter().take(63) {
+            assert!(value.is_nan());
+        }
+
+        // TA-Lib HT_DCPHASE
+        let expected_values = [
+            11.418_992_980_474_4,
+            13.858_906_040_048_51,
+            15.788_372_511_957_244,
+            17.412_069_497_932_883,
+            18.350_591_317_673_228,
+            18.634_798_649_917_542,
+            19.229_454_692_915_88,
+            19.957_458_362_864_493,
+            22.614_327_754_829_077,
+            26.431_516_535_624_255,
+            29.405_964_033_073_27,
+            29.182_052_457_238_527,
+            26.847_440_587_820_266,
+            22.990_822_384_151_805,
+            17.553_839_975_942_978,
+            11.200_225_774_915_396,
+            5.109_208_462_165_725,
+            2.086_649_714_364_625_7,
+            6.914_648_332_897_173,
+            12.761_836_739_723_774,
+            17.977_166_621_217_325,
+            26.574_740_305_828_307,
+            28.328_027_779_084_312,
+            38.526_064_089_257_375,
+            58.487_589_936_572_26,
+            79.415_427_881_447_85,
+            100.226_905_829_175_89,
+            127.030_345_359_207_4,
+            144.148_761_693_321_94,
+            154.292_960_690_412_4,
+            173.542_792_081_516_1,
+            181.574_326_982_349_76,
+            186.188_033_522_051_65,
+            186.245_914_181_494_53,
+            189.810_761_560_792,
+            197.378_698_765_424_02,
+            207.355_245_388_763_88,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_dcphase[i + 63], expected, epsilon = 0.00001);
+        }
+
+        // Incremental calculation should match the batch output
+        let mut cycle = DominantCycle::new();
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let dcphase = ht_dcphase_inc(price, &mut cycle).unwrap();
+            if i >= 63 {
+                assert_relative_eq!(dcphase, output_dcphase[i], epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ht_dcphase_indicator() {
+        let mut output_dcphase = vec![0.0; INPUT_PRICES.len()];
+        ht_dcphase(&INPUT_PRICES, &mut output_dcphase).unwrap();
+
+        let mut indicator = HtDcphase::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < 63 {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_dcphase[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
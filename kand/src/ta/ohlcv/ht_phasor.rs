@@ -0,0 +1,354 @@
+use crate::{KandError, TAFloat, hilbert::HilbertTransform, indicator::Indicator, types::Bar};
+
+/// Bars that only prime the Hilbert transform's price smoother, as in TA-Lib.
+const SMOOTHER_WARMUP: usize = 9;
+
+/// Returns the lookback period required for Hilbert Transform Phasor Components calculation.
+///
+/// # Description
+/// `HT_PHASOR` needs 32 bars to prime the price smoother and the Hilbert transform, matching
+/// TA-Lib. It takes no parameters.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (always 32)
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_phasor;
+/// let lookback = ht_phasor::lookback().unwrap();
+/// assert_eq!(lookback, 32);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(32)
+}
+
+/// Calculates the Hilbert Transform Phasor Components (`HT_PHASOR`).
+///
+/// # Description
+/// Splits the detrended price into its in-phase and quadrature components, the two legs of
+/// John Ehlers' phasor. Their ratio gives the instantaneous phase used by MAMA and the other
+/// `HT_*` indicators. The calculation matches TA-Lib's `HT_PHASOR`, including its zero-seeded
+/// start.
+///
+/// # Mathematical Formula
+/// ```text
+/// Smooth = (4 * Price + 3 * Price[1] + 2 * Price[2] + Price[3]) / 10
+/// Detrender = HT(Smooth) * (0.075 * Period(previous) + 0.54)
+/// InPhase = Detrender[3]
+/// Quadrature = HT(Detrender) * (0.075 * Period(previous) + 0.54)
+/// ```
+/// where `HT(x) = 0.0962 * x + 0.5769 * x[2] - 0.5769 * x[4] - 0.0962 * x[6]`.
+///
+/// # Calculation Steps
+/// 1. Smooth prices with a 4-bar WMA and run the Hilbert transform ([`HilbertTransform`])
+/// 2. Output its in-phase and quadrature components
+/// 3. Write NaN before the lookback period
+///
+/// # Arguments
+/// * `input_prices` - Array of price values
+/// * `output_in_phase` - Array to store the in-phase component. Must match input length
+/// * `output_quadrature` - Array to store the quadrature component. Must match input length
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output lengths don't match input
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input price is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_phasor;
+/// let prices: Vec<f64> = (0..40).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_in_phase = vec![0.0; prices.len()];
+/// let mut output_quadrature = vec![0.0; prices.len()];
+///
+/// ht_phasor::ht_phasor(&prices, &mut output_in_phase, &mut output_quadrature).unwrap();
+/// assert!(output_in_phase[31].is_nan());
+/// assert!(!output_quadrature[32].is_nan());
+/// ```
+pub fn ht_phasor(
+    input_prices: &[TAFloat],
+    output_in_phase: &mut [TAFloat],
+    output_quadrature: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_in_phase.len() != len || output_quadrature.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut hilbert = HilbertTransform::new(SMOOTHER_WARMUP);
+    for i in 0..len {
+        let (in_phase, quadrature) = phasor_step(input_prices[i], &mut hilbert);
+        if i >= lookback {
+            output_in_phase[i] = in_phase;
+            output_quadrature[i] = quadrature;
+        } else {
+            output_in_phase[i] = TAFloat::NAN;
+            output_quadrature[i] = TAFloat::NAN;
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculates the next phasor components incrementally.
+///
+/// # Description
+/// All state lives in the [`HilbertTransform`], which this function advances in place. Start a
+/// series with `HilbertTransform::new(9)`; folding this function over the prices then
+/// reproduces [`ht_phasor`], whose values are valid from index [`lookback`] onwards. While the
+/// transform is still priming, both components are NaN.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `hilbert` - Hilbert transform state, updated in place
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (in-phase, quadrature) on success
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::{hilbert::HilbertTransform, ohlcv::ht_phasor};
+///
+/// let mut hilbert = HilbertTransform::new(9);
+/// for i in 0..40 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     let (in_phase, quadrature) = ht_phasor::ht_phasor_inc(price, &mut hilbert).unwrap();
+/// }
+/// ```
+pub fn ht_phasor_inc(
+    input_price: TAFloat,
+    hilbert: &mut HilbertTransform,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(phasor_step(input_price, hilbert))
+}
+
+/// Advances the transform and returns its in-phase and quadrature components.
+fn phasor_step(price: TAFloat, hilbert: &mut HilbertTransform) -> (TAFloat, TAFloat) {
+    hilbert
+        .update(price)
+        .map_or((TAFloat::NAN, TAFloat::NAN), |step| {
+            (step.in_phase, step.quadrature)
+        })
+}
+
+/// Streaming phasor components that own the Hilbert transform.
+///
+/// Reads [`Bar::close`] and returns `(in_phase, quadrature)`. Both are `NaN` for the first
+/// [`lookback`] prices, after which every pair matches [`ht_phasor`] for the same series.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::ht_phasor::HtPhasor};
+///
+/// let mut phasor = HtPhasor::new();
+/// for i in 0..40 {
+///     let (in_phase, quadrature) = phasor
+///         .update(&Bar::from(100.0 + (i as f64 * 0.3).sin()))
+///         .unwrap();
+/// }
+/// assert!(phasor.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtPhasor {
+    hilbert: HilbertTransform,
+}
+
+impl HtPhasor {
+    /// Creates streaming phasor components.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            hilbert: HilbertTransform::new(SMOOTHER_WARMUP),
+        }
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns `(in_phase, quadrature)` for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<(TAFloat, TAFloat), KandError> {
+        let output = ht_phasor_inc(input_price, &mut self.hilbert)?;
+        if !self.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN));
+        }
+        Ok(output)
+    }
+}
+
+impl Default for HtPhasor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtPhasor {
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<(TAFloat, TAFloat), KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        32
+    }
+
+    fn is_ready(&self) -> bool {
+        self.hilbert.count() > 32
+    }
+
+    fn reset(&mut self) {
+        self.hilbert.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 60] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3, 35154.0, 35216.3, 35211.8, 35158.4,
+        35172.0, 35176.7, 35113.3, 35114.7, 35129.3, 35094.6, 35114.4, 35094.5, 35116.0, 35105.4,
+        35050.7, 35031.3, 35008.1, 35021.4, 35048.4, 35080.1, 35043.6, 34962.7, 34970.1, 34980.1,
+    ];
+
+    #[test]
+    fn test_ht_phasor_calculation() {
+        let mut output_in_phase = vec![0.0; INPUT_PRICES.len()];
+        let mut output_quadrature = vec![0.0; INPUT_PRICES.len()];
+        ht_phasor(&INPUT_PRICES, &mut output_in_phase, &mut output_quadrature).unwrap();
+
+        // First 32 values should be NaN
+        for i in 0..32 {
+            assert!(output_in_phase[i].is_nan());
+            assert!(output_quadrature[i].is_nan());
+        }
+
+        // TA-Lib HT_PHASOR
+        let expected_values = [
+            (31.058_591_412_868_95, -63.015_445_922_528_89),
+            (4.391_009_078_599_382, -30.055_778_437_693_007),
+            (14.445_834_471_174_924, 9.884_392_489_804_002),
+            (18.630_461_438_885_07, -5.726_084_862_008_079),
+            (12.148_546_964_750_92, 16.081_164_377_303_12),
+            (22.729_816_824_088_292, 27.542_107_586_055_383),
+            (17.944_892_904_290_736, 53.925_691_992_275_61),
+            (51.484_616_896_615_11, 126.803_803_389_543_35),
+            (97.554_732_224_219_63, 35.609_045_935_887_55),
+            (69.657_130_446_171_76, -85.341_594_683_689_11),
+            (46.904_027_921_512_81, -47.213_079_613_912_28),
+            (54.662_483_501_376_17, -124.711_971_011_245_88),
+            (-10.521_496_569_729_466, -187.843_691_481_089_9),
+            (-40.030_161_187_831_33, -70.988_101_166_113_72),
+            (-32.310_776_230_808_01, -78.349_662_821_806_73),
+            (-70.522_546_848_747_11, -102.805_663_347_049_5),
+            (-88.339_427_148_109_8, 27.876_971_065_239_484),
+            (-54.115_388_859_827_93, 59.195_796_690_462),
+            (-54.198_755_008_001_04, 44.000_329_422_051_3),
+            (-35.627_192_708_061_77, 79.739_863_874_723_77),
+            (-18.017_998_499_390_675, 26.734_584_767_871_21),
+            (-18.404_776_320_895_476, 4.675_467_803_257_312),
+            (-8.190_931_739_033_323, -76.757_241_151_925_8),
+            (-56.559_704_167_600_72, -151.526_005_611_154_94),
+            (-95.335_909_844_707_06, -67.966_252_372_345_55),
+            (-100.366_349_096_240_6, 45.691_278_041_914_32),
+            (-74.351_106_956_812_02, 167.208_482_018_683_58),
+            (-12.677_128_769_415_113, 192.079_614_313_830_06),
+        ];
+        for (i, &(expected_in_phase, expected_quadrature)) in expected_values.iter().enumerate() {
+            assert_relative_eq!(
+                output_in_phase[i + 32],
+                expected_in_phase,
+                epsilon = 0.00001
+            );
+            assert_relative_eq!(
+                output_quadrature[i + 32],
+                expected_quadrature,
+                epsilon = 0.00001
+            );
+        }
+
+        // Incremental calculation should match the batch output
+        let mut hilbert = HilbertTransform::new(9);
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let (in_phase, quadrature) = ht_phasor_inc(price, &mut hilbert).unwrap();
+            if i >= 32 {
+                assert_relative_eq!(in_phase, output_in_phase[i], epsilon = 0.00001);
+                assert_relative_eq!(quadrature, output_quadrature[i], epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ht_phasor_indicator() {
+        let mut output_in_phase = vec![0.0; INPUT_PRICES.len()];
+        let mut output_quadrature = vec![0.0; INPUT_PRICES.len()];
+        ht_phasor(&INPUT_PRICES, &mut output_in_phase, &mut output_quadrature).unwrap();
+
+        let mut indicator = HtPhasor::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let (in_phase, quadrature) = indicator.update(&Bar::from(price)).unwrap();
+            if i < 32 {
+                assert!(in_phase.is_nan() && quadrature.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(in_phase, output_in_phase[i], epsilon = 0.00001);
+                assert_relative_eq!(quadrature, output_quadrature[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
@@ -0,0 +1,356 @@
+use crate::{KandError, TAFloat, hilbert::DominantCycle, indicator::Indicator, types::Bar};
+
+/// Returns the lookback period required for Hilbert Transform `SineWave` calculation.
+///
+/// # Description
+/// `HT_SINE` needs 63 bars to prime the price smoother and let the dominant cycle settle,
+/// matching TA-Lib. It takes no parameters.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (always 63)
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_sine;
+/// let lookback = ht_sine::lookback().unwrap();
+/// assert_eq!(lookback, 63);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(63)
+}
+
+/// Calculates the Hilbert Transform `SineWave` (`HT_SINE`).
+///
+/// # Description
+/// John Ehlers' `SineWave` indicator plots the sine of the dominant cycle phase together with a
+/// lead sine advanced by 45 degrees. While the market cycles, the two lines cross ahead of
+/// cycle turning points; in a trend the phase stalls and the lines run apart without crossing.
+/// The calculation matches TA-Lib's `HT_SINE`, including its zero-seeded start.
+///
+/// # Mathematical Formula
+/// ```text
+/// Sine = sin(Phase)
+/// LeadSine = sin(Phase + 45)
+/// ```
+/// where `Phase` is the dominant cycle phase in degrees (see [`crate::ohlcv::ht_dcphase`]).
+///
+/// # Calculation Steps
+/// 1. Smooth prices with a 4-bar WMA and measure the dominant cycle ([`DominantCycle`])
+/// 2. Output the sine and lead sine of its phase
+/// 3. Write NaN before the lookback period
+///
+/// # Arguments
+/// * `input_prices` - Array of price values
+/// * `output_sine` - Array to store the sine. Must match input length
+/// * `output_lead_sine` - Array to store the lead sine. Must match input length
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output lengths don't match input
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input price is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_sine;
+/// let prices: Vec<f64> = (0..80).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_sine = vec![0.0; prices.len()];
+/// let mut output_lead_sine = vec![0.0; prices.len()];
+///
+/// ht_sine::ht_sine(&prices, &mut output_sine, &mut output_lead_sine).unwrap();
+/// assert!(output_sine[62].is_nan());
+/// assert!(!output_lead_sine[63].is_nan());
+/// ```
+pub fn ht_sine(
+    input_prices: &[TAFloat],
+    output_sine: &mut [TAFloat],
+    output_lead_sine: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_sine.len() != len || output_lead_sine.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut cycle = DominantCycle::new();
+    for i in 0..len {
+        let step = cycle.update(input_prices[i]);
+        match step {
+            Some(step) if i >= lookback => {
+                output_sine[i] = step.sine;
+                output_lead_sine[i] = step.lead_sine;
+            }
+            _ => {
+                output_sine[i] = TAFloat::NAN;
+                output_lead_sine[i] = TAFloat::NAN;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculates the next sine and lead sine incrementally.
+///
+/// # Description
+/// All state lives in the [`DominantCycle`], which this function advances in place. Start a
+/// series with `DominantCycle::new()`; folding this function over the prices then reproduces
+/// [`ht_sine`], whose values are valid from index [`lookback`] onwards. While the transform is
+/// still priming, both lines are NaN.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `cycle` - Dominant cycle state, updated in place
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (sine, lead sine) on success
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::{hilbert::DominantCycle, ohlcv::ht_sine};
+///
+/// let mut cycle = DominantCycle::new();
+/// for i in 0..80 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     let (sine, lead_sine) = ht_sine::ht_sine_inc(price, &mut cycle).unwrap();
+/// }
+/// ```
+pub fn ht_sine_inc(
+    input_price: TAFloat,
+    cycle: &mut DominantCycle,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(cycle
+        .update(input_price)
+        .map_or((TAFloat::NAN, TAFloat::NAN), |step| {
+            (step.sine, step.lead_sine)
+        }))
+}
+
+/// Streaming `SineWave` that owns the dominant cycle measurement.
+///
+/// Reads [`Bar::close`] and returns `(sine, lead_sine)`. Both are `NaN` for the first
+/// [`lookback`] prices, after which every pair matches [`ht_sine`] for the same series.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::ht_sine::HtSine};
+///
+/// let mut sine = HtSine::new();
+/// for i in 0..80 {
+///     let (sine_value, lead_sine_value) = sine
+///         .update(&Bar::from(100.0 + (i as f64 * 0.3).sin()))
+///         .unwrap();
+/// }
+/// assert!(sine.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtSine {
+    cycle: DominantCycle,
+}
+
+impl HtSine {
+    /// Creates a streaming `SineWave`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cycle: DominantCycle::new(),
+        }
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns `(sine, lead_sine)` for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<(TAFloat, TAFloat), KandError> {
+        let output = ht_sine_inc(input_price, &mut self.cycle)?;
+        if !self.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN));
+        }
+        Ok(output)
+    }
+}
+
+impl Default for HtSine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtSine {
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<(TAFloat, TAFloat), KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        63
+    }
+
+    fn is_ready(&self) -> bool {
+        self.cycle.count() > 63
+    }
+
+    fn reset(&mut self) {
+        self.cycle.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 100] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3, 35154.0, 35216.3, 35211.8, 35158.4,
+        35172.0, 35176.7, 35113.3, 35114.7, 35129.3, 35094.6, 35114.4, 35094.5, 35116.0, 35105.4,
+        35050.7, 35031.3, 35008.1, 35021.4, 35048.4, 35080.1, 35043.6, 34962.7, 34970.1, 34980.1,
+        34930.6, 35000.0, 34998.0, 35024.7, 34982.1, 34972.3, 34971.6, 34953.0, 34937.0, 34964.3,
+        34975.1, 34995.1, 34989.0, 34942.9, 34895.2, 34830.4, 34925.1, 34888.6, 34910.3, 34917.6,
+        34940.0, 35005.4, 34980.1, 34966.8, 34976.1, 34948.6, 34969.3, 34996.5, 35004.0, 35011.0,
+        35059.2, 35036.1, 35062.3, 35067.7, 35087.9, 35076.7, 35041.6, 34993.3, 34974.5, 34990.2,
+    ];
+
+    #[test]
+    fn test_ht_sine_calculation() {
+        let mut output_sine = vec![0.0; INPUT_PRICES.len()];
+        let mut output_lead_sine = vec![0.0; INPUT_PRICES.len()];
+        ht_sine(&INPUT_PRICES, &mut output_sine, &mut output_lead_sine).unwrap();
+
+        // First 63 values should be NaN
+        for i in 0..63 {
+            assert!(output_sine[i].is_nan());
+            assert!(output_lead_sine[i].is_nan());
+        }
+
+        // TA-Lib HT_SINE
+        let expected_values = [
+            (0.197_982_279_648_174_08, 0.833_104_638_732_727_6),
+            (0.239_531_758_713_763_67, 0.855_896_393_828_131),
+            (0.272_084_970_888_006_26, 0.872_823_054_056_206_6),
+            (0.299_241_798_709_513_56, 0.886_301_154_025_929_1),
+            (0.314_830_661_972_727_47, 0.893_767_781_977_955_9),
+            (0.319_534_878_541_629_76, 0.895_981_644_647_827_8),
+            (0.329_352_089_560_485_2, 0.900_542_396_624_877_8),
+            (0.341_322_335_200_507_86, 0.905_993_746_719_475_2),
+            (0.384_526_174_586_015_96, 0.924_641_297_637_699),
+            (0.445_127_813_655_934_5, 0.947_943_715_706_686),
+            (0.490_994_437_355_542_66, 0.963_190_553_985_289_2),
+            (0.487_586_197_855_045_1, 0.962_132_656_247_698_7),
+            (0.451_616_442_159_517_96, 0.950_230_337_437_776),
+            (0.390_583_677_365_167_97, 0.927_123_838_332_882),
+            (0.301_601_859_978_87, 0.887_444_339_671_822_1),
+            (0.194_238_216_687_597_78, 0.830_986_661_359_451_1),
+            (0.089_054_377_429_522_41, 0.767_268_234_398_333),
+            (0.036_410_857_762_089_13, 0.732_384_266_556_609),
+            (0.120_390_644_874_788_6, 0.787_092_748_644_602),
+            (0.220_898_926_359_324_95, 0.845_838_043_121_587_7),
+            (0.308_637_957_048_974_1, 0.890_825_530_217_838_3),
+            (0.447_364_843_181_090_27, 0.948_736_760_876_944),
+            (0.474_518_861_600_837_06, 0.957_962_950_403_834),
+            (0.622_870_583_931_652_1, 0.993_623_249_485_484_1),
+            (0.852_526_973_011_352, 0.972_420_461_029_480_1),
+            (0.982_984_845_603_782_3, 0.824_961_341_212_402),
+            (0.984_112_341_292_001_5, 0.570_327_896_956_761),
+            (0.798_316_660_909_151_1, 0.138_648_609_322_417_27),
+            (0.585_682_756_830_286_1, -0.158_998_350_468_071_62),
+            (0.433_769_786_852_696_96, -0.330_398_435_655_851_4),
+            (0.112_461_120_489_389_67, -0.623_098_963_816_513_4),
+            (-0.027_473_731_947_801_693, -0.726_266_728_772_802_6),
+            (-0.107_791_720_509_135_56, -0.779_207_078_946_83),
+            (-0.108_795_987_438_036_37, -0.779_839_845_890_277_2),
+            (-0.170_394_580_074_454_04, -0.817_253_152_129_512_2),
+            (-0.298_686_007_298_428_65, -0.886_031_275_329_675_7),
+            (-0.459_506_158_260_956_03, -0.952_954_191_272_124_9),
+        ];
+        for (i, &(expected_sine, expected_lead_sine)) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_sine[i + 63], expected_sine, epsilon = 0.00001);
+            assert_relative_eq!(
+                output_lead_sine[i + 63],
+                expected_lead_sine,
+                epsilon = 0.00001
+            );
+        }
+
+        // Incremental calculation should match the batch output
+        let mut cycle = DominantCycle::new();
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let (sine, lead_sine) = ht_sine_inc(price, &mut cycle).unwrap();
+            if i >= 63 {
+                assert_relative_eq!(sine, output_sine[i], epsilon = 0.00001);
+                assert_relative_eq!(lead_sine, output_lead_sine[i], epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ht_sine_indicator() {
+        let mut output_sine = vec![0.0; INPUT_PRICES.len()];
+        let mut output_lead_sine = vec![0.0; INPUT_PRICES.len()];
+        ht_sine(&INPUT_PRICES, &mut output_sine, &mut output_lead_sine).unwrap();
+
+        let mut indicator = HtSine::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let (sine, lead_sine) = indicator.update(&Bar::from(price)).unwrap();
+            if i < 63 {
+                assert!(sine.is_nan() && lead_sine.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(sine, output_sine[i], epsilon = 0.00001);
+                assert_relative_eq!(lead_sine, output_lead_sine[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
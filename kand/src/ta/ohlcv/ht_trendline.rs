@@ -0,0 +1,335 @@
+use crate::{KandError, TAFloat, hilbert::DominantCycle, indicator::Indicator, types::Bar};
+
+/// Returns the lookback period required for Hilbert Transform Instantaneous Trendline calculation.
+///
+/// # Description
+/// `HT_TRENDLINE` needs 63 bars to prime the price smoother and let the dominant cycle settle,
+/// matching TA-Lib. It takes no parameters.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (always 63)
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_trendline;
+/// let lookback = ht_trendline::lookback().unwrap();
+/// assert_eq!(lookback, 63);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(63)
+}
+
+/// Calculates the Hilbert Transform Instantaneous Trendline (`HT_TRENDLINE`).
+///
+/// # Description
+/// John Ehlers' instantaneous trendline removes the dominant cycle from the price by averaging
+/// it over exactly one measured cycle period, then smooths those averages with a 4-bar WMA.
+/// Because the window follows the cycle, the line lags less than a fixed-period average. The
+/// calculation matches TA-Lib's `HT_TRENDLINE`, including its zero-seeded start.
+///
+/// # Mathematical Formula
+/// ```text
+/// N = int(SmoothPeriod + 0.5)
+/// ITrend = Sum(Price[i]) / N for i in 0..N
+/// Trendline = (4 * ITrend + 3 * ITrend[1] + 2 * ITrend[2] + ITrend[3]) / 10
+/// ```
+///
+/// # Calculation Steps
+/// 1. Smooth prices with a 4-bar WMA and measure the dominant cycle ([`DominantCycle`])
+/// 2. Output its trendline
+/// 3. Write NaN before the lookback period
+///
+/// # Arguments
+/// * `input_prices` - Array of price values
+/// * `output_trendline` - Array to store the trendline values. Must match input length
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output length doesn't match input
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input price is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_trendline;
+/// let prices: Vec<f64> = (0..80).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_trendline = vec![0.0; prices.len()];
+///
+/// ht_trendline::ht_trendline(&prices, &mut output_trendline).unwrap();
+/// assert!(output_trendline[62].is_nan());
+/// assert!(!output_trendline[63].is_nan());
+/// ```
+pub fn ht_trendline(
+    input_prices: &[TAFloat],
+    output_trendline: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_trendline.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut cycle = DominantCycle::new();
+    for i in 0..len {
+        let step = cycle.update(input_prices[i]);
+        output_trendline[i] = match step {
+            Some(step) if i >= lookback => step.trendline,
+            _ => TAFloat::NAN,
+        };
+    }
+
+    Ok(())
+}
+
+/// Calculates the next instantaneous trendline value incrementally.
+///
+/// # Description
+/// All state lives in the [`DominantCycle`], which this function advances in place. Start a
+/// series with `DominantCycle::new()`; folding this function over the prices then reproduces
+/// [`ht_trendline`], whose values are valid from index [`lookback`] onwards. While the transform
+/// is still priming, the trendline is NaN.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `cycle` - Dominant cycle state, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The trendline value on success
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::{hilbert::DominantCycle, ohlcv::ht_trendline};
+///
+/// let mut cycle = DominantCycle::new();
+/// for i in 0..80 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     let trendline = ht_trendline::ht_trendline_inc(price, &mut cycle).unwrap();
+/// }
+/// ```
+pub fn ht_trendline_inc(
+    input_price: TAFloat,
+    cycle: &mut DominantCycle,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(cycle
+        .update(input_price)
+        .map_or(TAFloat::NAN, |step| step.trendline))
+}
+
+/// Streaming instantaneous trendline that owns the dominant cycle measurement.
+///
+/// Reads [`Bar::close`]. Outputs are `NaN` for the first [`lookback`] prices, after which every
+/// value matches [`ht_trendline`] for the same series.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::ht_trendline::HtTrendline};
+///
+/// let mut trendline = HtTrendline::new();
+/// for i in 0..80 {
+///     let value = trendline
+///         .update(&Bar::from(100.0 + (i as f64 * 0.3).sin()))
+///         .unwrap();
+/// }
+/// assert!(trendline.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtTrendline {
+    cycle: DominantCycle,
+}
+
+impl HtTrendline {
+    /// Creates a streaming instantaneous trendline.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cycle: DominantCycle::new(),
+        }
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the trendline value for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        let trendline = ht_trendline_inc(input_price, &mut self.cycle)?;
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(trendline)
+    }
+}
+
+impl Default for HtTrendline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtTrendline {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        63
+    }
+
+    fn is_ready(&self) -> bool {
+        self.cycle.count() > 63
+    }
+
+    fn reset(&mut self) {
+        self.cycle.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 100] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3, 35154.0, 35216.3, 35211.8, 35158.4,
+        35172.0, 35176.7, 35113.3, 35114.7, 35129.3, 35094.6, 35114.4, 35094.5, 35116.0, 35105.4,
+        35050.7, 35031.3, 35008.1, 35021.4, 35048.4, 35080.1, 35043.6, 34962.7, 34970.1, 34980.1,
+        34930.6, 35000.0, 34998.0, 35024.7, 34982.1, 34972.3, 34971.6, 34953.0, 34937.0, 34964.3,
+        34975.1, 34995.1, 34989.0, 34942.9, 34895.2, 34830.4, 34925.1, 34888.6, 34910.3, 34917.6,
+        34940.0, 35005.4, 34980.1, 34966.8, 34976.1, 34948.6, 34969.3, 34996.5, 35004.0, 35011.0,
+        35059.2, 35036.1, 35062.3, 35067.7, 35087.9, 35076.7, 35041.6, 34993.3, 34974.5, 34990.2,
+    ];
+
+    #[test]
+    fn test_ht_trendline_calculation() {
+        let mut output_trendline = vec![0.0; INPUT_PRICES.len()];
+        ht_trendline(&INPUT_PRICES, &mut output_trendline).unwrap();
+
+        // First 63 values should be NaN
+        for value in output_trendline.iter().take(63) {
+            assert!(value.is_nan());
+        }
+
+        // TA-Lib HT_TRENDLINE
+        let expected_values = [
+            35_061.124_677_733_86,
+            35_059.836_308_543_636,
+            35_056.300_759_866_215,
+            35_052.621_456_980_06,
+            35_048.635_372_405_37,
+            35_042.949_616_402_11,
+            35_038.457_794_654_26,
+            35_032.909_938_423_65,
+            35_027.856_194_581_28,
+            35_023.26,
+            35_017.878_275_862_065,
+            35_013.114,
+            35_006.701_229_885_06,
+            35_000.111_712_643_67,
+            34_993.321_666_666_67,
+            34_986.377_333_333_33,
+            34_979.812_666_666_66,
+            34_974.400_999_999_99,
+            34_971.817_967_741_925,
+            34_970.916_725_806_45,
+            34_969.555_861_559_13,
+            34_967.068_223_118_27,
+            34_962.265_963_987_76,
+            34_958.808_294_083_37,
+            34_957.472_803_411_79,
+            34_956.596_980_969_22,
+            34_955.827_548_270_245,
+            34_956.471_156_051_026,
+            34_958.629_446_214_654,
+            34_961.940_093_280_63,
+            34_965.223_948_804_814,
+            34_969.906_147_186_14,
+            34_976.302_424_242_414,
+            34_985.909_809_523_81,
+            34_994.471_285_714_28,
+            35_000.889_023_809_52,
+            35_005.700_499_999_99,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_trendline[i + 63], expected, epsilon = 0.00001);
+        }
+
+        // Incremental calculation should match the batch output
+        let mut cycle = DominantCycle::new();
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let trendline = ht_trendline_inc(price, &mut cycle).unwrap();
+            if i >= 63 {
+                assert_relative_eq!(trendline, output_trendline[i], epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ht_trendline_indicator() {
+        let mut output_trendline = vec![0.0; INPUT_PRICES.len()];
+        ht_trendline(&INPUT_PRICES, &mut output_trendline).unwrap();
+
+        let mut indicator = HtTrendline::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < 63 {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_trendline[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
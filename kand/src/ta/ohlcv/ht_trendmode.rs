@@ -0,0 +1,358 @@
+use crate::{
+    KandError, TAFloat, TAInt,
+    hilbert::{CycleStep, DominantCycle},
+    indicator::Indicator,
+    types::Bar,
+};
+
+/// Output for bars where the market is trending.
+const TREND_MODE: TAInt = 1;
+
+/// Output for bars where the market is cycling (and before the lookback period).
+const CYCLE_MODE: TAInt = 0;
+
+/// Returns the lookback period required for Hilbert Transform Trend vs Cycle Mode calculation.
+///
+/// # Description
+/// `HT_TRENDMODE` needs 63 bars to prime the price smoother and let the dominant cycle settle,
+/// matching TA-Lib. It takes no parameters.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (always 63)
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_trendmode;
+/// let lookback = ht_trendmode::lookback().unwrap();
+/// assert_eq!(lookback, 63);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(63)
+}
+
+/// Calculates the Hilbert Transform Trend vs Cycle Mode (`HT_TRENDMODE`).
+///
+/// # Description
+/// John Ehlers' trend mode classifies each bar as trending (1) or cycling (0). The market is
+/// assumed to trend unless the `SineWave` lines crossed less than half a cycle ago, or the phase
+/// advances at roughly the rate the dominant cycle implies. A smoothed price more than 1.5%
+/// away from the instantaneous trendline always counts as a trend. The calculation matches
+/// TA-Lib's `HT_TRENDMODE`, including its zero-seeded start.
+///
+/// # Mathematical Formula
+/// ```text
+/// Trend = 1
+/// If Sine crossed LeadSine: DaysInTrend = 0, Trend = 0
+/// DaysInTrend = DaysInTrend + 1
+/// If DaysInTrend < 0.5 * SmoothPeriod: Trend = 0
+/// If 0.67 * 360 / SmoothPeriod < Phase - Phase(previous) < 1.5 * 360 / SmoothPeriod: Trend = 0
+/// If |Smooth - Trendline| / Trendline >= 0.015: Trend = 1
+/// ```
+///
+/// # Calculation Steps
+/// 1. Smooth prices with a 4-bar WMA and measure the dominant cycle ([`DominantCycle`])
+/// 2. Apply the rules above to its phase, `SineWave` and trendline
+/// 3. Write 0 before the lookback period
+///
+/// # Arguments
+/// * `input_prices` - Array of price values
+/// * `output_trendmode` - Array to store the mode: 1 for trend, 0 for cycle. Must match input
+///   length
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output length doesn't match input
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input price is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_trendmode;
+/// let prices: Vec<f64> = (0..80).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_trendmode = vec![0; prices.len()];
+///
+/// ht_trendmode::ht_trendmode(&prices, &mut output_trendmode).unwrap();
+/// assert!(output_trendmode[63..].iter().all(|&mode| mode == 0 || mode == 1));
+/// ```
+pub fn ht_trendmode(
+    input_prices: &[TAFloat],
+    output_trendmode: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_trendmode.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut cycle = DominantCycle::new();
+    let mut days_in_trend = 0;
+    for i in 0..len {
+        let mut trendmode = CYCLE_MODE;
+        if let Some(step) = cycle.update(input_prices[i]) {
+            (trendmode, days_in_trend) = trendmode_step(&step, days_in_trend);
+        }
+        output_trendmode[i] = if i >= lookback { trendmode } else { CYCLE_MODE };
+    }
+
+    Ok(())
+}
+
+/// Calculates the next trend vs cycle mode incrementally.
+///
+/// # Description
+/// The dominant cycle is carried in a [`DominantCycle`] that this function advances in place,
+/// while the bar count since the last `SineWave` crossing is passed in and returned like other
+/// `*_inc` functions. Start a series with `DominantCycle::new()` and `0` for
+/// `prev_days_in_trend`; folding this function over the prices then reproduces
+/// [`ht_trendmode`], whose values are valid from index [`lookback`] onwards. While the
+/// transform is still priming, the mode is 0 and `prev_days_in_trend` is returned unchanged.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `prev_days_in_trend` - Bars since the previous `SineWave` crossing
+/// * `cycle` - Dominant cycle state, updated in place
+///
+/// # Returns
+/// * `Result<(TAInt, usize), KandError>` - Tuple of (mode, days in trend) on success
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::{hilbert::DominantCycle, ohlcv::ht_trendmode};
+///
+/// let mut cycle = DominantCycle::new();
+/// let mut days_in_trend = 0;
+/// for i in 0..80 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     let (trendmode, days) =
+///         ht_trendmode::ht_trendmode_inc(price, days_in_trend, &mut cycle).unwrap();
+///     days_in_trend = days;
+/// }
+/// ```
+pub fn ht_trendmode_inc(
+    input_price: TAFloat,
+    prev_days_in_trend: usize,
+    cycle: &mut DominantCycle,
+) -> Result<(TAInt, usize), KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(cycle
+        .update(input_price)
+        .map_or((CYCLE_MODE, prev_days_in_trend), |step| {
+            trendmode_step(&step, prev_days_in_trend)
+        }))
+}
+
+/// Classifies one dominant cycle step and advances the days-in-trend counter.
+fn trendmode_step(step: &CycleStep, prev_days_in_trend: usize) -> (TAInt, usize) {
+    let mut trendmode = TREND_MODE;
+    let mut days_in_trend = prev_days_in_trend;
+
+    // Measure days in trend from the last crossing of the SineWave lines
+    let crossed_up = step.sine > step.lead_sine && step.prev_sine <= step.prev_lead_sine;
+    let crossed_down = step.sine < step.lead_sine && step.prev_sine >= step.prev_lead_sine;
+    if crossed_up || crossed_down {
+        days_in_trend = 0;
+        trendmode = CYCLE_MODE;
+    }
+    days_in_trend += 1;
+    if (days_in_trend as TAFloat) < 0.5 * step.period {
+        trendmode = CYCLE_MODE;
+    }
+
+    // A phase advancing at the cycle's own rate means the market is cycling
+    let delta_phase = step.phase - step.prev_phase;
+    if step.period != 0.0
+        && delta_phase > 0.67 * 360.0 / step.period
+        && delta_phase < 1.5 * 360.0 / step.period
+    {
+        trendmode = CYCLE_MODE;
+    }
+
+    // Prices far enough from the trendline are a trend regardless
+    if step.trendline != 0.0 && ((step.smoothed - step.trendline) / step.trendline).abs() >= 0.015 {
+        trendmode = TREND_MODE;
+    }
+
+    (trendmode, days_in_trend)
+}
+
+/// Streaming trend vs cycle mode that owns the dominant cycle measurement.
+///
+/// Reads [`Bar::close`]. Outputs are 0 for the first [`lookback`] prices, after which every
+/// value matches [`ht_trendmode`] for the same series.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::ht_trendmode::HtTrendmode};
+///
+/// let mut trendmode = HtTrendmode::new();
+/// for i in 0..80 {
+///     let mode = trendmode
+///         .update(&Bar::from(100.0 + (i as f64 * 0.3).sin()))
+///         .unwrap();
+/// }
+/// assert!(trendmode.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HtTrendmode {
+    cycle: DominantCycle,
+    days_in_trend: usize,
+}
+
+impl HtTrendmode {
+    /// Creates a streaming trend vs cycle mode.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cycle: DominantCycle::new(),
+            days_in_trend: 0,
+        }
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the mode for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAInt, KandError> {
+        let trendmode;
+        (trendmode, self.days_in_trend) =
+            ht_trendmode_inc(input_price, self.days_in_trend, &mut self.cycle)?;
+        if !self.is_ready() {
+            return Ok(CYCLE_MODE);
+        }
+        Ok(trendmode)
+    }
+}
+
+impl Default for HtTrendmode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtTrendmode {
+    type Output = TAInt;
+
+    fn update(&mut self, input: &Bar) -> Result<TAInt, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        63
+    }
+
+    fn is_ready(&self) -> bool {
+        self.cycle.count() > 63
+    }
+
+    fn reset(&mut self) {
+        self.cycle.reset();
+        self.days_in_trend = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 100] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3, 35154.0, 35216.3, 35211.8, 35158.4,
+        35172.0, 35176.7, 35113.3, 35114.7, 35129.3, 35094.6, 35114.4, 35094.5, 35116.0, 35105.4,
+        35050.7, 35031.3, 35008.1, 35021.4, 35048.4, 35080.1, 35043.6, 34962.7, 34970.1, 34980.1,
+        34930.6, 35000.0, 34998.0, 35024.7, 34982.1, 34972.3, 34971.6, 34953.0, 34937.0, 34964.3,
+        34975.1, 34995.1, 34989.0, 34942.9, 34895.2, 34830.4, 34925.1, 34888.6, 34910.3, 34917.6,
+        34940.0, 35005.4, 34980.1, 34966.8, 34976.1, 34948.6, 34969.3, 34996.5, 35004.0, 35011.0,
+        35059.2, 35036.1, 35062.3, 35067.7, 35087.9, 35076.7, 35041.6, 34993.3, 34974.5, 34990.2,
+    ];
+
+    #[test]
+    fn test_ht_trendmode_calculation() {
+        let mut output_trendmode = vec![0; INPUT_PRICES.len()];
+        ht_trendmode(&INPUT_PRICES, &mut output_trendmode).unwrap();
+
+        // First 63 values should be 0
+        for &value in output_trendmode.iter().take(63) {
+            assert_eq!(value, 0);
+        }
+
+        // TA-Lib HT_TRENDMODE
+        let expected_values = [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 1, 1,
+        ];
+        assert_eq!(output_trendmode[63..], expected_values);
+
+        // Incremental calculation should match the batch output
+        let mut cycle = DominantCycle::new();
+        let mut days_in_trend = 0;
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let trendmode;
+            (trendmode, days_in_trend) =
+                ht_trendmode_inc(price, days_in_trend, &mut cycle).unwrap();
+            if i >= 63 {
+                assert_eq!(trendmode, output_trendmode[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ht_trendmode_indicator() {
+        let mut output_trendmode = vec![0; INPUT_PRICES.len()];
+        ht_trendmode(&INPUT_PRICES, &mut output_trendmode).unwrap();
+
+        let mut indicator = HtTrendmode::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            assert_eq!(value, output_trendmode[i]);
+            assert_eq!(indicator.is_ready(), i >= 63);
+        }
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
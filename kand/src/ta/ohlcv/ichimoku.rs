@@ -0,0 +1,485 @@
+use std::collections::VecDeque;
+
+use super::donchian::{self, Donchian};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Returns the lookback period required for Ichimoku Kinko Hyo calculation.
+///
+/// # Description
+/// The lookback is the index of the first bar on which the conversion line, the base line and
+/// both cloud spans are all valid: the longest period minus one, plus the forward displacement of
+/// the cloud.
+///
+/// # Arguments
+/// * `opt_tenkan_period` - Period of the conversion line (Tenkan-sen, must be >= 2)
+/// * `opt_kijun_period` - Period of the base line (Kijun-sen, must be >= 2)
+/// * `opt_senkou_b_period` - Period of the leading span B (Senkou Span B, must be >= 2)
+/// * `opt_displacement` - Bars the cloud is shifted forward and the lagging span backward (must be >= 1)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If any period is less than 2 or the displacement is 0
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ichimoku;
+/// let lookback = ichimoku::lookback(9, 26, 52, 26).unwrap();
+/// assert_eq!(lookback, 77); // 52 - 1 + 26
+/// ```
+pub fn lookback(
+    opt_tenkan_period: usize,
+    opt_kijun_period: usize,
+    opt_senkou_b_period: usize,
+    opt_displacement: usize,
+) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_displacement < 1 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    let tenkan_lookback = donchian::lookback(opt_tenkan_period)?;
+    let kijun_lookback = donchian::lookback(opt_kijun_period)?;
+    let senkou_b_lookback = donchian::lookback(opt_senkou_b_period)?;
+    Ok(tenkan_lookback.max(kijun_lookback).max(senkou_b_lookback) + opt_displacement)
+}
+
+/// Calculates Ichimoku Kinko Hyo for a price series.
+///
+/// # Description
+/// Ichimoku combines three Donchian midlines into a trend system. The conversion and base lines
+/// are short and medium term equilibrium prices, the cloud (Kumo) between the two leading spans
+/// is projected `opt_displacement` bars forward as future support and resistance, and the lagging
+/// span plots each close `opt_displacement` bars back to compare it with past price.
+///
+/// Every output is aligned to the bar it is plotted on:
+/// - `output_tenkan`, `output_kijun` and `output_chikou` have the length of the input
+/// - `output_senkou_a` and `output_senkou_b` are `opt_displacement` values longer than the input,
+///   so the last `opt_displacement` values are the cloud projected beyond the latest bar
+/// - `output_chikou[i]` is `Close[i + displacement]`, so the last `opt_displacement` values are
+///   NaN. It looks ahead and is meant for charting and historical comparison, not for signals
+///
+/// # Mathematical Formula
+/// ```text
+/// Mid(n)[i]            = (max(High[i-n+1...i]) + min(Low[i-n+1...i])) / 2
+/// Tenkan[i]            = Mid(tenkan_period)[i]
+/// Kijun[i]             = Mid(kijun_period)[i]
+/// Senkou A[i + d]      = (Tenkan[i] + Kijun[i]) / 2
+/// Senkou B[i + d]      = Mid(senkou_b_period)[i]
+/// Chikou[i]            = Close[i + d]
+/// ```
+/// Where:
+/// - d is the displacement
+///
+/// # Calculation Steps
+/// 1. Calculate the Donchian midlines for the conversion line, base line and leading span B
+/// 2. Average the conversion and base lines and shift both leading spans `d` bars forward
+/// 3. Shift the closes `d` bars backward for the lagging span
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `opt_tenkan_period` - Period of the conversion line (typically 9, must be >= 2)
+/// * `opt_kijun_period` - Period of the base line (typically 26, must be >= 2)
+/// * `opt_senkou_b_period` - Period of the leading span B (typically 52, must be >= 2)
+/// * `opt_displacement` - Bars the cloud is shifted forward and the lagging span backward (typically 26, must be >= 1)
+/// * `output_tenkan` - Buffer to store conversion line values, same length as the input
+/// * `output_kijun` - Buffer to store base line values, same length as the input
+/// * `output_senkou_a` - Buffer to store leading span A values, `opt_displacement` longer than the input
+/// * `output_senkou_b` - Buffer to store leading span B values, `opt_displacement` longer than the input
+/// * `output_chikou` - Buffer to store lagging span values, same length as the input
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If an input or output array has the wrong length
+/// * `KandError::InvalidParameter` - If any period is less than 2 or the displacement is 0
+/// * `KandError::InsufficientData` - If the input is shorter than the longest period
+/// * `KandError::NaNDetected` - If any input contains NaN (with `check-nan`)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ichimoku;
+///
+/// let high = vec![10.0, 12.0, 15.0, 14.0, 13.0, 12.0];
+/// let low = vec![8.0, 9.0, 11.0, 10.0, 9.0, 8.0];
+/// let close = vec![9.0, 11.0, 14.0, 12.0, 11.0, 9.0];
+/// let mut tenkan = vec![0.0; 6];
+/// let mut kijun = vec![0.0; 6];
+/// let mut senkou_a = vec![0.0; 8];
+/// let mut senkou_b = vec![0.0; 8];
+/// let mut chikou = vec![0.0; 6];
+///
+/// ichimoku::ichimoku(
+///     &high,
+///     &low,
+///     &close,
+///     2,
+///     3,
+///     4,
+///     2,
+///     &mut tenkan,
+///     &mut kijun,
+///     &mut senkou_a,
+///     &mut senkou_b,
+///     &mut chikou,
+/// )
+/// .unwrap();
+/// assert_eq!(senkou_b[5], 11.5); // midline of the 4 bars ending at index 3
+/// assert_eq!(senkou_b[7], 11.5); // cloud projected beyond the latest bar
+/// assert_eq!(chikou[0], 14.0);
+/// assert!(chikou[4].is_nan());
+/// ```
+pub fn ichimoku(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_tenkan_period: usize,
+    opt_kijun_period: usize,
+    opt_senkou_b_period: usize,
+    opt_displacement: usize,
+    output_tenkan: &mut [TAFloat],
+    output_kijun: &mut [TAFloat],
+    output_senkou_a: &mut [TAFloat],
+    output_senkou_b: &mut [TAFloat],
+    output_chikou: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    lookback(
+        opt_tenkan_period,
+        opt_kijun_period,
+        opt_senkou_b_period,
+        opt_displacement,
+    )?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len
+            < opt_tenkan_period
+                .max(opt_kijun_period)
+                .max(opt_senkou_b_period)
+        {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_low.len()
+            || len != input_close.len()
+            || len != output_tenkan.len()
+            || len != output_kijun.len()
+            || len != output_chikou.len()
+            || len + opt_displacement != output_senkou_a.len()
+            || len + opt_displacement != output_senkou_b.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut upper = vec![0.0; len];
+    let mut lower = vec![0.0; len];
+    donchian::donchian(
+        input_high,
+        input_low,
+        opt_tenkan_period,
+        &mut upper,
+        output_tenkan,
+        &mut lower,
+    )?;
+    donchian::donchian(
+        input_high,
+        input_low,
+        opt_kijun_period,
+        &mut upper,
+        output_kijun,
+        &mut lower,
+    )?;
+    donchian::donchian(
+        input_high,
+        input_low,
+        opt_senkou_b_period,
+        &mut upper,
+        &mut output_senkou_b[opt_displacement..],
+        &mut lower,
+    )?;
+
+    for i in 0..len {
+        output_senkou_a[i + opt_displacement] =
+            TAFloat::midpoint(output_tenkan[i], output_kijun[i]);
+    }
+    for (i, value) in output_chikou.iter_mut().enumerate() {
+        *value = input_close
+            .get(i + opt_displacement)
+            .copied()
+            .unwrap_or(TAFloat::NAN);
+    }
+
+    // Fill initial values with NAN
+    for i in 0..opt_displacement {
+        output_senkou_a[i] = TAFloat::NAN;
+        output_senkou_b[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Streaming Ichimoku Kinko Hyo built on three [`Donchian`] channels, so the rolling highs and
+/// lows are exact.
+///
+/// Yields `(tenkan, kijun, senkou_a, senkou_b)` for the latest bar. The leading spans are the
+/// ones plotted on that bar, computed `opt_displacement` bars earlier; [`Ichimoku::leading`]
+/// returns the spans just computed, which will be plotted `opt_displacement` bars ahead. The
+/// lagging span of the bar `opt_displacement` bars back is simply the latest close.
+///
+/// Folding [`Indicator::update`] over a series reproduces the first `len` values of every
+/// [`ichimoku`] output except the lagging span.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ichimoku {
+    opt_displacement: usize,
+    lookback: usize,
+    count: usize,
+    tenkan: Donchian,
+    kijun: Donchian,
+    senkou_b: Donchian,
+    leading: VecDeque<(TAFloat, TAFloat)>,
+}
+
+impl Ichimoku {
+    /// Creates a streaming Ichimoku Kinko Hyo.
+    ///
+    /// # Arguments
+    /// * `opt_tenkan_period` - Period of the conversion line (must be >= 2)
+    /// * `opt_kijun_period` - Period of the base line (must be >= 2)
+    /// * `opt_senkou_b_period` - Period of the leading span B (must be >= 2)
+    /// * `opt_displacement` - Bars the cloud is shifted forward (must be >= 1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period < 2 or the displacement is 0
+    pub fn new(
+        opt_tenkan_period: usize,
+        opt_kijun_period: usize,
+        opt_senkou_b_period: usize,
+        opt_displacement: usize,
+    ) -> Result<Self, KandError> {
+        let lookback = lookback(
+            opt_tenkan_period,
+            opt_kijun_period,
+            opt_senkou_b_period,
+            opt_displacement,
+        )?;
+        Ok(Self {
+            opt_displacement,
+            lookback,
+            count: 0,
+            tenkan: Donchian::new(opt_tenkan_period)?,
+            kijun: Donchian::new(opt_kijun_period)?,
+            senkou_b: Donchian::new(opt_senkou_b_period)?,
+            leading: VecDeque::with_capacity(opt_displacement + 1),
+        })
+    }
+
+    /// Returns the `(senkou_a, senkou_b)` computed from the latest bar, which are plotted
+    /// `opt_displacement` bars ahead of it. `NaN` until enough bars have been seen.
+    #[must_use]
+    pub fn leading(&self) -> (TAFloat, TAFloat) {
+        self.leading
+            .back()
+            .copied()
+            .unwrap_or((TAFloat::NAN, TAFloat::NAN))
+    }
+}
+
+impl Indicator for Ichimoku {
+    type Output = (TAFloat, TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        let (_, tenkan, _) = self.tenkan.update(input)?;
+        let (_, kijun, _) = self.kijun.update(input)?;
+        let (_, senkou_b, _) = self.senkou_b.update(input)?;
+        self.count += 1;
+
+        self.leading
+            .push_back((TAFloat::midpoint(tenkan, kijun), senkou_b));
+        let (senkou_a, senkou_b) = if self.leading.len() > self.opt_displacement {
+            self.leading
+                .pop_front()
+                .unwrap_or((TAFloat::NAN, TAFloat::NAN))
+        } else {
+            (TAFloat::NAN, TAFloat::NAN)
+        };
+        Ok((tenkan, kijun, senkou_a, senkou_b))
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.tenkan.reset();
+        self.kijun.reset();
+        self.senkou_b.reset();
+        self.leading.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_ichimoku_calculation() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let (opt_tenkan_period, opt_kijun_period, opt_senkou_b_period, opt_displacement) =
+            (3, 5, 10, 5);
+        let len = input_high.len();
+        let mut output_tenkan = vec![0.0; len];
+        let mut output_kijun = vec![0.0; len];
+        let mut output_senkou_a = vec![0.0; len + opt_displacement];
+        let mut output_senkou_b = vec![0.0; len + opt_displacement];
+        let mut output_chikou = vec![0.0; len];
+
+        ichimoku(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_tenkan_period,
+            opt_kijun_period,
+            opt_senkou_b_period,
+            opt_displacement,
+            &mut output_tenkan,
+            &mut output_kijun,
+            &mut output_senkou_a,
+            &mut output_senkou_b,
+            &mut output_chikou,
+        )
+        .unwrap();
+
+        let lookback = lookback(
+            opt_tenkan_period,
+            opt_kijun_period,
+            opt_senkou_b_period,
+            opt_displacement,
+        )
+        .unwrap();
+        assert_eq!(lookback, 14);
+
+        assert!(output_tenkan[1].is_nan());
+        assert_relative_eq!(output_tenkan[2], 35223.0, epsilon = 0.0001);
+        assert!(output_kijun[3].is_nan());
+        assert_relative_eq!(output_kijun[4], 35198.35, epsilon = 0.0001);
+        assert!(output_senkou_a[8].is_nan());
+        assert_relative_eq!(output_senkou_a[9], 35190.775, epsilon = 0.0001);
+        assert!(output_senkou_b[13].is_nan());
+        assert_relative_eq!(output_senkou_b[14], 35206.1, epsilon = 0.0001);
+
+        let expected = [
+            (18, 35014.45, 35081.35, 35192.45, 35212.7),
+            (22, 34972.55, 34998.0, 35061.8, 35103.05),
+            (24, 35021.95, 35001.2, 35032.5, 35095.55),
+        ];
+        for (i, tenkan, kijun, senkou_a, senkou_b) in expected {
+            assert_relative_eq!(output_tenkan[i], tenkan, epsilon = 0.0001);
+            assert_relative_eq!(output_kijun[i], kijun, epsilon = 0.0001);
+            assert_relative_eq!(output_senkou_a[i], senkou_a, epsilon = 0.0001);
+            assert_relative_eq!(output_senkou_b[i], senkou_b, epsilon = 0.0001);
+        }
+
+        // Projected cloud beyond the latest bar
+        assert_relative_eq!(output_senkou_a[29], 35011.575, epsilon = 0.0001);
+        assert_relative_eq!(output_senkou_b[29], 35019.95, epsilon = 0.0001);
+
+        // Lagging span is the close displaced backward
+        assert_relative_eq!(output_chikou[0], 35254.6, epsilon = 0.0001);
+        assert_relative_eq!(output_chikou[19], 35080.0, epsilon = 0.0001);
+        for value in output_chikou.iter().skip(20) {
+            assert!(value.is_nan());
+        }
+
+        // Streaming indicator matches the batch outputs
+        let mut indicator = Ichimoku::new(
+            opt_tenkan_period,
+            opt_kijun_period,
+            opt_senkou_b_period,
+            opt_displacement,
+        )
+        .unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            let (tenkan, kijun, senkou_a, senkou_b) = indicator.update(&bar).unwrap();
+            let expected = [
+                (tenkan, output_tenkan[i]),
+                (kijun, output_kijun[i]),
+                (senkou_a, output_senkou_a[i]),
+                (senkou_b, output_senkou_b[i]),
+            ];
+            for (value, expected) in expected {
+                if expected.is_nan() {
+                    assert!(value.is_nan());
+                } else {
+                    assert_relative_eq!(value, expected, epsilon = 0.00001);
+                }
+            }
+            assert_eq!(indicator.is_ready(), i >= lookback);
+        }
+
+        // The latest leading spans are the last projected cloud values
+        let (leading_a, leading_b) = indicator.leading();
+        assert_relative_eq!(leading_a, output_senkou_a[29], epsilon = 0.00001);
+        assert_relative_eq!(leading_b, output_senkou_b[29], epsilon = 0.00001);
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert!(indicator.leading().0.is_nan());
+    }
+}
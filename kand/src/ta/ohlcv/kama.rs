@@ -0,0 +1,503 @@
+use std::collections::VecDeque;
+
+use crate::{EPSILON, KandError, TAFloat, helper::period_to_k, indicator::Indicator, types::Bar};
+
+/// Returns the lookback period required for KAMA calculation.
+///
+/// # Description
+/// The first KAMA value needs `opt_period` price changes, so the lookback equals `opt_period`.
+///
+/// # Arguments
+/// * `opt_period` - The efficiency ratio period. Must be >= 2.
+/// * `opt_fast_period` - Period of the fast smoothing constant. Must be >= 1.
+/// * `opt_slow_period` - Period of the slow smoothing constant. Must be greater than `opt_fast_period`.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success, or error on failure.
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `opt_period` < 2, `opt_fast_period` < 1 or
+///   `opt_slow_period` <= `opt_fast_period`.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::kama;
+/// let lookback = kama::lookback(10, 2, 30).unwrap();
+/// assert_eq!(lookback, 10);
+/// ```
+pub const fn lookback(
+    opt_period: usize,
+    opt_fast_period: usize,
+    opt_slow_period: usize,
+) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 2 || opt_fast_period < 1 || opt_slow_period <= opt_fast_period {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period)
+}
+
+/// Calculates Kaufman Adaptive Moving Average (KAMA) for a price series.
+///
+/// # Description
+/// KAMA adjusts its smoothing to market noise. The efficiency ratio compares the net price change
+/// over `opt_period` bars with the sum of the individual bar-to-bar changes: in a clean trend the
+/// average follows price with the fast smoothing constant, in a choppy market it flattens towards
+/// the slow one. The calculation matches TA-Lib's `KAMA`, which fixes the fast and slow periods at
+/// 2 and 30.
+///
+/// # Mathematical Formula
+/// ```text
+/// Change = |Price[t] - Price[t-n]|
+/// Volatility = Sum(|Price[i] - Price[i-1]|) for i in (t-n+1)..=t
+/// ER = Change / Volatility (1 when Volatility is 0)
+/// SC = (ER * (fast_sc - slow_sc) + slow_sc)^2
+/// KAMA = KAMA(previous) + SC * (Price - KAMA(previous))
+/// where:
+/// fast_sc = 2/(fast_period+1), slow_sc = 2/(slow_period+1)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Seed the previous KAMA with the price at index `opt_period - 1`
+/// 2. Maintain the rolling volatility sum and compute the efficiency ratio for each bar
+/// 3. Apply the squared smoothing constant to update KAMA
+/// 4. Fill values before the lookback period with NaN
+///
+/// # Arguments
+/// * `input_prices` - Array of price values
+/// * `opt_period` - The efficiency ratio period (must be >= 2)
+/// * `opt_fast_period` - Period of the fast smoothing constant (typically 2)
+/// * `opt_slow_period` - Period of the slow smoothing constant (typically 30)
+/// * `output_kama` - Array to store calculated KAMA values. Must match input length
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output length doesn't match input
+/// * `KandError::InvalidParameter` - If any period is out of range (see [`lookback`])
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input price is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::kama;
+/// let prices = vec![10.0, 11.0, 10.5, 11.5, 12.0, 12.5];
+/// let mut kama_values = vec![0.0; prices.len()];
+///
+/// kama::kama(&prices, 3, 2, 30, &mut kama_values).unwrap();
+/// assert!(kama_values[2].is_nan());
+/// assert!(!kama_values[3].is_nan());
+/// ```
+pub fn kama(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    opt_fast_period: usize,
+    opt_slow_period: usize,
+    output_kama: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period, opt_fast_period, opt_slow_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_kama.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let fast_sc = period_to_k(opt_fast_period)?;
+    let slow_sc = period_to_k(opt_slow_period)?;
+
+    // Sum of absolute changes over the first period
+    let mut volatility = 0.0;
+    for i in 1..=lookback {
+        volatility += (input_prices[i] - input_prices[i - 1]).abs();
+    }
+
+    let mut prev_kama = input_prices[lookback - 1];
+    prev_kama = kama_step(
+        input_prices[lookback],
+        input_prices[0],
+        prev_kama,
+        volatility,
+        fast_sc,
+        slow_sc,
+    );
+    output_kama[lookback] = prev_kama;
+
+    for i in (lookback + 1)..len {
+        volatility -= (input_prices[i - opt_period] - input_prices[i - opt_period - 1]).abs();
+        volatility += (input_prices[i] - input_prices[i - 1]).abs();
+        prev_kama = kama_step(
+            input_prices[i],
+            input_prices[i - opt_period],
+            prev_kama,
+            volatility,
+            fast_sc,
+            slow_sc,
+        );
+        output_kama[i] = prev_kama;
+    }
+
+    // Fill initial values with NAN
+    for value in output_kama.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates a single KAMA value incrementally.
+///
+/// # Description
+/// Rolls the volatility sum forward by one bar and applies the adaptive smoothing constant to the
+/// previous KAMA, so a live series can be extended without reprocessing history.
+///
+/// # Mathematical Formula
+/// ```text
+/// Volatility = prev_volatility - |old_price - old_prev_price| + |price - prev_price|
+/// ER = |price - old_price| / Volatility
+/// SC = (ER * (fast_sc - slow_sc) + slow_sc)^2
+/// KAMA = prev_kama + SC * (price - prev_kama)
+/// ```
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `input_prev_price` - The previous price
+/// * `input_old_price` - The price `opt_period` bars before the current one
+/// * `input_old_prev_price` - The price `opt_period + 1` bars before the current one
+/// * `prev_kama` - The previous KAMA value
+/// * `prev_volatility` - The previous sum of absolute price changes over the period
+/// * `opt_fast_period` - Period of the fast smoothing constant (typically 2)
+/// * `opt_slow_period` - Period of the slow smoothing constant (typically 30)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (KAMA, volatility) on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `opt_fast_period` < 1 or `opt_slow_period` <= `opt_fast_period`
+/// * `KandError::NaNDetected` - If any input is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::kama;
+/// let (kama_value, volatility) =
+///     kama::kama_inc(11.0, 10.5, 10.0, 9.5, 10.2, 2.0, 2, 30).unwrap();
+/// assert_eq!(volatility, 2.0);
+/// ```
+pub fn kama_inc(
+    input_price: TAFloat,
+    input_prev_price: TAFloat,
+    input_old_price: TAFloat,
+    input_old_prev_price: TAFloat,
+    prev_kama: TAFloat,
+    prev_volatility: TAFloat,
+    opt_fast_period: usize,
+    opt_slow_period: usize,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_fast_period < 1 || opt_slow_period <= opt_fast_period {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan()
+            || input_prev_price.is_nan()
+            || input_old_price.is_nan()
+            || input_old_prev_price.is_nan()
+            || prev_kama.is_nan()
+            || prev_volatility.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let volatility = prev_volatility - (input_old_price - input_old_prev_price).abs()
+        + (input_price - input_prev_price).abs();
+    let kama = kama_step(
+        input_price,
+        input_old_price,
+        prev_kama,
+        volatility,
+        period_to_k(opt_fast_period)?,
+        period_to_k(opt_slow_period)?,
+    );
+    Ok((kama, volatility))
+}
+
+/// Applies one adaptive smoothing step given the rolling volatility sum.
+fn kama_step(
+    price: TAFloat,
+    old_price: TAFloat,
+    prev_kama: TAFloat,
+    volatility: TAFloat,
+    fast_sc: TAFloat,
+    slow_sc: TAFloat,
+) -> TAFloat {
+    let change = price - old_price;
+    // Same guard as TA-Lib: a flat or perfectly trending window is fully efficient
+    let er = if volatility <= change || volatility.abs() < EPSILON {
+        1.0
+    } else {
+        (change / volatility).abs()
+    };
+    let sc = er.mul_add(fast_sc - slow_sc, slow_sc);
+    let sc = sc * sc;
+    (price - prev_kama).mul_add(sc, prev_kama)
+}
+
+/// Streaming KAMA that owns the last `opt_period + 1` prices and the rolling volatility sum.
+///
+/// Reads [`Bar::close`]. Returns `NaN` for the first `opt_period` prices, after which every value
+/// matches [`kama`] for the same series.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::kama::Kama};
+///
+/// let mut kama = Kama::new(3, 2, 30).unwrap();
+/// for price in [10.0, 11.0, 10.5] {
+///     assert!(kama.update(&Bar::from(price)).unwrap().is_nan());
+/// }
+/// let first = kama.update(&Bar::from(11.5)).unwrap();
+/// assert!(kama.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Kama {
+    opt_period: usize,
+    fast_sc: TAFloat,
+    slow_sc: TAFloat,
+    window: VecDeque<TAFloat>,
+    volatility: TAFloat,
+    prev_kama: TAFloat,
+}
+
+impl Kama {
+    /// Creates a streaming KAMA.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The efficiency ratio period (must be >= 2)
+    /// * `opt_fast_period` - Period of the fast smoothing constant (typically 2)
+    /// * `opt_slow_period` - Period of the slow smoothing constant (typically 30)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is out of range (see [`lookback`])
+    pub fn new(
+        opt_period: usize,
+        opt_fast_period: usize,
+        opt_slow_period: usize,
+    ) -> Result<Self, KandError> {
+        lookback(opt_period, opt_fast_period, opt_slow_period)?;
+        Ok(Self {
+            opt_period,
+            fast_sc: period_to_k(opt_fast_period)?,
+            slow_sc: period_to_k(opt_slow_period)?,
+            window: VecDeque::with_capacity(opt_period + 2),
+            volatility: 0.0,
+            prev_kama: TAFloat::NAN,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the KAMA for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        if let Some(&prev_price) = self.window.back() {
+            if self.window.len() > self.opt_period {
+                // Drop the change that leaves the window: window[0] -> window[1]
+                self.volatility -= (self.window[1] - self.window[0]).abs();
+                self.window.pop_front();
+            }
+            self.volatility += (input_price - prev_price).abs();
+        }
+        self.window.push_back(input_price);
+
+        if self.window.len() <= self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        if self.prev_kama.is_nan() {
+            self.prev_kama = self.window[self.opt_period - 1];
+        }
+        self.prev_kama = kama_step(
+            input_price,
+            self.window[0],
+            self.prev_kama,
+            self.volatility,
+            self.fast_sc,
+            self.slow_sc,
+        );
+        Ok(self.prev_kama)
+    }
+}
+
+impl Indicator for Kama {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        !self.prev_kama.is_nan()
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.volatility = 0.0;
+        self.prev_kama = TAFloat::NAN;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_kama_calculation() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+        ];
+        let opt_period = 10;
+        let mut output_kama = vec![0.0; input_prices.len()];
+
+        kama(&input_prices, opt_period, 2, 30, &mut output_kama).unwrap();
+
+        // First 10 values should be NaN
+        for value in output_kama.iter().take(10) {
+            assert!(value.is_nan());
+        }
+
+        // TA-Lib KAMA(timeperiod=10)
+        let expected_values = [
+            35_184.506_674_936_5,
+            35_184.785_727_776_856,
+            35_185.071_092_107_46,
+            35_184.918_943_467_53,
+            35_181.579_435_904_285,
+            35_162.403_612_673_98,
+            35_140.977_428_501_77,
+            35_115.581_930_448_956,
+            35_112.196_368_864_87,
+            35_104.303_976_513_69,
+            35_083.268_543_375_15,
+            35_057.795_682_836_23,
+            35_051.305_863_359_725,
+            35_050.849_186_755_8,
+            35_051.030_731_644_53,
+            35_052.877_762_969_56,
+            35_054.928_316_237_41,
+            35_056.241_643_683_745,
+            35_056.329_140_417_445,
+            35_061.617_066_945_364,
+            35_065.192_336_201_115,
+            35_073.294_219_375_2,
+            35_075.557_979_762_55,
+            35_078.175_529_219_4,
+            35_083.497_219_566_8,
+            35_092.061_479_777_11,
+        ];
+
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_kama[i + 10], *expected, epsilon = 0.00001);
+        }
+
+        // Incremental calculation should match the batch output
+        let mut prev_kama = output_kama[10];
+        let mut prev_volatility: TAFloat = (1..=10)
+            .map(|j| (input_prices[j] - input_prices[j - 1]).abs())
+            .sum();
+        for i in 11..input_prices.len() {
+            let (result, volatility) = kama_inc(
+                input_prices[i],
+                input_prices[i - 1],
+                input_prices[i - opt_period],
+                input_prices[i - opt_period - 1],
+                prev_kama,
+                prev_volatility,
+                2,
+                30,
+            )
+            .unwrap();
+            assert_relative_eq!(result, output_kama[i], epsilon = 0.00001);
+            prev_kama = result;
+            prev_volatility = volatility;
+        }
+    }
+
+    #[test]
+    fn test_kama_indicator() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+        ];
+        let opt_period = 10;
+        let mut output_kama = vec![0.0; input_prices.len()];
+        kama(&input_prices, opt_period, 2, 30, &mut output_kama).unwrap();
+
+        let mut indicator = Kama::new(opt_period, 2, 30).unwrap();
+        assert_eq!(indicator.lookback(), lookback(opt_period, 2, 30).unwrap());
+        for (i, &price) in input_prices.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < opt_period {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_kama[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
@@ -0,0 +1,683 @@
+use crate::{
+    KandError, TAFloat,
+    indicator::Indicator,
+    ta::ohlcv::{
+        atr::{self, Atr},
+        ema,
+        ma::{self, Ma},
+        typprice,
+    },
+    types::{Bar, MAType},
+};
+
+/// Returns the lookback period required for Keltner Channels calculation.
+///
+/// # Description
+/// The channels are valid once both the EMA center line and the ATR are, so the lookback is the
+/// larger of `opt_period - 1` and `opt_atr_period`.
+///
+/// # Arguments
+/// * `opt_period` - The EMA period for the center line (must be >= 2)
+/// * `opt_atr_period` - The ATR period for the channel width (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If either period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::keltner;
+/// assert_eq!(keltner::lookback(20, 10).unwrap(), 19);
+/// assert_eq!(keltner::lookback(10, 14).unwrap(), 14);
+/// ```
+pub fn lookback(opt_period: usize, opt_atr_period: usize) -> Result<usize, KandError> {
+    let atr_lookback = atr::lookback(opt_atr_period)?;
+    Ok(ema::lookback(opt_period)?.max(atr_lookback))
+}
+
+/// Returns the lookback period required for [`keltner_ext`].
+///
+/// # Arguments
+/// * `opt_period` - The moving average period for the center line (must be >= 2)
+/// * `opt_atr_period` - The ATR period for the channel width (must be >= 2)
+/// * `opt_ma_type` - Moving average type for the center line
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If either period is invalid
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::keltner, types::MAType};
+/// assert_eq!(keltner::lookback_ext(20, 10, MAType::SMA).unwrap(), 19);
+/// assert_eq!(keltner::lookback_ext(20, 10, MAType::DEMA).unwrap(), 38);
+/// ```
+pub fn lookback_ext(
+    opt_period: usize,
+    opt_atr_period: usize,
+    opt_ma_type: MAType,
+) -> Result<usize, KandError> {
+    let atr_lookback = atr::lookback(opt_atr_period)?;
+    Ok(ma::lookback(opt_period, opt_ma_type)?.max(atr_lookback))
+}
+
+/// Calculates Keltner Channels for a price series.
+///
+/// # Description
+/// Keltner Channels place bands a multiple of the Average True Range above and below an EMA of
+/// the typical price. Because the width follows ATR rather than the standard deviation of closes,
+/// the channels react to gaps and wide ranges and are less prone to the squeeze-and-burst shape
+/// of Bollinger Bands.
+///
+/// # Mathematical Formula
+/// ```text
+/// Typical Price = (High + Low + Close) / 3
+/// Middle = EMA(Typical Price, N)
+/// Upper  = Middle + K × ATR(M)
+/// Lower  = Middle - K × ATR(M)
+/// ```
+/// Where:
+/// - N is the EMA period
+/// - M is the ATR period
+/// - K is the ATR multiplier
+///
+/// # Calculation Steps
+/// 1. Calculate the typical price of each bar
+/// 2. Calculate the N-period EMA of the typical price as the middle channel
+/// 3. Calculate the M-period ATR
+/// 4. Add/subtract K times the ATR to get upper/lower channels
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `opt_period` - The EMA period for the center line (must be >= 2)
+/// * `opt_atr_period` - The ATR period for the channel width (must be >= 2)
+/// * `opt_multiplier` - ATR multiplier (typically 1-3)
+/// * `output_upper` - Buffer to store upper channel values
+/// * `output_middle` - Buffer to store middle channel (EMA) values
+/// * `output_lower` - Buffer to store lower channel values
+/// * `output_atr` - Buffer to store ATR values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output array lengths differ
+/// * `KandError::InvalidParameter` - If either period is less than 2
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input contains NaN (with `check-nan`)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::keltner;
+///
+/// let high = vec![10.0, 12.0, 15.0, 14.0, 13.0];
+/// let low = vec![8.0, 9.0, 11.0, 10.0, 9.0];
+/// let close = vec![9.0, 11.0, 13.0, 12.0, 11.0];
+/// let mut upper = vec![0.0; 5];
+/// let mut middle = vec![0.0; 5];
+/// let mut lower = vec![0.0; 5];
+/// let mut atr = vec![0.0; 5];
+///
+/// keltner::keltner(
+///     &high,
+///     &low,
+///     &close,
+///     3,
+///     3,
+///     2.0,
+///     &mut upper,
+///     &mut middle,
+///     &mut lower,
+///     &mut atr,
+/// )
+/// .unwrap();
+/// assert!(upper[2].is_nan());
+/// assert!(!upper[3].is_nan());
+/// ```
+pub fn keltner(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_period: usize,
+    opt_atr_period: usize,
+    opt_multiplier: TAFloat,
+    output_upper: &mut [TAFloat],
+    output_middle: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_atr: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(opt_period, opt_atr_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_low.len()
+            || len != input_close.len()
+            || len != output_upper.len()
+            || len != output_middle.len()
+            || len != output_lower.len()
+            || len != output_atr.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut typ_prices = vec![0.0; len];
+    typprice::typprice(input_high, input_low, input_close, &mut typ_prices)?;
+    ema::ema(&typ_prices, opt_period, None, output_middle)?;
+    atr::atr(
+        input_high,
+        input_low,
+        input_close,
+        opt_atr_period,
+        output_atr,
+    )?;
+
+    for i in lookback..len {
+        output_upper[i] = opt_multiplier.mul_add(output_atr[i], output_middle[i]);
+        output_lower[i] = opt_multiplier.mul_add(-output_atr[i], output_middle[i]);
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_upper[i] = TAFloat::NAN;
+        output_middle[i] = TAFloat::NAN;
+        output_lower[i] = TAFloat::NAN;
+        output_atr[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates Keltner Channels around a center line of the selected moving average type.
+///
+/// # Description
+/// Same as [`keltner`], but the center line is the moving average of the typical price selected
+/// by `opt_ma_type` instead of an EMA. `MAType::SMA` gives the original 1960 formulation.
+///
+/// There is no incremental form: most center lines need more state than their previous value,
+/// so [`Keltner::with_ma_type`] is the streaming counterpart of this function.
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `opt_period` - The moving average period for the center line (must be >= 2)
+/// * `opt_atr_period` - The ATR period for the channel width (must be >= 2)
+/// * `opt_multiplier` - ATR multiplier (typically 1-3)
+/// * `opt_ma_type` - Moving average type for the center line
+/// * `output_upper` - Buffer to store upper channel values
+/// * `output_middle` - Buffer to store middle channel values
+/// * `output_lower` - Buffer to store lower channel values
+/// * `output_atr` - Buffer to store ATR values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output array lengths differ
+/// * `KandError::InvalidParameter` - If either period is invalid
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input contains NaN (with `check-nan`)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::keltner, types::MAType};
+///
+/// let high = vec![10.0, 12.0, 15.0, 14.0, 13.0];
+/// let low = vec![8.0, 9.0, 11.0, 10.0, 9.0];
+/// let close = vec![9.0, 11.0, 13.0, 12.0, 11.0];
+/// let mut upper = vec![0.0; 5];
+/// let mut middle = vec![0.0; 5];
+/// let mut lower = vec![0.0; 5];
+/// let mut atr = vec![0.0; 5];
+///
+/// keltner::keltner_ext(
+///     &high,
+///     &low,
+///     &close,
+///     3,
+///     3,
+///     2.0,
+///     MAType::SMA,
+///     &mut upper,
+///     &mut middle,
+///     &mut lower,
+///     &mut atr,
+/// )
+/// .unwrap();
+/// assert_eq!(middle[4], 12.0); // mean of typical prices 13.0, 12.0 and 11.0
+/// ```
+pub fn keltner_ext(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_period: usize,
+    opt_atr_period: usize,
+    opt_multiplier: TAFloat,
+    opt_ma_type: MAType,
+    output_upper: &mut [TAFloat],
+    output_middle: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_atr: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback_ext(opt_period, opt_atr_period, opt_ma_type)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_low.len()
+            || len != input_close.len()
+            || len != output_upper.len()
+            || len != output_middle.len()
+            || len != output_lower.len()
+            || len != output_atr.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut typ_prices = vec![0.0; len];
+    typprice::typprice(input_high, input_low, input_close, &mut typ_prices)?;
+    ma::ma(&typ_prices, opt_period, opt_ma_type, output_middle)?;
+    atr::atr(
+        input_high,
+        input_low,
+        input_close,
+        opt_atr_period,
+        output_atr,
+    )?;
+
+    for i in lookback..len {
+        output_upper[i] = opt_multiplier.mul_add(output_atr[i], output_middle[i]);
+        output_lower[i] = opt_multiplier.mul_add(-output_atr[i], output_middle[i]);
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_upper[i] = TAFloat::NAN;
+        output_middle[i] = TAFloat::NAN;
+        output_lower[i] = TAFloat::NAN;
+        output_atr[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Keltner Channels values using an incremental approach.
+///
+/// # Description
+/// Advances the EMA center line with [`ema::ema_inc`] and the ATR with [`atr::atr_inc`], then
+/// rebuilds the channels. Only the EMA center line of [`keltner`] can be advanced this way; use
+/// [`Keltner::with_ma_type`] to stream other center lines.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `prev_close` - Previous close price
+/// * `prev_middle` - Previous middle channel (EMA) value
+/// * `prev_atr` - Previous ATR value
+/// * `opt_period` - The EMA period for the center line (must be >= 2)
+/// * `opt_atr_period` - The ATR period for the channel width (must be >= 2)
+/// * `opt_multiplier` - ATR multiplier
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - A tuple containing:
+///   - Upper channel value
+///   - Middle channel (EMA) value
+///   - Lower channel value
+///   - New ATR value
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If either period is less than 2
+/// * `KandError::NaNDetected` - If any input contains NaN (with `check-nan`)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::keltner;
+///
+/// let (upper, middle, lower, atr) = keltner::keltner_inc(
+///     13.0, // current high
+///     9.0,  // current low
+///     11.0, // current close
+///     12.0, // previous close
+///     12.0, // previous middle channel
+///     4.0,  // previous ATR
+///     3,    // EMA period
+///     3,    // ATR period
+///     2.0,  // multiplier
+/// )
+/// .unwrap();
+/// assert_eq!((upper, middle, lower, atr), (19.5, 11.5, 3.5, 4.0));
+/// ```
+pub fn keltner_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    prev_close: TAFloat,
+    prev_middle: TAFloat,
+    prev_atr: TAFloat,
+    opt_period: usize,
+    opt_atr_period: usize,
+    opt_multiplier: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if opt_multiplier.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let typ_price = typprice::typprice_inc(input_high, input_low, input_close)?;
+    let middle = ema::ema_inc(typ_price, prev_middle, opt_period, None)?;
+    let atr = atr::atr_inc(input_high, input_low, prev_close, prev_atr, opt_atr_period)?;
+
+    let upper = opt_multiplier.mul_add(atr, middle);
+    let lower = opt_multiplier.mul_add(-atr, middle);
+    Ok((upper, middle, lower, atr))
+}
+
+/// Streaming Keltner Channels built on an [`Ma`] of the typical price and an [`Atr`].
+///
+/// Yields `(upper, middle, lower)`. Folding [`Indicator::update`] over a series reproduces
+/// [`keltner`], or [`keltner_ext`] when created with [`Keltner::with_ma_type`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keltner {
+    opt_multiplier: TAFloat,
+    ma: Ma,
+    atr: Atr,
+}
+
+impl Keltner {
+    /// Creates streaming Keltner Channels around an EMA center line.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The EMA period for the center line (must be >= 2)
+    /// * `opt_atr_period` - The ATR period for the channel width (must be >= 2)
+    /// * `opt_multiplier` - ATR multiplier
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If either period < 2
+    pub fn new(
+        opt_period: usize,
+        opt_atr_period: usize,
+        opt_multiplier: TAFloat,
+    ) -> Result<Self, KandError> {
+        Self::with_ma_type(opt_period, opt_atr_period, opt_multiplier, MAType::EMA)
+    }
+
+    /// Creates streaming Keltner Channels whose center line uses the given moving average type.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The moving average period for the center line (must be >= 2)
+    /// * `opt_atr_period` - The ATR period for the channel width (must be >= 2)
+    /// * `opt_multiplier` - ATR multiplier
+    /// * `opt_ma_type` - Moving average type for the center line
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If either period is invalid
+    pub fn with_ma_type(
+        opt_period: usize,
+        opt_atr_period: usize,
+        opt_multiplier: TAFloat,
+        opt_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        lookback_ext(opt_period, opt_atr_period, opt_ma_type)?;
+        Ok(Self {
+            opt_multiplier,
+            ma: Ma::new(opt_period, opt_ma_type)?,
+            atr: Atr::new(opt_atr_period)?,
+        })
+    }
+}
+
+impl Indicator for Keltner {
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        let typ_price = typprice::typprice_inc(input.high, input.low, input.close)?;
+        let middle = self.ma.update_value(typ_price)?;
+        let atr = self.atr.update(input)?;
+        if !self.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+        }
+        let upper = self.opt_multiplier.mul_add(atr, middle);
+        let lower = self.opt_multiplier.mul_add(-atr, middle);
+        Ok((upper, middle, lower))
+    }
+
+    fn lookback(&self) -> usize {
+        self.ma.lookback().max(self.atr.lookback())
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ma.is_ready() && self.atr.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.atr.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_keltner_calculation() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 10;
+        let opt_atr_period = 10;
+        let opt_multiplier = 2.0;
+        let len = input_high.len();
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        let mut output_atr = vec![0.0; len];
+
+        keltner(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_atr_period,
+            opt_multiplier,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+            &mut output_atr,
+        )
+        .unwrap();
+
+        // First 10 values should be NaN
+        let lookback = lookback(opt_period, opt_atr_period).unwrap();
+        assert_eq!(lookback, 10);
+        for i in 0..lookback {
+            assert!(output_upper[i].is_nan());
+            assert!(output_middle[i].is_nan());
+            assert!(output_lower[i].is_nan());
+            assert!(output_atr[i].is_nan());
+        }
+
+        let expected = [
+            (
+                10,
+                35_312.616_969_696_974,
+                35_203.216_969_696_97,
+                35_093.816_969_696_97,
+            ),
+            (
+                14,
+                35_318.133_868_780_03,
+                35_186.524_308_780_03,
+                35_054.914_748_780_04,
+            ),
+            (
+                20,
+                35_213.231_365_819_12,
+                35_067.373_313_243_16,
+                34_921.515_260_667_2,
+            ),
+            (
+                24,
+                35_178.500_862_096_18,
+                35_039.721_073_801_09,
+                34_900.941_285_506,
+            ),
+        ];
+        for (i, upper, middle, lower) in expected {
+            assert_relative_eq!(output_upper[i], upper, epsilon = 1e-8);
+            assert_relative_eq!(output_middle[i], middle, epsilon = 1e-8);
+            assert_relative_eq!(output_lower[i], lower, epsilon = 1e-8);
+        }
+
+        // Incremental calculation matches the batch outputs
+        for i in (lookback + 1)..len {
+            let (upper, middle, lower, atr) = keltner_inc(
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_close[i - 1],
+                output_middle[i - 1],
+                output_atr[i - 1],
+                opt_period,
+                opt_atr_period,
+                opt_multiplier,
+            )
+            .unwrap();
+            assert_relative_eq!(upper, output_upper[i], epsilon = 1e-8);
+            assert_relative_eq!(middle, output_middle[i], epsilon = 1e-8);
+            assert_relative_eq!(lower, output_lower[i], epsilon = 1e-8);
+            assert_relative_eq!(atr, output_atr[i], epsilon = 1e-8);
+        }
+
+        // SMA center line
+        let mut output_upper_ext = vec![0.0; len];
+        let mut output_middle_ext = vec![0.0; len];
+        let mut output_lower_ext = vec![0.0; len];
+        let mut output_atr_ext = vec![0.0; len];
+        keltner_ext(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_atr_period,
+            opt_multiplier,
+            MAType::SMA,
+            &mut output_upper_ext,
+            &mut output_middle_ext,
+            &mut output_lower_ext,
+            &mut output_atr_ext,
+        )
+        .unwrap();
+        assert!(output_middle_ext[9].is_nan());
+        assert!(output_atr_ext[9].is_nan());
+        for i in lookback..len {
+            assert_relative_eq!(output_atr_ext[i], output_atr[i], epsilon = 1e-8);
+        }
+        assert_relative_eq!(output_upper_ext[10], 35_312.853_333_333_33, epsilon = 1e-8);
+        assert_relative_eq!(output_middle_ext[10], 35_203.453_333_333_33, epsilon = 1e-8);
+        assert_relative_eq!(output_lower_ext[12], 35_089.234, epsilon = 1e-8);
+
+        // Streaming indicators match the batch outputs
+        let mut indicator = Keltner::new(opt_period, opt_atr_period, opt_multiplier).unwrap();
+        let mut indicator_ext =
+            Keltner::with_ma_type(opt_period, opt_atr_period, opt_multiplier, MAType::SMA).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            let (upper, middle, lower) = indicator.update(&bar).unwrap();
+            let (upper_ext, middle_ext, lower_ext) = indicator_ext.update(&bar).unwrap();
+            if i < lookback {
+                assert!(upper.is_nan() && middle.is_nan() && lower.is_nan());
+                assert!(upper_ext.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(upper, output_upper[i], epsilon = 1e-8);
+                assert_relative_eq!(middle, output_middle[i], epsilon = 1e-8);
+                assert_relative_eq!(lower, output_lower[i], epsilon = 1e-8);
+                assert_relative_eq!(upper_ext, output_upper_ext[i], epsilon = 1e-8);
+                assert_relative_eq!(middle_ext, output_middle_ext[i], epsilon = 1e-8);
+                assert_relative_eq!(lower_ext, output_lower_ext[i], epsilon = 1e-8);
+            }
+        }
+        assert!(indicator.is_ready());
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
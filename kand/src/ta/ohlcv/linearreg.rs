@@ -0,0 +1,398 @@
+use crate::{
+    KandError, TAFloat,
+    indicator::Indicator,
+    stats::linreg::{self, Linreg},
+    types::Bar,
+};
+
+/// Calculates the lookback period required for Linear Regression calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For LINEARREG, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The time period for the regression window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `opt_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg;
+///
+/// let lookback = linearreg::lookback(14).unwrap();
+/// assert_eq!(lookback, 13);
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    linreg::lookback(opt_period)
+}
+
+/// Calculates the Linear Regression (LINEARREG) for a price series.
+///
+/// # Description
+/// Fits a least-squares line to the last `opt_period` prices and returns its value at the
+/// current bar, i.e. the end point of the regression line. It tracks price like a moving average
+/// but with less lag, since the fitted trend is projected to the newest bar instead of the
+/// middle of the window.
+/// The calculation matches TA-Lib's `LINEARREG`.
+///
+/// # Mathematical Formula
+/// ```text
+/// x = 0 (oldest) .. n-1 (newest)
+/// Slope = (n * Sum(x*y) - Sum(x) * Sum(y)) / (n * Sum(x^2) - Sum(x)^2)
+/// Intercept = (Sum(y) - Slope * Sum(x)) / n
+/// LINEARREG = Intercept + Slope * (n - 1)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Fit the rolling least-squares line, updating its sums in O(1) per bar ([`linreg`])
+/// 2. Evaluate the line at the newest bar
+/// 3. Fill the first (period-1) values with NaN
+///
+/// # Arguments
+/// * `input_prices` - Array of price values
+/// * `opt_period` - The time period for the regression window (must be >= 2)
+/// * `output_linearreg` - Array to store the regression line value at the current bar
+/// * `output_sum_y` - Array to store running sum of prices
+/// * `output_sum_xy` - Array to store running sum of index-weighted prices
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output arrays don't match input length
+/// * `KandError::InvalidParameter` - If `opt_period` is less than 2
+/// * `KandError::InsufficientData` - If input length is less than or equal to lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg;
+///
+/// let input_prices = vec![1.0, 3.0, 5.0, 4.0, 6.0];
+/// let mut output_linearreg = vec![0.0; 5];
+/// let mut output_sum_y = vec![0.0; 5];
+/// let mut output_sum_xy = vec![0.0; 5];
+///
+/// linearreg::linearreg(
+///     &input_prices,
+///     3,
+///     &mut output_linearreg,
+///     &mut output_sum_y,
+///     &mut output_sum_xy,
+/// )
+/// .unwrap();
+/// assert!(output_linearreg[1].is_nan());
+/// assert!(!output_linearreg[2].is_nan());
+/// ```
+pub fn linearreg(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    output_linearreg: &mut [TAFloat],
+    output_sum_y: &mut [TAFloat],
+    output_sum_xy: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_linearreg.len() != len || output_sum_y.len() != len || output_sum_xy.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial sums
+    let mut sum_y = 0.0;
+    let mut sum_xy = 0.0;
+    for (x, val) in input_prices.iter().take(opt_period).enumerate() {
+        sum_y += *val;
+        sum_xy = (x as TAFloat).mul_add(*val, sum_xy);
+    }
+
+    let (slope, intercept) = linreg::fit(sum_y, sum_xy, opt_period);
+    output_linearreg[lookback] = value(slope, intercept, opt_period);
+    output_sum_y[lookback] = sum_y;
+    output_sum_xy[lookback] = sum_xy;
+
+    // Slide the window
+    for i in opt_period..len {
+        let (linearreg, new_sum_y, new_sum_xy) = linearreg_inc(
+            input_prices[i],
+            sum_y,
+            sum_xy,
+            input_prices[i - opt_period],
+            opt_period,
+        )?;
+        sum_y = new_sum_y;
+        sum_xy = new_sum_xy;
+        output_linearreg[i] = linearreg;
+        output_sum_y[i] = sum_y;
+        output_sum_xy[i] = sum_xy;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_linearreg[i] = TAFloat::NAN;
+        output_sum_y[i] = TAFloat::NAN;
+        output_sum_xy[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest Linear Regression value incrementally.
+///
+/// # Description
+/// Slides the regression window by one bar in O(1) using the running sums returned by the
+/// previous call (or by [`linearreg`]), see [`linreg::linreg_inc`].
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `prev_sum_y` - Previous sum of prices in the window
+/// * `prev_sum_xy` - Previous sum of index-weighted prices in the window
+/// * `input_old_price` - Oldest price value to remove from calculation
+/// * `opt_period` - The time period for the regression window (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (linearreg, `new_sum_y`,
+///   `new_sum_xy`) on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `opt_period` is less than 2
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg;
+///
+/// let (linearreg, new_sum_y, new_sum_xy) = linearreg::linearreg_inc(
+///     7.0,  // new price
+///     9.0,  // previous sum of prices
+///     13.0, // previous sum of index-weighted prices
+///     1.0,  // price to remove
+///     3,    // period
+/// )
+/// .unwrap();
+/// ```
+pub fn linearreg_inc(
+    input_price: TAFloat,
+    prev_sum_y: TAFloat,
+    prev_sum_xy: TAFloat,
+    input_old_price: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    let (slope, intercept, new_sum_y, new_sum_xy) = linreg::linreg_inc(
+        input_price,
+        prev_sum_y,
+        prev_sum_xy,
+        input_old_price,
+        opt_period,
+    )?;
+    Ok((value(slope, intercept, opt_period), new_sum_y, new_sum_xy))
+}
+
+/// Maps the fitted line to the regression line value.
+fn value(slope: TAFloat, intercept: TAFloat, opt_period: usize) -> TAFloat {
+    let period_t = opt_period as TAFloat;
+    slope.mul_add(period_t - 1.0, intercept)
+}
+
+/// Streaming Linear Regression that owns a rolling least-squares fit.
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`linearreg`].
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::linearreg::Linearreg};
+///
+/// let mut indicator = Linearreg::new(3).unwrap();
+/// for price in [1.0, 3.0, 5.0, 4.0, 6.0] {
+///     let value = indicator.update(&Bar::from(price)).unwrap();
+/// }
+/// assert!(indicator.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Linearreg {
+    opt_period: usize,
+    linreg: Linreg,
+}
+
+impl Linearreg {
+    /// Creates a streaming Linear Regression.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for the regression window (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            opt_period,
+            linreg: Linreg::new(opt_period)?,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the regression line value for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        let (slope, intercept) = self.linreg.update_value(input_price)?;
+        Ok(value(slope, intercept, self.opt_period))
+    }
+}
+
+impl Indicator for Linearreg {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.linreg.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.linreg.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.linreg.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 35] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1,
+    ];
+
+    #[test]
+    fn test_linearreg_calculation() {
+        let opt_period = 14;
+        let len = INPUT_PRICES.len();
+        let mut output_linearreg = vec![0.0; len];
+        let mut output_sum_y = vec![0.0; len];
+        let mut output_sum_xy = vec![0.0; len];
+
+        linearreg(
+            &INPUT_PRICES,
+            opt_period,
+            &mut output_linearreg,
+            &mut output_sum_y,
+            &mut output_sum_xy,
+        )
+        .unwrap();
+
+        // First 13 values should be NaN
+        for value in output_linearreg.iter().take(13) {
+            assert!(value.is_nan());
+        }
+
+        // TA-Lib LINEARREG
+        let expected_values = [
+            35_195.885_714_285_71,
+            35_168.562_857_142_87,
+            35_132.965_714_285_72,
+            35_087.357_142_857_14,
+            35_047.885_714_285_71,
+            35_028.828_571_428_574,
+            35_012.011_428_571_43,
+            34_972.482_857_142_844,
+            34_950.859_999_999_99,
+            34_942.22,
+            34_948.131_428_571_43,
+            34_966.577_142_857_15,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_linearreg[i + 13], expected, epsilon = 0.00001);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut prev_sum_y = output_sum_y[13];
+        let mut prev_sum_xy = output_sum_xy[13];
+        for i in 14..len {
+            let (linearreg, new_sum_y, new_sum_xy) = linearreg_inc(
+                INPUT_PRICES[i],
+                prev_sum_y,
+                prev_sum_xy,
+                INPUT_PRICES[i - opt_period],
+                opt_period,
+            )
+            .unwrap();
+            assert_relative_eq!(linearreg, output_linearreg[i], epsilon = 0.00001);
+            prev_sum_y = new_sum_y;
+            prev_sum_xy = new_sum_xy;
+        }
+    }
+
+    #[test]
+    fn test_linearreg_indicator() {
+        let opt_period = 14;
+        let len = INPUT_PRICES.len();
+        let mut output_linearreg = vec![0.0; len];
+        let mut output_sum_y = vec![0.0; len];
+        let mut output_sum_xy = vec![0.0; len];
+        linearreg(
+            &INPUT_PRICES,
+            opt_period,
+            &mut output_linearreg,
+            &mut output_sum_y,
+            &mut output_sum_xy,
+        )
+        .unwrap();
+
+        let mut indicator = Linearreg::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_linearreg[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
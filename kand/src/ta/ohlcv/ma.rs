@@ -0,0 +1,459 @@
+use super::{
+    dema::{self, Dema},
+    ema::{self, Ema},
+    kama::{self, Kama},
+    mama::{self, Mama},
+    rma::{self, Rma},
+    sma::{self, Sma},
+    t3::{self, T3},
+    tema::{self, Tema},
+    trima::{self, Trima},
+    wma::{self, Wma},
+};
+use crate::{
+    KandError, TAFloat,
+    indicator::Indicator,
+    types::{Bar, MAType},
+};
+
+/// Volume factor used for [`MAType::T3`], as in TA-Lib's `MA`.
+const T3_VFACTOR: TAFloat = 0.7;
+
+/// Fast and slow smoothing periods used for [`MAType::KAMA`], as in TA-Lib's `MA`.
+const KAMA_FAST_PERIOD: usize = 2;
+const KAMA_SLOW_PERIOD: usize = 30;
+
+/// Fast and slow alpha limits used for [`MAType::MAMA`], as in TA-Lib's `MA`.
+const MAMA_FAST_LIMIT: TAFloat = 0.5;
+const MAMA_SLOW_LIMIT: TAFloat = 0.05;
+
+/// Returns the lookback period required for a moving average of the given type.
+///
+/// # Description
+/// Dispatches to the `lookback` of the selected moving average. A period of 1 is the identity
+/// and needs no lookback, whatever the type. [`MAType::MAMA`] ignores the period.
+///
+/// # Arguments
+/// * `opt_period` - The time period for the moving average
+/// * `opt_ma_type` - Which moving average to use
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If the period is invalid for the selected moving average
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::ma, types::MAType};
+///
+/// assert_eq!(ma::lookback(10, MAType::SMA).unwrap(), 9);
+/// assert_eq!(ma::lookback(10, MAType::DEMA).unwrap(), 18);
+/// assert_eq!(ma::lookback(1, MAType::T3).unwrap(), 0);
+/// ```
+pub fn lookback(opt_period: usize, opt_ma_type: MAType) -> Result<usize, KandError> {
+    if opt_period == 1 {
+        return Ok(0);
+    }
+    match opt_ma_type {
+        MAType::SMA => sma::lookback(opt_period),
+        MAType::EMA => ema::lookback(opt_period),
+        MAType::WMA => wma::lookback(opt_period),
+        MAType::DEMA => dema::lookback(opt_period),
+        MAType::TEMA => tema::lookback(opt_period),
+        MAType::TRIMA => trima::lookback(opt_period),
+        MAType::T3 => t3::lookback(opt_period),
+        MAType::RMA => rma::lookback(opt_period),
+        MAType::KAMA => kama::lookback(opt_period, KAMA_FAST_PERIOD, KAMA_SLOW_PERIOD),
+        MAType::MAMA => mama::lookback(MAMA_FAST_LIMIT, MAMA_SLOW_LIMIT),
+    }
+}
+
+/// Calculates a moving average of the type selected by [`MAType`].
+///
+/// # Description
+/// A single entry point over every moving average in the crate, so callers that take the
+/// moving average as a parameter do not need their own `match`. Types with extra parameters use
+/// TA-Lib's `MA` defaults: a volume factor of 0.7 for T3, fast/slow periods of 2/30 for KAMA and
+/// fast/slow limits of 0.5/0.05 for MAMA (which ignores `opt_period`). A period of 1 copies the
+/// input.
+///
+/// # Arguments
+/// * `input` - Array of input values
+/// * `opt_period` - The time period for the moving average
+/// * `opt_ma_type` - Which moving average to use
+/// * `output` - Array to store the moving average. Must match input length
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output length doesn't match input
+/// * `KandError::InvalidParameter` - If the period is invalid for the selected moving average
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input value is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::ma, types::MAType};
+///
+/// let input = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// let mut output = vec![0.0; input.len()];
+///
+/// ma::ma(&input, 3, MAType::SMA, &mut output).unwrap();
+/// assert!(output[1].is_nan());
+/// assert_eq!(output[2], 2.0);
+/// assert_eq!(output[4], 4.0);
+/// ```
+pub fn ma(
+    input: &[TAFloat],
+    opt_period: usize,
+    opt_ma_type: MAType,
+    output: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input.len();
+    let lookback = lookback(opt_period, opt_ma_type)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    if opt_period == 1 {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.iter().any(|value| value.is_nan()) {
+                return Err(KandError::NaNDetected);
+            }
+        }
+        output.copy_from_slice(input);
+        return Ok(());
+    }
+
+    match opt_ma_type {
+        MAType::SMA => sma::sma(input, opt_period, output)?,
+        MAType::EMA => ema::ema(input, opt_period, None, output)?,
+        MAType::WMA => wma::wma(input, opt_period, output)?,
+        MAType::DEMA => {
+            let mut ema1 = vec![0.0; len];
+            let mut ema2 = vec![0.0; len];
+            dema::dema(input, opt_period, output, &mut ema1, &mut ema2)?;
+        }
+        MAType::TEMA => {
+            let mut ema1 = vec![0.0; len];
+            let mut ema2 = vec![0.0; len];
+            let mut ema3 = vec![0.0; len];
+            tema::tema(input, opt_period, output, &mut ema1, &mut ema2, &mut ema3)?;
+        }
+        MAType::TRIMA => {
+            let mut sma1 = vec![0.0; len];
+            trima::trima(input, opt_period, &mut sma1, output)?;
+        }
+        MAType::T3 => {
+            let mut emas: [Vec<TAFloat>; 6] = std::array::from_fn(|_| vec![0.0; len]);
+            let [ema1, ema2, ema3, ema4, ema5, ema6] = &mut emas;
+            t3::t3(
+                input, opt_period, T3_VFACTOR, output, ema1, ema2, ema3, ema4, ema5, ema6,
+            )?;
+        }
+        MAType::RMA => rma::rma(input, opt_period, output)?,
+        MAType::KAMA => kama::kama(
+            input,
+            opt_period,
+            KAMA_FAST_PERIOD,
+            KAMA_SLOW_PERIOD,
+            output,
+        )?,
+        MAType::MAMA => {
+            let mut fama = vec![0.0; len];
+            mama::mama(input, MAMA_FAST_LIMIT, MAMA_SLOW_LIMIT, output, &mut fama)?;
+        }
+    }
+
+    // Fill initial values with NAN
+    for value in output.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// State of the moving average wrapped by [`Ma`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum MaState {
+    /// Period 1: passes inputs through; the flag records whether one has been seen.
+    Identity(bool),
+    Sma(Sma),
+    Ema(Ema),
+    Wma(Wma),
+    Dema(Dema),
+    Tema(Tema),
+    Trima(Trima),
+    T3(T3),
+    Rma(Rma),
+    Kama(Kama),
+    Mama(Box<Mama>),
+}
+
+/// Streaming moving average of the type selected by [`MAType`].
+///
+/// Reads [`Bar::close`] and wraps the streaming struct of the selected moving average, using the
+/// same defaults as [`ma`]. For [`MAType::MAMA`] only the MAMA line is returned. Folding
+/// [`Indicator::update`] over a series reproduces [`ma`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ma::Ma, types::MAType};
+///
+/// let mut wma = Ma::new(3, MAType::WMA).unwrap();
+/// assert!(wma.update_value(1.0).unwrap().is_nan());
+/// assert!(wma.update_value(2.0).unwrap().is_nan());
+/// // (3 * 3 + 2 * 2 + 1 * 1) / 6
+/// assert!((wma.update_value(3.0).unwrap() - 14.0 / 6.0).abs() < 1e-12);
+/// assert!(wma.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ma {
+    lookback: usize,
+    state: MaState,
+}
+
+impl Ma {
+    /// Creates a streaming moving average.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for the moving average
+    /// * `opt_ma_type` - Which moving average to use
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the period is invalid for the selected moving average
+    pub fn new(opt_period: usize, opt_ma_type: MAType) -> Result<Self, KandError> {
+        let lookback = lookback(opt_period, opt_ma_type)?;
+        let state = if opt_period == 1 {
+            MaState::Identity(false)
+        } else {
+            match opt_ma_type {
+                MAType::SMA => MaState::Sma(Sma::new(opt_period)?),
+                MAType::EMA => MaState::Ema(Ema::new(opt_period, None)?),
+                MAType::WMA => MaState::Wma(Wma::new(opt_period)?),
+                MAType::DEMA => MaState::Dema(Dema::new(opt_period)?),
+                MAType::TEMA => MaState::Tema(Tema::new(opt_period)?),
+                MAType::TRIMA => MaState::Trima(Trima::new(opt_period)?),
+                MAType::T3 => MaState::T3(T3::new(opt_period, T3_VFACTOR)?),
+                MAType::RMA => MaState::Rma(Rma::new(opt_period)?),
+                MAType::KAMA => {
+                    MaState::Kama(Kama::new(opt_period, KAMA_FAST_PERIOD, KAMA_SLOW_PERIOD)?)
+                }
+                MAType::MAMA => {
+                    MaState::Mama(Box::new(Mama::new(MAMA_FAST_LIMIT, MAMA_SLOW_LIMIT)?))
+                }
+            }
+        };
+        Ok(Self { lookback, state })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the moving average for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        match &mut self.state {
+            MaState::Identity(seen) => {
+                #[cfg(feature = "check-nan")]
+                {
+                    if input_price.is_nan() {
+                        return Err(KandError::NaNDetected);
+                    }
+                }
+                *seen = true;
+                Ok(input_price)
+            }
+            MaState::Sma(ma) => ma.update_value(input_price),
+            MaState::Ema(ma) => ma.update_value(input_price),
+            MaState::Wma(ma) => ma.update_value(input_price),
+            MaState::Dema(ma) => ma.update_value(input_price),
+            MaState::Tema(ma) => ma.update_value(input_price),
+            MaState::Trima(ma) => ma.update_value(input_price),
+            MaState::T3(ma) => ma.update_value(input_price),
+            MaState::Rma(ma) => ma.update_value(input_price),
+            MaState::Kama(ma) => ma.update_value(input_price),
+            MaState::Mama(ma) => ma.update_value(input_price).map(|(mama, _)| mama),
+        }
+    }
+}
+
+impl Indicator for Ma {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+
+    fn is_ready(&self) -> bool {
+        match &self.state {
+            MaState::Identity(seen) => *seen,
+            MaState::Sma(ma) => ma.is_ready(),
+            MaState::Ema(ma) => ma.is_ready(),
+            MaState::Wma(ma) => ma.is_ready(),
+            MaState::Dema(ma) => ma.is_ready(),
+            MaState::Tema(ma) => ma.is_ready(),
+            MaState::Trima(ma) => ma.is_ready(),
+            MaState::T3(ma) => ma.is_ready(),
+            MaState::Rma(ma) => ma.is_ready(),
+            MaState::Kama(ma) => ma.is_ready(),
+            MaState::Mama(ma) => ma.is_ready(),
+        }
+    }
+
+    fn reset(&mut self) {
+        match &mut self.state {
+            MaState::Identity(seen) => *seen = false,
+            MaState::Sma(ma) => ma.reset(),
+            MaState::Ema(ma) => ma.reset(),
+            MaState::Wma(ma) => ma.reset(),
+            MaState::Dema(ma) => ma.reset(),
+            MaState::Tema(ma) => ma.reset(),
+            MaState::Trima(ma) => ma.reset(),
+            MaState::T3(ma) => ma.reset(),
+            MaState::Rma(ma) => ma.reset(),
+            MaState::Kama(ma) => ma.reset(),
+            MaState::Mama(ma) => ma.reset(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const MA_TYPES: [MAType; 10] = [
+        MAType::SMA,
+        MAType::EMA,
+        MAType::WMA,
+        MAType::DEMA,
+        MAType::TEMA,
+        MAType::TRIMA,
+        MAType::T3,
+        MAType::RMA,
+        MAType::KAMA,
+        MAType::MAMA,
+    ];
+
+    fn input() -> Vec<TAFloat> {
+        (0..80)
+            .map(|i| {
+                let t = i as TAFloat;
+                3.0f64.mul_add((t * 0.37).sin(), 0.2f64.mul_add(t, 100.0))
+            })
+            .collect()
+    }
+
+    /// Runs the moving average directly through its own module.
+    fn direct(input: &[TAFloat], opt_period: usize, opt_ma_type: MAType) -> Vec<TAFloat> {
+        let len = input.len();
+        let mut output = vec![0.0; len];
+        let mut scratch: [Vec<TAFloat>; 6] = std::array::from_fn(|_| vec![0.0; len]);
+        let [s1, s2, s3, s4, s5, s6] = &mut scratch;
+        match opt_ma_type {
+            MAType::SMA => sma::sma(input, opt_period, &mut output).unwrap(),
+            MAType::EMA => ema::ema(input, opt_period, None, &mut output).unwrap(),
+            MAType::WMA => wma::wma(input, opt_period, &mut output).unwrap(),
+            MAType::DEMA => dema::dema(input, opt_period, &mut output, s1, s2).unwrap(),
+            MAType::TEMA => tema::tema(input, opt_period, &mut output, s1, s2, s3).unwrap(),
+            MAType::TRIMA => trima::trima(input, opt_period, s1, &mut output).unwrap(),
+            MAType::T3 => {
+                t3::t3(input, opt_period, 0.7, &mut output, s1, s2, s3, s4, s5, s6).unwrap();
+            }
+            MAType::RMA => rma::rma(input, opt_period, &mut output).unwrap(),
+            MAType::KAMA => kama::kama(input, opt_period, 2, 30, &mut output).unwrap(),
+            MAType::MAMA => mama::mama(input, 0.5, 0.05, &mut output, s1).unwrap(),
+        }
+        output
+    }
+
+    #[test]
+    fn test_ma_calculation() {
+        let input = input();
+        let opt_period = 5;
+
+        for ma_type in MA_TYPES {
+            let lookback = lookback(opt_period, ma_type).unwrap();
+            let mut output = vec![0.0; input.len()];
+            ma(&input, opt_period, ma_type, &mut output).unwrap();
+            let expected = direct(&input, opt_period, ma_type);
+
+            assert!(
+                output[..lookback].iter().all(|value| value.is_nan()),
+                "{ma_type:?}"
+            );
+            for i in lookback..input.len() {
+                assert_relative_eq!(output[i], expected[i], epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ma_identity() {
+        let input = input();
+        for ma_type in MA_TYPES {
+            assert_eq!(lookback(1, ma_type).unwrap(), 0);
+            let mut output = vec![0.0; input.len()];
+            ma(&input, 1, ma_type, &mut output).unwrap();
+            assert_eq!(output, input);
+
+            let mut ma = Ma::new(1, ma_type).unwrap();
+            assert!(!ma.is_ready());
+            assert_eq!(ma.update_value(input[0]).unwrap(), input[0]);
+            assert!(ma.is_ready());
+        }
+    }
+
+    #[test]
+    fn test_ma_indicator() {
+        let input = input();
+        let opt_period = 5;
+
+        for ma_type in MA_TYPES {
+            let mut output = vec![0.0; input.len()];
+            ma(&input, opt_period, ma_type, &mut output).unwrap();
+
+            let mut indicator = Ma::new(opt_period, ma_type).unwrap();
+            assert_eq!(indicator.lookback(), lookback(opt_period, ma_type).unwrap());
+            for (i, &price) in input.iter().enumerate() {
+                let value = indicator.update(&Bar::from(price)).unwrap();
+                if output[i].is_nan() {
+                    assert!(value.is_nan(), "{ma_type:?} at {i}");
+                } else {
+                    assert_relative_eq!(value, output[i], epsilon = 1e-9);
+                }
+                assert_eq!(indicator.is_ready(), !output[i].is_nan());
+            }
+
+            indicator.reset();
+            assert!(!indicator.is_ready());
+            assert!(indicator.update(&Bar::from(input[0])).unwrap().is_nan());
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use super::ema;
-use crate::{KandError, TAFloat};
+use super::ema::{self, Ema};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculate the lookback period required for MACD calculation
 ///
@@ -280,3 +280,126 @@ pub fn macd_inc(
 
     Ok((macd, signal, histogram))
 }
+
+/// Streaming MACD that owns the fast, slow and signal EMAs.
+///
+/// Reads [`Bar::close`] and yields `(macd_line, signal_line, histogram)`. All three values are
+/// `NaN` until the signal line is seeded, matching [`macd`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Macd {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    signal_ema: Ema,
+}
+
+impl Macd {
+    /// Creates a streaming MACD.
+    ///
+    /// # Arguments
+    /// * `opt_fast_period` - Period for fast EMA calculation (typically 12)
+    /// * `opt_slow_period` - Period for slow EMA calculation (typically 26)
+    /// * `opt_signal_period` - Period for signal line calculation (typically 9)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is less than 2
+    pub fn new(
+        opt_fast_period: usize,
+        opt_slow_period: usize,
+        opt_signal_period: usize,
+    ) -> Result<Self, KandError> {
+        lookback(opt_fast_period, opt_slow_period, opt_signal_period)?;
+        Ok(Self {
+            fast_ema: Ema::new(opt_fast_period, None)?,
+            slow_ema: Ema::new(opt_slow_period, None)?,
+            signal_ema: Ema::new(opt_signal_period, None)?,
+        })
+    }
+}
+
+impl Indicator for Macd {
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        let fast = self.fast_ema.update_value(input.close)?;
+        let slow = self.slow_ema.update_value(input.close)?;
+        if !self.slow_ema.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+        }
+        let macd = fast - slow;
+        let signal = self.signal_ema.update_value(macd)?;
+        if !self.signal_ema.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+        }
+        Ok((macd, signal, macd - signal))
+    }
+
+    fn lookback(&self) -> usize {
+        self.slow_ema.lookback() + self.signal_ema.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.signal_ema.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.signal_ema.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_macd_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1,
+        ];
+        let (opt_fast_period, opt_slow_period, opt_signal_period) = (5, 12, 4);
+        let len = input_price.len();
+        let mut output_macd_line = vec![0.0; len];
+        let mut output_signal_line = vec![0.0; len];
+        let mut output_histogram = vec![0.0; len];
+        let mut output_fast_ema = vec![0.0; len];
+        let mut output_slow_ema = vec![0.0; len];
+        macd(
+            &input_price,
+            opt_fast_period,
+            opt_slow_period,
+            opt_signal_period,
+            &mut output_macd_line,
+            &mut output_signal_line,
+            &mut output_histogram,
+            &mut output_fast_ema,
+            &mut output_slow_ema,
+        )
+        .unwrap();
+
+        let mut indicator = Macd::new(opt_fast_period, opt_slow_period, opt_signal_period).unwrap();
+        let lookback = lookback(opt_fast_period, opt_slow_period, opt_signal_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let (macd_line, signal_line, histogram) = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(macd_line.is_nan() && signal_line.is_nan() && histogram.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(macd_line, output_macd_line[i], epsilon = 0.00001);
+                assert_relative_eq!(signal_line, output_signal_line[i], epsilon = 0.00001);
+                assert_relative_eq!(histogram, output_histogram[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
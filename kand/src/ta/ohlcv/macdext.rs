@@ -0,0 +1,451 @@
+use super::ma::{self, Ma};
+use crate::{
+    KandError, TAFloat,
+    indicator::Indicator,
+    types::{Bar, MAType},
+};
+
+/// Calculate the lookback period required for MACDEXT calculation
+///
+/// The MACD line is valid once both the fast and the slow moving average are, and the signal
+/// line needs its own lookback on top of that.
+///
+/// # Arguments
+/// * `opt_fast_period` - Fast MA period, must be < `slow_period`
+/// * `opt_fast_ma_type` - Moving average type for the fast line
+/// * `opt_slow_period` - Slow MA period, must be > `fast_period`
+/// * `opt_slow_ma_type` - Moving average type for the slow line
+/// * `opt_signal_period` - Signal line period
+/// * `opt_signal_ma_type` - Moving average type for the signal line
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Lookback period if successful
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `fast_period` >= `slow_period` or any period is invalid
+///   for its moving average type
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::macdext, types::MAType};
+/// let lookback =
+///     macdext::lookback(12, MAType::EMA, 26, MAType::EMA, 9, MAType::SMA).unwrap();
+/// assert_eq!(lookback, 33); // 25 (slow EMA) + 8 (signal SMA)
+/// ```
+pub fn lookback(
+    opt_fast_period: usize,
+    opt_fast_ma_type: MAType,
+    opt_slow_period: usize,
+    opt_slow_ma_type: MAType,
+    opt_signal_period: usize,
+    opt_signal_ma_type: MAType,
+) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_fast_period >= opt_slow_period {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let fast_lookback = ma::lookback(opt_fast_period, opt_fast_ma_type)?;
+    let slow_lookback = ma::lookback(opt_slow_period, opt_slow_ma_type)?;
+    let signal_lookback = ma::lookback(opt_signal_period, opt_signal_ma_type)?;
+    Ok(fast_lookback.max(slow_lookback) + signal_lookback)
+}
+
+/// Calculate MACD with a configurable moving average for each line (MACDEXT)
+///
+/// Same construction as [`super::macd::macd`], but the fast, slow and signal lines each use the
+/// moving average selected by their [`MAType`], as in TA-Lib's `MACDEXT`. With EMA for all
+/// three it matches [`super::macd::macd`].
+///
+/// # Mathematical Formula
+/// ```text
+/// Fast MA = MA(price, fast_period, fast_ma_type)
+/// Slow MA = MA(price, slow_period, slow_ma_type)
+/// MACD Line = Fast MA - Slow MA
+/// Signal Line = MA(MACD Line, signal_period, signal_ma_type)
+/// Histogram = MACD Line - Signal Line
+/// ```
+///
+/// # Arguments
+/// * `input_price` - Array of price values
+/// * `opt_fast_period` - Fast MA period (typically 12)
+/// * `opt_fast_ma_type` - Moving average type for the fast line
+/// * `opt_slow_period` - Slow MA period (typically 26)
+/// * `opt_slow_ma_type` - Moving average type for the slow line
+/// * `opt_signal_period` - Signal line period (typically 9)
+/// * `opt_signal_ma_type` - Moving average type for the signal line
+/// * `output_macd_line` - Output buffer for MACD line values
+/// * `output_signal_line` - Output buffer for signal line values
+/// * `output_histogram` - Output buffer for histogram values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok if successful
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If `fast_period` >= `slow_period` or any period is invalid
+///   for its moving average type
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::macdext, types::MAType};
+///
+/// let prices = vec![10.0, 12.0, 15.0, 11.0, 9.0, 10.0, 12.0, 13.0];
+/// let mut macd_line = vec![0.0; prices.len()];
+/// let mut signal_line = vec![0.0; prices.len()];
+/// let mut histogram = vec![0.0; prices.len()];
+///
+/// macdext::macdext(
+///     &prices,
+///     2,
+///     MAType::EMA,
+///     3,
+///     MAType::WMA,
+///     4,
+///     MAType::SMA,
+///     &mut macd_line,
+///     &mut signal_line,
+///     &mut histogram,
+/// )
+/// .unwrap();
+/// ```
+pub fn macdext(
+    input_price: &[TAFloat],
+    opt_fast_period: usize,
+    opt_fast_ma_type: MAType,
+    opt_slow_period: usize,
+    opt_slow_ma_type: MAType,
+    opt_signal_period: usize,
+    opt_signal_ma_type: MAType,
+    output_macd_line: &mut [TAFloat],
+    output_signal_line: &mut [TAFloat],
+    output_histogram: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_price.len();
+    let lookback = lookback(
+        opt_fast_period,
+        opt_fast_ma_type,
+        opt_slow_period,
+        opt_slow_ma_type,
+        opt_signal_period,
+        opt_signal_ma_type,
+    )?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != output_macd_line.len()
+            || len != output_signal_line.len()
+            || len != output_histogram.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_price {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut fast_ma = vec![0.0; len];
+    let mut slow_ma = vec![0.0; len];
+    ma::ma(input_price, opt_fast_period, opt_fast_ma_type, &mut fast_ma)?;
+    ma::ma(input_price, opt_slow_period, opt_slow_ma_type, &mut slow_ma)?;
+
+    // Calculate MACD line where both moving averages are valid
+    let macd_lookback = ma::lookback(opt_fast_period, opt_fast_ma_type)?
+        .max(ma::lookback(opt_slow_period, opt_slow_ma_type)?);
+    for i in macd_lookback..len {
+        output_macd_line[i] = fast_ma[i] - slow_ma[i];
+    }
+
+    // Calculate signal line using non-NaN MACD values
+    ma::ma(
+        &output_macd_line[macd_lookback..],
+        opt_signal_period,
+        opt_signal_ma_type,
+        &mut output_signal_line[macd_lookback..],
+    )?;
+
+    // Calculate histogram
+    for i in lookback..len {
+        output_histogram[i] = output_macd_line[i] - output_signal_line[i];
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_macd_line[i] = TAFloat::NAN;
+        output_signal_line[i] = TAFloat::NAN;
+        output_histogram[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Streaming MACDEXT that owns the fast, slow and signal moving averages.
+///
+/// Reads [`Bar::close`] and yields `(macd_line, signal_line, histogram)`. All three values are
+/// `NaN` until the signal line is seeded, matching [`macdext`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Macdext {
+    lookback: usize,
+    fast_ma: Ma,
+    slow_ma: Ma,
+    signal_ma: Ma,
+}
+
+impl Macdext {
+    /// Creates a streaming MACDEXT.
+    ///
+    /// # Arguments
+    /// * `opt_fast_period` - Period for the fast moving average (typically 12)
+    /// * `opt_fast_ma_type` - Moving average type for the fast line
+    /// * `opt_slow_period` - Period for the slow moving average (typically 26)
+    /// * `opt_slow_ma_type` - Moving average type for the slow line
+    /// * `opt_signal_period` - Period for the signal line (typically 9)
+    /// * `opt_signal_ma_type` - Moving average type for the signal line
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any parameter is out of range (see [`lookback`])
+    pub fn new(
+        opt_fast_period: usize,
+        opt_fast_ma_type: MAType,
+        opt_slow_period: usize,
+        opt_slow_ma_type: MAType,
+        opt_signal_period: usize,
+        opt_signal_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        let lookback = lookback(
+            opt_fast_period,
+            opt_fast_ma_type,
+            opt_slow_period,
+            opt_slow_ma_type,
+            opt_signal_period,
+            opt_signal_ma_type,
+        )?;
+        Ok(Self {
+            lookback,
+            fast_ma: Ma::new(opt_fast_period, opt_fast_ma_type)?,
+            slow_ma: Ma::new(opt_slow_period, opt_slow_ma_type)?,
+            signal_ma: Ma::new(opt_signal_period, opt_signal_ma_type)?,
+        })
+    }
+}
+
+impl Indicator for Macdext {
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        let fast = self.fast_ma.update_value(input.close)?;
+        let slow = self.slow_ma.update_value(input.close)?;
+        if !self.fast_ma.is_ready() || !self.slow_ma.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+        }
+        let macd = fast - slow;
+        let signal = self.signal_ma.update_value(macd)?;
+        if !self.signal_ma.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+        }
+        Ok((macd, signal, macd - signal))
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+
+    fn is_ready(&self) -> bool {
+        self.signal_ma.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::ohlcv::macd;
+
+    const INPUT_PRICE: [TAFloat; 35] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1,
+    ];
+
+    #[test]
+    fn test_macdext_calculation() {
+        let len = INPUT_PRICE.len();
+        let (opt_fast_period, opt_slow_period, opt_signal_period) = (5, 12, 4);
+
+        // All-EMA MACDEXT is plain MACD
+        let mut macd_line = vec![0.0; len];
+        let mut signal_line = vec![0.0; len];
+        let mut histogram = vec![0.0; len];
+        macdext(
+            &INPUT_PRICE,
+            opt_fast_period,
+            MAType::EMA,
+            opt_slow_period,
+            MAType::EMA,
+            opt_signal_period,
+            MAType::EMA,
+            &mut macd_line,
+            &mut signal_line,
+            &mut histogram,
+        )
+        .unwrap();
+
+        let mut expected_macd = vec![0.0; len];
+        let mut expected_signal = vec![0.0; len];
+        let mut expected_histogram = vec![0.0; len];
+        let mut fast_ema = vec![0.0; len];
+        let mut slow_ema = vec![0.0; len];
+        macd::macd(
+            &INPUT_PRICE,
+            opt_fast_period,
+            opt_slow_period,
+            opt_signal_period,
+            &mut expected_macd,
+            &mut expected_signal,
+            &mut expected_histogram,
+            &mut fast_ema,
+            &mut slow_ema,
+        )
+        .unwrap();
+
+        let macd_lookback =
+            macd::lookback(opt_fast_period, opt_slow_period, opt_signal_period).unwrap();
+        for i in 0..len {
+            if i < macd_lookback {
+                assert!(macd_line[i].is_nan() && signal_line[i].is_nan() && histogram[i].is_nan());
+            } else {
+                assert_relative_eq!(macd_line[i], expected_macd[i], epsilon = 1e-9);
+                assert_relative_eq!(signal_line[i], expected_signal[i], epsilon = 1e-9);
+                assert_relative_eq!(histogram[i], expected_histogram[i], epsilon = 1e-9);
+            }
+        }
+
+        // Signal is the SMA of the valid MACD values
+        macdext(
+            &INPUT_PRICE,
+            opt_fast_period,
+            MAType::WMA,
+            opt_slow_period,
+            MAType::SMA,
+            opt_signal_period,
+            MAType::SMA,
+            &mut macd_line,
+            &mut signal_line,
+            &mut histogram,
+        )
+        .unwrap();
+        let sma_lookback = lookback(
+            opt_fast_period,
+            MAType::WMA,
+            opt_slow_period,
+            MAType::SMA,
+            opt_signal_period,
+            MAType::SMA,
+        )
+        .unwrap();
+        assert_eq!(sma_lookback, 14);
+        assert!(signal_line[sma_lookback - 1].is_nan());
+
+        let mut fast_ma = vec![0.0; len];
+        let mut slow_ma = vec![0.0; len];
+        ma::ma(&INPUT_PRICE, opt_fast_period, MAType::WMA, &mut fast_ma).unwrap();
+        ma::ma(&INPUT_PRICE, opt_slow_period, MAType::SMA, &mut slow_ma).unwrap();
+        for i in sma_lookback..len {
+            let expected = (i + 1 - opt_signal_period..=i)
+                .map(|j| fast_ma[j] - slow_ma[j])
+                .sum::<TAFloat>()
+                / opt_signal_period as TAFloat;
+            assert_relative_eq!(signal_line[i], expected, epsilon = 1e-9);
+            assert_relative_eq!(histogram[i], macd_line[i] - expected, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_macdext_indicator() {
+        let len = INPUT_PRICE.len();
+        let (opt_fast_period, opt_slow_period, opt_signal_period) = (6, 12, 4);
+        let ma_types = (MAType::DEMA, MAType::SMA, MAType::EMA);
+
+        let mut macd_line = vec![0.0; len];
+        let mut signal_line = vec![0.0; len];
+        let mut histogram = vec![0.0; len];
+        macdext(
+            &INPUT_PRICE,
+            opt_fast_period,
+            ma_types.0,
+            opt_slow_period,
+            ma_types.1,
+            opt_signal_period,
+            ma_types.2,
+            &mut macd_line,
+            &mut signal_line,
+            &mut histogram,
+        )
+        .unwrap();
+
+        let mut indicator = Macdext::new(
+            opt_fast_period,
+            ma_types.0,
+            opt_slow_period,
+            ma_types.1,
+            opt_signal_period,
+            ma_types.2,
+        )
+        .unwrap();
+        let lookback = indicator.lookback();
+        for (i, &price) in INPUT_PRICE.iter().enumerate() {
+            let (macd, signal, hist) = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(macd.is_nan() && signal.is_nan() && hist.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(macd, macd_line[i], epsilon = 1e-9);
+                assert_relative_eq!(signal, signal_line[i], epsilon = 1e-9);
+                assert_relative_eq!(hist, histogram[i], epsilon = 1e-9);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_macdext_invalid_periods() {
+        assert!(matches!(
+            lookback(26, MAType::EMA, 12, MAType::EMA, 9, MAType::EMA),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
@@ -0,0 +1,475 @@
+use crate::{KandError, TAFloat, hilbert::HilbertTransform, indicator::Indicator, types::Bar};
+
+/// Bars that only prime the Hilbert transform's price smoother, as in TA-Lib.
+const SMOOTHER_WARMUP: usize = 9;
+
+/// Returns the lookback period required for MAMA calculation.
+///
+/// # Description
+/// MAMA needs 32 bars to prime the price smoother and the Hilbert transform, matching TA-Lib.
+/// The lookback does not depend on the limits.
+///
+/// # Arguments
+/// * `opt_fast_limit` - Upper bound of the adaptive alpha. Must be in `[0.01, 0.99]`.
+/// * `opt_slow_limit` - Lower bound of the adaptive alpha. Must be in `[0.01, 0.99]`.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success, or error on failure.
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If either limit is outside `[0.01, 0.99]`.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::mama;
+/// let lookback = mama::lookback(0.5, 0.05).unwrap();
+/// assert_eq!(lookback, 32);
+/// ```
+pub fn lookback(opt_fast_limit: TAFloat, opt_slow_limit: TAFloat) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if !(0.01..=0.99).contains(&opt_fast_limit) || !(0.01..=0.99).contains(&opt_slow_limit) {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(32)
+}
+
+/// Calculates the MESA Adaptive Moving Average (MAMA) and Following Adaptive Moving Average (FAMA).
+///
+/// # Description
+/// MAMA, by John Ehlers, adapts its smoothing factor to the rate of change of the phase measured
+/// by a Hilbert transform homodyne discriminator. When the phase advances slowly (a trend), alpha
+/// falls towards `opt_slow_limit`; when it jumps (a cycle turning), alpha rises towards
+/// `opt_fast_limit`. FAMA applies half the alpha to MAMA, so crossings mark trend changes. The
+/// calculation matches TA-Lib's `MAMA`, including its zero-seeded start.
+///
+/// # Mathematical Formula
+/// ```text
+/// Phase = atan(Q1 / I1) in degrees
+/// DeltaPhase = max(Phase(previous) - Phase, 1)
+/// Alpha = max(FastLimit / DeltaPhase, SlowLimit)
+/// MAMA = Alpha * Price + (1 - Alpha) * MAMA(previous)
+/// FAMA = 0.5 * Alpha * MAMA + (1 - 0.5 * Alpha) * FAMA(previous)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Smooth prices with a 4-bar WMA and run the Hilbert transform ([`HilbertTransform`])
+/// 2. Derive the phase from the in-phase and quadrature components
+/// 3. Turn the phase change into an adaptive alpha bounded by the limits
+/// 4. Update MAMA and FAMA, writing NaN before the lookback period
+///
+/// # Arguments
+/// * `input_prices` - Array of price values
+/// * `opt_fast_limit` - Upper bound of the adaptive alpha (typically 0.5)
+/// * `opt_slow_limit` - Lower bound of the adaptive alpha (typically 0.05)
+/// * `output_mama` - Array to store MAMA values. Must match input length
+/// * `output_fama` - Array to store FAMA values. Must match input length
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit on success, or error on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output lengths don't match input
+/// * `KandError::InvalidParameter` - If either limit is outside `[0.01, 0.99]`
+/// * `KandError::InsufficientData` - If input length <= lookback
+/// * `KandError::NaNDetected` - If any input price is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::mama;
+/// let prices: Vec<f64> = (0..40).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_mama = vec![0.0; prices.len()];
+/// let mut output_fama = vec![0.0; prices.len()];
+///
+/// mama::mama(&prices, 0.5, 0.05, &mut output_mama, &mut output_fama).unwrap();
+/// assert!(output_mama[31].is_nan());
+/// assert!(!output_fama[32].is_nan());
+/// ```
+pub fn mama(
+    input_prices: &[TAFloat],
+    opt_fast_limit: TAFloat,
+    opt_slow_limit: TAFloat,
+    output_mama: &mut [TAFloat],
+    output_fama: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_fast_limit, opt_slow_limit)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_mama.len() != len || output_fama.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut hilbert = HilbertTransform::new(SMOOTHER_WARMUP);
+    let (mut prev_mama, mut prev_fama, mut prev_phase) = (0.0, 0.0, 0.0);
+    for i in 0..len {
+        (prev_mama, prev_fama, prev_phase) = mama_step(
+            input_prices[i],
+            prev_mama,
+            prev_fama,
+            prev_phase,
+            &mut hilbert,
+            opt_fast_limit,
+            opt_slow_limit,
+        );
+        if i >= lookback {
+            output_mama[i] = prev_mama;
+            output_fama[i] = prev_fama;
+        } else {
+            output_mama[i] = TAFloat::NAN;
+            output_fama[i] = TAFloat::NAN;
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculates the next MAMA and FAMA values incrementally.
+///
+/// # Description
+/// MAMA depends on the full Hilbert transform state, so the discriminator is carried in a
+/// [`HilbertTransform`] that this function advances in place, while the scalar state is passed in
+/// and returned like other `*_inc` functions. Start a series with
+/// `HilbertTransform::new(9)` and `0.0` for `prev_mama`, `prev_fama` and `prev_phase`; folding
+/// this function over the prices then reproduces [`mama`], whose values are valid from index
+/// [`lookback`] onwards. While the transform is still priming, the previous values are returned
+/// unchanged.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `prev_mama` - The previous MAMA value
+/// * `prev_fama` - The previous FAMA value
+/// * `prev_phase` - The previous phase in degrees
+/// * `hilbert` - Hilbert transform state, updated in place
+/// * `opt_fast_limit` - Upper bound of the adaptive alpha (typically 0.5)
+/// * `opt_slow_limit` - Lower bound of the adaptive alpha (typically 0.05)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (MAMA, FAMA, phase) on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If either limit is outside `[0.01, 0.99]`
+/// * `KandError::NaNDetected` - If any input is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::{hilbert::HilbertTransform, ohlcv::mama};
+///
+/// let mut hilbert = HilbertTransform::new(9);
+/// let (mut prev_mama, mut prev_fama, mut prev_phase) = (0.0, 0.0, 0.0);
+/// for i in 0..40 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     (prev_mama, prev_fama, prev_phase) =
+///         mama::mama_inc(price, prev_mama, prev_fama, prev_phase, &mut hilbert, 0.5, 0.05)
+///             .unwrap();
+/// }
+/// ```
+pub fn mama_inc(
+    input_price: TAFloat,
+    prev_mama: TAFloat,
+    prev_fama: TAFloat,
+    prev_phase: TAFloat,
+    hilbert: &mut HilbertTransform,
+    opt_fast_limit: TAFloat,
+    opt_slow_limit: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    lookback(opt_fast_limit, opt_slow_limit)?;
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan() || prev_mama.is_nan() || prev_fama.is_nan() || prev_phase.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(mama_step(
+        input_price,
+        prev_mama,
+        prev_fama,
+        prev_phase,
+        hilbert,
+        opt_fast_limit,
+        opt_slow_limit,
+    ))
+}
+
+/// Advances the transform and applies the phase-adaptive alpha to MAMA and FAMA.
+fn mama_step(
+    price: TAFloat,
+    prev_mama: TAFloat,
+    prev_fama: TAFloat,
+    prev_phase: TAFloat,
+    hilbert: &mut HilbertTransform,
+    fast_limit: TAFloat,
+    slow_limit: TAFloat,
+) -> (TAFloat, TAFloat, TAFloat) {
+    let Some(step) = hilbert.update(price) else {
+        return (prev_mama, prev_fama, prev_phase);
+    };
+
+    let phase = if step.in_phase == 0.0 {
+        0.0
+    } else {
+        (step.quadrature / step.in_phase).atan().to_degrees()
+    };
+    let delta_phase = (prev_phase - phase).max(1.0);
+    let alpha = if delta_phase > 1.0 {
+        (fast_limit / delta_phase).max(slow_limit)
+    } else {
+        fast_limit
+    };
+
+    let mama = (price - prev_mama).mul_add(alpha, prev_mama);
+    let fama = (mama - prev_fama).mul_add(0.5 * alpha, prev_fama);
+    (mama, fama, phase)
+}
+
+/// Streaming MAMA/FAMA that owns the Hilbert transform and the previous phase.
+///
+/// Reads [`Bar::close`] and returns `(MAMA, FAMA)`. Both are `NaN` for the first
+/// [`lookback`] prices, after which every pair matches [`mama`] for the same series.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::mama::Mama};
+///
+/// let mut mama = Mama::new(0.5, 0.05).unwrap();
+/// for i in 0..40 {
+///     let (mama_value, fama_value) = mama
+///         .update(&Bar::from(100.0 + (i as f64 * 0.3).sin()))
+///         .unwrap();
+/// }
+/// assert!(mama.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mama {
+    opt_fast_limit: TAFloat,
+    opt_slow_limit: TAFloat,
+    hilbert: HilbertTransform,
+    prev_mama: TAFloat,
+    prev_fama: TAFloat,
+    prev_phase: TAFloat,
+}
+
+impl Mama {
+    /// Creates a streaming MAMA/FAMA.
+    ///
+    /// # Arguments
+    /// * `opt_fast_limit` - Upper bound of the adaptive alpha (typically 0.5)
+    /// * `opt_slow_limit` - Lower bound of the adaptive alpha (typically 0.05)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If either limit is outside `[0.01, 0.99]`
+    pub fn new(opt_fast_limit: TAFloat, opt_slow_limit: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_fast_limit, opt_slow_limit)?;
+        Ok(Self {
+            opt_fast_limit,
+            opt_slow_limit,
+            hilbert: HilbertTransform::new(SMOOTHER_WARMUP),
+            prev_mama: 0.0,
+            prev_fama: 0.0,
+            prev_phase: 0.0,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns `(MAMA, FAMA)` for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<(TAFloat, TAFloat), KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        (self.prev_mama, self.prev_fama, self.prev_phase) = mama_step(
+            input_price,
+            self.prev_mama,
+            self.prev_fama,
+            self.prev_phase,
+            &mut self.hilbert,
+            self.opt_fast_limit,
+            self.opt_slow_limit,
+        );
+        if !self.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN));
+        }
+        Ok((self.prev_mama, self.prev_fama))
+    }
+}
+
+impl Indicator for Mama {
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<(TAFloat, TAFloat), KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        32
+    }
+
+    fn is_ready(&self) -> bool {
+        self.hilbert.count() > 32
+    }
+
+    fn reset(&mut self) {
+        self.hilbert.reset();
+        self.prev_mama = 0.0;
+        self.prev_fama = 0.0;
+        self.prev_phase = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 60] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3, 35154.0, 35216.3, 35211.8, 35158.4,
+        35172.0, 35176.7, 35113.3, 35114.7, 35129.3, 35094.6, 35114.4, 35094.5, 35116.0, 35105.4,
+        35050.7, 35031.3, 35008.1, 35021.4, 35048.4, 35080.1, 35043.6, 34962.7, 34970.1, 34980.1,
+    ];
+
+    #[test]
+    fn test_mama_calculation() {
+        let mut output_mama = vec![0.0; INPUT_PRICES.len()];
+        let mut output_fama = vec![0.0; INPUT_PRICES.len()];
+
+        mama(&INPUT_PRICES, 0.5, 0.05, &mut output_mama, &mut output_fama).unwrap();
+
+        // First 32 values should be NaN
+        for i in 0..32 {
+            assert!(output_mama[i].is_nan());
+            assert!(output_fama[i].is_nan());
+        }
+
+        // TA-Lib MAMA(fastlimit=0.5, slowlimit=0.05)
+        let expected_values = [
+            (35_058.565_832_104_85, 32_806.309_979_143_11),
+            (35_061.877_540_499_6, 32_862.699_168_177_02),
+            (35_115.988_770_249_794, 33_426.021_568_695_214),
+            (35_120.954_331_737_3, 33_468.394_887_771_27),
+            (35_137.477_165_868_65, 33_885.665_457_295_62),
+            (35_153.480_398_116_69, 34_014.366_341_811_41),
+            (35_182.640_199_058_35, 34_306.434_806_123_14),
+            (35_179.357_487_864_15, 34_365.542_263_370_54),
+            (35_178.989_613_470_94, 34_385.878_447_123_05),
+            (35_178.875_132_797_395, 34_405.703_364_264_904),
+            (35_146.087_566_398_695, 34_590.799_414_798_355),
+            (35_144.518_188_078_76, 34_604.642_384_130_36),
+            (35_136.909_094_039_38, 34_737.709_061_607_62),
+            (35_134.793_639_337_41, 34_747.636_176_050_86),
+            (35_124.596_819_668_7, 34_841.876_336_955_32),
+            (35_123.091_978_685_26, 34_848.906_727_998_56),
+            (35_122.737_379_751, 34_855.752_494_292_37),
+            (35_121.870_510_763_445, 34_862.405_444_704_15),
+            (35_086.285_255_381_72, 34_918.375_397_373_544),
+            (35_083.535_992_612_63, 34_922.504_412_254_52),
+            (35_045.817_996_306_316, 34_953.332_808_267_47),
+            (35_033.608_998_153_155, 34_973.401_855_738_89),
+            (35_041.004_499_076_575, 34_990.302_516_573_31),
+            (35_042.959_274_122_74, 34_991.618_935_512_05),
+            (35_042.991_310_416_6, 34_992.903_244_884_66),
+            (35_038.976_744_895_765, 34_994.055_082_384_93),
+            (35_035.532_907_650_97, 34_995.092_028_016_58),
+            (35_032.761_262_268_42, 34_996.033_758_872_88),
+        ];
+
+        for (i, &(expected_mama, expected_fama)) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_mama[i + 32], expected_mama, epsilon = 0.00001);
+            assert_relative_eq!(output_fama[i + 32], expected_fama, epsilon = 0.00001);
+        }
+
+        // Incremental calculation should match the batch output
+        let mut hilbert = HilbertTransform::new(9);
+        let (mut prev_mama, mut prev_fama, mut prev_phase) = (0.0, 0.0, 0.0);
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            (prev_mama, prev_fama, prev_phase) = mama_inc(
+                price,
+                prev_mama,
+                prev_fama,
+                prev_phase,
+                &mut hilbert,
+                0.5,
+                0.05,
+            )
+            .unwrap();
+            if i >= 32 {
+                assert_relative_eq!(prev_mama, output_mama[i], epsilon = 0.00001);
+                assert_relative_eq!(prev_fama, output_fama[i], epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mama_indicator() {
+        let mut output_mama = vec![0.0; INPUT_PRICES.len()];
+        let mut output_fama = vec![0.0; INPUT_PRICES.len()];
+        mama(&INPUT_PRICES, 0.5, 0.05, &mut output_mama, &mut output_fama).unwrap();
+
+        let mut indicator = Mama::new(0.5, 0.05).unwrap();
+        assert_eq!(indicator.lookback(), lookback(0.5, 0.05).unwrap());
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let (mama_value, fama_value) = indicator.update(&Bar::from(price)).unwrap();
+            if i < 32 {
+                assert!(mama_value.is_nan() && fama_value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(mama_value, output_mama[i], epsilon = 0.00001);
+                assert_relative_eq!(fama_value, output_fama[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_mama_invalid_limits() {
+        assert!(matches!(
+            lookback(0.0, 0.05),
+            Err(KandError::InvalidParameter)
+        ));
+        assert!(matches!(
+            Mama::new(0.5, 1.0),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar, window::RollingExtremum};
 
 /// Calculates the lookback period required for Midpoint calculation.
 ///
@@ -51,7 +51,7 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
 ///
 /// # Calculation Steps
 /// 1. For each period window:
-///    - Find the highest and lowest prices
+///    - Find the highest and lowest prices with a [`RollingExtremum`] (O(1) amortized)
 ///    - Calculate midpoint as their average
 /// 2. Fill initial values before lookback period with NaN
 ///
@@ -130,15 +130,13 @@ pub fn midpoint(
     }
 
     // Calculate midpoint for each window
-    for i in lookback..len {
-        let start_idx = i + 1 - opt_period;
-        let mut highest = input_price[start_idx];
-        let mut lowest = input_price[start_idx];
-
-        // Find highest and lowest prices in the period
-        for &price in input_price.iter().take(i + 1).skip(start_idx + 1) {
-            highest = highest.max(price);
-            lowest = lowest.min(price);
+    let mut highest_window = RollingExtremum::highest(opt_period)?;
+    let mut lowest_window = RollingExtremum::lowest(opt_period)?;
+    for (i, &price) in input_price.iter().enumerate() {
+        let (highest, _) = highest_window.push(price);
+        let (lowest, _) = lowest_window.push(price);
+        if i < lookback {
+            continue;
         }
 
         output_highest[i] = highest;
@@ -161,6 +159,8 @@ pub fn midpoint(
 /// # Description
 /// Provides an optimized way to calculate the next Midpoint value when new data arrives,
 /// without recalculating the entire series. Updates the highest and lowest values with new price.
+/// Prices leaving the window are not removed, so the result only matches [`midpoint`] while the
+/// extremes stay inside the period; use [`Midpoint`] for exact streaming results.
 ///
 /// # Mathematical Formula
 /// ```text
@@ -188,8 +188,13 @@ pub fn midpoint(
 /// * `KandError::InvalidParameter` - If `opt_period` is less than 2
 /// * `KandError::NaNDetected` - If any input value is NaN (when `check-nan` enabled)
 ///
+/// # Migration
+/// Replace the `(prev_highest, prev_lowest)` state with a [`Midpoint`] built from the same
+/// period and call [`Indicator::update`] once per close.
+///
 /// # Examples
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::ohlcv::midpoint;
 ///
 /// let current_price = 15.0;
@@ -200,6 +205,7 @@ pub fn midpoint(
 /// let (midpoint, new_highest, new_lowest) =
 ///     midpoint::midpoint_inc(current_price, prev_highest, prev_lowest, period).unwrap();
 /// ```
+#[deprecated(note = "prices leaving the window are never dropped; use `Midpoint`")]
 pub const fn midpoint_inc(
     input_price: TAFloat,
     prev_highest: TAFloat,
@@ -227,6 +233,68 @@ pub const fn midpoint_inc(
     Ok((midpoint, new_highest, new_lowest))
 }
 
+/// Streaming Midpoint over the last `opt_period` closes, tracked with [`RollingExtremum`].
+///
+/// Folding [`Indicator::update`] over a series reproduces [`midpoint`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Midpoint {
+    opt_period: usize,
+    highest: RollingExtremum,
+    lowest: RollingExtremum,
+}
+
+impl Midpoint {
+    /// Creates a streaming Midpoint.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The lookback window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            highest: RollingExtremum::highest(opt_period)?,
+            lowest: RollingExtremum::lowest(opt_period)?,
+        })
+    }
+}
+
+impl Indicator for Midpoint {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.close.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let (highest, _) = self.highest.push(input.close);
+        let (lowest, _) = self.lowest.push(input.close);
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(TAFloat::midpoint(highest, lowest))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.highest.is_full()
+    }
+
+    fn reset(&mut self) {
+        self.highest.clear();
+        self.lowest.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -234,6 +302,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_midpoint_calculation() {
         let input_price = vec![
             35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
@@ -284,4 +353,42 @@ fn test_midpoint_calculation() {
             prev_lowest = new_lowest;
         }
     }
+
+    #[test]
+    fn test_midpoint_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let mut output_highest = vec![0.0; input_price.len()];
+        let mut output_lowest = vec![0.0; input_price.len()];
+        let mut output_midpoint = vec![0.0; input_price.len()];
+        midpoint(
+            &input_price,
+            opt_period,
+            &mut output_midpoint,
+            &mut output_highest,
+            &mut output_lowest,
+        )
+        .unwrap();
+
+        let mut indicator = Midpoint::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_midpoint[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,8 +1,4 @@
-use crate::{
-    TAFloat,
-    error::KandError,
-    helper::{highest_bars, lowest_bars},
-};
+use crate::{TAFloat, error::KandError, indicator::Indicator, types::Bar, window::RollingExtremum};
 
 /// Calculates the lookback period required for Midpoint Price calculation.
 ///
@@ -46,7 +42,7 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
 /// - i is the current index
 ///
 /// # Calculation Steps
-/// 1. Find highest high price in the period window
+/// 1. Find highest high price in the period window (tracked with a [`RollingExtremum`])
 /// 2. Find lowest low price in the period window
 /// 3. Calculate arithmetic mean of these values
 ///
@@ -124,12 +120,14 @@ pub fn midprice(
     }
 
     // Calculate midpoint price for each window
-    for i in lookback..len {
-        let highest_idx = highest_bars(input_high, i, opt_period)?;
-        let lowest_idx = lowest_bars(input_low, i, opt_period)?;
-
-        let highest_high = input_high[i - highest_idx];
-        let lowest_low = input_low[i - lowest_idx];
+    let mut highs = RollingExtremum::highest(opt_period)?;
+    let mut lows = RollingExtremum::lowest(opt_period)?;
+    for i in 0..len {
+        let (highest_high, _) = highs.push(input_high[i]);
+        let (lowest_low, _) = lows.push(input_low[i]);
+        if i < lookback {
+            continue;
+        }
 
         output_highest_high[i] = highest_high;
         output_lowest_low[i] = lowest_low;
@@ -149,7 +147,9 @@ pub fn midprice(
 /// Incrementally calculates the next Midpoint Price value.
 ///
 /// Provides optimized calculation of the next value when new data arrives, avoiding
-/// recalculation of the entire series.
+/// recalculation of the entire series. Bars leaving the window are not removed, so the result
+/// only matches [`midprice`] while the extremes stay inside the period; use [`Midprice`] for
+/// exact streaming results.
 ///
 /// # Arguments
 /// * `input_high` - Current high price
@@ -165,8 +165,13 @@ pub fn midprice(
 /// * `KandError::InvalidParameter` - If period is less than 2
 /// * `KandError::NaNDetected` - If any input contains NaN (with `check-nan`)
 ///
+/// # Migration
+/// Replace the `(prev_highest_high, prev_lowest_low)` state with a [`Midprice`] built from the
+/// same period; it keeps the last `opt_period` highs and lows itself.
+///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::ohlcv::midprice;
 ///
 /// let (midprice, highest, lowest) = midprice::midprice_inc(
@@ -178,6 +183,7 @@ pub fn midprice(
 /// )
 /// .unwrap();
 /// ```
+#[deprecated(note = "bars leaving the window are never dropped; use `Midprice`")]
 pub const fn midprice_inc(
     input_high: TAFloat,
     input_low: TAFloat,
@@ -210,6 +216,69 @@ pub const fn midprice_inc(
     Ok((midprice, new_highest_high, new_lowest_low))
 }
 
+/// Streaming Midpoint Price over the last `opt_period` highs and lows, tracked with
+/// [`RollingExtremum`].
+///
+/// Folding [`Indicator::update`] over a series reproduces [`midprice`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Midprice {
+    opt_period: usize,
+    highs: RollingExtremum,
+    lows: RollingExtremum,
+}
+
+impl Midprice {
+    /// Creates a streaming Midpoint Price.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The lookback window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            highs: RollingExtremum::highest(opt_period)?,
+            lows: RollingExtremum::lowest(opt_period)?,
+        })
+    }
+}
+
+impl Indicator for Midprice {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.high.is_nan() || input.low.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let (highest_high, _) = self.highs.push(input.high);
+        let (lowest_low, _) = self.lows.push(input.low);
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(TAFloat::midpoint(highest_high, lowest_low))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.highs.is_full()
+    }
+
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -217,6 +286,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_midprice_calculation() {
         let input_high = vec![
             35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
@@ -279,4 +349,66 @@ fn test_midprice_calculation() {
             prev_lowest_low = new_lowest_low;
         }
     }
+
+    #[test]
+    fn test_midprice_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 14;
+        let mut output_highest_high = vec![0.0; input_high.len()];
+        let mut output_lowest_low = vec![0.0; input_high.len()];
+        let len = input_high.len();
+        let mut output_midprice = vec![0.0; len];
+        midprice(
+            &input_high,
+            &input_low,
+            opt_period,
+            &mut output_midprice,
+            &mut output_highest_high,
+            &mut output_lowest_low,
+        )
+        .unwrap();
+
+        let mut indicator = Midprice::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_midprice[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
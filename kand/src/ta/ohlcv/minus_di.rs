@@ -1,5 +1,5 @@
 use super::trange;
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculates the lookback period required for -DI (Minus Directional Indicator) calculation.
 ///
@@ -345,6 +345,98 @@ pub fn minus_di_inc(
     ))
 }
 
+/// Streaming Minus Directional Indicator that owns the smoothed Minus DM and True Range.
+///
+/// Folding [`Indicator::update`] over a series reproduces [`minus_di`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinusDi {
+    opt_period: usize,
+    count: usize,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    prev_close: TAFloat,
+    smoothed_minus_dm: TAFloat,
+    smoothed_tr: TAFloat,
+}
+
+impl MinusDi {
+    /// Creates a streaming Minus DI.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            count: 0,
+            prev_high: TAFloat::NAN,
+            prev_low: TAFloat::NAN,
+            prev_close: TAFloat::NAN,
+            smoothed_minus_dm: 0.0,
+            smoothed_tr: 0.0,
+        })
+    }
+}
+
+impl Indicator for MinusDi {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let (prev_high, prev_low, prev_close) = (self.prev_high, self.prev_low, self.prev_close);
+        self.prev_high = input.high;
+        self.prev_low = input.low;
+        self.prev_close = input.close;
+        self.count += 1;
+        if self.count == 1 {
+            return Ok(TAFloat::NAN);
+        }
+        if self.count > self.opt_period {
+            let (minus_di, smoothed_minus_dm, smoothed_tr) = minus_di_inc(
+                input.high,
+                input.low,
+                prev_high,
+                prev_low,
+                prev_close,
+                self.smoothed_minus_dm,
+                self.smoothed_tr,
+                self.opt_period,
+            )?;
+            self.smoothed_minus_dm = smoothed_minus_dm;
+            self.smoothed_tr = smoothed_tr;
+            return Ok(minus_di);
+        }
+
+        let high_diff = input.high - prev_high;
+        let low_diff = prev_low - input.low;
+        if low_diff > high_diff && low_diff > 0.0 {
+            self.smoothed_minus_dm += low_diff;
+        }
+        self.smoothed_tr += trange::trange_inc(input.high, input.low, prev_close)?;
+        Ok(TAFloat::NAN)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.prev_high = TAFloat::NAN;
+        self.prev_low = TAFloat::NAN;
+        self.prev_close = TAFloat::NAN;
+        self.smoothed_minus_dm = 0.0;
+        self.smoothed_tr = 0.0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -440,4 +532,67 @@ fn test_minus_di_calculation() {
             prev_smoothed_tr = new_smoothed_tr;
         }
     }
+
+    #[test]
+    fn test_minus_di_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 14;
+        let mut output_smoothed_dm = vec![0.0; input_high.len()];
+        let mut output_smoothed_tr = vec![0.0; input_high.len()];
+        let len = input_high.len();
+        let mut output_minus_di = vec![0.0; len];
+        minus_di(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            &mut output_minus_di,
+            &mut output_smoothed_dm,
+            &mut output_smoothed_tr,
+        )
+        .unwrap();
+
+        let mut indicator = MinusDi::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_minus_di[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -9,19 +9,50 @@
 pub mod bbands;
 pub mod bop;
 pub mod cci;
+pub mod cdl_dark_cloud_cover;
 pub mod cdl_doji;
 pub mod cdl_dragonfly_doji;
+pub mod cdl_engulfing;
+pub mod cdl_evening_doji_star;
+pub mod cdl_evening_star;
 pub mod cdl_gravestone_doji;
 pub mod cdl_hammer;
+pub mod cdl_harami;
+pub mod cdl_harami_cross;
 pub mod cdl_inverted_hammer;
 pub mod cdl_long_shadow;
 pub mod cdl_marubozu;
+pub mod cdl_morning_doji_star;
+pub mod cdl_morning_star;
+pub mod cdl_piercing;
+pub mod cdl_scan;
+pub mod cdl_three_black_crows;
+pub mod cdl_three_inside;
+pub mod cdl_three_outside;
+pub mod cdl_three_white_soldiers;
 pub mod dema;
+pub mod donchian;
 pub mod dx;
 pub mod ecl;
 pub mod ema;
 pub mod ha;
+pub mod ht_dcperiod;
+pub mod ht_dcphase;
+pub mod ht_phasor;
+pub mod ht_sine;
+pub mod ht_trendline;
+pub mod ht_trendmode;
+pub mod ichimoku;
+pub mod kama;
+pub mod keltner;
+pub mod linearreg;
+pub mod linearreg_angle;
+pub mod linearreg_intercept;
+pub mod linearreg_slope;
+pub mod ma;
 pub mod macd;
+pub mod macdext;
+pub mod mama;
 pub mod medprice;
 pub mod mfi;
 pub mod midpoint;
@@ -48,6 +79,7 @@
 pub mod trange;
 pub mod trima;
 pub mod trix;
+pub mod tsf;
 pub mod typprice;
 pub mod vegas;
 pub mod vwap;
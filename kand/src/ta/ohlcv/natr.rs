@@ -1,5 +1,5 @@
-use super::atr;
-use crate::{KandError, TAFloat};
+use super::atr::{self, Atr};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for NATR calculation
 ///
@@ -215,6 +215,55 @@ pub fn natr_inc(
     Ok((output_atr / input_close) * 100.0)
 }
 
+/// Streaming NATR built on [`Atr`].
+///
+/// Folding [`Indicator::update`] over a series reproduces [`natr`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Natr {
+    atr: Atr,
+}
+
+impl Natr {
+    /// Creates a streaming NATR.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for ATR calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            atr: Atr::new(opt_period)?,
+        })
+    }
+}
+
+impl Indicator for Natr {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let atr = self.atr.update(input)?;
+        if !self.atr.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok((atr / input.close) * 100.0)
+    }
+
+    fn lookback(&self) -> usize {
+        self.atr.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.atr.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.atr.reset();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -296,4 +345,63 @@ fn test_natr_calculation() {
             assert_relative_eq!(output_natr_inc, output_natr[i], epsilon = 0.00001);
         }
     }
+
+    #[test]
+    fn test_natr_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let opt_period = 14;
+        let len = input_high.len();
+        let mut output_natr = vec![0.0; len];
+        natr(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            &mut output_natr,
+        )
+        .unwrap();
+
+        let mut indicator = Natr::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_natr[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
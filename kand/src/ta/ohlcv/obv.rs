@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for On Balance Volume (OBV) calculation
 ///
@@ -178,6 +178,59 @@ pub fn obv_inc(
     })
 }
 
+/// Streaming OBV that owns the previous close and running total.
+///
+/// The first bar's volume starts the total, matching [`obv`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Obv {
+    prev_close: TAFloat,
+    prev_obv: TAFloat,
+}
+
+impl Obv {
+    /// Creates a streaming OBV.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            prev_close: TAFloat::NAN,
+            prev_obv: TAFloat::NAN,
+        }
+    }
+}
+
+impl Default for Obv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for Obv {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.prev_obv = if self.is_ready() {
+            obv_inc(input.close, self.prev_close, input.volume, self.prev_obv)?
+        } else {
+            input.volume
+        };
+        self.prev_close = input.close;
+        Ok(self.prev_obv)
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn is_ready(&self) -> bool {
+        !self.prev_close.is_nan()
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -224,4 +277,55 @@ fn test_obv_calculation() {
             prev_obv = result;
         }
     }
+
+    #[test]
+    fn test_obv_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let len = input_high.len();
+        let mut output_obv = vec![0.0; len];
+        obv(&input_close, &input_volume, &mut output_obv).unwrap();
+
+        let mut indicator = Obv::new();
+        let lookback = lookback().unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_obv[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
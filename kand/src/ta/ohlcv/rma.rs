@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculates the lookback period required for RMA calculation.
 ///
@@ -185,6 +185,98 @@ pub fn rma_inc(
     Ok(input_current.mul_add(alpha, prev_rma * (1.0 - alpha)))
 }
 
+/// Streaming RMA (Wilder's smoothing) that owns its SMA seed and previous RMA value.
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`rma`].
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::rma::Rma};
+///
+/// let mut rma = Rma::new(5).unwrap();
+/// for price in [1.0, 2.0, 3.0, 4.0] {
+///     assert!(rma.update(&Bar::from(price)).unwrap().is_nan());
+/// }
+/// assert_eq!(rma.update(&Bar::from(5.0)).unwrap(), 3.0);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rma {
+    opt_period: usize,
+    count: usize,
+    sum: TAFloat,
+    prev_rma: TAFloat,
+}
+
+impl Rma {
+    /// Creates a streaming RMA.
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` is less than 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            count: 0,
+            sum: 0.0,
+            prev_rma: TAFloat::NAN,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the RMA for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        if self.count >= self.opt_period {
+            self.prev_rma = rma_inc(input_price, self.prev_rma, self.opt_period)?;
+            return Ok(self.prev_rma);
+        }
+
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.sum = if self.count == 0 {
+            input_price
+        } else {
+            self.sum + input_price
+        };
+        self.count += 1;
+
+        if self.count < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        self.prev_rma = self.sum / self.opt_period as TAFloat;
+        Ok(self.prev_rma)
+    }
+}
+
+impl Indicator for Rma {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count >= self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.sum = 0.0;
+        self.prev_rma = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -275,6 +367,7 @@ fn test_rma_edge_cases() {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_rma_with_extended_data() {
         // More extensive test with a larger dataset
         let prices = vec![
@@ -355,4 +448,23 @@ fn test_rma_error_conditions() {
             Err(KandError::InvalidData)
         ));
     }
+
+    #[test]
+    fn test_rma_indicator() {
+        let input = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let opt_period = 5;
+        let mut output_rma = vec![0.0; input.len()];
+        rma(&input, opt_period, &mut output_rma).unwrap();
+
+        let mut indicator = Rma::new(opt_period).unwrap();
+        for (i, &price) in input.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < opt_period - 1 {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_rma[i], epsilon = 1e-12);
+            }
+        }
+    }
 }
@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for ROC (Rate of Change) calculation
 ///
@@ -185,6 +187,61 @@ pub fn roc_inc(current_price: TAFloat, prev_price: TAFloat) -> Result<TAFloat, K
     Ok((current_price - prev_price) / prev_price * 100.0)
 }
 
+/// Streaming Rate of Change that keeps the last `opt_period + 1` closes.
+///
+/// Folding [`Indicator::update`] over a series reproduces [`roc`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Roc {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+}
+
+impl Roc {
+    /// Creates a streaming Rate of Change.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The lookback period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+        })
+    }
+}
+
+impl Indicator for Roc {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.window.push_back(input.close);
+        if self.window.len() > self.opt_period + 1 {
+            self.window.pop_front();
+        }
+        let old_price = match self.window.front() {
+            Some(&old_price) if self.is_ready() => old_price,
+            _ => return Ok(TAFloat::NAN),
+        };
+        roc_inc(input.close, old_price)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -228,4 +285,33 @@ fn test_roc_calculation() {
             assert_relative_eq!(result, output_roc[i], epsilon = 0.0001);
         }
     }
+
+    #[test]
+    fn test_roc_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let mut output_roc = vec![0.0; input_price.len()];
+        roc(&input_price, opt_period, &mut output_roc).unwrap();
+
+        let mut indicator = Roc::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_roc[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
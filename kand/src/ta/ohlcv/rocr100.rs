@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculates the lookback period required for ROCR100 (Rate of Change Ratio * 100) calculation.
 ///
@@ -162,6 +164,61 @@ pub fn rocr100_inc(input: TAFloat, prev: TAFloat) -> Result<TAFloat, KandError>
     Ok((input / prev) * 100.0)
 }
 
+/// Streaming Rate of Change Ratio * 100 that keeps the last `opt_period + 1` closes.
+///
+/// Folding [`Indicator::update`] over a series reproduces [`rocr100`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rocr100 {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+}
+
+impl Rocr100 {
+    /// Creates a streaming Rate of Change Ratio * 100.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The lookback period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+        })
+    }
+}
+
+impl Indicator for Rocr100 {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.window.push_back(input.close);
+        if self.window.len() > self.opt_period + 1 {
+            self.window.pop_front();
+        }
+        let old_price = match self.window.front() {
+            Some(&old_price) if self.is_ready() => old_price,
+            _ => return Ok(TAFloat::NAN),
+        };
+        rocr100_inc(input.close, old_price)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -209,4 +266,33 @@ fn test_rocr100_calculation() {
             assert_relative_eq!(result, output_rocr100[i], epsilon = 0.0001);
         }
     }
+
+    #[test]
+    fn test_rocr100_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 10;
+        let mut output_rocr100 = vec![0.0; input_price.len()];
+        rocr100(&input_price, opt_period, &mut output_rocr100).unwrap();
+
+        let mut indicator = Rocr100::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_rocr100[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
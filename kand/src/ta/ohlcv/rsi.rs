@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculates the lookback period required for RSI (Relative Strength Index) calculation.
 ///
@@ -290,6 +290,105 @@ pub fn rsi_inc(
     Ok((output_rsi, output_avg_gain, output_avg_loss))
 }
 
+/// Streaming RSI that owns the previous close and Wilder-smoothed averages.
+///
+/// The first `opt_period` price changes are averaged to seed the smoothing, exactly as [`rsi`]
+/// does.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rsi {
+    opt_period: usize,
+    count: usize,
+    prev_price: TAFloat,
+    avg_gain: TAFloat,
+    avg_loss: TAFloat,
+}
+
+impl Rsi {
+    /// Creates a streaming RSI.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for RSI calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            count: 0,
+            prev_price: TAFloat::NAN,
+            avg_gain: 0.0,
+            avg_loss: 0.0,
+        })
+    }
+}
+
+impl Indicator for Rsi {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let prev_price = self.prev_price;
+        self.prev_price = input.close;
+        self.count += 1;
+        if self.count > self.opt_period + 1 {
+            let (rsi, avg_gain, avg_loss) = rsi_inc(
+                input.close,
+                prev_price,
+                self.avg_gain,
+                self.avg_loss,
+                self.opt_period,
+            )?;
+            self.avg_gain = avg_gain;
+            self.avg_loss = avg_loss;
+            return Ok(rsi);
+        }
+
+        #[cfg(feature = "check-nan")]
+        {
+            if input.close.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        if self.count == 1 {
+            return Ok(TAFloat::NAN);
+        }
+        let diff = input.close - prev_price;
+        if diff > 0.0 {
+            self.avg_gain += diff;
+        } else {
+            self.avg_loss += diff.abs();
+        }
+        if self.count <= self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+
+        self.avg_gain /= self.opt_period as TAFloat;
+        self.avg_loss /= self.opt_period as TAFloat;
+        if self.avg_loss == 0.0 {
+            return Ok(100.0);
+        }
+        let rs = self.avg_gain / self.avg_loss;
+        Ok(100.0 - (100.0 / (1.0 + rs)))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.prev_price = TAFloat::NAN;
+        self.avg_gain = 0.0;
+        self.avg_loss = 0.0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -356,4 +455,42 @@ fn test_rsi_calculation() {
             prev_price = input_prices[i];
         }
     }
+
+    #[test]
+    fn test_rsi_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let mut output_avg_gain = vec![0.0; input_price.len()];
+        let mut output_avg_loss = vec![0.0; input_price.len()];
+        let mut output_rsi = vec![0.0; input_price.len()];
+        rsi(
+            &input_price,
+            opt_period,
+            &mut output_rsi,
+            &mut output_avg_gain,
+            &mut output_avg_loss,
+        )
+        .unwrap();
+
+        let mut indicator = Rsi::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_rsi[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required by the Parabolic SAR indicator.
 ///
@@ -341,6 +341,155 @@ pub fn sar_inc(
     Ok((sar, is_long, af, ep))
 }
 
+/// Streaming Parabolic SAR that owns the trend state and the last two highs and lows.
+///
+/// Unlike [`sar_inc`], which only clamps against the previous bar, the struct also clamps
+/// against the bar before it once a trend is two bars old, so folding [`Indicator::update`]
+/// over a series reproduces [`sar`] exactly.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sar {
+    opt_acceleration: TAFloat,
+    opt_maximum: TAFloat,
+    count: usize,
+    trend_start: usize,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    prev2_high: TAFloat,
+    prev2_low: TAFloat,
+    sar: TAFloat,
+    is_long: bool,
+    af: TAFloat,
+    ep: TAFloat,
+}
+
+impl Sar {
+    /// Creates a streaming Parabolic SAR.
+    ///
+    /// # Arguments
+    /// * `opt_acceleration` - Initial acceleration factor (e.g. 0.02)
+    /// * `opt_maximum` - Maximum acceleration factor (e.g. 0.2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If acceleration or maximum are invalid
+    pub fn new(opt_acceleration: TAFloat, opt_maximum: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_acceleration, opt_maximum)?;
+        Ok(Self {
+            opt_acceleration,
+            opt_maximum,
+            count: 0,
+            trend_start: 0,
+            prev_high: TAFloat::NAN,
+            prev_low: TAFloat::NAN,
+            prev2_high: TAFloat::NAN,
+            prev2_low: TAFloat::NAN,
+            sar: TAFloat::NAN,
+            is_long: true,
+            af: 0.0,
+            ep: TAFloat::NAN,
+        })
+    }
+
+    fn next_sar(&mut self, high: TAFloat, low: TAFloat) -> TAFloat {
+        let i = self.count - 1;
+        let mut sar_val = self.af.mul_add(self.ep - self.sar, self.sar);
+        if self.is_long {
+            sar_val = sar_val.min(self.prev_low);
+            if i - self.trend_start >= 2 {
+                sar_val = sar_val.min(self.prev2_low);
+            }
+            if high > self.ep {
+                self.ep = high;
+                self.af = (self.af + self.opt_acceleration).min(self.opt_maximum);
+            }
+            if low < sar_val {
+                self.is_long = false;
+                sar_val = self.ep;
+                self.ep = low;
+                self.af = self.opt_acceleration;
+                self.trend_start = i - 1;
+            }
+        } else {
+            sar_val = sar_val.max(self.prev_high);
+            if i - self.trend_start >= 2 {
+                sar_val = sar_val.max(self.prev2_high);
+            }
+            if low < self.ep {
+                self.ep = low;
+                self.af = (self.af + self.opt_acceleration).min(self.opt_maximum);
+            }
+            if high > sar_val {
+                self.is_long = true;
+                sar_val = self.ep;
+                self.ep = high;
+                self.af = self.opt_acceleration;
+                self.trend_start = i - 1;
+            }
+        }
+        sar_val
+    }
+}
+
+impl Indicator for Sar {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.high.is_nan() || input.low.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.count += 1;
+        let sar = match self.count {
+            1 => TAFloat::NAN,
+            2 => {
+                let plus_dm = input.high - self.prev_high;
+                let minus_dm = self.prev_low - input.low;
+                self.is_long = plus_dm >= minus_dm;
+                self.af = self.opt_acceleration;
+                self.trend_start = 1;
+                if self.is_long {
+                    self.ep = input.high;
+                    self.prev_low
+                } else {
+                    self.ep = input.low;
+                    self.prev_high
+                }
+            }
+            _ => self.next_sar(input.high, input.low),
+        };
+        self.sar = sar;
+        self.prev2_high = self.prev_high;
+        self.prev2_low = self.prev_low;
+        self.prev_high = input.high;
+        self.prev_low = input.low;
+        Ok(sar)
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > 1
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.trend_start = 0;
+        self.prev_high = TAFloat::NAN;
+        self.prev_low = TAFloat::NAN;
+        self.prev2_high = TAFloat::NAN;
+        self.prev2_low = TAFloat::NAN;
+        self.sar = TAFloat::NAN;
+        self.is_long = true;
+        self.af = 0.0;
+        self.ep = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -438,4 +587,69 @@ fn test_sar_calculation() {
             ep = new_ep;
         }
     }
+
+    #[test]
+    fn test_sar_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let (opt_acceleration, opt_maximum) = (0.02, 0.2);
+        let mut output_is_long = vec![false; input_high.len()];
+        let mut output_af = vec![0.0; input_high.len()];
+        let mut output_ep = vec![0.0; input_high.len()];
+        let len = input_high.len();
+        let mut output_sar = vec![0.0; len];
+        sar(
+            &input_high,
+            &input_low,
+            opt_acceleration,
+            opt_maximum,
+            &mut output_sar,
+            &mut output_is_long,
+            &mut output_af,
+            &mut output_ep,
+        )
+        .unwrap();
+
+        let mut indicator = Sar::new(opt_acceleration, opt_maximum).unwrap();
+        let lookback = lookback(opt_acceleration, opt_maximum).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_sar[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
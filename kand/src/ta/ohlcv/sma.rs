@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat, TAPeriod};
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, TAPeriod, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period for Simple Moving Average (SMA) without input validation.
 ///
@@ -18,7 +20,6 @@ pub const fn lookback_raw(opt_period: TAPeriod) -> TAPeriod {
 /// # Errors
 ///
 /// Returns [`KandError::InvalidParameter`] if `opt_period < 2` (with "check" feature).
-#[must_use]
 pub const fn lookback(opt_period: TAPeriod) -> Result<TAPeriod, KandError> {
     #[cfg(feature = "check")]
     {
@@ -135,7 +136,6 @@ pub fn sma_inc_raw(
 ///
 /// With "check-nan" feature:
 /// - [`KandError::NaNDetected`] if any input is NaN.
-#[must_use]
 pub fn sma_inc(
     input: TAFloat,
     prev_input: TAFloat,
@@ -159,6 +159,94 @@ pub fn sma_inc(
     Ok(sma_inc_raw(input, prev_input, prev_sma, opt_period))
 }
 
+/// Streaming SMA that owns its rolling window and running sum.
+///
+/// Reads [`Bar::close`]. Returns `NaN` until `opt_period` prices have been seen, after which
+/// every value matches [`sma`] for the same series.
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, ohlcv::sma::Sma};
+///
+/// let mut sma = Sma::new(3).unwrap();
+/// for price in [2.0, 4.0, 6.0] {
+///     sma.update(&Bar::from(price)).unwrap();
+/// }
+/// assert_eq!(sma.update(&Bar::from(8.0)).unwrap(), 6.0);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sma {
+    opt_period: TAPeriod,
+    window: VecDeque<TAFloat>,
+    sum: TAFloat,
+}
+
+impl Sma {
+    /// Creates a streaming SMA.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for SMA calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: TAPeriod) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period),
+            sum: 0.0,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the SMA for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        if self.window.len() == self.opt_period {
+            let old = self.window.pop_front().unwrap_or_default();
+            self.sum = self.sum + input_price - old;
+        } else {
+            self.sum += input_price;
+        }
+        self.window.push_back(input_price);
+
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(self.sum / self.opt_period as TAFloat)
+    }
+}
+
+impl Indicator for Sma {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        lookback_raw(self.opt_period)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -244,4 +332,32 @@ fn test_sma_without_nan() {
             prev_sma = result;
         }
     }
+
+    /// Tests that folding the streaming SMA reproduces the batch output.
+    #[test]
+    fn test_sma_indicator() {
+        let mut output = vec![0.0; INPUT_DATA.len()];
+        sma(&INPUT_DATA, PERIOD, &mut output).unwrap();
+
+        let mut indicator = Sma::new(PERIOD).unwrap();
+        for (i, &price) in INPUT_DATA.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < LOOKBACK {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output[i], epsilon = EPSILON);
+                assert!(indicator.is_ready());
+            }
+        }
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert!(
+            indicator
+                .update(&Bar::from(INPUT_DATA[0]))
+                .unwrap()
+                .is_nan()
+        );
+    }
 }
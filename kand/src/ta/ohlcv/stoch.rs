@@ -1,4 +1,9 @@
-use crate::{KandError, TAFloat, ta::ohlcv::sma};
+use crate::{
+    KandError, TAFloat,
+    ta::ohlcv::{ma, sma},
+    types::MAType,
+    window::RollingExtremum,
+};
 
 /// Calculates the lookback period required for Stochastic Oscillator calculation.
 ///
@@ -43,6 +48,54 @@ pub const fn lookback(
     Ok(opt_k_period + opt_k_slow_period + opt_d_period - 3)
 }
 
+/// Calculates the lookback period required for [`stoch_ext`].
+///
+/// # Description
+/// Fast %K needs `opt_k_period - 1` bars, and each smoothing stage adds the lookback of its
+/// moving average.
+///
+/// # Arguments
+/// * `opt_k_period` - The period used for %K calculation, must be >= 2
+/// * `opt_k_slow_period` - The smoothing period for slow %K calculation
+/// * `opt_k_ma_type` - Moving average type used to smooth slow %K
+/// * `opt_d_period` - The period used for %D calculation
+/// * `opt_d_ma_type` - Moving average type used for %D
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period if successful
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `opt_k_period` is less than 2 or a smoothing period is
+///   invalid for its moving average type
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::stoch, types::MAType};
+///
+/// let lookback = stoch::lookback_ext(14, 3, MAType::SMA, 3, MAType::SMA).unwrap();
+/// assert_eq!(lookback, 17);
+/// let lookback = stoch::lookback_ext(14, 3, MAType::EMA, 3, MAType::DEMA).unwrap();
+/// assert_eq!(lookback, 19); // 13 + 2 (EMA) + 4 (DEMA)
+/// ```
+pub fn lookback_ext(
+    opt_k_period: usize,
+    opt_k_slow_period: usize,
+    opt_k_ma_type: MAType,
+    opt_d_period: usize,
+    opt_d_ma_type: MAType,
+) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_k_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let slow_k_lookback = ma::lookback(opt_k_slow_period, opt_k_ma_type)?;
+    let d_lookback = ma::lookback(opt_d_period, opt_d_ma_type)?;
+    Ok(opt_k_period - 1 + slow_k_lookback + d_lookback)
+}
+
 /// Calculates the Stochastic Oscillator indicator for the entire price series.
 ///
 /// # Description
@@ -58,7 +111,8 @@ pub const fn lookback(
 /// ```
 ///
 /// # Calculation Steps
-/// 1. Calculate the Fast %K by comparing current close to the high-low range
+/// 1. Calculate the Fast %K by comparing current close to the high-low range, tracked with a
+///    [`RollingExtremum`]
 /// 2. Smooth the Fast %K using SMA to get Slow %K
 /// 3. Calculate %D as the SMA of Slow %K
 ///
@@ -158,26 +212,14 @@ pub fn stoch(
         }
     }
 
-    let hundred = 100.0;
-
     // Calculate Fast %K first
-    for i in (opt_k_period - 1)..len {
-        let mut highest_high = input_high[i];
-        let mut lowest_low = input_low[i];
-
-        for j in 0..opt_k_period {
-            let idx = i - j;
-            highest_high = highest_high.max(input_high[idx]);
-            lowest_low = lowest_low.min(input_low[idx]);
-        }
-
-        let range = highest_high - lowest_low;
-        if range > 0.0 {
-            output_fast_k[i] = hundred * (input_close[i] - lowest_low) / range;
-        } else {
-            output_fast_k[i] = 50.0; // Default to 50 when range is zero
-        }
-    }
+    fast_k(
+        input_high,
+        input_low,
+        input_close,
+        opt_k_period,
+        output_fast_k,
+    )?;
 
     // Calculate Slow %K (SMA of Fast %K)
     sma::sma(output_fast_k, opt_k_slow_period, output_k)?;
@@ -199,6 +241,190 @@ pub fn stoch(
     Ok(())
 }
 
+/// Calculates the Stochastic Oscillator with configurable smoothing for slow %K and %D.
+///
+/// # Description
+/// Same as [`stoch`], but slow %K and %D each use the moving average selected by their
+/// [`MAType`], as in TA-Lib's `STOCH`. Each stage is applied to the valid values of the
+/// previous one only, so recursive averages such as EMA are seeded from real data.
+///
+/// # Mathematical Formula
+/// ```text
+/// Fast %K = 100 * (Close - Lowest Low) / (Highest High - Lowest Low)
+/// Slow %K = MA(Fast %K, k_slow_period, k_ma_type)
+/// %D = MA(Slow %K, d_period, d_ma_type)
+/// ```
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_k_period` - Period for %K calculation, must be >= 2
+/// * `opt_k_slow_period` - Smoothing period for slow %K
+/// * `opt_k_ma_type` - Moving average type used to smooth slow %K
+/// * `opt_d_period` - Period for %D calculation
+/// * `opt_d_ma_type` - Moving average type used for %D
+/// * `output_fast_k` - Array to store Fast %K values
+/// * `output_k` - Array to store Slow %K values
+/// * `output_d` - Array to store %D values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit type if successful
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If any period is invalid (see [`lookback_ext`])
+/// * `KandError::InsufficientData` - If input length is less than required lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::stoch, types::MAType};
+///
+/// let input_high = vec![10.0, 12.0, 15.0, 14.0, 13.0, 14.0];
+/// let input_low = vec![8.0, 9.0, 11.0, 10.0, 9.0, 10.0];
+/// let input_close = vec![9.0, 11.0, 14.0, 12.0, 11.0, 13.0];
+/// let mut output_fast_k = vec![0.0; 6];
+/// let mut output_k = vec![0.0; 6];
+/// let mut output_d = vec![0.0; 6];
+///
+/// stoch::stoch_ext(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     3,
+///     2,
+///     MAType::EMA,
+///     2,
+///     MAType::SMA,
+///     &mut output_fast_k,
+///     &mut output_k,
+///     &mut output_d,
+/// )
+/// .unwrap();
+/// ```
+#[allow(clippy::similar_names)]
+pub fn stoch_ext(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_k_period: usize,
+    opt_k_slow_period: usize,
+    opt_k_ma_type: MAType,
+    opt_d_period: usize,
+    opt_d_ma_type: MAType,
+    output_fast_k: &mut [TAFloat],
+    output_k: &mut [TAFloat],
+    output_d: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback_ext(
+        opt_k_period,
+        opt_k_slow_period,
+        opt_k_ma_type,
+        opt_d_period,
+        opt_d_ma_type,
+    )?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_low.len()
+            || len != input_close.len()
+            || len != output_fast_k.len()
+            || len != output_k.len()
+            || len != output_d.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    fast_k(
+        input_high,
+        input_low,
+        input_close,
+        opt_k_period,
+        output_fast_k,
+    )?;
+
+    // Smooth only the valid part of each stage
+    let fast_k_lookback = opt_k_period - 1;
+    ma::ma(
+        &output_fast_k[fast_k_lookback..],
+        opt_k_slow_period,
+        opt_k_ma_type,
+        &mut output_k[fast_k_lookback..],
+    )?;
+
+    let slow_k_lookback = fast_k_lookback + ma::lookback(opt_k_slow_period, opt_k_ma_type)?;
+    ma::ma(
+        &output_k[slow_k_lookback..],
+        opt_d_period,
+        opt_d_ma_type,
+        &mut output_d[slow_k_lookback..],
+    )?;
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_fast_k[i] = TAFloat::NAN;
+        output_k[i] = TAFloat::NAN;
+        output_d[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Writes Fast %K from index `opt_k_period - 1` onwards, leaving earlier values untouched.
+fn fast_k(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_k_period: usize,
+    output_fast_k: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let hundred = 100.0;
+
+    let mut highs = RollingExtremum::highest(opt_k_period)?;
+    let mut lows = RollingExtremum::lowest(opt_k_period)?;
+    for i in 0..input_high.len() {
+        let (highest_high, _) = highs.push(input_high[i]);
+        let (lowest_low, _) = lows.push(input_low[i]);
+        if i < opt_k_period - 1 {
+            continue;
+        }
+
+        let range = highest_high - lowest_low;
+        if range > 0.0 {
+            output_fast_k[i] = hundred * (input_close[i] - lowest_low) / range;
+        } else {
+            output_fast_k[i] = 50.0; // Default to 50 when range is zero
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -278,4 +504,118 @@ fn test_stoch_calculation() {
             assert_relative_eq!(output_d[i + 17], exp_d, epsilon = 0.0001);
         }
     }
+
+    #[test]
+    #[allow(clippy::similar_names)]
+    fn test_stoch_ext() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let len = input_high.len();
+        let (opt_k_period, opt_k_slow_period, opt_d_period) = (10, 3, 3);
+        let mut output_fast_k = vec![0.0; len];
+        let mut output_k = vec![0.0; len];
+        let mut output_d = vec![0.0; len];
+
+        // SMA smoothing matches `stoch`
+        let mut expected_fast_k = vec![0.0; len];
+        let mut expected_k = vec![0.0; len];
+        let mut expected_d = vec![0.0; len];
+        stoch(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_k_period,
+            opt_k_slow_period,
+            opt_d_period,
+            &mut expected_fast_k,
+            &mut expected_k,
+            &mut expected_d,
+        )
+        .unwrap();
+        stoch_ext(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_k_period,
+            opt_k_slow_period,
+            MAType::SMA,
+            opt_d_period,
+            MAType::SMA,
+            &mut output_fast_k,
+            &mut output_k,
+            &mut output_d,
+        )
+        .unwrap();
+        let lookback = lookback(opt_k_period, opt_k_slow_period, opt_d_period).unwrap();
+        for i in lookback..len {
+            assert_relative_eq!(output_k[i], expected_k[i], epsilon = 1e-9);
+            assert_relative_eq!(output_d[i], expected_d[i], epsilon = 1e-9);
+        }
+
+        // EMA slow %K is seeded from the first valid Fast %K values
+        stoch_ext(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_k_period,
+            opt_k_slow_period,
+            MAType::EMA,
+            opt_d_period,
+            MAType::WMA,
+            &mut output_fast_k,
+            &mut output_k,
+            &mut output_d,
+        )
+        .unwrap();
+        let lookback = lookback_ext(
+            opt_k_period,
+            opt_k_slow_period,
+            MAType::EMA,
+            opt_d_period,
+            MAType::WMA,
+        )
+        .unwrap();
+        assert_eq!(lookback, 13);
+        let fast_k_lookback = opt_k_period - 1;
+        fast_k(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_k_period,
+            &mut expected_fast_k,
+        )
+        .unwrap();
+        let mut expected_k = vec![0.0; len - fast_k_lookback];
+        ma::ma(
+            &expected_fast_k[fast_k_lookback..],
+            opt_k_slow_period,
+            MAType::EMA,
+            &mut expected_k,
+        )
+        .unwrap();
+        for i in 0..len {
+            if i < lookback {
+                assert!(output_fast_k[i].is_nan() && output_k[i].is_nan() && output_d[i].is_nan());
+                continue;
+            }
+            let k = &expected_k[i - 2 - fast_k_lookback..=i - fast_k_lookback];
+            let expected_d = (3.0 * k[2] + 2.0 * k[1] + k[0]) / 6.0;
+            assert_relative_eq!(output_fast_k[i], expected_fast_k[i], epsilon = 1e-9);
+            assert_relative_eq!(output_k[i], k[2], epsilon = 1e-9);
+            assert_relative_eq!(output_d[i], expected_d, epsilon = 1e-9);
+        }
+    }
 }
@@ -1,5 +1,13 @@
-use super::atr;
-use crate::{KandError, TAFloat, TAInt, types::Signal};
+use super::{
+    atr::{self, Atr},
+    ma::{self, Ma},
+    trange::{self, Trange},
+};
+use crate::{
+    KandError, TAFloat, TAInt,
+    indicator::Indicator,
+    types::{Bar, MAType, Signal},
+};
 
 /// Returns the lookback period required for Supertrend calculation
 ///
@@ -28,6 +36,33 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
     atr::lookback(opt_period)
 }
 
+/// Returns the lookback period required for [`supertrend_ext`]
+///
+/// # Description
+/// The true range starts on the second bar, so the lookback is one more than the lookback of
+/// the moving average that smooths it. With [`MAType::RMA`] this equals [`lookback`].
+///
+/// # Arguments
+/// * `opt_period` - The period used for ATR smoothing
+/// * `opt_ma_type` - Moving average type used to smooth the true range
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period if successful
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `opt_period` is invalid for the moving average type
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::supertrend, types::MAType};
+///
+/// assert_eq!(supertrend::lookback_ext(14, MAType::RMA).unwrap(), 14);
+/// assert_eq!(supertrend::lookback_ext(14, MAType::EMA).unwrap(), 14);
+/// ```
+pub fn lookback_ext(opt_period: usize, opt_ma_type: MAType) -> Result<usize, KandError> {
+    Ok(ma::lookback(opt_period, opt_ma_type)? + 1)
+}
+
 /// Calculates Supertrend values for the entire price series
 ///
 /// # Description
@@ -144,6 +179,174 @@ pub fn supertrend(
     // Calculate ATR
     atr::atr(input_high, input_low, input_close, opt_period, output_atr)?;
 
+    bands(
+        input_high,
+        input_low,
+        input_close,
+        opt_multiplier,
+        lookback,
+        output_trend,
+        output_supertrend,
+        output_atr,
+        output_upper,
+        output_lower,
+    );
+
+    Ok(())
+}
+
+/// Calculates Supertrend with the true range smoothed by the selected moving average type
+///
+/// # Description
+/// Same bands and trend logic as [`supertrend`], but the ATR is the moving average selected by
+/// `opt_ma_type` of the true range instead of Wilder's smoothing. [`MAType::RMA`] gives the
+/// classic ATR (up to floating-point rounding); SMA or EMA match other charting packages.
+///
+/// # Parameters
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_period` - ATR smoothing period (typically 7-14)
+/// * `opt_multiplier` - ATR multiplier (typically 2-4)
+/// * `opt_ma_type` - Moving average type used to smooth the true range
+/// * `output_trend` - Output array for trend signals (see [`supertrend`])
+/// * `output_supertrend` - Output array for Supertrend values (support/resistance levels)
+/// * `output_atr` - Output array for the smoothed true range
+/// * `output_upper` - Output array for upper band values
+/// * `output_lower` - Output array for lower band values
+///
+/// # Returns
+/// * `Ok(())` - Calculation successful
+///
+/// # Errors
+/// * `KandError::InvalidData` - Empty input arrays
+/// * `KandError::LengthMismatch` - Input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - Invalid `opt_period` for the moving average type
+/// * `KandError::InsufficientData` - Input length less than required lookback
+/// * `KandError::NaNDetected` - NaN values in input (with `check-nan` feature)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::supertrend, types::MAType};
+///
+/// let high = vec![10.0, 12.0, 15.0, 14.0, 13.0, 14.5];
+/// let low = vec![8.0, 9.0, 11.0, 10.0, 9.0, 11.0];
+/// let close = vec![9.0, 11.0, 14.0, 12.0, 11.0, 14.0];
+/// let len = high.len();
+/// let mut trend = vec![0; len];
+/// let mut line = vec![0.0; len];
+/// let mut atr = vec![0.0; len];
+/// let mut upper = vec![0.0; len];
+/// let mut lower = vec![0.0; len];
+///
+/// supertrend::supertrend_ext(
+///     &high,
+///     &low,
+///     &close,
+///     3,
+///     3.0,
+///     MAType::SMA,
+///     &mut trend,
+///     &mut line,
+///     &mut atr,
+///     &mut upper,
+///     &mut lower,
+/// )
+/// .unwrap();
+/// assert!(line[2].is_nan());
+/// assert!(!line[3].is_nan());
+/// ```
+pub fn supertrend_ext(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_period: usize,
+    opt_multiplier: TAFloat,
+    opt_ma_type: MAType,
+    output_trend: &mut [TAInt],
+    output_supertrend: &mut [TAFloat],
+    output_atr: &mut [TAFloat],
+    output_upper: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback_ext(opt_period, opt_ma_type)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_low.len()
+            || len != input_close.len()
+            || len != output_trend.len()
+            || len != output_supertrend.len()
+            || len != output_atr.len()
+            || len != output_upper.len()
+            || len != output_lower.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for i in 0..len {
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Smooth the true range, which starts on the second bar
+    let mut output_trange = vec![0.0; len];
+    trange::trange(input_high, input_low, input_close, &mut output_trange)?;
+    ma::ma(
+        &output_trange[1..],
+        opt_period,
+        opt_ma_type,
+        &mut output_atr[1..],
+    )?;
+
+    bands(
+        input_high,
+        input_low,
+        input_close,
+        opt_multiplier,
+        lookback,
+        output_trend,
+        output_supertrend,
+        output_atr,
+        output_upper,
+        output_lower,
+    );
+
+    Ok(())
+}
+
+/// Derives the final bands and trend from `output_atr`, which must be valid from `lookback` on.
+fn bands(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_multiplier: TAFloat,
+    lookback: usize,
+    output_trend: &mut [TAInt],
+    output_supertrend: &mut [TAFloat],
+    output_atr: &mut [TAFloat],
+    output_upper: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+) {
+    let len = input_high.len();
     let mut basic_upper = vec![0.0; len];
     let mut basic_lower = vec![0.0; len];
 
@@ -207,8 +410,6 @@ pub fn supertrend(
         output_upper[i] = TAFloat::NAN;
         output_lower[i] = TAFloat::NAN;
     }
-
-    Ok(())
 }
 
 /// Calculates a single Supertrend value incrementally
@@ -337,6 +538,193 @@ pub fn supertrend_inc(
     ))
 }
 
+/// ATR feeding a streaming [`Supertrend`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SupertrendAtr {
+    /// Wilder's ATR, as in [`supertrend`].
+    Wilder(Atr),
+    /// True range smoothed by an [`Ma`], as in [`supertrend_ext`].
+    Smoothed { trange: Trange, ma: Ma },
+}
+
+impl SupertrendAtr {
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        match self {
+            Self::Wilder(atr) => atr.update(input),
+            Self::Smoothed { trange, ma } => {
+                let tr = trange.update(input)?;
+                if !trange.is_ready() {
+                    return Ok(TAFloat::NAN);
+                }
+                ma.update_value(tr)
+            }
+        }
+    }
+
+    fn lookback(&self) -> usize {
+        match self {
+            Self::Wilder(atr) => atr.lookback(),
+            Self::Smoothed { ma, .. } => ma.lookback() + 1,
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        match self {
+            Self::Wilder(atr) => atr.is_ready(),
+            Self::Smoothed { ma, .. } => ma.is_ready(),
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Self::Wilder(atr) => atr.reset(),
+            Self::Smoothed { trange, ma } => {
+                trange.reset();
+                ma.reset();
+            }
+        }
+    }
+}
+
+/// Streaming Supertrend built on [`Atr`] that owns the final bands and trend direction.
+///
+/// Yields `(trend, supertrend)`. Before the lookback is complete the trend is
+/// [`Signal::Neutral`] and the line is `NaN`; the first valid bar starts in an uptrend,
+/// matching [`supertrend`]. [`Supertrend::with_ma_type`] smooths the true range with another
+/// moving average instead, matching [`supertrend_ext`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Supertrend {
+    opt_multiplier: TAFloat,
+    atr: SupertrendAtr,
+    prev_close: TAFloat,
+    prev_trend: TAInt,
+    prev_upper: TAFloat,
+    prev_lower: TAFloat,
+}
+
+impl Supertrend {
+    /// Creates a streaming Supertrend.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The ATR period (must be >= 2)
+    /// * `opt_multiplier` - The ATR multiplier for the bands
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize, opt_multiplier: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self::with_atr(
+            opt_multiplier,
+            SupertrendAtr::Wilder(Atr::new(opt_period)?),
+        ))
+    }
+
+    /// Creates a streaming Supertrend whose ATR is the given moving average of the true range.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The ATR smoothing period
+    /// * `opt_multiplier` - The ATR multiplier for the bands
+    /// * `opt_ma_type` - Moving average type used to smooth the true range
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` is invalid for the moving average type
+    pub fn with_ma_type(
+        opt_period: usize,
+        opt_multiplier: TAFloat,
+        opt_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        lookback_ext(opt_period, opt_ma_type)?;
+        Ok(Self::with_atr(
+            opt_multiplier,
+            SupertrendAtr::Smoothed {
+                trange: Trange::new(),
+                ma: Ma::new(opt_period, opt_ma_type)?,
+            },
+        ))
+    }
+
+    fn with_atr(opt_multiplier: TAFloat, atr: SupertrendAtr) -> Self {
+        Self {
+            opt_multiplier,
+            atr,
+            prev_close: TAFloat::NAN,
+            prev_trend: Signal::Neutral.into(),
+            prev_upper: TAFloat::NAN,
+            prev_lower: TAFloat::NAN,
+        }
+    }
+}
+
+impl Indicator for Supertrend {
+    type Output = (TAInt, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        let was_ready = self.atr.is_ready();
+        let atr = self.atr.update(input)?;
+        let prev_close = self.prev_close;
+        self.prev_close = input.close;
+        if !self.atr.is_ready() {
+            return Ok((Signal::Neutral.into(), TAFloat::NAN));
+        }
+
+        let hl2 = TAFloat::midpoint(input.high, input.low);
+        let basic_upper = self.opt_multiplier.mul_add(atr, hl2);
+        let basic_lower = self.opt_multiplier.mul_add(-atr, hl2);
+        let up_trend = Signal::Bullish.into();
+        let down_trend = Signal::Bearish.into();
+        if !was_ready {
+            self.prev_trend = up_trend;
+            self.prev_upper = basic_upper;
+            self.prev_lower = basic_lower;
+            return Ok((up_trend, basic_lower));
+        }
+
+        let upper = if prev_close <= self.prev_upper {
+            basic_upper.min(self.prev_upper)
+        } else {
+            basic_upper
+        };
+        let lower = if prev_close >= self.prev_lower {
+            basic_lower.max(self.prev_lower)
+        } else {
+            basic_lower
+        };
+        let (trend, supertrend) = if self.prev_trend == up_trend {
+            if input.close < lower {
+                (down_trend, upper)
+            } else {
+                (up_trend, lower)
+            }
+        } else if input.close > upper {
+            (up_trend, lower)
+        } else {
+            (down_trend, upper)
+        };
+        self.prev_trend = trend;
+        self.prev_upper = upper;
+        self.prev_lower = lower;
+        Ok((trend, supertrend))
+    }
+
+    fn lookback(&self) -> usize {
+        self.atr.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.atr.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.prev_close = TAFloat::NAN;
+        self.prev_trend = Signal::Neutral.into();
+        self.prev_upper = TAFloat::NAN;
+        self.prev_lower = TAFloat::NAN;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -451,4 +839,187 @@ fn test_supertrend_calculation() {
         assert!(trend == output_trend[len - 1]);
         assert_relative_eq!(supertrend, output_supertrend[len - 1], epsilon = 0.00001);
     }
+
+    #[test]
+    fn test_supertrend_indicator() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let (opt_period, opt_multiplier) = (5, 3.0);
+        let len = input_high.len();
+        let mut output_trend = vec![0; len];
+        let mut output_supertrend = vec![0.0; len];
+        let mut output_atr = vec![0.0; len];
+        let mut output_upper = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        supertrend(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_multiplier,
+            &mut output_trend,
+            &mut output_supertrend,
+            &mut output_atr,
+            &mut output_upper,
+            &mut output_lower,
+        )
+        .unwrap();
+
+        let mut indicator = Supertrend::new(opt_period, opt_multiplier).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+            );
+            let (trend, value) = indicator.update(&bar).unwrap();
+            assert_eq!(trend, output_trend[i]);
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_supertrend[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_supertrend_ext() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let (opt_period, opt_multiplier) = (5, 3.0);
+        let len = input_high.len();
+        let mut output_trend = vec![0; len];
+        let mut output_supertrend = vec![0.0; len];
+        let mut output_atr = vec![0.0; len];
+        let mut output_upper = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+
+        // RMA smoothing is Wilder's ATR
+        let mut expected_trend = vec![0; len];
+        let mut expected_supertrend = vec![0.0; len];
+        let mut expected_atr = vec![0.0; len];
+        let mut expected_upper = vec![0.0; len];
+        let mut expected_lower = vec![0.0; len];
+        supertrend(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_multiplier,
+            &mut expected_trend,
+            &mut expected_supertrend,
+            &mut expected_atr,
+            &mut expected_upper,
+            &mut expected_lower,
+        )
+        .unwrap();
+        supertrend_ext(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_multiplier,
+            MAType::RMA,
+            &mut output_trend,
+            &mut output_supertrend,
+            &mut output_atr,
+            &mut output_upper,
+            &mut output_lower,
+        )
+        .unwrap();
+        let lookback = lookback_ext(opt_period, MAType::RMA).unwrap();
+        assert_eq!(lookback, super::lookback(opt_period).unwrap());
+        assert_eq!(output_trend, expected_trend);
+        for i in lookback..len {
+            assert_relative_eq!(output_atr[i], expected_atr[i], epsilon = 1e-9);
+            assert_relative_eq!(output_supertrend[i], expected_supertrend[i], epsilon = 1e-9);
+        }
+
+        // SMA of the true range, followed by the streaming variant
+        supertrend_ext(
+            &input_high,
+            &input_low,
+            &input_close,
+            opt_period,
+            opt_multiplier,
+            MAType::SMA,
+            &mut output_trend,
+            &mut output_supertrend,
+            &mut output_atr,
+            &mut output_upper,
+            &mut output_lower,
+        )
+        .unwrap();
+        let mut indicator =
+            Supertrend::with_ma_type(opt_period, opt_multiplier, MAType::SMA).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                input_close[i],
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                0.0,
+            );
+            let (trend, value) = indicator.update(&bar).unwrap();
+            assert_eq!(trend, output_trend[i]);
+            if i < lookback {
+                assert!(value.is_nan() && output_atr[i].is_nan());
+                continue;
+            }
+            let expected_atr = (i + 1 - opt_period..=i)
+                .map(|j| {
+                    (input_high[j] - input_low[j])
+                        .max((input_high[j] - input_close[j - 1]).abs())
+                        .max((input_low[j] - input_close[j - 1]).abs())
+                })
+                .sum::<TAFloat>()
+                / opt_period as TAFloat;
+            assert_relative_eq!(output_atr[i], expected_atr, epsilon = 1e-6);
+            assert_relative_eq!(value, output_supertrend[i], epsilon = 1e-6);
+        }
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{TAFloat, error::KandError};
+use crate::{TAFloat, error::KandError, indicator::Indicator, types::Bar};
 
 /// Calculates the lookback period required for T3 indicator
 ///
@@ -402,6 +402,121 @@ pub fn t3_inc(
     Ok((t3, ema1, ema2, ema3, ema4, ema5, ema6))
 }
 
+/// Streaming T3 that owns the six EMA stages and their staggered SMA seeds.
+///
+/// Reads [`Bar::close`]. Each EMA stage is seeded with the average of the previous stage's
+/// first `opt_period` values, exactly as [`t3`] does, so folding [`Indicator::update`] over a
+/// series reproduces the batch output.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct T3 {
+    opt_period: usize,
+    k: TAFloat,
+    coefficients: [TAFloat; 4],
+    emas: [TAFloat; 6],
+    seeded: usize,
+    count: usize,
+    sum: TAFloat,
+}
+
+impl T3 {
+    /// Creates a streaming T3.
+    ///
+    /// # Arguments
+    /// * `opt_period` - Smoothing period for EMAs (must be >= 2)
+    /// * `opt_vfactor` - Volume factor controlling smoothing (typically 0-1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize, opt_vfactor: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        let a = opt_vfactor;
+        let a2 = a * a;
+        let a3 = a2 * a;
+        let c1 = -a3;
+        let c2 = 3.0 * (a2 + a3);
+        let c3 = a3.mul_add(-3.0, a2.mul_add(-6.0, -(3.0 * a)));
+        let c4 = a2.mul_add(3.0, a.mul_add(3.0, 1.0) + a3);
+        Ok(Self {
+            opt_period,
+            k: crate::helper::period_to_k(opt_period)?,
+            coefficients: [c1, c2, c3, c4],
+            emas: [TAFloat::NAN; 6],
+            seeded: 0,
+            count: 0,
+            sum: 0.0,
+        })
+    }
+
+    fn output(&self) -> TAFloat {
+        let [c1, c2, c3, c4] = self.coefficients;
+        let [_, _, e3, e4, e5, e6] = self.emas;
+        c4.mul_add(e3, c3.mul_add(e4, c1.mul_add(e6, c2 * e5)))
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the T3 for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let one_minus_k = 1.0 - self.k;
+        let mut value = input_price;
+        for ema in &mut self.emas[..self.seeded] {
+            *ema = self.k.mul_add(value, one_minus_k * *ema);
+            value = *ema;
+        }
+        if self.seeded == self.emas.len() {
+            return Ok(self.output());
+        }
+
+        self.sum += value;
+        self.count += 1;
+        if self.count < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+
+        let seed = self.sum / (self.opt_period as TAFloat);
+        self.emas[self.seeded] = seed;
+        self.seeded += 1;
+        self.sum = seed;
+        self.count = 1;
+        if self.seeded == self.emas.len() {
+            return Ok(self.output());
+        }
+        Ok(TAFloat::NAN)
+    }
+}
+
+impl Indicator for T3 {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        6 * (self.opt_period - 1)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.seeded == self.emas.len()
+    }
+
+    fn reset(&mut self) {
+        self.emas = [TAFloat::NAN; 6];
+        self.seeded = 0;
+        self.count = 0;
+        self.sum = 0.0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -505,4 +620,52 @@ fn test_t3_calculation() {
             prev_ema6 = ema6;
         }
     }
+
+    #[test]
+    fn test_t3_indicator() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1,
+        ];
+        let opt_period = 5;
+        let opt_vfactor = 0.7;
+        let len = input.len();
+        let mut output = vec![0.0; len];
+        let mut emas = vec![vec![0.0; len]; 6];
+        let [e1, e2, e3, e4, e5, e6] = &mut emas[..] else {
+            unreachable!()
+        };
+        t3(
+            &input,
+            opt_period,
+            opt_vfactor,
+            &mut output,
+            e1,
+            e2,
+            e3,
+            e4,
+            e5,
+            e6,
+        )
+        .unwrap();
+
+        let mut indicator = T3::new(opt_period, opt_vfactor).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,4 +1,9 @@
-use crate::{KandError, TAFloat, ta::ohlcv::ema};
+use crate::{
+    KandError, TAFloat,
+    indicator::Indicator,
+    ta::ohlcv::ema::{self, Ema},
+    types::Bar,
+};
 
 /// Calculates the lookback period required for Triple Exponential Moving Average (TEMA)
 ///
@@ -235,6 +240,80 @@ pub fn tema_inc(
     Ok((tema, ema1, ema2, ema3))
 }
 
+/// Streaming TEMA built from three chained [`Ema`] stages.
+///
+/// Reads [`Bar::close`]. Each stage is seeded from the previous one exactly as [`tema`] does,
+/// so folding [`Indicator::update`] over a series reproduces the batch output.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tema {
+    opt_period: usize,
+    ema1: Ema,
+    ema2: Ema,
+    ema3: Ema,
+}
+
+impl Tema {
+    /// Creates a streaming TEMA.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for EMA calculations (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            ema1: Ema::new(opt_period, None)?,
+            ema2: Ema::new(opt_period, None)?,
+            ema3: Ema::new(opt_period, None)?,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the TEMA for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        let ema1 = self.ema1.update_value(input_price)?;
+        if !self.ema1.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        let ema2 = self.ema2.update_value(ema1)?;
+        if !self.ema2.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        let ema3 = self.ema3.update_value(ema2)?;
+        if !self.ema3.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(ema1.mul_add(3.0, -(3.0 * ema2)) + ema3)
+    }
+}
+
+impl Indicator for Tema {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        3 * (self.opt_period - 1)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ema3.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -310,4 +389,45 @@ fn test_tema_calculation() {
             prev_ema3 = new_ema3;
         }
     }
+
+    #[test]
+    fn test_tema_indicator() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6,
+        ];
+        let opt_period = 3;
+        let len = input.len();
+        let mut output_tema = vec![0.0; len];
+        let mut ema1 = vec![0.0; len];
+        let mut ema2 = vec![0.0; len];
+        let mut ema3 = vec![0.0; len];
+        tema(
+            &input,
+            opt_period,
+            &mut output_tema,
+            &mut ema1,
+            &mut ema2,
+            &mut ema3,
+        )
+        .unwrap();
+
+        let mut indicator = Tema::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_tema[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{KandError, TAFloat};
+use super::sma::Sma;
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for Triangular Moving Average (TRIMA) calculation.
 ///
@@ -260,6 +261,75 @@ pub fn trima_inc(
     Ok((new_sma1, new_sma2))
 }
 
+/// Streaming TRIMA built from two chained [`Sma`] stages.
+///
+/// Folding [`Indicator::update`] over a series reproduces the TRIMA values that [`trima`]
+/// writes to `output_sma2`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trima {
+    opt_period: usize,
+    sma1: Sma,
+    sma2: Sma,
+}
+
+impl Trima {
+    /// Creates a streaming TRIMA.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for TRIMA calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        let (n, m) = if opt_period % 2 == 1 {
+            let n = opt_period.div_ceil(2);
+            (n, n)
+        } else {
+            ((opt_period / 2) + 1, opt_period / 2)
+        };
+        Ok(Self {
+            opt_period,
+            sma1: Sma::new(n)?,
+            sma2: Sma::new(m)?,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the TRIMA for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        let sma1 = self.sma1.update_value(input_price)?;
+        if !self.sma1.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        self.sma2.update_value(sma1)
+    }
+}
+
+impl Indicator for Trima {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.sma2.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.sma1.reset();
+        self.sma2.reset();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -398,4 +468,40 @@ fn test_trima_calculation() {
             prev_sma2 = new_sma2;
         }
     }
+
+    #[test]
+    fn test_trima_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 10;
+        let mut output_sma1 = vec![0.0; input_price.len()];
+        let mut output_trima = vec![0.0; input_price.len()];
+        trima(
+            &input_price,
+            opt_period,
+            &mut output_sma1,
+            &mut output_trima,
+        )
+        .unwrap();
+
+        let mut indicator = Trima::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_trima[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,5 +1,8 @@
-use super::{ema, roc};
-use crate::{KandError, TAFloat};
+use super::{
+    ema::{self, Ema},
+    roc,
+};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculates the lookback period required for TRIX calculation
 ///
@@ -231,6 +234,80 @@ pub fn trix_inc(
     Ok((trix, new_ema1, new_ema2, new_ema3))
 }
 
+/// Streaming TRIX built from three chained [`Ema`] stages and the previous triple EMA.
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`trix`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trix {
+    opt_period: usize,
+    ema1: Ema,
+    ema2: Ema,
+    ema3: Ema,
+    prev_ema3: TAFloat,
+    count: usize,
+}
+
+impl Trix {
+    /// Creates a streaming TRIX.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for EMA calculations (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            ema1: Ema::new(opt_period, None)?,
+            ema2: Ema::new(opt_period, None)?,
+            ema3: Ema::new(opt_period, None)?,
+            prev_ema3: TAFloat::NAN,
+            count: 0,
+        })
+    }
+}
+
+impl Indicator for Trix {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        let ema1 = self.ema1.update_value(input.close)?;
+        self.count += 1;
+        if !self.ema1.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        let ema2 = self.ema2.update_value(ema1)?;
+        if !self.ema2.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        let ema3 = self.ema3.update_value(ema2)?;
+        let prev_ema3 = self.prev_ema3;
+        self.prev_ema3 = ema3;
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        roc::roc_inc(ema3, prev_ema3)
+    }
+
+    fn lookback(&self) -> usize {
+        3 * (self.opt_period - 1) + 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback()
+    }
+
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+        self.prev_ema3 = TAFloat::NAN;
+        self.count = 0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -302,4 +379,45 @@ fn test_trix_calculation() {
             prev_ema3 = new_ema3;
         }
     }
+
+    #[test]
+    fn test_trix_indicator() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6,
+        ];
+        let opt_period = 3;
+        let len = input.len();
+        let mut output = vec![0.0; len];
+        let mut ema1 = vec![0.0; len];
+        let mut ema2 = vec![0.0; len];
+        let mut ema3 = vec![0.0; len];
+        trix(
+            &input,
+            opt_period,
+            &mut output,
+            &mut ema1,
+            &mut ema2,
+            &mut ema3,
+        )
+        .unwrap();
+
+        let mut indicator = Trix::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,5 +1,5 @@
-use super::ema;
-use crate::{KandError, TAFloat};
+use super::ema::{self, Ema};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Returns the lookback period required for VEGAS (Volume and EMA Guided Adaptive Scaling) calculation
 ///
@@ -198,3 +198,117 @@ pub fn vegas_inc(
 
     Ok((channel_upper, channel_lower, boundary_upper, boundary_lower))
 }
+
+/// Streaming VEGAS channel that owns its four EMAs.
+///
+/// Reads [`Bar::close`] and yields `(channel_upper, channel_lower, boundary_upper,
+/// boundary_lower)` with the same warmup `NaN`s as [`vegas`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vegas {
+    count: usize,
+    channel_upper: Ema,
+    channel_lower: Ema,
+    boundary_upper: Ema,
+    boundary_lower: Ema,
+}
+
+impl Vegas {
+    /// Creates a streaming VEGAS channel.
+    ///
+    /// # Errors
+    /// Never fails; returns `Result` for consistency with the other indicators.
+    pub fn new() -> Result<Self, KandError> {
+        Ok(Self {
+            count: 0,
+            channel_upper: Ema::new(144, None)?,
+            channel_lower: Ema::new(169, None)?,
+            boundary_upper: Ema::new(576, None)?,
+            boundary_lower: Ema::new(676, None)?,
+        })
+    }
+}
+
+impl Indicator for Vegas {
+    type Output = (TAFloat, TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        let channel_upper = self.channel_upper.update_value(input.close)?;
+        let channel_lower = self.channel_lower.update_value(input.close)?;
+        let boundary_upper = self.boundary_upper.update_value(input.close)?;
+        let boundary_lower = self.boundary_lower.update_value(input.close)?;
+        self.count += 1;
+        let channel_upper = if self.is_ready() {
+            channel_upper
+        } else {
+            TAFloat::NAN
+        };
+        Ok((channel_upper, channel_lower, boundary_upper, boundary_lower))
+    }
+
+    fn lookback(&self) -> usize {
+        self.boundary_lower.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback()
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.channel_upper.reset();
+        self.channel_lower.reset();
+        self.boundary_upper.reset();
+        self.boundary_lower.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_vegas_indicator() {
+        let input_price: Vec<TAFloat> = (0..800)
+            .map(|i| 100.0f64.mul_add((i as TAFloat * 0.05).sin(), 35000.0))
+            .collect();
+        let len = input_price.len();
+        let mut output_channel_upper = vec![0.0; len];
+        let mut output_channel_lower = vec![0.0; len];
+        let mut output_boundary_upper = vec![0.0; len];
+        let mut output_boundary_lower = vec![0.0; len];
+        vegas(
+            &input_price,
+            &mut output_channel_upper,
+            &mut output_channel_lower,
+            &mut output_boundary_upper,
+            &mut output_boundary_lower,
+        )
+        .unwrap();
+
+        let mut indicator = Vegas::new().unwrap();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        let expected = [
+            &output_channel_upper,
+            &output_channel_lower,
+            &output_boundary_upper,
+            &output_boundary_lower,
+        ];
+        for (i, &price) in input_price.iter().enumerate() {
+            let (a, b, c, d) = indicator.update(&Bar::from(price)).unwrap();
+            for (value, output) in [a, b, c, d].into_iter().zip(expected) {
+                if output[i].is_nan() {
+                    assert!(value.is_nan());
+                } else {
+                    assert_relative_eq!(value, output[i], epsilon = 0.00001);
+                }
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculates the lookback period required for Weighted Moving Average (WMA).
 ///
@@ -191,6 +193,75 @@ pub fn wma_inc(input_window: &[TAFloat], opt_period: usize) -> Result<TAFloat, K
     Ok(weighted_sum / denominator)
 }
 
+/// Streaming WMA that keeps the last `opt_period` closes, newest first.
+///
+/// Folding [`Indicator::update`] over a series reproduces [`wma`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wma {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+}
+
+impl Wma {
+    /// Creates a streaming WMA.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The time period for WMA calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the WMA for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_front(input_price);
+        if self.window.len() > self.opt_period {
+            self.window.pop_back();
+        }
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        wma_inc(self.window.make_contiguous(), self.opt_period)
+    }
+}
+
+impl Indicator for Wma {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -247,4 +318,33 @@ fn test_wma_calculation() {
             assert_relative_eq!(result, output[i], epsilon = 0.0001);
         }
     }
+
+    #[test]
+    fn test_wma_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 10;
+        let mut output_wma = vec![0.0; input_price.len()];
+        wma(&input_price, opt_period, &mut output_wma).unwrap();
+
+        let mut indicator = Wma::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_wma[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
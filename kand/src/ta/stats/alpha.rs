@@ -0,0 +1,478 @@
+use super::beta::{self, CapmBeta};
+use crate::{KandError, TAFloat, indicator::Indicator};
+
+/// Calculates the lookback period required for rolling Jensen's Alpha calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For Jensen's Alpha, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::alpha;
+/// let lookback = alpha::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    beta::capm_lookback(opt_period)
+}
+
+/// Calculates the rolling Jensen's Alpha of an asset's returns against a benchmark's returns.
+///
+/// Jensen's Alpha is the average return the asset earned beyond what its CAPM Beta to the
+/// benchmark predicts. The risk-free rate is given per year and spread evenly over
+/// `opt_periods_per_year`, and the per-period alpha is annualised by multiplying it by
+/// `opt_periods_per_year`.
+///
+/// # Mathematical Formula
+/// ```text
+/// Rf = RiskFree / PeriodsPerYear
+/// Alpha = [(Mean(Ra) - Rf) - Beta * (Mean(Rm) - Rf)] * PeriodsPerYear
+/// ```
+/// Where:
+/// - Ra are the asset returns and Rm the benchmark returns in the window
+/// - Beta is the rolling CAPM Beta from [`beta::capm_beta`]
+///
+/// # Calculation Steps
+/// 1. Calculate the rolling CAPM Beta and its running sums
+/// 2. Take the window means of both return series from the sums
+/// 3. Subtract the Beta-weighted benchmark excess return from the asset excess return
+/// 4. Annualise, and fill initial values before lookback period with NaN
+///
+/// # Arguments
+/// * `input_returns` - Array of asset returns
+/// * `input_benchmark` - Array of benchmark returns, aligned with `input_returns`
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_risk_free` - Annual risk-free rate (e.g. 0.02 for 2%)
+/// * `opt_periods_per_year` - Number of return periods per year (e.g. 252.0 for daily bars)
+/// * `output_alpha` - Array to store the annualised Jensen's Alpha
+/// * `output_beta` - Array to store the CAPM Beta
+/// * `output_sum_returns` - Array to store running sum of asset returns
+/// * `output_sum_benchmark` - Array to store running sum of benchmark returns
+/// * `output_sum_benchmark_sq` - Array to store running sum of squared benchmark returns
+/// * `output_sum_cross` - Array to store running sum of asset times benchmark returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input data is empty
+/// * Returns `KandError::LengthMismatch` if arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if input contains NaN values (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::alpha;
+/// // The asset tracks twice the benchmark plus 0.1% per period
+/// let input_returns = vec![0.021, -0.009, 0.031, 0.011, -0.019];
+/// let input_benchmark = vec![0.01, -0.005, 0.015, 0.005, -0.01];
+/// let mut output_alpha = vec![0.0; 5];
+/// let mut output_beta = vec![0.0; 5];
+/// let mut output_sum_returns = vec![0.0; 5];
+/// let mut output_sum_benchmark = vec![0.0; 5];
+/// let mut output_sum_benchmark_sq = vec![0.0; 5];
+/// let mut output_sum_cross = vec![0.0; 5];
+///
+/// alpha::alpha(
+///     &input_returns,
+///     &input_benchmark,
+///     3,
+///     0.0,
+///     252.0,
+///     &mut output_alpha,
+///     &mut output_beta,
+///     &mut output_sum_returns,
+///     &mut output_sum_benchmark,
+///     &mut output_sum_benchmark_sq,
+///     &mut output_sum_cross,
+/// )
+/// .unwrap();
+/// assert!((output_beta[4] - 2.0).abs() < 1e-9);
+/// assert!((output_alpha[4] - 0.252).abs() < 1e-9);
+/// ```
+pub fn alpha(
+    input_returns: &[TAFloat],
+    input_benchmark: &[TAFloat],
+    opt_period: usize,
+    opt_risk_free: TAFloat,
+    opt_periods_per_year: TAFloat,
+    output_alpha: &mut [TAFloat],
+    output_beta: &mut [TAFloat],
+    output_sum_returns: &mut [TAFloat],
+    output_sum_benchmark: &mut [TAFloat],
+    output_sum_benchmark_sq: &mut [TAFloat],
+    output_sum_cross: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Length consistency check
+        if output_alpha.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    beta::capm_beta(
+        input_returns,
+        input_benchmark,
+        opt_period,
+        output_beta,
+        output_sum_returns,
+        output_sum_benchmark,
+        output_sum_benchmark_sq,
+        output_sum_cross,
+    )?;
+
+    for i in lookback..len {
+        output_alpha[i] = jensen(
+            output_beta[i],
+            output_sum_returns[i],
+            output_sum_benchmark[i],
+            opt_period,
+            opt_risk_free,
+            opt_periods_per_year,
+        );
+    }
+
+    // Fill initial values with NAN
+    for value in output_alpha.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Jensen's Alpha value incrementally.
+///
+/// # Arguments
+/// * `input_return` - The newest asset return to add
+/// * `input_benchmark` - The newest benchmark return to add
+/// * `input_old_return` - The oldest asset return to remove
+/// * `input_old_benchmark` - The oldest benchmark return to remove
+/// * `prev_sum_returns` - Previous sum of asset returns
+/// * `prev_sum_benchmark` - Previous sum of benchmark returns
+/// * `prev_sum_benchmark_sq` - Previous sum of squared benchmark returns
+/// * `prev_sum_cross` - Previous sum of asset times benchmark returns
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_risk_free` - Annual risk-free rate
+/// * `opt_periods_per_year` - Number of return periods per year (must be > 0)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing:
+///   - New Jensen's Alpha value
+///   - New CAPM Beta value
+///   - New sum of asset returns
+///   - New sum of benchmark returns
+///   - New sum of squared benchmark returns
+///   - New sum of asset times benchmark returns
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not positive
+/// * Returns `KandError::NaNDetected` if any input contains NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::alpha;
+/// // Window [0.021, -0.009, 0.031] against [0.01, -0.005, 0.015]
+/// let (alpha, beta, sum_returns, sum_benchmark, sum_benchmark_sq, sum_cross) =
+///     alpha::alpha_inc(
+///         0.011, 0.005,  // newest asset and benchmark returns
+///         0.021, 0.01,   // oldest asset and benchmark returns
+///         0.043, 0.02, 0.00035, 0.00072,
+///         3,
+///         0.0,
+///         252.0,
+///     )
+///     .unwrap();
+/// assert!((alpha - 0.252).abs() < 1e-9);
+/// ```
+pub fn alpha_inc(
+    input_return: TAFloat,
+    input_benchmark: TAFloat,
+    input_old_return: TAFloat,
+    input_old_benchmark: TAFloat,
+    prev_sum_returns: TAFloat,
+    prev_sum_benchmark: TAFloat,
+    prev_sum_benchmark_sq: TAFloat,
+    prev_sum_cross: TAFloat,
+    opt_period: usize,
+    opt_risk_free: TAFloat,
+    opt_periods_per_year: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    let (beta, sum_returns, sum_benchmark, sum_benchmark_sq, sum_cross) = beta::capm_beta_inc(
+        input_return,
+        input_benchmark,
+        input_old_return,
+        input_old_benchmark,
+        prev_sum_returns,
+        prev_sum_benchmark,
+        prev_sum_benchmark_sq,
+        prev_sum_cross,
+        opt_period,
+    )?;
+
+    Ok((
+        jensen(
+            beta,
+            sum_returns,
+            sum_benchmark,
+            opt_period,
+            opt_risk_free,
+            opt_periods_per_year,
+        ),
+        beta,
+        sum_returns,
+        sum_benchmark,
+        sum_benchmark_sq,
+        sum_cross,
+    ))
+}
+
+/// Annualised Jensen's Alpha of a window from its Beta and return sums.
+fn jensen(
+    beta: TAFloat,
+    sum_returns: TAFloat,
+    sum_benchmark: TAFloat,
+    period: usize,
+    opt_risk_free: TAFloat,
+    opt_periods_per_year: TAFloat,
+) -> TAFloat {
+    let n = period as TAFloat;
+    let risk_free = opt_risk_free / opt_periods_per_year;
+    let excess_benchmark = sum_benchmark / n - risk_free;
+    (beta.mul_add(-excess_benchmark, sum_returns / n - risk_free)) * opt_periods_per_year
+}
+
+/// Streaming rolling Jensen's Alpha over aligned asset and benchmark returns.
+///
+/// Consumes `(asset_return, benchmark_return)` pairs and outputs `(alpha, beta)`, so folding
+/// [`Indicator::update`] over both series reproduces [`alpha`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alpha {
+    opt_risk_free: TAFloat,
+    opt_periods_per_year: TAFloat,
+    beta: CapmBeta,
+}
+
+impl Alpha {
+    /// Creates a streaming rolling Jensen's Alpha.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    /// * `opt_risk_free` - Annual risk-free rate
+    /// * `opt_periods_per_year` - Number of return periods per year (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or periods per year is not positive
+    pub fn new(
+        opt_period: usize,
+        opt_risk_free: TAFloat,
+        opt_periods_per_year: TAFloat,
+    ) -> Result<Self, KandError> {
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_risk_free,
+            opt_periods_per_year,
+            beta: CapmBeta::new(opt_period)?,
+        })
+    }
+}
+
+impl Indicator<(TAFloat, TAFloat)> for Alpha {
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input: &(TAFloat, TAFloat)) -> Result<Self::Output, KandError> {
+        let beta = self.beta.update(input)?;
+        if !self.beta.is_ready() {
+            return Ok((TAFloat::NAN, TAFloat::NAN));
+        }
+        let (sum_returns, sum_benchmark, _, _) = self.beta.sums();
+        let alpha = jensen(
+            beta,
+            sum_returns,
+            sum_benchmark,
+            self.beta.lookback() + 1,
+            self.opt_risk_free,
+            self.opt_periods_per_year,
+        );
+        Ok((alpha, beta))
+    }
+
+    fn lookback(&self) -> usize {
+        self.beta.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.beta.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.beta.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_BENCHMARK: [TAFloat; 12] = [
+        0.010, -0.004, 0.007, 0.002, -0.012, 0.015, 0.001, -0.006, 0.009, 0.004, -0.003, 0.011,
+    ];
+    const INPUT_RETURNS: [TAFloat; 12] = [
+        0.018, -0.010, 0.009, 0.006, -0.020, 0.024, -0.002, -0.011, 0.016, 0.003, -0.008, 0.019,
+    ];
+
+    #[test]
+    fn test_alpha_calculation() {
+        let opt_period = 5;
+        let len = INPUT_RETURNS.len();
+        let mut output_alpha = vec![0.0; len];
+        let mut output_beta = vec![0.0; len];
+        let mut output_sum_returns = vec![0.0; len];
+        let mut output_sum_benchmark = vec![0.0; len];
+        let mut output_sum_benchmark_sq = vec![0.0; len];
+        let mut output_sum_cross = vec![0.0; len];
+
+        alpha(
+            &INPUT_RETURNS,
+            &INPUT_BENCHMARK,
+            opt_period,
+            0.02,
+            252.0,
+            &mut output_alpha,
+            &mut output_beta,
+            &mut output_sum_returns,
+            &mut output_sum_benchmark,
+            &mut output_sum_benchmark_sq,
+            &mut output_sum_cross,
+        )
+        .unwrap();
+
+        for value in &output_alpha[..4] {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            -0.093_593_830_334_190_37,
+            -0.197_796_801_505_173_84,
+            -0.189_442_959_917_780_04,
+            -0.138_224_390_243_902_35,
+            -0.223_865_884_325_230_36,
+            -0.496_650_868_878_356_36,
+            -0.534_365_217_391_304_4,
+            -0.406_106_422_018_348_73,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_alpha[i + 4], expected, epsilon = 0.000_000_1);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        for i in opt_period..len {
+            let (alpha, beta, sum_returns, sum_benchmark, sum_benchmark_sq, sum_cross) = alpha_inc(
+                INPUT_RETURNS[i],
+                INPUT_BENCHMARK[i],
+                INPUT_RETURNS[i - opt_period],
+                INPUT_BENCHMARK[i - opt_period],
+                output_sum_returns[i - 1],
+                output_sum_benchmark[i - 1],
+                output_sum_benchmark_sq[i - 1],
+                output_sum_cross[i - 1],
+                opt_period,
+                0.02,
+                252.0,
+            )
+            .unwrap();
+            assert_relative_eq!(alpha, output_alpha[i], epsilon = 0.000_000_1);
+            assert_relative_eq!(beta, output_beta[i], epsilon = 0.000_000_1);
+            assert_relative_eq!(sum_returns, output_sum_returns[i], epsilon = 0.000_000_1);
+            assert_relative_eq!(
+                sum_benchmark,
+                output_sum_benchmark[i],
+                epsilon = 0.000_000_1
+            );
+            assert_relative_eq!(
+                sum_benchmark_sq,
+                output_sum_benchmark_sq[i],
+                epsilon = 0.000_000_1
+            );
+            assert_relative_eq!(sum_cross, output_sum_cross[i], epsilon = 0.000_000_1);
+        }
+    }
+
+    #[test]
+    fn test_alpha_indicator() {
+        let opt_period = 5;
+        let len = INPUT_RETURNS.len();
+        let mut output_alpha = vec![0.0; len];
+        let mut output_beta = vec![0.0; len];
+        let mut sums = vec![vec![0.0; len]; 4];
+        let [sum_returns, sum_benchmark, sum_benchmark_sq, sum_cross] = &mut sums[..] else {
+            unreachable!()
+        };
+        alpha(
+            &INPUT_RETURNS,
+            &INPUT_BENCHMARK,
+            opt_period,
+            0.02,
+            252.0,
+            &mut output_alpha,
+            &mut output_beta,
+            sum_returns,
+            sum_benchmark,
+            sum_benchmark_sq,
+            sum_cross,
+        )
+        .unwrap();
+
+        let mut indicator = Alpha::new(opt_period, 0.02, 252.0).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let (alpha, beta) = indicator
+                .update(&(INPUT_RETURNS[i], INPUT_BENCHMARK[i]))
+                .unwrap();
+            if i < lookback {
+                assert!(alpha.is_nan());
+                assert!(beta.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(alpha, output_alpha[i], epsilon = 1e-9);
+                assert_relative_eq!(beta, output_beta[i], epsilon = 1e-9);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert!(Alpha::new(opt_period, 0.02, 0.0).is_err());
+    }
+}
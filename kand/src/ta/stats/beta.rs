@@ -0,0 +1,1150 @@
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, indicator::Indicator};
+
+/// Calculates the lookback period required for TA-Lib compatible Beta calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. Beta works on the returns between consecutive prices, so one extra price
+/// is needed and the lookback equals the period.
+///
+/// # Arguments
+/// * `opt_period` - The number of returns in the rolling window (must be >= 1)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 1
+///
+/// # Example
+/// ```
+/// use kand::stats::beta;
+/// let lookback = beta::lookback(5).unwrap();
+/// assert_eq!(lookback, 5);
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 1 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period)
+}
+
+/// Calculates the lookback period required for rolling CAPM Beta calculation.
+///
+/// CAPM Beta works on return series directly, so the lookback equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::beta;
+/// let lookback = beta::capm_lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn capm_lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates the rolling CAPM Beta of an asset's returns against a benchmark's returns.
+///
+/// Beta is the slope of the least-squares line of the asset returns on the benchmark returns:
+/// how much the asset moves, on average, for a unit move of the benchmark. It is NaN when the
+/// benchmark returns in the window have no variance.
+///
+/// # Mathematical Formula
+/// ```text
+/// Beta = [n(Σxy) - (Σx)(Σy)] / [n(Σx²) - (Σx)²]
+/// ```
+/// Where:
+/// - x is the benchmark return and y the asset return
+/// - n is the period (number of observations)
+///
+/// # Calculation Steps
+/// 1. Calculate initial sums for the first period
+/// 2. Apply the least-squares slope formula
+/// 3. For subsequent periods, update sums incrementally
+/// 4. Fill initial values before lookback period with NaN
+///
+/// # Arguments
+/// * `input_returns` - Array of asset returns
+/// * `input_benchmark` - Array of benchmark returns, aligned with `input_returns`
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `output_beta` - Array to store calculated Beta values
+/// * `output_sum_returns` - Array to store running sum of asset returns
+/// * `output_sum_benchmark` - Array to store running sum of benchmark returns
+/// * `output_sum_benchmark_sq` - Array to store running sum of squared benchmark returns
+/// * `output_sum_cross` - Array to store running sum of asset times benchmark returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input data is empty
+/// * Returns `KandError::LengthMismatch` if arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if input contains NaN values (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::beta;
+/// let input_returns = vec![0.02, -0.01, 0.03, 0.01, -0.02];
+/// let input_benchmark = vec![0.01, -0.005, 0.015, 0.005, -0.01];
+/// let mut output_beta = vec![0.0; 5];
+/// let mut output_sum_returns = vec![0.0; 5];
+/// let mut output_sum_benchmark = vec![0.0; 5];
+/// let mut output_sum_benchmark_sq = vec![0.0; 5];
+/// let mut output_sum_cross = vec![0.0; 5];
+///
+/// beta::capm_beta(
+///     &input_returns,
+///     &input_benchmark,
+///     3,
+///     &mut output_beta,
+///     &mut output_sum_returns,
+///     &mut output_sum_benchmark,
+///     &mut output_sum_benchmark_sq,
+///     &mut output_sum_cross,
+/// )
+/// .unwrap();
+/// // The asset moves exactly twice as much as the benchmark
+/// assert!((output_beta[4] - 2.0).abs() < 1e-9);
+/// ```
+pub fn capm_beta(
+    input_returns: &[TAFloat],
+    input_benchmark: &[TAFloat],
+    opt_period: usize,
+    output_beta: &mut [TAFloat],
+    output_sum_returns: &mut [TAFloat],
+    output_sum_benchmark: &mut [TAFloat],
+    output_sum_benchmark_sq: &mut [TAFloat],
+    output_sum_cross: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    let lookback = capm_lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check - all arrays must have same length
+        if len != input_benchmark.len()
+            || len != output_beta.len()
+            || len != output_sum_returns.len()
+            || len != output_sum_benchmark.len()
+            || len != output_sum_benchmark_sq.len()
+            || len != output_sum_cross.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check for both input series
+        for i in 0..len {
+            if input_returns[i].is_nan() || input_benchmark[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial sums for the first window
+    let mut sum_y = 0.0;
+    let mut sum_x = 0.0;
+    let mut sum_xx = 0.0;
+    let mut sum_xy = 0.0;
+    for i in 0..opt_period {
+        let y = input_returns[i];
+        let x = input_benchmark[i];
+        sum_y += y;
+        sum_x += x;
+        sum_xx = x.mul_add(x, sum_xx);
+        sum_xy = x.mul_add(y, sum_xy);
+    }
+
+    output_beta[lookback] = slope(sum_x, sum_y, sum_xx, sum_xy, opt_period);
+    output_sum_returns[lookback] = sum_y;
+    output_sum_benchmark[lookback] = sum_x;
+    output_sum_benchmark_sq[lookback] = sum_xx;
+    output_sum_cross[lookback] = sum_xy;
+
+    // Slide the window
+    for i in opt_period..len {
+        let (beta, new_sum_y, new_sum_x, new_sum_xx, new_sum_xy) = capm_beta_inc(
+            input_returns[i],
+            input_benchmark[i],
+            input_returns[i - opt_period],
+            input_benchmark[i - opt_period],
+            sum_y,
+            sum_x,
+            sum_xx,
+            sum_xy,
+            opt_period,
+        )?;
+        sum_y = new_sum_y;
+        sum_x = new_sum_x;
+        sum_xx = new_sum_xx;
+        sum_xy = new_sum_xy;
+
+        output_beta[i] = beta;
+        output_sum_returns[i] = sum_y;
+        output_sum_benchmark[i] = sum_x;
+        output_sum_benchmark_sq[i] = sum_xx;
+        output_sum_cross[i] = sum_xy;
+    }
+
+    // Fill initial values with NaN
+    for i in 0..lookback {
+        output_beta[i] = TAFloat::NAN;
+        output_sum_returns[i] = TAFloat::NAN;
+        output_sum_benchmark[i] = TAFloat::NAN;
+        output_sum_benchmark_sq[i] = TAFloat::NAN;
+        output_sum_cross[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling CAPM Beta value incrementally.
+///
+/// # Arguments
+/// * `input_return` - The newest asset return to add
+/// * `input_benchmark` - The newest benchmark return to add
+/// * `input_old_return` - The oldest asset return to remove
+/// * `input_old_benchmark` - The oldest benchmark return to remove
+/// * `prev_sum_returns` - Previous sum of asset returns
+/// * `prev_sum_benchmark` - Previous sum of benchmark returns
+/// * `prev_sum_benchmark_sq` - Previous sum of squared benchmark returns
+/// * `prev_sum_cross` - Previous sum of asset times benchmark returns
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing:
+///   - New Beta value
+///   - New sum of asset returns
+///   - New sum of benchmark returns
+///   - New sum of squared benchmark returns
+///   - New sum of asset times benchmark returns
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::NaNDetected` if any input contains NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::beta;
+/// // Window [0.02, -0.01, 0.03] against [0.01, -0.005, 0.015]
+/// let (beta, sum_returns, sum_benchmark, sum_benchmark_sq, sum_cross) = beta::capm_beta_inc(
+///     0.01,     // new asset return
+///     0.005,    // new benchmark return
+///     0.02,     // old asset return
+///     0.01,     // old benchmark return
+///     0.04,     // previous sum of asset returns
+///     0.02,     // previous sum of benchmark returns
+///     0.00035,  // previous sum of squared benchmark returns
+///     0.0007,   // previous sum of cross products
+///     3,
+/// )
+/// .unwrap();
+/// assert!((beta - 2.0).abs() < 1e-9);
+/// ```
+pub fn capm_beta_inc(
+    input_return: TAFloat,
+    input_benchmark: TAFloat,
+    input_old_return: TAFloat,
+    input_old_benchmark: TAFloat,
+    prev_sum_returns: TAFloat,
+    prev_sum_benchmark: TAFloat,
+    prev_sum_benchmark_sq: TAFloat,
+    prev_sum_cross: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check for all inputs
+        if input_return.is_nan()
+            || input_benchmark.is_nan()
+            || input_old_return.is_nan()
+            || input_old_benchmark.is_nan()
+            || prev_sum_returns.is_nan()
+            || prev_sum_benchmark.is_nan()
+            || prev_sum_benchmark_sq.is_nan()
+            || prev_sum_cross.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (sum_x, sum_y, sum_xx, sum_xy) = slide(
+        (input_benchmark, input_return),
+        (input_old_benchmark, input_old_return),
+        (
+            prev_sum_benchmark,
+            prev_sum_returns,
+            prev_sum_benchmark_sq,
+            prev_sum_cross,
+        ),
+    );
+
+    Ok((
+        slope(sum_x, sum_y, sum_xx, sum_xy, opt_period),
+        sum_y,
+        sum_x,
+        sum_xx,
+        sum_xy,
+    ))
+}
+
+/// Calculates the TA-Lib compatible Beta of two price series.
+///
+/// Both price series are first turned into simple returns, then Beta is the least-squares slope
+/// of the returns of `input_1` on the returns of `input_0`. Following TA-Lib, a return whose
+/// previous price is zero counts as 0, and Beta is 0 when the denominator is within TA-Lib's
+/// `TA_IS_ZERO` tolerance, for example over a flat run of `input_0`.
+///
+/// # Mathematical Formula
+/// ```text
+/// x[i] = (input_0[i] - input_0[i-1]) / input_0[i-1]
+/// y[i] = (input_1[i] - input_1[i-1]) / input_1[i-1]
+/// Beta = [n(Σxy) - (Σx)(Σy)] / [n(Σx²) - (Σx)²]
+/// ```
+///
+/// # Calculation Steps
+/// 1. Convert both price series into returns
+/// 2. Calculate initial sums over the first `opt_period` returns
+/// 3. For subsequent prices, update sums incrementally
+/// 4. Fill initial values before lookback period with NaN
+///
+/// # Arguments
+/// * `input_0` - First price series (the independent variable, e.g. the market)
+/// * `input_1` - Second price series (the dependent variable, e.g. the asset)
+/// * `opt_period` - The number of returns in the rolling window (must be >= 1, TA-Lib uses 5)
+/// * `output_beta` - Array to store calculated Beta values
+/// * `output_sum_x` - Array to store running sum of `input_0` returns
+/// * `output_sum_y` - Array to store running sum of `input_1` returns
+/// * `output_sum_xx` - Array to store running sum of squared `input_0` returns
+/// * `output_sum_xy` - Array to store running sum of return products
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input data is empty
+/// * Returns `KandError::LengthMismatch` if arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 1
+/// * Returns `KandError::InsufficientData` if input length is not greater than period
+/// * Returns `KandError::NaNDetected` if input contains NaN values (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::beta;
+/// let input_0 = vec![100.0, 101.0, 100.5, 102.0, 103.0, 102.5];
+/// let input_1 = vec![50.0, 51.0, 50.2, 51.8, 52.5, 52.0];
+/// let len = input_0.len();
+/// let mut output_beta = vec![0.0; len];
+/// let mut output_sum_x = vec![0.0; len];
+/// let mut output_sum_y = vec![0.0; len];
+/// let mut output_sum_xx = vec![0.0; len];
+/// let mut output_sum_xy = vec![0.0; len];
+///
+/// beta::beta(
+///     &input_0,
+///     &input_1,
+///     3,
+///     &mut output_beta,
+///     &mut output_sum_x,
+///     &mut output_sum_y,
+///     &mut output_sum_xx,
+///     &mut output_sum_xy,
+/// )
+/// .unwrap();
+/// // First 3 values are NaN, followed by rolling Beta values
+/// ```
+pub fn beta(
+    input_0: &[TAFloat],
+    input_1: &[TAFloat],
+    opt_period: usize,
+    output_beta: &mut [TAFloat],
+    output_sum_x: &mut [TAFloat],
+    output_sum_y: &mut [TAFloat],
+    output_sum_xx: &mut [TAFloat],
+    output_sum_xy: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_0.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check - all arrays must have same length
+        if len != input_1.len()
+            || len != output_beta.len()
+            || len != output_sum_x.len()
+            || len != output_sum_y.len()
+            || len != output_sum_xx.len()
+            || len != output_sum_xy.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check for both input series
+        for i in 0..len {
+            if input_0[i].is_nan() || input_1[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial sums over the first `opt_period` returns
+    let mut sum_x = 0.0;
+    let mut sum_y = 0.0;
+    let mut sum_xx = 0.0;
+    let mut sum_xy = 0.0;
+    for i in 1..=opt_period {
+        let x = price_return(input_0[i], input_0[i - 1]);
+        let y = price_return(input_1[i], input_1[i - 1]);
+        sum_x += x;
+        sum_y += y;
+        sum_xx = x.mul_add(x, sum_xx);
+        sum_xy = x.mul_add(y, sum_xy);
+    }
+
+    output_beta[lookback] = talib_slope(sum_x, sum_y, sum_xx, sum_xy, opt_period);
+    output_sum_x[lookback] = sum_x;
+    output_sum_y[lookback] = sum_y;
+    output_sum_xx[lookback] = sum_xx;
+    output_sum_xy[lookback] = sum_xy;
+
+    // Slide the window
+    for i in (opt_period + 1)..len {
+        let (new_sum_x, new_sum_y, new_sum_xx, new_sum_xy) = slide(
+            (
+                price_return(input_0[i], input_0[i - 1]),
+                price_return(input_1[i], input_1[i - 1]),
+            ),
+            (
+                price_return(input_0[i - opt_period], input_0[i - opt_period - 1]),
+                price_return(input_1[i - opt_period], input_1[i - opt_period - 1]),
+            ),
+            (sum_x, sum_y, sum_xx, sum_xy),
+        );
+        sum_x = new_sum_x;
+        sum_y = new_sum_y;
+        sum_xx = new_sum_xx;
+        sum_xy = new_sum_xy;
+
+        output_beta[i] = talib_slope(sum_x, sum_y, sum_xx, sum_xy, opt_period);
+        output_sum_x[i] = sum_x;
+        output_sum_y[i] = sum_y;
+        output_sum_xx[i] = sum_xx;
+        output_sum_xy[i] = sum_xy;
+    }
+
+    // Fill initial values with NaN
+    for i in 0..lookback {
+        output_beta[i] = TAFloat::NAN;
+        output_sum_x[i] = TAFloat::NAN;
+        output_sum_y[i] = TAFloat::NAN;
+        output_sum_xx[i] = TAFloat::NAN;
+        output_sum_xy[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest TA-Lib compatible Beta value incrementally.
+///
+/// The window gains the return from `input_prev_*` to `input_*` and drops the return from
+/// `input_old_prev_*` to `input_old_*`, which are the prices `opt_period` and `opt_period + 1`
+/// bars back.
+///
+/// # Arguments
+/// * `input_0` - The newest price of series 0
+/// * `input_1` - The newest price of series 1
+/// * `input_prev_0` - The price of series 0 one bar back
+/// * `input_prev_1` - The price of series 1 one bar back
+/// * `input_old_0` - The price of series 0 `opt_period` bars back
+/// * `input_old_1` - The price of series 1 `opt_period` bars back
+/// * `input_old_prev_0` - The price of series 0 `opt_period + 1` bars back
+/// * `input_old_prev_1` - The price of series 1 `opt_period + 1` bars back
+/// * `prev_sum_x` - Previous sum of series 0 returns
+/// * `prev_sum_y` - Previous sum of series 1 returns
+/// * `prev_sum_xx` - Previous sum of squared series 0 returns
+/// * `prev_sum_xy` - Previous sum of return products
+/// * `opt_period` - The number of returns in the rolling window (must be >= 1)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing:
+///   - New Beta value
+///   - New sum of series 0 returns
+///   - New sum of series 1 returns
+///   - New sum of squared series 0 returns
+///   - New sum of return products
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 1
+/// * Returns `KandError::NaNDetected` if any input contains NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::beta;
+/// // Prices [100.0, 101.0, 100.5, 102.0, 103.0, 102.5] and [50.0, 51.0, 50.2, 51.8, 52.5, 52.0]
+/// let (beta, sum_x, sum_y, sum_xx, sum_xy) = beta::beta_inc(
+///     102.5, 52.0, // newest prices
+///     103.0, 52.5, // prices one bar back
+///     100.5, 50.2, // prices `period` bars back
+///     101.0, 51.0, // prices `period + 1` bars back
+///     0.019_778_799_653_450_86,
+///     0.029_699_748_963_868_955,
+///     0.000_343_391_042_557_902_26,
+///     0.000_685_849_354_792_007_9,
+///     3,
+/// )
+/// .unwrap();
+/// assert!((beta - 1.977_630_646_568_971_3).abs() < 1e-9);
+/// ```
+pub fn beta_inc(
+    input_0: TAFloat,
+    input_1: TAFloat,
+    input_prev_0: TAFloat,
+    input_prev_1: TAFloat,
+    input_old_0: TAFloat,
+    input_old_1: TAFloat,
+    input_old_prev_0: TAFloat,
+    input_old_prev_1: TAFloat,
+    prev_sum_x: TAFloat,
+    prev_sum_y: TAFloat,
+    prev_sum_xx: TAFloat,
+    prev_sum_xy: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 1 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check for all inputs
+        if input_0.is_nan()
+            || input_1.is_nan()
+            || input_prev_0.is_nan()
+            || input_prev_1.is_nan()
+            || input_old_0.is_nan()
+            || input_old_1.is_nan()
+            || input_old_prev_0.is_nan()
+            || input_old_prev_1.is_nan()
+            || prev_sum_x.is_nan()
+            || prev_sum_y.is_nan()
+            || prev_sum_xx.is_nan()
+            || prev_sum_xy.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (sum_x, sum_y, sum_xx, sum_xy) = slide(
+        (
+            price_return(input_0, input_prev_0),
+            price_return(input_1, input_prev_1),
+        ),
+        (
+            price_return(input_old_0, input_old_prev_0),
+            price_return(input_old_1, input_old_prev_1),
+        ),
+        (prev_sum_x, prev_sum_y, prev_sum_xx, prev_sum_xy),
+    );
+
+    Ok((
+        talib_slope(sum_x, sum_y, sum_xx, sum_xy, opt_period),
+        sum_x,
+        sum_y,
+        sum_xx,
+        sum_xy,
+    ))
+}
+
+/// Simple return between two prices, 0 when the previous price is 0 as in TA-Lib.
+fn price_return(price: TAFloat, prev_price: TAFloat) -> TAFloat {
+    if prev_price == 0.0 {
+        0.0
+    } else {
+        (price - prev_price) / prev_price
+    }
+}
+
+/// Moves the `(Σx, Σy, Σx², Σxy)` sums one `(x, y)` pair forward.
+fn slide(
+    (new_x, new_y): (TAFloat, TAFloat),
+    (old_x, old_y): (TAFloat, TAFloat),
+    (sum_x, sum_y, sum_xx, sum_xy): (TAFloat, TAFloat, TAFloat, TAFloat),
+) -> (TAFloat, TAFloat, TAFloat, TAFloat) {
+    (
+        sum_x - old_x + new_x,
+        sum_y - old_y + new_y,
+        new_x.mul_add(new_x, old_x.mul_add(-old_x, sum_xx)),
+        new_x.mul_add(new_y, old_x.mul_add(-old_y, sum_xy)),
+    )
+}
+
+/// TA-Lib's `TA_IS_ZERO` tolerance.
+const TALIB_ZERO: TAFloat = 1e-8;
+
+/// Least-squares slope of y on x, NaN when x has no variance.
+fn slope(
+    sum_x: TAFloat,
+    sum_y: TAFloat,
+    sum_xx: TAFloat,
+    sum_xy: TAFloat,
+    period: usize,
+) -> TAFloat {
+    let n = period as TAFloat;
+    let denominator = n.mul_add(sum_xx, -(sum_x * sum_x));
+    if denominator > 0.0 {
+        n.mul_add(sum_xy, -(sum_x * sum_y)) / denominator
+    } else {
+        TAFloat::NAN
+    }
+}
+
+/// Least-squares slope of y on x as TA-Lib's BETA computes it, 0 when the denominator is zero.
+fn talib_slope(
+    sum_x: TAFloat,
+    sum_y: TAFloat,
+    sum_xx: TAFloat,
+    sum_xy: TAFloat,
+    period: usize,
+) -> TAFloat {
+    let n = period as TAFloat;
+    let denominator = n.mul_add(sum_xx, -(sum_x * sum_x));
+    if denominator.abs() < TALIB_ZERO {
+        0.0
+    } else {
+        n.mul_add(sum_xy, -(sum_x * sum_y)) / denominator
+    }
+}
+
+/// Streaming rolling CAPM Beta over aligned asset and benchmark returns.
+///
+/// Consumes `(asset_return, benchmark_return)` pairs, so folding [`Indicator::update`] over both
+/// series reproduces [`capm_beta`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CapmBeta {
+    opt_period: usize,
+    window: VecDeque<(TAFloat, TAFloat)>,
+    sum_returns: TAFloat,
+    sum_benchmark: TAFloat,
+    sum_benchmark_sq: TAFloat,
+    sum_cross: TAFloat,
+}
+
+impl CapmBeta {
+    /// Creates a streaming rolling CAPM Beta.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        capm_lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+            sum_returns: 0.0,
+            sum_benchmark: 0.0,
+            sum_benchmark_sq: 0.0,
+            sum_cross: 0.0,
+        })
+    }
+
+    /// Returns the running `(Σy, Σx, Σx², Σxy)` sums of the window, with y the asset and x the
+    /// benchmark returns.
+    #[must_use]
+    pub const fn sums(&self) -> (TAFloat, TAFloat, TAFloat, TAFloat) {
+        (
+            self.sum_returns,
+            self.sum_benchmark,
+            self.sum_benchmark_sq,
+            self.sum_cross,
+        )
+    }
+
+    /// Adds a return pair to the window and updates the sums, without computing Beta.
+    fn push(&mut self, input_return: TAFloat, input_benchmark: TAFloat) -> Result<(), KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_return.is_nan() || input_benchmark.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back((input_return, input_benchmark));
+        if self.window.len() > self.opt_period {
+            let (old_return, old_benchmark) = self.window.pop_front().unwrap_or((0.0, 0.0));
+            (
+                self.sum_benchmark,
+                self.sum_returns,
+                self.sum_benchmark_sq,
+                self.sum_cross,
+            ) = slide(
+                (input_benchmark, input_return),
+                (old_benchmark, old_return),
+                (
+                    self.sum_benchmark,
+                    self.sum_returns,
+                    self.sum_benchmark_sq,
+                    self.sum_cross,
+                ),
+            );
+        } else {
+            self.sum_returns += input_return;
+            self.sum_benchmark += input_benchmark;
+            self.sum_benchmark_sq = input_benchmark.mul_add(input_benchmark, self.sum_benchmark_sq);
+            self.sum_cross = input_benchmark.mul_add(input_return, self.sum_cross);
+        }
+        Ok(())
+    }
+}
+
+impl Indicator<(TAFloat, TAFloat)> for CapmBeta {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &(TAFloat, TAFloat)) -> Result<TAFloat, KandError> {
+        let (input_return, input_benchmark) = *input;
+        self.push(input_return, input_benchmark)?;
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(slope(
+            self.sum_benchmark,
+            self.sum_returns,
+            self.sum_benchmark_sq,
+            self.sum_cross,
+            self.opt_period,
+        ))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum_returns = 0.0;
+        self.sum_benchmark = 0.0;
+        self.sum_benchmark_sq = 0.0;
+        self.sum_cross = 0.0;
+    }
+}
+
+/// Streaming TA-Lib compatible Beta over two aligned price series.
+///
+/// Consumes `(input_0, input_1)` price pairs and turns them into returns internally, so folding
+/// [`Indicator::update`] over both series reproduces [`beta`], including its 0 on a flat run.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Beta {
+    prev: Option<(TAFloat, TAFloat)>,
+    returns: CapmBeta,
+}
+
+impl Beta {
+    /// Creates a streaming TA-Lib compatible Beta.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of returns in the rolling window (must be >= 1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 1
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            prev: None,
+            returns: CapmBeta {
+                opt_period,
+                window: VecDeque::with_capacity(opt_period + 1),
+                sum_returns: 0.0,
+                sum_benchmark: 0.0,
+                sum_benchmark_sq: 0.0,
+                sum_cross: 0.0,
+            },
+        })
+    }
+}
+
+impl Indicator<(TAFloat, TAFloat)> for Beta {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &(TAFloat, TAFloat)) -> Result<TAFloat, KandError> {
+        let (input_0, input_1) = *input;
+        #[cfg(feature = "check-nan")]
+        {
+            if input_0.is_nan() || input_1.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let Some((prev_0, prev_1)) = self.prev.replace((input_0, input_1)) else {
+            return Ok(TAFloat::NAN);
+        };
+        // Series 1 plays the asset and series 0 the benchmark
+        self.returns
+            .push(price_return(input_1, prev_1), price_return(input_0, prev_0))?;
+        if !self.returns.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        let (sum_y, sum_x, sum_xx, sum_xy) = self.returns.sums();
+        Ok(talib_slope(
+            sum_x,
+            sum_y,
+            sum_xx,
+            sum_xy,
+            self.returns.opt_period,
+        ))
+    }
+
+    fn lookback(&self) -> usize {
+        self.returns.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.returns.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.prev = None;
+        self.returns.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    // Real BTC/ETH daily closing prices from Binance
+    const INPUT_BTC: [TAFloat; 30] = [
+        103_297.99, 102_120.01, 100_963.87, 105_333.93, 106_083.0, 107_340.58, 106_947.06,
+        107_047.59, 107_296.79, 108_356.93, 107_146.5, 105_681.14, 108_849.6, 109_584.78,
+        107_984.24, 108_198.12, 109_203.84, 108_262.94, 108_922.98, 111_233.99, 116_010.0,
+        117_527.66, 117_420.0, 119_086.64, 119_841.18, 117_758.09, 118_630.43, 119_177.56,
+        117_924.84, 117_893.24,
+    ];
+    const INPUT_ETH: [TAFloat; 30] = [
+        2406.49, 2295.73, 2227.7, 2411.66, 2448.45, 2418.49, 2415.96, 2423.17, 2435.62, 2500.09,
+        2485.47, 2405.01, 2570.41, 2591.25, 2508.04, 2516.41, 2570.35, 2542.29, 2615.25, 2768.74,
+        2951.29, 2958.22, 2943.28, 2972.03, 3013.62, 3137.89, 3371.35, 3476.87, 3546.92, 3552.85,
+    ];
+
+    #[test]
+    fn test_beta_calculation() {
+        let opt_period = 5;
+        let len = INPUT_BTC.len();
+        let mut output_beta = vec![0.0; len];
+        let mut output_sum_x = vec![0.0; len];
+        let mut output_sum_y = vec![0.0; len];
+        let mut output_sum_xx = vec![0.0; len];
+        let mut output_sum_xy = vec![0.0; len];
+
+        beta(
+            &INPUT_BTC,
+            &INPUT_ETH,
+            opt_period,
+            &mut output_beta,
+            &mut output_sum_x,
+            &mut output_sum_y,
+            &mut output_sum_xx,
+            &mut output_sum_xy,
+        )
+        .unwrap();
+
+        // First 5 values should be NaN
+        for value in &output_beta[..opt_period] {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            2.148_636_640_166_262,
+            1.908_525_621_770_546_2,
+            1.826_102_008_219_981_5,
+            -0.390_852_960_563_505_3,
+            0.264_552_129_491_034_57,
+            1.468_696_127_321_111_7,
+            1.997_689_664_996_455_5,
+            2.096_251_643_305_155,
+            2.066_990_185_292_590_3,
+            2.109_631_713_074_161_8,
+            2.262_375_112_906_881_6,
+            2.270_268_672_256_538,
+            1.944_832_435_616_386_6,
+            2.342_211_260_979_244,
+            2.357_407_203_769_286,
+            1.494_764_866_171_142_1,
+            1.515_882_414_555_867_8,
+            1.576_202_286_175_843,
+            1.814_294_649_518_895_2,
+            1.597_078_742_139_491,
+            -0.964_244_982_889_469_4,
+            -0.331_732_128_101_163_9,
+            -0.543_272_315_032_387,
+            0.393_602_243_212_620_8,
+            0.712_782_403_980_081_3,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_beta[i + opt_period], expected, epsilon = 0.000_000_1);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        for i in (opt_period + 1)..len {
+            let (beta, sum_x, sum_y, sum_xx, sum_xy) = beta_inc(
+                INPUT_BTC[i],
+                INPUT_ETH[i],
+                INPUT_BTC[i - 1],
+                INPUT_ETH[i - 1],
+                INPUT_BTC[i - opt_period],
+                INPUT_ETH[i - opt_period],
+                INPUT_BTC[i - opt_period - 1],
+                INPUT_ETH[i - opt_period - 1],
+                output_sum_x[i - 1],
+                output_sum_y[i - 1],
+                output_sum_xx[i - 1],
+                output_sum_xy[i - 1],
+                opt_period,
+            )
+            .unwrap();
+            assert_relative_eq!(beta, output_beta[i], epsilon = 0.000_000_1);
+            assert_relative_eq!(sum_x, output_sum_x[i], epsilon = 0.000_000_1);
+            assert_relative_eq!(sum_y, output_sum_y[i], epsilon = 0.000_000_1);
+            assert_relative_eq!(sum_xx, output_sum_xx[i], epsilon = 0.000_000_1);
+            assert_relative_eq!(sum_xy, output_sum_xy[i], epsilon = 0.000_000_1);
+        }
+    }
+
+    #[test]
+    fn test_beta_indicator() {
+        let opt_period = 5;
+        let len = INPUT_BTC.len();
+        let mut output_beta = vec![0.0; len];
+        let mut sums = vec![vec![0.0; len]; 4];
+        let [sum_x, sum_y, sum_xx, sum_xy] = &mut sums[..] else {
+            unreachable!()
+        };
+        beta(
+            &INPUT_BTC,
+            &INPUT_ETH,
+            opt_period,
+            &mut output_beta,
+            sum_x,
+            sum_y,
+            sum_xx,
+            sum_xy,
+        )
+        .unwrap();
+
+        let mut indicator = Beta::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let value = indicator.update(&(INPUT_BTC[i], INPUT_ETH[i])).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_beta[i], epsilon = 1e-9);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_beta_flat_run() {
+        // Series 0 is flat, so the denominator is zero and TA-Lib outputs 0
+        let input_0 = [100.0; 8];
+        let input_1 = [10.0, 10.5, 10.2, 10.8, 11.0, 10.7, 10.9, 11.3];
+        let opt_period = 3;
+        let len = input_0.len();
+        let mut output_beta = vec![0.0; len];
+        let mut output_sum_x = vec![0.0; len];
+        let mut output_sum_y = vec![0.0; len];
+        let mut output_sum_xx = vec![0.0; len];
+        let mut output_sum_xy = vec![0.0; len];
+        beta(
+            &input_0,
+            &input_1,
+            opt_period,
+            &mut output_beta,
+            &mut output_sum_x,
+            &mut output_sum_y,
+            &mut output_sum_xx,
+            &mut output_sum_xy,
+        )
+        .unwrap();
+        for value in &output_beta[opt_period..] {
+            assert_eq!(*value, 0.0);
+        }
+
+        let i = opt_period + 1;
+        let (result, ..) = beta_inc(
+            input_0[i],
+            input_1[i],
+            input_0[i - 1],
+            input_1[i - 1],
+            input_0[i - opt_period],
+            input_1[i - opt_period],
+            input_0[i - opt_period - 1],
+            input_1[i - opt_period - 1],
+            output_sum_x[i - 1],
+            output_sum_y[i - 1],
+            output_sum_xx[i - 1],
+            output_sum_xy[i - 1],
+            opt_period,
+        )
+        .unwrap();
+        assert_eq!(result, 0.0);
+
+        let mut indicator = Beta::new(opt_period).unwrap();
+        for i in 0..len {
+            let value = indicator.update(&(input_0[i], input_1[i])).unwrap();
+            if i >= opt_period {
+                assert_eq!(value, 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_capm_beta_calculation() {
+        let input_benchmark = [
+            0.010, -0.004, 0.007, 0.002, -0.012, 0.015, 0.001, -0.006, 0.009, 0.004, -0.003, 0.011,
+        ];
+        let input_returns = [
+            0.018, -0.010, 0.009, 0.006, -0.020, 0.024, -0.002, -0.011, 0.016, 0.003, -0.008, 0.019,
+        ];
+        let opt_period = 5;
+        let len = input_returns.len();
+        let mut output_beta = vec![0.0; len];
+        let mut output_sum_returns = vec![0.0; len];
+        let mut output_sum_benchmark = vec![0.0; len];
+        let mut output_sum_benchmark_sq = vec![0.0; len];
+        let mut output_sum_cross = vec![0.0; len];
+
+        capm_beta(
+            &input_returns,
+            &input_benchmark,
+            opt_period,
+            &mut output_beta,
+            &mut output_sum_returns,
+            &mut output_sum_benchmark,
+            &mut output_sum_benchmark_sq,
+            &mut output_sum_cross,
+        )
+        .unwrap();
+
+        for value in &output_beta[..4] {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            1.713_367_609_254_499,
+            1.647_695_202_257_760_7,
+            1.615_621_788_283_658_8,
+            1.648_780_487_804_878,
+            1.672_673_931_265_716_6,
+            1.745_655_608_214_849_3,
+            1.782_608_695_652_174,
+            1.825_688_073_394_495_3,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_beta[i + 4], expected, epsilon = 0.000_000_1);
+        }
+
+        // Streaming matches batch
+        let mut indicator = CapmBeta::new(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), capm_lookback(opt_period).unwrap());
+        for i in 0..len {
+            let value = indicator
+                .update(&(input_returns[i], input_benchmark[i]))
+                .unwrap();
+            if i < 4 {
+                assert!(value.is_nan());
+            } else {
+                assert_relative_eq!(value, output_beta[i], epsilon = 1e-9);
+            }
+        }
+        let (sum_returns, sum_benchmark, sum_benchmark_sq, sum_cross) = indicator.sums();
+        assert_relative_eq!(sum_returns, output_sum_returns[len - 1], epsilon = 1e-12);
+        assert_relative_eq!(
+            sum_benchmark,
+            output_sum_benchmark[len - 1],
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            sum_benchmark_sq,
+            output_sum_benchmark_sq[len - 1],
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(sum_cross, output_sum_cross[len - 1], epsilon = 1e-12);
+
+        // A flat benchmark leaves Beta undefined
+        let mut flat = CapmBeta::new(3).unwrap();
+        for value in [0.01, 0.02, -0.01] {
+            assert!(flat.update(&(value, 0.0)).unwrap().is_nan());
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use std::collections::VecDeque;
+
+use super::drawdown::DrawdownState;
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Calmar ratio calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For the Calmar ratio, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::calmar;
+/// let period = 252;
+/// let lookback = calmar::lookback(period).unwrap();
+/// assert_eq!(lookback, 251); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates the rolling Calmar ratio for a series of periodic returns.
+///
+/// # Description
+/// The Calmar ratio divides the compound annual growth rate by the maximum drawdown of the equity
+/// curve built from the returns in the window. The equity curve starts at 1 before the first
+/// return of each window. By convention the ratio has no risk-free term.
+///
+/// Maximum drawdown depends on the order of the returns, so unlike [`sharpe`](super::sharpe)
+/// each window is rescanned, costing O(period) per bar.
+///
+/// # Mathematical Formula
+/// ```text
+/// Equity[t] = Product(1 + r[i]) for i in 0..=t
+/// MaxDD = Max(1 - Equity[t] / Max(1, Equity[0..=t]))
+/// CAGR = Equity[n-1] ^ (PeriodsPerYear / n) - 1
+/// Calmar = CAGR / MaxDD
+/// ```
+///
+/// # Calculation Steps
+/// 1. Compound the returns of the window into an equity curve
+/// 2. Track the running peak and the deepest drawdown from it
+/// 3. Annualize the total growth and divide by the maximum drawdown
+/// 4. Fill the first (period-1) values with NaN
+///
+/// A window without any drawdown produces NaN.
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns (e.g. 0.01 for +1%)
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_periods_per_year` - Number of return periods per year (e.g. 252.0 for daily bars)
+/// * `output_calmar` - Array to store the Calmar ratio
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output array doesn't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::calmar;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005, -0.01];
+/// let mut output_calmar = vec![0.0; 5];
+///
+/// calmar::calmar(&input_returns, 3, 252.0, &mut output_calmar).unwrap();
+/// // First (period-1) values are NaN, followed by rolling Calmar ratios
+/// ```
+pub fn calmar(
+    input_returns: &[TAFloat],
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    output_calmar: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_calmar.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for i in lookback..len {
+        let window = &input_returns[i - lookback..=i];
+        output_calmar[i] = ratio(window.iter().copied(), opt_period, opt_periods_per_year);
+    }
+
+    // Fill initial values with NAN
+    for value in output_calmar.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the Calmar ratio of an entire return series.
+///
+/// # Description
+/// Treats the whole series as a single window; see [`calmar`] for the formula.
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns
+/// * `opt_periods_per_year` - Number of return periods per year
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The Calmar ratio, or NaN if the equity curve never draws down
+///
+/// # Errors
+/// * Returns `KandError::InsufficientData` if there are fewer than 2 returns
+/// * Returns `KandError::InvalidParameter` if periods per year is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::calmar;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005, -0.01];
+/// let ratio = calmar::calmar_ratio(&input_returns, 252.0).unwrap();
+/// ```
+pub fn calmar_ratio(
+    input_returns: &[TAFloat],
+    opt_periods_per_year: TAFloat,
+) -> Result<TAFloat, KandError> {
+    let len = input_returns.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Data sufficiency check
+        if len < 2 {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    Ok(ratio(
+        input_returns.iter().copied(),
+        len,
+        opt_periods_per_year,
+    ))
+}
+
+/// Calmar ratio of the `count` returns yielded by `returns`.
+fn ratio(
+    returns: impl Iterator<Item = TAFloat>,
+    count: usize,
+    opt_periods_per_year: TAFloat,
+) -> TAFloat {
+    let mut equity: TAFloat = 1.0;
+    let mut drawdown = DrawdownState::new();
+    drawdown.update(equity);
+    for value in returns {
+        equity *= 1.0 + value;
+        drawdown.update(equity);
+    }
+    if drawdown.max_drawdown <= 0.0 {
+        return TAFloat::NAN;
+    }
+    let cagr = equity.powf(opt_periods_per_year / count as TAFloat) - 1.0;
+    cagr / (drawdown.max_drawdown / 100.0)
+}
+
+/// Streaming rolling Calmar ratio that keeps the last `opt_period` returns.
+///
+/// Reads [`Bar::close`] as the periodic return. Folding [`Indicator::update`] over a series
+/// reproduces [`calmar`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::calmar::Calmar};
+///
+/// let mut calmar = Calmar::new(3, 252.0).unwrap();
+/// for r in [0.01, -0.02, 0.015, 0.005, -0.01] {
+///     let value = calmar.update_value(r).unwrap();
+/// }
+/// assert!(calmar.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Calmar {
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    window: VecDeque<TAFloat>,
+}
+
+impl Calmar {
+    /// Creates a streaming rolling Calmar ratio.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+    /// * `opt_periods_per_year` - Number of return periods per year (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or periods per year is not positive
+    pub fn new(opt_period: usize, opt_periods_per_year: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            opt_periods_per_year,
+            window: VecDeque::with_capacity(opt_period + 1),
+        })
+    }
+
+    /// Feeds a raw return instead of a [`Bar`] and returns the rolling Calmar ratio for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_return` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_return: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_return.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_return);
+        if self.window.len() > self.opt_period {
+            self.window.pop_front();
+        }
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(ratio(
+            self.window.iter().copied(),
+            self.opt_period,
+            self.opt_periods_per_year,
+        ))
+    }
+}
+
+impl Indicator for Calmar {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_RETURNS: [TAFloat; 20] = [
+        0.012, -0.008, 0.004, 0.015, -0.021, 0.007, 0.003, -0.012, 0.018, 0.006, -0.004, 0.009,
+        -0.015, 0.011, 0.002, -0.006, 0.013, -0.009, 0.005, 0.010,
+    ];
+
+    #[test]
+    fn test_calmar_calculation() {
+        let opt_period = 10;
+        let mut output_calmar = vec![0.0; INPUT_RETURNS.len()];
+
+        calmar(&INPUT_RETURNS, opt_period, 252.0, &mut output_calmar).unwrap();
+
+        // First 9 values should be NaN
+        for value in output_calmar.iter().take(9) {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            34.599_221_278_351_09,
+            8.809_268_064_418_108,
+            36.700_672_884_705_13,
+            6.102_374_578_999_915,
+            1.416_150_289_048_987_7,
+            56.929_347_038_674_564,
+            22.417_124_817_143_37,
+            47.719_420_353_428_56,
+            56.801_299_167_308_82,
+            22.642_295_736_710_53,
+            32.035_014_257_248_74,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_calmar[i + 9], expected, epsilon = 0.00001);
+        }
+
+        // Full-series ratio
+        let ratio = calmar_ratio(&INPUT_RETURNS, 252.0).unwrap();
+        assert_relative_eq!(ratio, 27.387_291_300_778_504, epsilon = 0.00001);
+
+        // An equity curve that never draws down leaves the ratio undefined
+        assert!(calmar_ratio(&[0.01, 0.02, 0.005], 252.0).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_calmar_indicator() {
+        let opt_period = 10;
+        let mut output_calmar = vec![0.0; INPUT_RETURNS.len()];
+        calmar(&INPUT_RETURNS, opt_period, 252.0, &mut output_calmar).unwrap();
+
+        let mut indicator = Calmar::new(opt_period, 252.0).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &value) in INPUT_RETURNS.iter().enumerate() {
+            let calmar = indicator.update(&Bar::from(value)).unwrap();
+            if i < lookback {
+                assert!(calmar.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(calmar, output_calmar[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use super::moments::{self, RollingCoMoments};
+use crate::{KandError, TAFloat, indicator::Indicator};
 
 /// Calculates the lookback period required for Correlation calculation.
 ///
@@ -55,10 +58,8 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
 /// - Σy² is the sum of squares of series 1
 ///
 /// # Calculation Steps
-/// 1. Calculate initial sums for the first period
-/// 2. Apply Pearson correlation formula
-/// 3. For subsequent periods, update sums incrementally
-/// 4. Fill initial values before lookback period with NaN
+/// 1. Compute the correlation with [`correl_welford`], which stays accurate for large values
+/// 2. Convert its rolling means, squared deviations and co-deviation into the running sums
 ///
 /// # Arguments
 /// * `input_0` - First input series
@@ -118,6 +119,121 @@ pub fn correl(
     output_sum_0_sq: &mut [TAFloat],
     output_sum_1_sq: &mut [TAFloat],
     output_sum_01: &mut [TAFloat],
+) -> Result<(), KandError> {
+    correl_welford(
+        input_0,
+        input_1,
+        opt_period,
+        output_correl,
+        output_sum_0,
+        output_sum_1,
+        output_sum_0_sq,
+        output_sum_1_sq,
+        output_sum_01,
+    )?;
+
+    // Convert means and deviations in place: sum = n * mean, sum_sq = M2 + sum * mean,
+    // sum_01 = C + sum_0 * mean_1
+    let n = opt_period as TAFloat;
+    for i in 0..output_correl.len() {
+        let mean_0 = output_sum_0[i];
+        let mean_1 = output_sum_1[i];
+        output_sum_0[i] = mean_0 * n;
+        output_sum_1[i] = mean_1 * n;
+        output_sum_0_sq[i] = output_sum_0[i].mul_add(mean_0, output_sum_0_sq[i]);
+        output_sum_1_sq[i] = output_sum_1[i].mul_add(mean_1, output_sum_1_sq[i]);
+        output_sum_01[i] = output_sum_0[i].mul_add(mean_1, output_sum_01[i]);
+    }
+
+    Ok(())
+}
+
+/// Calculates the Pearson Correlation Coefficient (CORREL), returning the Welford state.
+///
+/// Same values as [`correl`], but the state outputs are the rolling means, sums of squared
+/// deviations and co-deviation that seed [`correl_inc_welford`], instead of the running sums of
+/// [`correl_inc`].
+///
+/// NOTE: We don't reuse stddev here for performance reasons.
+/// Calling stddev twice + covariance would require 3 passes through the data, so the means,
+/// squared deviations and co-deviation are slid together in one pass with [`RollingCoMoments`].
+///
+/// # Mathematical Formula
+/// ```text
+/// r = Σ(x - x̄)(y - ȳ) / sqrt(Σ(x - x̄)² * Σ(y - ȳ)²) = C / sqrt(M2_x * M2_y)
+/// ```
+/// Where:
+/// - x̄ and ȳ are the means of series 0 and series 1 over the period
+/// - M2_x and M2_y are the sums of squared deviations from the means
+/// - C is the sum of co-deviations
+///
+/// # Calculation Steps
+/// 1. Accumulate means, squared deviations and co-deviation for the first period
+/// 2. Apply Pearson correlation formula
+/// 3. For subsequent periods, slide them with Welford's update using compensated sums
+/// 4. Fill initial values before lookback period with NaN
+///
+/// Unlike the textbook `n(Σxy) - (Σx)(Σy)` form, this does not cancel catastrophically for
+/// large values such as prices around 1e5.
+///
+/// # Arguments
+/// * `input_0` - First input series
+/// * `input_1` - Second input series
+/// * `opt_period` - The time period for correlation calculation (must be >= 2)
+/// * `output_correl` - Array to store calculated correlation values
+/// * `output_mean_0` - Array to store rolling mean of series 0
+/// * `output_mean_1` - Array to store rolling mean of series 1
+/// * `output_m2_0` - Array to store rolling sum of squared deviations of series 0
+/// * `output_m2_1` - Array to store rolling sum of squared deviations of series 1
+/// * `output_c_01` - Array to store rolling sum of co-deviations
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input data is empty
+/// * Returns `KandError::LengthMismatch` if arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if input contains NaN values (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::correl;
+/// let series1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// let series2 = vec![2.0, 4.0, 6.0, 8.0, 10.0];
+/// let period = 3;
+/// let mut output_correl = vec![0.0; 5];
+/// let mut output_mean_0 = vec![0.0; 5];
+/// let mut output_mean_1 = vec![0.0; 5];
+/// let mut output_m2_0 = vec![0.0; 5];
+/// let mut output_m2_1 = vec![0.0; 5];
+/// let mut output_c_01 = vec![0.0; 5];
+///
+/// correl::correl_welford(
+///     &series1,
+///     &series2,
+///     period,
+///     &mut output_correl,
+///     &mut output_mean_0,
+///     &mut output_mean_1,
+///     &mut output_m2_0,
+///     &mut output_m2_1,
+///     &mut output_c_01,
+/// )
+/// .unwrap();
+/// // output_correl = [NaN, NaN, 1.0, 1.0, 1.0] (perfect positive correlation)
+/// ```
+pub fn correl_welford(
+    input_0: &[TAFloat],
+    input_1: &[TAFloat],
+    opt_period: usize,
+    output_correl: &mut [TAFloat],
+    output_mean_0: &mut [TAFloat],
+    output_mean_1: &mut [TAFloat],
+    output_m2_0: &mut [TAFloat],
+    output_m2_1: &mut [TAFloat],
+    output_c_01: &mut [TAFloat],
 ) -> Result<(), KandError> {
     let len = input_0.len();
     let lookback = lookback(opt_period)?;
@@ -137,11 +253,11 @@ pub fn correl(
         // Length consistency check - all arrays must have same length
         if len != input_1.len()
             || len != output_correl.len()
-            || len != output_sum_0.len()
-            || len != output_sum_1.len()
-            || len != output_sum_0_sq.len()
-            || len != output_sum_1_sq.len()
-            || len != output_sum_01.len()
+            || len != output_mean_0.len()
+            || len != output_mean_1.len()
+            || len != output_m2_0.len()
+            || len != output_m2_1.len()
+            || len != output_c_01.len()
         {
             return Err(KandError::LengthMismatch);
         }
@@ -162,88 +278,42 @@ pub fn correl(
         }
     }
 
-    // Calculate initial sums for the first period
-    let mut sum_0 = 0.0;
-    let mut sum_1 = 0.0;
-    let mut sum_0_sq = 0.0;
-    let mut sum_1_sq = 0.0;
-    let mut sum_01 = 0.0;
-
-    // Initialize sums for the first window
+    // Accumulate moments for the first window
+    let mut moments = RollingCoMoments::new();
     for i in 0..opt_period {
-        let val_0 = input_0[i];
-        let val_1 = input_1[i];
-
-        sum_0 += val_0;
-        sum_1 += val_1;
-        sum_0_sq += val_0 * val_0;
-        sum_1_sq += val_1 * val_1;
-        sum_01 += val_0 * val_1;
-    }
-
-    // Store sums for first valid position
-    output_sum_0[lookback] = sum_0;
-    output_sum_1[lookback] = sum_1;
-    output_sum_0_sq[lookback] = sum_0_sq;
-    output_sum_1_sq[lookback] = sum_1_sq;
-    output_sum_01[lookback] = sum_01;
-
-    // Calculate first correlation value
-    let n = opt_period as TAFloat;
-    let numerator = n.mul_add(sum_01, -(sum_0 * sum_1));
-    let denominator_0 = n.mul_add(sum_0_sq, -(sum_0 * sum_0));
-    let denominator_1 = n.mul_add(sum_1_sq, -(sum_1 * sum_1));
-    let denominator = (denominator_0 * denominator_1).sqrt();
-
-    if denominator > 0.0 {
-        output_correl[lookback] = numerator / denominator;
-    } else {
-        output_correl[lookback] = TAFloat::NAN;
+        moments.push(input_0[i], input_1[i]);
     }
+    output_correl[lookback] = moments.correlation();
+    output_mean_0[lookback] = moments.series_0().mean();
+    output_mean_1[lookback] = moments.series_1().mean();
+    output_m2_0[lookback] = moments.series_0().m2();
+    output_m2_1[lookback] = moments.series_1().m2();
+    output_c_01[lookback] = moments.c_01();
 
     // Calculate subsequent correlations using sliding window
     for i in opt_period..len {
-        // Remove old values and add new values
-        let old_0 = input_0[i - opt_period];
-        let old_1 = input_1[i - opt_period];
-        let new_0 = input_0[i];
-        let new_1 = input_1[i];
-
-        // Update sums
-        sum_0 = sum_0 - old_0 + new_0;
-        sum_1 = sum_1 - old_1 + new_1;
-        sum_0_sq = new_0.mul_add(new_0, old_0.mul_add(-old_0, sum_0_sq));
-        sum_1_sq = new_1.mul_add(new_1, old_1.mul_add(-old_1, sum_1_sq));
-        sum_01 = new_0.mul_add(new_1, old_0.mul_add(-old_1, sum_01));
-
-        // Store updated sums
-        output_sum_0[i] = sum_0;
-        output_sum_1[i] = sum_1;
-        output_sum_0_sq[i] = sum_0_sq;
-        output_sum_1_sq[i] = sum_1_sq;
-        output_sum_01[i] = sum_01;
-
-        // Calculate correlation for this window
-        let numerator = n.mul_add(sum_01, -(sum_0 * sum_1));
-        let denominator_0 = n.mul_add(sum_0_sq, -(sum_0 * sum_0));
-        let denominator_1 = n.mul_add(sum_1_sq, -(sum_1 * sum_1));
-        let denominator = (denominator_0 * denominator_1).sqrt();
-
-        if denominator > 0.0 {
-            output_correl[i] = numerator / denominator;
-        } else {
-            output_correl[i] = TAFloat::NAN;
-        }
+        moments.replace(
+            input_0[i],
+            input_1[i],
+            input_0[i - opt_period],
+            input_1[i - opt_period],
+        );
+        output_correl[i] = moments.correlation();
+        output_mean_0[i] = moments.series_0().mean();
+        output_mean_1[i] = moments.series_1().mean();
+        output_m2_0[i] = moments.series_0().m2();
+        output_m2_1[i] = moments.series_1().m2();
+        output_c_01[i] = moments.c_01();
     }
 
     // Fill initial values with NaN
     for i in 0..lookback {
         output_correl[i] = TAFloat::NAN;
-        output_sum_0[i] = TAFloat::NAN;
-        output_sum_1[i] = TAFloat::NAN;
-        output_sum_0_sq[i] = TAFloat::NAN;
-        output_sum_1_sq[i] = TAFloat::NAN;
-        output_sum_01[i] = TAFloat::NAN;
+        output_mean_0[i] = TAFloat::NAN;
+        output_mean_1[i] = TAFloat::NAN;
+        output_m2_0[i] = TAFloat::NAN;
+        output_m2_1[i] = TAFloat::NAN;
+        output_c_01[i] = TAFloat::NAN;
     }
 
     Ok(())
@@ -284,8 +354,15 @@ pub fn correl(
 /// * Returns `KandError::InvalidParameter` if period is less than 2
 /// * Returns `KandError::NaNDetected` if any input contains NaN (with "`check-nan`" feature)
 ///
+/// # Migration
+/// `n(Σxy) - (Σx)(Σy)` cancels catastrophically when values are large relative to their spread,
+/// and the running sums drift over long streams. Switch to [`correl_welford`] and
+/// [`correl_inc_welford`], which carry means, squared deviations and the co-deviation instead:
+/// `mean = sum / n`, `M2 = sum_sq - sum * mean` and `C = sum_01 - sum_0 * mean_1`.
+///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::stats::correl;
 /// let prev_sum_0 = 6.0;      // sum of [1.0, 2.0, 3.0]
 /// let prev_sum_1 = 12.0;     // sum of [2.0, 4.0, 6.0]
@@ -305,6 +382,7 @@ pub fn correl(
 ///         period
 ///     ).unwrap();
 /// ```
+#[deprecated(note = "sum-based state is numerically unstable; use `correl_inc_welford`")]
 pub fn correl_inc(
     input_new_0: TAFloat,
     input_new_1: TAFloat,
@@ -378,6 +456,189 @@ pub fn correl_inc(
     ))
 }
 
+/// Calculates the latest Correlation value incrementally from the Welford state.
+///
+/// This function provides an optimized way to update the Correlation value when new data arrives,
+/// avoiding full recalculation of the entire series.
+///
+/// # Mathematical Formula
+/// The correlation is updated by:
+/// 1. Sliding the means, squared deviations and co-deviation with Welford's update
+/// 2. Recalculating correlation as `C / sqrt(M2_0 * M2_1)`
+///
+/// # Arguments
+/// * `input_new_0` - The newest value from series 0 to add
+/// * `input_new_1` - The newest value from series 1 to add
+/// * `input_old_0` - The oldest value from series 0 to remove
+/// * `input_old_1` - The oldest value from series 1 to remove
+/// * `prev_mean_0` - Previous mean of series 0
+/// * `prev_mean_1` - Previous mean of series 1
+/// * `prev_m2_0` - Previous sum of squared deviations of series 0
+/// * `prev_m2_1` - Previous sum of squared deviations of series 1
+/// * `prev_c_01` - Previous sum of co-deviations
+/// * `opt_period` - The time period (must be >= 2)
+///
+/// Seed the previous state from the mean, M2 and co-deviation outputs of [`correl_welford`].
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing:
+///   - New correlation value
+///   - New mean of series 0
+///   - New mean of series 1
+///   - New sum of squared deviations of series 0
+///   - New sum of squared deviations of series 1
+///   - New sum of co-deviations
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::NaNDetected` if any input contains NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::correl;
+/// let prev_mean_0 = 2.0; // mean of [1.0, 2.0, 3.0]
+/// let prev_mean_1 = 4.0; // mean of [2.0, 4.0, 6.0]
+/// let prev_m2_0 = 2.0;   // squared deviations [1, 0, 1]
+/// let prev_m2_1 = 8.0;   // squared deviations [4, 0, 4]
+/// let prev_c_01 = 4.0;   // co-deviations [2, 0, 2]
+/// let new_0 = 4.0;
+/// let new_1 = 8.0;
+/// let old_0 = 1.0;
+/// let old_1 = 2.0;
+/// let period = 3;
+///
+/// let (new_correl, new_mean_0, new_mean_1, new_m2_0, new_m2_1, new_c_01) =
+///     correl::correl_inc_welford(
+///         new_0, new_1, old_0, old_1,
+///         prev_mean_0, prev_mean_1, prev_m2_0, prev_m2_1, prev_c_01,
+///         period
+///     ).unwrap();
+/// ```
+pub fn correl_inc_welford(
+    input_new_0: TAFloat,
+    input_new_1: TAFloat,
+    input_old_0: TAFloat,
+    input_old_1: TAFloat,
+    prev_mean_0: TAFloat,
+    prev_mean_1: TAFloat,
+    prev_m2_0: TAFloat,
+    prev_m2_1: TAFloat,
+    prev_c_01: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check for all inputs
+        if input_new_0.is_nan()
+            || input_new_1.is_nan()
+            || input_old_0.is_nan()
+            || input_old_1.is_nan()
+            || prev_mean_0.is_nan()
+            || prev_mean_1.is_nan()
+            || prev_m2_0.is_nan()
+            || prev_m2_1.is_nan()
+            || prev_c_01.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (new_mean_0, new_mean_1, new_m2_0, new_m2_1, new_c_01) = moments::co_slide(
+        prev_mean_0,
+        prev_mean_1,
+        prev_m2_0,
+        prev_m2_1,
+        prev_c_01,
+        input_new_0,
+        input_new_1,
+        input_old_0,
+        input_old_1,
+        opt_period,
+    );
+    let new_correl = moments::correlation(new_c_01, new_m2_0, new_m2_1);
+
+    Ok((
+        new_correl, new_mean_0, new_mean_1, new_m2_0, new_m2_1, new_c_01,
+    ))
+}
+
+/// Streaming rolling correlation over two aligned series.
+///
+/// Consumes `(input_0, input_1)` pairs and keeps the last `opt_period` of them together with
+/// their [`RollingCoMoments`], so folding [`Indicator::update`] over both series reproduces [`correl`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Correl {
+    opt_period: usize,
+    window: VecDeque<(TAFloat, TAFloat)>,
+    moments: RollingCoMoments,
+}
+
+impl Correl {
+    /// Creates a streaming rolling correlation.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+            moments: RollingCoMoments::new(),
+        })
+    }
+}
+
+impl Indicator<(TAFloat, TAFloat)> for Correl {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &(TAFloat, TAFloat)) -> Result<TAFloat, KandError> {
+        let (val_0, val_1) = *input;
+        #[cfg(feature = "check-nan")]
+        {
+            if val_0.is_nan() || val_1.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back((val_0, val_1));
+        if self.window.len() > self.opt_period {
+            let (old_0, old_1) = self.window.pop_front().unwrap_or((0.0, 0.0));
+            self.moments.replace(val_0, val_1, old_0, old_1);
+            return Ok(self.moments.correlation());
+        }
+
+        self.moments.push(val_0, val_1);
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(self.moments.correlation())
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.moments = RollingCoMoments::new();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -385,6 +646,8 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
+    #[allow(clippy::needless_range_loop)]
     fn test_correl_perfect_positive() {
         // Test with perfectly correlated data (y = 2x)
         let input_0 = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
@@ -440,6 +703,7 @@ fn test_correl_perfect_positive() {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_correl_perfect_negative() {
         // Test with perfectly negative correlated data (y = -x + 10)
         let input_0 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
@@ -472,6 +736,7 @@ fn test_correl_perfect_negative() {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_correl_no_variance() {
         // Test when one series has no variance
         let input_0 = vec![5.0, 5.0, 5.0, 5.0, 5.0];
@@ -520,6 +785,7 @@ fn test_correl_lookback() {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn test_talib_compatibility() {
         // Real BTC/ETH daily closing prices from Binance
         // Testing against TA-Lib CORREL output with period=14
@@ -590,4 +856,128 @@ fn test_talib_compatibility() {
             assert_relative_eq!(output_correl[i + 13], expected, epsilon = 0.000_000_000_1);
         }
     }
+
+    #[test]
+    fn test_correl_indicator() {
+        let input_0 = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+        ];
+        let input_1 = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+        ];
+        let opt_period = 5;
+        let len = input_0.len();
+        let mut output_correl = vec![0.0; len];
+        let mut sums = vec![vec![0.0; len]; 5];
+        let [sum_0, sum_1, sum_0_sq, sum_1_sq, sum_01] = &mut sums[..] else {
+            unreachable!()
+        };
+        correl(
+            &input_0,
+            &input_1,
+            opt_period,
+            &mut output_correl,
+            sum_0,
+            sum_1,
+            sum_0_sq,
+            sum_1_sq,
+            sum_01,
+        )
+        .unwrap();
+
+        let mut indicator = Correl::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let value = indicator.update(&(input_0[i], input_1[i])).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_correl[i], epsilon = 1e-9);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_correl_welford() {
+        let input_0 = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+        ];
+        let input_1 = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+        ];
+        let opt_period = 5;
+        let len = input_0.len();
+        let mut output_correl = vec![0.0; len];
+        let mut expected_correl = vec![0.0; len];
+        let mut state = vec![vec![0.0; len]; 5];
+        let mut sums = vec![vec![0.0; len]; 5];
+        let [mean_0, mean_1, m2_0, m2_1, c_01] = &mut state[..] else {
+            unreachable!()
+        };
+        let [sum_0, sum_1, sum_0_sq, sum_1_sq, sum_01] = &mut sums[..] else {
+            unreachable!()
+        };
+        correl_welford(
+            &input_0,
+            &input_1,
+            opt_period,
+            &mut output_correl,
+            mean_0,
+            mean_1,
+            m2_0,
+            m2_1,
+            c_01,
+        )
+        .unwrap();
+        correl(
+            &input_0,
+            &input_1,
+            opt_period,
+            &mut expected_correl,
+            sum_0,
+            sum_1,
+            sum_0_sq,
+            sum_1_sq,
+            sum_01,
+        )
+        .unwrap();
+
+        let lookback = lookback(opt_period).unwrap();
+        let mut prev = (
+            mean_0[lookback],
+            mean_1[lookback],
+            m2_0[lookback],
+            m2_1[lookback],
+            c_01[lookback],
+        );
+        for i in (lookback + 1)..len {
+            let (correl, new_mean_0, new_mean_1, new_m2_0, new_m2_1, new_c_01) =
+                correl_inc_welford(
+                    input_0[i],
+                    input_1[i],
+                    input_0[i - opt_period],
+                    input_1[i - opt_period],
+                    prev.0,
+                    prev.1,
+                    prev.2,
+                    prev.3,
+                    prev.4,
+                    opt_period,
+                )
+                .unwrap();
+            assert_relative_eq!(correl, output_correl[i], epsilon = 1e-9);
+            assert_relative_eq!(correl, expected_correl[i], epsilon = 1e-9);
+            prev = (new_mean_0, new_mean_1, new_m2_0, new_m2_1, new_c_01);
+        }
+    }
 }
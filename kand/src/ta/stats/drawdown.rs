@@ -0,0 +1,520 @@
+use crate::{KandError, TAFloat, TAInt, indicator::Indicator, types::Bar};
+
+/// Returns the lookback period required for drawdown calculation.
+///
+/// # Description
+/// Every bar of an equity curve has a drawdown relative to the peak so far, the first bar
+/// included, so no warm-up is needed.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (always 0)
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown;
+/// let lookback = drawdown::lookback().unwrap();
+/// assert_eq!(lookback, 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Running drawdown state of an equity curve.
+///
+/// Holds the peak so far, the current and deepest drawdown and how long the curve has been under
+/// water. The public fields describe the latest bar after each [`DrawdownState::update`]; the
+/// trough of the current episode is kept privately to measure the time to recovery.
+///
+/// Durations are counted in bars. A drawdown episode starts on the first bar below the peak and
+/// ends on the first bar that reaches the peak again, which is the bar reporting `recovery`.
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown::DrawdownState;
+///
+/// let mut state = DrawdownState::new();
+/// for equity in [100.0, 90.0, 80.0, 95.0, 105.0] {
+///     state.update(equity);
+/// }
+/// assert_eq!(state.max_drawdown, 20.0);
+/// assert_eq!(state.max_duration, 3);
+/// assert_eq!(state.recovery, 2); // bars from the trough at 80 to the new high
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DrawdownState {
+    /// Highest equity seen so far (NaN before the first update)
+    pub peak: TAFloat,
+    /// Percentage below the peak on the latest bar (0 at a peak)
+    pub drawdown: TAFloat,
+    /// Deepest drawdown percentage seen so far
+    pub max_drawdown: TAFloat,
+    /// Bars since the last peak (0 at a peak)
+    pub duration: TAInt,
+    /// Longest run of bars below a peak seen so far
+    pub max_duration: TAInt,
+    /// Bars from the trough to the peak on the bar that ends a drawdown, otherwise 0
+    pub recovery: TAInt,
+    trough_drawdown: TAFloat,
+    trough_duration: TAInt,
+}
+
+impl DrawdownState {
+    /// Creates an empty drawdown state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            peak: TAFloat::NAN,
+            drawdown: 0.0,
+            max_drawdown: 0.0,
+            duration: 0,
+            max_duration: 0,
+            recovery: 0,
+            trough_drawdown: 0.0,
+            trough_duration: 0,
+        }
+    }
+
+    /// Advances the state by one equity value and returns its drawdown percentage.
+    pub fn update(&mut self, equity: TAFloat) -> TAFloat {
+        if self.peak.is_nan() || equity >= self.peak {
+            // Reaching the peak again ends the current drawdown episode
+            self.recovery = if self.duration > 0 {
+                self.duration + 1 - self.trough_duration
+            } else {
+                0
+            };
+            self.peak = equity;
+            self.drawdown = 0.0;
+            self.duration = 0;
+            self.trough_drawdown = 0.0;
+            self.trough_duration = 0;
+        } else {
+            self.recovery = 0;
+            self.drawdown = (self.peak - equity) / self.peak * 100.0;
+            self.duration += 1;
+            if self.drawdown > self.trough_drawdown {
+                self.trough_drawdown = self.drawdown;
+                self.trough_duration = self.duration;
+            }
+        }
+        self.max_drawdown = self.max_drawdown.max(self.drawdown);
+        self.max_duration = self.max_duration.max(self.duration);
+        self.drawdown
+    }
+}
+
+impl Default for DrawdownState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Calculates drawdown analytics for an equity curve.
+///
+/// # Description
+/// Drawdown measures how far an equity curve has fallen from its running peak. Alongside the
+/// per-bar drawdown this reports the deepest drawdown so far, how many bars the curve has been
+/// under water, the longest such stretch, and on each bar that regains the peak, how many bars
+/// the recovery from the trough took.
+///
+/// # Mathematical Formula
+/// ```text
+/// Peak[t] = Max(Equity[0..=t])
+/// Drawdown[t] = (Peak[t] - Equity[t]) / Peak[t] * 100
+/// MaxDrawdown[t] = Max(Drawdown[0..=t])
+/// Duration[t] = t - (last index where Equity == Peak)
+/// Recovery[t] = t - (index of the deepest drawdown in the episode), on the bar that regains the peak
+/// ```
+///
+/// # Calculation Steps
+/// 1. Track the running peak of the equity curve
+/// 2. Measure the percentage drop from the peak and count bars since it
+/// 3. Remember the trough of the current episode to time its recovery
+/// 4. Keep the running maxima of drawdown and duration
+///
+/// # Arguments
+/// * `input_equity` - Array of equity values (must be positive)
+/// * `output_drawdown` - Array to store the drawdown percentage (positive below the peak)
+/// * `output_max_drawdown` - Array to store the deepest drawdown percentage so far
+/// * `output_duration` - Array to store the bars since the last peak
+/// * `output_max_duration` - Array to store the longest drawdown duration so far
+/// * `output_recovery` - Array to store the bars from trough to recovery, non-zero only on the bar
+///   that regains the peak
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown;
+/// let input_equity = vec![100.0, 90.0, 80.0, 95.0, 105.0];
+/// let mut output_drawdown = vec![0.0; 5];
+/// let mut output_max_drawdown = vec![0.0; 5];
+/// let mut output_duration = vec![0; 5];
+/// let mut output_max_duration = vec![0; 5];
+/// let mut output_recovery = vec![0; 5];
+///
+/// drawdown::drawdown(
+///     &input_equity,
+///     &mut output_drawdown,
+///     &mut output_max_drawdown,
+///     &mut output_duration,
+///     &mut output_max_duration,
+///     &mut output_recovery,
+/// )
+/// .unwrap();
+/// assert_eq!(output_drawdown[2], 20.0);
+/// assert_eq!(output_duration[3], 3);
+/// assert_eq!(output_recovery[4], 2);
+/// ```
+pub fn drawdown(
+    input_equity: &[TAFloat],
+    output_drawdown: &mut [TAFloat],
+    output_max_drawdown: &mut [TAFloat],
+    output_duration: &mut [TAInt],
+    output_max_duration: &mut [TAInt],
+    output_recovery: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_equity.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_drawdown.len() != len
+            || output_max_drawdown.len() != len
+            || output_duration.len() != len
+            || output_max_duration.len() != len
+            || output_recovery.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_equity {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut state = DrawdownState::new();
+    for i in 0..len {
+        output_drawdown[i] = state.update(input_equity[i]);
+        output_max_drawdown[i] = state.max_drawdown;
+        output_duration[i] = state.duration;
+        output_max_duration[i] = state.max_duration;
+        output_recovery[i] = state.recovery;
+    }
+
+    Ok(())
+}
+
+/// Calculates the drawdown of the latest equity value incrementally.
+///
+/// # Description
+/// All running values live in the [`DrawdownState`], which this function advances in place.
+/// Start a curve with `DrawdownState::new()`; folding this function over it reproduces
+/// [`drawdown`], with the other outputs read from the state's public fields.
+///
+/// # Arguments
+/// * `input_equity` - The latest equity value
+/// * `state` - Drawdown state, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The drawdown percentage on success
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If `input_equity` is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown::{self, DrawdownState};
+///
+/// let mut state = DrawdownState::new();
+/// for equity in [100.0, 90.0, 80.0] {
+///     let dd = drawdown::drawdown_inc(equity, &mut state).unwrap();
+/// }
+/// assert_eq!(state.drawdown, 20.0);
+/// assert_eq!(state.duration, 2);
+/// ```
+pub fn drawdown_inc(
+    input_equity: TAFloat,
+    state: &mut DrawdownState,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_equity.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(state.update(input_equity))
+}
+
+/// Calculates the maximum drawdown percentage of an entire equity curve.
+///
+/// # Arguments
+/// * `input_equity` - Array of equity values (must be positive)
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The deepest drawdown percentage
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown;
+/// let mdd = drawdown::max_drawdown(&[100.0, 120.0, 90.0, 130.0]).unwrap();
+/// assert_eq!(mdd, 25.0);
+/// ```
+pub fn max_drawdown(input_equity: &[TAFloat]) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if input_equity.is_empty() {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    let mut state = DrawdownState::new();
+    for &equity in input_equity {
+        drawdown_inc(equity, &mut state)?;
+    }
+    Ok(state.max_drawdown)
+}
+
+/// Streaming drawdown analytics over an equity curve.
+///
+/// Reads [`Bar::close`] as the equity value and outputs `(drawdown, max_drawdown, duration,
+/// max_duration, recovery)`. Folding [`Indicator::update`] over a curve reproduces [`drawdown`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::drawdown::Drawdown};
+///
+/// let mut drawdown = Drawdown::new();
+/// for equity in [100.0, 90.0, 80.0, 95.0, 105.0] {
+///     let (dd, max_dd, duration, max_duration, recovery) = drawdown.update_value(equity).unwrap();
+/// }
+/// assert!(drawdown.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Drawdown {
+    state: DrawdownState,
+}
+
+impl Drawdown {
+    /// Creates a streaming drawdown tracker.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: DrawdownState::new(),
+        }
+    }
+
+    /// Feeds a raw equity value instead of a [`Bar`] and returns
+    /// `(drawdown, max_drawdown, duration, max_duration, recovery)` for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_equity` is NaN (with "`check-nan`")
+    pub fn update_value(
+        &mut self,
+        input_equity: TAFloat,
+    ) -> Result<(TAFloat, TAFloat, TAInt, TAInt, TAInt), KandError> {
+        let drawdown = drawdown_inc(input_equity, &mut self.state)?;
+        Ok((
+            drawdown,
+            self.state.max_drawdown,
+            self.state.duration,
+            self.state.max_duration,
+            self.state.recovery,
+        ))
+    }
+
+    /// Returns the running drawdown state.
+    #[must_use]
+    pub const fn state(&self) -> &DrawdownState {
+        &self.state
+    }
+}
+
+impl Default for Drawdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for Drawdown {
+    type Output = (TAFloat, TAFloat, TAInt, TAInt, TAInt);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn is_ready(&self) -> bool {
+        !self.state.peak.is_nan()
+    }
+
+    fn reset(&mut self) {
+        self.state = DrawdownState::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_EQUITY: [TAFloat; 16] = [
+        100.0, 104.0, 102.0, 96.0, 98.0, 106.0, 110.0, 99.0, 88.0, 93.0, 101.0, 110.0, 112.0,
+        108.0, 100.8, 105.0,
+    ];
+
+    #[test]
+    fn test_drawdown_calculation() {
+        let len = INPUT_EQUITY.len();
+        let mut output_drawdown = vec![0.0; len];
+        let mut output_max_drawdown = vec![0.0; len];
+        let mut output_duration = vec![0; len];
+        let mut output_max_duration = vec![0; len];
+        let mut output_recovery = vec![0; len];
+
+        drawdown(
+            &INPUT_EQUITY,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+            &mut output_duration,
+            &mut output_max_duration,
+            &mut output_recovery,
+        )
+        .unwrap();
+
+        let expected_drawdown = [
+            0.0,
+            0.0,
+            1.923_076_923_076_923,
+            7.692_307_692_307_693,
+            5.769_230_769_230_769,
+            0.0,
+            0.0,
+            10.0,
+            20.0,
+            15.454_545_454_545_455,
+            8.181_818_181_818_182,
+            0.0,
+            0.0,
+            3.571_428_571_428_571,
+            10.0,
+            6.25,
+        ];
+        let expected_max_drawdown = [
+            0.0,
+            0.0,
+            1.923_076_923_076_923,
+            7.692_307_692_307_693,
+            7.692_307_692_307_693,
+            7.692_307_692_307_693,
+            7.692_307_692_307_693,
+            10.0,
+            20.0,
+            20.0,
+            20.0,
+            20.0,
+            20.0,
+            20.0,
+            20.0,
+            20.0,
+        ];
+        let expected_duration = [0, 0, 1, 2, 3, 0, 0, 1, 2, 3, 4, 0, 0, 1, 2, 3];
+        let expected_max_duration = [0, 0, 1, 2, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4];
+        let expected_recovery = [0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0];
+        for i in 0..len {
+            assert_relative_eq!(output_drawdown[i], expected_drawdown[i], epsilon = 0.00001);
+            assert_relative_eq!(
+                output_max_drawdown[i],
+                expected_max_drawdown[i],
+                epsilon = 0.00001
+            );
+            assert_eq!(output_duration[i], expected_duration[i]);
+            assert_eq!(output_max_duration[i], expected_max_duration[i]);
+            assert_eq!(output_recovery[i], expected_recovery[i]);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut state = DrawdownState::new();
+        for (i, &equity) in INPUT_EQUITY.iter().enumerate() {
+            let dd = drawdown_inc(equity, &mut state).unwrap();
+            assert_relative_eq!(dd, output_drawdown[i], epsilon = 0.00001);
+            assert_eq!(state.duration, output_duration[i]);
+            assert_eq!(state.recovery, output_recovery[i]);
+        }
+
+        assert_relative_eq!(
+            max_drawdown(&INPUT_EQUITY).unwrap(),
+            20.0,
+            epsilon = 0.00001
+        );
+    }
+
+    #[test]
+    fn test_drawdown_indicator() {
+        let len = INPUT_EQUITY.len();
+        let mut output_drawdown = vec![0.0; len];
+        let mut output_max_drawdown = vec![0.0; len];
+        let mut output_duration = vec![0; len];
+        let mut output_max_duration = vec![0; len];
+        let mut output_recovery = vec![0; len];
+        drawdown(
+            &INPUT_EQUITY,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+            &mut output_duration,
+            &mut output_max_duration,
+            &mut output_recovery,
+        )
+        .unwrap();
+
+        let mut indicator = Drawdown::new();
+        assert_eq!(indicator.lookback(), lookback().unwrap());
+        assert!(!indicator.is_ready());
+        for (i, &equity) in INPUT_EQUITY.iter().enumerate() {
+            let (dd, max_dd, duration, max_duration, recovery) =
+                indicator.update(&Bar::from(equity)).unwrap();
+            assert_relative_eq!(dd, output_drawdown[i], epsilon = 0.00001);
+            assert_relative_eq!(max_dd, output_max_drawdown[i], epsilon = 0.00001);
+            assert_eq!(duration, output_duration[i]);
+            assert_eq!(max_duration, output_max_duration[i]);
+            assert_eq!(recovery, output_recovery[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
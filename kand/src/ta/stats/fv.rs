@@ -0,0 +1,110 @@
+use crate::{KandError, TAFloat};
+
+/// Calculates the future value of an investment, as the spreadsheet `FV` function does.
+///
+/// # Description
+/// The future value is the balance left after `input_nper` periods of compounding at
+/// `input_rate`, starting from the present value `input_pv` and adding the payment `input_pmt`
+/// every period. Cash paid out is negative and cash received is positive, so a deposit of 100
+/// grows into a future value of about -100 × (1 + rate)^nper from the depositor's point of view.
+///
+/// # Mathematical Formula
+/// ```text
+/// FV = -(PV * (1 + r)^n + PMT * (1 + r * w) * ((1 + r)^n - 1) / r)
+/// FV = -(PV + PMT * n)                                  when r = 0
+/// ```
+/// Where:
+/// - r is the rate per period and n the number of periods
+/// - w is 1 when payments are made at the beginning of each period and 0 at the end
+///
+/// # Arguments
+/// * `input_rate` - Interest rate per period (e.g. 0.05 / 12 for 5% a year paid monthly)
+/// * `input_nper` - Number of payment periods
+/// * `input_pmt` - Payment made each period
+/// * `input_pv` - Present value
+/// * `opt_begin` - Whether payments are due at the beginning (`true`) or end (`false`) of each period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The future value on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if the rate is not greater than -1
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::fv;
+/// // Deposit 200 a month at the start of each month for 10 months at 6% a year, on top of 500
+/// let value = fv::fv(0.06 / 12.0, 10.0, -200.0, -500.0, true).unwrap();
+/// assert!((value - 2581.40).abs() < 0.01);
+/// ```
+pub fn fv(
+    input_rate: TAFloat,
+    input_nper: TAFloat,
+    input_pmt: TAFloat,
+    input_pv: TAFloat,
+    opt_begin: bool,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if input_rate <= -1.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_rate.is_nan() || input_nper.is_nan() || input_pmt.is_nan() || input_pv.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (growth, annuity) = growth_annuity(input_rate, input_nper, opt_begin);
+    Ok(-input_pmt.mul_add(annuity, input_pv * growth))
+}
+
+/// Returns the compounding factor `(1 + r)^n` and the annuity factor
+/// `(1 + r * w) * ((1 + r)^n - 1) / r` shared by the time-value-of-money functions.
+///
+/// The annuity factor tends to `n` as the rate tends to zero.
+pub(crate) fn growth_annuity(rate: TAFloat, nper: TAFloat, begin: bool) -> (TAFloat, TAFloat) {
+    if rate == 0.0 {
+        return (1.0, nper);
+    }
+    let growth = (1.0 + rate).powf(nper);
+    let timing = if begin { 1.0 + rate } else { 1.0 };
+    (growth, timing * (growth - 1.0) / rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_fv_calculation() {
+        // Spreadsheet reference values
+        assert_relative_eq!(
+            fv(0.06 / 12.0, 10.0, -200.0, -500.0, true).unwrap(),
+            2_581.403_374_060_136_7,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            fv(0.12 / 12.0, 12.0, -1000.0, 0.0, false).unwrap(),
+            12_682.503_013_196_976,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            fv(0.11 / 12.0, 35.0, -2000.0, 0.0, true).unwrap(),
+            82_846.246_371_900_6,
+            epsilon = 1e-6
+        );
+
+        // Zero rate is a plain sum
+        assert_relative_eq!(fv(0.0, 10.0, -100.0, -50.0, false).unwrap(), 1050.0);
+
+        #[cfg(feature = "check")]
+        assert!(fv(-1.0, 10.0, -100.0, 0.0, false).is_err());
+    }
+}
@@ -0,0 +1,571 @@
+use std::collections::VecDeque;
+
+use super::moments::CompensatedSum;
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// `ln(2)` at the precision of [`TAFloat`].
+const LN_2: TAFloat = std::f64::consts::LN_2 as TAFloat;
+
+/// Calculates the lookback period required for Garman-Klass volatility calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For Garman-Klass volatility, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::garman_klass;
+/// let period = 20;
+/// let lookback = garman_klass::lookback(period).unwrap();
+/// assert_eq!(lookback, 19); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates rolling Garman-Klass volatility from OHLC prices.
+///
+/// # Description
+/// The Garman-Klass estimator extends Parkinson's high-low range with the open-to-close move of
+/// each bar, which makes it the most efficient of the classic range estimators for a driftless
+/// price. It assumes no drift and ignores opening gaps. The per-bar variance is annualized by
+/// `opt_periods_per_year`; pass 1.0 to keep it per bar.
+///
+/// # Mathematical Formula
+/// ```text
+/// u[t] = 0.5 * ln(High[t] / Low[t])^2 - (2 * ln(2) - 1) * ln(Close[t] / Open[t])^2
+/// Variance = Sum(u) / n
+/// GarmanKlass = sqrt(Variance * PeriodsPerYear)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Sum the Garman-Klass terms of the first window
+/// 2. Scale the sum to a variance and annualize it
+/// 3. Slide the window, adding the newest and removing the oldest term
+/// 4. Fill the first (period-1) values with NaN
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+/// * `opt_periods_per_year` - Number of bars per year (e.g. 252.0 for daily bars)
+/// * `output_garman_klass` - Array to store the annualized Garman-Klass volatility
+/// * `output_sum` - Array to store the running sum of Garman-Klass terms
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty or any price is not positive
+/// * Returns `KandError::LengthMismatch` if input and output arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::garman_klass;
+/// let input_open = vec![100.0, 101.0, 101.5, 102.0, 103.0];
+/// let input_high = vec![101.0, 102.5, 102.0, 103.5, 104.0];
+/// let input_low = vec![99.0, 100.5, 100.0, 101.0, 102.0];
+/// let input_close = vec![100.5, 101.5, 101.0, 103.0, 103.5];
+/// let mut output_garman_klass = vec![0.0; 5];
+/// let mut output_sum = vec![0.0; 5];
+///
+/// garman_klass::garman_klass(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     3,
+///     252.0,
+///     &mut output_garman_klass,
+///     &mut output_sum,
+/// )
+/// .unwrap();
+/// // First (period-1) values are NaN, followed by annualized volatilities
+/// assert!(output_garman_klass[1].is_nan());
+/// assert!(output_garman_klass[2] > 0.0);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn garman_klass(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    output_garman_klass: &mut [TAFloat],
+    output_sum: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_high.len() != len
+            || input_low.len() != len
+            || input_close.len() != len
+            || output_garman_klass.len() != len
+            || output_sum.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Price range check
+        if input_open
+            .iter()
+            .chain(input_high)
+            .chain(input_low)
+            .chain(input_close)
+            .any(|price| *price <= 0.0)
+        {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for i in 0..len {
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let term = |i: usize| gk_term(input_open[i], input_high[i], input_low[i], input_close[i]);
+
+    // Calculate initial sum
+    let mut sum = CompensatedSum::new(0.0);
+    for i in 0..opt_period {
+        sum.add(term(i));
+    }
+    output_garman_klass[lookback] = volatility(sum.value(), opt_period, opt_periods_per_year);
+    output_sum[lookback] = sum.value();
+
+    // Slide the window
+    for i in opt_period..len {
+        sum.add(term(i));
+        sum.add(-term(i - opt_period));
+        output_garman_klass[i] = volatility(sum.value(), opt_period, opt_periods_per_year);
+        output_sum[i] = sum.value();
+    }
+
+    // Fill initial values with NAN
+    for value in output_garman_klass.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+    for value in output_sum.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Garman-Klass volatility using incremental computation.
+///
+/// # Arguments
+/// * `input_open` - Opening price of the newest bar
+/// * `input_high` - High price of the newest bar
+/// * `input_low` - Low price of the newest bar
+/// * `input_close` - Closing price of the newest bar
+/// * `input_old_open` - Opening price of the bar leaving the window
+/// * `input_old_high` - High price of the bar leaving the window
+/// * `input_old_low` - Low price of the bar leaving the window
+/// * `input_old_close` - Closing price of the bar leaving the window
+/// * `prev_sum` - Previous sum of Garman-Klass terms in the window
+/// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+/// * `opt_periods_per_year` - Number of bars per year
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple containing (`garman_klass`, `new_sum`) on
+///   success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InvalidData` if any price is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::garman_klass;
+/// let (garman_klass, new_sum) = garman_klass::garman_klass_inc(
+///     100.0, 101.0, 99.5, 100.5, // new bar
+///     100.0, 102.0, 99.0, 101.0, // bar leaving the window
+///     0.0004, // previous sum
+///     3,      // period
+///     252.0,  // periods per year
+/// )
+/// .unwrap();
+/// assert!(new_sum < 0.0004);
+/// assert!(garman_klass > 0.0);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn garman_klass_inc(
+    input_open: TAFloat,
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    input_old_open: TAFloat,
+    input_old_high: TAFloat,
+    input_old_low: TAFloat,
+    input_old_close: TAFloat,
+    prev_sum: TAFloat,
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 2 || opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Price range check
+        if [
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            input_old_open,
+            input_old_high,
+            input_old_low,
+            input_old_close,
+        ]
+        .iter()
+        .any(|price| *price <= 0.0)
+        {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_open.is_nan()
+            || input_high.is_nan()
+            || input_low.is_nan()
+            || input_close.is_nan()
+            || input_old_open.is_nan()
+            || input_old_high.is_nan()
+            || input_old_low.is_nan()
+            || input_old_close.is_nan()
+            || prev_sum.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let new_sum = prev_sum + gk_term(input_open, input_high, input_low, input_close)
+        - gk_term(
+            input_old_open,
+            input_old_high,
+            input_old_low,
+            input_old_close,
+        );
+    let garman_klass = volatility(new_sum, opt_period, opt_periods_per_year);
+
+    Ok((garman_klass, new_sum))
+}
+
+/// Garman-Klass variance term of a bar.
+fn gk_term(open: TAFloat, high: TAFloat, low: TAFloat, close: TAFloat) -> TAFloat {
+    let range = (high / low).ln();
+    let body = (close / open).ln();
+    let body_weight = LN_2.mul_add(2.0, -1.0);
+    (0.5 * range).mul_add(range, -body_weight * body * body)
+}
+
+/// Annualized Garman-Klass volatility of a window from its sum of terms.
+fn volatility(sum: TAFloat, opt_period: usize, opt_periods_per_year: TAFloat) -> TAFloat {
+    // A large open-to-close move relative to the range can drive the estimate below zero
+    let variance = sum.max(0.0) / opt_period as TAFloat;
+    (variance * opt_periods_per_year).sqrt()
+}
+
+/// Streaming rolling Garman-Klass volatility that keeps the terms of the last `opt_period`
+/// bars.
+///
+/// Reads the full OHLC of each [`Bar`]. Folding [`Indicator::update`] over a series reproduces
+/// [`garman_klass`].
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, stats::garman_klass::GarmanKlass};
+///
+/// let mut garman_klass = GarmanKlass::new(3, 252.0).unwrap();
+/// for (open, high, low, close) in [
+///     (100.0, 101.0, 99.0, 100.5),
+///     (101.0, 102.5, 100.5, 101.5),
+///     (101.5, 102.0, 100.0, 101.0),
+/// ] {
+///     let value = garman_klass
+///         .update(&Bar::new(open, high, low, close, 0.0))
+///         .unwrap();
+/// }
+/// assert!(garman_klass.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GarmanKlass {
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    window: VecDeque<TAFloat>,
+    sum: CompensatedSum,
+}
+
+impl GarmanKlass {
+    /// Creates a streaming rolling Garman-Klass volatility.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+    /// * `opt_periods_per_year` - Number of bars per year (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or periods per year is not positive
+    pub fn new(opt_period: usize, opt_periods_per_year: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            opt_periods_per_year,
+            window: VecDeque::with_capacity(opt_period + 1),
+            sum: CompensatedSum::new(0.0),
+        })
+    }
+}
+
+impl Indicator for GarmanKlass {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.open.is_nan()
+                || input.high.is_nan()
+                || input.low.is_nan()
+                || input.close.is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        #[cfg(feature = "check")]
+        {
+            if input.open <= 0.0 || input.high <= 0.0 || input.low <= 0.0 || input.close <= 0.0 {
+                return Err(KandError::InvalidData);
+            }
+        }
+
+        let term = gk_term(input.open, input.high, input.low, input.close);
+        self.window.push_back(term);
+        self.sum.add(term);
+        if self.window.len() > self.opt_period {
+            let old_term = self.window.pop_front().unwrap_or(0.0);
+            self.sum.add(-old_term);
+        }
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(volatility(
+            self.sum.value(),
+            self.opt_period,
+            self.opt_periods_per_year,
+        ))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = CompensatedSum::new(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_OPEN: [TAFloat; 25] = [
+        35230.0, 35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+        35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0,
+        35024.6, 34939.5, 34952.6, 35000.0, 35041.8,
+    ];
+    const INPUT_HIGH: [TAFloat; 25] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+        35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+    ];
+    const INPUT_LOW: [TAFloat; 25] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+        34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+    ];
+    const INPUT_CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    #[test]
+    fn test_garman_klass_calculation() {
+        let opt_period = 10;
+        let len = INPUT_CLOSE.len();
+        let mut output_garman_klass = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+
+        garman_klass(
+            &INPUT_OPEN,
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            opt_period,
+            252.0,
+            &mut output_garman_klass,
+            &mut output_sum,
+        )
+        .unwrap();
+
+        // First 9 values should be NaN
+        for value in output_garman_klass.iter().take(9) {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            0.015_952_923_520_964_96,
+            0.015_236_968_712_470_671,
+            0.015_822_020_969_258_33,
+            0.015_955_100_524_164_13,
+            0.016_002_293_478_871_627,
+            0.019_719_817_686_462_637,
+            0.021_453_335_910_864_443,
+            0.022_987_726_401_069_573,
+            0.022_660_538_121_154_63,
+            0.022_154_705_818_896_277,
+            0.022_299_227_395_982_11,
+            0.023_375_231_499_692_825,
+            0.023_782_253_709_910_155,
+            0.023_544_672_003_045_847,
+            0.023_553_169_783_934_5,
+            0.020_769_178_791_562_787,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_garman_klass[i + 9], expected, epsilon = 1e-12);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut prev_sum = output_sum[9];
+        for i in 10..len {
+            let j = i - opt_period;
+            let (garman_klass, new_sum) = garman_klass_inc(
+                INPUT_OPEN[i],
+                INPUT_HIGH[i],
+                INPUT_LOW[i],
+                INPUT_CLOSE[i],
+                INPUT_OPEN[j],
+                INPUT_HIGH[j],
+                INPUT_LOW[j],
+                INPUT_CLOSE[j],
+                prev_sum,
+                opt_period,
+                252.0,
+            )
+            .unwrap();
+            assert_relative_eq!(garman_klass, output_garman_klass[i], epsilon = 1e-12);
+            prev_sum = new_sum;
+        }
+    }
+
+    #[test]
+    fn test_garman_klass_indicator() {
+        let opt_period = 10;
+        let len = INPUT_CLOSE.len();
+        let mut output_garman_klass = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        garman_klass(
+            &INPUT_OPEN,
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            opt_period,
+            252.0,
+            &mut output_garman_klass,
+            &mut output_sum,
+        )
+        .unwrap();
+
+        let mut indicator = GarmanKlass::new(opt_period, 252.0).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                INPUT_OPEN[i],
+                INPUT_HIGH[i],
+                INPUT_LOW[i],
+                INPUT_CLOSE[i],
+                0.0,
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_garman_klass[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert!(GarmanKlass::new(opt_period, 0.0).is_err());
+    }
+}
@@ -0,0 +1,506 @@
+use std::collections::VecDeque;
+
+use super::moments::{self, RollingMoments};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for historical volatility calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. A window of `opt_period` log returns needs `opt_period + 1` prices, so this
+/// equals the period.
+///
+/// # Arguments
+/// * `opt_period` - The number of log returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::hv;
+/// let period = 20;
+/// let lookback = hv::lookback(period).unwrap();
+/// assert_eq!(lookback, 20); // one extra price for the first return
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period)
+}
+
+/// Calculates rolling close-to-close historical volatility.
+///
+/// # Description
+/// Historical volatility is the sample standard deviation of log returns over a rolling window,
+/// annualized by `opt_periods_per_year`. It is the baseline the range-based estimators
+/// ([`parkinson`](super::parkinson), [`garman_klass`](super::garman_klass),
+/// [`rogers_satchell`](super::rogers_satchell) and [`yang_zhang`](super::yang_zhang)) are
+/// compared against. Pass 1.0 as `opt_periods_per_year` to keep it per bar.
+///
+/// # Mathematical Formula
+/// ```text
+/// r[t] = ln(Price[t] / Price[t-1])
+/// Variance = Sum((r - Mean(r))^2) / (n - 1)
+/// HV = sqrt(Variance * PeriodsPerYear)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Calculate the log returns of the first window
+/// 2. Track their mean and M2 with Welford's update (see [`RollingMoments`])
+/// 3. Slide the window, replacing the oldest return by the newest
+/// 4. Fill the first `period` values with NaN
+///
+/// # Arguments
+/// * `input_prices` - Array of prices
+/// * `opt_period` - The number of log returns in the rolling window (must be >= 2)
+/// * `opt_periods_per_year` - Number of bars per year (e.g. 252.0 for daily bars)
+/// * `output_hv` - Array to store the annualized historical volatility
+/// * `output_mean` - Array to store the rolling mean of log returns
+/// * `output_m2` - Array to store the rolling sum of squared deviations of log returns (M2)
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty or any price is not positive
+/// * Returns `KandError::LengthMismatch` if input and output arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InsufficientData` if input length is not greater than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::hv;
+/// let input_prices = vec![100.0, 101.0, 100.5, 102.0, 101.5, 103.0];
+/// let mut output_hv = vec![0.0; 6];
+/// let mut output_mean = vec![0.0; 6];
+/// let mut output_m2 = vec![0.0; 6];
+///
+/// hv::hv(
+///     &input_prices,
+///     3,
+///     252.0,
+///     &mut output_hv,
+///     &mut output_mean,
+///     &mut output_m2,
+/// )
+/// .unwrap();
+/// // First `period` values are NaN, followed by annualized volatilities
+/// assert!(output_hv[2].is_nan());
+/// assert!(output_hv[3] > 0.0);
+/// ```
+pub fn hv(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    output_hv: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_hv.len() != len || output_mean.len() != len || output_m2.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Price range check
+        if input_prices.iter().any(|price| *price <= 0.0) {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let log_return = |i: usize| (input_prices[i] / input_prices[i - 1]).ln();
+
+    // Calculate initial values
+    let mut moments = RollingMoments::new();
+    for i in 1..=opt_period {
+        moments.push(log_return(i));
+    }
+    output_hv[lookback] = volatility(moments.m2(), opt_period, opt_periods_per_year);
+    output_mean[lookback] = moments.mean();
+    output_m2[lookback] = moments.m2();
+
+    // Calculate remaining HV values incrementally
+    for i in opt_period + 1..len {
+        moments.replace(log_return(i), log_return(i - opt_period));
+        output_hv[i] = volatility(moments.m2(), opt_period, opt_periods_per_year);
+        output_mean[i] = moments.mean();
+        output_m2[i] = moments.m2();
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_hv[i] = TAFloat::NAN;
+        output_mean[i] = TAFloat::NAN;
+        output_m2[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling historical volatility using incremental computation.
+///
+/// # Arguments
+/// * `input_price` - Newest price
+/// * `input_prev_price` - Price before the newest one
+/// * `input_old_price` - Price that ends the log return leaving the window
+/// * `input_old_prev_price` - Price that starts the log return leaving the window
+/// * `prev_mean` - Previous mean of log returns in the window
+/// * `prev_m2` - Previous sum of squared deviations of log returns in the window
+/// * `opt_period` - The number of log returns in the rolling window (must be >= 2)
+/// * `opt_periods_per_year` - Number of bars per year
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (hv, `new_mean`,
+///   `new_m2`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InvalidData` if any price is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::hv;
+/// // Prices [100, 101, 100.5, 102] slide to [101, 100.5, 102, 101.5]
+/// let r1 = (101.0_f64 / 100.0).ln();
+/// let r2 = (100.5_f64 / 101.0).ln();
+/// let r3 = (102.0_f64 / 100.5).ln();
+/// let mean = (r1 + r2 + r3) / 3.0;
+/// let m2 = (r1 - mean).powi(2) + (r2 - mean).powi(2) + (r3 - mean).powi(2);
+/// let (hv, new_mean, new_m2) = hv::hv_inc(
+///     101.5, // newest price
+///     102.0, // price before the newest
+///     101.0, // end of the return leaving the window
+///     100.0, // start of the return leaving the window
+///     mean, m2, 3, 252.0,
+/// )
+/// .unwrap();
+/// assert!(hv > 0.0);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn hv_inc(
+    input_price: TAFloat,
+    input_prev_price: TAFloat,
+    input_old_price: TAFloat,
+    input_old_prev_price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 2 || opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Price range check
+        if input_price <= 0.0
+            || input_prev_price <= 0.0
+            || input_old_price <= 0.0
+            || input_old_prev_price <= 0.0
+        {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan()
+            || input_prev_price.is_nan()
+            || input_old_price.is_nan()
+            || input_old_prev_price.is_nan()
+            || prev_mean.is_nan()
+            || prev_m2.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (new_mean, new_m2) = moments::slide(
+        prev_mean,
+        prev_m2,
+        (input_price / input_prev_price).ln(),
+        (input_old_price / input_old_prev_price).ln(),
+        opt_period,
+    );
+    let hv = volatility(new_m2, opt_period, opt_periods_per_year);
+
+    Ok((hv, new_mean, new_m2))
+}
+
+/// Annualized volatility of a window from the M2 of its log returns.
+fn volatility(m2: TAFloat, opt_period: usize, opt_periods_per_year: TAFloat) -> TAFloat {
+    (m2 / (opt_period - 1) as TAFloat * opt_periods_per_year).sqrt()
+}
+
+/// Streaming rolling historical volatility that keeps the last `opt_period` log returns and
+/// their [`RollingMoments`].
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`hv`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hv {
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    prev_price: Option<TAFloat>,
+    window: VecDeque<TAFloat>,
+    moments: RollingMoments,
+}
+
+impl Hv {
+    /// Creates a streaming rolling historical volatility.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of log returns in the rolling window (must be >= 2)
+    /// * `opt_periods_per_year` - Number of bars per year (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or periods per year is not positive
+    pub fn new(opt_period: usize, opt_periods_per_year: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            opt_periods_per_year,
+            prev_price: None,
+            window: VecDeque::with_capacity(opt_period + 1),
+            moments: RollingMoments::new(),
+        })
+    }
+
+    /// Feeds a raw price instead of a [`Bar`] and returns the rolling historical volatility for
+    /// it.
+    ///
+    /// # Errors
+    /// * `KandError::InvalidData` - If `input_price` is not positive (with "check")
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        #[cfg(feature = "check")]
+        {
+            if input_price <= 0.0 {
+                return Err(KandError::InvalidData);
+            }
+        }
+
+        let Some(prev_price) = self.prev_price.replace(input_price) else {
+            return Ok(TAFloat::NAN);
+        };
+        let log_return = (input_price / prev_price).ln();
+        self.window.push_back(log_return);
+        if self.window.len() > self.opt_period {
+            let old_return = self.window.pop_front().unwrap_or(0.0);
+            self.moments.replace(log_return, old_return);
+        } else {
+            self.moments.push(log_return);
+        }
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(volatility(
+            self.moments.m2(),
+            self.opt_period,
+            self.opt_periods_per_year,
+        ))
+    }
+}
+
+impl Indicator for Hv {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.prev_price = None;
+        self.window.clear();
+        self.moments = RollingMoments::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    #[test]
+    fn test_hv_calculation() {
+        let opt_period = 10;
+        let len = INPUT_CLOSE.len();
+        let mut output_hv = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+
+        hv(
+            &INPUT_CLOSE,
+            opt_period,
+            252.0,
+            &mut output_hv,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+
+        // First 10 values should be NaN
+        for value in output_hv.iter().take(10) {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            0.018_405_469_110_376_358,
+            0.020_239_005_904_620_758,
+            0.019_855_702_259_460_643,
+            0.021_121_446_050_214_085,
+            0.023_171_744_377_487_116,
+            0.019_668_329_516_951_542,
+            0.019_377_604_035_420_375,
+            0.017_050_340_483_440_4,
+            0.019_651_811_515_776_95,
+            0.020_165_481_048_708_668,
+            0.022_406_286_879_533_92,
+            0.019_741_675_310_699_397,
+            0.022_507_394_471_692_17,
+            0.023_585_020_420_778_58,
+            0.022_558_856_924_550_126,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_hv[i + 10], expected, epsilon = 1e-12);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut prev_mean = output_mean[10];
+        let mut prev_m2 = output_m2[10];
+        for i in 11..len {
+            let (hv, new_mean, new_m2) = hv_inc(
+                INPUT_CLOSE[i],
+                INPUT_CLOSE[i - 1],
+                INPUT_CLOSE[i - opt_period],
+                INPUT_CLOSE[i - opt_period - 1],
+                prev_mean,
+                prev_m2,
+                opt_period,
+                252.0,
+            )
+            .unwrap();
+            assert_relative_eq!(hv, output_hv[i], epsilon = 1e-12);
+            prev_mean = new_mean;
+            prev_m2 = new_m2;
+        }
+
+        // Too little data for a single window of returns
+        assert!(
+            hv(
+                &INPUT_CLOSE[..10],
+                opt_period,
+                252.0,
+                &mut output_hv[..10],
+                &mut output_mean[..10],
+                &mut output_m2[..10],
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_hv_indicator() {
+        let opt_period = 10;
+        let len = INPUT_CLOSE.len();
+        let mut output_hv = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        hv(
+            &INPUT_CLOSE,
+            opt_period,
+            252.0,
+            &mut output_hv,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+
+        let mut indicator = Hv::new(opt_period, 252.0).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in INPUT_CLOSE.iter().enumerate() {
+            let value = indicator.update_value(price).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_hv[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert!(indicator.update_value(INPUT_CLOSE[0]).unwrap().is_nan());
+        assert!(Hv::new(opt_period, 0.0).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use super::rate::newton;
+use crate::{KandError, TAFloat, TAInt};
+
+/// Number of days in the year used by `XIRR` to turn dates into year fractions.
+const DAYS_PER_YEAR: TAFloat = 365.0;
+
+/// Calculates the internal rate of return of periodic cash flows, as the spreadsheet `IRR`
+/// function does.
+///
+/// # Description
+/// The internal rate of return is the rate at which the net present value of the cash flows is
+/// zero. The first cash flow happens today and the rest one period apart. It is found with
+/// Newton's method starting from `opt_guess`.
+///
+/// # Mathematical Formula
+/// ```text
+/// Σ values[i] / (1 + IRR)^i = 0,  i = 0..len
+/// ```
+///
+/// # Arguments
+/// * `input_values` - Cash flows, with at least one positive and one negative value
+/// * `opt_guess` - Starting rate for the solver, 0.1 in spreadsheets
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The rate of return per period on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if the cash flows do not contain both a positive and a
+///   negative value
+/// * Returns `KandError::InvalidParameter` if the guess is not greater than -1
+/// * Returns `KandError::CalculationError` if the solver does not converge
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::irr;
+/// let values = [-70000.0, 12000.0, 15000.0, 18000.0, 21000.0, 26000.0];
+/// let value = irr::irr(&values, 0.1).unwrap();
+/// assert!((value - 0.0866).abs() < 1e-4);
+/// ```
+pub fn irr(input_values: &[TAFloat], opt_guess: TAFloat) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if !has_sign_change(input_values) {
+            return Err(KandError::InvalidData);
+        }
+        if opt_guess <= -1.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if opt_guess.is_nan() || input_values.iter().any(|v| v.is_nan()) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    newton("IRR", opt_guess, |r| {
+        // Horner's scheme in the discount factor, carrying the derivative along
+        let discount = 1.0 / (1.0 + r);
+        let (value, slope) = input_values.iter().rev().fold(
+            (0.0, 0.0),
+            |(value, slope): (TAFloat, TAFloat), &cash| {
+                (
+                    value.mul_add(discount, cash),
+                    slope.mul_add(discount, value),
+                )
+            },
+        );
+        (value, -slope * discount * discount)
+    })
+}
+
+/// Calculates the internal rate of return of dated cash flows, as the spreadsheet `XIRR`
+/// function does.
+///
+/// # Description
+/// Like `irr`, but each cash flow is discounted by the time elapsed since the first one, in
+/// years of 365 days, so the cash flows need not be evenly spaced. The result is an annual
+/// rate.
+///
+/// # Mathematical Formula
+/// ```text
+/// Σ values[i] / (1 + XIRR)^((dates[i] - dates[0]) / 365) = 0
+/// ```
+///
+/// # Arguments
+/// * `input_values` - Cash flows, with at least one positive and one negative value
+/// * `input_dates` - Day numbers of the cash flows (e.g. spreadsheet serial dates or days since
+///   an epoch), none earlier than the first
+/// * `opt_guess` - Starting rate for the solver, 0.1 in spreadsheets
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The annual rate of return on success
+///
+/// # Errors
+/// * Returns `KandError::LengthMismatch` if the values and dates differ in length
+/// * Returns `KandError::InvalidData` if there are no dates, the cash flows do not contain both
+///   a positive and a negative value, or a date precedes the first date
+/// * Returns `KandError::InvalidParameter` if the guess is not greater than -1
+/// * Returns `KandError::CalculationError` if the solver does not converge
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::irr;
+/// let values = [-10000.0, 2750.0, 4250.0, 3250.0, 2750.0];
+/// // 2008-01-01, 2008-03-01, 2008-10-30, 2009-02-15, 2009-04-01
+/// let dates = [39448, 39508, 39751, 39859, 39904];
+/// let value = irr::xirr(&values, &dates, 0.1).unwrap();
+/// assert!((value - 0.3734).abs() < 1e-4);
+/// ```
+pub fn xirr(
+    input_values: &[TAFloat],
+    input_dates: &[TAInt],
+    opt_guess: TAFloat,
+) -> Result<TAFloat, KandError> {
+    let Some(&start) = input_dates.first() else {
+        return Err(KandError::InvalidData);
+    };
+
+    #[cfg(feature = "check")]
+    {
+        if input_values.len() != input_dates.len() {
+            return Err(KandError::LengthMismatch);
+        }
+        if !has_sign_change(input_values) || input_dates.iter().any(|&d| d < start) {
+            return Err(KandError::InvalidData);
+        }
+        if opt_guess <= -1.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if opt_guess.is_nan() || input_values.iter().any(|v| v.is_nan()) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    newton("XIRR", opt_guess, |r| {
+        let base = 1.0 + r;
+        input_values.iter().zip(input_dates).fold(
+            (0.0, 0.0),
+            |(value, slope): (TAFloat, TAFloat), (&cash, &date)| {
+                let years = (date - start) as TAFloat / DAYS_PER_YEAR;
+                let discounted = cash / base.powf(years);
+                (
+                    value + discounted,
+                    (-years * discounted).mul_add(1.0 / base, slope),
+                )
+            },
+        )
+    })
+}
+
+/// Returns whether the cash flows contain both a positive and a negative value.
+#[cfg(feature = "check")]
+fn has_sign_change(values: &[TAFloat]) -> bool {
+    values.iter().any(|&v| v > 0.0) && values.iter().any(|&v| v < 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_irr_calculation() {
+        let values = [-70000.0, 12000.0, 15000.0, 18000.0, 21000.0, 26000.0];
+        assert_relative_eq!(
+            irr(&values, 0.1).unwrap(),
+            0.086_630_948_036_531_51,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            irr(&values[..5], -0.1).unwrap(),
+            -0.021_244_848_273_410_978,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            irr(&values[..3], -0.4).unwrap(),
+            -0.443_506_941_334_740_5,
+            epsilon = 1e-10
+        );
+
+        #[cfg(feature = "check")]
+        {
+            assert!(irr(&[100.0, 200.0], 0.1).is_err());
+            assert!(irr(&values, -1.0).is_err());
+        }
+    }
+
+    #[test]
+    fn test_xirr_calculation() {
+        let values = [-10000.0, 2750.0, 4250.0, 3250.0, 2750.0];
+        let dates = [39448, 39508, 39751, 39859, 39904];
+        assert_relative_eq!(
+            xirr(&values, &dates, 0.1).unwrap(),
+            0.373_362_533_518_831_36,
+            epsilon = 1e-10
+        );
+
+        // Yearly dates reduce to the periodic rate
+        let values = [-70000.0, 12000.0, 15000.0, 18000.0, 21000.0, 26000.0];
+        let dates = [0, 365, 730, 1095, 1460, 1825];
+        assert_relative_eq!(
+            xirr(&values, &dates, 0.1).unwrap(),
+            irr(&values, 0.1).unwrap(),
+            epsilon = 1e-10
+        );
+        assert!(xirr(&[], &[], 0.1).is_err());
+
+        #[cfg(feature = "check")]
+        {
+            assert!(xirr(&values, &dates[..5], 0.1).is_err());
+            assert!(xirr(&values, &[0, 365, -1, 1095, 1460, 1825], 0.1).is_err());
+        }
+    }
+}
@@ -0,0 +1,412 @@
+use std::collections::VecDeque;
+
+use super::winrate::{self, TradeStats};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Kelly fraction calculation.
+///
+/// The lookback period represents the number of trades needed before the first valid output
+/// can be calculated. For the rolling Kelly fraction, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The number of trades in the rolling window (must be >= 1)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 1
+///
+/// # Example
+/// ```
+/// use kand::stats::kelly;
+/// let lookback = kelly::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    winrate::lookback(opt_period)
+}
+
+/// Calculates the rolling Kelly fraction from a series of trade returns.
+///
+/// # Description
+/// The Kelly criterion gives the fraction of capital to risk per trade that maximises long-run
+/// growth, given the win rate and payoff ratio of the trades. Because the full Kelly fraction is
+/// very sensitive to estimation error, it is usually scaled down; `opt_fraction` is that scale,
+/// with 1.0 giving full Kelly and 0.5 half Kelly. A negative result means the trades have no edge.
+///
+/// # Mathematical Formula
+/// ```text
+/// Kelly = Fraction * (WinRate - (1 - WinRate) / PayoffRatio)
+/// ```
+/// Where `WinRate` and `PayoffRatio` come from [`winrate::TradeStats`] over the last
+/// `opt_period` trades. A window without losing trades has an unbounded payoff ratio, so the
+/// second term drops out and the result is `Fraction * WinRate`. A window with losing but no
+/// winning trades has no payoff ratio and produces NaN.
+///
+/// # Arguments
+/// * `input_trades` - Array of per-trade returns
+/// * `opt_period` - The number of trades in the rolling window (must be >= 1)
+/// * `opt_fraction` - Scale applied to the full Kelly fraction (must be > 0, 1.0 for full Kelly)
+/// * `output_kelly` - Array to store the Kelly fraction
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output array doesn't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 1 or fraction is not positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::kelly;
+/// let input_trades = vec![0.04, -0.02, 0.02, -0.01, 0.03];
+/// let mut output_kelly = vec![0.0; 5];
+///
+/// kelly::kelly(&input_trades, 4, 1.0, &mut output_kelly).unwrap();
+/// // Win rate 0.5 and payoff ratio 2 give a full Kelly fraction of 0.25
+/// assert!((output_kelly[3] - 0.25).abs() < 1e-12);
+/// ```
+pub fn kelly(
+    input_trades: &[TAFloat],
+    opt_period: usize,
+    opt_fraction: TAFloat,
+    output_kelly: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_trades.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_kelly.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_fraction <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_trades {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut stats = TradeStats::new();
+    for value in input_trades.iter().take(opt_period) {
+        stats.push(*value);
+    }
+    output_kelly[lookback] = fraction(&stats, opt_fraction);
+
+    for i in opt_period..len {
+        output_kelly[i] = kelly_inc(
+            input_trades[i],
+            input_trades[i - opt_period],
+            opt_fraction,
+            &mut stats,
+        )?;
+    }
+
+    // Fill initial values with NAN
+    for value in output_kelly.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Kelly fraction incrementally.
+///
+/// # Description
+/// Slides the [`TradeStats`] forward by one trade, as [`winrate::winrate_inc`] does, and returns
+/// the Kelly fraction of the updated window.
+///
+/// # Arguments
+/// * `input_trade` - The newest trade return
+/// * `input_old_trade` - The trade return leaving the window
+/// * `opt_fraction` - Scale applied to the full Kelly fraction (must be > 0)
+/// * `stats` - Rolling trade statistics, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The new Kelly fraction on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If fraction is not positive
+/// * `KandError::NaNDetected` - If either input is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::stats::{kelly, winrate::TradeStats};
+///
+/// let mut stats = TradeStats::new();
+/// for trade in [0.04, -0.02, 0.02, -0.01] {
+///     stats.push(trade);
+/// }
+/// let half_kelly = kelly::kelly_inc(0.03, 0.04, 0.5, &mut stats).unwrap();
+/// ```
+pub fn kelly_inc(
+    input_trade: TAFloat,
+    input_old_trade: TAFloat,
+    opt_fraction: TAFloat,
+    stats: &mut TradeStats,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_fraction <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    winrate::winrate_inc(input_trade, input_old_trade, stats)?;
+    Ok(fraction(stats, opt_fraction))
+}
+
+/// Calculates the Kelly fraction of an entire list of trade returns.
+///
+/// # Arguments
+/// * `input_trades` - Array of per-trade returns
+/// * `opt_fraction` - Scale applied to the full Kelly fraction (must be > 0, 1.0 for full Kelly)
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The Kelly fraction, or NaN when there are losing but no winning
+///   trades
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::InvalidParameter` if fraction is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::kelly;
+/// let full = kelly::kelly_criterion(&[0.04, -0.02, 0.02, -0.01], 1.0).unwrap();
+/// assert!((full - 0.25).abs() < 1e-12);
+/// ```
+pub fn kelly_criterion(
+    input_trades: &[TAFloat],
+    opt_fraction: TAFloat,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_fraction <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    let stats = winrate::trade_stats(input_trades)?;
+    Ok(fraction(&stats, opt_fraction))
+}
+
+/// Scaled Kelly fraction of the given trade statistics.
+fn fraction(stats: &TradeStats, opt_fraction: TAFloat) -> TAFloat {
+    let win_rate = stats.win_rate();
+    // Without losers the payoff ratio is unbounded and the loss term vanishes
+    if stats.losses() == 0 {
+        return opt_fraction * win_rate;
+    }
+    opt_fraction * (win_rate - (1.0 - win_rate) / stats.payoff_ratio())
+}
+
+/// Streaming rolling Kelly fraction that keeps the last `opt_period` trades.
+///
+/// Reads [`Bar::close`] as the trade return. Folding [`Indicator::update`] over a trade list
+/// reproduces [`kelly`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Kelly {
+    opt_period: usize,
+    opt_fraction: TAFloat,
+    window: VecDeque<TAFloat>,
+    stats: TradeStats,
+}
+
+impl Kelly {
+    /// Creates a streaming rolling Kelly fraction.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of trades in the rolling window (must be >= 1)
+    /// * `opt_fraction` - Scale applied to the full Kelly fraction (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 1 or fraction is not positive
+    pub fn new(opt_period: usize, opt_fraction: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        if opt_fraction <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            opt_fraction,
+            window: VecDeque::with_capacity(opt_period + 1),
+            stats: TradeStats::new(),
+        })
+    }
+
+    /// Feeds a raw trade return instead of a [`Bar`] and returns the rolling Kelly fraction.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_trade` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_trade: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_trade.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_trade);
+        if self.window.len() > self.opt_period {
+            let old = self.window.pop_front().unwrap_or(0.0);
+            return kelly_inc(input_trade, old, self.opt_fraction, &mut self.stats);
+        }
+
+        self.stats.push(input_trade);
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(fraction(&self.stats, self.opt_fraction))
+    }
+}
+
+impl Indicator for Kelly {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.stats = TradeStats::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_TRADES: [TAFloat; 14] = [
+        0.032, -0.015, 0.021, -0.008, 0.0, 0.045, -0.027, -0.012, 0.018, 0.009, -0.021, 0.037,
+        -0.005, 0.014,
+    ];
+
+    #[test]
+    fn test_kelly_calculation() {
+        let opt_period = 5;
+        let len = INPUT_TRADES.len();
+        let mut output_kelly = vec![0.0; len];
+
+        kelly(&INPUT_TRADES, opt_period, 0.5, &mut output_kelly).unwrap();
+
+        for value in &output_kelly[..4] {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            0.069_811_320_754_717_02,
+            0.095_454_545_454_545_49,
+            0.040_909_090_909_090_92,
+            -0.039_259_259_259_259_27,
+            0.014_285_714_285_714_318,
+            0.137_499_999_999_999_96,
+            -0.244_444_444_444_444_46,
+            0.145_312_499_999_999_96,
+            0.178_124_999_999_999_98,
+            0.169_999_999_999_999_96,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_kelly[i + 4], expected, epsilon = 0.000_000_1);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut stats = TradeStats::new();
+        for &trade in &INPUT_TRADES[..opt_period] {
+            stats.push(trade);
+        }
+        for i in opt_period..len {
+            let value = kelly_inc(
+                INPUT_TRADES[i],
+                INPUT_TRADES[i - opt_period],
+                0.5,
+                &mut stats,
+            )
+            .unwrap();
+            assert_relative_eq!(value, output_kelly[i], epsilon = 0.000_000_1);
+        }
+
+        // Full-series criterion, full and half Kelly
+        let full = kelly_criterion(&INPUT_TRADES, 1.0).unwrap();
+        assert_relative_eq!(full, 0.208_333_333_333_333_31, epsilon = 0.000_000_1);
+        let half = kelly_criterion(&INPUT_TRADES, 0.5).unwrap();
+        assert_relative_eq!(half, full / 2.0, epsilon = 0.000_000_1);
+
+        // Without losing trades Kelly reduces to the scaled win rate
+        assert_relative_eq!(kelly_criterion(&[0.01, 0.02], 1.0).unwrap(), 1.0);
+        assert_relative_eq!(kelly_criterion(&[0.01, 0.0, 0.02, 0.0], 0.5).unwrap(), 0.25);
+        // Without winning trades there is no payoff ratio
+        assert!(kelly_criterion(&[-0.01, -0.02], 1.0).unwrap().is_nan());
+
+        // A rolling window that runs out of losers stays finite
+        let input_trades = [0.02, -0.01, 0.03, 0.01, 0.02];
+        let mut output_kelly = vec![0.0; input_trades.len()];
+        kelly(&input_trades, 3, 1.0, &mut output_kelly).unwrap();
+        assert_relative_eq!(output_kelly[2], 2.0 / 3.0 - 1.0 / 3.0 / 2.5);
+        assert_relative_eq!(output_kelly[3], 2.0 / 3.0 - 1.0 / 3.0 / 2.0);
+        assert_relative_eq!(output_kelly[4], 1.0);
+    }
+
+    #[test]
+    fn test_kelly_indicator() {
+        let opt_period = 5;
+        let len = INPUT_TRADES.len();
+        let mut output_kelly = vec![0.0; len];
+        kelly(&INPUT_TRADES, opt_period, 0.5, &mut output_kelly).unwrap();
+
+        let mut indicator = Kelly::new(opt_period, 0.5).unwrap();
+        assert_eq!(indicator.lookback(), lookback(opt_period).unwrap());
+        for (i, &trade) in INPUT_TRADES.iter().enumerate() {
+            let value = indicator.update(&Bar::from(trade)).unwrap();
+            if i < indicator.lookback() {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_kelly[i], epsilon = 1e-9);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert!(Kelly::new(opt_period, 0.0).is_err());
+    }
+}
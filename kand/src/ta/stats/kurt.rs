@@ -0,0 +1,474 @@
+use std::collections::VecDeque;
+
+use super::moments::{self, RollingHigherMoments};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Kurtosis calculation.
+///
+/// # Description
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For Kurtosis calculation, this equals the specified period minus one.
+///
+/// # Arguments
+/// * `opt_period` - The time period used for Kurtosis calculation (must be >= 4)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `opt_period` is less than 4
+///
+/// # Example
+/// ```
+/// use kand::stats::kurt;
+/// let period = 20;
+/// let lookback = kurt::lookback(period).unwrap();
+/// assert_eq!(lookback, 19); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 4 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates rolling excess Kurtosis for an entire price series.
+///
+/// # Description
+/// Excess kurtosis measures how heavy the tails of the values in a period are compared with a
+/// normal distribution, which has an excess kurtosis of zero. Positive values mean more extreme
+/// moves than a normal distribution with the same variance would produce.
+///
+/// # Mathematical Formula
+/// ```text
+/// KURT = n * M4 / M2^2 - 3
+/// ```
+/// Where:
+/// - M2 = sum((x - mean)^2) and M4 = sum((x - mean)^4) over the period
+/// - n is the time period
+///
+/// This is the population (biased) excess kurtosis, consistent with the population variance of
+/// [`super::var`]. The sample-adjusted estimate reported by pandas is
+/// `((n + 1) * KURT + 6) * (n - 1) / ((n - 2) * (n - 3))`.
+///
+/// # Calculation Steps
+/// 1. Accumulate the mean, M2, M3 and M4 of the first period
+/// 2. Slide the window in O(1) per value with [`RollingHigherMoments`]
+/// 3. Apply the kurtosis formula, giving NaN for a constant window
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `opt_period` - The time period for Kurtosis calculation (must be >= 4)
+/// * `output_kurt` - Array to store calculated excess Kurtosis values
+/// * `output_mean` - Array to store the rolling mean values
+/// * `output_m2` - Array to store the rolling sum of squared deviations (M2)
+/// * `output_m3` - Array to store the rolling sum of cubed deviations (M3)
+/// * `output_m4` - Array to store the rolling sum of fourth-power deviations (M4)
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 4
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::kurt;
+/// let input_prices = vec![1.0, 2.0, 3.0, 4.0, 20.0];
+/// let period = 4;
+/// let mut output_kurt = vec![0.0; 5];
+/// let mut output_mean = vec![0.0; 5];
+/// let mut output_m2 = vec![0.0; 5];
+/// let mut output_m3 = vec![0.0; 5];
+/// let mut output_m4 = vec![0.0; 5];
+///
+/// kurt::kurt(
+///     &input_prices,
+///     period,
+///     &mut output_kurt,
+///     &mut output_mean,
+///     &mut output_m2,
+///     &mut output_m3,
+///     &mut output_m4,
+/// )
+/// .unwrap();
+/// assert!(output_kurt[2].is_nan());
+/// assert!((output_kurt[3] + 1.36).abs() < 1e-12); // [1, 2, 3, 4] is flatter than normal
+/// assert!(output_kurt[4] > output_kurt[3]); // 20 is an outlier
+/// ```
+pub fn kurt(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    output_kurt: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+    output_m3: &mut [TAFloat],
+    output_m4: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_kurt.len() != len
+            || output_mean.len() != len
+            || output_m2.len() != len
+            || output_m3.len() != len
+            || output_m4.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial values
+    let mut moments = RollingHigherMoments::new();
+    for val in input_prices.iter().take(opt_period) {
+        moments.push(*val);
+    }
+    output_kurt[lookback] = moments.excess_kurtosis();
+    output_mean[lookback] = moments.mean();
+    output_m2[lookback] = moments.m2();
+    output_m3[lookback] = moments.m3();
+    output_m4[lookback] = moments.m4();
+
+    // Calculate remaining values incrementally
+    for i in opt_period..len {
+        moments.replace(input_prices[i], input_prices[i - opt_period]);
+        output_kurt[i] = moments.excess_kurtosis();
+        output_mean[i] = moments.mean();
+        output_m2[i] = moments.m2();
+        output_m3[i] = moments.m3();
+        output_m4[i] = moments.m4();
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_kurt[i] = TAFloat::NAN;
+        output_mean[i] = TAFloat::NAN;
+        output_m2[i] = TAFloat::NAN;
+        output_m3[i] = TAFloat::NAN;
+        output_m4[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling excess Kurtosis value using incremental computation.
+///
+/// # Description
+/// This function updates the mean and M2 to M4 of the window in O(1) by removing the oldest value
+/// and adding the newest one (see [`moments::slide_higher`]), then applies the kurtosis formula.
+/// M3 is carried along because the M4 update depends on it.
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `prev_mean` - Previous mean of values in the period
+/// * `prev_m2` - Previous sum of squared deviations from the mean in the period
+/// * `prev_m3` - Previous sum of cubed deviations from the mean in the period
+/// * `prev_m4` - Previous sum of fourth-power deviations from the mean in the period
+/// * `input_old_price` - Oldest price value to remove from calculation
+/// * `opt_period` - The time period for Kurtosis calculation (must be >= 4)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing
+///   (excess kurtosis, `new_mean`, `new_m2`, `new_m3`, `new_m4`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 4
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::kurt;
+/// // Window [1, 2, 3, 4] slides to [2, 3, 4, 5]
+/// let (kurt_value, new_mean, new_m2, new_m3, new_m4) = kurt::kurt_inc(
+///     5.0,    // new price
+///     2.5,    // previous mean
+///     5.0,    // previous M2
+///     0.0,    // previous M3
+///     10.25,  // previous M4
+///     1.0,    // price to remove
+///     4,      // period
+/// )
+/// .unwrap();
+/// assert!((new_mean - 3.5).abs() < 1e-12);
+/// assert!((new_m4 - 10.25).abs() < 1e-12);
+/// assert!((kurt_value + 1.36).abs() < 1e-12);
+/// ```
+pub fn kurt_inc(
+    input_price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    prev_m3: TAFloat,
+    prev_m4: TAFloat,
+    input_old_price: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 4 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan()
+            || prev_mean.is_nan()
+            || prev_m2.is_nan()
+            || prev_m3.is_nan()
+            || prev_m4.is_nan()
+            || input_old_price.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (new_mean, new_m2, new_m3, new_m4) = moments::slide_higher(
+        prev_mean,
+        prev_m2,
+        prev_m3,
+        prev_m4,
+        input_price,
+        input_old_price,
+        opt_period,
+    );
+
+    Ok((
+        moments::excess_kurtosis(new_m2, new_m4, opt_period),
+        new_mean,
+        new_m2,
+        new_m3,
+        new_m4,
+    ))
+}
+
+/// Streaming rolling excess kurtosis that keeps the last `opt_period` values and their
+/// [`RollingHigherMoments`].
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`kurt`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Kurt {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+    moments: RollingHigherMoments,
+}
+
+impl Kurt {
+    /// Creates a streaming rolling excess kurtosis.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 4)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 4
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+            moments: RollingHigherMoments::new(),
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the rolling excess kurtosis for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() > self.opt_period {
+            let old_price = self.window.pop_front().unwrap_or(0.0);
+            self.moments.replace(input_price, old_price);
+        } else {
+            self.moments.push(input_price);
+        }
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(self.moments.excess_kurtosis())
+    }
+}
+
+impl Indicator for Kurt {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.moments = RollingHigherMoments::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_kurt_calculation() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let len = input_close.len();
+        let mut output_kurt = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        let mut output_m3 = vec![0.0; len];
+        let mut output_m4 = vec![0.0; len];
+
+        kurt(
+            &input_close,
+            opt_period,
+            &mut output_kurt,
+            &mut output_mean,
+            &mut output_m2,
+            &mut output_m3,
+            &mut output_m4,
+        )
+        .unwrap();
+
+        for value in &output_kurt[..13] {
+            assert!(value.is_nan());
+        }
+
+        // Two-pass population excess kurtosis reference values
+        let expected_values = [
+            -0.855_417_325_899_084_7,
+            1.021_980_624_480_901_8,
+            0.922_976_793_029_913_1,
+            0.185_180_461_434_186_32,
+            -0.774_658_236_626_502_5,
+            -1.199_673_625_325_345_7,
+            -1.494_539_203_245_057_7,
+            -1.315_043_402_364_812_8,
+            -1.427_204_927_987_597,
+            -1.338_955_462_183_354_4,
+            -0.987_547_286_698_470_5,
+            -0.492_493_676_540_672,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_kurt[i + 13], expected, epsilon = 1e-9);
+        }
+
+        // Incremental calculation matches the batch outputs
+        let mut prev_mean = output_mean[13];
+        let mut prev_m2 = output_m2[13];
+        let mut prev_m3 = output_m3[13];
+        let mut prev_m4 = output_m4[13];
+        for i in 14..len {
+            let (kurt_value, new_mean, new_m2, new_m3, new_m4) = kurt_inc(
+                input_close[i],
+                prev_mean,
+                prev_m2,
+                prev_m3,
+                prev_m4,
+                input_close[i - opt_period],
+                opt_period,
+            )
+            .unwrap();
+            assert_relative_eq!(kurt_value, output_kurt[i], epsilon = 1e-9);
+            prev_mean = new_mean;
+            prev_m2 = new_m2;
+            prev_m3 = new_m3;
+            prev_m4 = new_m4;
+        }
+
+        #[cfg(feature = "check")]
+        assert!(lookback(3).is_err());
+    }
+
+    #[test]
+    fn test_kurt_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+        ];
+        let opt_period = 6;
+        let len = input_price.len();
+        let mut outputs = vec![vec![0.0; len]; 5];
+        let [output_kurt, output_mean, output_m2, output_m3, output_m4] = &mut outputs[..] else {
+            unreachable!()
+        };
+        kurt(
+            &input_price,
+            opt_period,
+            output_kurt,
+            output_mean,
+            output_m2,
+            output_m3,
+            output_m4,
+        )
+        .unwrap();
+
+        let mut indicator = Kurt::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_kurt[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
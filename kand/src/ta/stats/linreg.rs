@@ -0,0 +1,517 @@
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling linear regression.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For a rolling least-squares fit, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The time period for the regression window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::linreg;
+/// let period = 14;
+/// let lookback = linreg::lookback(period).unwrap();
+/// assert_eq!(lookback, 13); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Solves the least-squares line for a window from its running sums.
+///
+/// # Description
+/// The bars of the window are indexed `x = 0` (oldest) to `x = n - 1` (newest), so the sums of
+/// `x` and `x^2` are fixed by the period and only `sum(y)` and `sum(x * y)` need to be tracked.
+///
+/// # Arguments
+/// * `sum_y` - Sum of the prices in the window
+/// * `sum_xy` - Sum of each price multiplied by its index in the window
+/// * `opt_period` - The time period for the regression window
+///
+/// # Returns
+/// * `(TAFloat, TAFloat)` - Tuple of (slope, intercept), where the intercept is the fitted value at
+///   the oldest bar of the window
+///
+/// # Example
+/// ```
+/// use kand::stats::linreg;
+/// // Prices 1, 3, 5 lie on the line y = 1 + 2x
+/// let (slope, intercept) = linreg::fit(9.0, 13.0, 3);
+/// assert_eq!(slope, 2.0);
+/// assert_eq!(intercept, 1.0);
+/// ```
+#[must_use]
+pub fn fit(sum_y: TAFloat, sum_xy: TAFloat, opt_period: usize) -> (TAFloat, TAFloat) {
+    let n = opt_period as TAFloat;
+    let sum_x = n * (n - 1.0) / 2.0;
+    let sum_xx = n * (n - 1.0) * n.mul_add(2.0, -1.0) / 6.0;
+    let divisor = n.mul_add(sum_xx, -(sum_x * sum_x));
+    let slope = n.mul_add(sum_xy, -(sum_x * sum_y)) / divisor;
+    let intercept = slope.mul_add(-sum_x, sum_y) / n;
+    (slope, intercept)
+}
+
+/// Calculates a rolling least-squares line for an entire price series.
+///
+/// # Description
+/// Fits a straight line to the last `opt_period` prices at every bar. The running sums are updated
+/// in O(1) per bar: dropping the oldest price shifts every remaining index down by one, which
+/// removes exactly `sum(y)` of the remaining prices from `sum(x * y)`.
+///
+/// # Mathematical Formula
+/// ```text
+/// x = 0 (oldest) .. n-1 (newest)
+/// Slope = (n * Sum(x*y) - Sum(x) * Sum(y)) / (n * Sum(x^2) - Sum(x)^2)
+/// Intercept = (Sum(y) - Slope * Sum(x)) / n
+///
+/// Sum(y)'   = Sum(y) - Old + New
+/// Sum(x*y)' = Sum(x*y) - (Sum(y) - Old) + (n-1) * New
+/// ```
+///
+/// # Calculation Steps
+/// 1. Accumulate `sum(y)` and `sum(x * y)` over the first window
+/// 2. Solve for slope and intercept
+/// 3. Slide the window, updating both sums incrementally
+/// 4. Fill the first (period-1) values with NaN
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `opt_period` - The time period for the regression window (must be >= 2)
+/// * `output_slope` - Array to store the slope of the fitted line
+/// * `output_intercept` - Array to store the fitted value at the oldest bar of each window
+/// * `output_sum_y` - Array to store running sum of prices
+/// * `output_sum_xy` - Array to store running sum of index-weighted prices
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::linreg;
+/// let input_prices = vec![1.0, 3.0, 5.0, 4.0, 6.0];
+/// let period = 3;
+/// let mut output_slope = vec![0.0; 5];
+/// let mut output_intercept = vec![0.0; 5];
+/// let mut output_sum_y = vec![0.0; 5];
+/// let mut output_sum_xy = vec![0.0; 5];
+///
+/// linreg::linreg(
+///     &input_prices,
+///     period,
+///     &mut output_slope,
+///     &mut output_intercept,
+///     &mut output_sum_y,
+///     &mut output_sum_xy,
+/// )
+/// .unwrap();
+/// assert_eq!(output_slope[2], 2.0);
+/// assert_eq!(output_intercept[2], 1.0);
+/// ```
+pub fn linreg(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    output_slope: &mut [TAFloat],
+    output_intercept: &mut [TAFloat],
+    output_sum_y: &mut [TAFloat],
+    output_sum_xy: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_slope.len() != len
+            || output_intercept.len() != len
+            || output_sum_y.len() != len
+            || output_sum_xy.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial sums
+    let mut sum_y = 0.0;
+    let mut sum_xy = 0.0;
+    for (x, val) in input_prices.iter().take(opt_period).enumerate() {
+        sum_y += *val;
+        sum_xy = (x as TAFloat).mul_add(*val, sum_xy);
+    }
+
+    let (slope, intercept) = fit(sum_y, sum_xy, opt_period);
+    output_slope[lookback] = slope;
+    output_intercept[lookback] = intercept;
+    output_sum_y[lookback] = sum_y;
+    output_sum_xy[lookback] = sum_xy;
+
+    // Slide the window
+    for i in opt_period..len {
+        let (slope, intercept, new_sum_y, new_sum_xy) = linreg_inc(
+            input_prices[i],
+            sum_y,
+            sum_xy,
+            input_prices[i - opt_period],
+            opt_period,
+        )?;
+        sum_y = new_sum_y;
+        sum_xy = new_sum_xy;
+        output_slope[i] = slope;
+        output_intercept[i] = intercept;
+        output_sum_y[i] = sum_y;
+        output_sum_xy[i] = sum_xy;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_slope[i] = TAFloat::NAN;
+        output_intercept[i] = TAFloat::NAN;
+        output_sum_y[i] = TAFloat::NAN;
+        output_sum_xy[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest least-squares line using incremental computation.
+///
+/// # Description
+/// Slides the regression window by one bar in O(1): the oldest price leaves the window, every
+/// remaining price moves one index closer to the start, and the newest price enters at index
+/// `n - 1`.
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `prev_sum_y` - Previous sum of prices in the window
+/// * `prev_sum_xy` - Previous sum of index-weighted prices in the window
+/// * `input_old_price` - Oldest price value to remove from calculation
+/// * `opt_period` - The time period for the regression window (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing
+///   (slope, intercept, `new_sum_y`, `new_sum_xy`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::linreg;
+/// // Window [1, 3, 5] slides to [3, 5, 7]
+/// let (slope, intercept, new_sum_y, new_sum_xy) = linreg::linreg_inc(
+///     7.0,  // new price
+///     9.0,  // previous sum of prices
+///     13.0, // previous sum of index-weighted prices
+///     1.0,  // price to remove
+///     3,    // period
+/// )
+/// .unwrap();
+/// assert_eq!(slope, 2.0);
+/// assert_eq!(intercept, 3.0);
+/// ```
+pub fn linreg_inc(
+    input_price: TAFloat,
+    prev_sum_y: TAFloat,
+    prev_sum_xy: TAFloat,
+    input_old_price: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan()
+            || prev_sum_y.is_nan()
+            || prev_sum_xy.is_nan()
+            || input_old_price.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let last_x = (opt_period - 1) as TAFloat;
+    let new_sum_xy = last_x.mul_add(input_price, prev_sum_xy - (prev_sum_y - input_old_price));
+    let new_sum_y = prev_sum_y - input_old_price + input_price;
+    let (slope, intercept) = fit(new_sum_y, new_sum_xy, opt_period);
+
+    Ok((slope, intercept, new_sum_y, new_sum_xy))
+}
+
+/// Streaming rolling least-squares line that keeps the last `opt_period` values and their running
+/// sums.
+///
+/// Reads [`Bar::close`] and outputs `(slope, intercept)`. Folding [`Indicator::update`] over a
+/// series reproduces [`linreg`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Linreg {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+    sum_y: TAFloat,
+    sum_xy: TAFloat,
+}
+
+impl Linreg {
+    /// Creates a streaming rolling least-squares line.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+            sum_y: 0.0,
+            sum_xy: 0.0,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns `(slope, intercept)` for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<(TAFloat, TAFloat), KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() > self.opt_period {
+            let old_price = self.window.pop_front().unwrap_or(0.0);
+            let (slope, intercept, sum_y, sum_xy) = linreg_inc(
+                input_price,
+                self.sum_y,
+                self.sum_xy,
+                old_price,
+                self.opt_period,
+            )?;
+            self.sum_y = sum_y;
+            self.sum_xy = sum_xy;
+            return Ok((slope, intercept));
+        }
+        let x = (self.window.len() - 1) as TAFloat;
+        self.sum_y += input_price;
+        self.sum_xy = x.mul_add(input_price, self.sum_xy);
+        if self.window.len() < self.opt_period {
+            return Ok((TAFloat::NAN, TAFloat::NAN));
+        }
+        Ok(fit(self.sum_y, self.sum_xy, self.opt_period))
+    }
+}
+
+impl Indicator for Linreg {
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<(TAFloat, TAFloat), KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum_y = 0.0;
+        self.sum_xy = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 35] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0, 35073.2, 35139.3,
+        35092.0, 35126.7, 35106.3, 35124.8, 35170.1,
+    ];
+
+    #[test]
+    fn test_linreg_calculation() {
+        let opt_period = 14;
+        let len = INPUT_PRICES.len();
+        let mut output_slope = vec![0.0; len];
+        let mut output_intercept = vec![0.0; len];
+        let mut output_sum_y = vec![0.0; len];
+        let mut output_sum_xy = vec![0.0; len];
+
+        linreg(
+            &INPUT_PRICES,
+            opt_period,
+            &mut output_slope,
+            &mut output_intercept,
+            &mut output_sum_y,
+            &mut output_sum_xy,
+        )
+        .unwrap();
+
+        // First 13 values should be NaN
+        for i in 0..13 {
+            assert!(output_slope[i].is_nan());
+            assert!(output_intercept[i].is_nan());
+            assert!(output_sum_y[i].is_nan());
+            assert!(output_sum_xy[i].is_nan());
+        }
+
+        // TA-Lib LINEARREG_SLOPE and LINEARREG_INTERCEPT
+        let expected_slope = [
+            -1.176_923_076_923_077,
+            -3.998_021_978_020_574_4,
+            -7.494_285_714_284_778_5,
+            -12.407_692_307_692_308,
+            -16.759_340_659_340_66,
+            -18.454_945_054_945_053,
+            -18.514_725_274_724_807,
+            -21.702_637_362_639_233,
+            -21.740_219_780_220_247,
+            -20.898_021_978_022_914,
+        ];
+        let expected_intercept = [
+            35_211.185_714_285_71,
+            35_220.537_142_857_14,
+            35_230.391_428_571_42,
+            35_248.657_142_857_14,
+            35_265.757_142_857_14,
+            35_268.742_857_142_86,
+            35_252.702_857_142_85,
+            35_254.617_142_857_154,
+            35_233.482_857_142_86,
+            35_213.894_285_714_3,
+        ];
+        for i in 0..expected_slope.len() {
+            assert_relative_eq!(output_slope[i + 13], expected_slope[i], epsilon = 0.00001);
+            assert_relative_eq!(
+                output_intercept[i + 13],
+                expected_intercept[i],
+                epsilon = 0.00001
+            );
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut prev_sum_y = output_sum_y[13];
+        let mut prev_sum_xy = output_sum_xy[13];
+        for i in 14..len {
+            let (slope, intercept, new_sum_y, new_sum_xy) = linreg_inc(
+                INPUT_PRICES[i],
+                prev_sum_y,
+                prev_sum_xy,
+                INPUT_PRICES[i - opt_period],
+                opt_period,
+            )
+            .unwrap();
+            assert_relative_eq!(slope, output_slope[i], epsilon = 0.00001);
+            assert_relative_eq!(intercept, output_intercept[i], epsilon = 0.00001);
+            assert_relative_eq!(new_sum_y, output_sum_y[i], epsilon = 0.00001);
+            assert_relative_eq!(new_sum_xy, output_sum_xy[i], epsilon = 0.00001);
+            prev_sum_y = new_sum_y;
+            prev_sum_xy = new_sum_xy;
+        }
+    }
+
+    #[test]
+    fn test_linreg_indicator() {
+        let opt_period = 14;
+        let len = INPUT_PRICES.len();
+        let mut output_slope = vec![0.0; len];
+        let mut output_intercept = vec![0.0; len];
+        let mut output_sum_y = vec![0.0; len];
+        let mut output_sum_xy = vec![0.0; len];
+        linreg(
+            &INPUT_PRICES,
+            opt_period,
+            &mut output_slope,
+            &mut output_intercept,
+            &mut output_sum_y,
+            &mut output_sum_xy,
+        )
+        .unwrap();
+
+        let mut indicator = Linreg::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let (slope, intercept) = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(slope.is_nan());
+                assert!(intercept.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(slope, output_slope[i], epsilon = 0.00001);
+                assert_relative_eq!(intercept, output_intercept[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{EPSILON, KandError, TAFloat};
+use crate::{
+    EPSILON, KandError, TAFloat, indicator::Indicator, types::Bar, window::RollingExtremum,
+};
 
 /// Calculates the lookback period required for Maximum Value calculation.
 ///
@@ -34,8 +36,8 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
 /// Calculates Maximum Value for a series of prices over a specified period.
 ///
 /// # Calculation Principle
-/// For each period, finds the highest price value within that period by comparing
-/// all values in the window.
+/// For each period, finds the highest price value within that period. The window is tracked
+/// with a [`RollingExtremum`], so each step costs O(1) amortized regardless of the period.
 ///
 /// # Mathematical Formula
 /// ```text
@@ -112,14 +114,12 @@ pub fn max(
     }
 
     // Calculate MAX values
-    for i in lookback..len {
-        let mut max_val = input_prices[i - lookback];
-        for price in input_prices.iter().take(i + 1).skip(i - lookback + 1) {
-            if *price > max_val {
-                max_val = *price;
-            }
+    let mut window = RollingExtremum::highest(opt_period)?;
+    for (i, &price) in input_prices.iter().enumerate() {
+        let (max_val, _) = window.push(price);
+        if i >= lookback {
+            output_max[i] = max_val;
         }
-        output_max[i] = max_val;
     }
 
     // Fill initial values with NAN
@@ -134,6 +134,8 @@ pub fn max(
 ///
 /// This function provides an optimized way to calculate the latest MAX value
 /// by using the previous MAX value and only considering the new and removed prices.
+/// When the removed price was the previous MAX, the next highest value in the window is
+/// unknown and the new price is returned in its place, which can understate the MAX.
 ///
 /// # Arguments
 /// * `input_price` - The newest price value to include in calculation
@@ -148,8 +150,14 @@ pub fn max(
 /// * Returns `KandError::InvalidParameter` if period is less than 2
 /// * Returns `KandError::NaNDetected` if any input value is NaN (with "`check-nan`" feature)
 ///
+/// # Migration
+/// Keep a [`Max`] per series and feed it every value with [`Max::update_value`], or push the
+/// values into a [`RollingExtremum::highest`] window directly. Both hold the whole window, so the
+/// result stays exact when the previous MAX leaves it.
+///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::stats::max;
 /// let new_price = 10.5;
 /// let prev_max = 11.0;
@@ -159,6 +167,7 @@ pub fn max(
 /// let new_max = max::max_inc(new_price, prev_max, old_price, period).unwrap();
 /// assert_eq!(new_max, 11.0);
 /// ```
+#[deprecated(note = "returns the new price when the previous max leaves the window; use `Max`")]
 pub fn max_inc(
     input_price: TAFloat,
     prev_max: TAFloat,
@@ -195,6 +204,73 @@ pub fn max_inc(
     Ok(prev_max)
 }
 
+/// Streaming rolling maximum that keeps the last `opt_period` values.
+///
+/// Reads [`Bar::close`]. Unlike [`max_inc`], the struct tracks the window with a
+/// [`RollingExtremum`], so it never needs a full recalculation and reproduces [`max`] exactly.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Max {
+    opt_period: usize,
+    window: RollingExtremum,
+}
+
+impl Max {
+    /// Creates a streaming rolling maximum.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: RollingExtremum::highest(opt_period)?,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the rolling maximum for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let (max_val, _) = self.window.push(input_price);
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(max_val)
+    }
+}
+
+impl Indicator for Max {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.is_full()
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -202,6 +278,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_max_calculation() {
         let input_close = vec![
             35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
@@ -243,5 +320,46 @@ fn test_max_calculation() {
             assert_relative_eq!(result, output_max[i], epsilon = 0.0001);
             prev_max = result;
         }
+
+        // The previous max (index 5) leaves the window at index 19. The deprecated approximation
+        // then falls back to the new price instead of the true max of the window.
+        let result = max_inc(
+            input_close[19],
+            prev_max,
+            input_close[19 - opt_period],
+            opt_period,
+        )
+        .unwrap();
+        assert_relative_eq!(result, input_close[19], epsilon = 0.0001);
+        assert!(result < output_max[19]);
+    }
+
+    #[test]
+    fn test_max_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let mut output_max = vec![0.0; input_price.len()];
+        max(&input_price, opt_period, &mut output_max).unwrap();
+
+        let mut indicator = Max::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_max[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
     }
 }
@@ -1,4 +1,6 @@
-use crate::{EPSILON, KandError, TAFloat};
+use crate::{
+    EPSILON, KandError, TAFloat, indicator::Indicator, types::Bar, window::RollingExtremum,
+};
 
 /// Calculates the lookback period required for Minimum Value calculation.
 ///
@@ -35,7 +37,8 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
 ///
 /// The MIN indicator finds the lowest price value within a given time period. For each
 /// calculation point, it looks back over the specified number of periods and returns
-/// the minimum value found.
+/// the minimum value found. The window is tracked with a [`RollingExtremum`], so each step
+/// costs O(1) amortized regardless of the period.
 ///
 /// # Mathematical Formula
 /// ```text
@@ -118,14 +121,12 @@ pub fn min(
     }
 
     // Calculate MIN values
-    for i in lookback..len {
-        let mut min_val = input_prices[i - lookback];
-        for price in input_prices.iter().take(i + 1).skip(i - lookback + 1) {
-            if *price < min_val {
-                min_val = *price;
-            }
+    let mut window = RollingExtremum::lowest(opt_period)?;
+    for (i, &price) in input_prices.iter().enumerate() {
+        let (min_val, _) = window.push(price);
+        if i >= lookback {
+            output_min[i] = min_val;
         }
-        output_min[i] = min_val;
     }
 
     // Fill initial values with NAN
@@ -140,6 +141,8 @@ pub fn min(
 ///
 /// This function provides an optimized way to calculate the current MIN value
 /// when you already have the previous MIN value and are adding a new price point.
+/// When the dropped price was the previous MIN, the next lowest value in the window is
+/// unknown and `KandError::InsufficientData` is returned.
 ///
 /// # Arguments
 /// * `input_price` - The new price value to include in calculation
@@ -155,8 +158,14 @@ pub fn min(
 /// * Returns `KandError::NaNDetected` if any input value is NaN (with "`check-nan`" feature)
 /// * Returns `KandError::InsufficientData` if full recalculation is needed
 ///
+/// # Migration
+/// Replace the `prev_min` state with a [`Min`] built from the same period, or a
+/// [`RollingExtremum::lowest`] window, and feed it every value. Neither needs the full period
+/// again when the previous MIN drops out.
+///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::stats::min;
 /// let new_price = 15.0;
 /// let prev_min = 12.0;
@@ -166,6 +175,7 @@ pub fn min(
 /// let new_min = min::min_inc(new_price, prev_min, dropping_price, period).unwrap();
 /// assert_eq!(new_min, 12.0);
 /// ```
+#[deprecated(note = "cannot recover when the previous min leaves the window; use `Min`")]
 pub fn min_inc(
     input_price: TAFloat,
     prev_min: TAFloat,
@@ -204,6 +214,73 @@ pub fn min_inc(
     Ok(prev_min)
 }
 
+/// Streaming rolling minimum that keeps the last `opt_period` values.
+///
+/// Reads [`Bar::close`]. Unlike [`min_inc`], the struct tracks the window with a
+/// [`RollingExtremum`], so it never needs a full recalculation and reproduces [`min`] exactly.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Min {
+    opt_period: usize,
+    window: RollingExtremum,
+}
+
+impl Min {
+    /// Creates a streaming rolling minimum.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: RollingExtremum::lowest(opt_period)?,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the rolling minimum for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let (min_val, _) = self.window.push(input_price);
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(min_val)
+    }
+}
+
+impl Indicator for Min {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.is_full()
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -211,6 +288,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_min_calculation() {
         let input_close = vec![
             35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
@@ -254,4 +332,33 @@ fn test_min_calculation() {
             prev_min = result;
         }
     }
+
+    #[test]
+    fn test_min_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let mut output_min = vec![0.0; input_price.len()];
+        min(&input_price, opt_period, &mut output_min).unwrap();
+
+        let mut indicator = Min::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_min[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -1,7 +1,36 @@
-// pub mod beta;
+pub mod alpha;
+pub mod beta;
+pub mod calmar;
 pub mod correl;
+pub mod drawdown;
+pub mod fv;
+pub mod garman_klass;
+pub mod hv;
+pub mod irr;
+pub mod kelly;
+pub mod kurt;
+pub mod linreg;
 pub mod max;
 pub mod min;
+pub mod moments;
+pub mod nper;
+pub mod npv;
+pub mod order_stats;
+pub mod parkinson;
+pub mod percentrank;
+pub mod pmt;
+pub mod pv;
+pub mod quantile;
+pub mod rate;
+pub mod ret;
+pub mod risk;
+pub mod rogers_satchell;
+pub mod sharpe;
+pub mod skew;
+pub mod sortino;
 pub mod stddev;
 pub mod sum;
 pub mod var;
+pub mod winrate;
+pub mod yang_zhang;
+pub mod zscore;
@@ -0,0 +1,764 @@
+use crate::TAFloat;
+
+/// Running sum with Neumaier compensation.
+///
+/// Keeps the low-order bits lost by each addition in a separate term, so long streams of
+/// increments do not drift the way a plain `+=` accumulator does.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompensatedSum {
+    sum: TAFloat,
+    compensation: TAFloat,
+}
+
+impl CompensatedSum {
+    /// Creates a sum starting at `value`.
+    #[must_use]
+    pub const fn new(value: TAFloat) -> Self {
+        Self {
+            sum: value,
+            compensation: 0.0,
+        }
+    }
+
+    /// Adds `value` to the sum.
+    pub fn add(&mut self, value: TAFloat) {
+        let sum = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - sum) + value;
+        } else {
+            self.compensation += (value - sum) + self.sum;
+        }
+        self.sum = sum;
+    }
+
+    /// Returns the compensated value of the sum.
+    #[must_use]
+    pub fn value(&self) -> TAFloat {
+        self.sum + self.compensation
+    }
+}
+
+/// Mean and sum of squared deviations of a sliding window, updated with Welford's method.
+///
+/// # Description
+/// Tracking the mean and `M2 = Σ(x - mean)²` directly, instead of `Σx` and `Σx²`, avoids the
+/// catastrophic cancellation of `Σx² - Σx·mean` when values are large relative to their spread,
+/// such as prices around 1e5. Both terms are accumulated with [`CompensatedSum`] so they do not
+/// drift over long streams, and the variance is clamped at zero.
+///
+/// # Mathematical Formula
+/// ```text
+/// Push x (n -> n+1):       mean' = mean + (x - mean) / (n + 1)
+///                          M2'   = M2 + (x - mean) * (x - mean')
+/// Pop x (n -> n-1):        mean' = mean - (x - mean) / (n - 1)
+///                          M2'   = M2 - (x - mean) * (x - mean')
+/// Replace old by new (n):  mean' = mean + (new - old) / n
+///                          M2'   = M2 + (new - old) * (new - mean' + old - mean)
+/// Variance = M2 / n
+/// ```
+///
+/// # Example
+/// ```
+/// use kand::stats::moments::RollingMoments;
+/// let mut moments = RollingMoments::new();
+/// for value in [100_000.1, 100_000.2, 100_000.3] {
+///     moments.push(value);
+/// }
+/// moments.replace(100_000.4, 100_000.1);
+/// assert!((moments.mean() - 100_000.3).abs() < 1e-9);
+/// assert!((moments.variance() - 0.02 / 3.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingMoments {
+    count: usize,
+    mean: CompensatedSum,
+    m2: CompensatedSum,
+}
+
+impl RollingMoments {
+    /// Creates moments of an empty window.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: CompensatedSum::new(0.0),
+            m2: CompensatedSum::new(0.0),
+        }
+    }
+
+    /// Adds a value, growing the window by one.
+    pub fn push(&mut self, value: TAFloat) {
+        self.count += 1;
+        let mean = self.mean();
+        let delta = value - mean;
+        let step = delta / self.count as TAFloat;
+        self.mean.add(step);
+        self.m2.add(delta * (value - (mean + step)));
+    }
+
+    /// Removes a value that is in the window, shrinking it by one.
+    pub fn pop(&mut self, value: TAFloat) {
+        if self.count <= 1 {
+            *self = Self::new();
+            return;
+        }
+        self.count -= 1;
+        let mean = self.mean();
+        let delta = value - mean;
+        let step = delta / self.count as TAFloat;
+        self.mean.add(-step);
+        self.m2.add(-delta * (value - (mean - step)));
+    }
+
+    /// Replaces `old_value`, which is in the window, by `new_value`, keeping the window size.
+    pub fn replace(&mut self, new_value: TAFloat, old_value: TAFloat) {
+        let (mean_step, m2_step) =
+            slide_steps(self.mean(), new_value, old_value, self.count as TAFloat);
+        self.mean.add(mean_step);
+        self.m2.add(m2_step);
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the window.
+    #[must_use]
+    pub fn mean(&self) -> TAFloat {
+        self.mean.value()
+    }
+
+    /// Returns the sum of squared deviations from the mean, clamped at zero.
+    #[must_use]
+    pub fn m2(&self) -> TAFloat {
+        self.m2.value().max(0.0)
+    }
+
+    /// Returns the population variance `M2 / n`, NaN for an empty window.
+    #[must_use]
+    pub fn variance(&self) -> TAFloat {
+        if self.count == 0 {
+            return TAFloat::NAN;
+        }
+        self.m2() / self.count as TAFloat
+    }
+}
+
+/// Means, squared deviations and co-deviation of a sliding window of paired values.
+///
+/// # Description
+/// The two-series counterpart of [`RollingMoments`]: besides the moments of each series it
+/// tracks `C = Σ(x - mean_x)(y - mean_y)`, which gives the covariance and correlation without
+/// the cancellation of `n·Σxy - Σx·Σy`.
+///
+/// # Mathematical Formula
+/// ```text
+/// Replace (x_old, y_old) by (x_new, y_new):
+///   C' = C + (x_new - mean_x) * (y_new - mean_y') - (x_old - mean_x) * (y_old - mean_y')
+/// Correlation = C / sqrt(M2_x * M2_y)
+/// ```
+///
+/// # Example
+/// ```
+/// use kand::stats::moments::RollingCoMoments;
+/// let mut moments = RollingCoMoments::new();
+/// for (x, y) in [(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)] {
+///     moments.push(x, y);
+/// }
+/// assert!((moments.correlation() - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingCoMoments {
+    series_0: RollingMoments,
+    series_1: RollingMoments,
+    c_01: CompensatedSum,
+}
+
+impl RollingCoMoments {
+    /// Creates co-moments of an empty window.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            series_0: RollingMoments::new(),
+            series_1: RollingMoments::new(),
+            c_01: CompensatedSum::new(0.0),
+        }
+    }
+
+    /// Adds a pair, growing the window by one.
+    pub fn push(&mut self, value_0: TAFloat, value_1: TAFloat) {
+        let delta_0 = value_0 - self.series_0.mean();
+        self.series_0.push(value_0);
+        self.series_1.push(value_1);
+        self.c_01.add(delta_0 * (value_1 - self.series_1.mean()));
+    }
+
+    /// Replaces the pair `(old_0, old_1)`, which is in the window, by `(new_0, new_1)`.
+    pub fn replace(&mut self, new_0: TAFloat, new_1: TAFloat, old_0: TAFloat, old_1: TAFloat) {
+        let mean_0 = self.series_0.mean();
+        self.series_0.replace(new_0, old_0);
+        self.series_1.replace(new_1, old_1);
+        self.c_01.add(co_slide_step(
+            mean_0,
+            self.series_1.mean(),
+            new_0,
+            new_1,
+            old_0,
+            old_1,
+        ));
+    }
+
+    /// Returns the moments of the first series.
+    #[must_use]
+    pub const fn series_0(&self) -> &RollingMoments {
+        &self.series_0
+    }
+
+    /// Returns the moments of the second series.
+    #[must_use]
+    pub const fn series_1(&self) -> &RollingMoments {
+        &self.series_1
+    }
+
+    /// Returns the sum of co-deviations `Σ(x - mean_x)(y - mean_y)`.
+    #[must_use]
+    pub fn c_01(&self) -> TAFloat {
+        self.c_01.value()
+    }
+
+    /// Returns the Pearson correlation, NaN if either series is constant.
+    #[must_use]
+    pub fn correlation(&self) -> TAFloat {
+        correlation(self.c_01(), self.series_0.m2(), self.series_1.m2())
+    }
+}
+
+/// Mean and second to fourth central moment sums of a sliding window.
+///
+/// # Description
+/// Extends [`RollingMoments`] with `M3 = Σ(x - mean)³` and `M4 = Σ(x - mean)⁴`, which give the
+/// skewness and kurtosis of the window. Values are added and removed with the one-pass update
+/// formulas of Pébay (2008), so every step is O(1) and never goes through raw power sums.
+///
+/// # Mathematical Formula
+/// ```text
+/// Push x (n -> n+1), with d = x - mean, e = d / (n + 1), t = d * e * n:
+///   mean' = mean + e
+///   M4'   = M4 + t * e² * ((n+1)² - 3(n+1) + 3) + 6 * e² * M2 - 4 * e * M3
+///   M3'   = M3 + t * e * (n - 1) - 3 * e * M2
+///   M2'   = M2 + t
+/// Pop inverts push; replace is a pop followed by a push.
+/// Skewness        = sqrt(n) * M3 / M2^(3/2)
+/// Excess Kurtosis = n * M4 / M2² - 3
+/// ```
+///
+/// # Example
+/// ```
+/// use kand::stats::moments::RollingHigherMoments;
+/// let mut moments = RollingHigherMoments::new();
+/// for value in [1.0, 2.0, 3.0, 10.0] {
+///     moments.push(value);
+/// }
+/// assert!(moments.skewness() > 0.0);
+/// moments.replace(4.0, 10.0);
+/// assert!(moments.skewness().abs() < 1e-12);
+/// assert!((moments.excess_kurtosis() + 1.36).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingHigherMoments {
+    count: usize,
+    mean: CompensatedSum,
+    m2: CompensatedSum,
+    m3: CompensatedSum,
+    m4: CompensatedSum,
+}
+
+impl RollingHigherMoments {
+    /// Creates moments of an empty window.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: CompensatedSum::new(0.0),
+            m2: CompensatedSum::new(0.0),
+            m3: CompensatedSum::new(0.0),
+            m4: CompensatedSum::new(0.0),
+        }
+    }
+
+    /// Adds a value, growing the window by one.
+    pub fn push(&mut self, value: TAFloat) {
+        let steps = push_steps(
+            self.count as TAFloat,
+            self.mean(),
+            self.m2.value(),
+            self.m3.value(),
+            value,
+        );
+        self.count += 1;
+        self.add_steps(steps);
+    }
+
+    /// Removes a value that is in the window, shrinking it by one.
+    pub fn pop(&mut self, value: TAFloat) {
+        if self.count <= 1 {
+            *self = Self::new();
+            return;
+        }
+        let steps = pop_steps(
+            self.count as TAFloat,
+            self.mean(),
+            self.m2.value(),
+            self.m3.value(),
+            value,
+        );
+        self.count -= 1;
+        self.add_steps(steps);
+    }
+
+    /// Replaces `old_value`, which is in the window, by `new_value`, keeping the window size.
+    pub fn replace(&mut self, new_value: TAFloat, old_value: TAFloat) {
+        self.pop(old_value);
+        self.push(new_value);
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the window.
+    #[must_use]
+    pub fn mean(&self) -> TAFloat {
+        self.mean.value()
+    }
+
+    /// Returns the sum of squared deviations from the mean, clamped at zero.
+    #[must_use]
+    pub fn m2(&self) -> TAFloat {
+        self.m2.value().max(0.0)
+    }
+
+    /// Returns the sum of cubed deviations from the mean.
+    #[must_use]
+    pub fn m3(&self) -> TAFloat {
+        self.m3.value()
+    }
+
+    /// Returns the sum of fourth-power deviations from the mean, clamped at zero.
+    #[must_use]
+    pub fn m4(&self) -> TAFloat {
+        self.m4.value().max(0.0)
+    }
+
+    /// Returns the population skewness, NaN for an empty or constant window.
+    #[must_use]
+    pub fn skewness(&self) -> TAFloat {
+        skewness(self.m2(), self.m3(), self.count)
+    }
+
+    /// Returns the population excess kurtosis, NaN for an empty or constant window.
+    #[must_use]
+    pub fn excess_kurtosis(&self) -> TAFloat {
+        excess_kurtosis(self.m2(), self.m4(), self.count)
+    }
+
+    fn add_steps(&mut self, (mean_step, m2_step, m3_step, m4_step): Steps) {
+        self.mean.add(mean_step);
+        self.m2.add(m2_step);
+        self.m3.add(m3_step);
+        self.m4.add(m4_step);
+    }
+}
+
+/// Slides a window of `n` values by one using Welford's update, without compensation.
+///
+/// Takes the previous mean and `M2` and returns the new `(mean, M2)` after replacing
+/// `old_value` by `new_value`. This is the O(1) core of the `_inc` functions built on
+/// [`RollingMoments`].
+///
+/// # Example
+/// ```
+/// use kand::stats::moments;
+/// // Window [2, 4, 6] has mean 4 and M2 8; sliding in 8 gives [4, 6, 8]
+/// let (mean, m2) = moments::slide(4.0, 8.0, 8.0, 2.0, 3);
+/// assert!((mean - 6.0).abs() < 1e-12);
+/// assert!((m2 - 8.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn slide(
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    new_value: TAFloat,
+    old_value: TAFloat,
+    opt_period: usize,
+) -> (TAFloat, TAFloat) {
+    let (mean_step, m2_step) = slide_steps(prev_mean, new_value, old_value, opt_period as TAFloat);
+    (prev_mean + mean_step, (prev_m2 + m2_step).max(0.0))
+}
+
+/// Slides a window of `n` pairs by one, without compensation.
+///
+/// Returns the new `(mean_0, mean_1, m2_0, m2_1, c_01)` after replacing `(old_0, old_1)` by
+/// `(new_0, new_1)`.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn co_slide(
+    prev_mean_0: TAFloat,
+    prev_mean_1: TAFloat,
+    prev_m2_0: TAFloat,
+    prev_m2_1: TAFloat,
+    prev_c_01: TAFloat,
+    new_0: TAFloat,
+    new_1: TAFloat,
+    old_0: TAFloat,
+    old_1: TAFloat,
+    opt_period: usize,
+) -> (TAFloat, TAFloat, TAFloat, TAFloat, TAFloat) {
+    let (mean_0, m2_0) = slide(prev_mean_0, prev_m2_0, new_0, old_0, opt_period);
+    let (mean_1, m2_1) = slide(prev_mean_1, prev_m2_1, new_1, old_1, opt_period);
+    let c_01 = prev_c_01 + co_slide_step(prev_mean_0, mean_1, new_0, new_1, old_0, old_1);
+    (mean_0, mean_1, m2_0, m2_1, c_01)
+}
+
+/// Returns the Pearson correlation from co-deviation and squared deviations, NaN if either
+/// series is constant.
+#[must_use]
+pub fn correlation(c_01: TAFloat, m2_0: TAFloat, m2_1: TAFloat) -> TAFloat {
+    let denominator = (m2_0 * m2_1).sqrt();
+    if denominator > 0.0 {
+        (c_01 / denominator).clamp(-1.0, 1.0)
+    } else {
+        TAFloat::NAN
+    }
+}
+
+/// Slides a window of `n` values by one, updating the mean and `M2` to `M4`, without
+/// compensation.
+///
+/// Returns the new `(mean, M2, M3, M4)` after replacing `old_value` by `new_value`. `M4` does not
+/// feed back into the lower moments, so callers that only need the skewness may pass `0.0` for
+/// it and ignore the result.
+///
+/// # Example
+/// ```
+/// use kand::stats::moments;
+/// // Window [1, 2, 3] has mean 2, M2 2, M3 0 and M4 2; sliding in 7 gives [2, 3, 7]
+/// let (mean, m2, m3, m4) = moments::slide_higher(2.0, 2.0, 0.0, 2.0, 7.0, 1.0, 3);
+/// assert!((mean - 4.0).abs() < 1e-12);
+/// assert!((m2 - 14.0).abs() < 1e-12);
+/// assert!((m3 - 18.0).abs() < 1e-12);
+/// assert!((m4 - 98.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn slide_higher(
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    prev_m3: TAFloat,
+    prev_m4: TAFloat,
+    new_value: TAFloat,
+    old_value: TAFloat,
+    opt_period: usize,
+) -> (TAFloat, TAFloat, TAFloat, TAFloat) {
+    let n = opt_period as TAFloat;
+    let (mean_step, m2_step, m3_step, m4_step) =
+        pop_steps(n, prev_mean, prev_m2, prev_m3, old_value);
+    let (mean, m2, m3, m4) = (
+        prev_mean + mean_step,
+        prev_m2 + m2_step,
+        prev_m3 + m3_step,
+        prev_m4 + m4_step,
+    );
+    let (mean_step, m2_step, m3_step, m4_step) = push_steps(n - 1.0, mean, m2, m3, new_value);
+    (
+        mean + mean_step,
+        (m2 + m2_step).max(0.0),
+        m3 + m3_step,
+        (m4 + m4_step).max(0.0),
+    )
+}
+
+/// Returns the population skewness `sqrt(n) * M3 / M2^(3/2)`, NaN if the window is constant.
+#[must_use]
+pub fn skewness(m2: TAFloat, m3: TAFloat, count: usize) -> TAFloat {
+    if m2 > 0.0 {
+        (count as TAFloat).sqrt() * m3 / (m2 * m2.sqrt())
+    } else {
+        TAFloat::NAN
+    }
+}
+
+/// Returns the population excess kurtosis `n * M4 / M2² - 3`, NaN if the window is constant.
+#[must_use]
+pub fn excess_kurtosis(m2: TAFloat, m4: TAFloat, count: usize) -> TAFloat {
+    if m2 > 0.0 {
+        count as TAFloat * m4 / (m2 * m2) - 3.0
+    } else {
+        TAFloat::NAN
+    }
+}
+
+/// Increments of the mean and `M2` to `M4`.
+type Steps = (TAFloat, TAFloat, TAFloat, TAFloat);
+
+/// Increments when `value` is added to a window of `n` values with the given moments.
+fn push_steps(n: TAFloat, mean: TAFloat, m2: TAFloat, m3: TAFloat, value: TAFloat) -> Steps {
+    let count = n + 1.0;
+    let delta = value - mean;
+    let delta_n = delta / count;
+    let delta_n2 = delta_n * delta_n;
+    let term = delta * delta_n * n;
+    let m3_step = (term * delta_n).mul_add(count - 2.0, -3.0 * delta_n * m2);
+    let m4_step = (term * delta_n2).mul_add(
+        count.mul_add(count, 3.0 - 3.0 * count),
+        (6.0 * delta_n2).mul_add(m2, -4.0 * delta_n * m3),
+    );
+    (delta_n, term, m3_step, m4_step)
+}
+
+/// Increments when `value`, which is in the window, is removed from a window of `n` values.
+///
+/// Inverts [`push_steps`]: the moments of the remaining `n - 1` values are the ones that pushing
+/// `value` back would turn into the current moments.
+fn pop_steps(n: TAFloat, mean: TAFloat, m2: TAFloat, m3: TAFloat, value: TAFloat) -> Steps {
+    let remaining = n - 1.0;
+    let mean_step = -(value - mean) / remaining;
+    let delta = value - (mean + mean_step);
+    let delta_n = delta / n;
+    let delta_n2 = delta_n * delta_n;
+    let term = delta * delta_n * remaining;
+    let new_m2 = m2 - term;
+    let m3_step = (3.0 * delta_n).mul_add(new_m2, -(term * delta_n * (n - 2.0)));
+    let new_m3 = m3 + m3_step;
+    let m4_step = (4.0 * delta_n).mul_add(
+        new_m3,
+        -(6.0 * delta_n2).mul_add(new_m2, term * delta_n2 * n.mul_add(n, 3.0 - 3.0 * n)),
+    );
+    (mean_step, -term, m3_step, m4_step)
+}
+
+/// Increments of the mean and `M2` when `old_value` is replaced by `new_value` in a window of
+/// `n` values.
+fn slide_steps(
+    mean: TAFloat,
+    new_value: TAFloat,
+    old_value: TAFloat,
+    n: TAFloat,
+) -> (TAFloat, TAFloat) {
+    let delta = new_value - old_value;
+    let mean_step = delta / n;
+    let new_mean = mean + mean_step;
+    (mean_step, delta * (new_value - new_mean + old_value - mean))
+}
+
+/// Increment of the co-deviation when `(old_0, old_1)` is replaced by `(new_0, new_1)`, given
+/// the previous mean of the first series and the updated mean of the second.
+fn co_slide_step(
+    prev_mean_0: TAFloat,
+    new_mean_1: TAFloat,
+    new_0: TAFloat,
+    new_1: TAFloat,
+    old_0: TAFloat,
+    old_1: TAFloat,
+) -> TAFloat {
+    (new_0 - prev_mean_0).mul_add(
+        new_1 - new_mean_1,
+        -(old_0 - prev_mean_0) * (old_1 - new_mean_1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    /// Two-pass population variance of a window, as a reference.
+    fn two_pass(values: &[TAFloat]) -> TAFloat {
+        let mean = values.iter().sum::<TAFloat>() / values.len() as TAFloat;
+        values.iter().map(|v| (v - mean).powi(2)).sum::<TAFloat>() / values.len() as TAFloat
+    }
+
+    #[test]
+    fn test_compensated_sum() {
+        let mut sum = CompensatedSum::new(1e16);
+        for _ in 0..1000 {
+            sum.add(1.0);
+        }
+        sum.add(-1e16);
+        assert_eq!(sum.value(), 1000.0);
+    }
+
+    #[test]
+    fn test_rolling_moments_stability() {
+        // Prices around 1e5 with a spread of cents, streamed for a long time
+        let period = 20;
+        let prices: Vec<TAFloat> = (0..200_000)
+            .map(|i| 100_000.0 + ((i * 7919) % 13) as TAFloat * 0.01)
+            .collect();
+
+        let mut moments = RollingMoments::new();
+        for &price in &prices[..period] {
+            moments.push(price);
+        }
+        for i in period..prices.len() {
+            moments.replace(prices[i], prices[i - period]);
+        }
+        let window = &prices[prices.len() - period..];
+        assert_relative_eq!(moments.variance(), two_pass(window), epsilon = 1e-10);
+        assert_relative_eq!(
+            moments.mean(),
+            window.iter().sum::<TAFloat>() / period as TAFloat,
+            epsilon = 1e-9
+        );
+
+        // A constant window never goes negative
+        let mut moments = RollingMoments::new();
+        for _ in 0..period {
+            moments.push(100_000.1);
+        }
+        for _ in 0..10_000 {
+            moments.replace(100_000.1, 100_000.1);
+        }
+        assert!(moments.variance() >= 0.0);
+        assert!(moments.variance() < 1e-12);
+    }
+
+    #[test]
+    fn test_rolling_moments_push_pop() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mut moments = RollingMoments::new();
+        assert!(moments.variance().is_nan());
+        for &value in &values {
+            moments.push(value);
+        }
+        assert_eq!(moments.count(), 8);
+        assert_relative_eq!(moments.mean(), 5.0);
+        assert_relative_eq!(moments.variance(), 4.0);
+
+        moments.pop(2.0);
+        assert_relative_eq!(moments.variance(), two_pass(&values[1..]), epsilon = 1e-12);
+
+        // Stateless slide matches the compensated engine
+        let (mean, m2) = slide(moments.mean(), moments.m2(), 10.0, 4.0, 7);
+        moments.replace(10.0, 4.0);
+        assert_relative_eq!(mean, moments.mean(), epsilon = 1e-12);
+        assert_relative_eq!(m2, moments.m2(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_rolling_co_moments() {
+        let xs = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let ys = [2.0, 1.0, 4.0, 3.0, 7.0, 5.0];
+        let period = 4;
+
+        let mut moments = RollingCoMoments::new();
+        for i in 0..period {
+            moments.push(xs[i], ys[i]);
+        }
+        let (mut mean_0, mut mean_1) = (moments.series_0().mean(), moments.series_1().mean());
+        let (mut m2_0, mut m2_1, mut c_01) = (
+            moments.series_0().m2(),
+            moments.series_1().m2(),
+            moments.c_01(),
+        );
+        for i in period..xs.len() {
+            moments.replace(xs[i], ys[i], xs[i - period], ys[i - period]);
+            (mean_0, mean_1, m2_0, m2_1, c_01) = co_slide(
+                mean_0,
+                mean_1,
+                m2_0,
+                m2_1,
+                c_01,
+                xs[i],
+                ys[i],
+                xs[i - period],
+                ys[i - period],
+                period,
+            );
+        }
+
+        // Window [3, 4, 5, 6] and [4, 3, 7, 5]
+        assert_relative_eq!(moments.c_01(), 3.5, epsilon = 1e-12);
+        assert_relative_eq!(
+            moments.correlation(),
+            3.5 / (5.0_f64 * 8.75).sqrt(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            correlation(c_01, m2_0, m2_1),
+            moments.correlation(),
+            epsilon = 1e-12
+        );
+
+        // A constant series has no correlation
+        assert!(correlation(1.0, 0.0, 2.0).is_nan());
+    }
+
+    #[test]
+    fn test_rolling_higher_moments() {
+        // Two-pass central moment sums of a window, as a reference
+        fn central(values: &[TAFloat], power: i32) -> TAFloat {
+            let mean = values.iter().sum::<TAFloat>() / values.len() as TAFloat;
+            values.iter().map(|v| (v - mean).powi(power)).sum()
+        }
+
+        let values: Vec<TAFloat> = (0..500)
+            .map(|i| 35_000.0 + ((i * 37) % 23) as TAFloat * 1.5 + (i % 5) as TAFloat)
+            .collect();
+        let period = 12;
+
+        let mut moments = RollingHigherMoments::new();
+        for &value in &values[..period] {
+            moments.push(value);
+        }
+        let (mut mean, mut m2, mut m3, mut m4) =
+            (moments.mean(), moments.m2(), moments.m3(), moments.m4());
+        for i in period..values.len() {
+            moments.replace(values[i], values[i - period]);
+            (mean, m2, m3, m4) =
+                slide_higher(mean, m2, m3, m4, values[i], values[i - period], period);
+
+            let window = &values[i + 1 - period..=i];
+            let (c2, c3, c4) = (central(window, 2), central(window, 3), central(window, 4));
+            assert_relative_eq!(moments.m2(), c2, max_relative = 1e-8);
+            assert_relative_eq!(moments.m3(), c3, epsilon = 1e-6 * c2.powf(1.5));
+            assert_relative_eq!(moments.m4(), c4, max_relative = 1e-8);
+            assert_relative_eq!(m2, c2, max_relative = 1e-8);
+            assert_relative_eq!(m3, c3, epsilon = 1e-6 * c2.powf(1.5));
+            assert_relative_eq!(m4, c4, max_relative = 1e-8);
+        }
+        assert_eq!(moments.count(), period);
+
+        // Pop back to a known window: [1, 2, 3, 10] without 10
+        let mut moments = RollingHigherMoments::new();
+        for value in [1.0, 2.0, 3.0, 10.0] {
+            moments.push(value);
+        }
+        moments.pop(10.0);
+        assert_relative_eq!(moments.mean(), 2.0, epsilon = 1e-12);
+        assert_relative_eq!(moments.m2(), 2.0, epsilon = 1e-12);
+        assert_relative_eq!(moments.m3(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(moments.m4(), 2.0, epsilon = 1e-12);
+        assert_relative_eq!(
+            moments.excess_kurtosis(),
+            3.0 * 2.0 / 4.0 - 3.0,
+            epsilon = 1e-12
+        );
+
+        // A constant window has no shape
+        let mut moments = RollingHigherMoments::new();
+        for _ in 0..4 {
+            moments.push(5.0);
+        }
+        assert!(moments.skewness().is_nan());
+        assert!(moments.excess_kurtosis().is_nan());
+    }
+}
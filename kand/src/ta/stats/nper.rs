@@ -0,0 +1,115 @@
+use crate::{KandError, TAFloat};
+
+/// Calculates the number of periods of an investment, as the spreadsheet `NPER` function does.
+///
+/// # Description
+/// The number of periods is how long the payment `input_pmt` has to be made at `input_rate`
+/// for the present value `input_pv` to reach the future value `input_fv`. The result is
+/// fractional in general. Cash paid out is negative and cash received is positive.
+///
+/// # Mathematical Formula
+/// ```text
+/// z = PMT * (1 + r * w) / r
+/// n = ln((z - FV) / (PV + z)) / ln(1 + r)
+/// n = -(FV + PV) / PMT                                  when r = 0
+/// ```
+/// Where:
+/// - r is the rate per period
+/// - w is 1 when payments are made at the beginning of each period and 0 at the end
+///
+/// # Arguments
+/// * `input_rate` - Interest rate per period
+/// * `input_pmt` - Payment made each period
+/// * `input_pv` - Present value
+/// * `input_fv` - Future value
+/// * `opt_begin` - Whether payments are due at the beginning (`true`) or end (`false`) of each period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The number of periods on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if the rate is not greater than -1
+/// * Returns `KandError::CalculationError` if no number of periods settles the cash flows,
+///   e.g. when the payment does not cover the interest
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::nper;
+/// // Months needed to grow 1,000 into 10,000 saving 100 a month at 12% a year
+/// let value = nper::nper(0.12 / 12.0, -100.0, -1000.0, 10000.0, true).unwrap();
+/// assert!((value - 59.6739).abs() < 1e-4);
+/// ```
+pub fn nper(
+    input_rate: TAFloat,
+    input_pmt: TAFloat,
+    input_pv: TAFloat,
+    input_fv: TAFloat,
+    opt_begin: bool,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if input_rate <= -1.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_rate.is_nan() || input_pmt.is_nan() || input_pv.is_nan() || input_fv.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let periods = if input_rate == 0.0 {
+        -(input_fv + input_pv) / input_pmt
+    } else {
+        let timing = if opt_begin { 1.0 + input_rate } else { 1.0 };
+        let z = input_pmt * timing / input_rate;
+        ((z - input_fv) / (input_pv + z)).ln() / input_rate.ln_1p()
+    };
+
+    if !periods.is_finite() {
+        return Err(KandError::CalculationError(
+            "NPER has no solution for the given cash flows".to_string(),
+        ));
+    }
+
+    Ok(periods)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_nper_calculation() {
+        assert_relative_eq!(
+            nper(0.12 / 12.0, -100.0, -1000.0, 10000.0, true).unwrap(),
+            59.673_865_674_294_57,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            nper(0.12 / 12.0, -100.0, -1000.0, 10000.0, false).unwrap(),
+            60.082_122_853_761_66,
+            epsilon = 1e-9
+        );
+        // Paying off a 20,000 loan at 500 a month and 5% a year
+        assert_relative_eq!(
+            nper(0.05 / 12.0, -500.0, 20000.0, 0.0, false).unwrap(),
+            43.848_271_234_390_815,
+            epsilon = 1e-9
+        );
+
+        // Zero rate divides the balance by the payment
+        assert_relative_eq!(nper(0.0, -100.0, 1000.0, 0.0, false).unwrap(), 10.0);
+
+        // The payment never covers the interest
+        assert!(matches!(
+            nper(0.1, -50.0, 1000.0, 0.0, false),
+            Err(KandError::CalculationError(_))
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{KandError, TAFloat};
+
+/// Calculates the net present value of a series of cash flows, as the spreadsheet `NPV`
+/// function does.
+///
+/// # Description
+/// Each cash flow is discounted at `input_rate` per period. As in spreadsheets, the first value
+/// is received one period from now and is discounted once; add an initial outlay made today to
+/// the result separately.
+///
+/// # Mathematical Formula
+/// ```text
+/// NPV = Σ values[i] / (1 + r)^(i + 1),  i = 0..len
+/// ```
+///
+/// # Arguments
+/// * `input_rate` - Discount rate per period
+/// * `input_values` - Cash flows at the end of each period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The net present value on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if the input is empty
+/// * Returns `KandError::InvalidParameter` if the rate is not greater than -1
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::npv;
+/// let value = npv::npv(0.1, &[-10000.0, 3000.0, 4200.0, 6800.0]).unwrap();
+/// assert!((value - 1188.44).abs() < 0.01);
+/// ```
+pub fn npv(input_rate: TAFloat, input_values: &[TAFloat]) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if input_values.is_empty() {
+            return Err(KandError::InvalidData);
+        }
+        if input_rate <= -1.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_rate.is_nan() || input_values.iter().any(|v| v.is_nan()) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    // Horner's scheme from the last cash flow back to the first
+    let discount = 1.0 / (1.0 + input_rate);
+    Ok(input_values
+        .iter()
+        .rev()
+        .fold(0.0, |acc, &value| (acc + value) * discount))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_npv_calculation() {
+        assert_relative_eq!(
+            npv(0.1, &[-10000.0, 3000.0, 4200.0, 6800.0]).unwrap(),
+            1_188.443_412_335_221_6,
+            epsilon = 1e-8
+        );
+        // Initial outlay made today is added outside
+        assert_relative_eq!(
+            npv(0.08, &[8000.0, 9200.0, 10000.0, 12000.0, 14500.0]).unwrap() - 40000.0,
+            1_922.061_554_932_363,
+            epsilon = 1e-8
+        );
+
+        // Zero rate is a plain sum
+        assert_relative_eq!(npv(0.0, &[1.0, 2.0, 3.0]).unwrap(), 6.0);
+
+        #[cfg(feature = "check")]
+        {
+            assert!(npv(0.1, &[]).is_err());
+            assert!(npv(-1.0, &[1.0]).is_err());
+        }
+    }
+}
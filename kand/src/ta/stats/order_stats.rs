@@ -0,0 +1,418 @@
+use crate::TAFloat;
+
+/// Marks a missing child or an empty tree.
+const NIL: usize = usize::MAX;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Node {
+    value: TAFloat,
+    priority: u64,
+    left: usize,
+    right: usize,
+    size: usize,
+    sum: TAFloat,
+}
+
+/// Multiset of values supporting rank and selection queries in O(log n).
+///
+/// # Description
+/// The sliding order-statistic structure behind the rolling quantile and percentile rank
+/// indicators. Values are kept in a treap (a binary search tree balanced by random priorities)
+/// whose nodes carry subtree sizes and sums, so inserting, removing, finding the k-th smallest
+/// value, summing the k smallest values and counting values below a threshold all take expected
+/// O(log n). Sliding a window therefore costs
+/// O(log period) per bar instead of re-sorting the whole window.
+///
+/// Nodes live in a vector and removed slots are reused, so a window of `period` values never
+/// holds more than `period` nodes. Priorities come from a deterministic generator, which keeps
+/// results reproducible and the structure serializable.
+///
+/// NaN values are not ordered and must not be inserted.
+///
+/// # Example
+/// ```
+/// use kand::stats::order_stats::OrderStatistics;
+/// let mut window = OrderStatistics::new();
+/// for value in [5.0, 1.0, 4.0, 2.0, 3.0] {
+///     window.insert(value);
+/// }
+/// assert_eq!(window.select(0), Some(1.0));
+/// assert_eq!(window.quantile(0.5), 3.0);
+/// assert_eq!(window.count_less(4.0), 3);
+///
+/// assert!(window.remove(3.0));
+/// assert_eq!(window.quantile(0.5), 3.0); // midway between 2 and 4
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderStatistics {
+    nodes: Vec<Node>,
+    free: Vec<usize>,
+    root: usize,
+    seed: u64,
+}
+
+impl OrderStatistics {
+    /// Creates an empty multiset.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            seed: 0x853C_49E6_748F_EA9B,
+        }
+    }
+
+    /// Creates an empty multiset with room for `capacity` values.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the number of values held.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    /// Returns `true` if no values are held.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.root == NIL
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.free.clear();
+        self.root = NIL;
+    }
+
+    /// Adds a value.
+    pub fn insert(&mut self, value: TAFloat) {
+        let node = Node {
+            value,
+            priority: self.next_priority(),
+            left: NIL,
+            right: NIL,
+            size: 1,
+            sum: value,
+        };
+        let index = if let Some(index) = self.free.pop() {
+            self.nodes[index] = node;
+            index
+        } else {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        };
+
+        let (less, rest) = self.split(self.root, value, false);
+        let less = self.merge(less, index);
+        self.root = self.merge(less, rest);
+    }
+
+    /// Removes one occurrence of `value`, returning `false` if it is not held.
+    pub fn remove(&mut self, value: TAFloat) -> bool {
+        let (less, rest) = self.split(self.root, value, false);
+        let (equal, greater) = self.split(rest, value, true);
+        let found = equal != NIL;
+        let equal = if found {
+            let Node { left, right, .. } = self.nodes[equal];
+            self.free.push(equal);
+            self.merge(left, right)
+        } else {
+            NIL
+        };
+        let less = self.merge(less, equal);
+        self.root = self.merge(less, greater);
+        found
+    }
+
+    /// Returns the `k`-th smallest value (0-based), or `None` if fewer than `k + 1` are held.
+    #[must_use]
+    pub fn select(&self, mut k: usize) -> Option<TAFloat> {
+        let mut node = self.root;
+        while node != NIL {
+            let Node {
+                value, left, right, ..
+            } = self.nodes[node];
+            let left_size = self.size(left);
+            if k < left_size {
+                node = left;
+            } else if k == left_size {
+                return Some(value);
+            } else {
+                k -= left_size + 1;
+                node = right;
+            }
+        }
+        None
+    }
+
+    /// Returns the sum of the `k` smallest values, or of all values if fewer than `k` are held.
+    #[must_use]
+    pub fn sum_smallest(&self, mut k: usize) -> TAFloat {
+        let mut total = 0.0;
+        let mut node = self.root;
+        while node != NIL && k > 0 {
+            let Node {
+                value, left, right, ..
+            } = self.nodes[node];
+            let left_size = self.size(left);
+            if k <= left_size {
+                node = left;
+            } else {
+                total += self.sum(left) + value;
+                k -= left_size + 1;
+                node = right;
+            }
+        }
+        total
+    }
+
+    /// Returns the number of held values strictly less than `value`.
+    #[must_use]
+    pub fn count_less(&self, value: TAFloat) -> usize {
+        self.count_below(value, false)
+    }
+
+    /// Returns the number of held values less than or equal to `value`.
+    #[must_use]
+    pub fn count_less_equal(&self, value: TAFloat) -> usize {
+        self.count_below(value, true)
+    }
+
+    /// Returns the `q` quantile (0 <= q <= 1) of the held values, NaN if empty.
+    ///
+    /// Interpolates linearly between the two nearest order statistics, the default method of
+    /// NumPy and pandas: with the values sorted as `x[0..n]` and `h = q * (n - 1)`, the result is
+    /// `x[floor(h)] + (h - floor(h)) * (x[floor(h) + 1] - x[floor(h)])`.
+    #[must_use]
+    pub fn quantile(&self, q: TAFloat) -> TAFloat {
+        let len = self.len();
+        if len == 0 {
+            return TAFloat::NAN;
+        }
+        let h = q * (len - 1) as TAFloat;
+        let lower = h.floor();
+        let fraction = h - lower;
+        let lower_value = self.select(lower as usize).unwrap_or(TAFloat::NAN);
+        if fraction == 0.0 {
+            return lower_value;
+        }
+        let upper_value = self.select(lower as usize + 1).unwrap_or(lower_value);
+        fraction.mul_add(upper_value - lower_value, lower_value)
+    }
+
+    /// Returns the percentile rank of `value` among the held values, NaN if empty.
+    ///
+    /// Uses `100 * (count_less + 0.5 * count_equal) / n`, so the minimum of a window of distinct
+    /// values ranks just above 0, the maximum just below 100 and the median at 50.
+    #[must_use]
+    pub fn percent_rank(&self, value: TAFloat) -> TAFloat {
+        let len = self.len();
+        if len == 0 {
+            return TAFloat::NAN;
+        }
+        let less = self.count_less(value);
+        let equal = self.count_less_equal(value) - less;
+        100.0 * (0.5 as TAFloat).mul_add(equal as TAFloat, less as TAFloat) / len as TAFloat
+    }
+
+    fn count_below(&self, value: TAFloat, inclusive: bool) -> usize {
+        let mut count = 0;
+        let mut node = self.root;
+        while node != NIL {
+            let Node {
+                value: node_value,
+                left,
+                right,
+                ..
+            } = self.nodes[node];
+            if Self::goes_left(node_value, value, inclusive) {
+                count += self.size(left) + 1;
+                node = right;
+            } else {
+                node = left;
+            }
+        }
+        count
+    }
+
+    /// Returns `true` if `node_value` belongs to the lower part when splitting at `value`.
+    fn goes_left(node_value: TAFloat, value: TAFloat, inclusive: bool) -> bool {
+        if inclusive {
+            node_value <= value
+        } else {
+            node_value < value
+        }
+    }
+
+    fn size(&self, node: usize) -> usize {
+        if node == NIL {
+            0
+        } else {
+            self.nodes[node].size
+        }
+    }
+
+    fn sum(&self, node: usize) -> TAFloat {
+        if node == NIL {
+            0.0
+        } else {
+            self.nodes[node].sum
+        }
+    }
+
+    fn refresh(&mut self, node: usize) {
+        let Node {
+            value, left, right, ..
+        } = self.nodes[node];
+        self.nodes[node].size = self.size(left) + self.size(right) + 1;
+        self.nodes[node].sum = self.sum(left) + value + self.sum(right);
+    }
+
+    /// Splits `node` into values below `value` (or at most `value` if `inclusive`) and the rest.
+    fn split(&mut self, node: usize, value: TAFloat, inclusive: bool) -> (usize, usize) {
+        if node == NIL {
+            return (NIL, NIL);
+        }
+        let Node {
+            value: node_value,
+            left,
+            right,
+            ..
+        } = self.nodes[node];
+        if Self::goes_left(node_value, value, inclusive) {
+            let (lower, upper) = self.split(right, value, inclusive);
+            self.nodes[node].right = lower;
+            self.refresh(node);
+            (node, upper)
+        } else {
+            let (lower, upper) = self.split(left, value, inclusive);
+            self.nodes[node].left = upper;
+            self.refresh(node);
+            (lower, node)
+        }
+    }
+
+    /// Joins two trees where every value of `lower` is at most every value of `upper`.
+    fn merge(&mut self, lower: usize, upper: usize) -> usize {
+        if lower == NIL {
+            return upper;
+        }
+        if upper == NIL {
+            return lower;
+        }
+        if self.nodes[lower].priority > self.nodes[upper].priority {
+            let right = self.nodes[lower].right;
+            self.nodes[lower].right = self.merge(right, upper);
+            self.refresh(lower);
+            lower
+        } else {
+            let left = self.nodes[upper].left;
+            self.nodes[upper].left = self.merge(lower, left);
+            self.refresh(upper);
+            upper
+        }
+    }
+
+    /// Draws the next node priority from a `SplitMix64` sequence.
+    const fn next_priority(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Default for OrderStatistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_order_statistics_sliding_window() {
+        let values: Vec<TAFloat> = (0..2000)
+            .map(|i| ((i * 7919) % 101) as TAFloat * 0.5 - 20.0)
+            .collect();
+        let period = 37;
+
+        let mut window = OrderStatistics::with_capacity(period);
+        for &value in &values[..period] {
+            window.insert(value);
+        }
+        for i in period..values.len() {
+            assert!(window.remove(values[i - period]));
+            window.insert(values[i]);
+            assert_eq!(window.len(), period);
+
+            let mut sorted = values[i + 1 - period..=i].to_vec();
+            sorted.sort_by(TAFloat::total_cmp);
+            let mut prefix = 0.0;
+            for (k, &expected) in sorted.iter().enumerate() {
+                assert_eq!(window.select(k), Some(expected));
+                assert_relative_eq!(window.sum_smallest(k), prefix, epsilon = 1e-9);
+                prefix += expected;
+            }
+            assert_relative_eq!(window.sum_smallest(period + 5), prefix, epsilon = 1e-9);
+            assert_eq!(window.select(period), None);
+
+            let current = values[i];
+            let less = sorted.iter().filter(|&&v| v < current).count();
+            let less_equal = sorted.iter().filter(|&&v| v <= current).count();
+            assert_eq!(window.count_less(current), less);
+            assert_eq!(window.count_less_equal(current), less_equal);
+        }
+
+        // Removed slots are reused
+        assert!(window.nodes.len() <= period + 1);
+    }
+
+    #[test]
+    fn test_order_statistics_quantile() {
+        let mut window = OrderStatistics::new();
+        assert!(window.is_empty());
+        assert!(window.quantile(0.5).is_nan());
+        assert!(window.percent_rank(1.0).is_nan());
+
+        for value in [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0] {
+            window.insert(value);
+        }
+        // numpy.quantile([1, 1, 2, 3, 4, 5, 6, 9], q)
+        assert_relative_eq!(window.quantile(0.0), 1.0);
+        assert_relative_eq!(window.quantile(0.25), 1.75);
+        assert_relative_eq!(window.quantile(0.5), 3.5);
+        assert_relative_eq!(window.quantile(0.9), 6.9, epsilon = 1e-12);
+        assert_relative_eq!(window.quantile(1.0), 9.0);
+
+        // Ties share the midpoint of their ranks
+        assert_relative_eq!(window.percent_rank(1.0), 100.0 * 1.0 / 8.0);
+        assert_relative_eq!(window.percent_rank(9.0), 100.0 * 7.5 / 8.0);
+        assert_relative_eq!(window.percent_rank(7.0), 100.0 * 7.0 / 8.0);
+
+        assert!(!window.remove(7.0));
+        assert!(window.remove(1.0));
+        assert!(window.remove(1.0));
+        assert!(!window.remove(1.0));
+        assert_eq!(window.len(), 6);
+        assert_eq!(window.select(0), Some(2.0));
+
+        window.clear();
+        assert!(window.is_empty());
+        assert_eq!(window.len(), 0);
+    }
+}
@@ -0,0 +1,510 @@
+use std::collections::VecDeque;
+
+use super::moments::CompensatedSum;
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// `ln(2)` at the precision of [`TAFloat`].
+const LN_2: TAFloat = std::f64::consts::LN_2 as TAFloat;
+
+/// Calculates the lookback period required for Parkinson volatility calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For Parkinson volatility, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::parkinson;
+/// let period = 20;
+/// let lookback = parkinson::lookback(period).unwrap();
+/// assert_eq!(lookback, 19); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates rolling Parkinson volatility from high and low prices.
+///
+/// # Description
+/// Parkinson's estimator measures volatility from the high-low range of each bar instead of
+/// close-to-close moves, which makes it several times more efficient for a driftless price. It
+/// ignores opening gaps and, because the observed range understates the true one, is biased low
+/// for coarsely sampled data. The per-bar variance is annualized by `opt_periods_per_year`; pass
+/// 1.0 to keep it per bar.
+///
+/// # Mathematical Formula
+/// ```text
+/// u[t] = ln(High[t] / Low[t])^2
+/// Variance = Sum(u) / (4 * ln(2) * n)
+/// Parkinson = sqrt(Variance * PeriodsPerYear)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Sum the squared log ranges of the first window
+/// 2. Scale the sum to a variance and annualize it
+/// 3. Slide the window, adding the newest and removing the oldest squared log range
+/// 4. Fill the first (period-1) values with NaN
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+/// * `opt_periods_per_year` - Number of bars per year (e.g. 252.0 for daily bars)
+/// * `output_parkinson` - Array to store the annualized Parkinson volatility
+/// * `output_sum` - Array to store the running sum of squared log ranges
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty or any price is not positive
+/// * Returns `KandError::LengthMismatch` if input and output arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::parkinson;
+/// let input_high = vec![101.0, 102.5, 102.0, 103.5, 104.0];
+/// let input_low = vec![99.0, 100.5, 100.0, 101.0, 102.0];
+/// let mut output_parkinson = vec![0.0; 5];
+/// let mut output_sum = vec![0.0; 5];
+///
+/// parkinson::parkinson(
+///     &input_high,
+///     &input_low,
+///     3,
+///     252.0,
+///     &mut output_parkinson,
+///     &mut output_sum,
+/// )
+/// .unwrap();
+/// // First (period-1) values are NaN, followed by annualized volatilities
+/// assert!(output_parkinson[1].is_nan());
+/// assert!(output_parkinson[2] > 0.0);
+/// ```
+pub fn parkinson(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    output_parkinson: &mut [TAFloat],
+    output_sum: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_low.len() != len || output_parkinson.len() != len || output_sum.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Price range check
+        if input_high
+            .iter()
+            .chain(input_low)
+            .any(|price| *price <= 0.0)
+        {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for i in 0..len {
+            if input_high[i].is_nan() || input_low[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial sum
+    let mut sum = CompensatedSum::new(0.0);
+    for i in 0..opt_period {
+        sum.add(range_term(input_high[i], input_low[i]));
+    }
+    output_parkinson[lookback] = volatility(sum.value(), opt_period, opt_periods_per_year);
+    output_sum[lookback] = sum.value();
+
+    // Slide the window
+    for i in opt_period..len {
+        sum.add(range_term(input_high[i], input_low[i]));
+        sum.add(-range_term(
+            input_high[i - opt_period],
+            input_low[i - opt_period],
+        ));
+        output_parkinson[i] = volatility(sum.value(), opt_period, opt_periods_per_year);
+        output_sum[i] = sum.value();
+    }
+
+    // Fill initial values with NAN
+    for value in output_parkinson.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+    for value in output_sum.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Parkinson volatility using incremental computation.
+///
+/// # Arguments
+/// * `input_high` - High price of the newest bar
+/// * `input_low` - Low price of the newest bar
+/// * `input_old_high` - High price of the bar leaving the window
+/// * `input_old_low` - Low price of the bar leaving the window
+/// * `prev_sum` - Previous sum of squared log ranges in the window
+/// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+/// * `opt_periods_per_year` - Number of bars per year
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple containing (parkinson, `new_sum`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InvalidData` if any price is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::parkinson;
+/// // Window of three bars, each with a 2% range, slides in a bar with a 1% range
+/// let prev_sum = 3.0 * (102.0_f64 / 100.0).ln().powi(2);
+/// let (parkinson, new_sum) = parkinson::parkinson_inc(
+///     101.0,    // new high
+///     100.0,    // new low
+///     102.0,    // old high
+///     100.0,    // old low
+///     prev_sum, // previous sum
+///     3,        // period
+///     252.0,    // periods per year
+/// )
+/// .unwrap();
+/// assert!(new_sum < prev_sum);
+/// assert!(parkinson > 0.0);
+/// ```
+pub fn parkinson_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_old_high: TAFloat,
+    input_old_low: TAFloat,
+    prev_sum: TAFloat,
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 2 || opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Price range check
+        if input_high <= 0.0 || input_low <= 0.0 || input_old_high <= 0.0 || input_old_low <= 0.0 {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_high.is_nan()
+            || input_low.is_nan()
+            || input_old_high.is_nan()
+            || input_old_low.is_nan()
+            || prev_sum.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let new_sum =
+        prev_sum + range_term(input_high, input_low) - range_term(input_old_high, input_old_low);
+    let parkinson = volatility(new_sum, opt_period, opt_periods_per_year);
+
+    Ok((parkinson, new_sum))
+}
+
+/// Squared log range of a bar.
+fn range_term(high: TAFloat, low: TAFloat) -> TAFloat {
+    let range = (high / low).ln();
+    range * range
+}
+
+/// Annualized Parkinson volatility of a window from its sum of squared log ranges.
+fn volatility(sum: TAFloat, opt_period: usize, opt_periods_per_year: TAFloat) -> TAFloat {
+    // Sliding the window can leave rounding residue below zero
+    let variance = sum.max(0.0) / (4.0 * LN_2 * opt_period as TAFloat);
+    (variance * opt_periods_per_year).sqrt()
+}
+
+/// Streaming rolling Parkinson volatility that keeps the squared log ranges of the last
+/// `opt_period` bars.
+///
+/// Reads [`Bar::high`] and [`Bar::low`]. Folding [`Indicator::update`] over a series reproduces
+/// [`parkinson`].
+///
+/// # Example
+/// ```
+/// use kand::{Bar, Indicator, stats::parkinson::Parkinson};
+///
+/// let mut parkinson = Parkinson::new(3, 252.0).unwrap();
+/// for (high, low) in [(101.0, 99.0), (102.5, 100.5), (102.0, 100.0)] {
+///     let value = parkinson.update(&Bar::new(low, high, low, high, 0.0)).unwrap();
+/// }
+/// assert!(parkinson.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parkinson {
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    window: VecDeque<TAFloat>,
+    sum: CompensatedSum,
+}
+
+impl Parkinson {
+    /// Creates a streaming rolling Parkinson volatility.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+    /// * `opt_periods_per_year` - Number of bars per year (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or periods per year is not positive
+    pub fn new(opt_period: usize, opt_periods_per_year: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            opt_periods_per_year,
+            window: VecDeque::with_capacity(opt_period + 1),
+            sum: CompensatedSum::new(0.0),
+        })
+    }
+}
+
+impl Indicator for Parkinson {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.high.is_nan() || input.low.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        #[cfg(feature = "check")]
+        {
+            if input.high <= 0.0 || input.low <= 0.0 {
+                return Err(KandError::InvalidData);
+            }
+        }
+
+        let term = range_term(input.high, input.low);
+        self.window.push_back(term);
+        self.sum.add(term);
+        if self.window.len() > self.opt_period {
+            let old_term = self.window.pop_front().unwrap_or(0.0);
+            self.sum.add(-old_term);
+        }
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(volatility(
+            self.sum.value(),
+            self.opt_period,
+            self.opt_periods_per_year,
+        ))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = CompensatedSum::new(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_HIGH: [TAFloat; 25] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+        35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+    ];
+    const INPUT_LOW: [TAFloat; 25] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+        34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+    ];
+
+    #[test]
+    fn test_parkinson_calculation() {
+        let opt_period = 10;
+        let len = INPUT_HIGH.len();
+        let mut output_parkinson = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+
+        parkinson(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            opt_period,
+            252.0,
+            &mut output_parkinson,
+            &mut output_sum,
+        )
+        .unwrap();
+
+        // First 9 values should be NaN
+        for value in output_parkinson.iter().take(9) {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            0.016_433_516_607_010_767,
+            0.015_917_968_627_706_995,
+            0.016_832_919_952_569_98,
+            0.016_815_822_970_446_984,
+            0.017_223_445_939_645_896,
+            0.020_491_397_925_561_2,
+            0.021_328_334_383_181_84,
+            0.022_337_333_708_464_243,
+            0.021_810_096_638_567_39,
+            0.021_455_078_820_627_78,
+            0.021_800_949_474_107_503,
+            0.023_489_692_583_263_18,
+            0.023_443_149_710_737_34,
+            0.023_508_576_603_648_183,
+            0.023_403_572_656_142_343,
+            0.020_948_657_260_846_028,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_parkinson[i + 9], expected, epsilon = 1e-12);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut prev_sum = output_sum[9];
+        for i in 10..len {
+            let (parkinson, new_sum) = parkinson_inc(
+                INPUT_HIGH[i],
+                INPUT_LOW[i],
+                INPUT_HIGH[i - opt_period],
+                INPUT_LOW[i - opt_period],
+                prev_sum,
+                opt_period,
+                252.0,
+            )
+            .unwrap();
+            assert_relative_eq!(parkinson, output_parkinson[i], epsilon = 1e-12);
+            prev_sum = new_sum;
+        }
+
+        // Non-positive prices have no log range
+        let mut input_low = INPUT_LOW;
+        input_low[3] = 0.0;
+        assert!(
+            parkinson(
+                &INPUT_HIGH,
+                &input_low,
+                opt_period,
+                252.0,
+                &mut output_parkinson,
+                &mut output_sum,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parkinson_indicator() {
+        let opt_period = 10;
+        let len = INPUT_HIGH.len();
+        let mut output_parkinson = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        parkinson(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            opt_period,
+            252.0,
+            &mut output_parkinson,
+            &mut output_sum,
+        )
+        .unwrap();
+
+        let mut indicator = Parkinson::new(opt_period, 252.0).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                INPUT_LOW[i],
+                INPUT_HIGH[i],
+                INPUT_LOW[i],
+                INPUT_HIGH[i],
+                0.0,
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_parkinson[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert!(Parkinson::new(opt_period, 0.0).is_err());
+    }
+}
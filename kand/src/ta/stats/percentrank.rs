@@ -0,0 +1,327 @@
+use std::collections::VecDeque;
+
+use super::order_stats::OrderStatistics;
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Percent Rank calculation.
+///
+/// # Description
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For Percent Rank calculation, this equals the specified period minus one.
+///
+/// # Arguments
+/// * `opt_period` - The time period used for Percent Rank calculation (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `opt_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::percentrank;
+/// let period = 20;
+/// let lookback = percentrank::lookback(period).unwrap();
+/// assert_eq!(lookback, 19); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates a rolling Percent Rank for an entire price series.
+///
+/// # Description
+/// Percent Rank locates the latest value within its own window on a 0 to 100 scale: values near
+/// 100 are at the top of the recent range, values near 0 at the bottom. Ties share the midpoint of
+/// their ranks, so a value equal to the median of the window ranks 50.
+///
+/// # Mathematical Formula
+/// ```text
+/// PERCENTRANK = 100 * (LESS + 0.5 * EQUAL) / n
+/// ```
+/// Where:
+/// - LESS is the number of window values strictly below the latest value
+/// - EQUAL is the number of window values equal to it, the latest value included
+/// - n is the time period
+///
+/// # Calculation Steps
+/// 1. Insert the first period into an [`OrderStatistics`] tree
+/// 2. For each following value, remove the value leaving the window and insert the new one, in
+///    O(log period)
+/// 3. Count the window values below and equal to the latest value
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `opt_period` - The time period for Percent Rank calculation (must be >= 2)
+/// * `output_percentrank` - Array to store calculated Percent Rank values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output array doesn't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::percentrank;
+/// let input_prices = vec![3.0, 1.0, 4.0, 1.0, 5.0];
+/// let mut output_percentrank = vec![0.0; 5];
+///
+/// percentrank::percentrank(&input_prices, 4, &mut output_percentrank).unwrap();
+/// assert!(output_percentrank[2].is_nan());
+/// assert_eq!(output_percentrank[3], 25.0); // 1 in [3, 1, 4, 1]
+/// assert_eq!(output_percentrank[4], 87.5); // 5 in [1, 4, 1, 5]
+/// ```
+pub fn percentrank(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    output_percentrank: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_percentrank.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial value
+    let mut window = OrderStatistics::with_capacity(opt_period);
+    for val in input_prices.iter().take(opt_period) {
+        window.insert(*val);
+    }
+    output_percentrank[lookback] = window.percent_rank(input_prices[lookback]);
+
+    // Slide the window one value at a time
+    for i in opt_period..len {
+        window.remove(input_prices[i - opt_period]);
+        window.insert(input_prices[i]);
+        output_percentrank[i] = window.percent_rank(input_prices[i]);
+    }
+
+    // Fill initial values with NAN
+    for value in output_percentrank.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Percent Rank value incrementally.
+///
+/// # Description
+/// `window` holds the last `opt_period` values. This function removes `input_old_price` from it,
+/// inserts `input_price` and returns the percent rank of `input_price` in the updated window, in
+/// O(log period). Fill the window with [`OrderStatistics::insert`] during warm-up.
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `input_old_price` - Oldest price value to remove from calculation
+/// * `window` - The values of the current window, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The percent rank of `input_price` on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if `input_old_price` is not in the window; the window is
+///   left unchanged
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::{order_stats::OrderStatistics, percentrank};
+///
+/// let mut window = OrderStatistics::new();
+/// for price in [3.0, 1.0, 4.0, 1.0] {
+///     window.insert(price);
+/// }
+/// // Window [3, 1, 4, 1] slides to [1, 4, 1, 5]
+/// let rank = percentrank::percentrank_inc(5.0, 3.0, &mut window).unwrap();
+/// assert_eq!(rank, 87.5);
+/// ```
+pub fn percentrank_inc(
+    input_price: TAFloat,
+    input_old_price: TAFloat,
+    window: &mut OrderStatistics,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan() || input_old_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    if !window.remove(input_old_price) {
+        return Err(KandError::InvalidData);
+    }
+    window.insert(input_price);
+    Ok(window.percent_rank(input_price))
+}
+
+/// Streaming rolling percent rank that keeps the last `opt_period` values in arrival order and in
+/// an [`OrderStatistics`] tree.
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`percentrank`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PercentRank {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+    order: OrderStatistics,
+}
+
+impl PercentRank {
+    /// Creates a streaming rolling percent rank.
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+            order: OrderStatistics::with_capacity(opt_period),
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns its rolling percent rank.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() > self.opt_period
+            && let Some(old_price) = self.window.pop_front()
+        {
+            self.order.remove(old_price);
+        }
+        self.order.insert(input_price);
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(self.order.percent_rank(input_price))
+    }
+}
+
+impl Indicator for PercentRank {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_percentrank_calculation() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let len = input_close.len();
+        let mut output_percentrank = vec![0.0; len];
+
+        percentrank(&input_close, opt_period, &mut output_percentrank).unwrap();
+
+        for value in output_percentrank.iter().take(13) {
+            assert!(value.is_nan());
+        }
+
+        // 100 * (rank - 0.5) / 14 of the latest value within its window
+        let expected_ranks = [1.0, 1.0, 1.0, 1.0, 2.0, 4.0, 3.0, 1.0, 2.0, 4.0, 8.0, 10.0];
+        for (i, rank) in expected_ranks.iter().enumerate() {
+            let expected = 100.0 * (rank - 0.5) / 14.0;
+            assert_relative_eq!(output_percentrank[i + 13], expected, epsilon = 1e-9);
+        }
+
+        // Incremental calculation matches the batch outputs
+        let mut window = OrderStatistics::new();
+        for &price in &input_close[..opt_period] {
+            window.insert(price);
+        }
+        for i in opt_period..len {
+            let value =
+                percentrank_inc(input_close[i], input_close[i - opt_period], &mut window).unwrap();
+            assert_relative_eq!(value, output_percentrank[i], epsilon = 1e-9);
+        }
+        assert!(percentrank_inc(1.0, 123.0, &mut window).is_err());
+        assert_eq!(window.len(), opt_period);
+
+        // Streaming indicator matches the batch outputs
+        let mut indicator = PercentRank::new(opt_period).unwrap();
+        for (i, &price) in input_close.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < opt_period - 1 {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_percentrank[i], epsilon = 1e-9);
+            }
+        }
+        assert!(indicator.is_ready());
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
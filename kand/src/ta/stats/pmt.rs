@@ -0,0 +1,99 @@
+use super::fv::growth_annuity;
+use crate::{KandError, TAFloat};
+
+/// Calculates the periodic payment of a loan or annuity, as the spreadsheet `PMT` function does.
+///
+/// # Description
+/// The payment is the constant amount that, paid every period for `input_nper` periods at
+/// `input_rate`, takes the present value `input_pv` to the future value `input_fv`.
+/// Cash paid out is negative and cash received is positive, so borrowing a positive amount
+/// gives a negative payment.
+///
+/// # Mathematical Formula
+/// ```text
+/// PMT = -(FV + PV * (1 + r)^n) * r / ((1 + r * w) * ((1 + r)^n - 1))
+/// PMT = -(FV + PV) / n                                  when r = 0
+/// ```
+/// Where:
+/// - r is the rate per period and n the number of periods
+/// - w is 1 when payments are made at the beginning of each period and 0 at the end
+///
+/// # Arguments
+/// * `input_rate` - Interest rate per period
+/// * `input_nper` - Number of payment periods
+/// * `input_pv` - Present value
+/// * `input_fv` - Future value
+/// * `opt_begin` - Whether payments are due at the beginning (`true`) or end (`false`) of each period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The payment per period on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if the rate is not greater than -1 or the number of
+///   periods is zero
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::pmt;
+/// // Monthly repayment of a 10,000 loan over 10 months at 8% a year
+/// let value = pmt::pmt(0.08 / 12.0, 10.0, 10000.0, 0.0, false).unwrap();
+/// assert!((value + 1037.03).abs() < 0.01);
+/// ```
+pub fn pmt(
+    input_rate: TAFloat,
+    input_nper: TAFloat,
+    input_pv: TAFloat,
+    input_fv: TAFloat,
+    opt_begin: bool,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if input_rate <= -1.0 || input_nper == 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_rate.is_nan() || input_nper.is_nan() || input_pv.is_nan() || input_fv.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (growth, annuity) = growth_annuity(input_rate, input_nper, opt_begin);
+    Ok(-input_pv.mul_add(growth, input_fv) / annuity)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_pmt_calculation() {
+        assert_relative_eq!(
+            pmt(0.08 / 12.0, 10.0, 10000.0, 0.0, false).unwrap(),
+            -1_037.032_089_359_163_6,
+            epsilon = 1e-8
+        );
+        // Saving towards 50,000 over 18 years at 6% a year
+        assert_relative_eq!(
+            pmt(0.06 / 12.0, 216.0, 0.0, 50000.0, false).unwrap(),
+            -129.081_160_867_995_4,
+            epsilon = 1e-8
+        );
+        assert_relative_eq!(
+            pmt(0.1, 5.0, 1000.0, 100.0, true).unwrap(),
+            -254.706_571_703_836_12,
+            epsilon = 1e-8
+        );
+
+        // Zero rate splits the balance evenly
+        assert_relative_eq!(pmt(0.0, 10.0, 1000.0, 0.0, false).unwrap(), -100.0);
+
+        #[cfg(feature = "check")]
+        assert!(pmt(0.05, 0.0, 1000.0, 0.0, false).is_err());
+    }
+}
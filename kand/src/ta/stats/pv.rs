@@ -0,0 +1,91 @@
+use super::fv::growth_annuity;
+use crate::{KandError, TAFloat};
+
+/// Calculates the present value of an investment, as the spreadsheet `PV` function does.
+///
+/// # Description
+/// The present value is the lump sum that, compounded at `input_rate` for `input_nper` periods
+/// together with the payment `input_pmt` each period, settles the future value `input_fv`.
+/// Cash paid out is negative and cash received is positive.
+///
+/// # Mathematical Formula
+/// ```text
+/// PV = -(FV + PMT * (1 + r * w) * ((1 + r)^n - 1) / r) / (1 + r)^n
+/// PV = -(FV + PMT * n)                                  when r = 0
+/// ```
+/// Where:
+/// - r is the rate per period and n the number of periods
+/// - w is 1 when payments are made at the beginning of each period and 0 at the end
+///
+/// # Arguments
+/// * `input_rate` - Interest rate per period
+/// * `input_nper` - Number of payment periods
+/// * `input_pmt` - Payment made each period
+/// * `input_fv` - Future value
+/// * `opt_begin` - Whether payments are due at the beginning (`true`) or end (`false`) of each period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The present value on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if the rate is not greater than -1
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::pv;
+/// // An annuity paying 500 a month for 20 years, discounted at 8% a year
+/// let value = pv::pv(0.08 / 12.0, 240.0, 500.0, 0.0, false).unwrap();
+/// assert!((value + 59777.15).abs() < 0.01);
+/// ```
+pub fn pv(
+    input_rate: TAFloat,
+    input_nper: TAFloat,
+    input_pmt: TAFloat,
+    input_fv: TAFloat,
+    opt_begin: bool,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if input_rate <= -1.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_rate.is_nan() || input_nper.is_nan() || input_pmt.is_nan() || input_fv.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (growth, annuity) = growth_annuity(input_rate, input_nper, opt_begin);
+    Ok(-input_pmt.mul_add(annuity, input_fv) / growth)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_pv_calculation() {
+        assert_relative_eq!(
+            pv(0.08 / 12.0, 240.0, 500.0, 0.0, false).unwrap(),
+            -59_777.145_851_187_77,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            pv(0.05, 10.0, -100.0, 1000.0, true).unwrap(),
+            196.868_914_023_646_62,
+            epsilon = 1e-8
+        );
+
+        // Zero rate is a plain sum
+        assert_relative_eq!(pv(0.0, 10.0, -100.0, 500.0, false).unwrap(), 500.0);
+
+        #[cfg(feature = "check")]
+        assert!(pv(-1.5, 10.0, -100.0, 0.0, false).is_err());
+    }
+}
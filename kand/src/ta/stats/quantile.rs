@@ -0,0 +1,442 @@
+use std::collections::VecDeque;
+
+use super::order_stats::OrderStatistics;
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Quantile calculation.
+///
+/// # Description
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For Quantile calculation, this equals the specified period minus one.
+///
+/// # Arguments
+/// * `opt_period` - The time period used for Quantile calculation (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `opt_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::quantile;
+/// let period = 20;
+/// let lookback = quantile::lookback(period).unwrap();
+/// assert_eq!(lookback, 19); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates a rolling Quantile for an entire price series.
+///
+/// # Description
+/// The `q` quantile of a window is the value below which a fraction `q` of the window lies:
+/// `q = 0.5` is the median, `q = 0.25` and `q = 0.75` the quartiles. Unlike the mean, quantiles
+/// are not pulled around by single outliers.
+///
+/// # Mathematical Formula
+/// ```text
+/// h = q * (n - 1)
+/// QUANTILE = x[floor(h)] + (h - floor(h)) * (x[floor(h) + 1] - x[floor(h)])
+/// ```
+/// Where:
+/// - x[0..n] are the values of the window in ascending order
+/// - n is the time period
+///
+/// This linear interpolation is the default method of NumPy and pandas.
+///
+/// # Calculation Steps
+/// 1. Insert the first period into an [`OrderStatistics`] tree
+/// 2. For each following value, remove the value leaving the window and insert the new one, in
+///    O(log period)
+/// 3. Select the two order statistics around `h` and interpolate
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `opt_period` - The time period for Quantile calculation (must be >= 2)
+/// * `opt_quantile` - The quantile to calculate (must be between 0 and 1)
+/// * `output_quantile` - Array to store calculated Quantile values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output array doesn't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or the quantile is outside
+///   [0, 1]
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::quantile;
+/// let input_prices = vec![1.0, 5.0, 2.0, 8.0, 3.0];
+/// let mut output_quantile = vec![0.0; 5];
+///
+/// quantile::quantile(&input_prices, 4, 0.75, &mut output_quantile).unwrap();
+/// assert!(output_quantile[2].is_nan());
+/// assert_eq!(output_quantile[3], 5.75); // [1, 2, 5, 8]
+/// assert_eq!(output_quantile[4], 5.75); // [2, 3, 5, 8]
+/// ```
+pub fn quantile(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    opt_quantile: TAFloat,
+    output_quantile: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_quantile.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if !(0.0..=1.0).contains(&opt_quantile) {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial value
+    let mut window = OrderStatistics::with_capacity(opt_period);
+    for val in input_prices.iter().take(opt_period) {
+        window.insert(*val);
+    }
+    output_quantile[lookback] = window.quantile(opt_quantile);
+
+    // Slide the window one value at a time
+    for i in opt_period..len {
+        window.remove(input_prices[i - opt_period]);
+        window.insert(input_prices[i]);
+        output_quantile[i] = window.quantile(opt_quantile);
+    }
+
+    // Fill initial values with NAN
+    for value in output_quantile.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates a rolling Median for an entire price series.
+///
+/// # Description
+/// The median is the middle value of the window, or the average of the two middle values when
+/// the period is even. This is [`quantile`] with `opt_quantile = 0.5`.
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `opt_period` - The time period for Median calculation (must be >= 2)
+/// * `output_median` - Array to store calculated Median values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output array doesn't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::quantile;
+/// let input_prices = vec![1.0, 100.0, 2.0, 3.0];
+/// let mut output_median = vec![0.0; 4];
+///
+/// quantile::median(&input_prices, 3, &mut output_median).unwrap();
+/// assert_eq!(output_median[2], 2.0); // [1, 100, 2]
+/// assert_eq!(output_median[3], 3.0); // [100, 2, 3]
+/// ```
+pub fn median(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    output_median: &mut [TAFloat],
+) -> Result<(), KandError> {
+    quantile(input_prices, opt_period, 0.5, output_median)
+}
+
+/// Calculates the latest rolling Quantile value incrementally.
+///
+/// # Description
+/// `window` holds the last `opt_period` values. This function removes `input_old_price` from it,
+/// inserts `input_price` and returns the quantile of the updated window, in O(log period).
+/// Fill the window with [`OrderStatistics::insert`] during warm-up.
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `input_old_price` - Oldest price value to remove from calculation
+/// * `opt_quantile` - The quantile to calculate (must be between 0 and 1)
+/// * `window` - The values of the current window, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The quantile of the updated window on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if the quantile is outside [0, 1]
+/// * Returns `KandError::InvalidData` if `input_old_price` is not in the window; the window is
+///   left unchanged
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::{order_stats::OrderStatistics, quantile};
+///
+/// let mut window = OrderStatistics::new();
+/// for price in [1.0, 100.0, 2.0] {
+///     window.insert(price);
+/// }
+/// // Window [1, 100, 2] slides to [100, 2, 3]
+/// let median = quantile::quantile_inc(3.0, 1.0, 0.5, &mut window).unwrap();
+/// assert_eq!(median, 3.0);
+/// ```
+pub fn quantile_inc(
+    input_price: TAFloat,
+    input_old_price: TAFloat,
+    opt_quantile: TAFloat,
+    window: &mut OrderStatistics,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if !(0.0..=1.0).contains(&opt_quantile) {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan() || input_old_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    if !window.remove(input_old_price) {
+        return Err(KandError::InvalidData);
+    }
+    window.insert(input_price);
+    Ok(window.quantile(opt_quantile))
+}
+
+/// Streaming rolling quantile that keeps the last `opt_period` values in arrival order and in an
+/// [`OrderStatistics`] tree.
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`quantile`], or
+/// [`median`] when created with [`Quantile::median`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quantile {
+    opt_period: usize,
+    opt_quantile: TAFloat,
+    window: VecDeque<TAFloat>,
+    order: OrderStatistics,
+}
+
+impl Quantile {
+    /// Creates a streaming rolling quantile.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    /// * `opt_quantile` - The quantile to calculate (must be between 0 and 1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or the quantile is outside [0, 1]
+    pub fn new(opt_period: usize, opt_quantile: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        #[cfg(feature = "check")]
+        {
+            if !(0.0..=1.0).contains(&opt_quantile) {
+                return Err(KandError::InvalidParameter);
+            }
+        }
+        Ok(Self {
+            opt_period,
+            opt_quantile,
+            window: VecDeque::with_capacity(opt_period + 1),
+            order: OrderStatistics::with_capacity(opt_period),
+        })
+    }
+
+    /// Creates a streaming rolling median.
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn median(opt_period: usize) -> Result<Self, KandError> {
+        Self::new(opt_period, 0.5)
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the rolling quantile for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() > self.opt_period
+            && let Some(old_price) = self.window.pop_front()
+        {
+            self.order.remove(old_price);
+        }
+        self.order.insert(input_price);
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(self.order.quantile(self.opt_quantile))
+    }
+}
+
+impl Indicator for Quantile {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_quantile_calculation() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let len = input_close.len();
+        let mut output_median = vec![0.0; len];
+        let mut output_q90 = vec![0.0; len];
+
+        median(&input_close, opt_period, &mut output_median).unwrap();
+        quantile(&input_close, opt_period, 0.9, &mut output_q90).unwrap();
+
+        for i in 0..13 {
+            assert!(output_median[i].is_nan());
+            assert!(output_q90[i].is_nan());
+        }
+
+        // pandas Series.rolling(14).median() and .quantile(0.9)
+        let expected_median = [
+            35200.2, 35194.15, 35187.7, 35183.1, 35183.1, 35179.9, 35167.9, 35125.5, 35079.65,
+            35055.1, 35041.5, 35041.5,
+        ];
+        let expected_q90 = [
+            35245.3, 35245.3, 35245.3, 35245.3, 35245.3, 35245.3, 35224.68, 35224.68, 35208.03,
+            35204.16, 35201.28, 35196.96,
+        ];
+        for i in 0..expected_median.len() {
+            assert_relative_eq!(output_median[i + 13], expected_median[i], epsilon = 1e-6);
+            assert_relative_eq!(output_q90[i + 13], expected_q90[i], epsilon = 1e-6);
+        }
+
+        // Incremental calculation matches the batch outputs
+        let mut window = OrderStatistics::new();
+        for &price in &input_close[..opt_period] {
+            window.insert(price);
+        }
+        for i in opt_period..len {
+            let value = quantile_inc(
+                input_close[i],
+                input_close[i - opt_period],
+                0.9,
+                &mut window,
+            )
+            .unwrap();
+            assert_relative_eq!(value, output_q90[i], epsilon = 1e-9);
+        }
+        assert!(quantile_inc(1.0, 123.0, 0.9, &mut window).is_err());
+        assert_eq!(window.len(), opt_period);
+
+        #[cfg(feature = "check")]
+        {
+            assert!(quantile(&input_close, opt_period, 1.5, &mut output_q90).is_err());
+            assert!(Quantile::new(opt_period, -0.1).is_err());
+        }
+    }
+
+    #[test]
+    fn test_quantile_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+        ];
+        let opt_period = 5;
+        let mut output_median = vec![0.0; input_price.len()];
+        median(&input_price, opt_period, &mut output_median).unwrap();
+
+        let mut indicator = Quantile::median(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_eq!(value, output_median[i]);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
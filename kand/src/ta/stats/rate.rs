@@ -0,0 +1,174 @@
+use super::fv::growth_annuity;
+use crate::{KandError, TAFloat};
+
+/// Maximum number of Newton steps taken by the iterative rate solvers.
+pub(crate) const MAX_ITERATIONS: usize = 100;
+
+/// Step size below which the iterative rate solvers consider a rate converged.
+pub(crate) const TOLERANCE: TAFloat = 1e-10;
+
+/// Calculates the interest rate per period of an annuity, as the spreadsheet `RATE` function does.
+///
+/// # Description
+/// The rate is the root of the time-value-of-money equation for the given number of periods,
+/// payment, present value and future value. There is no closed form, so it is found with
+/// Newton's method starting from `opt_guess`. Cash paid out is negative and cash received is
+/// positive.
+///
+/// # Mathematical Formula
+/// ```text
+/// f(r) = PV * (1 + r)^n + PMT * (1 + r * w) * ((1 + r)^n - 1) / r + FV
+/// r[k+1] = r[k] - f(r[k]) / f'(r[k])
+/// ```
+/// Where:
+/// - n is the number of periods
+/// - w is 1 when payments are made at the beginning of each period and 0 at the end
+/// - Iteration stops once a step is smaller than 1e-10, for at most 100 steps
+///
+/// # Arguments
+/// * `input_nper` - Number of payment periods
+/// * `input_pmt` - Payment made each period
+/// * `input_pv` - Present value
+/// * `input_fv` - Future value
+/// * `opt_begin` - Whether payments are due at the beginning (`true`) or end (`false`) of each period
+/// * `opt_guess` - Starting rate for the solver, 0.1 in spreadsheets
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The rate per period on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if the number of periods is not positive or the guess
+///   is not greater than -1
+/// * Returns `KandError::CalculationError` if the solver does not converge
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::rate;
+/// // Monthly rate of an 8,000 loan repaid at 200 a month over 4 years
+/// let value = rate::rate(48.0, -200.0, 8000.0, 0.0, false, 0.1).unwrap();
+/// assert!((value - 0.0077015).abs() < 1e-7);
+/// ```
+pub fn rate(
+    input_nper: TAFloat,
+    input_pmt: TAFloat,
+    input_pv: TAFloat,
+    input_fv: TAFloat,
+    opt_begin: bool,
+    opt_guess: TAFloat,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if input_nper <= 0.0 || opt_guess <= -1.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_nper.is_nan()
+            || input_pmt.is_nan()
+            || input_pv.is_nan()
+            || input_fv.is_nan()
+            || opt_guess.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let n = input_nper;
+    let w: TAFloat = if opt_begin { 1.0 } else { 0.0 };
+    newton("RATE", opt_guess, |r| {
+        if r == 0.0 {
+            let value = input_pmt.mul_add(n, input_pv) + input_fv;
+            let slope = input_pmt.mul_add(w.mul_add(n, n * (n - 1.0) / 2.0), input_pv * n);
+            return (value, slope);
+        }
+        let (growth, annuity) = growth_annuity(r, n, opt_begin);
+        let growth_slope = n * (1.0 + r).powf(n - 1.0);
+        let timing = r.mul_add(w, 1.0);
+        let annuity_slope =
+            w * (growth - 1.0) / r + timing * growth_slope.mul_add(r, -(growth - 1.0)) / (r * r);
+        (
+            input_pmt.mul_add(annuity, input_pv.mul_add(growth, input_fv)),
+            input_pmt.mul_add(annuity_slope, input_pv * growth_slope),
+        )
+    })
+}
+
+/// Finds a rate with Newton's method.
+///
+/// `function` returns the value and the derivative of the equation at a rate. Steps that would
+/// cross -1 are halved towards it instead, so the rate stays in the domain of `(1 + r)^t`,
+/// and only a full step below the tolerance counts as convergence.
+pub(crate) fn newton<F>(name: &str, guess: TAFloat, function: F) -> Result<TAFloat, KandError>
+where
+    F: Fn(TAFloat) -> (TAFloat, TAFloat),
+{
+    let mut rate = guess;
+    for _ in 0..MAX_ITERATIONS {
+        let (value, slope) = function(rate);
+        if !value.is_finite() || !slope.is_finite() || slope == 0.0 {
+            return Err(KandError::CalculationError(format!(
+                "{name} did not converge: derivative vanished at rate {rate}"
+            )));
+        }
+
+        let step = value / slope;
+        if step.abs() <= TOLERANCE {
+            return Ok(rate - step);
+        }
+        rate = if rate - step <= -1.0 {
+            (rate - 1.0) / 2.0
+        } else {
+            rate - step
+        };
+    }
+
+    Err(KandError::CalculationError(format!(
+        "{name} did not converge after {MAX_ITERATIONS} iterations"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_rate_calculation() {
+        assert_relative_eq!(
+            rate(48.0, -200.0, 8000.0, 0.0, false, 0.1).unwrap(),
+            0.007_701_472_488_201_94,
+            epsilon = 1e-10
+        );
+        // Doubling over 10 periods without payments
+        assert_relative_eq!(
+            rate(10.0, 0.0, -1000.0, 2000.0, false, 0.1).unwrap(),
+            0.071_773_462_536_293_23,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            rate(60.0, -300.0, 15000.0, 0.0, true, 0.1).unwrap(),
+            0.006_407_985_777_783_389_5,
+            epsilon = 1e-10
+        );
+
+        // Payments that exactly repay the balance imply a zero rate
+        assert_relative_eq!(
+            rate(10.0, -100.0, 1000.0, 0.0, false, 0.0).unwrap(),
+            0.0,
+            epsilon = 1e-10
+        );
+
+        // Cash flows of a single sign have no rate
+        assert!(matches!(
+            rate(10.0, -100.0, -1000.0, 0.0, false, 0.1),
+            Err(KandError::CalculationError(_))
+        ));
+
+        #[cfg(feature = "check")]
+        assert!(rate(0.0, -100.0, 1000.0, 0.0, false, 0.1).is_err());
+    }
+}
@@ -0,0 +1,828 @@
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Returns the lookback period for rolling-window returns.
+///
+/// # Description
+/// A return over `opt_period` bars needs the price `opt_period` bars back, so the first
+/// `opt_period` values are NaN. Simple and log returns are rolling returns over one bar and have
+/// a lookback of 1, while cumulative and excess returns have none.
+///
+/// # Arguments
+/// * `opt_period` - The number of bars each return spans (must be >= 1)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `opt_period` < 1 (with "check" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let lookback = ret::lookback(5).unwrap();
+/// assert_eq!(lookback, 5);
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 1 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period)
+}
+
+/// Calculates simple returns of a price series.
+///
+/// # Description
+/// The simple return of a bar is the relative change from the previous close. The first bar
+/// has no previous close, so its return is NaN. A zero previous price also yields NaN.
+///
+/// # Mathematical Formula
+/// ```text
+/// R[t] = P[t] / P[t-1] - 1
+/// ```
+///
+/// # Arguments
+/// * `input_price` - Array of prices
+/// * `output_returns` - Array to store the simple returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the input is empty
+/// * `KandError::LengthMismatch` - If the input and output lengths differ
+/// * `KandError::InsufficientData` - If there are fewer than 2 prices
+/// * `KandError::NaNDetected` - If any price is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let input_price = vec![100.0, 102.0, 99.96];
+/// let mut output_returns = vec![0.0; 3];
+/// ret::simple_returns(&input_price, &mut output_returns).unwrap();
+/// assert!(output_returns[0].is_nan());
+/// assert!((output_returns[1] - 0.02).abs() < 1e-12);
+/// assert!((output_returns[2] + 0.02).abs() < 1e-12);
+/// ```
+pub fn simple_returns(
+    input_price: &[TAFloat],
+    output_returns: &mut [TAFloat],
+) -> Result<(), KandError> {
+    rolling_returns(input_price, 1, output_returns)
+}
+
+/// Calculates a single simple return incrementally.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `prev_price` - The previous price
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The simple return, NaN if the previous price is zero
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If either input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let value = ret::simple_returns_inc(102.0, 100.0).unwrap();
+/// assert!((value - 0.02).abs() < 1e-12);
+/// ```
+pub fn simple_returns_inc(input_price: TAFloat, prev_price: TAFloat) -> Result<TAFloat, KandError> {
+    rolling_returns_inc(input_price, prev_price)
+}
+
+/// Calculates log returns of a price series.
+///
+/// # Description
+/// The log return of a bar is the natural logarithm of the ratio to the previous close. Log
+/// returns add up over time, so the sum over a window is the log return of the whole window.
+/// The first bar is NaN, as is any bar where either price is not positive.
+///
+/// # Mathematical Formula
+/// ```text
+/// R[t] = ln(P[t] / P[t-1])
+/// ```
+///
+/// # Arguments
+/// * `input_price` - Array of prices
+/// * `output_returns` - Array to store the log returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the input is empty
+/// * `KandError::LengthMismatch` - If the input and output lengths differ
+/// * `KandError::InsufficientData` - If there are fewer than 2 prices
+/// * `KandError::NaNDetected` - If any price is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let input_price = vec![100.0, 110.0, 100.0];
+/// let mut output_returns = vec![0.0; 3];
+/// ret::log_returns(&input_price, &mut output_returns).unwrap();
+/// assert!(output_returns[0].is_nan());
+/// assert!((output_returns[1] + output_returns[2]).abs() < 1e-12);
+/// ```
+pub fn log_returns(
+    input_price: &[TAFloat],
+    output_returns: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_price.len();
+
+    #[cfg(feature = "check")]
+    {
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+        if len != output_returns.len() {
+            return Err(KandError::LengthMismatch);
+        }
+        if len < 2 {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_price.iter().any(|p| p.is_nan()) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    output_returns[0] = TAFloat::NAN;
+    for i in 1..len {
+        output_returns[i] = log_ratio(input_price[i], input_price[i - 1]);
+    }
+
+    Ok(())
+}
+
+/// Calculates a single log return incrementally.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `prev_price` - The previous price
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The log return, NaN if either price is not positive
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If either input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let value = ret::log_returns_inc(110.0, 100.0).unwrap();
+/// assert!((value - 1.1_f64.ln()).abs() < 1e-12);
+/// ```
+pub fn log_returns_inc(input_price: TAFloat, prev_price: TAFloat) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_price.is_nan() || prev_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(log_ratio(input_price, prev_price))
+}
+
+/// Calculates cumulative returns of a price series.
+///
+/// # Description
+/// The cumulative return of a bar is the relative change from the first price, i.e. the
+/// compounded simple returns since the start. The first bar is 0, as nothing has been gained
+/// yet. Every bar is NaN if the first price is zero.
+///
+/// # Mathematical Formula
+/// ```text
+/// C[t] = P[t] / P[0] - 1 = Π (1 + R[i]) - 1,  i = 1..=t
+/// ```
+///
+/// # Arguments
+/// * `input_price` - Array of prices
+/// * `output_returns` - Array to store the cumulative returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the input is empty
+/// * `KandError::LengthMismatch` - If the input and output lengths differ
+/// * `KandError::NaNDetected` - If any price is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let input_price = vec![100.0, 110.0, 121.0];
+/// let mut output_returns = vec![0.0; 3];
+/// ret::cumulative_returns(&input_price, &mut output_returns).unwrap();
+/// assert_eq!(output_returns[0], 0.0);
+/// assert!((output_returns[2] - 0.21).abs() < 1e-12);
+/// ```
+pub fn cumulative_returns(
+    input_price: &[TAFloat],
+    output_returns: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_price.len();
+
+    #[cfg(feature = "check")]
+    {
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+        if len != output_returns.len() {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_price.iter().any(|p| p.is_nan()) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let base_price = input_price[0];
+    for i in 0..len {
+        output_returns[i] = change(input_price[i], base_price);
+    }
+
+    Ok(())
+}
+
+/// Calculates a single cumulative return incrementally.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `base_price` - The first price of the series
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The cumulative return, NaN if the base price is zero
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If either input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let value = ret::cumulative_returns_inc(121.0, 100.0).unwrap();
+/// assert!((value - 0.21).abs() < 1e-12);
+/// ```
+pub fn cumulative_returns_inc(
+    input_price: TAFloat,
+    base_price: TAFloat,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_price.is_nan() || base_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(change(input_price, base_price))
+}
+
+/// Calculates returns over a rolling window of `opt_period` bars.
+///
+/// # Description
+/// Each value is the simple return from the price `opt_period` bars back. The first
+/// `opt_period` bars are NaN, as is any bar whose starting price is zero.
+///
+/// # Mathematical Formula
+/// ```text
+/// R[t] = P[t] / P[t-n] - 1
+/// ```
+///
+/// # Arguments
+/// * `input_price` - Array of prices
+/// * `opt_period` - The number of bars each return spans (must be >= 1)
+/// * `output_returns` - Array to store the rolling returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the input is empty
+/// * `KandError::LengthMismatch` - If the input and output lengths differ
+/// * `KandError::InvalidParameter` - If `opt_period` < 1
+/// * `KandError::InsufficientData` - If there are no more prices than `opt_period`
+/// * `KandError::NaNDetected` - If any price is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let input_price = vec![100.0, 101.0, 103.0, 105.0];
+/// let mut output_returns = vec![0.0; 4];
+/// ret::rolling_returns(&input_price, 2, &mut output_returns).unwrap();
+/// assert!(output_returns[1].is_nan());
+/// assert!((output_returns[2] - 0.03).abs() < 1e-12);
+/// ```
+pub fn rolling_returns(
+    input_price: &[TAFloat],
+    opt_period: usize,
+    output_returns: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_price.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+        if len != output_returns.len() {
+            return Err(KandError::LengthMismatch);
+        }
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        if input_price.iter().any(|p| p.is_nan()) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    for i in lookback..len {
+        output_returns[i] = change(input_price[i], input_price[i - opt_period]);
+    }
+
+    for value in output_returns.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates a single rolling-window return incrementally.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `old_price` - The price `opt_period` bars back
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The rolling return, NaN if the old price is zero
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If either input is NaN (with "`check-nan`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let value = ret::rolling_returns_inc(103.0, 100.0).unwrap();
+/// assert!((value - 0.03).abs() < 1e-12);
+/// ```
+pub fn rolling_returns_inc(input_price: TAFloat, old_price: TAFloat) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        if input_price.is_nan() || old_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(change(input_price, old_price))
+}
+
+/// Calculates excess returns over a risk-free series.
+///
+/// # Description
+/// Subtracts the risk-free return of each period from the asset return of the same period. Both
+/// series must use the same periodicity (e.g. daily returns against a daily risk-free rate). NaN
+/// inputs, such as the first bar of a return series, propagate to the output.
+///
+/// # Mathematical Formula
+/// ```text
+/// E[t] = R[t] - Rf[t]
+/// ```
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns
+/// * `input_risk_free` - Array of risk-free returns for the same periods
+/// * `output_excess` - Array to store the excess returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the input is empty
+/// * `KandError::LengthMismatch` - If the array lengths differ
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let input_returns = vec![f64::NAN, 0.012, -0.004];
+/// let input_risk_free = vec![0.0002, 0.0002, 0.0002];
+/// let mut output_excess = vec![0.0; 3];
+/// ret::excess_returns(&input_returns, &input_risk_free, &mut output_excess).unwrap();
+/// assert!(output_excess[0].is_nan());
+/// assert!((output_excess[1] - 0.0118).abs() < 1e-12);
+/// ```
+pub fn excess_returns(
+    input_returns: &[TAFloat],
+    input_risk_free: &[TAFloat],
+    output_excess: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+
+    #[cfg(feature = "check")]
+    {
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+        if len != input_risk_free.len() || len != output_excess.len() {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    for i in 0..len {
+        output_excess[i] = input_returns[i] - input_risk_free[i];
+    }
+
+    Ok(())
+}
+
+/// Calculates a single excess return incrementally.
+///
+/// Like [`excess_returns`], a NaN input propagates to the output instead of being rejected, so
+/// the first bar of a return series can be fed as is.
+///
+/// # Arguments
+/// * `input_return` - The asset return of the period
+/// * `input_risk_free` - The risk-free return of the same period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The excess return, NaN if either input is NaN
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let value = ret::excess_returns_inc(0.012, 0.0002).unwrap();
+/// assert!((value - 0.0118).abs() < 1e-12);
+/// assert!(ret::excess_returns_inc(f64::NAN, 0.0002).unwrap().is_nan());
+/// ```
+pub fn excess_returns_inc(
+    input_return: TAFloat,
+    input_risk_free: TAFloat,
+) -> Result<TAFloat, KandError> {
+    Ok(input_return - input_risk_free)
+}
+
+/// Relative change from `base` to `price`, NaN when `base` is zero.
+fn change(price: TAFloat, base: TAFloat) -> TAFloat {
+    if base == 0.0 {
+        TAFloat::NAN
+    } else {
+        price / base - 1.0
+    }
+}
+
+/// Log of the ratio of `price` to `base`, NaN unless both are positive.
+fn log_ratio(price: TAFloat, base: TAFloat) -> TAFloat {
+    if price > 0.0 && base > 0.0 {
+        (price / base).ln()
+    } else {
+        TAFloat::NAN
+    }
+}
+
+/// Streaming simple, log and cumulative returns of the close.
+///
+/// Each update returns `(simple, log, cumulative)`. The first bar gives NaN simple and log
+/// returns and a cumulative return of 0, matching the batch functions.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Returns {
+    base_price: TAFloat,
+    prev_price: TAFloat,
+    bars: usize,
+}
+
+impl Returns {
+    /// Creates streaming returns.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            base_price: TAFloat::NAN,
+            prev_price: TAFloat::NAN,
+            bars: 0,
+        }
+    }
+
+    /// Processes a raw price and returns `(simple, log, cumulative)`.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If the price is NaN (with "`check-nan`" feature)
+    pub fn update_value(
+        &mut self,
+        price: TAFloat,
+    ) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+        if self.bars == 0 {
+            self.base_price = price;
+        }
+        let cumulative = cumulative_returns_inc(price, self.base_price)?;
+        let (simple, log) = if self.bars == 0 {
+            (TAFloat::NAN, TAFloat::NAN)
+        } else {
+            (
+                simple_returns_inc(price, self.prev_price)?,
+                log_returns_inc(price, self.prev_price)?,
+            )
+        };
+        self.prev_price = price;
+        self.bars = self.bars.saturating_add(1);
+        Ok((simple, log, cumulative))
+    }
+}
+
+impl Default for Returns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for Returns {
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars > 1
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Streaming rolling-window returns that keep the last `opt_period + 1` closes.
+///
+/// Folding [`Indicator::update`] over a series reproduces [`rolling_returns`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingReturns {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+}
+
+impl RollingReturns {
+    /// Creates streaming rolling-window returns.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of bars each return spans (must be >= 1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` < 1
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        if opt_period < 1 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+        })
+    }
+
+    /// Processes a raw price and returns the rolling return, NaN during warm-up.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If the price is NaN (with "`check-nan`" feature)
+    pub fn update_value(&mut self, price: TAFloat) -> Result<TAFloat, KandError> {
+        self.window.push_back(price);
+        if self.window.len() > self.opt_period + 1 {
+            self.window.pop_front();
+        }
+        match self.window.front() {
+            Some(&old_price) if self.is_ready() => rolling_returns_inc(price, old_price),
+            _ => Ok(TAFloat::NAN),
+        }
+    }
+}
+
+impl Indicator for RollingReturns {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const PRICES: [TAFloat; 15] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3,
+    ];
+
+    #[test]
+    fn test_simple_and_log_returns() {
+        let mut output_simple = vec![0.0; PRICES.len()];
+        let mut output_log = vec![0.0; PRICES.len()];
+        simple_returns(&PRICES, &mut output_simple).unwrap();
+        log_returns(&PRICES, &mut output_log).unwrap();
+
+        assert!(output_simple[0].is_nan());
+        assert!(output_log[0].is_nan());
+
+        let expected_simple = [
+            0.000_150_499_345_469_956_58,
+            -0.000_871_629_179_987_287_7,
+            -0.000_588_223_593_165_127,
+            0.000_326_983_224_338_883_7,
+            0.002_077_796_569_219_581,
+        ];
+        let expected_log = [
+            0.000_150_488_021_579_607_62,
+            -0.000_872_009_269_581_828_3,
+            -0.000_588_396_664_536_008_7,
+            0.000_326_929_776_974_994_4,
+            0.002_075_640_935_390_651,
+        ];
+        for i in 0..5 {
+            assert_relative_eq!(output_simple[i + 1], expected_simple[i], epsilon = 1e-12);
+            assert_relative_eq!(output_log[i + 1], expected_log[i], epsilon = 1e-12);
+        }
+
+        for i in 1..PRICES.len() {
+            let simple = simple_returns_inc(PRICES[i], PRICES[i - 1]).unwrap();
+            let log = log_returns_inc(PRICES[i], PRICES[i - 1]).unwrap();
+            assert_relative_eq!(simple, output_simple[i], epsilon = 1e-12);
+            assert_relative_eq!(log, output_log[i], epsilon = 1e-12);
+        }
+
+        // Non-positive prices have no return
+        assert!(simple_returns_inc(1.0, 0.0).unwrap().is_nan());
+        assert!(log_returns_inc(0.0, 1.0).unwrap().is_nan());
+        assert!(log_returns_inc(-1.0, 1.0).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_cumulative_and_rolling_returns() {
+        let mut output_cumulative = vec![0.0; PRICES.len()];
+        let mut output_rolling = vec![0.0; PRICES.len()];
+        cumulative_returns(&PRICES, &mut output_cumulative).unwrap();
+        rolling_returns(&PRICES, 5, &mut output_rolling).unwrap();
+
+        assert_eq!(output_cumulative[0], 0.0);
+        for value in output_rolling.iter().take(5) {
+            assert!(value.is_nan());
+        }
+
+        let expected_cumulative = [
+            -0.001_164_240_219_672_208,
+            0.000_391_866_220_279_979_4,
+            -0.000_102_225_970_507_796_58,
+            -0.001_573_144_101_703_505_3,
+            -0.003_572_229_747_189_403_8,
+        ];
+        let expected_rolling = [
+            -0.002_255_024_876_186_34,
+            0.000_769_825_127_546_530_7,
+            -0.001_117_670_253_234_615,
+            -0.001_048_366_934_109_146_2,
+            -0.002_682_984_365_363_183_5,
+        ];
+        for i in 0..5 {
+            assert_relative_eq!(
+                output_cumulative[i + 10],
+                expected_cumulative[i],
+                epsilon = 1e-12
+            );
+            assert_relative_eq!(output_rolling[i + 10], expected_rolling[i], epsilon = 1e-12);
+        }
+
+        for i in 0..PRICES.len() {
+            let cumulative = cumulative_returns_inc(PRICES[i], PRICES[0]).unwrap();
+            assert_relative_eq!(cumulative, output_cumulative[i], epsilon = 1e-12);
+        }
+        for i in 5..PRICES.len() {
+            let rolling = rolling_returns_inc(PRICES[i], PRICES[i - 5]).unwrap();
+            assert_relative_eq!(rolling, output_rolling[i], epsilon = 1e-12);
+        }
+
+        // Compounding the simple returns reproduces the cumulative return
+        let mut output_simple = vec![0.0; PRICES.len()];
+        simple_returns(&PRICES, &mut output_simple).unwrap();
+        let compounded = output_simple[1..]
+            .iter()
+            .fold(1.0, |acc, r| acc * (1.0 + r))
+            - 1.0;
+        assert_relative_eq!(compounded, output_cumulative[14], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_excess_returns() {
+        let input_returns = [TAFloat::NAN, 0.012, -0.004, 0.0, 0.003];
+        let input_risk_free = [0.0002, 0.0002, 0.0003, 0.0001, TAFloat::NAN];
+        let mut output_excess = vec![0.0; 5];
+        excess_returns(&input_returns, &input_risk_free, &mut output_excess).unwrap();
+
+        assert!(output_excess[0].is_nan());
+        assert_relative_eq!(output_excess[1], 0.0118, epsilon = 1e-12);
+        assert_relative_eq!(output_excess[2], -0.0043, epsilon = 1e-12);
+        assert_relative_eq!(output_excess[3], -0.0001, epsilon = 1e-12);
+        assert!(output_excess[4].is_nan());
+
+        // Both forms propagate NaN the same way, with or without "check-nan"
+        for i in 0..5 {
+            let value = excess_returns_inc(input_returns[i], input_risk_free[i]).unwrap();
+            if output_excess[i].is_nan() {
+                assert!(value.is_nan());
+            } else {
+                assert_relative_eq!(value, output_excess[i], epsilon = 1e-12);
+            }
+        }
+
+        #[cfg(feature = "check")]
+        assert!(excess_returns(&input_returns, &input_risk_free[..3], &mut output_excess).is_err());
+    }
+
+    #[test]
+    fn test_returns_indicator() {
+        let mut output_simple = vec![0.0; PRICES.len()];
+        let mut output_log = vec![0.0; PRICES.len()];
+        let mut output_cumulative = vec![0.0; PRICES.len()];
+        simple_returns(&PRICES, &mut output_simple).unwrap();
+        log_returns(&PRICES, &mut output_log).unwrap();
+        cumulative_returns(&PRICES, &mut output_cumulative).unwrap();
+
+        let mut indicator = Returns::new();
+        assert_eq!(indicator.lookback(), lookback(1).unwrap());
+        for (i, &price) in PRICES.iter().enumerate() {
+            let (simple, log, cumulative) = indicator.update(&Bar::from(price)).unwrap();
+            assert_relative_eq!(cumulative, output_cumulative[i], epsilon = 1e-12);
+            if i == 0 {
+                assert!(simple.is_nan());
+                assert!(log.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(simple, output_simple[i], epsilon = 1e-12);
+                assert_relative_eq!(log, output_log[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        let (_, _, cumulative) = indicator.update(&Bar::from(PRICES[5])).unwrap();
+        assert_eq!(cumulative, 0.0);
+    }
+
+    #[test]
+    fn test_rolling_returns_indicator() {
+        let opt_period = 5;
+        let mut output_rolling = vec![0.0; PRICES.len()];
+        rolling_returns(&PRICES, opt_period, &mut output_rolling).unwrap();
+
+        let mut indicator = RollingReturns::new(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback(opt_period).unwrap());
+        for (i, &price) in PRICES.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < opt_period {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_rolling[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert!(RollingReturns::new(0).is_err());
+    }
+}
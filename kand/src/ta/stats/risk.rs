@@ -0,0 +1,1387 @@
+use std::collections::VecDeque;
+
+use super::{
+    kurt,
+    moments::{self, RollingHigherMoments},
+    order_stats::OrderStatistics,
+    quantile, var,
+};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Value-at-Risk and Expected Shortfall.
+///
+/// # Description
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For all risk measures of this module, this equals the period minus one.
+/// The Cornish-Fisher measures additionally need a period of at least 4.
+///
+/// # Arguments
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `opt_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::risk;
+/// let period = 250;
+/// let lookback = risk::lookback(period).unwrap();
+/// assert_eq!(lookback, 249); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates the rolling historical Value-at-Risk of a return series.
+///
+/// # Description
+/// Historical VaR is the loss that the returns of the window exceeded only `1 - confidence` of
+/// the time, read directly from their empirical distribution without assuming any shape. Like all
+/// risk measures of this module it is reported as a positive loss: a VaR of 0.02 means a 2% loss.
+///
+/// # Mathematical Formula
+/// ```text
+/// VaR = -QUANTILE(returns, 1 - confidence)
+/// ```
+/// The quantile interpolates linearly between order statistics, as in [`quantile::quantile`].
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns (e.g. 0.01 for +1%)
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_confidence` - The confidence level (e.g. 0.95 or 0.99, strictly between 0 and 1)
+/// * `output_var` - Array to store the Value-at-Risk
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output array doesn't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or the confidence is not
+///   strictly between 0 and 1
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::risk;
+/// let input_returns = vec![0.01, -0.02, 0.005, -0.04, 0.03];
+/// let mut output_var = vec![0.0; 5];
+///
+/// risk::historical_var(&input_returns, 5, 0.75, &mut output_var).unwrap();
+/// assert!(output_var[3].is_nan());
+/// assert!((output_var[4] - 0.02).abs() < 1e-12); // 25% quantile of the window is -2%
+/// ```
+pub fn historical_var(
+    input_returns: &[TAFloat],
+    opt_period: usize,
+    opt_confidence: TAFloat,
+    output_var: &mut [TAFloat],
+) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+    }
+    quantile::quantile(input_returns, opt_period, 1.0 - opt_confidence, output_var)?;
+
+    for value in output_var.iter_mut() {
+        *value = -*value;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling historical Value-at-Risk incrementally.
+///
+/// # Description
+/// `window` holds the last `opt_period` returns. This function removes `input_old_return` from it,
+/// inserts `input_return` and returns the VaR of the updated window, in O(log period). Fill the
+/// window with [`OrderStatistics::insert`] during warm-up.
+///
+/// # Arguments
+/// * `input_return` - The newest return to include in calculation
+/// * `input_old_return` - Oldest return to remove from calculation
+/// * `opt_confidence` - The confidence level (strictly between 0 and 1)
+/// * `window` - The returns of the current window, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The Value-at-Risk of the updated window on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if the confidence is not strictly between 0 and 1
+/// * Returns `KandError::InvalidData` if `input_old_return` is not in the window
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::{order_stats::OrderStatistics, risk};
+///
+/// let mut window = OrderStatistics::new();
+/// for ret in [0.01, -0.02, 0.005, -0.04, 0.03] {
+///     window.insert(ret);
+/// }
+/// // Window slides to [-0.02, 0.005, -0.04, 0.03, 0.02]
+/// let var = risk::historical_var_inc(0.02, 0.01, 0.75, &mut window).unwrap();
+/// assert!((var - 0.02).abs() < 1e-12);
+/// ```
+pub fn historical_var_inc(
+    input_return: TAFloat,
+    input_old_return: TAFloat,
+    opt_confidence: TAFloat,
+    window: &mut OrderStatistics,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+    }
+    let value =
+        quantile::quantile_inc(input_return, input_old_return, 1.0 - opt_confidence, window)?;
+    Ok(-value)
+}
+
+/// Calculates the rolling historical Expected Shortfall (CVaR) of a return series.
+///
+/// # Description
+/// Expected Shortfall is the average loss in the worst `1 - confidence` share of the window, so
+/// unlike VaR it also reflects how bad the tail is. When the tail share does not cover a whole
+/// number of returns, the boundary return is counted with its fractional weight, which keeps the
+/// measure continuous in the confidence level.
+///
+/// # Mathematical Formula
+/// ```text
+/// alpha = 1 - confidence
+/// m = alpha * n,  j = floor(m)
+/// ES = -(x[0] + ... + x[j-1] + (m - j) * x[j]) / m
+/// ```
+/// Where x[0..n] are the returns of the window in ascending order.
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns (e.g. 0.01 for +1%)
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_confidence` - The confidence level (e.g. 0.95 or 0.99, strictly between 0 and 1)
+/// * `output_es` - Array to store the Expected Shortfall
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output array doesn't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or the confidence is not
+///   strictly between 0 and 1
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::risk;
+/// let input_returns = vec![0.01, -0.02, 0.005, -0.04, 0.03];
+/// let mut output_es = vec![0.0; 5];
+///
+/// risk::historical_es(&input_returns, 5, 0.6, &mut output_es).unwrap();
+/// assert!(output_es[3].is_nan());
+/// assert!((output_es[4] - 0.03).abs() < 1e-12); // mean of the two worst returns
+/// ```
+pub fn historical_es(
+    input_returns: &[TAFloat],
+    opt_period: usize,
+    opt_confidence: TAFloat,
+    output_es: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_es.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial value
+    let mut window = OrderStatistics::with_capacity(opt_period);
+    for value in input_returns.iter().take(opt_period) {
+        window.insert(*value);
+    }
+    output_es[lookback] = tail_loss(&window, 1.0 - opt_confidence);
+
+    // Slide the window one return at a time
+    for i in opt_period..len {
+        window.remove(input_returns[i - opt_period]);
+        window.insert(input_returns[i]);
+        output_es[i] = tail_loss(&window, 1.0 - opt_confidence);
+    }
+
+    // Fill initial values with NAN
+    for value in output_es.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling historical Expected Shortfall incrementally.
+///
+/// # Description
+/// `window` holds the last `opt_period` returns. This function removes `input_old_return` from it,
+/// inserts `input_return` and returns the Expected Shortfall of the updated window, in
+/// O(log period). Fill the window with [`OrderStatistics::insert`] during warm-up.
+///
+/// # Arguments
+/// * `input_return` - The newest return to include in calculation
+/// * `input_old_return` - Oldest return to remove from calculation
+/// * `opt_confidence` - The confidence level (strictly between 0 and 1)
+/// * `window` - The returns of the current window, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The Expected Shortfall of the updated window on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if the confidence is not strictly between 0 and 1
+/// * Returns `KandError::InvalidData` if `input_old_return` is not in the window; the window is
+///   left unchanged
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::{order_stats::OrderStatistics, risk};
+///
+/// let mut window = OrderStatistics::new();
+/// for ret in [0.01, -0.02, 0.005, -0.04, 0.03] {
+///     window.insert(ret);
+/// }
+/// // Window slides to [-0.02, 0.005, -0.04, 0.03, -0.06]
+/// let es = risk::historical_es_inc(-0.06, 0.01, 0.6, &mut window).unwrap();
+/// assert!((es - 0.05).abs() < 1e-12);
+/// ```
+pub fn historical_es_inc(
+    input_return: TAFloat,
+    input_old_return: TAFloat,
+    opt_confidence: TAFloat,
+    window: &mut OrderStatistics,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_return.is_nan() || input_old_return.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    if !window.remove(input_old_return) {
+        return Err(KandError::InvalidData);
+    }
+    window.insert(input_return);
+    Ok(tail_loss(window, 1.0 - opt_confidence))
+}
+
+/// Calculates the rolling Gaussian (parametric) Value-at-Risk of a return series.
+///
+/// # Description
+/// Gaussian VaR assumes the returns of the window are normally distributed with their rolling
+/// mean and standard deviation, both taken from [`var::var_welford`].
+///
+/// # Mathematical Formula
+/// ```text
+/// z = NORMINV(1 - confidence)
+/// VaR = -(Mean + z * StdDev)
+/// ```
+/// Where StdDev is the population standard deviation of the window.
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns (e.g. 0.01 for +1%)
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_confidence` - The confidence level (e.g. 0.95 or 0.99, strictly between 0 and 1)
+/// * `output_var` - Array to store the Value-at-Risk
+/// * `output_mean` - Array to store the rolling mean of the returns
+/// * `output_m2` - Array to store the rolling sum of squared deviations from the mean
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or the confidence is not
+///   strictly between 0 and 1
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::risk;
+/// let input_returns = vec![0.01, -0.01, 0.01, -0.01];
+/// let mut output_var = vec![0.0; 4];
+/// let mut output_mean = vec![0.0; 4];
+/// let mut output_m2 = vec![0.0; 4];
+///
+/// risk::gaussian_var(
+///     &input_returns,
+///     4,
+///     0.95,
+///     &mut output_var,
+///     &mut output_mean,
+///     &mut output_m2,
+/// )
+/// .unwrap();
+/// // Mean 0 and standard deviation 1%, so VaR is 1.645 standard deviations
+/// assert!((output_var[3] - 0.016_448_536_269_514_72).abs() < 1e-12);
+/// ```
+pub fn gaussian_var(
+    input_returns: &[TAFloat],
+    opt_period: usize,
+    opt_confidence: TAFloat,
+    output_var: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+    }
+    var::var_welford(
+        input_returns,
+        opt_period,
+        output_var,
+        output_mean,
+        output_m2,
+    )?;
+
+    let z = inverse_normal_cdf(1.0 - opt_confidence);
+    for (value, mean) in output_var.iter_mut().zip(output_mean.iter()) {
+        *value = -z.mul_add(value.sqrt(), *mean);
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Gaussian Value-at-Risk incrementally.
+///
+/// # Description
+/// Slides the rolling mean and M2 with [`var::var_inc_welford`] and converts them to Gaussian VaR.
+///
+/// # Arguments
+/// * `input_return` - The newest return to include in calculation
+/// * `prev_mean` - Previous rolling mean
+/// * `prev_m2` - Previous rolling sum of squared deviations from the mean
+/// * `input_old_return` - Oldest return to remove from calculation
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_confidence` - The confidence level (strictly between 0 and 1)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (VaR, `new_mean`,
+///   `new_m2`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or the confidence is not
+///   strictly between 0 and 1
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::risk;
+/// // Window [0.03, -0.01, 0.01, -0.01] slides to [-0.01, 0.01, -0.01, 0.01]
+/// let (var, mean, m2) = risk::gaussian_var_inc(0.01, 0.005, 0.0011, 0.03, 4, 0.95).unwrap();
+/// assert!(mean.abs() < 1e-12);
+/// assert!((m2 - 0.0004).abs() < 1e-12);
+/// assert!((var - 0.016_448_536_269_514_72).abs() < 1e-12);
+/// ```
+pub fn gaussian_var_inc(
+    input_return: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    input_old_return: TAFloat,
+    opt_period: usize,
+    opt_confidence: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+    }
+    let (variance, new_mean, new_m2) = var::var_inc_welford(
+        input_return,
+        prev_mean,
+        prev_m2,
+        input_old_return,
+        opt_period,
+    )?;
+
+    let z = inverse_normal_cdf(1.0 - opt_confidence);
+    Ok((-z.mul_add(variance.sqrt(), new_mean), new_mean, new_m2))
+}
+
+/// Calculates the rolling Gaussian (parametric) Expected Shortfall of a return series.
+///
+/// # Description
+/// Gaussian Expected Shortfall is the mean loss beyond the Gaussian VaR when the returns of the
+/// window are normally distributed with their rolling mean and standard deviation.
+///
+/// # Mathematical Formula
+/// ```text
+/// alpha = 1 - confidence
+/// z = NORMINV(alpha)
+/// ES = -Mean + StdDev * NORMPDF(z) / alpha
+/// ```
+/// Where StdDev is the population standard deviation of the window.
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns (e.g. 0.01 for +1%)
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_confidence` - The confidence level (e.g. 0.95 or 0.99, strictly between 0 and 1)
+/// * `output_es` - Array to store the Expected Shortfall
+/// * `output_mean` - Array to store the rolling mean of the returns
+/// * `output_m2` - Array to store the rolling sum of squared deviations from the mean
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or the confidence is not
+///   strictly between 0 and 1
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::risk;
+/// let input_returns = vec![0.01, -0.01, 0.01, -0.01];
+/// let mut output_es = vec![0.0; 4];
+/// let mut output_mean = vec![0.0; 4];
+/// let mut output_m2 = vec![0.0; 4];
+///
+/// risk::gaussian_es(
+///     &input_returns,
+///     4,
+///     0.95,
+///     &mut output_es,
+///     &mut output_mean,
+///     &mut output_m2,
+/// )
+/// .unwrap();
+/// assert!((output_es[3] - 0.020_627_128_075_074_27).abs() < 1e-12);
+/// ```
+pub fn gaussian_es(
+    input_returns: &[TAFloat],
+    opt_period: usize,
+    opt_confidence: TAFloat,
+    output_es: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+    }
+    var::var_welford(input_returns, opt_period, output_es, output_mean, output_m2)?;
+
+    let tail = gaussian_tail_factor(1.0 - opt_confidence);
+    for (value, mean) in output_es.iter_mut().zip(output_mean.iter()) {
+        *value = tail.mul_add(value.sqrt(), -*mean);
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Gaussian Expected Shortfall incrementally.
+///
+/// # Description
+/// Slides the rolling mean and M2 with [`var::var_inc_welford`] and converts them to Gaussian Expected
+/// Shortfall.
+///
+/// # Arguments
+/// * `input_return` - The newest return to include in calculation
+/// * `prev_mean` - Previous rolling mean
+/// * `prev_m2` - Previous rolling sum of squared deviations from the mean
+/// * `input_old_return` - Oldest return to remove from calculation
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_confidence` - The confidence level (strictly between 0 and 1)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (ES, `new_mean`,
+///   `new_m2`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or the confidence is not
+///   strictly between 0 and 1
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::risk;
+/// // Window [0.03, -0.01, 0.01, -0.01] slides to [-0.01, 0.01, -0.01, 0.01]
+/// let (es, _, _) = risk::gaussian_es_inc(0.01, 0.005, 0.0011, 0.03, 4, 0.95).unwrap();
+/// assert!((es - 0.020_627_128_075_074_27).abs() < 1e-12);
+/// ```
+pub fn gaussian_es_inc(
+    input_return: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    input_old_return: TAFloat,
+    opt_period: usize,
+    opt_confidence: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+    }
+    let (variance, new_mean, new_m2) = var::var_inc_welford(
+        input_return,
+        prev_mean,
+        prev_m2,
+        input_old_return,
+        opt_period,
+    )?;
+
+    let tail = gaussian_tail_factor(1.0 - opt_confidence);
+    Ok((tail.mul_add(variance.sqrt(), -new_mean), new_mean, new_m2))
+}
+
+/// Calculates the rolling Cornish-Fisher (modified) Value-at-Risk of a return series.
+///
+/// # Description
+/// Cornish-Fisher VaR corrects the Gaussian quantile for the skewness and excess kurtosis of the
+/// window, so fat left tails raise the VaR above its Gaussian value. The moments come from
+/// [`kurt::kurt`].
+///
+/// # Mathematical Formula
+/// ```text
+/// z = NORMINV(1 - confidence)
+/// Zcf = z + (z^2 - 1) * S / 6 + (z^3 - 3z) * K / 24 - (2z^3 - 5z) * S^2 / 36
+/// VaR = -(Mean + Zcf * StdDev)
+/// ```
+/// Where:
+/// - S is the skewness and K the excess kurtosis of the window
+/// - StdDev is the population standard deviation of the window
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns (e.g. 0.01 for +1%)
+/// * `opt_period` - The number of returns in the rolling window (must be >= 4)
+/// * `opt_confidence` - The confidence level (e.g. 0.95 or 0.99, strictly between 0 and 1)
+/// * `output_var` - Array to store the Value-at-Risk
+/// * `output_mean` - Array to store the rolling mean of the returns
+/// * `output_m2` - Array to store the rolling sum of squared deviations from the mean
+/// * `output_m3` - Array to store the rolling sum of cubed deviations from the mean
+/// * `output_m4` - Array to store the rolling sum of fourth-power deviations from the mean
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 4 or the confidence is not
+///   strictly between 0 and 1
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::risk;
+/// let input_returns = vec![0.01, -0.01, 0.01, -0.01];
+/// let mut output_var = vec![0.0; 4];
+/// let mut output_mean = vec![0.0; 4];
+/// let mut output_m2 = vec![0.0; 4];
+/// let mut output_m3 = vec![0.0; 4];
+/// let mut output_m4 = vec![0.0; 4];
+///
+/// risk::cornish_fisher_var(
+///     &input_returns,
+///     4,
+///     0.95,
+///     &mut output_var,
+///     &mut output_mean,
+///     &mut output_m2,
+///     &mut output_m3,
+///     &mut output_m4,
+/// )
+/// .unwrap();
+/// // Symmetric window with an excess kurtosis of -2
+/// assert!((output_var[3] - 0.016_852_151_200_773_69).abs() < 1e-12);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn cornish_fisher_var(
+    input_returns: &[TAFloat],
+    opt_period: usize,
+    opt_confidence: TAFloat,
+    output_var: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+    output_m3: &mut [TAFloat],
+    output_m4: &mut [TAFloat],
+) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+    }
+    kurt::kurt(
+        input_returns,
+        opt_period,
+        output_var,
+        output_mean,
+        output_m2,
+        output_m3,
+        output_m4,
+    )?;
+
+    let z = inverse_normal_cdf(1.0 - opt_confidence);
+    for i in 0..output_var.len() {
+        output_var[i] = cornish_fisher_loss(
+            z,
+            output_mean[i],
+            output_m2[i],
+            output_m3[i],
+            output_m4[i],
+            opt_period,
+        );
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Cornish-Fisher Value-at-Risk incrementally.
+///
+/// # Description
+/// Slides the first four central moments with [`kurt::kurt_inc`] and converts them to
+/// Cornish-Fisher VaR.
+///
+/// # Arguments
+/// * `input_return` - The newest return to include in calculation
+/// * `prev_mean` - Previous rolling mean
+/// * `prev_m2` - Previous rolling sum of squared deviations from the mean
+/// * `prev_m3` - Previous rolling sum of cubed deviations from the mean
+/// * `prev_m4` - Previous rolling sum of fourth-power deviations from the mean
+/// * `input_old_return` - Oldest return to remove from calculation
+/// * `opt_period` - The number of returns in the rolling window (must be >= 4)
+/// * `opt_confidence` - The confidence level (strictly between 0 and 1)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing
+///   (VaR, `new_mean`, `new_m2`, `new_m3`, `new_m4`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 4 or the confidence is not
+///   strictly between 0 and 1
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::risk;
+/// // Window [0.03, -0.01, 0.01, -0.01] slides to [-0.01, 0.01, -0.01, 0.01]
+/// let (var, mean, _, m3, _) =
+///     risk::cornish_fisher_var_inc(0.01, 0.005, 0.0011, 0.000_009, 0.000_000_492_5, 0.03, 4, 0.95)
+///         .unwrap();
+/// assert!(mean.abs() < 1e-12);
+/// assert!(m3.abs() < 1e-15);
+/// assert!((var - 0.016_852_151_200_773_69).abs() < 1e-12);
+/// ```
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn cornish_fisher_var_inc(
+    input_return: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    prev_m3: TAFloat,
+    prev_m4: TAFloat,
+    input_old_return: TAFloat,
+    opt_period: usize,
+    opt_confidence: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        check_confidence(opt_confidence)?;
+    }
+    let (_, new_mean, new_m2, new_m3, new_m4) = kurt::kurt_inc(
+        input_return,
+        prev_mean,
+        prev_m2,
+        prev_m3,
+        prev_m4,
+        input_old_return,
+        opt_period,
+    )?;
+
+    let z = inverse_normal_cdf(1.0 - opt_confidence);
+    let value = cornish_fisher_loss(z, new_mean, new_m2, new_m3, new_m4, opt_period);
+    Ok((value, new_mean, new_m2, new_m3, new_m4))
+}
+
+/// Streaming historical VaR and Expected Shortfall that keeps the last `opt_period` returns in
+/// arrival order and in an [`OrderStatistics`] tree.
+///
+/// Reads [`Bar::close`] as the periodic return and outputs `(var, es)`. Folding
+/// [`Indicator::update`] over a series reproduces [`historical_var`] and [`historical_es`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoricalRisk {
+    opt_period: usize,
+    opt_confidence: TAFloat,
+    window: VecDeque<TAFloat>,
+    order: OrderStatistics,
+}
+
+impl HistoricalRisk {
+    /// Creates a streaming historical VaR and Expected Shortfall.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+    /// * `opt_confidence` - The confidence level (strictly between 0 and 1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or the confidence is not strictly between
+    ///   0 and 1
+    pub fn new(opt_period: usize, opt_confidence: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        #[cfg(feature = "check")]
+        {
+            // Parameter range check
+            check_confidence(opt_confidence)?;
+        }
+        Ok(Self {
+            opt_period,
+            opt_confidence,
+            window: VecDeque::with_capacity(opt_period + 1),
+            order: OrderStatistics::with_capacity(opt_period),
+        })
+    }
+
+    /// Feeds a raw return instead of a [`Bar`] and returns `(var, es)` for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_return` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_return: TAFloat) -> Result<(TAFloat, TAFloat), KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_return.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_return);
+        if self.window.len() > self.opt_period
+            && let Some(old_return) = self.window.pop_front()
+        {
+            self.order.remove(old_return);
+        }
+        self.order.insert(input_return);
+        if self.window.len() < self.opt_period {
+            return Ok((TAFloat::NAN, TAFloat::NAN));
+        }
+        let alpha = 1.0 - self.opt_confidence;
+        Ok((-self.order.quantile(alpha), tail_loss(&self.order, alpha)))
+    }
+}
+
+impl Indicator for HistoricalRisk {
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.order.clear();
+    }
+}
+
+/// Streaming parametric risk that keeps the last `opt_period` returns and their
+/// [`RollingHigherMoments`].
+///
+/// Reads [`Bar::close`] as the periodic return and outputs `(gaussian_var, gaussian_es,
+/// cornish_fisher_var)`. Folding [`Indicator::update`] over a series reproduces [`gaussian_var`],
+/// [`gaussian_es`] and [`cornish_fisher_var`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParametricRisk {
+    opt_period: usize,
+    opt_confidence: TAFloat,
+    window: VecDeque<TAFloat>,
+    moments: RollingHigherMoments,
+}
+
+impl ParametricRisk {
+    /// Creates a streaming parametric VaR and Expected Shortfall.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of returns in the rolling window (must be >= 4)
+    /// * `opt_confidence` - The confidence level (strictly between 0 and 1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 4 or the confidence is not strictly between
+    ///   0 and 1
+    pub fn new(opt_period: usize, opt_confidence: TAFloat) -> Result<Self, KandError> {
+        kurt::lookback(opt_period)?;
+        #[cfg(feature = "check")]
+        {
+            // Parameter range check
+            check_confidence(opt_confidence)?;
+        }
+        Ok(Self {
+            opt_period,
+            opt_confidence,
+            window: VecDeque::with_capacity(opt_period + 1),
+            moments: RollingHigherMoments::new(),
+        })
+    }
+
+    /// Feeds a raw return instead of a [`Bar`] and returns `(gaussian_var, gaussian_es,
+    /// cornish_fisher_var)` for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_return` is NaN (with "`check-nan`")
+    pub fn update_value(
+        &mut self,
+        input_return: TAFloat,
+    ) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_return.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_return);
+        if self.window.len() > self.opt_period {
+            let old_return = self.window.pop_front().unwrap_or(0.0);
+            self.moments.replace(input_return, old_return);
+        } else {
+            self.moments.push(input_return);
+        }
+        if self.window.len() < self.opt_period {
+            return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+        }
+
+        let alpha = 1.0 - self.opt_confidence;
+        let z = inverse_normal_cdf(alpha);
+        let mean = self.moments.mean();
+        let std_dev = (self.moments.m2() / self.opt_period as TAFloat).sqrt();
+        Ok((
+            -z.mul_add(std_dev, mean),
+            gaussian_tail_factor(alpha).mul_add(std_dev, -mean),
+            cornish_fisher_loss(
+                z,
+                mean,
+                self.moments.m2(),
+                self.moments.m3(),
+                self.moments.m4(),
+                self.opt_period,
+            ),
+        ))
+    }
+}
+
+impl Indicator for ParametricRisk {
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input: &Bar) -> Result<Self::Output, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.moments = RollingHigherMoments::new();
+    }
+}
+
+/// Checks that a confidence level lies strictly between 0 and 1.
+#[cfg(feature = "check")]
+const fn check_confidence(opt_confidence: TAFloat) -> Result<(), KandError> {
+    if opt_confidence > 0.0 && opt_confidence < 1.0 {
+        Ok(())
+    } else {
+        Err(KandError::InvalidParameter)
+    }
+}
+
+/// Returns the historical Expected Shortfall of the values held in `window` at tail share `alpha`.
+fn tail_loss(window: &OrderStatistics, alpha: TAFloat) -> TAFloat {
+    let tail = alpha * window.len() as TAFloat;
+    let whole = tail.floor();
+    let boundary = if tail > whole {
+        window.select(whole as usize).unwrap_or(0.0)
+    } else {
+        0.0
+    };
+    let sum = (tail - whole).mul_add(boundary, window.sum_smallest(whole as usize));
+    -sum / tail
+}
+
+/// Returns `NORMPDF(z) / alpha` for `z = NORMINV(alpha)`, the Gaussian ES in standard deviations.
+fn gaussian_tail_factor(alpha: TAFloat) -> TAFloat {
+    let z = inverse_normal_cdf(alpha);
+    (-0.5 * z * z).exp() / (2.0 * std::f64::consts::PI as TAFloat).sqrt() / alpha
+}
+
+/// Returns the Cornish-Fisher VaR for the standard normal quantile `z` and the central moments of
+/// a window.
+fn cornish_fisher_loss(
+    z: TAFloat,
+    mean: TAFloat,
+    m2: TAFloat,
+    m3: TAFloat,
+    m4: TAFloat,
+    opt_period: usize,
+) -> TAFloat {
+    let s = moments::skewness(m2, m3, opt_period);
+    let k = moments::excess_kurtosis(m2, m4, opt_period);
+    let z2 = z * z;
+    let z3 = z2 * z;
+    let z_cf =
+        z + (z2 - 1.0) * s / 6.0 + (z3 - 3.0 * z) * k / 24.0 - (2.0 * z3 - 5.0 * z) * s * s / 36.0;
+    let std_dev = (m2 / opt_period as TAFloat).sqrt();
+    -z_cf.mul_add(std_dev, mean)
+}
+
+/// Returns the quantile of the standard normal distribution at probability `p`, NaN outside
+/// (0, 1).
+///
+/// Uses algorithm AS241 (Wichura, 1988), accurate to about 1e-16.
+fn inverse_normal_cdf(p: TAFloat) -> TAFloat {
+    const CENTRAL_NUM: [TAFloat; 8] = [
+        3.387_132_872_796_366_5,
+        133.141_667_891_784_38,
+        1_971.590_950_306_551_3,
+        13_731.693_765_509_46,
+        45_921.953_931_549_87,
+        67_265.770_927_008_7,
+        33_430.575_583_588_13,
+        2_509.080_928_730_122_7,
+    ];
+    const CENTRAL_DEN: [TAFloat; 8] = [
+        1.0,
+        42.313_330_701_600_91,
+        687.187_007_492_057_9,
+        5_394.196_021_424_751,
+        21_213.794_301_586_597,
+        39_307.895_800_092_71,
+        28_729.085_735_721_943,
+        5_226.495_278_852_854,
+    ];
+    const NEAR_NUM: [TAFloat; 8] = [
+        1.423_437_110_749_683_5,
+        4.630_337_846_156_546,
+        5.769_497_221_460_691,
+        3.647_848_324_763_204_5,
+        1.270_458_252_452_368_4,
+        0.241_780_725_177_450_6,
+        0.022_723_844_989_269_184,
+        0.000_774_545_014_278_341_4,
+    ];
+    const NEAR_DEN: [TAFloat; 8] = [
+        1.0,
+        2.053_191_626_637_759,
+        1.676_384_830_183_803_8,
+        0.689_767_334_985_1,
+        0.148_103_976_427_480_08,
+        0.015_198_666_563_616_457,
+        0.000_547_593_808_499_534_5,
+        1.050_750_071_644_416_9e-9,
+    ];
+    const FAR_NUM: [TAFloat; 8] = [
+        6.657_904_643_501_103,
+        5.463_784_911_164_114,
+        1.784_826_539_917_291_3,
+        0.296_560_571_828_504_87,
+        0.026_532_189_526_576_124,
+        0.001_242_660_947_388_078_4,
+        2.711_555_568_743_487_6e-5,
+        2.010_334_399_292_288_1e-7,
+    ];
+    const FAR_DEN: [TAFloat; 8] = [
+        1.0,
+        0.599_832_206_555_888,
+        0.136_929_880_922_735_8,
+        0.014_875_361_290_850_615,
+        0.000_786_869_131_145_613_3,
+        1.846_318_317_510_054_8e-5,
+        1.421_511_758_316_446e-7,
+        2.044_263_103_389_939_7e-15,
+    ];
+
+    if !(p > 0.0 && p < 1.0) {
+        return TAFloat::NAN;
+    }
+
+    let q = p - 0.5;
+    if q.abs() <= 0.425 {
+        let r = 0.180_625 - q * q;
+        return q * polynomial(&CENTRAL_NUM, r) / polynomial(&CENTRAL_DEN, r);
+    }
+
+    let r = (-p.min(1.0 - p).ln()).sqrt();
+    let x = if r <= 5.0 {
+        let r = r - 1.6;
+        polynomial(&NEAR_NUM, r) / polynomial(&NEAR_DEN, r)
+    } else {
+        let r = r - 5.0;
+        polynomial(&FAR_NUM, r) / polynomial(&FAR_DEN, r)
+    };
+    if q < 0.0 { -x } else { x }
+}
+
+/// Evaluates a polynomial with coefficients in ascending order of power.
+fn polynomial(coefficients: &[TAFloat], x: TAFloat) -> TAFloat {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |acc, &coefficient| acc.mul_add(x, coefficient))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const RETURNS: [TAFloat; 30] = [
+        0.012, -0.008, 0.004, -0.021, 0.015, 0.003, -0.012, 0.007, -0.035, 0.018, 0.002, -0.006,
+        0.011, -0.017, 0.009, 0.024, -0.041, 0.006, -0.003, 0.013, -0.009, 0.001, 0.016, -0.026,
+        0.005, 0.008, -0.014, 0.019, -0.002, 0.010,
+    ];
+
+    #[test]
+    fn test_inverse_normal_cdf() {
+        // Python statistics.NormalDist().inv_cdf(p)
+        assert_relative_eq!(inverse_normal_cdf(0.5), 0.0);
+        assert_relative_eq!(
+            inverse_normal_cdf(0.05),
+            -1.644_853_626_951_472_2,
+            epsilon = 1e-15
+        );
+        assert_relative_eq!(
+            inverse_normal_cdf(0.99),
+            2.326_347_874_040_840_8,
+            epsilon = 1e-15
+        );
+        assert_relative_eq!(
+            inverse_normal_cdf(1e-10),
+            -6.361_340_902_404_056,
+            epsilon = 1e-13
+        );
+        assert!(inverse_normal_cdf(0.0).is_nan());
+        assert!(inverse_normal_cdf(1.0).is_nan());
+    }
+
+    #[test]
+    fn test_historical_risk_calculation() {
+        let opt_period = 12;
+        let opt_confidence = 0.9;
+        let len = RETURNS.len();
+        let mut output_var = vec![0.0; len];
+        let mut output_es = vec![0.0; len];
+
+        historical_var(&RETURNS, opt_period, opt_confidence, &mut output_var).unwrap();
+        historical_es(&RETURNS, opt_period, opt_confidence, &mut output_es).unwrap();
+
+        for i in 0..11 {
+            assert!(output_var[i].is_nan());
+            assert!(output_es[i].is_nan());
+        }
+
+        let expected_var = [
+            0.020_1, 0.020_1, 0.020_6, 0.020_6, 0.016_5, 0.033_2, 0.033_2, 0.033_2, 0.033_2,
+            0.016_2, 0.016_2, 0.016_2, 0.025_1, 0.025_1, 0.024_3, 0.024_8, 0.024_8, 0.013_5,
+            0.013_5,
+        ];
+        let expected_es = [
+            0.032_666_666_666_666_66,
+            0.032_666_666_666_666_66,
+            0.032_666_666_666_666_66,
+            0.032_666_666_666_666_66,
+            0.032,
+            0.04,
+            0.04,
+            0.04,
+            0.04,
+            0.037,
+            0.037,
+            0.037,
+            0.038_5,
+            0.038_5,
+            0.038_5,
+            0.038_5,
+            0.038_5,
+            0.024,
+            0.024,
+        ];
+        for i in 0..expected_var.len() {
+            assert_relative_eq!(output_var[i + 11], expected_var[i], epsilon = 1e-12);
+            assert_relative_eq!(output_es[i + 11], expected_es[i], epsilon = 1e-12);
+        }
+
+        // Incremental calculation matches the batch outputs
+        let mut var_window = OrderStatistics::new();
+        let mut es_window = OrderStatistics::new();
+        for &value in &RETURNS[..opt_period] {
+            var_window.insert(value);
+            es_window.insert(value);
+        }
+        for i in opt_period..len {
+            let old = RETURNS[i - opt_period];
+            let var = historical_var_inc(RETURNS[i], old, opt_confidence, &mut var_window).unwrap();
+            let es = historical_es_inc(RETURNS[i], old, opt_confidence, &mut es_window).unwrap();
+            assert_relative_eq!(var, output_var[i], epsilon = 1e-12);
+            assert_relative_eq!(es, output_es[i], epsilon = 1e-12);
+        }
+
+        // Streaming indicator matches the batch outputs
+        let mut indicator = HistoricalRisk::new(opt_period, opt_confidence).unwrap();
+        for (i, &value) in RETURNS.iter().enumerate() {
+            let (var, es) = indicator.update_value(value).unwrap();
+            if i < opt_period - 1 {
+                assert!(var.is_nan() && es.is_nan());
+            } else {
+                assert_relative_eq!(var, output_var[i], epsilon = 1e-12);
+                assert_relative_eq!(es, output_es[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        #[cfg(feature = "check")]
+        {
+            assert!(historical_var(&RETURNS, opt_period, 1.0, &mut output_var).is_err());
+            assert!(historical_es(&RETURNS, opt_period, 0.0, &mut output_es).is_err());
+            assert!(HistoricalRisk::new(opt_period, 1.5).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parametric_risk_calculation() {
+        let opt_period = 12;
+        let opt_confidence = 0.95;
+        let len = RETURNS.len();
+        let mut output_gaussian_var = vec![0.0; len];
+        let mut output_gaussian_es = vec![0.0; len];
+        let mut output_cf_var = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        let mut output_m3 = vec![0.0; len];
+        let mut output_m4 = vec![0.0; len];
+
+        gaussian_var(
+            &RETURNS,
+            opt_period,
+            opt_confidence,
+            &mut output_gaussian_var,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+        gaussian_es(
+            &RETURNS,
+            opt_period,
+            opt_confidence,
+            &mut output_gaussian_es,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+        cornish_fisher_var(
+            &RETURNS,
+            opt_period,
+            opt_confidence,
+            &mut output_cf_var,
+            &mut output_mean,
+            &mut output_m2,
+            &mut output_m3,
+            &mut output_m4,
+        )
+        .unwrap();
+
+        for i in 0..11 {
+            assert!(output_gaussian_var[i].is_nan());
+            assert!(output_gaussian_es[i].is_nan());
+            assert!(output_cf_var[i].is_nan());
+        }
+
+        let expected_gaussian_var = [
+            0.026_166_751_897_163_74,
+            0.026_127_041_220_705_72,
+            0.027_721_970_328_794_67,
+            0.027_700_190_090_827_265,
+            0.024_652_454_411_969_175,
+            0.034_668_937_019_213_29,
+            0.034_578_250_763_777_29,
+            0.033_504_331_253_420_276,
+            0.033_509_728_492_108_11,
+            0.027_115_551_891_425_223,
+            0.027_166_692_748_372_294,
+            0.027_086_376_079_772_78,
+            0.031_193_455_169_891_7,
+            0.031_256_092_403_918_4,
+            0.028_453_559_836_032_177,
+            0.030_692_106_715_206_44,
+            0.030_186_259_126_658_534,
+            0.019_320_768_636_683_74,
+            0.019_278_826_792_740_872,
+        ];
+        let expected_gaussian_es = [
+            0.032_369_591_938_618_665,
+            0.032_298_623_119_635_82,
+            0.034_108_198_308_657_98,
+            0.034_186_735_154_718_755,
+            0.031_317_402_945_298_8,
+            0.042_692_954_632_262_824,
+            0.042_642_740_495_235_434,
+            0.041_486_532_361_169_86,
+            0.041_620_320_888_026_43,
+            0.034_152_186_211_150_38,
+            0.033_856_428_422_207_006,
+            0.034_052_088_480_382_05,
+            0.038_779_130_781_070_835,
+            0.038_730_660_227_753_55,
+            0.035_745_422_039_207_51,
+            0.038_065_739_478_163_96,
+            0.037_325_536_051_249_1,
+            0.024_525_403_740_548_676,
+            0.024_557_487_090_691_523,
+        ];
+        let expected_cf_var = [
+            0.029_210_150_933_840_41,
+            0.029_178_497_520_072_11,
+            0.030_436_388_741_591_283,
+            0.030_575_150_258_796_733,
+            0.028_081_307_308_554_723,
+            0.038_290_017_546_843_524,
+            0.038_337_279_847_353_92,
+            0.037_778_145_056_432_955,
+            0.037_919_626_084_847_564,
+            0.031_313_175_870_714_26,
+            0.031_070_480_062_763_944,
+            0.031_284_770_806_913_165,
+            0.034_994_753_665_049_2,
+            0.034_859_662_467_812_92,
+            0.033_245_647_173_179_77,
+            0.034_364_152_360_029_4,
+            0.034_323_377_024_086_81,
+            0.021_393_240_460_770_627,
+            0.021_500_897_763_783_226,
+        ];
+        for i in 0..expected_gaussian_var.len() {
+            assert_relative_eq!(
+                output_gaussian_var[i + 11],
+                expected_gaussian_var[i],
+                epsilon = 1e-12
+            );
+            assert_relative_eq!(
+                output_gaussian_es[i + 11],
+                expected_gaussian_es[i],
+                epsilon = 1e-12
+            );
+            assert_relative_eq!(output_cf_var[i + 11], expected_cf_var[i], epsilon = 1e-12);
+        }
+
+        // Incremental calculation matches the batch outputs
+        let mut mean = output_mean[opt_period - 1];
+        let mut m2 = output_m2[opt_period - 1];
+        let mut m3 = output_m3[opt_period - 1];
+        let mut m4 = output_m4[opt_period - 1];
+        for i in opt_period..len {
+            let old = RETURNS[i - opt_period];
+            let (var, _, _) =
+                gaussian_var_inc(RETURNS[i], mean, m2, old, opt_period, opt_confidence).unwrap();
+            let (es, _, _) =
+                gaussian_es_inc(RETURNS[i], mean, m2, old, opt_period, opt_confidence).unwrap();
+            let (cf_var, new_mean, new_m2, new_m3, new_m4) = cornish_fisher_var_inc(
+                RETURNS[i],
+                mean,
+                m2,
+                m3,
+                m4,
+                old,
+                opt_period,
+                opt_confidence,
+            )
+            .unwrap();
+            assert_relative_eq!(var, output_gaussian_var[i], epsilon = 1e-12);
+            assert_relative_eq!(es, output_gaussian_es[i], epsilon = 1e-12);
+            assert_relative_eq!(cf_var, output_cf_var[i], epsilon = 1e-12);
+            (mean, m2, m3, m4) = (new_mean, new_m2, new_m3, new_m4);
+        }
+
+        // Streaming indicator matches the batch outputs
+        let mut indicator = ParametricRisk::new(opt_period, opt_confidence).unwrap();
+        for (i, &value) in RETURNS.iter().enumerate() {
+            let (var, es, cf_var) = indicator.update_value(value).unwrap();
+            if i < opt_period - 1 {
+                assert!(var.is_nan() && es.is_nan() && cf_var.is_nan());
+            } else {
+                assert_relative_eq!(var, output_gaussian_var[i], epsilon = 1e-12);
+                assert_relative_eq!(es, output_gaussian_es[i], epsilon = 1e-12);
+                assert_relative_eq!(cf_var, output_cf_var[i], epsilon = 1e-12);
+            }
+        }
+        indicator.reset();
+        assert!(!indicator.is_ready());
+
+        #[cfg(feature = "check")]
+        {
+            assert!(ParametricRisk::new(3, opt_confidence).is_err());
+            assert!(gaussian_var_inc(0.01, 0.0, 0.0, 0.0, opt_period, -0.5).is_err());
+        }
+    }
+}
@@ -0,0 +1,600 @@
+use std::collections::VecDeque;
+
+use super::moments::{self, RollingMoments};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Sharpe ratio calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For the Sharpe ratio, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::sharpe;
+/// let period = 20;
+/// let lookback = sharpe::lookback(period).unwrap();
+/// assert_eq!(lookback, 19); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates the rolling Sharpe ratio for a series of periodic returns.
+///
+/// # Description
+/// The Sharpe ratio is the mean excess return per unit of volatility, scaled to a yearly figure.
+/// The risk-free rate is given per year and spread evenly over `opt_periods_per_year`. Volatility
+/// is the sample standard deviation of the returns, which a constant risk-free rate does not
+/// change, so only the mean and the sum of squared deviations (M2) of the raw returns are tracked.
+///
+/// # Mathematical Formula
+/// ```text
+/// Rf = RiskFree / PeriodsPerYear
+/// Mean = Sum(r) / n
+/// M2 = Sum((r - Mean)^2)
+/// StdDev = sqrt(M2 / (n - 1))
+/// Sharpe = (Mean - Rf) / StdDev * sqrt(PeriodsPerYear)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Accumulate the mean and M2 over the first window
+/// 2. Compute the annualized excess return over volatility
+/// 3. Slide the window with Welford's update (see [`RollingMoments`])
+/// 4. Fill the first (period-1) values with NaN
+///
+/// A window whose returns are all equal has an M2 of exactly zero and produces NaN. Low but
+/// non-zero volatility, such as a near-cash series, still gives a finite ratio.
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns (e.g. 0.01 for +1%)
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_risk_free` - Annual risk-free rate (e.g. 0.02 for 2%)
+/// * `opt_periods_per_year` - Number of return periods per year (e.g. 252.0 for daily bars)
+/// * `output_sharpe` - Array to store the annualized Sharpe ratio
+/// * `output_mean` - Array to store the rolling mean of returns
+/// * `output_m2` - Array to store the rolling sum of squared deviations from the mean
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sharpe;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005, -0.01];
+/// let mut output_sharpe = vec![0.0; 5];
+/// let mut output_mean = vec![0.0; 5];
+/// let mut output_m2 = vec![0.0; 5];
+///
+/// sharpe::sharpe(
+///     &input_returns,
+///     3,
+///     0.02,
+///     252.0,
+///     &mut output_sharpe,
+///     &mut output_mean,
+///     &mut output_m2,
+/// )
+/// .unwrap();
+/// // First (period-1) values are NaN, followed by rolling Sharpe ratios
+/// ```
+pub fn sharpe(
+    input_returns: &[TAFloat],
+    opt_period: usize,
+    opt_risk_free: TAFloat,
+    opt_periods_per_year: TAFloat,
+    output_sharpe: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_sharpe.len() != len || output_mean.len() != len || output_m2.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial moments
+    let mut moments = RollingMoments::new();
+    for value in input_returns.iter().take(opt_period) {
+        moments.push(*value);
+    }
+
+    for i in lookback..len {
+        if i > lookback {
+            moments.replace(input_returns[i], input_returns[i - opt_period]);
+        }
+        output_sharpe[i] = ratio(
+            moments.mean(),
+            moments.m2(),
+            opt_period,
+            opt_risk_free,
+            opt_periods_per_year,
+        );
+        output_mean[i] = moments.mean();
+        output_m2[i] = moments.m2();
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_sharpe[i] = TAFloat::NAN;
+        output_mean[i] = TAFloat::NAN;
+        output_m2[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Sharpe ratio using incremental computation.
+///
+/// # Arguments
+/// * `input_return` - The newest return to include in calculation
+/// * `prev_mean` - Previous mean of returns in the window
+/// * `prev_m2` - Previous sum of squared deviations from the mean in the window
+/// * `input_old_return` - Oldest return to remove from calculation
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_risk_free` - Annual risk-free rate
+/// * `opt_periods_per_year` - Number of return periods per year
+///
+/// Seed `prev_mean` and `prev_m2` from the `output_mean` and `output_m2` of [`sharpe`].
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (sharpe, `new_mean`,
+///   `new_m2`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sharpe;
+/// // Window [0.01, -0.02, 0.015] slides to [-0.02, 0.015, 0.005]
+/// let (sharpe_value, new_mean, new_m2) = sharpe::sharpe_inc(
+///     0.005,           // new return
+///     0.005 / 3.0,     // previous mean
+///     0.002_15 / 3.0,  // previous M2
+///     0.01,            // return to remove
+///     3,               // period
+///     0.02,            // annual risk-free rate
+///     252.0,           // periods per year
+/// )
+/// .unwrap();
+/// assert!(new_mean.abs() < 1e-12);
+/// assert!((new_m2 - 0.000_65).abs() < 1e-12);
+/// ```
+pub fn sharpe_inc(
+    input_return: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    input_old_return: TAFloat,
+    opt_period: usize,
+    opt_risk_free: TAFloat,
+    opt_periods_per_year: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 2 || opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_return.is_nan()
+            || prev_mean.is_nan()
+            || prev_m2.is_nan()
+            || input_old_return.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (new_mean, new_m2) = moments::slide(
+        prev_mean,
+        prev_m2,
+        input_return,
+        input_old_return,
+        opt_period,
+    );
+    let sharpe = ratio(
+        new_mean,
+        new_m2,
+        opt_period,
+        opt_risk_free,
+        opt_periods_per_year,
+    );
+
+    Ok((sharpe, new_mean, new_m2))
+}
+
+/// Calculates the Sharpe ratio of an entire return series.
+///
+/// # Description
+/// Treats the whole series as a single window; see [`sharpe`] for the formula.
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns
+/// * `opt_risk_free` - Annual risk-free rate
+/// * `opt_periods_per_year` - Number of return periods per year
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The annualized Sharpe ratio, or NaN if the returns have zero
+///   volatility
+///
+/// # Errors
+/// * Returns `KandError::InsufficientData` if there are fewer than 2 returns
+/// * Returns `KandError::InvalidParameter` if periods per year is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sharpe;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005, -0.01];
+/// let ratio = sharpe::sharpe_ratio(&input_returns, 0.0, 252.0).unwrap();
+/// ```
+pub fn sharpe_ratio(
+    input_returns: &[TAFloat],
+    opt_risk_free: TAFloat,
+    opt_periods_per_year: TAFloat,
+) -> Result<TAFloat, KandError> {
+    let len = input_returns.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Data sufficiency check
+        if len < 2 {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut moments = RollingMoments::new();
+    for value in input_returns {
+        moments.push(*value);
+    }
+
+    Ok(ratio(
+        moments.mean(),
+        moments.m2(),
+        len,
+        opt_risk_free,
+        opt_periods_per_year,
+    ))
+}
+
+/// Annualized Sharpe ratio of a window from its mean and M2, NaN if the returns are constant.
+fn ratio(
+    mean: TAFloat,
+    m2: TAFloat,
+    opt_period: usize,
+    opt_risk_free: TAFloat,
+    opt_periods_per_year: TAFloat,
+) -> TAFloat {
+    if m2 <= 0.0 {
+        return TAFloat::NAN;
+    }
+    let variance = m2 / (opt_period as TAFloat - 1.0);
+    let excess = mean - opt_risk_free / opt_periods_per_year;
+    excess / variance.sqrt() * opt_periods_per_year.sqrt()
+}
+
+/// Streaming rolling Sharpe ratio that keeps the last `opt_period` returns and their [`RollingMoments`].
+///
+/// Reads [`Bar::close`] as the periodic return. Folding [`Indicator::update`] over a series
+/// reproduces [`sharpe`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::sharpe::Sharpe};
+///
+/// let mut sharpe = Sharpe::new(3, 0.02, 252.0).unwrap();
+/// for r in [0.01, -0.02, 0.015, 0.005, -0.01] {
+///     let value = sharpe.update_value(r).unwrap();
+/// }
+/// assert!(sharpe.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sharpe {
+    opt_period: usize,
+    opt_risk_free: TAFloat,
+    opt_periods_per_year: TAFloat,
+    window: VecDeque<TAFloat>,
+    moments: RollingMoments,
+}
+
+impl Sharpe {
+    /// Creates a streaming rolling Sharpe ratio.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+    /// * `opt_risk_free` - Annual risk-free rate
+    /// * `opt_periods_per_year` - Number of return periods per year (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or periods per year is not positive
+    pub fn new(
+        opt_period: usize,
+        opt_risk_free: TAFloat,
+        opt_periods_per_year: TAFloat,
+    ) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            opt_risk_free,
+            opt_periods_per_year,
+            window: VecDeque::with_capacity(opt_period + 1),
+            moments: RollingMoments::new(),
+        })
+    }
+
+    /// Feeds a raw return instead of a [`Bar`] and returns the rolling Sharpe ratio for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_return` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_return: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_return.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_return);
+        if self.window.len() > self.opt_period {
+            let old_return = self.window.pop_front().unwrap_or(0.0);
+            self.moments.replace(input_return, old_return);
+        } else {
+            self.moments.push(input_return);
+        }
+        if !self.is_ready() {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(ratio(
+            self.moments.mean(),
+            self.moments.m2(),
+            self.opt_period,
+            self.opt_risk_free,
+            self.opt_periods_per_year,
+        ))
+    }
+}
+
+impl Indicator for Sharpe {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.moments = RollingMoments::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_RETURNS: [TAFloat; 20] = [
+        0.012, -0.008, 0.004, 0.015, -0.021, 0.007, 0.003, -0.012, 0.018, 0.006, -0.004, 0.009,
+        -0.015, 0.011, 0.002, -0.006, 0.013, -0.009, 0.005, 0.010,
+    ];
+
+    #[test]
+    fn test_sharpe_calculation() {
+        let opt_period = 10;
+        let len = INPUT_RETURNS.len();
+        let mut output_sharpe = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+
+        sharpe(
+            &INPUT_RETURNS,
+            opt_period,
+            0.02,
+            252.0,
+            &mut output_sharpe,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+
+        // First 9 values should be NaN
+        for value in output_sharpe.iter().take(9) {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            2.959_611_193_748_672_4,
+            0.945_464_083_530_725_8,
+            3.224_040_235_995_955_2,
+            0.630_512_787_333_264,
+            0.151_102_099_305_661_65,
+            3.747_058_816_908_975,
+            1.703_406_898_968_432_6,
+            3.034_561_616_732_687_6,
+            3.591_094_396_674_634_5,
+            1.911_391_989_081_043,
+            2.510_038_881_721_385,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_sharpe[i + 9], expected, epsilon = 0.00001);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut prev_mean = output_mean[9];
+        let mut prev_m2 = output_m2[9];
+        for i in 10..len {
+            let (sharpe, new_mean, new_m2) = sharpe_inc(
+                INPUT_RETURNS[i],
+                prev_mean,
+                prev_m2,
+                INPUT_RETURNS[i - opt_period],
+                opt_period,
+                0.02,
+                252.0,
+            )
+            .unwrap();
+            assert_relative_eq!(sharpe, output_sharpe[i], epsilon = 0.00001);
+            prev_mean = new_mean;
+            prev_m2 = new_m2;
+        }
+
+        // Full-series ratio
+        let ratio = sharpe_ratio(&INPUT_RETURNS, 0.02, 252.0).unwrap();
+        assert_relative_eq!(ratio, 2.814_293_970_366_401_6, epsilon = 0.00001);
+
+        // Zero volatility has no defined ratio
+        assert!(sharpe_ratio(&[0.01; 5], 0.0, 252.0).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_sharpe_low_volatility() {
+        // Near-cash returns whose variance is around 1e-16 still have a defined ratio
+        let input_returns = [
+            1.0e-4, 1.000_1e-4, 0.999_9e-4, 1.0e-4, 1.000_2e-4, 0.999_8e-4, 1.000_1e-4, 0.999_9e-4,
+        ];
+        let opt_period = 4;
+        let len = input_returns.len();
+        let mut output_sharpe = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        sharpe(
+            &input_returns,
+            opt_period,
+            0.0,
+            252.0,
+            &mut output_sharpe,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+
+        for i in opt_period - 1..len {
+            let window = &input_returns[i + 1 - opt_period..=i];
+            let expected = sharpe_ratio(window, 0.0, 252.0).unwrap();
+            assert!(output_sharpe[i].is_finite());
+            assert_relative_eq!(output_sharpe[i], expected, max_relative = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_sharpe_indicator() {
+        let opt_period = 10;
+        let len = INPUT_RETURNS.len();
+        let mut output_sharpe = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        sharpe(
+            &INPUT_RETURNS,
+            opt_period,
+            0.02,
+            252.0,
+            &mut output_sharpe,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+
+        let mut indicator = Sharpe::new(opt_period, 0.02, 252.0).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &value) in INPUT_RETURNS.iter().enumerate() {
+            let sharpe = indicator.update(&Bar::from(value)).unwrap();
+            if i < lookback {
+                assert!(sharpe.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(sharpe, output_sharpe[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
@@ -0,0 +1,464 @@
+use std::collections::VecDeque;
+
+use super::moments::{self, RollingHigherMoments};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Skewness calculation.
+///
+/// # Description
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For Skewness calculation, this equals the specified period minus one.
+///
+/// # Arguments
+/// * `opt_period` - The time period used for Skewness calculation (must be >= 3)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `opt_period` is less than 3
+///
+/// # Example
+/// ```
+/// use kand::stats::skew;
+/// let period = 20;
+/// let lookback = skew::lookback(period).unwrap();
+/// assert_eq!(lookback, 19); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 3 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates rolling Skewness for an entire price series.
+///
+/// # Description
+/// Skewness measures the asymmetry of values around their mean over a specified period. Positive
+/// values mean a longer right tail (occasional large rises), negative values a longer left tail.
+///
+/// # Mathematical Formula
+/// ```text
+/// SKEW = sqrt(n) * M3 / M2^(3/2)
+/// ```
+/// Where:
+/// - M2 = sum((x - mean)^2) and M3 = sum((x - mean)^3) over the period
+/// - n is the time period
+///
+/// This is the population (biased) skewness, consistent with the population variance of
+/// [`super::var`]. Multiply by `sqrt(n * (n - 1)) / (n - 2)` for the sample-adjusted estimate
+/// reported by pandas.
+///
+/// # Calculation Steps
+/// 1. Accumulate the mean, M2 and M3 of the first period
+/// 2. Slide the window in O(1) per value with [`RollingHigherMoments`]
+/// 3. Apply the skewness formula, giving NaN for a constant window
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `opt_period` - The time period for Skewness calculation (must be >= 3)
+/// * `output_skew` - Array to store calculated Skewness values
+/// * `output_mean` - Array to store the rolling mean values
+/// * `output_m2` - Array to store the rolling sum of squared deviations (M2)
+/// * `output_m3` - Array to store the rolling sum of cubed deviations (M3)
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 3
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::skew;
+/// let input_prices = vec![1.0, 2.0, 3.0, 10.0, 4.0];
+/// let period = 3;
+/// let mut output_skew = vec![0.0; 5];
+/// let mut output_mean = vec![0.0; 5];
+/// let mut output_m2 = vec![0.0; 5];
+/// let mut output_m3 = vec![0.0; 5];
+///
+/// skew::skew(
+///     &input_prices,
+///     period,
+///     &mut output_skew,
+///     &mut output_mean,
+///     &mut output_m2,
+///     &mut output_m3,
+/// )
+/// .unwrap();
+/// assert!(output_skew[1].is_nan());
+/// assert!(output_skew[2].abs() < 1e-12); // [1, 2, 3] is symmetric
+/// assert!(output_skew[3] > 0.0); // [2, 3, 10] has a right tail
+/// ```
+pub fn skew(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    output_skew: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+    output_m3: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_skew.len() != len
+            || output_mean.len() != len
+            || output_m2.len() != len
+            || output_m3.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial values
+    let mut moments = RollingHigherMoments::new();
+    for val in input_prices.iter().take(opt_period) {
+        moments.push(*val);
+    }
+    output_skew[lookback] = moments.skewness();
+    output_mean[lookback] = moments.mean();
+    output_m2[lookback] = moments.m2();
+    output_m3[lookback] = moments.m3();
+
+    // Calculate remaining values incrementally
+    for i in opt_period..len {
+        moments.replace(input_prices[i], input_prices[i - opt_period]);
+        output_skew[i] = moments.skewness();
+        output_mean[i] = moments.mean();
+        output_m2[i] = moments.m2();
+        output_m3[i] = moments.m3();
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_skew[i] = TAFloat::NAN;
+        output_mean[i] = TAFloat::NAN;
+        output_m2[i] = TAFloat::NAN;
+        output_m3[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Skewness value using incremental computation.
+///
+/// # Description
+/// This function updates the mean, M2 and M3 of the window in O(1) by removing the oldest value
+/// and adding the newest one (see [`moments::slide_higher`]), then applies the skewness formula.
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `prev_mean` - Previous mean of values in the period
+/// * `prev_m2` - Previous sum of squared deviations from the mean in the period
+/// * `prev_m3` - Previous sum of cubed deviations from the mean in the period
+/// * `input_old_price` - Oldest price value to remove from calculation
+/// * `opt_period` - The time period for Skewness calculation (must be >= 3)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (skewness,
+///   `new_mean`, `new_m2`, `new_m3`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 3
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::skew;
+/// // Window [1, 2, 3] slides to [2, 3, 10]
+/// let (skew_value, new_mean, new_m2, new_m3) = skew::skew_inc(
+///     10.0, // new price
+///     2.0,  // previous mean
+///     2.0,  // previous M2
+///     0.0,  // previous M3
+///     1.0,  // price to remove
+///     3,    // period
+/// )
+/// .unwrap();
+/// assert!((new_mean - 5.0).abs() < 1e-12);
+/// assert!((new_m2 - 38.0).abs() < 1e-12);
+/// assert!((new_m3 - 90.0).abs() < 1e-12);
+/// assert!(skew_value > 0.0);
+/// ```
+pub fn skew_inc(
+    input_price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    prev_m3: TAFloat,
+    input_old_price: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 3 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan()
+            || prev_mean.is_nan()
+            || prev_m2.is_nan()
+            || prev_m3.is_nan()
+            || input_old_price.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    // M4 does not feed back into the lower moments, so it is not tracked here
+    let (new_mean, new_m2, new_m3, _) = moments::slide_higher(
+        prev_mean,
+        prev_m2,
+        prev_m3,
+        0.0,
+        input_price,
+        input_old_price,
+        opt_period,
+    );
+
+    Ok((
+        moments::skewness(new_m2, new_m3, opt_period),
+        new_mean,
+        new_m2,
+        new_m3,
+    ))
+}
+
+/// Streaming rolling skewness that keeps the last `opt_period` values and their
+/// [`RollingHigherMoments`].
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`skew`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Skew {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+    moments: RollingHigherMoments,
+}
+
+impl Skew {
+    /// Creates a streaming rolling skewness.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 3)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 3
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+            moments: RollingHigherMoments::new(),
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the rolling skewness for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() > self.opt_period {
+            let old_price = self.window.pop_front().unwrap_or(0.0);
+            self.moments.replace(input_price, old_price);
+        } else {
+            self.moments.push(input_price);
+        }
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(self.moments.skewness())
+    }
+}
+
+impl Indicator for Skew {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.moments = RollingHigherMoments::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_skew_calculation() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let len = input_close.len();
+        let mut output_skew = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        let mut output_m3 = vec![0.0; len];
+
+        skew(
+            &input_close,
+            opt_period,
+            &mut output_skew,
+            &mut output_mean,
+            &mut output_m2,
+            &mut output_m3,
+        )
+        .unwrap();
+
+        for value in &output_skew[..13] {
+            assert!(value.is_nan());
+        }
+
+        // Two-pass population skewness reference values
+        let expected_values = [
+            0.369_310_051_411_874_7,
+            -0.798_303_750_486_967_2,
+            -1.114_118_435_513_047,
+            -1.062_899_896_729_715_9,
+            -0.774_843_548_229_169_5,
+            -0.501_482_140_449_091_9,
+            -0.268_860_656_141_469_76,
+            -0.197_790_705_561_172,
+            -0.064_613_483_650_996_63,
+            0.217_010_836_929_633_72,
+            0.469_146_252_688_635_97,
+            0.652_047_060_425_716_3,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_skew[i + 13], expected, epsilon = 1e-9);
+        }
+
+        // Incremental calculation matches the batch outputs
+        let mut prev_mean = output_mean[13];
+        let mut prev_m2 = output_m2[13];
+        let mut prev_m3 = output_m3[13];
+        for i in 14..len {
+            let (skew_value, new_mean, new_m2, new_m3) = skew_inc(
+                input_close[i],
+                prev_mean,
+                prev_m2,
+                prev_m3,
+                input_close[i - opt_period],
+                opt_period,
+            )
+            .unwrap();
+            assert_relative_eq!(skew_value, output_skew[i], epsilon = 1e-9);
+            prev_mean = new_mean;
+            prev_m2 = new_m2;
+            prev_m3 = new_m3;
+        }
+
+        // A constant window has no skewness
+        let flat = vec![100.0; 5];
+        let mut outputs = vec![vec![0.0; 5]; 4];
+        let [out_skew, out_mean, out_m2, out_m3] = &mut outputs[..] else {
+            unreachable!()
+        };
+        skew(&flat, 3, out_skew, out_mean, out_m2, out_m3).unwrap();
+        assert!(out_skew[4].is_nan());
+
+        #[cfg(feature = "check")]
+        assert!(lookback(2).is_err());
+    }
+
+    #[test]
+    fn test_skew_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+        ];
+        let opt_period = 5;
+        let len = input_price.len();
+        let mut output_skew = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        let mut output_m3 = vec![0.0; len];
+        skew(
+            &input_price,
+            opt_period,
+            &mut output_skew,
+            &mut output_mean,
+            &mut output_m2,
+            &mut output_m3,
+        )
+        .unwrap();
+
+        let mut indicator = Skew::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_skew[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
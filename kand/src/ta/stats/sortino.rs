@@ -0,0 +1,697 @@
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Sortino ratio calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For the Sortino ratio, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::sortino;
+/// let period = 20;
+/// let lookback = sortino::lookback(period).unwrap();
+/// assert_eq!(lookback, 19); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates the rolling Sortino ratio for a series of periodic returns.
+///
+/// # Description
+/// The Sortino ratio is a Sharpe ratio that only penalizes downside volatility. Both the excess
+/// return and the downside deviation are measured against a minimum acceptable return (MAR),
+/// given per year and spread evenly over `opt_periods_per_year`. Pass the risk-free rate as the
+/// MAR for the textbook form, or 0 to measure against flat returns.
+///
+/// # Mathematical Formula
+/// ```text
+/// T = MAR / PeriodsPerYear
+/// Mean = Sum(r) / n
+/// DownsideDev = sqrt(Sum(min(r - T, 0)^2) / n)
+/// Sortino = (Mean - T) / DownsideDev * sqrt(PeriodsPerYear)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Accumulate the sum of returns, the squared shortfalls below `T` and the number of returns
+///    below `T` over the first window
+/// 2. Compute the annualized excess return over downside deviation
+/// 3. Slide the window, updating the sums and the count incrementally
+/// 4. Fill the first (period-1) values with NaN
+///
+/// A window without any return below the MAR has no downside deviation and produces NaN. The
+/// count of shortfalls decides this exactly: once it drops to zero the sum of squared shortfalls
+/// is reset to zero, so rounding residue left by sliding never passes for downside risk.
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns (e.g. 0.01 for +1%)
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_mar` - Annual minimum acceptable return (e.g. 0.02 for 2%)
+/// * `opt_periods_per_year` - Number of return periods per year (e.g. 252.0 for daily bars)
+/// * `output_sortino` - Array to store the annualized Sortino ratio
+/// * `output_sum` - Array to store running sum of returns
+/// * `output_sum_down_sq` - Array to store running sum of squared shortfalls below the MAR
+/// * `output_down_count` - Array to store the number of returns below the MAR in the window
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sortino;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005, -0.01];
+/// let mut output_sortino = vec![0.0; 5];
+/// let mut output_sum = vec![0.0; 5];
+/// let mut output_sum_down_sq = vec![0.0; 5];
+/// let mut output_down_count = vec![0; 5];
+///
+/// sortino::sortino(
+///     &input_returns,
+///     3,
+///     0.0,
+///     252.0,
+///     &mut output_sortino,
+///     &mut output_sum,
+///     &mut output_sum_down_sq,
+///     &mut output_down_count,
+/// )
+/// .unwrap();
+/// // First (period-1) values are NaN, followed by rolling Sortino ratios
+/// ```
+pub fn sortino(
+    input_returns: &[TAFloat],
+    opt_period: usize,
+    opt_mar: TAFloat,
+    opt_periods_per_year: TAFloat,
+    output_sortino: &mut [TAFloat],
+    output_sum: &mut [TAFloat],
+    output_sum_down_sq: &mut [TAFloat],
+    output_down_count: &mut [usize],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_sortino.len() != len
+            || output_sum.len() != len
+            || output_sum_down_sq.len() != len
+            || output_down_count.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial sums
+    let target = opt_mar / opt_periods_per_year;
+    let mut sum = 0.0;
+    let mut sum_down_sq = 0.0;
+    let mut down_count = 0;
+    for value in input_returns.iter().take(opt_period) {
+        sum += *value;
+        sum_down_sq += shortfall_sq(*value, target);
+        down_count += usize::from(*value < target);
+    }
+
+    output_sortino[lookback] = ratio(
+        sum,
+        sum_down_sq,
+        down_count,
+        opt_period,
+        target,
+        opt_periods_per_year,
+    );
+    output_sum[lookback] = sum;
+    output_sum_down_sq[lookback] = sum_down_sq;
+    output_down_count[lookback] = down_count;
+
+    // Slide the window
+    for i in opt_period..len {
+        let (sortino, new_sum, new_sum_down_sq, new_down_count) = sortino_inc(
+            input_returns[i],
+            sum,
+            sum_down_sq,
+            down_count,
+            input_returns[i - opt_period],
+            opt_period,
+            opt_mar,
+            opt_periods_per_year,
+        )?;
+        sum = new_sum;
+        sum_down_sq = new_sum_down_sq;
+        down_count = new_down_count;
+        output_sortino[i] = sortino;
+        output_sum[i] = sum;
+        output_sum_down_sq[i] = sum_down_sq;
+        output_down_count[i] = down_count;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_sortino[i] = TAFloat::NAN;
+        output_sum[i] = TAFloat::NAN;
+        output_sum_down_sq[i] = TAFloat::NAN;
+        output_down_count[i] = 0;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Sortino ratio using incremental computation.
+///
+/// # Arguments
+/// * `input_return` - The newest return to include in calculation
+/// * `prev_sum` - Previous sum of returns in the window
+/// * `prev_sum_down_sq` - Previous sum of squared shortfalls below the MAR in the window
+/// * `prev_down_count` - Previous number of returns below the MAR in the window
+/// * `input_old_return` - Oldest return to remove from calculation
+/// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+/// * `opt_mar` - Annual minimum acceptable return
+/// * `opt_periods_per_year` - Number of return periods per year
+///
+/// Seed the state from the `output_sum`, `output_sum_down_sq` and `output_down_count` of
+/// [`sortino`].
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, usize), KandError>` - Tuple containing (sortino,
+///   `new_sum`, `new_sum_down_sq`, `new_down_count`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sortino;
+/// // Window [0.01, -0.02, 0.015] slides to [-0.02, 0.015, 0.005]
+/// let (sortino_value, new_sum, new_sum_down_sq, new_down_count) = sortino::sortino_inc(
+///     0.005,   // new return
+///     0.005,   // previous sum
+///     0.000_4, // previous sum of squared shortfalls
+///     1,       // previous number of shortfalls
+///     0.01,    // return to remove
+///     3,       // period
+///     0.0,     // annual minimum acceptable return
+///     252.0,   // periods per year
+/// )
+/// .unwrap();
+/// assert_eq!(new_down_count, 1);
+/// ```
+pub fn sortino_inc(
+    input_return: TAFloat,
+    prev_sum: TAFloat,
+    prev_sum_down_sq: TAFloat,
+    prev_down_count: usize,
+    input_old_return: TAFloat,
+    opt_period: usize,
+    opt_mar: TAFloat,
+    opt_periods_per_year: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, usize), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 2 || opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_return.is_nan()
+            || prev_sum.is_nan()
+            || prev_sum_down_sq.is_nan()
+            || input_old_return.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let target = opt_mar / opt_periods_per_year;
+    let new_sum = prev_sum - input_old_return + input_return;
+    let new_down_count = (prev_down_count + usize::from(input_return < target))
+        .saturating_sub(usize::from(input_old_return < target));
+    // Without shortfalls left the sum is exactly zero, whatever residue sliding left behind
+    let new_sum_down_sq = if new_down_count == 0 {
+        0.0
+    } else {
+        prev_sum_down_sq - shortfall_sq(input_old_return, target)
+            + shortfall_sq(input_return, target)
+    };
+    let sortino = ratio(
+        new_sum,
+        new_sum_down_sq,
+        new_down_count,
+        opt_period,
+        target,
+        opt_periods_per_year,
+    );
+
+    Ok((sortino, new_sum, new_sum_down_sq, new_down_count))
+}
+
+/// Calculates the Sortino ratio of an entire return series.
+///
+/// # Description
+/// Treats the whole series as a single window; see [`sortino`] for the formula.
+///
+/// # Arguments
+/// * `input_returns` - Array of periodic returns
+/// * `opt_mar` - Annual minimum acceptable return
+/// * `opt_periods_per_year` - Number of return periods per year
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The annualized Sortino ratio, or NaN if no return falls below
+///   the MAR
+///
+/// # Errors
+/// * Returns `KandError::InsufficientData` if there are fewer than 2 returns
+/// * Returns `KandError::InvalidParameter` if periods per year is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sortino;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005, -0.01];
+/// let ratio = sortino::sortino_ratio(&input_returns, 0.0, 252.0).unwrap();
+/// ```
+pub fn sortino_ratio(
+    input_returns: &[TAFloat],
+    opt_mar: TAFloat,
+    opt_periods_per_year: TAFloat,
+) -> Result<TAFloat, KandError> {
+    let len = input_returns.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Data sufficiency check
+        if len < 2 {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let target = opt_mar / opt_periods_per_year;
+    let mut sum = 0.0;
+    let mut sum_down_sq = 0.0;
+    let mut down_count = 0;
+    for value in input_returns {
+        sum += *value;
+        sum_down_sq += shortfall_sq(*value, target);
+        down_count += usize::from(*value < target);
+    }
+
+    Ok(ratio(
+        sum,
+        sum_down_sq,
+        down_count,
+        len,
+        target,
+        opt_periods_per_year,
+    ))
+}
+
+/// Squared shortfall of a return below the per-period target, zero when at or above it.
+fn shortfall_sq(value: TAFloat, target: TAFloat) -> TAFloat {
+    let shortfall = (value - target).min(0.0);
+    shortfall * shortfall
+}
+
+/// Annualized Sortino ratio of a window from its running sums, NaN without any shortfall.
+fn ratio(
+    sum: TAFloat,
+    sum_down_sq: TAFloat,
+    down_count: usize,
+    opt_period: usize,
+    target: TAFloat,
+    opt_periods_per_year: TAFloat,
+) -> TAFloat {
+    if down_count == 0 || sum_down_sq <= 0.0 {
+        return TAFloat::NAN;
+    }
+    let n = opt_period as TAFloat;
+    let downside_var = sum_down_sq / n;
+    (sum / n - target) / downside_var.sqrt() * opt_periods_per_year.sqrt()
+}
+
+/// Streaming rolling Sortino ratio that keeps the last `opt_period` returns, their running sums
+/// and the number of shortfalls below the MAR.
+///
+/// Reads [`Bar::close`] as the periodic return. Folding [`Indicator::update`] over a series
+/// reproduces [`sortino`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::sortino::Sortino};
+///
+/// let mut sortino = Sortino::new(3, 0.0, 252.0).unwrap();
+/// for r in [0.01, -0.02, 0.015, 0.005, -0.01] {
+///     let value = sortino.update_value(r).unwrap();
+/// }
+/// assert!(sortino.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sortino {
+    opt_period: usize,
+    opt_mar: TAFloat,
+    opt_periods_per_year: TAFloat,
+    window: VecDeque<TAFloat>,
+    sum: TAFloat,
+    sum_down_sq: TAFloat,
+    down_count: usize,
+}
+
+impl Sortino {
+    /// Creates a streaming rolling Sortino ratio.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of returns in the rolling window (must be >= 2)
+    /// * `opt_mar` - Annual minimum acceptable return
+    /// * `opt_periods_per_year` - Number of return periods per year (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or periods per year is not positive
+    pub fn new(
+        opt_period: usize,
+        opt_mar: TAFloat,
+        opt_periods_per_year: TAFloat,
+    ) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            opt_mar,
+            opt_periods_per_year,
+            window: VecDeque::with_capacity(opt_period + 1),
+            sum: 0.0,
+            sum_down_sq: 0.0,
+            down_count: 0,
+        })
+    }
+
+    /// Feeds a raw return instead of a [`Bar`] and returns the rolling Sortino ratio for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_return` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_return: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_return.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_return);
+        if self.window.len() > self.opt_period {
+            let old_return = self.window.pop_front().unwrap_or(0.0);
+            let (sortino, sum, sum_down_sq, down_count) = sortino_inc(
+                input_return,
+                self.sum,
+                self.sum_down_sq,
+                self.down_count,
+                old_return,
+                self.opt_period,
+                self.opt_mar,
+                self.opt_periods_per_year,
+            )?;
+            self.sum = sum;
+            self.sum_down_sq = sum_down_sq;
+            self.down_count = down_count;
+            return Ok(sortino);
+        }
+        let target = self.opt_mar / self.opt_periods_per_year;
+        self.sum += input_return;
+        self.sum_down_sq += shortfall_sq(input_return, target);
+        self.down_count += usize::from(input_return < target);
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(ratio(
+            self.sum,
+            self.sum_down_sq,
+            self.down_count,
+            self.opt_period,
+            target,
+            self.opt_periods_per_year,
+        ))
+    }
+}
+
+impl Indicator for Sortino {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+        self.sum_down_sq = 0.0;
+        self.down_count = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_RETURNS: [TAFloat; 20] = [
+        0.012, -0.008, 0.004, 0.015, -0.021, 0.007, 0.003, -0.012, 0.018, 0.006, -0.004, 0.009,
+        -0.015, 0.011, 0.002, -0.006, 0.013, -0.009, 0.005, 0.010,
+    ];
+
+    #[test]
+    fn test_sortino_calculation() {
+        let opt_period = 10;
+        let len = INPUT_RETURNS.len();
+        let mut output_sortino = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_down_sq = vec![0.0; len];
+        let mut output_down_count = vec![0; len];
+
+        sortino(
+            &INPUT_RETURNS,
+            opt_period,
+            0.0,
+            252.0,
+            &mut output_sortino,
+            &mut output_sum,
+            &mut output_sum_down_sq,
+            &mut output_down_count,
+        )
+        .unwrap();
+
+        // First 9 values should be NaN
+        for value in output_sortino.iter().take(9) {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            4.729_216_146_688_109,
+            1.557_325_642_855_319_7,
+            5.119_211_149_712_53,
+            1.048_000_517_531_247_5,
+            0.349_333_505_843_748_4,
+            6.396_021_490_668_311_5,
+            2.935_894_656_604_580_3,
+            5.382_473_537_108_399,
+            6.632_828_462_113_371,
+            3.183_757_661_814_418_3,
+            4.245_010_215_752_558,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_sortino[i + 9], expected, epsilon = 0.00001);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut prev_sum = output_sum[9];
+        let mut prev_sum_down_sq = output_sum_down_sq[9];
+        let mut prev_down_count = output_down_count[9];
+        for i in 10..len {
+            let (sortino, new_sum, new_sum_down_sq, new_down_count) = sortino_inc(
+                INPUT_RETURNS[i],
+                prev_sum,
+                prev_sum_down_sq,
+                prev_down_count,
+                INPUT_RETURNS[i - opt_period],
+                opt_period,
+                0.0,
+                252.0,
+            )
+            .unwrap();
+            assert_relative_eq!(sortino, output_sortino[i], epsilon = 0.00001);
+            prev_sum = new_sum;
+            prev_sum_down_sq = new_sum_down_sq;
+            prev_down_count = new_down_count;
+        }
+
+        // Full-series ratio
+        let ratio = sortino_ratio(&INPUT_RETURNS, 0.0, 252.0).unwrap();
+        assert_relative_eq!(ratio, 4.474_355_928_308_305, epsilon = 0.00001);
+
+        // No return below the MAR leaves the ratio undefined
+        assert!(
+            sortino_ratio(&[0.01, 0.02, 0.005], 0.0, 252.0)
+                .unwrap()
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn test_sortino_shortfalls_leave_window() {
+        // Small shortfalls slide out of the window one by one, leaving only gains behind
+        let input_returns = [
+            -1.0e-5, -3.0e-6, -7.0e-6, 2.0e-4, 1.0e-4, 3.0e-4, 2.0e-4, -1.0e-9, 1.0e-4, 1.0e-4,
+            1.0e-4,
+        ];
+        let opt_period = 3;
+        let len = input_returns.len();
+        let mut output_sortino = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_down_sq = vec![0.0; len];
+        let mut output_down_count = vec![0; len];
+        sortino(
+            &input_returns,
+            opt_period,
+            0.0,
+            252.0,
+            &mut output_sortino,
+            &mut output_sum,
+            &mut output_sum_down_sq,
+            &mut output_down_count,
+        )
+        .unwrap();
+
+        for i in opt_period - 1..len {
+            let window = &input_returns[i + 1 - opt_period..=i];
+            let expected = sortino_ratio(window, 0.0, 252.0).unwrap();
+            if window.iter().all(|r| *r >= 0.0) {
+                assert_eq!(output_down_count[i], 0);
+                assert!(output_sortino[i].is_nan());
+                assert!(expected.is_nan());
+            } else {
+                // A shortfall of 1e-9 squares to 1e-18, far below the machine epsilon
+                assert!(output_sortino[i].is_finite());
+                assert_relative_eq!(output_sortino[i], expected, max_relative = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sortino_indicator() {
+        let opt_period = 10;
+        let len = INPUT_RETURNS.len();
+        let mut output_sortino = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_down_sq = vec![0.0; len];
+        let mut output_down_count = vec![0; len];
+        sortino(
+            &INPUT_RETURNS,
+            opt_period,
+            0.0,
+            252.0,
+            &mut output_sortino,
+            &mut output_sum,
+            &mut output_sum_down_sq,
+            &mut output_down_count,
+        )
+        .unwrap();
+
+        let mut indicator = Sortino::new(opt_period, 0.0, 252.0).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &value) in INPUT_RETURNS.iter().enumerate() {
+            let sortino = indicator.update(&Bar::from(value)).unwrap();
+            if i < lookback {
+                assert!(sortino.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(sortino, output_sortino[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
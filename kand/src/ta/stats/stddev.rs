@@ -1,4 +1,9 @@
-use crate::{KandError, TAFloat, ta::stats::var};
+use crate::{
+    KandError, TAFloat,
+    indicator::Indicator,
+    ta::stats::var::{self, Var},
+    types::Bar,
+};
 
 /// Calculates the lookback period required for Standard Deviation calculation.
 ///
@@ -128,6 +133,109 @@ pub fn stddev(
     Ok(())
 }
 
+/// Calculates Standard Deviation for a price series, returning the Welford state.
+///
+/// Same values as [`stddev`], but the state outputs are the rolling mean and sum of squared
+/// deviations (M2) that seed [`stddev_inc_welford`], as returned by [`var::var_welford`].
+///
+/// # Mathematical Formula
+/// ```text
+/// STDDEV = sqrt(VAR)
+/// where:
+/// VAR = sum((x - mean)^2) / n
+/// mean = sum(x) / n
+/// ```
+/// Where:
+/// - x: Each value in the dataset
+/// - n: Time period
+///
+/// # Arguments
+/// * `input_prices` - Array of input values to calculate Standard Deviation
+/// * `opt_period` - The time period for calculation (must be >= 2)
+/// * `output_stddev` - Array to store calculated Standard Deviation values
+/// * `output_mean` - Array to store the rolling mean values
+/// * `output_m2` - Array to store the rolling sum of squared deviations (M2)
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length <= lookback period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::stddev;
+/// let input = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// let period = 3;
+/// let mut output_stddev = vec![0.0; 5];
+/// let mut output_mean = vec![0.0; 5];
+/// let mut output_m2 = vec![0.0; 5];
+///
+/// stddev::stddev_welford(
+///     &input,
+///     period,
+///     &mut output_stddev,
+///     &mut output_mean,
+///     &mut output_m2,
+/// )
+/// .unwrap();
+/// ```
+pub fn stddev_welford(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    output_stddev: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+        if output_stddev.len() != len || output_mean.len() != len || output_m2.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate variance first
+    var::var_welford(
+        input_prices,
+        opt_period,
+        output_stddev,
+        output_mean,
+        output_m2,
+    )?;
+
+    // Take square root to get standard deviation
+    for value in output_stddev.iter_mut().take(len).skip(lookback) {
+        *value = value.sqrt();
+    }
+
+    Ok(())
+}
+
 /// Calculates the latest Standard Deviation value incrementally.
 ///
 /// This function provides an optimized way to calculate the latest Standard Deviation value
@@ -150,8 +258,14 @@ pub fn stddev(
 /// * Returns `KandError::InvalidParameter` if period is less than 2
 /// * Returns `KandError::NaNDetected` if any input value is NaN (when `check-nan` enabled)
 ///
+/// # Migration
+/// The running sums lose precision when values are large relative to their spread. Switch to
+/// [`stddev_welford`] and [`stddev_inc_welford`], which carry the mean and M2 instead, as
+/// described for [`var::var_inc`].
+///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::stats::stddev;
 /// let (stddev, new_sum, new_sum_sq) = stddev::stddev_inc(
 ///     10.0,   // new price
@@ -162,6 +276,7 @@ pub fn stddev(
 /// )
 /// .unwrap();
 /// ```
+#[deprecated(note = "sum-based state is numerically unstable; use `stddev_inc_welford`")]
 pub fn stddev_inc(
     input_price: TAFloat,
     prev_sum: TAFloat,
@@ -169,6 +284,7 @@ pub fn stddev_inc(
     input_old_price: TAFloat,
     opt_period: usize,
 ) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[allow(deprecated)]
     let (var, new_sum, new_sum_sq) = var::var_inc(
         input_price,
         prev_sum,
@@ -180,6 +296,110 @@ pub fn stddev_inc(
     Ok((var.sqrt(), new_sum, new_sum_sq))
 }
 
+/// Calculates the latest Standard Deviation value incrementally from the Welford state.
+///
+/// This function provides an optimized way to calculate the latest Standard Deviation value
+/// by sliding the previous mean and sum of squared deviations (M2), avoiding recalculation of the
+/// entire series.
+///
+/// # Arguments
+/// * `input_price` - The latest price value to include in calculation
+/// * `prev_mean` - Previous mean of values in the period
+/// * `prev_m2` - Previous sum of squared deviations from the mean in the period
+/// * `input_old_price` - Price value to remove from the period
+/// * `opt_period` - The time period for calculation (must be >= 2)
+///
+/// Seed `prev_mean` and `prev_m2` from the `output_mean` and `output_m2` of [`stddev_welford`].
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing:
+///   - Latest Standard Deviation value
+///   - New mean
+///   - New sum of squared deviations (M2)
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when `check-nan` enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::stddev;
+/// // Window [5, 8, 12] slides to [8, 12, 10]
+/// let (stddev, new_mean, new_m2) = stddev::stddev_inc_welford(
+///     10.0,       // new price
+///     25.0 / 3.0, // previous mean
+///     74.0 / 3.0, // previous M2
+///     5.0,        // old price to remove
+///     3,          // period
+/// )
+/// .unwrap();
+/// assert!((stddev - (8.0_f64 / 3.0).sqrt()).abs() < 1e-12);
+/// ```
+pub fn stddev_inc_welford(
+    input_price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    input_old_price: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    let (var, new_mean, new_m2) =
+        var::var_inc_welford(input_price, prev_mean, prev_m2, input_old_price, opt_period)?;
+
+    Ok((var.sqrt(), new_mean, new_m2))
+}
+
+/// Streaming rolling standard deviation built on [`Var`].
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`stddev`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stddev {
+    var: Var,
+}
+
+impl Stddev {
+    /// Creates a streaming rolling standard deviation.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            var: Var::new(opt_period)?,
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the rolling standard deviation for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        Ok(self.var.update_value(input_price)?.sqrt())
+    }
+}
+
+impl Indicator for Stddev {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.var.lookback()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.var.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.var.reset();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -187,6 +407,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_stddev_calculation() {
         let input_close = vec![
             35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
@@ -252,4 +473,97 @@ fn test_stddev_calculation() {
             prev_sum_sq = new_sum_sq;
         }
     }
+
+    #[test]
+    fn test_stddev_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let mut output_sum = vec![0.0; input_price.len()];
+        let mut output_sum_sq = vec![0.0; input_price.len()];
+        let mut output_stddev = vec![0.0; input_price.len()];
+        stddev(
+            &input_price,
+            opt_period,
+            &mut output_stddev,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        let mut indicator = Stddev::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_stddev[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+
+    #[test]
+    fn test_stddev_welford() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+        ];
+        let opt_period = 5;
+        let len = input_close.len();
+        let mut output_stddev = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        let mut expected_stddev = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+
+        stddev_welford(
+            &input_close,
+            opt_period,
+            &mut output_stddev,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+        stddev(
+            &input_close,
+            opt_period,
+            &mut expected_stddev,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        let lookback = lookback(opt_period).unwrap();
+        let mut prev_mean = output_mean[lookback];
+        let mut prev_m2 = output_m2[lookback];
+        for i in (lookback + 1)..len {
+            let (stddev, new_mean, new_m2) = stddev_inc_welford(
+                input_close[i],
+                prev_mean,
+                prev_m2,
+                input_close[i - opt_period],
+                opt_period,
+            )
+            .unwrap();
+            assert_relative_eq!(stddev, expected_stddev[i], epsilon = 1e-8);
+            assert_relative_eq!(
+                new_mean * opt_period as TAFloat,
+                output_sum[i],
+                epsilon = 1e-6
+            );
+            prev_mean = new_mean;
+            prev_m2 = new_m2;
+        }
+    }
 }
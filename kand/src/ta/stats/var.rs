@@ -1,4 +1,7 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use super::moments::{self, RollingMoments};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
 
 /// Calculates the lookback period required for Variance calculation.
 ///
@@ -48,10 +51,8 @@ pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
 /// - n is the time period
 ///
 /// # Calculation Steps
-/// 1. Calculate sum and sum of squares for initial period
-/// 2. Calculate mean for the period
-/// 3. Apply variance formula using sum and sum of squares
-/// 4. Update sums incrementally for subsequent periods
+/// 1. Compute the variance with [`var_welford`], which stays accurate for large values
+/// 2. Convert its rolling mean and M2 into the running sum and sum of squares
 ///
 /// # Arguments
 /// * `input_prices` - Array of input price values
@@ -95,6 +96,91 @@ pub fn var(
     output_var: &mut [TAFloat],
     output_sum: &mut [TAFloat],
     output_sum_sq: &mut [TAFloat],
+) -> Result<(), KandError> {
+    var_welford(
+        input_prices,
+        opt_period,
+        output_var,
+        output_sum,
+        output_sum_sq,
+    )?;
+
+    // Convert mean and M2 in place: sum = n * mean, sum_sq = M2 + sum * mean
+    let period_t = opt_period as TAFloat;
+    for (sum, sum_sq) in output_sum.iter_mut().zip(output_sum_sq.iter_mut()) {
+        let mean = *sum;
+        *sum = mean * period_t;
+        *sum_sq = sum.mul_add(mean, *sum_sq);
+    }
+
+    Ok(())
+}
+
+/// Calculates Variance (VAR) for an entire price series, returning the Welford state.
+///
+/// # Description
+/// Same values as [`var`], but the state outputs are the rolling mean and sum of squared
+/// deviations (M2) that seed [`var_inc_welford`], instead of the running sums of [`var_inc`].
+///
+/// # Mathematical Formula
+/// ```text
+/// VAR = sum((x - mean)^2) / n
+/// ```
+/// Where:
+/// - x represents each value in the dataset
+/// - mean is the average of all values in the period (sum(x) / n)
+/// - n is the time period
+///
+/// # Calculation Steps
+/// 1. Accumulate the mean and sum of squared deviations (M2) of the first period
+/// 2. Slide the window with Welford's update, using compensated sums (see [`RollingMoments`])
+/// 3. Divide M2 by the period, clamping at zero
+///
+/// Tracking M2 instead of the sum of squares keeps the result accurate for large values such as
+/// prices around 1e5, where `sum_sq - sum * mean` cancels catastrophically.
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `opt_period` - The time period for Variance calculation (must be >= 2)
+/// * `output_var` - Array to store calculated Variance values
+/// * `output_mean` - Array to store the rolling mean values
+/// * `output_m2` - Array to store the rolling sum of squared deviations (M2)
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::var;
+/// let input_prices = vec![2.0, 4.0, 6.0, 8.0, 10.0];
+/// let period = 3;
+/// let mut output_var = vec![0.0; 5];
+/// let mut output_mean = vec![0.0; 5];
+/// let mut output_m2 = vec![0.0; 5];
+///
+/// var::var_welford(
+///     &input_prices,
+///     period,
+///     &mut output_var,
+///     &mut output_mean,
+///     &mut output_m2,
+/// )
+/// .unwrap();
+/// // First (period-1) values are NaN, followed by calculated Variance values
+/// ```
+pub fn var_welford(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    output_var: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
 ) -> Result<(), KandError> {
     let len = input_prices.len();
     let lookback = lookback(opt_period)?;
@@ -107,7 +193,7 @@ pub fn var(
         }
 
         // Length consistency check
-        if output_var.len() != len || output_sum.len() != len || output_sum_sq.len() != len {
+        if output_var.len() != len || output_mean.len() != len || output_m2.len() != len {
             return Err(KandError::LengthMismatch);
         }
 
@@ -133,38 +219,27 @@ pub fn var(
     }
 
     // Calculate initial values
-    let mut sum = 0.0;
-    let mut sum_sq = 0.0;
+    let mut moments = RollingMoments::new();
     for val in input_prices.iter().take(opt_period) {
-        sum += *val;
-        sum_sq += *val * *val;
+        moments.push(*val);
     }
-
-    let period_t = opt_period as TAFloat;
-    let mean = sum / period_t;
-    output_var[lookback] = sum.mul_add(-mean, sum_sq) / period_t;
-    output_sum[lookback] = sum;
-    output_sum_sq[lookback] = sum_sq;
+    output_var[lookback] = moments.variance();
+    output_mean[lookback] = moments.mean();
+    output_m2[lookback] = moments.m2();
 
     // Calculate remaining VAR values incrementally
     for i in opt_period..len {
-        let old_val = input_prices[i - opt_period];
-        let new_val = input_prices[i];
-
-        sum = sum - old_val + new_val;
-        sum_sq = new_val.mul_add(new_val, old_val.mul_add(-old_val, sum_sq));
-
-        let mean = sum / period_t;
-        output_var[i] = sum.mul_add(-mean, sum_sq) / period_t;
-        output_sum[i] = sum;
-        output_sum_sq[i] = sum_sq;
+        moments.replace(input_prices[i], input_prices[i - opt_period]);
+        output_var[i] = moments.variance();
+        output_mean[i] = moments.mean();
+        output_m2[i] = moments.m2();
     }
 
     // Fill initial values with NAN
     for i in 0..lookback {
         output_var[i] = TAFloat::NAN;
-        output_sum[i] = TAFloat::NAN;
-        output_sum_sq[i] = TAFloat::NAN;
+        output_mean[i] = TAFloat::NAN;
+        output_m2[i] = TAFloat::NAN;
     }
 
     Ok(())
@@ -190,8 +265,15 @@ pub fn var(
 /// * Returns `KandError::InvalidParameter` if period is less than 2
 /// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
 ///
+/// # Migration
+/// `sum_sq - sum * mean` cancels catastrophically when values are large relative to their spread,
+/// and the running sums drift over long streams. Switch to [`var_welford`] and
+/// [`var_inc_welford`], which carry the mean and M2 instead:
+/// `(prev_sum, prev_sum_sq)` becomes `(prev_sum / n, prev_sum_sq - prev_sum * prev_sum / n)`.
+///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::stats::var;
 /// let (var_value, new_sum, new_sum_sq) = var::var_inc(
 ///     10.0,  // new price
@@ -202,6 +284,7 @@ pub fn var(
 /// )
 /// .unwrap();
 /// ```
+#[deprecated(note = "sum-based state is numerically unstable; use `var_inc_welford`")]
 pub fn var_inc(
     input_price: TAFloat,
     prev_sum: TAFloat,
@@ -242,6 +325,152 @@ pub fn var_inc(
     Ok((var, new_sum, new_sum_sq))
 }
 
+/// Calculates the latest Variance value incrementally from the Welford state.
+///
+/// # Description
+/// This function efficiently updates the Variance by sliding the previous mean and sum of squared
+/// deviations (M2) with Welford's update, removing the oldest value and adding the newest value to
+/// the calculation window in O(1).
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `prev_mean` - Previous mean of values in the period
+/// * `prev_m2` - Previous sum of squared deviations from the mean in the period
+/// * `input_old_price` - Oldest price value to remove from calculation
+/// * `opt_period` - The time period for Variance calculation (must be >= 2)
+///
+/// Seed `prev_mean` and `prev_m2` from the `output_mean` and `output_m2` of [`var_welford`].
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (variance, `new_mean`, `new_m2`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::var;
+/// // Window [5, 8, 12] slides to [8, 12, 10]
+/// let (var_value, new_mean, new_m2) = var::var_inc_welford(
+///     10.0,         // new price
+///     25.0 / 3.0,   // previous mean
+///     74.0 / 3.0,   // previous M2
+///     5.0,          // price to remove
+///     3,            // period
+/// )
+/// .unwrap();
+/// assert!((new_mean - 10.0).abs() < 1e-12);
+/// assert!((var_value - 8.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn var_inc_welford(
+    input_price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    input_old_price: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan()
+            || prev_mean.is_nan()
+            || prev_m2.is_nan()
+            || input_old_price.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (new_mean, new_m2) =
+        moments::slide(prev_mean, prev_m2, input_price, input_old_price, opt_period);
+
+    Ok((new_m2 / opt_period as TAFloat, new_mean, new_m2))
+}
+
+/// Streaming rolling variance that keeps the last `opt_period` values and their [`RollingMoments`].
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`var`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Var {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+    moments: RollingMoments,
+}
+
+impl Var {
+    /// Creates a streaming rolling variance.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+            moments: RollingMoments::new(),
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the rolling variance for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() > self.opt_period {
+            let old_price = self.window.pop_front().unwrap_or(0.0);
+            self.moments.replace(input_price, old_price);
+        } else {
+            self.moments.push(input_price);
+        }
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(self.moments.variance())
+    }
+}
+
+impl Indicator for Var {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.moments = RollingMoments::new();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -249,6 +478,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_var_calculation() {
         let input_close = vec![
             35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
@@ -316,4 +546,85 @@ fn test_var_calculation() {
             prev_sum_sq = new_sum_sq;
         }
     }
+
+    #[test]
+    fn test_var_large_values() {
+        // Prices near 1e8 with a unit spread: Σx² - Σx·mean loses every significant digit here
+        let input_price: Vec<TAFloat> = (0..1000)
+            .map(|i| 100_000_000.0 + (i % 4) as TAFloat)
+            .collect();
+        let opt_period = 4;
+        let len = input_price.len();
+        let mut output_var = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        var_welford(
+            &input_price,
+            opt_period,
+            &mut output_var,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+
+        // Every window is a rotation of [0, 1, 2, 3] above the base price
+        for i in 3..len {
+            assert_relative_eq!(output_var[i], 1.25, epsilon = 1e-9);
+            assert_relative_eq!(output_mean[i], 100_000_001.5, epsilon = 1e-9);
+        }
+
+        let mut prev_mean = output_mean[3];
+        let mut prev_m2 = output_m2[3];
+        for i in 4..len {
+            let (new_var, new_mean, new_m2) = var_inc_welford(
+                input_price[i],
+                prev_mean,
+                prev_m2,
+                input_price[i - 4],
+                opt_period,
+            )
+            .unwrap();
+            assert_relative_eq!(new_var, 1.25, epsilon = 1e-9);
+            prev_mean = new_mean;
+            prev_m2 = new_m2;
+        }
+    }
+
+    #[test]
+    fn test_var_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let mut output_sum = vec![0.0; input_price.len()];
+        let mut output_sum_sq = vec![0.0; input_price.len()];
+        let mut output_var = vec![0.0; input_price.len()];
+        var(
+            &input_price,
+            opt_period,
+            &mut output_var,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        let mut indicator = Var::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_var[i], epsilon = 0.00001);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
 }
@@ -0,0 +1,825 @@
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat, TAInt, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling trade statistics.
+///
+/// The lookback period represents the number of trades needed before the first valid output
+/// can be calculated. For rolling trade statistics, this equals the period minus 1.
+///
+/// # Arguments
+/// * `opt_period` - The number of trades in the rolling window (must be >= 1)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 1
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let lookback = winrate::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 1 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Running totals of a set of trade returns.
+///
+/// Trades are pushed and popped one at a time, so the same state serves a whole trade list and a
+/// sliding window over it. A trade with a positive return is a win, a negative one a loss, and a
+/// zero return counts towards the number of trades only. Losses are accumulated as positive
+/// magnitudes, so [`TradeStats::avg_loss`] is positive.
+///
+/// Ratios whose denominator is zero, such as the average loss of a window without losers, are NaN.
+/// The one exception is [`TradeStats::profit_factor`], which is +inf when there are winners but no
+/// losers.
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate::TradeStats;
+///
+/// let mut stats = TradeStats::new();
+/// for trade in [0.04, -0.02, 0.02, -0.01] {
+///     stats.push(trade);
+/// }
+/// assert_eq!(stats.win_rate(), 0.5);
+/// assert!((stats.profit_factor() - 2.0).abs() < 1e-12);
+/// assert!((stats.expectancy() - 0.0075).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TradeStats {
+    count: usize,
+    wins: usize,
+    losses: usize,
+    sum_win: TAFloat,
+    sum_loss: TAFloat,
+}
+
+impl TradeStats {
+    /// Creates empty trade statistics.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            wins: 0,
+            losses: 0,
+            sum_win: 0.0,
+            sum_loss: 0.0,
+        }
+    }
+
+    /// Adds a trade return.
+    pub fn push(&mut self, trade: TAFloat) {
+        self.count += 1;
+        if trade > 0.0 {
+            self.wins += 1;
+            self.sum_win += trade;
+        } else if trade < 0.0 {
+            self.losses += 1;
+            self.sum_loss -= trade;
+        }
+    }
+
+    /// Removes a trade return that was previously pushed.
+    pub fn pop(&mut self, trade: TAFloat) {
+        self.count = self.count.saturating_sub(1);
+        if trade > 0.0 {
+            self.wins = self.wins.saturating_sub(1);
+            // Reset exactly once empty so rounding residue does not linger
+            self.sum_win = if self.wins == 0 {
+                0.0
+            } else {
+                self.sum_win - trade
+            };
+        } else if trade < 0.0 {
+            self.losses = self.losses.saturating_sub(1);
+            self.sum_loss = if self.losses == 0 {
+                0.0
+            } else {
+                self.sum_loss + trade
+            };
+        }
+    }
+
+    /// Number of trades.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Number of winning trades.
+    #[must_use]
+    pub const fn wins(&self) -> usize {
+        self.wins
+    }
+
+    /// Number of losing trades.
+    #[must_use]
+    pub const fn losses(&self) -> usize {
+        self.losses
+    }
+
+    /// Fraction of trades that won, between 0 and 1.
+    #[must_use]
+    pub fn win_rate(&self) -> TAFloat {
+        ratio(self.wins as TAFloat, self.count as TAFloat)
+    }
+
+    /// Average return of the winning trades.
+    #[must_use]
+    pub fn avg_win(&self) -> TAFloat {
+        ratio(self.sum_win, self.wins as TAFloat)
+    }
+
+    /// Average magnitude of the losing trades (positive).
+    #[must_use]
+    pub fn avg_loss(&self) -> TAFloat {
+        ratio(self.sum_loss, self.losses as TAFloat)
+    }
+
+    /// Gross profit over gross loss.
+    ///
+    /// Returns +inf when there are winning but no losing trades, and NaN when there are neither.
+    #[must_use]
+    pub fn profit_factor(&self) -> TAFloat {
+        if self.sum_loss <= 0.0 && self.sum_win > 0.0 {
+            return TAFloat::INFINITY;
+        }
+        ratio(self.sum_win, self.sum_loss)
+    }
+
+    /// Average return per trade, winners and losers together.
+    #[must_use]
+    pub fn expectancy(&self) -> TAFloat {
+        ratio(self.sum_win - self.sum_loss, self.count as TAFloat)
+    }
+
+    /// Average win over average loss.
+    #[must_use]
+    pub fn payoff_ratio(&self) -> TAFloat {
+        ratio(self.avg_win(), self.avg_loss())
+    }
+}
+
+impl Default for TradeStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `numerator / denominator`, NaN when the denominator is zero.
+fn ratio(numerator: TAFloat, denominator: TAFloat) -> TAFloat {
+    if denominator > 0.0 {
+        numerator / denominator
+    } else {
+        TAFloat::NAN
+    }
+}
+
+/// Extracts closed-trade returns from a position signal and a price series.
+///
+/// # Description
+/// The signal gives the position held after each bar's price: positive for long, negative for
+/// short and zero for flat; only its sign matters. A trade opens at the price of the bar where the
+/// position becomes non-zero and closes at the price of the bar where it changes again, so a flip
+/// from long to short closes one trade and opens the next on the same bar. A trade still open at
+/// the end of the series is not reported.
+///
+/// The trade returns are written to the front of `output_trade_return` in the order the trades
+/// close, and the remaining elements are set to NaN.
+///
+/// # Mathematical Formula
+/// ```text
+/// TradeReturn = Side * (ExitPrice - EntryPrice) / EntryPrice
+/// ```
+///
+/// # Arguments
+/// * `input_signal` - Array of positions per bar (sign gives long, short or flat)
+/// * `input_price` - Array of prices at which positions change
+/// * `output_trade_return` - Array to store the closed-trade returns
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The number of closed trades written on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if array lengths don't match
+/// * Returns `KandError::NaNDetected` if any price is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let input_signal = vec![0, 1, 1, -1, -1, 0];
+/// let input_price = vec![100.0, 100.0, 104.0, 110.0, 105.0, 99.0];
+/// let mut output_trade_return = vec![0.0; 6];
+///
+/// let count =
+///     winrate::trade_returns(&input_signal, &input_price, &mut output_trade_return).unwrap();
+/// assert_eq!(count, 2);
+/// assert!((output_trade_return[0] - 0.1).abs() < 1e-12); // long 100 -> 110
+/// assert!((output_trade_return[1] - 0.1).abs() < 1e-12); // short 110 -> 99
+/// ```
+pub fn trade_returns(
+    input_signal: &[TAInt],
+    input_price: &[TAFloat],
+    output_trade_return: &mut [TAFloat],
+) -> Result<usize, KandError> {
+    let len = input_signal.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_price.len() != len || output_trade_return.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for price in input_price {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut count = 0;
+    let mut side: TAInt = 0;
+    let mut entry: TAFloat = 0.0;
+    for i in 0..len {
+        let position = input_signal[i].signum();
+        if position == side {
+            continue;
+        }
+        if side != 0 {
+            output_trade_return[count] = side as TAFloat * (input_price[i] - entry) / entry;
+            count += 1;
+        }
+        side = position;
+        entry = input_price[i];
+    }
+
+    for value in output_trade_return.iter_mut().skip(count) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(count)
+}
+
+/// Calculates rolling trade statistics over a series of trade returns.
+///
+/// # Description
+/// Each output covers the last `opt_period` trades: the win rate, the average win and average
+/// loss, the profit factor, the expectancy and the payoff ratio. Position sizing from recent
+/// performance, for example with [`crate::stats::kelly`], reads these rolling values.
+///
+/// # Mathematical Formula
+/// ```text
+/// WinRate = Wins / Trades
+/// AvgWin = Sum(Winning returns) / Wins
+/// AvgLoss = Sum(|Losing returns|) / Losses
+/// ProfitFactor = Sum(Winning returns) / Sum(|Losing returns|)
+/// Expectancy = Sum(Returns) / Trades
+/// PayoffRatio = AvgWin / AvgLoss
+/// ```
+///
+/// A window with winners but no losers has a profit factor of +inf; the other ratios, and the
+/// profit factor of a window with neither, are NaN when their denominator is zero.
+///
+/// # Calculation Steps
+/// 1. Accumulate the first `opt_period` trades into [`TradeStats`]
+/// 2. For each further trade, push it and pop the trade leaving the window
+/// 3. Read the ratios from the running totals
+/// 4. Fill initial values before lookback period with NaN
+///
+/// # Arguments
+/// * `input_trades` - Array of per-trade returns (e.g. from [`trade_returns`])
+/// * `opt_period` - The number of trades in the rolling window (must be >= 1)
+/// * `output_win_rate` - Array to store the fraction of winning trades
+/// * `output_avg_win` - Array to store the average winning return
+/// * `output_avg_loss` - Array to store the average losing return magnitude
+/// * `output_profit_factor` - Array to store the gross profit over gross loss
+/// * `output_expectancy` - Array to store the average return per trade
+/// * `output_payoff_ratio` - Array to store the average win over average loss
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 1
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let input_trades = vec![0.04, -0.02, 0.02, -0.01, 0.03];
+/// let mut output_win_rate = vec![0.0; 5];
+/// let mut output_avg_win = vec![0.0; 5];
+/// let mut output_avg_loss = vec![0.0; 5];
+/// let mut output_profit_factor = vec![0.0; 5];
+/// let mut output_expectancy = vec![0.0; 5];
+/// let mut output_payoff_ratio = vec![0.0; 5];
+///
+/// winrate::winrate(
+///     &input_trades,
+///     4,
+///     &mut output_win_rate,
+///     &mut output_avg_win,
+///     &mut output_avg_loss,
+///     &mut output_profit_factor,
+///     &mut output_expectancy,
+///     &mut output_payoff_ratio,
+/// )
+/// .unwrap();
+/// assert_eq!(output_win_rate[3], 0.5);
+/// assert!((output_profit_factor[4] - 5.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn winrate(
+    input_trades: &[TAFloat],
+    opt_period: usize,
+    output_win_rate: &mut [TAFloat],
+    output_avg_win: &mut [TAFloat],
+    output_avg_loss: &mut [TAFloat],
+    output_profit_factor: &mut [TAFloat],
+    output_expectancy: &mut [TAFloat],
+    output_payoff_ratio: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_trades.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_win_rate.len() != len
+            || output_avg_win.len() != len
+            || output_avg_loss.len() != len
+            || output_profit_factor.len() != len
+            || output_expectancy.len() != len
+            || output_payoff_ratio.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_trades {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut stats = TradeStats::new();
+    for i in 0..len {
+        stats.push(input_trades[i]);
+        if i >= opt_period {
+            stats.pop(input_trades[i - opt_period]);
+        }
+        if i < lookback {
+            output_win_rate[i] = TAFloat::NAN;
+            output_avg_win[i] = TAFloat::NAN;
+            output_avg_loss[i] = TAFloat::NAN;
+            output_profit_factor[i] = TAFloat::NAN;
+            output_expectancy[i] = TAFloat::NAN;
+            output_payoff_ratio[i] = TAFloat::NAN;
+            continue;
+        }
+        output_win_rate[i] = stats.win_rate();
+        output_avg_win[i] = stats.avg_win();
+        output_avg_loss[i] = stats.avg_loss();
+        output_profit_factor[i] = stats.profit_factor();
+        output_expectancy[i] = stats.expectancy();
+        output_payoff_ratio[i] = stats.payoff_ratio();
+    }
+
+    Ok(())
+}
+
+/// Slides rolling trade statistics forward by one trade.
+///
+/// # Description
+/// Adds `input_trade` to the [`TradeStats`] and removes `input_old_trade`, the trade leaving the
+/// window, in place. While the window is still filling, call [`TradeStats::push`] instead. The
+/// other ratios are read from the updated state.
+///
+/// # Arguments
+/// * `input_trade` - The newest trade return
+/// * `input_old_trade` - The trade return leaving the window
+/// * `stats` - Rolling trade statistics, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The new win rate on success
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If either input is NaN (with "`check-nan`")
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate::{self, TradeStats};
+///
+/// let mut stats = TradeStats::new();
+/// for trade in [0.04, -0.02, 0.02, -0.01] {
+///     stats.push(trade);
+/// }
+/// let win_rate = winrate::winrate_inc(0.03, 0.04, &mut stats).unwrap();
+/// assert_eq!(win_rate, 0.5);
+/// assert!((stats.profit_factor() - 5.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn winrate_inc(
+    input_trade: TAFloat,
+    input_old_trade: TAFloat,
+    stats: &mut TradeStats,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_trade.is_nan() || input_old_trade.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    stats.push(input_trade);
+    stats.pop(input_old_trade);
+    Ok(stats.win_rate())
+}
+
+/// Calculates the trade statistics of an entire list of trade returns.
+///
+/// # Arguments
+/// * `input_trades` - Array of per-trade returns
+///
+/// # Returns
+/// * `Result<TradeStats, KandError>` - The statistics of all trades
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let stats = winrate::trade_stats(&[0.04, -0.02, 0.02, -0.01]).unwrap();
+/// assert_eq!(stats.count(), 4);
+/// assert!((stats.payoff_ratio() - 2.0).abs() < 1e-12);
+/// ```
+pub fn trade_stats(input_trades: &[TAFloat]) -> Result<TradeStats, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if input_trades.is_empty() {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for value in input_trades {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut stats = TradeStats::new();
+    for &trade in input_trades {
+        stats.push(trade);
+    }
+    Ok(stats)
+}
+
+/// Streaming rolling trade statistics that keep the last `opt_period` trades.
+///
+/// Reads [`Bar::close`] as the trade return and outputs the [`TradeStats`] of the window. Folding
+/// [`Indicator::update`] over a trade list reproduces [`winrate`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::winrate::WinRate};
+///
+/// let mut winrate = WinRate::new(3).unwrap();
+/// for trade in [0.04, -0.02, 0.02, -0.01] {
+///     let stats = winrate.update_value(trade).unwrap();
+/// }
+/// assert!(winrate.is_ready());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WinRate {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+    stats: TradeStats,
+}
+
+impl WinRate {
+    /// Creates streaming rolling trade statistics.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of trades in the rolling window (must be >= 1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 1
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+            stats: TradeStats::new(),
+        })
+    }
+
+    /// Feeds a raw trade return instead of a [`Bar`] and returns the statistics of the window.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_trade` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_trade: TAFloat) -> Result<TradeStats, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_trade.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_trade);
+        if self.window.len() > self.opt_period {
+            let old = self.window.pop_front().unwrap_or(0.0);
+            winrate_inc(input_trade, old, &mut self.stats)?;
+        } else {
+            self.stats.push(input_trade);
+        }
+        Ok(self.stats)
+    }
+}
+
+impl Indicator for WinRate {
+    type Output = TradeStats;
+
+    fn update(&mut self, input: &Bar) -> Result<TradeStats, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.stats = TradeStats::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_TRADES: [TAFloat; 14] = [
+        0.032, -0.015, 0.021, -0.008, 0.0, 0.045, -0.027, -0.012, 0.018, 0.009, -0.021, 0.037,
+        -0.005, 0.014,
+    ];
+
+    fn assert_nan_eq(actual: TAFloat, expected: TAFloat) {
+        if expected.is_nan() {
+            assert!(actual.is_nan());
+        } else {
+            assert_relative_eq!(actual, expected, epsilon = 0.000_000_1);
+        }
+    }
+
+    #[test]
+    fn test_winrate_calculation() {
+        let opt_period = 5;
+        let len = INPUT_TRADES.len();
+        let mut output_win_rate = vec![0.0; len];
+        let mut output_avg_win = vec![0.0; len];
+        let mut output_avg_loss = vec![0.0; len];
+        let mut output_profit_factor = vec![0.0; len];
+        let mut output_expectancy = vec![0.0; len];
+        let mut output_payoff_ratio = vec![0.0; len];
+
+        winrate(
+            &INPUT_TRADES,
+            opt_period,
+            &mut output_win_rate,
+            &mut output_avg_win,
+            &mut output_avg_loss,
+            &mut output_profit_factor,
+            &mut output_expectancy,
+            &mut output_payoff_ratio,
+        )
+        .unwrap();
+
+        for i in 0..4 {
+            assert!(output_win_rate[i].is_nan());
+            assert!(output_payoff_ratio[i].is_nan());
+        }
+
+        let expected_win_rate = [0.4, 0.4, 0.4, 0.2, 0.4, 0.6, 0.4, 0.6, 0.6, 0.6];
+        let expected_avg_win = [
+            0.026_500_000_000_000_003,
+            0.033,
+            0.033,
+            0.045,
+            0.031_5,
+            0.023_999_999_999_999_997,
+            0.013_499_999_999_999_998,
+            0.021_333_333_333_333_333,
+            0.021_333_333_333_333_333,
+            0.02,
+        ];
+        let expected_avg_loss = [
+            0.011_5,
+            0.011_5,
+            0.017_5,
+            0.015_666_666_666_666_666,
+            0.019_5,
+            0.019_5,
+            0.02,
+            0.016_5,
+            0.013_000_000_000_000_001,
+            0.013_000_000_000_000_001,
+        ];
+        let expected_profit_factor = [
+            2.304_347_826_086_957,
+            2.869_565_217_391_304_7,
+            1.885_714_285_714_285_7,
+            0.957_446_808_510_638_2,
+            1.615_384_615_384_615_4,
+            1.846_153_846_153_846,
+            0.449_999_999_999_999_96,
+            1.939_393_939_393_939_4,
+            2.461_538_461_538_461_2,
+            2.307_692_307_692_307_5,
+        ];
+        let expected_expectancy = [
+            0.006_000_000_000_000_001,
+            0.008_6,
+            0.006_2,
+            -0.000_400_000_000_000_000_34,
+            0.004_800_000_000_000_000_4,
+            0.006_599_999_999_999_999,
+            -0.006_6,
+            0.006_2,
+            0.007_6,
+            0.006_799_999_999_999_999,
+        ];
+        let expected_payoff_ratio = [
+            2.304_347_826_086_957,
+            2.869_565_217_391_304_7,
+            1.885_714_285_714_285_7,
+            2.872_340_425_531_915,
+            1.615_384_615_384_615_4,
+            1.230_769_230_769_230_6,
+            0.674_999_999_999_999_9,
+            1.292_929_292_929_292_8,
+            1.641_025_641_025_640_7,
+            1.538_461_538_461_538_3,
+        ];
+        for i in 0..expected_win_rate.len() {
+            assert_nan_eq(output_win_rate[i + 4], expected_win_rate[i]);
+            assert_nan_eq(output_avg_win[i + 4], expected_avg_win[i]);
+            assert_nan_eq(output_avg_loss[i + 4], expected_avg_loss[i]);
+            assert_nan_eq(output_profit_factor[i + 4], expected_profit_factor[i]);
+            assert_nan_eq(output_expectancy[i + 4], expected_expectancy[i]);
+            assert_nan_eq(output_payoff_ratio[i + 4], expected_payoff_ratio[i]);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut stats = TradeStats::new();
+        for &trade in &INPUT_TRADES[..opt_period] {
+            stats.push(trade);
+        }
+        for i in opt_period..len {
+            let win_rate =
+                winrate_inc(INPUT_TRADES[i], INPUT_TRADES[i - opt_period], &mut stats).unwrap();
+            assert_relative_eq!(win_rate, output_win_rate[i], epsilon = 0.000_000_1);
+            assert_nan_eq(stats.profit_factor(), output_profit_factor[i]);
+            assert_nan_eq(stats.expectancy(), output_expectancy[i]);
+        }
+
+        // Full-series statistics
+        let stats = trade_stats(&INPUT_TRADES).unwrap();
+        assert_eq!(stats.count(), 14);
+        assert_eq!(stats.wins(), 7);
+        assert_eq!(stats.losses(), 6);
+        assert_relative_eq!(stats.win_rate(), 0.5, epsilon = 0.000_000_1);
+        assert_relative_eq!(stats.profit_factor(), 2.0, epsilon = 0.000_000_1);
+        assert_relative_eq!(
+            stats.expectancy(),
+            0.006_285_714_285_714_286,
+            epsilon = 0.000_000_1
+        );
+
+        // Winners without losers give an unbounded profit factor, no winners or losers none
+        let stats = trade_stats(&[0.01, 0.0, 0.02]).unwrap();
+        assert_eq!(stats.profit_factor(), TAFloat::INFINITY);
+        assert!(stats.payoff_ratio().is_nan());
+        assert!(trade_stats(&[0.0, 0.0]).unwrap().profit_factor().is_nan());
+        assert_eq!(trade_stats(&[-0.01, -0.02]).unwrap().profit_factor(), 0.0);
+    }
+
+    #[test]
+    fn test_trade_returns() {
+        let input_signal = [0, 1, 1, 1, 0, 0, -1, -1, 1, 1, 0, 1];
+        let input_price = [
+            100.0, 101.0, 103.0, 99.0, 104.0, 102.0, 100.0, 96.0, 98.0, 105.0, 103.0, 107.0,
+        ];
+        let mut output_trade_return = vec![0.0; input_signal.len()];
+
+        let count = trade_returns(&input_signal, &input_price, &mut output_trade_return).unwrap();
+
+        // Long 101 -> 104, short 100 -> 98, long 98 -> 103; the last long is still open
+        assert_eq!(count, 3);
+        assert_relative_eq!(output_trade_return[0], 3.0 / 101.0, epsilon = 1e-12);
+        assert_relative_eq!(output_trade_return[1], 0.02, epsilon = 1e-12);
+        assert_relative_eq!(output_trade_return[2], 5.0 / 98.0, epsilon = 1e-12);
+        for value in &output_trade_return[count..] {
+            assert!(value.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_winrate_indicator() {
+        let opt_period = 5;
+        let len = INPUT_TRADES.len();
+        let mut outputs = vec![vec![0.0; len]; 6];
+        let [
+            win_rate,
+            avg_win,
+            avg_loss,
+            profit_factor,
+            expectancy,
+            payoff_ratio,
+        ] = &mut outputs[..]
+        else {
+            unreachable!()
+        };
+        winrate(
+            &INPUT_TRADES,
+            opt_period,
+            win_rate,
+            avg_win,
+            avg_loss,
+            profit_factor,
+            expectancy,
+            payoff_ratio,
+        )
+        .unwrap();
+
+        let mut indicator = WinRate::new(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback(opt_period).unwrap());
+        for (i, &trade) in INPUT_TRADES.iter().enumerate() {
+            let stats = indicator.update(&Bar::from(trade)).unwrap();
+            if i < indicator.lookback() {
+                assert!(!indicator.is_ready());
+                continue;
+            }
+            assert_nan_eq(stats.win_rate(), win_rate[i]);
+            assert_nan_eq(stats.avg_win(), avg_win[i]);
+            assert_nan_eq(stats.avg_loss(), avg_loss[i]);
+            assert_nan_eq(stats.profit_factor(), profit_factor[i]);
+            assert_nan_eq(stats.expectancy(), expectancy[i]);
+            assert_nan_eq(stats.payoff_ratio(), payoff_ratio[i]);
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
@@ -0,0 +1,675 @@
+use std::collections::VecDeque;
+
+use super::{
+    moments::{self, RollingMoments},
+    rogers_satchell::{self, RogersSatchell},
+};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for Yang-Zhang volatility calculation.
+///
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. Every bar in the window needs the previous close for its overnight return,
+/// so this equals the period.
+///
+/// # Arguments
+/// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period) on success, or error on failure
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::yang_zhang;
+/// let period = 20;
+/// let lookback = yang_zhang::lookback(period).unwrap();
+/// assert_eq!(lookback, 20); // one extra bar for the first overnight return
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period)
+}
+
+/// Calculates rolling Yang-Zhang volatility from OHLC prices.
+///
+/// # Description
+/// The Yang-Zhang estimator adds the overnight gap, which the other range estimators ignore, to
+/// a weighted mix of the open-to-close variance and the [`rogers_satchell`] variance. It is
+/// unbiased under drift and opening jumps, and the weight `k` minimizes its estimation error.
+/// The per-bar variance is annualized by `opt_periods_per_year`; pass 1.0 to keep it per bar.
+///
+/// # Mathematical Formula
+/// ```text
+/// o[t] = ln(Open[t] / Close[t-1])
+/// c[t] = ln(Close[t] / Open[t])
+/// k = 0.34 / (1.34 + (n + 1) / (n - 1))
+/// Variance = Var(o) + k * Var(c) + (1 - k) * RogersSatchellVariance
+/// YangZhang = sqrt(Variance * PeriodsPerYear)
+/// ```
+/// where `Var` is the sample variance over the `n` bars of the window.
+///
+/// # Calculation Steps
+/// 1. Track the mean and M2 of overnight and open-to-close returns (see [`RollingMoments`])
+/// 2. Sum the Rogers-Satchell terms over the same bars
+/// 3. Combine the three variances with the weight `k` and annualize
+/// 4. Fill the first `period` values with NaN
+///
+/// # Arguments
+/// * `input_open` - Array of opening prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+/// * `opt_periods_per_year` - Number of bars per year (e.g. 252.0 for daily bars)
+/// * `output_yang_zhang` - Array to store the annualized Yang-Zhang volatility
+/// * `output_overnight_mean` - Array to store the rolling mean of overnight returns
+/// * `output_overnight_m2` - Array to store the rolling M2 of overnight returns
+/// * `output_intraday_mean` - Array to store the rolling mean of open-to-close returns
+/// * `output_intraday_m2` - Array to store the rolling M2 of open-to-close returns
+/// * `output_rs_sum` - Array to store the running sum of Rogers-Satchell terms
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty or any price is not positive
+/// * Returns `KandError::LengthMismatch` if input and output arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InsufficientData` if input length is not greater than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::yang_zhang;
+/// let input_open = vec![100.0, 101.0, 101.5, 102.0, 103.0];
+/// let input_high = vec![101.0, 102.5, 102.0, 103.5, 104.0];
+/// let input_low = vec![99.0, 100.5, 100.0, 101.0, 102.0];
+/// let input_close = vec![100.5, 101.5, 101.0, 103.0, 103.5];
+/// let mut output_yang_zhang = vec![0.0; 5];
+/// let mut output_overnight_mean = vec![0.0; 5];
+/// let mut output_overnight_m2 = vec![0.0; 5];
+/// let mut output_intraday_mean = vec![0.0; 5];
+/// let mut output_intraday_m2 = vec![0.0; 5];
+/// let mut output_rs_sum = vec![0.0; 5];
+///
+/// yang_zhang::yang_zhang(
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     3,
+///     252.0,
+///     &mut output_yang_zhang,
+///     &mut output_overnight_mean,
+///     &mut output_overnight_m2,
+///     &mut output_intraday_mean,
+///     &mut output_intraday_m2,
+///     &mut output_rs_sum,
+/// )
+/// .unwrap();
+/// // First `period` values are NaN, followed by annualized volatilities
+/// assert!(output_yang_zhang[2].is_nan());
+/// assert!(output_yang_zhang[3] > 0.0);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn yang_zhang(
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    output_yang_zhang: &mut [TAFloat],
+    output_overnight_mean: &mut [TAFloat],
+    output_overnight_m2: &mut [TAFloat],
+    output_intraday_mean: &mut [TAFloat],
+    output_intraday_m2: &mut [TAFloat],
+    output_rs_sum: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_open.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_high.len() != len
+            || input_low.len() != len
+            || input_close.len() != len
+            || output_yang_zhang.len() != len
+            || output_overnight_mean.len() != len
+            || output_overnight_m2.len() != len
+            || output_intraday_mean.len() != len
+            || output_intraday_m2.len() != len
+            || output_rs_sum.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    // Rogers-Satchell sums over the same windows; its volatility output is overwritten below
+    rogers_satchell::rogers_satchell(
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        opt_period,
+        opt_periods_per_year,
+        output_yang_zhang,
+        output_rs_sum,
+    )?;
+
+    let overnight = |i: usize| (input_open[i] / input_close[i - 1]).ln();
+    let intraday = |i: usize| (input_close[i] / input_open[i]).ln();
+
+    // Calculate initial values
+    let mut overnight_moments = RollingMoments::new();
+    let mut intraday_moments = RollingMoments::new();
+    for i in 1..=opt_period {
+        overnight_moments.push(overnight(i));
+        intraday_moments.push(intraday(i));
+    }
+
+    for i in lookback..len {
+        if i > lookback {
+            overnight_moments.replace(overnight(i), overnight(i - opt_period));
+            intraday_moments.replace(intraday(i), intraday(i - opt_period));
+        }
+        output_yang_zhang[i] = volatility(
+            overnight_moments.m2(),
+            intraday_moments.m2(),
+            output_rs_sum[i],
+            opt_period,
+            opt_periods_per_year,
+        );
+        output_overnight_mean[i] = overnight_moments.mean();
+        output_overnight_m2[i] = overnight_moments.m2();
+        output_intraday_mean[i] = intraday_moments.mean();
+        output_intraday_m2[i] = intraday_moments.m2();
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_yang_zhang[i] = TAFloat::NAN;
+        output_overnight_mean[i] = TAFloat::NAN;
+        output_overnight_m2[i] = TAFloat::NAN;
+        output_intraday_mean[i] = TAFloat::NAN;
+        output_intraday_m2[i] = TAFloat::NAN;
+        output_rs_sum[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Yang-Zhang volatility using incremental computation.
+///
+/// # Arguments
+/// * `input_open` - Opening price of the newest bar
+/// * `input_high` - High price of the newest bar
+/// * `input_low` - Low price of the newest bar
+/// * `input_close` - Closing price of the newest bar
+/// * `input_prev_close` - Closing price of the bar before the newest one
+/// * `input_old_open` - Opening price of the bar leaving the window
+/// * `input_old_high` - High price of the bar leaving the window
+/// * `input_old_low` - Low price of the bar leaving the window
+/// * `input_old_close` - Closing price of the bar leaving the window
+/// * `input_old_prev_close` - Closing price of the bar before the one leaving the window
+/// * `prev_overnight_mean` - Previous mean of overnight returns in the window
+/// * `prev_overnight_m2` - Previous M2 of overnight returns in the window
+/// * `prev_intraday_mean` - Previous mean of open-to-close returns in the window
+/// * `prev_intraday_m2` - Previous M2 of open-to-close returns in the window
+/// * `prev_rs_sum` - Previous sum of Rogers-Satchell terms in the window
+/// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+/// * `opt_periods_per_year` - Number of bars per year
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple
+///   containing (`yang_zhang`, `new_overnight_mean`, `new_overnight_m2`, `new_intraday_mean`,
+///   `new_intraday_m2`, `new_rs_sum`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or periods per year is not
+///   positive
+/// * Returns `KandError::InvalidData` if any price is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::yang_zhang;
+/// let input_open = [100.0, 101.0, 101.5, 102.0, 103.0];
+/// let input_high = [101.0, 102.5, 102.0, 103.5, 104.0];
+/// let input_low = [99.0, 100.5, 100.0, 101.0, 102.0];
+/// let input_close = [100.5, 101.5, 101.0, 103.0, 103.5];
+/// let mut outputs = [[0.0; 5]; 6];
+/// let [yz, on_mean, on_m2, id_mean, id_m2, rs_sum] = &mut outputs;
+/// yang_zhang::yang_zhang(
+///     &input_open, &input_high, &input_low, &input_close, 3, 252.0, yz, on_mean, on_m2,
+///     id_mean, id_m2, rs_sum,
+/// )
+/// .unwrap();
+///
+/// // Slide the window from bars 1..=3 to bars 2..=4
+/// let (yang_zhang, ..) = yang_zhang::yang_zhang_inc(
+///     103.0, 104.0, 102.0, 103.5, 103.0, // newest bar and its previous close
+///     101.0, 102.5, 100.5, 101.5, 100.5, // bar leaving the window and its previous close
+///     on_mean[3], on_m2[3], id_mean[3], id_m2[3], rs_sum[3], 3, 252.0,
+/// )
+/// .unwrap();
+/// assert!((yang_zhang - yz[4]).abs() < 1e-12);
+/// ```
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn yang_zhang_inc(
+    input_open: TAFloat,
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    input_prev_close: TAFloat,
+    input_old_open: TAFloat,
+    input_old_high: TAFloat,
+    input_old_low: TAFloat,
+    input_old_close: TAFloat,
+    input_old_prev_close: TAFloat,
+    prev_overnight_mean: TAFloat,
+    prev_overnight_m2: TAFloat,
+    prev_intraday_mean: TAFloat,
+    prev_intraday_m2: TAFloat,
+    prev_rs_sum: TAFloat,
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Price range check
+        if input_prev_close <= 0.0 || input_old_prev_close <= 0.0 {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_prev_close.is_nan()
+            || input_old_prev_close.is_nan()
+            || prev_overnight_mean.is_nan()
+            || prev_overnight_m2.is_nan()
+            || prev_intraday_mean.is_nan()
+            || prev_intraday_m2.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    // Checks the remaining prices and parameters
+    let (_, new_rs_sum) = rogers_satchell::rogers_satchell_inc(
+        input_open,
+        input_high,
+        input_low,
+        input_close,
+        input_old_open,
+        input_old_high,
+        input_old_low,
+        input_old_close,
+        prev_rs_sum,
+        opt_period,
+        opt_periods_per_year,
+    )?;
+    let (new_overnight_mean, new_overnight_m2) = moments::slide(
+        prev_overnight_mean,
+        prev_overnight_m2,
+        (input_open / input_prev_close).ln(),
+        (input_old_open / input_old_prev_close).ln(),
+        opt_period,
+    );
+    let (new_intraday_mean, new_intraday_m2) = moments::slide(
+        prev_intraday_mean,
+        prev_intraday_m2,
+        (input_close / input_open).ln(),
+        (input_old_close / input_old_open).ln(),
+        opt_period,
+    );
+    let yang_zhang = volatility(
+        new_overnight_m2,
+        new_intraday_m2,
+        new_rs_sum,
+        opt_period,
+        opt_periods_per_year,
+    );
+
+    Ok((
+        yang_zhang,
+        new_overnight_mean,
+        new_overnight_m2,
+        new_intraday_mean,
+        new_intraday_m2,
+        new_rs_sum,
+    ))
+}
+
+/// Annualized Yang-Zhang volatility of a window from its overnight and open-to-close M2 and its
+/// Rogers-Satchell sum.
+fn volatility(
+    overnight_m2: TAFloat,
+    intraday_m2: TAFloat,
+    rs_sum: TAFloat,
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+) -> TAFloat {
+    let n = opt_period as TAFloat;
+    let k = 0.34 / (1.34 + (n + 1.0) / (n - 1.0));
+    let variance =
+        k.mul_add(intraday_m2, overnight_m2) / (n - 1.0) + (1.0 - k) * rs_sum.max(0.0) / n;
+    (variance * opt_periods_per_year).sqrt()
+}
+
+/// Streaming rolling Yang-Zhang volatility that keeps the overnight and open-to-close returns of
+/// the last `opt_period` bars.
+///
+/// Reads the full OHLC of each [`Bar`] and feeds a per-bar [`RogersSatchell`] for the range part.
+/// Folding [`Indicator::update`] over a series reproduces [`yang_zhang`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YangZhang {
+    opt_period: usize,
+    opt_periods_per_year: TAFloat,
+    prev_close: Option<TAFloat>,
+    window: VecDeque<(TAFloat, TAFloat)>,
+    overnight_moments: RollingMoments,
+    intraday_moments: RollingMoments,
+    rogers_satchell: RogersSatchell,
+}
+
+impl YangZhang {
+    /// Creates a streaming rolling Yang-Zhang volatility.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The number of bars in the rolling window (must be >= 2)
+    /// * `opt_periods_per_year` - Number of bars per year (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2 or periods per year is not positive
+    pub fn new(opt_period: usize, opt_periods_per_year: TAFloat) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        if opt_periods_per_year <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            opt_periods_per_year,
+            prev_close: None,
+            window: VecDeque::with_capacity(opt_period + 1),
+            overnight_moments: RollingMoments::new(),
+            intraday_moments: RollingMoments::new(),
+            // Per-bar volatility, squared back into the Rogers-Satchell variance
+            rogers_satchell: RogersSatchell::new(opt_period, 1.0)?,
+        })
+    }
+}
+
+impl Indicator for YangZhang {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input.open.is_nan()
+                || input.high.is_nan()
+                || input.low.is_nan()
+                || input.close.is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        #[cfg(feature = "check")]
+        {
+            if input.open <= 0.0 || input.high <= 0.0 || input.low <= 0.0 || input.close <= 0.0 {
+                return Err(KandError::InvalidData);
+            }
+        }
+
+        // The first bar has no overnight return
+        let Some(prev_close) = self.prev_close.replace(input.close) else {
+            return Ok(TAFloat::NAN);
+        };
+        let rs_volatility = self.rogers_satchell.update(input)?;
+
+        let overnight = (input.open / prev_close).ln();
+        let intraday = (input.close / input.open).ln();
+        self.window.push_back((overnight, intraday));
+        if self.window.len() > self.opt_period {
+            let (old_overnight, old_intraday) = self.window.pop_front().unwrap_or((0.0, 0.0));
+            self.overnight_moments.replace(overnight, old_overnight);
+            self.intraday_moments.replace(intraday, old_intraday);
+        } else {
+            self.overnight_moments.push(overnight);
+            self.intraday_moments.push(intraday);
+        }
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(volatility(
+            self.overnight_moments.m2(),
+            self.intraday_moments.m2(),
+            rs_volatility * rs_volatility * self.opt_period as TAFloat,
+            self.opt_period,
+            self.opt_periods_per_year,
+        ))
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.prev_close = None;
+        self.window.clear();
+        self.overnight_moments = RollingMoments::new();
+        self.intraday_moments = RollingMoments::new();
+        self.rogers_satchell.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_OPEN: [TAFloat; 25] = [
+        35230.0, 35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+        35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0,
+        35024.6, 34939.5, 34952.6, 35000.0, 35041.8,
+    ];
+    const INPUT_HIGH: [TAFloat; 25] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+        35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+    ];
+    const INPUT_LOW: [TAFloat; 25] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+        34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+    ];
+    const INPUT_CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    #[test]
+    fn test_yang_zhang_calculation() {
+        let opt_period = 10;
+        let len = INPUT_CLOSE.len();
+        let mut output_yang_zhang = vec![0.0; len];
+        let mut output_overnight_mean = vec![0.0; len];
+        let mut output_overnight_m2 = vec![0.0; len];
+        let mut output_intraday_mean = vec![0.0; len];
+        let mut output_intraday_m2 = vec![0.0; len];
+        let mut output_rs_sum = vec![0.0; len];
+
+        yang_zhang(
+            &INPUT_OPEN,
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            opt_period,
+            252.0,
+            &mut output_yang_zhang,
+            &mut output_overnight_mean,
+            &mut output_overnight_m2,
+            &mut output_intraday_mean,
+            &mut output_intraday_m2,
+            &mut output_rs_sum,
+        )
+        .unwrap();
+
+        // First 10 values should be NaN
+        for i in 0..10 {
+            assert!(output_yang_zhang[i].is_nan());
+            assert!(output_rs_sum[i].is_nan());
+        }
+
+        let expected_values = [
+            0.015_699_356_410_335_555,
+            0.016_344_164_531_679_195,
+            0.016_493_176_497_987_22,
+            0.016_223_751_713_580_94,
+            0.019_844_683_776_206_806,
+            0.021_084_347_776_934_013,
+            0.022_797_417_482_507_448,
+            0.022_326_789_858_154_57,
+            0.022_108_639_908_845_273,
+            0.022_242_157_463_655_536,
+            0.023_138_468_145_911_84,
+            0.023_217_254_358_450_63,
+            0.023_232_799_225_297_48,
+            0.023_451_168_359_802_477,
+            0.020_712_727_670_714_255,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_yang_zhang[i + 10], expected, epsilon = 1e-12);
+        }
+
+        // Now test incremental calculation matches regular calculation
+        let mut prev_overnight_mean = output_overnight_mean[10];
+        let mut prev_overnight_m2 = output_overnight_m2[10];
+        let mut prev_intraday_mean = output_intraday_mean[10];
+        let mut prev_intraday_m2 = output_intraday_m2[10];
+        let mut prev_rs_sum = output_rs_sum[10];
+        for i in 11..len {
+            let j = i - opt_period;
+            let (
+                yang_zhang,
+                new_overnight_mean,
+                new_overnight_m2,
+                new_intraday_mean,
+                new_intraday_m2,
+                new_rs_sum,
+            ) = yang_zhang_inc(
+                INPUT_OPEN[i],
+                INPUT_HIGH[i],
+                INPUT_LOW[i],
+                INPUT_CLOSE[i],
+                INPUT_CLOSE[i - 1],
+                INPUT_OPEN[j],
+                INPUT_HIGH[j],
+                INPUT_LOW[j],
+                INPUT_CLOSE[j],
+                INPUT_CLOSE[j - 1],
+                prev_overnight_mean,
+                prev_overnight_m2,
+                prev_intraday_mean,
+                prev_intraday_m2,
+                prev_rs_sum,
+                opt_period,
+                252.0,
+            )
+            .unwrap();
+            assert_relative_eq!(yang_zhang, output_yang_zhang[i], epsilon = 1e-12);
+            prev_overnight_mean = new_overnight_mean;
+            prev_overnight_m2 = new_overnight_m2;
+            prev_intraday_mean = new_intraday_mean;
+            prev_intraday_m2 = new_intraday_m2;
+            prev_rs_sum = new_rs_sum;
+        }
+    }
+
+    #[test]
+    fn test_yang_zhang_indicator() {
+        let opt_period = 10;
+        let len = INPUT_CLOSE.len();
+        let mut output_yang_zhang = vec![0.0; len];
+        let mut output_overnight_mean = vec![0.0; len];
+        let mut output_overnight_m2 = vec![0.0; len];
+        let mut output_intraday_mean = vec![0.0; len];
+        let mut output_intraday_m2 = vec![0.0; len];
+        let mut output_rs_sum = vec![0.0; len];
+        yang_zhang(
+            &INPUT_OPEN,
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            opt_period,
+            252.0,
+            &mut output_yang_zhang,
+            &mut output_overnight_mean,
+            &mut output_overnight_m2,
+            &mut output_intraday_mean,
+            &mut output_intraday_m2,
+            &mut output_rs_sum,
+        )
+        .unwrap();
+
+        let mut indicator = YangZhang::new(opt_period, 252.0).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for i in 0..len {
+            let bar = Bar::new(
+                INPUT_OPEN[i],
+                INPUT_HIGH[i],
+                INPUT_LOW[i],
+                INPUT_CLOSE[i],
+                0.0,
+            );
+            let value = indicator.update(&bar).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_yang_zhang[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+        assert!(YangZhang::new(opt_period, 0.0).is_err());
+    }
+}
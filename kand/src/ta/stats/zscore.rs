@@ -0,0 +1,440 @@
+use std::collections::VecDeque;
+
+use super::moments::{self, RollingMoments};
+use crate::{KandError, TAFloat, indicator::Indicator, types::Bar};
+
+/// Calculates the lookback period required for rolling Z-Score calculation.
+///
+/// # Description
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For Z-Score calculation, this equals the specified period minus one.
+///
+/// # Arguments
+/// * `opt_period` - The time period used for Z-Score calculation (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `opt_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::zscore;
+/// let period = 20;
+/// let lookback = zscore::lookback(period).unwrap();
+/// assert_eq!(lookback, 19); // lookback is period - 1
+/// ```
+pub const fn lookback(opt_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(opt_period - 1)
+}
+
+/// Calculates the rolling Z-Score of a price series against its own rolling mean.
+///
+/// # Description
+/// The Z-Score expresses how many standard deviations the latest price is above (positive) or
+/// below (negative) the mean of the last `opt_period` prices, including itself. It is a common
+/// mean-reversion signal.
+///
+/// # Mathematical Formula
+/// ```text
+/// Z = (x - mean) / sqrt(M2 / n)
+/// ```
+/// Where:
+/// - x is the latest price and mean the rolling mean over the period
+/// - M2 = sum((x - mean)^2) over the period, so sqrt(M2 / n) is the population standard deviation
+/// - n is the time period
+///
+/// # Calculation Steps
+/// 1. Accumulate the mean and M2 of the first period
+/// 2. Slide the window with Welford's update, using compensated sums (see [`RollingMoments`])
+/// 3. Divide the deviation of the latest price by the standard deviation, giving NaN for a
+///    constant window
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `opt_period` - The time period for Z-Score calculation (must be >= 2)
+/// * `output_zscore` - Array to store calculated Z-Score values
+/// * `output_mean` - Array to store the rolling mean values
+/// * `output_m2` - Array to store the rolling sum of squared deviations (M2)
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::zscore;
+/// let input_prices = vec![2.0, 4.0, 6.0, 8.0, 10.0];
+/// let period = 3;
+/// let mut output_zscore = vec![0.0; 5];
+/// let mut output_mean = vec![0.0; 5];
+/// let mut output_m2 = vec![0.0; 5];
+///
+/// zscore::zscore(
+///     &input_prices,
+///     period,
+///     &mut output_zscore,
+///     &mut output_mean,
+///     &mut output_m2,
+/// )
+/// .unwrap();
+/// // Every window is evenly spaced, so the latest price is sqrt(1.5) deviations above its mean
+/// assert!((output_zscore[4] - 1.5_f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn zscore(
+    input_prices: &[TAFloat],
+    opt_period: usize,
+    output_zscore: &mut [TAFloat],
+    output_mean: &mut [TAFloat],
+    output_m2: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(opt_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_zscore.len() != len || output_mean.len() != len || output_m2.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial values
+    let mut moments = RollingMoments::new();
+    for val in input_prices.iter().take(opt_period) {
+        moments.push(*val);
+    }
+    output_zscore[lookback] = z_of(input_prices[lookback], &moments);
+    output_mean[lookback] = moments.mean();
+    output_m2[lookback] = moments.m2();
+
+    // Calculate remaining values incrementally
+    for i in opt_period..len {
+        moments.replace(input_prices[i], input_prices[i - opt_period]);
+        output_zscore[i] = z_of(input_prices[i], &moments);
+        output_mean[i] = moments.mean();
+        output_m2[i] = moments.m2();
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_zscore[i] = TAFloat::NAN;
+        output_mean[i] = TAFloat::NAN;
+        output_m2[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Z-Score value using incremental computation.
+///
+/// # Description
+/// This function slides the previous mean and sum of squared deviations (M2) with Welford's
+/// update in O(1), then scores the newest price against the updated window.
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `prev_mean` - Previous mean of values in the period
+/// * `prev_m2` - Previous sum of squared deviations from the mean in the period
+/// * `input_old_price` - Oldest price value to remove from calculation
+/// * `opt_period` - The time period for Z-Score calculation (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (z-score, `new_mean`,
+///   `new_m2`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`check-nan`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::zscore;
+/// // Window [5, 8, 12] slides to [8, 12, 10]
+/// let (z, new_mean, new_m2) = zscore::zscore_inc(
+///     10.0,         // new price
+///     25.0 / 3.0,   // previous mean
+///     74.0 / 3.0,   // previous M2
+///     5.0,          // price to remove
+///     3,            // period
+/// )
+/// .unwrap();
+/// assert!((new_mean - 10.0).abs() < 1e-12);
+/// assert!(z.abs() < 1e-12); // 10 is the window mean
+/// ```
+pub fn zscore_inc(
+    input_price: TAFloat,
+    prev_mean: TAFloat,
+    prev_m2: TAFloat,
+    input_old_price: TAFloat,
+    opt_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if opt_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "check-nan")]
+    {
+        // NaN check
+        if input_price.is_nan()
+            || prev_mean.is_nan()
+            || prev_m2.is_nan()
+            || input_old_price.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (new_mean, new_m2) =
+        moments::slide(prev_mean, prev_m2, input_price, input_old_price, opt_period);
+
+    Ok((
+        z_score(input_price, new_mean, new_m2, opt_period),
+        new_mean,
+        new_m2,
+    ))
+}
+
+/// Returns `(price - mean) / sqrt(M2 / n)`, NaN if the window is constant.
+fn z_score(price: TAFloat, mean: TAFloat, m2: TAFloat, count: usize) -> TAFloat {
+    if m2 > 0.0 {
+        (price - mean) / (m2 / count as TAFloat).sqrt()
+    } else {
+        TAFloat::NAN
+    }
+}
+
+/// Scores `price` against the window held by `moments`.
+fn z_of(price: TAFloat, moments: &RollingMoments) -> TAFloat {
+    z_score(price, moments.mean(), moments.m2(), moments.count())
+}
+
+/// Streaming rolling z-score that keeps the last `opt_period` values and their
+/// [`RollingMoments`].
+///
+/// Reads [`Bar::close`]. Folding [`Indicator::update`] over a series reproduces [`zscore`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Zscore {
+    opt_period: usize,
+    window: VecDeque<TAFloat>,
+    moments: RollingMoments,
+}
+
+impl Zscore {
+    /// Creates a streaming rolling z-score.
+    ///
+    /// # Arguments
+    /// * `opt_period` - The rolling window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If period < 2
+    pub fn new(opt_period: usize) -> Result<Self, KandError> {
+        lookback(opt_period)?;
+        Ok(Self {
+            opt_period,
+            window: VecDeque::with_capacity(opt_period + 1),
+            moments: RollingMoments::new(),
+        })
+    }
+
+    /// Feeds a raw value instead of a [`Bar`] and returns the rolling z-score for it.
+    ///
+    /// # Errors
+    /// * `KandError::NaNDetected` - If `input_price` is NaN (with "`check-nan`")
+    pub fn update_value(&mut self, input_price: TAFloat) -> Result<TAFloat, KandError> {
+        #[cfg(feature = "check-nan")]
+        {
+            if input_price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() > self.opt_period {
+            let old_price = self.window.pop_front().unwrap_or(0.0);
+            self.moments.replace(input_price, old_price);
+        } else {
+            self.moments.push(input_price);
+        }
+        if self.window.len() < self.opt_period {
+            return Ok(TAFloat::NAN);
+        }
+        Ok(z_of(input_price, &self.moments))
+    }
+}
+
+impl Indicator for Zscore {
+    type Output = TAFloat;
+
+    fn update(&mut self, input: &Bar) -> Result<TAFloat, KandError> {
+        self.update_value(input.close)
+    }
+
+    fn lookback(&self) -> usize {
+        self.opt_period - 1
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.opt_period
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.moments = RollingMoments::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_zscore_calculation() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let opt_period = 14;
+        let len = input_close.len();
+        let mut output_zscore = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+
+        zscore(
+            &input_close,
+            opt_period,
+            &mut output_zscore,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+
+        for value in &output_zscore[..13] {
+            assert!(value.is_nan());
+        }
+
+        // Two-pass reference values
+        let expected_values = [
+            -1.527_613_924_502_455,
+            -2.598_018_103_470_067,
+            -2.534_246_015_772_266_6,
+            -2.327_083_790_393_395_6,
+            -1.734_422_631_107_981_3,
+            -0.935_060_989_590_259_7,
+            -1.260_117_308_082_391,
+            -1.808_598_276_863_036_6,
+            -1.448_282_251_341_085_3,
+            -0.827_361_715_427_562_2,
+            -0.289_833_149_246_793,
+            0.222_917_652_567_130_5,
+        ];
+        for (i, &expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_zscore[i + 13], expected, epsilon = 1e-9);
+        }
+
+        // Incremental calculation matches the batch outputs
+        let mut prev_mean = output_mean[13];
+        let mut prev_m2 = output_m2[13];
+        for i in 14..len {
+            let (z, new_mean, new_m2) = zscore_inc(
+                input_close[i],
+                prev_mean,
+                prev_m2,
+                input_close[i - opt_period],
+                opt_period,
+            )
+            .unwrap();
+            assert_relative_eq!(z, output_zscore[i], epsilon = 1e-9);
+            prev_mean = new_mean;
+            prev_m2 = new_m2;
+        }
+
+        // A constant window has no z-score
+        let flat = vec![100.0; 4];
+        let mut outputs = vec![vec![0.0; 4]; 3];
+        let [out_z, out_mean, out_m2] = &mut outputs[..] else {
+            unreachable!()
+        };
+        zscore(&flat, 2, out_z, out_mean, out_m2).unwrap();
+        assert!(out_z[3].is_nan());
+    }
+
+    #[test]
+    fn test_zscore_indicator() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+        ];
+        let opt_period = 5;
+        let len = input_price.len();
+        let mut output_zscore = vec![0.0; len];
+        let mut output_mean = vec![0.0; len];
+        let mut output_m2 = vec![0.0; len];
+        zscore(
+            &input_price,
+            opt_period,
+            &mut output_zscore,
+            &mut output_mean,
+            &mut output_m2,
+        )
+        .unwrap();
+
+        let mut indicator = Zscore::new(opt_period).unwrap();
+        let lookback = lookback(opt_period).unwrap();
+        assert_eq!(indicator.lookback(), lookback);
+        for (i, &price) in input_price.iter().enumerate() {
+            let value = indicator.update(&Bar::from(price)).unwrap();
+            if i < lookback {
+                assert!(value.is_nan());
+                assert!(!indicator.is_ready());
+            } else {
+                assert_relative_eq!(value, output_zscore[i], epsilon = 1e-12);
+            }
+        }
+        assert!(indicator.is_ready());
+
+        indicator.reset();
+        assert!(!indicator.is_ready());
+    }
+}
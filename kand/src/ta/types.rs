@@ -1,5 +1,7 @@
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
+use crate::TAFloat;
+
 /// Moving Average types for technical analysis.
 ///
 /// The integer representation of this enum is determined by the enabled features:
@@ -20,6 +22,7 @@
 /// * `TRIMA` - Triangular Moving Average
 /// * `WMA` - Weighted Moving Average
 #[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg(feature = "i64")]
 #[repr(i64)]
 pub enum MAType {
@@ -36,6 +39,7 @@ pub enum MAType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg(not(feature = "i64"))]
 #[repr(i32)]
 pub enum MAType {
@@ -112,3 +116,51 @@ fn default() -> Self {
         Self::Neutral
     }
 }
+
+/// A single OHLCV bar fed to streaming indicators.
+///
+/// Indicators that only consume one price series read [`Bar::close`]; use
+/// [`Bar::from`] to wrap a bare price when no other fields are available.
+///
+/// # Fields
+/// * `open` - Opening price
+/// * `high` - Highest price
+/// * `low` - Lowest price
+/// * `close` - Closing price
+/// * `volume` - Traded volume
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bar {
+    pub open: TAFloat,
+    pub high: TAFloat,
+    pub low: TAFloat,
+    pub close: TAFloat,
+    pub volume: TAFloat,
+}
+
+impl Bar {
+    /// Creates a bar from its OHLCV components.
+    #[must_use]
+    pub const fn new(
+        open: TAFloat,
+        high: TAFloat,
+        low: TAFloat,
+        close: TAFloat,
+        volume: TAFloat,
+    ) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+            volume,
+        }
+    }
+}
+
+impl From<TAFloat> for Bar {
+    /// Creates a flat bar whose open, high, low and close all equal `price`, with zero volume.
+    fn from(price: TAFloat) -> Self {
+        Self::new(price, price, price, price, 0.0)
+    }
+}
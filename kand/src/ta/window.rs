@@ -0,0 +1,187 @@
+use std::collections::VecDeque;
+
+use crate::{KandError, TAFloat};
+
+/// Which end of the window a [`RollingExtremum`] tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Extremum {
+    /// Track the highest value in the window.
+    Highest,
+    /// Track the lowest value in the window.
+    Lowest,
+}
+
+/// Exact rolling highest/lowest value over the last `opt_period` inputs.
+///
+/// Keeps a monotonic deque of `(sequence, value)` pairs: every value that can no longer become the
+/// extremum before it leaves the window is dropped on insertion, so each update costs O(1)
+/// amortized and the front of the deque is always the current extremum.
+///
+/// Ties resolve to the most recent value, matching [`crate::helper::highest_bars`] and
+/// [`crate::helper::lowest_bars`].
+///
+/// # Example
+/// ```
+/// use kand::window::RollingExtremum;
+///
+/// let mut highest = RollingExtremum::highest(3).unwrap();
+/// for price in [5.0, 9.0, 7.0, 6.0] {
+///     highest.push(price);
+/// }
+/// // Window is [9.0, 7.0, 6.0]; the 9.0 was pushed 2 bars ago
+/// assert_eq!(highest.value(), 9.0);
+/// assert_eq!(highest.bars_since(), 2);
+///
+/// highest.push(4.0);
+/// // 9.0 has left the window
+/// assert_eq!(highest.value(), 7.0);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingExtremum {
+    opt_period: usize,
+    kind: Extremum,
+    count: usize,
+    deque: VecDeque<(usize, TAFloat)>,
+}
+
+impl RollingExtremum {
+    /// Creates a window of `opt_period` inputs tracking the given [`Extremum`].
+    ///
+    /// # Arguments
+    /// * `opt_period` - Number of most recent inputs covered by the window
+    /// * `kind` - Whether to track the highest or the lowest value
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` is 0
+    pub fn new(opt_period: usize, kind: Extremum) -> Result<Self, KandError> {
+        if opt_period == 0 {
+            return Err(KandError::InvalidParameter);
+        }
+        Ok(Self {
+            opt_period,
+            kind,
+            count: 0,
+            deque: VecDeque::with_capacity(opt_period),
+        })
+    }
+
+    /// Creates a window tracking the highest of the last `opt_period` inputs.
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` is 0
+    pub fn highest(opt_period: usize) -> Result<Self, KandError> {
+        Self::new(opt_period, Extremum::Highest)
+    }
+
+    /// Creates a window tracking the lowest of the last `opt_period` inputs.
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `opt_period` is 0
+    pub fn lowest(opt_period: usize) -> Result<Self, KandError> {
+        Self::new(opt_period, Extremum::Lowest)
+    }
+
+    /// Adds the next input and returns `(extremum, bars_since)` for the updated window.
+    ///
+    /// `bars_since` is 0 when the newest input is the extremum. Until [`Self::is_full`] holds,
+    /// the result covers only the inputs seen so far.
+    pub fn push(&mut self, value: TAFloat) -> (TAFloat, usize) {
+        let seq = self.count;
+        self.count += 1;
+
+        while let Some(&(_, back)) = self.deque.back() {
+            let dominated = match self.kind {
+                Extremum::Highest => back <= value,
+                Extremum::Lowest => back >= value,
+            };
+            if !dominated {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((seq, value));
+
+        while let Some(&(front_seq, _)) = self.deque.front() {
+            if seq - front_seq < self.opt_period {
+                break;
+            }
+            self.deque.pop_front();
+        }
+
+        (self.value(), self.bars_since())
+    }
+
+    /// Current extremum, or `NaN` if nothing has been pushed yet.
+    #[must_use]
+    pub fn value(&self) -> TAFloat {
+        self.deque.front().map_or(TAFloat::NAN, |&(_, value)| value)
+    }
+
+    /// Number of inputs since the current extremum was pushed (0 for the newest input).
+    #[must_use]
+    pub fn bars_since(&self) -> usize {
+        self.deque
+            .front()
+            .map_or(0, |&(seq, _)| self.count - 1 - seq)
+    }
+
+    /// Returns `true` once `opt_period` inputs have been pushed.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.count >= self.opt_period
+    }
+
+    /// Removes all inputs from the window.
+    pub fn clear(&mut self) {
+        self.count = 0;
+        self.deque.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::{highest_bars, lowest_bars};
+
+    #[test]
+    fn test_rolling_extremum() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35080.0, 35080.0,
+            35041.8, 35041.8,
+        ];
+        let opt_period = 5;
+
+        let mut highest = RollingExtremum::highest(opt_period).unwrap();
+        let mut lowest = RollingExtremum::lowest(opt_period).unwrap();
+        for (i, &value) in input.iter().enumerate() {
+            let (high, high_bars) = highest.push(value);
+            let (low, low_bars) = lowest.push(value);
+            let window = opt_period.min(i + 1);
+            assert_eq!(highest.is_full(), i + 1 >= opt_period);
+
+            let expected_high_bars = highest_bars(&input, i, window).unwrap();
+            let expected_low_bars = lowest_bars(&input, i, window).unwrap();
+            assert_eq!(high_bars, expected_high_bars);
+            assert_eq!(low_bars, expected_low_bars);
+            assert_eq!(high, input[i - expected_high_bars]);
+            assert_eq!(low, input[i - expected_low_bars]);
+        }
+
+        highest.clear();
+        assert!(!highest.is_full());
+        assert!(highest.value().is_nan());
+        assert_eq!(highest.push(1.0), (1.0, 0));
+    }
+
+    #[test]
+    fn test_rolling_extremum_invalid_period() {
+        assert!(matches!(
+            RollingExtremum::highest(0),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}